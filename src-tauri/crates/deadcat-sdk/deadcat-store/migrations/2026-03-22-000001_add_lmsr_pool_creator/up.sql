@@ -0,0 +1 @@
+ALTER TABLE lmsr_pools ADD COLUMN creator_pubkey BLOB;
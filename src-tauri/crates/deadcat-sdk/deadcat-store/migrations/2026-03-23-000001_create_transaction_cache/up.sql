@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS transactions (
+    txid BLOB PRIMARY KEY NOT NULL,
+    raw_tx BLOB NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
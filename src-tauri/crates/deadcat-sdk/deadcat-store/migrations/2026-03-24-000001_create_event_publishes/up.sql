@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS event_publishes (
+    event_id TEXT PRIMARY KEY NOT NULL,
+    event_json TEXT NOT NULL,
+    accepted_relays TEXT NOT NULL DEFAULT '[]',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    status TEXT NOT NULL,
+    last_error TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+CREATE INDEX IF NOT EXISTS idx_event_publishes_status ON event_publishes(status, created_at);
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS price_alerts (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    pool_id TEXT NOT NULL,
+    direction TEXT NOT NULL,
+    threshold_bps INTEGER NOT NULL,
+    triggered INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+CREATE INDEX IF NOT EXISTS idx_price_alerts_pool ON price_alerts(pool_id);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS action_log (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    kind TEXT NOT NULL,
+    market_id TEXT,
+    txid TEXT,
+    details_json TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+CREATE INDEX IF NOT EXISTS idx_action_log_market ON action_log(market_id);
+CREATE INDEX IF NOT EXISTS idx_action_log_kind ON action_log(kind);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS broadcast_intents (
+    txid TEXT PRIMARY KEY NOT NULL,
+    kind TEXT NOT NULL,
+    market_id TEXT,
+    raw_tx BLOB NOT NULL,
+    status TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+CREATE INDEX IF NOT EXISTS idx_broadcast_intents_status ON broadcast_intents(status, created_at);
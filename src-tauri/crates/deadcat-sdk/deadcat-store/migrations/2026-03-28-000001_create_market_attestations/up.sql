@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS market_attestations (
+    market_id TEXT PRIMARY KEY NOT NULL,
+    outcome_yes INTEGER NOT NULL,
+    signature_hex TEXT NOT NULL,
+    nostr_event_id TEXT NOT NULL,
+    signed_at BIGINT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS payment_swaps (
+    id TEXT PRIMARY KEY NOT NULL,
+    flow TEXT NOT NULL,
+    network TEXT NOT NULL,
+    status TEXT NOT NULL,
+    invoice_amount_sat BIGINT NOT NULL,
+    expected_amount_sat BIGINT,
+    lockup_address TEXT,
+    timeout_block_height BIGINT,
+    pair_hash TEXT,
+    invoice TEXT,
+    invoice_expiry_seconds BIGINT,
+    invoice_expires_at TEXT,
+    lockup_txid TEXT,
+    claim_txid TEXT,
+    preimage_hash TEXT,
+    preimage TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_payment_swaps_lockup_txid ON payment_swaps(lockup_txid);
+CREATE INDEX IF NOT EXISTS idx_payment_swaps_claim_txid ON payment_swaps(claim_txid);
@@ -0,0 +1 @@
+ALTER TABLE payment_swaps ADD COLUMN claim_details TEXT;
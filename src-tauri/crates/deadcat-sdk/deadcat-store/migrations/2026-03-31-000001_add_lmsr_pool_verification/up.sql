@@ -0,0 +1,3 @@
+ALTER TABLE lmsr_pools ADD COLUMN verification_status TEXT;
+ALTER TABLE lmsr_pools ADD COLUMN verification_reason TEXT;
+ALTER TABLE lmsr_pools ADD COLUMN verified_at TEXT;
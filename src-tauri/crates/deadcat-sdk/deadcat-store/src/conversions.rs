@@ -2,18 +2,23 @@ use deadcat_sdk::elements::encode::{deserialize as elements_deserialize, seriali
 use deadcat_sdk::elements::hashes::Hash;
 use deadcat_sdk::elements::{OutPoint, TxOut, Txid};
 use deadcat_sdk::{
-    CompiledMakerOrder, CompiledPredictionMarket, DormantOutputOpening, MakerOrderParams, MarketId,
-    MarketSlot, MarketState, OrderDirection, PredictionMarketAnchor,
-    PredictionMarketCandidateIngestInput, PredictionMarketParams, UnblindedUtxo,
-    derive_maker_receive, maker_receive_script_pubkey,
+    BroadcastIntent, CompiledMakerOrder, CompiledPredictionMarket, DormantOutputOpening,
+    IntentKind, IntentStatus, MakerOrderParams, MarketId, MarketSlot, MarketState, OrderDirection,
+    PredictionMarketAnchor, PredictionMarketCandidateIngestInput, PredictionMarketParams,
+    PublishRecord, PublishStatus, UnblindedUtxo, VerifiedAttestation, derive_maker_receive,
+    maker_receive_script_pubkey,
 };
 
 use crate::error::StoreError;
 use crate::models::{
-    MakerOrderRow, MarketCandidateRow, MarketRow, NewMakerOrderRow, NewMarketCandidateRow,
-    NewUtxoRow, UtxoRow,
+    ActionLogRow, BroadcastIntentRow, EventPublishRow, MakerOrderRow, MarketAttestationRow,
+    MarketCandidateRow, MarketRow, NewMakerOrderRow, NewMarketCandidateRow, NewUtxoRow,
+    PaymentSwapRow, PriceAlertRow, UtxoRow,
+};
+use crate::store::{
+    ActionKind, ActionLogEntry, IssuanceData, MakerOrderInfo, MarketCandidateInfo, MarketInfo,
+    OrderStatus, PaymentSwapRecord, PriceAlert, PriceAlertDirection,
 };
-use crate::store::{IssuanceData, MakerOrderInfo, MarketCandidateInfo, MarketInfo, OrderStatus};
 
 pub struct DecodedDormantOpenings {
     pub yes_abf: Vec<u8>,
@@ -438,3 +443,126 @@ pub fn new_utxo_row(
         block_height: block_height.map(|h| h as i32),
     }
 }
+
+pub fn publish_record_from_row(
+    row: EventPublishRow,
+) -> std::result::Result<PublishRecord, StoreError> {
+    let accepted_relays = serde_json::from_str(&row.accepted_relays).map_err(|e| {
+        StoreError::InvalidData(format!("accepted relays for {}: {e}", row.event_id))
+    })?;
+    let attempts = u32::try_from(row.attempts)
+        .map_err(|_| StoreError::InvalidData(format!("invalid attempt count: {}", row.attempts)))?;
+    let status = PublishStatus::parse(&row.status).ok_or_else(|| {
+        StoreError::InvalidData(format!("invalid publish status: {}", row.status))
+    })?;
+    Ok(PublishRecord {
+        event_id: row.event_id,
+        event_json: row.event_json,
+        accepted_relays,
+        attempts,
+        status,
+        last_error: row.last_error,
+    })
+}
+
+pub fn price_alert_from_row(row: PriceAlertRow) -> std::result::Result<PriceAlert, StoreError> {
+    let direction = PriceAlertDirection::parse(&row.direction).ok_or_else(|| {
+        StoreError::InvalidData(format!("invalid price alert direction: {}", row.direction))
+    })?;
+    let threshold_bps = u16::try_from(row.threshold_bps).map_err(|_| {
+        StoreError::InvalidData(format!("invalid alert threshold: {}", row.threshold_bps))
+    })?;
+    Ok(PriceAlert {
+        id: row.id,
+        pool_id: row.pool_id,
+        direction,
+        threshold_bps,
+        triggered: row.triggered != 0,
+        created_at: row.created_at,
+    })
+}
+
+pub fn action_log_entry_from_row(
+    row: ActionLogRow,
+) -> std::result::Result<ActionLogEntry, StoreError> {
+    let kind = ActionKind::parse(&row.kind)
+        .ok_or_else(|| StoreError::InvalidData(format!("invalid action kind: {}", row.kind)))?;
+    Ok(ActionLogEntry {
+        id: row.id,
+        kind,
+        market_id: row.market_id,
+        txid: row.txid,
+        details_json: row.details_json,
+        created_at: row.created_at,
+    })
+}
+
+pub fn broadcast_intent_from_row(
+    row: BroadcastIntentRow,
+) -> std::result::Result<BroadcastIntent, StoreError> {
+    let kind = IntentKind::parse(&row.kind)
+        .ok_or_else(|| StoreError::InvalidData(format!("invalid intent kind: {}", row.kind)))?;
+    let status = IntentStatus::parse(&row.status)
+        .ok_or_else(|| StoreError::InvalidData(format!("invalid intent status: {}", row.status)))?;
+    Ok(BroadcastIntent {
+        txid: row.txid,
+        kind,
+        market_id: row.market_id,
+        raw_tx: row.raw_tx,
+        status,
+    })
+}
+
+pub fn market_attestation_from_row(
+    row: MarketAttestationRow,
+) -> std::result::Result<VerifiedAttestation, StoreError> {
+    let signed_at = u64::try_from(row.signed_at).map_err(|_| {
+        StoreError::InvalidData(format!("invalid attestation time: {}", row.signed_at))
+    })?;
+    Ok(VerifiedAttestation {
+        market_id: row.market_id,
+        outcome_yes: row.outcome_yes != 0,
+        signature_hex: row.signature_hex,
+        nostr_event_id: row.nostr_event_id,
+        signed_at,
+    })
+}
+
+pub fn payment_swap_from_row(
+    row: PaymentSwapRow,
+) -> std::result::Result<PaymentSwapRecord, StoreError> {
+    let sat = |value: i64, field: &str| {
+        u64::try_from(value)
+            .map_err(|_| StoreError::InvalidData(format!("invalid swap {field}: {value}")))
+    };
+    Ok(PaymentSwapRecord {
+        invoice_amount_sat: sat(row.invoice_amount_sat, "invoice amount")?,
+        expected_amount_sat: row
+            .expected_amount_sat
+            .map(|v| sat(v, "expected amount"))
+            .transpose()?,
+        timeout_block_height: row
+            .timeout_block_height
+            .map(|v| sat(v, "timeout height"))
+            .transpose()?,
+        invoice_expiry_seconds: row
+            .invoice_expiry_seconds
+            .map(|v| sat(v, "invoice expiry"))
+            .transpose()?,
+        id: row.id,
+        flow: row.flow,
+        network: row.network,
+        status: row.status,
+        lockup_address: row.lockup_address,
+        pair_hash: row.pair_hash,
+        invoice: row.invoice,
+        invoice_expires_at: row.invoice_expires_at,
+        lockup_txid: row.lockup_txid,
+        claim_txid: row.claim_txid,
+        preimage_hash: row.preimage_hash,
+        preimage: row.preimage,
+        claim_details: row.claim_details,
+        created_at: row.created_at,
+        updated_at: row.updated_at,
+    })
+}
@@ -11,6 +11,11 @@ pub enum StoreError {
     #[error("migration error: {0}")]
     Migration(String),
 
+    /// A migration failed and the database file was restored from the copy
+    /// taken before migrating, so it is back at its previous schema version.
+    #[error("migration failed and the database was restored from backup: {reason}")]
+    MigrationRolledBack { reason: String },
+
     #[error("invalid data: {0}")]
     InvalidData(String),
 
@@ -32,8 +32,10 @@ pub use deadcat_sdk::{
 };
 pub use error::StoreError;
 pub use store::{
-    DeadcatStore, IssuanceData, LmsrPoolFilter, LmsrPoolInfo, MakerOrderInfo,
-    MarketCandidateFilter, MarketCandidateInfo, MarketFilter, MarketInfo, OrderFilter, OrderStatus,
+    ActionKind, ActionLogEntry, ActionLogFilter, CategoryCount, CovenantScriptLabel, DeadcatStore,
+    IssuanceData, LmsrPoolFilter, LmsrPoolInfo, MIGRATIONS, MakerOrderInfo, MarketCandidateFilter,
+    MarketCandidateInfo, MarketFilter, MarketInfo, MarketMetrics, OrderFilter, OrderStatus,
+    PaymentSwapRecord, PriceAlert, PriceAlertDirection, PruneReport, SortBy, StoreCounts,
 };
 pub use sync::{ChainSource, ChainUtxo, MarketStateChange, OrderStatusChange, SyncReport};
 
@@ -0,0 +1,14 @@
+use diesel::prelude::*;
+
+use crate::schema::action_log;
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = action_log)]
+pub struct ActionLogRow {
+    pub id: i32,
+    pub kind: String,
+    pub market_id: Option<String>,
+    pub txid: Option<String>,
+    pub details_json: String,
+    pub created_at: String,
+}
@@ -0,0 +1,16 @@
+use diesel::prelude::*;
+
+use crate::schema::broadcast_intents;
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = broadcast_intents)]
+#[allow(dead_code)]
+pub struct BroadcastIntentRow {
+    pub txid: String,
+    pub kind: String,
+    pub market_id: Option<String>,
+    pub raw_tx: Vec<u8>,
+    pub status: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
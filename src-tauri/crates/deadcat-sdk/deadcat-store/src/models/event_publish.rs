@@ -0,0 +1,17 @@
+use diesel::prelude::*;
+
+use crate::schema::event_publishes;
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = event_publishes)]
+#[allow(dead_code)]
+pub struct EventPublishRow {
+    pub event_id: String,
+    pub event_json: String,
+    pub accepted_relays: String,
+    pub attempts: i32,
+    pub status: String,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
@@ -0,0 +1,15 @@
+use diesel::prelude::*;
+
+use crate::schema::market_attestations;
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = market_attestations)]
+#[allow(dead_code)]
+pub struct MarketAttestationRow {
+    pub market_id: String,
+    pub outcome_yes: i32,
+    pub signature_hex: String,
+    pub nostr_event_id: String,
+    pub signed_at: i64,
+    pub created_at: String,
+}
@@ -1,9 +1,21 @@
+pub mod action_log;
+pub mod broadcast_intent;
 pub mod candidate;
+pub mod event_publish;
 pub mod maker_order;
 pub mod market;
+pub mod market_attestation;
+pub mod payment_swap;
+pub mod price_alert;
 pub mod utxo;
 
+pub use action_log::ActionLogRow;
+pub use broadcast_intent::BroadcastIntentRow;
 pub use candidate::{MarketCandidateRow, NewMarketCandidateRow};
+pub use event_publish::EventPublishRow;
 pub use maker_order::{MakerOrderRow, NewMakerOrderRow};
 pub use market::MarketRow;
+pub use market_attestation::MarketAttestationRow;
+pub use payment_swap::PaymentSwapRow;
+pub use price_alert::PriceAlertRow;
 pub use utxo::{NewUtxoRow, UtxoRow};
@@ -0,0 +1,27 @@
+use diesel::prelude::*;
+
+use crate::schema::payment_swaps;
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = payment_swaps)]
+pub struct PaymentSwapRow {
+    pub id: String,
+    pub flow: String,
+    pub network: String,
+    pub status: String,
+    pub invoice_amount_sat: i64,
+    pub expected_amount_sat: Option<i64>,
+    pub lockup_address: Option<String>,
+    pub timeout_block_height: Option<i64>,
+    pub pair_hash: Option<String>,
+    pub invoice: Option<String>,
+    pub invoice_expiry_seconds: Option<i64>,
+    pub invoice_expires_at: Option<String>,
+    pub lockup_txid: Option<String>,
+    pub claim_txid: Option<String>,
+    pub preimage_hash: Option<String>,
+    pub preimage: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub claim_details: Option<String>,
+}
@@ -0,0 +1,14 @@
+use diesel::prelude::*;
+
+use crate::schema::price_alerts;
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = price_alerts)]
+pub struct PriceAlertRow {
+    pub id: i32,
+    pub pool_id: String,
+    pub direction: String,
+    pub threshold_bps: i32,
+    pub triggered: i32,
+    pub created_at: String,
+}
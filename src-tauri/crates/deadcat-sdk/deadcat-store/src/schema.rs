@@ -1,5 +1,28 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    action_log (id) {
+        id -> Integer,
+        kind -> Text,
+        market_id -> Nullable<Text>,
+        txid -> Nullable<Text>,
+        details_json -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    broadcast_intents (txid) {
+        txid -> Text,
+        kind -> Text,
+        market_id -> Nullable<Text>,
+        raw_tx -> Binary,
+        status -> Text,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     maker_orders (id) {
         id -> Integer,
@@ -28,6 +51,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    event_publishes (event_id) {
+        event_id -> Text,
+        event_json -> Text,
+        accepted_relays -> Text,
+        attempts -> Integer,
+        status -> Text,
+        last_error -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     lmsr_pools (pool_id) {
         pool_id -> Text,
@@ -52,6 +88,21 @@ diesel::table! {
         nostr_event_json -> Nullable<Text>,
         created_at -> Text,
         updated_at -> Text,
+        creator_pubkey -> Nullable<Binary>,
+        verification_status -> Nullable<Text>,
+        verification_reason -> Nullable<Text>,
+        verified_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    market_attestations (market_id) {
+        market_id -> Text,
+        outcome_yes -> Integer,
+        signature_hex -> Text,
+        nostr_event_id -> Text,
+        signed_at -> BigInt,
+        created_at -> Text,
     }
 }
 
@@ -118,6 +169,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    payment_swaps (id) {
+        id -> Text,
+        flow -> Text,
+        network -> Text,
+        status -> Text,
+        invoice_amount_sat -> BigInt,
+        expected_amount_sat -> Nullable<BigInt>,
+        lockup_address -> Nullable<Text>,
+        timeout_block_height -> Nullable<BigInt>,
+        pair_hash -> Nullable<Text>,
+        invoice -> Nullable<Text>,
+        invoice_expiry_seconds -> Nullable<BigInt>,
+        invoice_expires_at -> Nullable<Text>,
+        lockup_txid -> Nullable<Text>,
+        claim_txid -> Nullable<Text>,
+        preimage_hash -> Nullable<Text>,
+        preimage -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+        claim_details -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    price_alerts (id) {
+        id -> Integer,
+        pool_id -> Text,
+        direction -> Text,
+        threshold_bps -> Integer,
+        triggered -> Integer,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     sync_state (id) {
         id -> Integer,
@@ -127,6 +213,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    transactions (txid) {
+        txid -> Binary,
+        raw_tx -> Binary,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     utxos (txid, vout) {
         txid -> Binary,
@@ -152,10 +246,17 @@ diesel::joinable!(utxos -> maker_orders (maker_order_id));
 diesel::joinable!(utxos -> markets (market_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    action_log,
+    broadcast_intents,
+    event_publishes,
     lmsr_pools,
     maker_orders,
+    market_attestations,
     market_candidates,
     markets,
+    payment_swaps,
+    price_alerts,
     sync_state,
+    transactions,
     utxos,
 );
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
 use chrono::{TimeZone, Utc};
 use diesel::prelude::*;
 use diesel::sql_types::Integer;
@@ -5,10 +8,11 @@ use diesel::sqlite::SqliteConnection;
 use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
 
 use deadcat_sdk::{
-    CompiledMakerOrder, CompiledPredictionMarket, LmsrPoolIngestInput, LmsrPoolSyncInfo,
-    LmsrPoolSyncRepairInput, LmsrPriceHistoryEntry, LmsrPriceTransitionInput, MakerOrderParams,
-    MarketId, MarketSlot, MarketState, OrderDirection, PredictionMarketAnchor,
-    PredictionMarketCandidateIngestInput, PredictionMarketParams, UnblindedUtxo,
+    BroadcastIntent, CompiledMakerOrder, CompiledPredictionMarket, IntentStatus,
+    LmsrPoolIngestInput, LmsrPoolSyncInfo, LmsrPoolSyncRepairInput, LmsrPriceHistoryEntry,
+    LmsrPriceTransitionInput, MakerOrderParams, MarketId, MarketSlot, MarketState, OrderDirection,
+    PredictionMarketAnchor, PredictionMarketCandidateIngestInput, PredictionMarketParams,
+    PublishRecord, PublishStatus, UnblindedUtxo, VerifiedAttestation,
     parse_prediction_market_anchor,
     prediction_market_scan::{
         CanonicalMarketScan, PredictionMarketScanBackend, scan_prediction_market_canonical,
@@ -17,12 +21,19 @@ use deadcat_sdk::{
 };
 
 use crate::conversions::{
-    DecodedDormantOpenings, direction_to_i32, new_maker_order_row, new_market_candidate_row,
-    new_utxo_row, vec_to_array32,
+    DecodedDormantOpenings, action_log_entry_from_row, broadcast_intent_from_row, direction_to_i32,
+    market_attestation_from_row, new_maker_order_row, new_market_candidate_row, new_utxo_row,
+    payment_swap_from_row, price_alert_from_row, publish_record_from_row, vec_to_array32,
 };
 use crate::error::StoreError;
-use crate::models::{MakerOrderRow, MarketCandidateRow, MarketRow, NewUtxoRow, UtxoRow};
-use crate::schema::{maker_orders, market_candidates, markets, sync_state, utxos};
+use crate::models::{
+    ActionLogRow, BroadcastIntentRow, EventPublishRow, MakerOrderRow, MarketAttestationRow,
+    MarketCandidateRow, MarketRow, NewUtxoRow, PaymentSwapRow, PriceAlertRow, UtxoRow,
+};
+use crate::schema::{
+    action_log, broadcast_intents, event_publishes, lmsr_pools, maker_orders, market_attestations,
+    market_candidates, markets, payment_swaps, price_alerts, sync_state, transactions, utxos,
+};
 use crate::sync::{ChainSource, ChainUtxo, MarketStateChange, OrderStatusChange, SyncReport};
 
 use deadcat_sdk::elements::Txid;
@@ -36,6 +47,17 @@ pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 const DATETIME_NOW: &str = "datetime('now')";
 const CANDIDATE_TTL_SECS: u64 = 6 * 60 * 60;
 
+/// Columns of an [`LmsrPoolRow`], for the raw LMSR pool queries.
+const LMSR_POOL_SELECT: &str = "SELECT pool_id, market_id, creation_txid, witness_schema_version,
+        current_s_index, reserve_yes, reserve_no, reserve_collateral,
+        initial_reserve_yes_outpoint, initial_reserve_no_outpoint,
+        initial_reserve_collateral_outpoint,
+        reserve_yes_outpoint, reserve_no_outpoint, reserve_collateral_outpoint,
+        state_source, last_transition_txid, params_json, lmsr_table_values_json,
+        nostr_event_id, nostr_event_json, created_at, updated_at,
+        creator_pubkey, verification_status, verification_reason, verified_at
+     FROM lmsr_pools";
+
 fn sqlite_datetime_from_unix(now_unix: u64) -> crate::Result<String> {
     let ts = i64::try_from(now_unix)
         .map_err(|_| StoreError::InvalidData(format!("timestamp out of range: {now_unix}")))?;
@@ -58,6 +80,18 @@ pub enum OrderStatus {
 }
 
 impl OrderStatus {
+    /// Orders that can still be filled or cancelled: placed but not yet
+    /// confirmed, or resting on chain.
+    pub const OPEN: [OrderStatus; 3] = [
+        OrderStatus::Pending,
+        OrderStatus::Active,
+        OrderStatus::PartiallyFilled,
+    ];
+
+    pub fn is_open(self) -> bool {
+        Self::OPEN.contains(&self)
+    }
+
     pub fn from_i32(v: i32) -> std::result::Result<Self, StoreError> {
         match v {
             0 => Ok(OrderStatus::Pending),
@@ -77,7 +111,8 @@ impl OrderStatus {
 }
 
 /// Issuance data needed by InitialIssuanceParams / SubsequentIssuanceParams.
-/// Stored as market metadata; auto-extracted from chain during sync.
+/// Stored as market metadata; auto-extracted from chain during sync, or
+/// rebuilt with `DeadcatStore::recover_issuance_data` when sync missed it.
 #[derive(Debug, Clone, Copy)]
 pub struct IssuanceData {
     pub yes_entropy: [u8; 32],
@@ -159,9 +194,60 @@ pub struct MarketFilter {
     pub current_state: Option<MarketState>,
     pub expiry_before: Option<u32>,
     pub expiry_after: Option<u32>,
+    /// Matched case-insensitively, ignoring surrounding whitespace.
+    pub category: Option<String>,
+    /// Result order; `None` keeps storage order.
+    pub sort_by: Option<SortBy>,
+    pub descending: bool,
     pub limit: Option<i64>,
 }
 
+/// Aggregate activity for one market, from [`DeadcatStore::market_metrics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketMetrics {
+    /// Collateral held in the market's unspent covenant outputs.
+    pub total_collateral_locked: u64,
+    /// Collateral reserves across the market's LMSR pools.
+    pub pool_tvl: u64,
+    /// Open maker orders; see [`OrderStatus::OPEN`].
+    pub open_order_count: usize,
+    /// Recorded LMSR price transitions.
+    pub snapshot_count: usize,
+    /// Most recent update to the market, its pools' price history or its
+    /// orders, as a SQLite datetime.
+    pub last_activity: String,
+}
+
+/// Number of rows the store holds per record kind, from
+/// [`DeadcatStore::record_counts`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StoreCounts {
+    pub markets: usize,
+    pub maker_orders: usize,
+    pub lmsr_pools: usize,
+}
+
+/// Sort key for [`MarketFilter`] and [`OrderFilter`].
+///
+/// For markets, `Volume` counts recorded LMSR pool transitions and `Price` is
+/// the latest implied YES price, with pool-less markets sorting lowest. For
+/// orders, `Volume` counts filled (spent) order outputs and `Expiry` is the
+/// expiry of the order's market.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    CreatedAt,
+    Expiry,
+    Volume,
+    Price,
+}
+
+/// A distinct market category and how many canonical markets use it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryCount {
+    pub category: String,
+    pub market_count: usize,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct MarketCandidateFilter {
     pub market_id: Option<MarketId>,
@@ -181,9 +267,176 @@ pub struct OrderFilter {
     pub maker_base_pubkey: Option<[u8; 32]>,
     pub min_price: Option<u64>,
     pub max_price: Option<u64>,
+    /// Result order; `None` keeps storage order.
+    pub sort_by: Option<SortBy>,
+    pub descending: bool,
+    pub limit: Option<i64>,
+}
+
+/// Contract locked by a covenant scriptPubKey the store knows about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CovenantScriptLabel {
+    Market {
+        market_id: MarketId,
+        slot: MarketSlot,
+    },
+    MakerOrder {
+        order_id: i32,
+    },
+}
+
+// --- Action log types ---
+
+/// Kind of user action recorded in the action log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionKind {
+    CreateMarket,
+    IssueTokens,
+    CancelTokens,
+    ResolveMarket,
+    RedeemTokens,
+    RedeemExpired,
+    Swap,
+    CreateLimitOrder,
+    CancelLimitOrder,
+    TopUpLimitOrder,
+    CreateLmsrPool,
+}
+
+impl ActionKind {
+    pub const ALL: [ActionKind; 11] = [
+        ActionKind::CreateMarket,
+        ActionKind::IssueTokens,
+        ActionKind::CancelTokens,
+        ActionKind::ResolveMarket,
+        ActionKind::RedeemTokens,
+        ActionKind::RedeemExpired,
+        ActionKind::Swap,
+        ActionKind::CreateLimitOrder,
+        ActionKind::CancelLimitOrder,
+        ActionKind::TopUpLimitOrder,
+        ActionKind::CreateLmsrPool,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ActionKind::CreateMarket => "create_market",
+            ActionKind::IssueTokens => "issue_tokens",
+            ActionKind::CancelTokens => "cancel_tokens",
+            ActionKind::ResolveMarket => "resolve_market",
+            ActionKind::RedeemTokens => "redeem_tokens",
+            ActionKind::RedeemExpired => "redeem_expired",
+            ActionKind::Swap => "swap",
+            ActionKind::CreateLimitOrder => "create_limit_order",
+            ActionKind::CancelLimitOrder => "cancel_limit_order",
+            ActionKind::TopUpLimitOrder => "top_up_limit_order",
+            ActionKind::CreateLmsrPool => "create_lmsr_pool",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        ActionKind::ALL.into_iter().find(|kind| kind.as_str() == s)
+    }
+}
+
+/// A successful user action, with the transaction it broadcast.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionLogEntry {
+    pub id: i32,
+    pub kind: ActionKind,
+    /// Hex market id, for actions tied to a market.
+    pub market_id: Option<String>,
+    pub txid: Option<String>,
+    /// Operation-specific fields (amounts, outcome, order ids) as JSON.
+    pub details_json: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ActionLogFilter {
+    pub kind: Option<ActionKind>,
+    pub market_id: Option<String>,
     pub limit: Option<i64>,
 }
 
+// --- Price alert types ---
+
+/// Side of the threshold a [`PriceAlert`] fires on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceAlertDirection {
+    /// Fires when the YES price rises to or above the threshold.
+    Above,
+    /// Fires when the YES price falls to or below the threshold.
+    Below,
+}
+
+impl PriceAlertDirection {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PriceAlertDirection::Above => "above",
+            PriceAlertDirection::Below => "below",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "above" => Some(PriceAlertDirection::Above),
+            "below" => Some(PriceAlertDirection::Below),
+            _ => None,
+        }
+    }
+
+    /// Whether `price_bps` is on the firing side of `threshold_bps`.
+    pub fn is_met(self, price_bps: u16, threshold_bps: u16) -> bool {
+        match self {
+            PriceAlertDirection::Above => price_bps >= threshold_bps,
+            PriceAlertDirection::Below => price_bps <= threshold_bps,
+        }
+    }
+}
+
+/// A user-defined alert on an LMSR pool's YES spot price.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceAlert {
+    pub id: i32,
+    pub pool_id: String,
+    pub direction: PriceAlertDirection,
+    pub threshold_bps: u16,
+    /// Set once the alert has fired; cleared when the price moves back
+    /// across the threshold, re-arming it for the next crossing.
+    pub triggered: bool,
+    pub created_at: String,
+}
+
+// --- Payment swap types ---
+
+/// A Boltz Lightning or chain swap started by the wallet. Timestamps are
+/// RFC 3339 strings set by the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentSwapRecord {
+    pub id: String,
+    pub flow: String,
+    pub network: String,
+    pub status: String,
+    pub invoice_amount_sat: u64,
+    pub expected_amount_sat: Option<u64>,
+    pub lockup_address: Option<String>,
+    pub timeout_block_height: Option<u64>,
+    pub pair_hash: Option<String>,
+    pub invoice: Option<String>,
+    pub invoice_expiry_seconds: Option<u64>,
+    pub invoice_expires_at: Option<String>,
+    pub lockup_txid: Option<String>,
+    pub claim_txid: Option<String>,
+    pub preimage_hash: Option<String>,
+    pub preimage: Option<String>,
+    /// Boltz's JSON description of the lockup the wallet claims (chain
+    /// swaps only).
+    pub claim_details: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
 // --- LMSR Pool types ---
 
 #[derive(Debug, Clone, Default)]
@@ -216,6 +469,14 @@ pub struct LmsrPoolInfo {
     pub nostr_event_json: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    /// Set only for pools created by this wallet.
+    pub creator_pubkey: Option<Vec<u8>>,
+    /// Latest verdict of checking the announcement against the chain:
+    /// "verified", "stale" or "invalid". `None` until the pool is verified.
+    pub verification_status: Option<String>,
+    /// Why the pool was found invalid.
+    pub verification_reason: Option<String>,
+    pub verified_at: Option<String>,
 }
 
 #[derive(Debug, Clone, QueryableByName)]
@@ -264,6 +525,14 @@ struct LmsrPoolRow {
     created_at: String,
     #[diesel(sql_type = diesel::sql_types::Text)]
     updated_at: String,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Binary>)]
+    creator_pubkey: Option<Vec<u8>>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    verification_status: Option<String>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    verification_reason: Option<String>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    verified_at: Option<String>,
 }
 
 impl From<LmsrPoolRow> for LmsrPoolInfo {
@@ -306,6 +575,10 @@ impl From<LmsrPoolRow> for LmsrPoolInfo {
             nostr_event_json: r.nostr_event_json,
             created_at: r.created_at,
             updated_at: r.updated_at,
+            creator_pubkey: r.creator_pubkey,
+            verification_status: r.verification_status,
+            verification_reason: r.verification_reason,
+            verified_at: r.verified_at,
         }
     }
 }
@@ -356,6 +629,131 @@ impl From<PriceHistoryRow> for LmsrPriceHistoryEntry {
     }
 }
 
+#[derive(Debug, QueryableByName)]
+struct CategoryCountRow {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    category: String,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    market_count: i64,
+}
+
+#[derive(Debug, QueryableByName)]
+struct PoolActivityRow {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pool_tvl: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    snapshot_count: i64,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    last_recorded_at: Option<String>,
+}
+
+#[derive(Debug, QueryableByName)]
+struct MarketIdRow {
+    #[diesel(sql_type = diesel::sql_types::Binary)]
+    market_id: Vec<u8>,
+}
+
+#[derive(Debug, QueryableByName)]
+struct PriceSnapshotKeyRow {
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    id: i32,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pool_id: String,
+}
+
+#[derive(Debug, QueryableByName)]
+struct TableColumnRow {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    name: String,
+}
+
+#[derive(Debug, QueryableByName)]
+struct IntegrityCheckRow {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    integrity_check: String,
+}
+
+/// Grouping key for a category, matching SQLite's `LOWER(TRIM(..))`, which
+/// only folds ASCII.
+fn normalize_category(category: &str) -> String {
+    category.trim().to_ascii_lowercase()
+}
+
+/// Escape `%`, `_` and the escape character itself for a `LIKE ... ESCAPE '\'`
+/// pattern.
+fn escape_like(term: &str) -> String {
+    let mut escaped = String::with_capacity(term.len());
+    for ch in term.chars() {
+        if matches!(ch, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Rows removed by [`DeadcatStore::prune`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    pub spent_utxos: usize,
+    pub price_snapshots: usize,
+}
+
+/// Ids to delete so that `ids` (one pool's snapshots, oldest first) keeps
+/// `keep` evenly spaced rows. The first and last rows are always kept.
+fn thinned_snapshot_ids(ids: &[i32], keep: usize) -> Vec<i32> {
+    let keep = keep.max(2);
+    if ids.len() <= keep {
+        return Vec::new();
+    }
+    let last = ids.len() - 1;
+    let kept: std::collections::HashSet<usize> = (0..keep).map(|i| i * last / (keep - 1)).collect();
+    ids.iter()
+        .enumerate()
+        .filter(|(index, _)| !kept.contains(index))
+        .map(|(_, id)| *id)
+        .collect()
+}
+
+// --- Opening and migrating ---
+
+fn connect(path: &str) -> crate::Result<SqliteConnection> {
+    let mut conn = SqliteConnection::establish(path)?;
+    diesel::sql_query("PRAGMA foreign_keys = ON").execute(&mut conn)?;
+    Ok(conn)
+}
+
+fn has_pending_migrations(conn: &mut SqliteConnection) -> crate::Result<bool> {
+    conn.has_pending_migration(MIGRATIONS)
+        .map_err(|e| StoreError::Migration(e.to_string()))
+}
+
+fn run_migrations(conn: &mut SqliteConnection) -> crate::Result<()> {
+    conn.run_pending_migrations(MIGRATIONS)
+        .map_err(|e| StoreError::Migration(e.to_string()))?;
+    Ok(())
+}
+
+fn migration_backup_path(path: &str) -> PathBuf {
+    PathBuf::from(format!("{path}.pre-migration"))
+}
+
+/// Put the pre-migration copy back in place of a half-migrated database.
+///
+/// Journal sidecars belong to the failed attempt and are removed so SQLite
+/// does not replay them over the restored file.
+fn restore_migration_backup(path: &str, backup: &Path) -> crate::Result<()> {
+    for suffix in ["-journal", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{path}{suffix}"));
+    }
+    std::fs::rename(backup, path).map_err(|e| {
+        StoreError::Migration(format!(
+            "migration failed and restoring the backup at {} also failed: {e}",
+            backup.display()
+        ))
+    })
+}
+
 // --- DeadcatStore ---
 
 /// Persistent storage for deadcat prediction markets, maker orders, and UTXOs.
@@ -364,25 +762,72 @@ impl From<PriceHistoryRow> for LmsrPriceHistoryEntry {
 /// `&mut` for all operations, including reads.
 pub struct DeadcatStore {
     conn: SqliteConnection,
+    min_confirmations: u32,
 }
 
 impl DeadcatStore {
     /// Open (or create) a store at the given file path. Runs migrations automatically.
+    ///
+    /// When an existing database has pending migrations, the file is copied
+    /// aside first. If migrating or the post-migration integrity check fails,
+    /// the copy is restored and [`StoreError::MigrationRolledBack`] is
+    /// returned, leaving the database exactly as it was before the call.
     pub fn open(path: &str) -> crate::Result<Self> {
-        let mut conn = SqliteConnection::establish(path)?;
-        diesel::sql_query("PRAGMA foreign_keys = ON").execute(&mut conn)?;
-        conn.run_pending_migrations(MIGRATIONS)
-            .map_err(|e| StoreError::Migration(e.to_string()))?;
-        Ok(DeadcatStore { conn })
+        let existed = path != ":memory:" && Path::new(path).exists();
+        let mut conn = connect(path)?;
+        if !existed || !has_pending_migrations(&mut conn)? {
+            run_migrations(&mut conn)?;
+            return Ok(DeadcatStore {
+                conn,
+                min_confirmations: 0,
+            });
+        }
+        // Close the connection so the file on disk is complete before copying.
+        drop(conn);
+
+        let backup = migration_backup_path(path);
+        std::fs::copy(path, &backup).map_err(|e| {
+            StoreError::Migration(format!("failed to back up database before migrating: {e}"))
+        })?;
+
+        let migrated = connect(path).and_then(|conn| {
+            let mut store = DeadcatStore {
+                conn,
+                min_confirmations: 0,
+            };
+            run_migrations(&mut store.conn)?;
+            let problems = store.integrity_errors()?;
+            if !problems.is_empty() {
+                return Err(StoreError::Migration(format!(
+                    "integrity check failed after migrating: {}",
+                    problems.join("; ")
+                )));
+            }
+            Ok(store)
+        });
+
+        match migrated {
+            Ok(store) => {
+                let _ = std::fs::remove_file(&backup);
+                Ok(store)
+            }
+            Err(e) => {
+                restore_migration_backup(path, &backup)?;
+                Err(StoreError::MigrationRolledBack {
+                    reason: e.to_string(),
+                })
+            }
+        }
     }
 
     /// Open an in-memory store for tests.
     pub fn open_in_memory() -> crate::Result<Self> {
-        let mut conn = SqliteConnection::establish(":memory:")?;
-        diesel::sql_query("PRAGMA foreign_keys = ON").execute(&mut conn)?;
-        conn.run_pending_migrations(MIGRATIONS)
-            .map_err(|e| StoreError::Migration(e.to_string()))?;
-        Ok(DeadcatStore { conn })
+        let mut conn = connect(":memory:")?;
+        run_migrations(&mut conn)?;
+        Ok(DeadcatStore {
+            conn,
+            min_confirmations: 0,
+        })
     }
 
     // ==================== Ingest ====================
@@ -791,16 +1236,7 @@ impl DeadcatStore {
     pub fn list_lmsr_pools(&mut self, filter: &LmsrPoolFilter) -> crate::Result<Vec<LmsrPoolInfo>> {
         use diesel::sql_types::{BigInt, Text};
 
-        let mut query = String::from(
-            "SELECT pool_id, market_id, creation_txid, witness_schema_version,
-                    current_s_index, reserve_yes, reserve_no, reserve_collateral,
-                    initial_reserve_yes_outpoint, initial_reserve_no_outpoint,
-                    initial_reserve_collateral_outpoint,
-                    reserve_yes_outpoint, reserve_no_outpoint, reserve_collateral_outpoint,
-                    state_source, last_transition_txid, params_json, lmsr_table_values_json,
-                    nostr_event_id, nostr_event_json, created_at, updated_at
-             FROM lmsr_pools WHERE 1=1",
-        );
+        let mut query = format!("{LMSR_POOL_SELECT} WHERE 1=1");
         let mut bind_market: Option<String> = None;
         let mut bind_pool: Option<String> = None;
         let mut bind_limit: Option<i64> = None;
@@ -1077,12 +1513,81 @@ impl DeadcatStore {
         )?))
     }
 
+    /// Look up a market by the `nevent` of an announcement seen for it.
+    pub fn get_market_by_nevent(&mut self, nevent: &str) -> crate::Result<Option<MarketInfo>> {
+        let market_id: Option<Vec<u8>> = market_candidates::table
+            .filter(market_candidates::nevent.eq(nevent))
+            .select(market_candidates::market_id)
+            .first(&mut self.conn)
+            .optional()?;
+        self.get_market_by_id_bytes(market_id)
+    }
+
+    /// Look up a market by the Nostr event id of an announcement seen for it.
+    ///
+    /// Prefer this over [`get_market_by_nevent`](Self::get_market_by_nevent)
+    /// when the id is known: the same event has many `nevent` encodings,
+    /// one per set of relay hints.
+    pub fn get_market_by_event_id(&mut self, event_id: &str) -> crate::Result<Option<MarketInfo>> {
+        let market_id: Option<Vec<u8>> = market_candidates::table
+            .filter(market_candidates::nostr_event_id.eq(event_id))
+            .select(market_candidates::market_id)
+            .first(&mut self.conn)
+            .optional()?;
+        self.get_market_by_id_bytes(market_id)
+    }
+
+    fn get_market_by_id_bytes(
+        &mut self,
+        market_id: Option<Vec<u8>>,
+    ) -> crate::Result<Option<MarketInfo>> {
+        let Some(market_id) = market_id else {
+            return Ok(None);
+        };
+        let mid = MarketId(vec_to_array32(&market_id, "market_id")?);
+        self.get_market(&mid)
+    }
+
     pub fn list_markets(&mut self, filter: &MarketFilter) -> crate::Result<Vec<MarketInfo>> {
         let mut query = markets::table.into_boxed();
 
         if let Some(state) = filter.current_state {
             query = query.filter(markets::current_state.eq(state.as_u64() as i32));
         }
+        if let Some(ref category) = filter.category {
+            query = query.filter(
+                diesel::dsl::sql::<diesel::sql_types::Bool>(
+                    "candidate_id IN (SELECT candidate_id FROM market_candidates
+                     WHERE LOWER(TRIM(category)) = ",
+                )
+                .bind::<diesel::sql_types::Text, _>(normalize_category(category))
+                .sql(")"),
+            );
+        }
+        if let Some(sort_by) = filter.sort_by {
+            let key = diesel::dsl::sql::<diesel::sql_types::BigInt>(match sort_by {
+                SortBy::CreatedAt => "markets.created_at",
+                SortBy::Expiry => {
+                    "(SELECT expiry_time FROM market_candidates
+                      WHERE candidate_id = markets.candidate_id)"
+                }
+                SortBy::Volume => {
+                    "(SELECT COUNT(*) FROM lmsr_price_history
+                      WHERE market_id = LOWER(HEX(markets.market_id)))"
+                }
+                SortBy::Price => {
+                    "COALESCE((SELECT implied_yes_price_bps FROM lmsr_price_history
+                               WHERE market_id = LOWER(HEX(markets.market_id))
+                               ORDER BY block_height DESC, id DESC LIMIT 1), -1)"
+                }
+            });
+            query = if filter.descending {
+                query.order(key.desc())
+            } else {
+                query.order(key.asc())
+            };
+            query = query.then_order_by(markets::market_id);
+        }
         if let Some(lim) = filter.limit {
             query = query.limit(lim);
         }
@@ -1118,6 +1623,99 @@ impl DeadcatStore {
         Ok(markets_info)
     }
 
+    /// Distinct categories of canonical markets, most used first.
+    ///
+    /// Categories are grouped case-insensitively after trimming whitespace;
+    /// each group is labelled with one of its trimmed spellings.
+    pub fn list_categories(&mut self) -> crate::Result<Vec<CategoryCount>> {
+        let rows = diesel::sql_query(
+            "SELECT MIN(TRIM(c.category)) AS category, COUNT(*) AS market_count
+             FROM markets m
+             JOIN market_candidates c ON c.candidate_id = m.candidate_id
+             WHERE TRIM(COALESCE(c.category, '')) <> ''
+             GROUP BY LOWER(TRIM(c.category))
+             ORDER BY market_count DESC, LOWER(TRIM(c.category))",
+        )
+        .load::<CategoryCountRow>(&mut self.conn)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| CategoryCount {
+                category: row.category,
+                market_count: row.market_count as usize,
+            })
+            .collect())
+    }
+
+    /// Search canonical markets by question, category and description.
+    ///
+    /// `query` is split on whitespace and every term must appear as a
+    /// substring of at least one of the three fields (case-insensitive for
+    /// ASCII, as with SQLite's `LIKE`).
+    ///
+    /// Matches rank by relevance, weighting question hits over category hits
+    /// over description hits, then by most recently created. This queries
+    /// the market rows directly, so newly ingested markets are searchable
+    /// without a separate index to maintain.
+    pub fn search_markets(&mut self, query: &str) -> crate::Result<Vec<MarketInfo>> {
+        use diesel::sql_types::Text;
+
+        let patterns: Vec<String> = query
+            .split_whitespace()
+            .map(|term| format!("%{}%", escape_like(term)))
+            .collect();
+        if patterns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let score = patterns
+            .iter()
+            .map(|_| {
+                "(CASE WHEN c.question LIKE ? ESCAPE '\\' THEN 3 ELSE 0 END
+                  + CASE WHEN c.category LIKE ? ESCAPE '\\' THEN 2 ELSE 0 END
+                  + CASE WHEN c.description LIKE ? ESCAPE '\\' THEN 1 ELSE 0 END)"
+            })
+            .collect::<Vec<_>>()
+            .join(" + ");
+        let matches_all = patterns
+            .iter()
+            .map(|_| {
+                "(c.question LIKE ? ESCAPE '\\'
+                  OR c.category LIKE ? ESCAPE '\\'
+                  OR c.description LIKE ? ESCAPE '\\')"
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let sql = format!(
+            "SELECT m.market_id FROM markets m
+             JOIN market_candidates c ON c.candidate_id = m.candidate_id
+             WHERE {matches_all}
+             ORDER BY {score} DESC, m.created_at DESC, m.market_id"
+        );
+
+        let mut search = diesel::sql_query(sql).into_boxed::<diesel::sqlite::Sqlite>();
+        // WHERE binds come before ORDER BY binds in statement order.
+        for _ in 0..2 {
+            for pattern in &patterns {
+                for _ in 0..3 {
+                    search = search.bind::<Text, _>(pattern.clone());
+                }
+            }
+        }
+        let rows: Vec<MarketIdRow> = search.load(&mut self.conn)?;
+
+        let mut markets_info = Vec::with_capacity(rows.len());
+        for row in rows {
+            let market: MarketRow = markets::table
+                .filter(markets::market_id.eq(&row.market_id))
+                .first(&mut self.conn)?;
+            let candidate = self.load_candidate(market.candidate_id)?;
+            markets_info.push(crate::conversions::market_info_from_rows(
+                &market, &candidate,
+            )?);
+        }
+        Ok(markets_info)
+    }
+
     /// Return a visible, unpromoted candidate if it has not yet hit its TTL.
     ///
     /// Callers pass `now_unix` explicitly so candidate visibility flips exactly
@@ -1315,6 +1913,27 @@ impl DeadcatStore {
         if let Some(max_p) = filter.max_price {
             query = query.filter(maker_orders::price.le(max_p as i64));
         }
+        if let Some(sort_by) = filter.sort_by {
+            let key = diesel::dsl::sql::<diesel::sql_types::BigInt>(match sort_by {
+                SortBy::CreatedAt => "maker_orders.created_at",
+                SortBy::Expiry => {
+                    "(SELECT c.expiry_time FROM markets m
+                      JOIN market_candidates c ON c.candidate_id = m.candidate_id
+                      WHERE LOWER(HEX(m.market_id)) = maker_orders.market_id)"
+                }
+                SortBy::Volume => {
+                    "(SELECT COUNT(*) FROM utxos
+                      WHERE utxos.maker_order_id = maker_orders.id AND utxos.spent = 1)"
+                }
+                SortBy::Price => "maker_orders.price",
+            });
+            query = if filter.descending {
+                query.order(key.desc())
+            } else {
+                query.order(key.asc())
+            };
+            query = query.then_order_by(maker_orders::id);
+        }
         if let Some(lim) = filter.limit {
             query = query.limit(lim);
         }
@@ -1430,6 +2049,520 @@ impl DeadcatStore {
         Ok(())
     }
 
+    // ==================== Metrics ====================
+
+    /// Liquidity and activity summary for a canonical market, or `None` if
+    /// the market is unknown.
+    pub fn market_metrics(&mut self, mid: &MarketId) -> crate::Result<Option<MarketMetrics>> {
+        use diesel::sql_types::Text;
+
+        let Some(market) = self.get_market(mid)? else {
+            return Ok(None);
+        };
+        let mid_hex = hex::encode(mid.as_bytes());
+
+        let collateral_values: Vec<i64> = utxos::table
+            .filter(utxos::market_id.eq(mid.as_bytes().to_vec()))
+            .filter(utxos::spent.eq(0))
+            .filter(utxos::asset_id.eq(market.params.collateral_asset_id.to_vec()))
+            .select(utxos::value)
+            .load(&mut self.conn)?;
+
+        let pools = diesel::sql_query(
+            "SELECT
+                (SELECT COALESCE(SUM(reserve_collateral), 0) FROM lmsr_pools
+                 WHERE market_id = ?) AS pool_tvl,
+                COUNT(*) AS snapshot_count,
+                MAX(recorded_at) AS last_recorded_at
+             FROM lmsr_price_history WHERE market_id = ?",
+        )
+        .bind::<Text, _>(&mid_hex)
+        .bind::<Text, _>(&mid_hex)
+        .get_result::<PoolActivityRow>(&mut self.conn)?;
+
+        let open_order_count: i64 = maker_orders::table
+            .filter(maker_orders::market_id.eq(&mid_hex))
+            .filter(maker_orders::order_status.eq_any(OrderStatus::OPEN.map(OrderStatus::as_i32)))
+            .count()
+            .get_result(&mut self.conn)?;
+        let last_order_update: Option<String> = maker_orders::table
+            .filter(maker_orders::market_id.eq(&mid_hex))
+            .select(diesel::dsl::max(maker_orders::updated_at))
+            .first(&mut self.conn)?;
+
+        let last_activity = [
+            Some(market.updated_at),
+            pools.last_recorded_at,
+            last_order_update,
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or_default();
+
+        Ok(Some(MarketMetrics {
+            total_collateral_locked: collateral_values.iter().map(|v| *v as u64).sum(),
+            pool_tvl: pools.pool_tvl as u64,
+            open_order_count: open_order_count as usize,
+            snapshot_count: pools.snapshot_count as usize,
+            last_activity,
+        }))
+    }
+
+    /// Count the known markets, maker orders and LMSR pools.
+    pub fn record_counts(&mut self) -> crate::Result<StoreCounts> {
+        let market_count: i64 = markets::table.count().get_result(&mut self.conn)?;
+        let order_count: i64 = maker_orders::table.count().get_result(&mut self.conn)?;
+        let pool_count: i64 = lmsr_pools::table.count().get_result(&mut self.conn)?;
+        Ok(StoreCounts {
+            markets: market_count as usize,
+            maker_orders: order_count as usize,
+            lmsr_pools: pool_count as usize,
+        })
+    }
+
+    // ==================== Pruning ====================
+
+    /// Delete history older than `before_height` that the store no longer
+    /// needs.
+    ///
+    /// - Spent UTXOs whose spend height (or, when that is unknown, creation
+    ///   height) is below the cutoff are deleted. Spent outputs of maker
+    ///   orders are kept unless the order is cancelled, because order status
+    ///   is derived from how many of its outputs have been spent.
+    /// - Each pool's price history below the cutoff is thinned to
+    ///   `keep_snapshots_per_pool` evenly spaced rows, always keeping the
+    ///   oldest and newest of them so charts keep their endpoints. Rows at or
+    ///   above the cutoff are untouched, so the latest snapshot always stays.
+    ///
+    /// Unspent UTXOs are never deleted. Everything runs in one transaction.
+    pub fn prune(
+        &mut self,
+        before_height: u32,
+        keep_snapshots_per_pool: usize,
+    ) -> crate::Result<PruneReport> {
+        use diesel::sql_types::{BigInt, Integer};
+
+        let cutoff = i64::from(before_height);
+        self.conn.transaction(|conn| {
+            let spent_utxos = diesel::sql_query(
+                "DELETE FROM utxos
+                 WHERE spent = 1
+                   AND COALESCE(spent_block_height, block_height) < ?
+                   AND (maker_order_id IS NULL
+                        OR maker_order_id IN
+                           (SELECT id FROM maker_orders WHERE order_status = ?))",
+            )
+            .bind::<BigInt, _>(cutoff)
+            .bind::<Integer, _>(OrderStatus::Cancelled.as_i32())
+            .execute(conn)?;
+
+            let rows = diesel::sql_query(
+                "SELECT id, pool_id FROM lmsr_price_history
+                 WHERE block_height < ?
+                 ORDER BY pool_id, block_height, id",
+            )
+            .bind::<BigInt, _>(cutoff)
+            .load::<PriceSnapshotKeyRow>(conn)?;
+
+            let mut doomed = Vec::new();
+            for pool_rows in rows.chunk_by(|a, b| a.pool_id == b.pool_id) {
+                let ids: Vec<i32> = pool_rows.iter().map(|row| row.id).collect();
+                doomed.extend(thinned_snapshot_ids(&ids, keep_snapshots_per_pool));
+            }
+            for id in &doomed {
+                diesel::sql_query("DELETE FROM lmsr_price_history WHERE id = ?")
+                    .bind::<Integer, _>(*id)
+                    .execute(conn)?;
+            }
+
+            Ok(PruneReport {
+                spent_utxos,
+                price_snapshots: doomed.len(),
+            })
+        })
+    }
+
+    // ==================== Transaction Cache ====================
+
+    /// Return the raw bytes of a cached transaction, if present.
+    pub fn get_cached_transaction(&mut self, txid: &[u8; 32]) -> crate::Result<Option<Vec<u8>>> {
+        let raw = transactions::table
+            .filter(transactions::txid.eq(txid.as_slice()))
+            .select(transactions::raw_tx)
+            .first::<Vec<u8>>(&mut self.conn)
+            .optional()?;
+        Ok(raw)
+    }
+
+    /// Cache a raw transaction. A txid that is already cached is left as-is.
+    pub fn cache_transaction(&mut self, txid: &[u8; 32], raw_tx: &[u8]) -> crate::Result<()> {
+        diesel::insert_or_ignore_into(transactions::table)
+            .values((
+                transactions::txid.eq(txid.as_slice()),
+                transactions::raw_tx.eq(raw_tx),
+            ))
+            .execute(&mut self.conn)?;
+        Ok(())
+    }
+
+    // ==================== Event Publishes ====================
+
+    /// Insert or update the publish record for an announcement.
+    pub fn save_publish_record(&mut self, record: &PublishRecord) -> crate::Result<()> {
+        let accepted_relays = serde_json::to_string(&record.accepted_relays)
+            .map_err(|e| StoreError::InvalidData(format!("accepted relays: {e}")))?;
+        let attempts = i32::try_from(record.attempts).map_err(|_| {
+            StoreError::InvalidData(format!("attempt count out of range: {}", record.attempts))
+        })?;
+
+        diesel::insert_into(event_publishes::table)
+            .values((
+                event_publishes::event_id.eq(&record.event_id),
+                event_publishes::event_json.eq(&record.event_json),
+                event_publishes::accepted_relays.eq(&accepted_relays),
+                event_publishes::attempts.eq(attempts),
+                event_publishes::status.eq(record.status.as_str()),
+                event_publishes::last_error.eq(record.last_error.as_deref()),
+            ))
+            .on_conflict(event_publishes::event_id)
+            .do_update()
+            .set((
+                event_publishes::accepted_relays.eq(&accepted_relays),
+                event_publishes::attempts.eq(attempts),
+                event_publishes::status.eq(record.status.as_str()),
+                event_publishes::last_error.eq(record.last_error.as_deref()),
+                event_publishes::updated_at
+                    .eq(diesel::dsl::sql::<diesel::sql_types::Text>(DATETIME_NOW)),
+            ))
+            .execute(&mut self.conn)?;
+        Ok(())
+    }
+
+    pub fn get_publish_record(&mut self, event_id: &str) -> crate::Result<Option<PublishRecord>> {
+        event_publishes::table
+            .filter(event_publishes::event_id.eq(event_id))
+            .first::<EventPublishRow>(&mut self.conn)
+            .optional()?
+            .map(publish_record_from_row)
+            .transpose()
+    }
+
+    /// Announcements still short of relay quorum, oldest first.
+    pub fn list_pending_publishes(&mut self) -> crate::Result<Vec<PublishRecord>> {
+        event_publishes::table
+            .filter(event_publishes::status.eq(PublishStatus::Pending.as_str()))
+            .order((
+                event_publishes::created_at.asc(),
+                event_publishes::event_id.asc(),
+            ))
+            .load::<EventPublishRow>(&mut self.conn)?
+            .into_iter()
+            .map(publish_record_from_row)
+            .collect()
+    }
+
+    // ==================== Broadcast Intents ====================
+
+    /// Record a signed covenant transaction before it is broadcast. Saving a
+    /// txid that is already journaled resets its status.
+    pub fn save_broadcast_intent(&mut self, intent: &BroadcastIntent) -> crate::Result<()> {
+        diesel::insert_into(broadcast_intents::table)
+            .values((
+                broadcast_intents::txid.eq(&intent.txid),
+                broadcast_intents::kind.eq(intent.kind.as_str()),
+                broadcast_intents::market_id.eq(intent.market_id.as_deref()),
+                broadcast_intents::raw_tx.eq(&intent.raw_tx),
+                broadcast_intents::status.eq(intent.status.as_str()),
+            ))
+            .on_conflict(broadcast_intents::txid)
+            .do_update()
+            .set((
+                broadcast_intents::status.eq(intent.status.as_str()),
+                broadcast_intents::updated_at
+                    .eq(diesel::dsl::sql::<diesel::sql_types::Text>(DATETIME_NOW)),
+            ))
+            .execute(&mut self.conn)?;
+        Ok(())
+    }
+
+    pub fn set_broadcast_intent_status(
+        &mut self,
+        txid: &str,
+        status: IntentStatus,
+    ) -> crate::Result<()> {
+        diesel::update(broadcast_intents::table.filter(broadcast_intents::txid.eq(txid)))
+            .set((
+                broadcast_intents::status.eq(status.as_str()),
+                broadcast_intents::updated_at
+                    .eq(diesel::dsl::sql::<diesel::sql_types::Text>(DATETIME_NOW)),
+            ))
+            .execute(&mut self.conn)?;
+        Ok(())
+    }
+
+    /// Broadcasts not yet confirmed as completed or abandoned, oldest first.
+    pub fn list_pending_broadcast_intents(&mut self) -> crate::Result<Vec<BroadcastIntent>> {
+        broadcast_intents::table
+            .filter(broadcast_intents::status.eq(IntentStatus::Pending.as_str()))
+            .order((
+                broadcast_intents::created_at.asc(),
+                broadcast_intents::txid.asc(),
+            ))
+            .load::<BroadcastIntentRow>(&mut self.conn)?
+            .into_iter()
+            .map(broadcast_intent_from_row)
+            .collect()
+    }
+
+    // ==================== Action Log ====================
+
+    /// Record an action that completed successfully.
+    pub fn log_action(
+        &mut self,
+        kind: ActionKind,
+        market_id: Option<&str>,
+        txid: Option<&str>,
+        details_json: &str,
+    ) -> crate::Result<ActionLogEntry> {
+        serde_json::from_str::<serde_json::Value>(details_json)
+            .map_err(|e| StoreError::InvalidData(format!("invalid action details: {e}")))?;
+
+        diesel::insert_into(action_log::table)
+            .values((
+                action_log::kind.eq(kind.as_str()),
+                action_log::market_id.eq(market_id),
+                action_log::txid.eq(txid),
+                action_log::details_json.eq(details_json),
+            ))
+            .execute(&mut self.conn)?;
+
+        let id: i32 = diesel::select(diesel::dsl::sql::<Integer>("last_insert_rowid()"))
+            .get_result(&mut self.conn)?;
+        action_log::table
+            .filter(action_log::id.eq(id))
+            .first::<ActionLogRow>(&mut self.conn)
+            .map_err(StoreError::from)
+            .and_then(action_log_entry_from_row)
+    }
+
+    /// Logged actions matching `filter`, newest first.
+    pub fn get_action_log(
+        &mut self,
+        filter: &ActionLogFilter,
+    ) -> crate::Result<Vec<ActionLogEntry>> {
+        let mut query = action_log::table.into_boxed();
+        if let Some(kind) = filter.kind {
+            query = query.filter(action_log::kind.eq(kind.as_str()));
+        }
+        if let Some(ref market_id) = filter.market_id {
+            query = query.filter(action_log::market_id.eq(market_id));
+        }
+        if let Some(limit) = filter.limit {
+            query = query.limit(limit);
+        }
+        query
+            .order(action_log::id.desc())
+            .load::<ActionLogRow>(&mut self.conn)?
+            .into_iter()
+            .map(action_log_entry_from_row)
+            .collect()
+    }
+
+    // ==================== Price Alerts ====================
+
+    /// Add an alert on `pool_id`'s YES spot price. `threshold_bps` is a
+    /// probability in basis points and must not exceed 10000.
+    pub fn create_price_alert(
+        &mut self,
+        pool_id: &str,
+        direction: PriceAlertDirection,
+        threshold_bps: u16,
+    ) -> crate::Result<PriceAlert> {
+        if threshold_bps > 10_000 {
+            return Err(StoreError::InvalidData(format!(
+                "alert threshold out of range: {threshold_bps} bps"
+            )));
+        }
+
+        diesel::insert_into(price_alerts::table)
+            .values((
+                price_alerts::pool_id.eq(pool_id),
+                price_alerts::direction.eq(direction.as_str()),
+                price_alerts::threshold_bps.eq(i32::from(threshold_bps)),
+            ))
+            .execute(&mut self.conn)?;
+
+        let id: i32 = diesel::select(diesel::dsl::sql::<Integer>("last_insert_rowid()"))
+            .get_result(&mut self.conn)?;
+        price_alerts::table
+            .filter(price_alerts::id.eq(id))
+            .first::<PriceAlertRow>(&mut self.conn)
+            .map_err(StoreError::from)
+            .and_then(price_alert_from_row)
+    }
+
+    /// Alerts, optionally restricted to one pool, oldest first.
+    pub fn list_price_alerts(&mut self, pool_id: Option<&str>) -> crate::Result<Vec<PriceAlert>> {
+        let mut query = price_alerts::table.into_boxed();
+        if let Some(pool_id) = pool_id {
+            query = query.filter(price_alerts::pool_id.eq(pool_id));
+        }
+        query
+            .order(price_alerts::id.asc())
+            .load::<PriceAlertRow>(&mut self.conn)?
+            .into_iter()
+            .map(price_alert_from_row)
+            .collect()
+    }
+
+    /// Returns whether an alert with `id` existed.
+    pub fn delete_price_alert(&mut self, id: i32) -> crate::Result<bool> {
+        let deleted = diesel::delete(price_alerts::table.filter(price_alerts::id.eq(id)))
+            .execute(&mut self.conn)?;
+        Ok(deleted > 0)
+    }
+
+    pub fn set_price_alert_triggered(&mut self, id: i32, triggered: bool) -> crate::Result<()> {
+        diesel::update(price_alerts::table.filter(price_alerts::id.eq(id)))
+            .set(price_alerts::triggered.eq(i32::from(triggered)))
+            .execute(&mut self.conn)?;
+        Ok(())
+    }
+
+    /// Record `price_bps` as the latest YES price seen for `alert`. Returns
+    /// `true` only when the price has just crossed onto the firing side; the
+    /// alert then stays quiet until the price moves back, which re-arms it.
+    pub fn observe_price_alert(
+        &mut self,
+        alert: &PriceAlert,
+        price_bps: u16,
+    ) -> crate::Result<bool> {
+        let met = alert.direction.is_met(price_bps, alert.threshold_bps);
+        if met == alert.triggered {
+            return Ok(false);
+        }
+        self.set_price_alert_triggered(alert.id, met)?;
+        Ok(met)
+    }
+
+    // ==================== Market Attestations ====================
+
+    /// Cache a verified oracle attestation. The first one saved for a market
+    /// is kept.
+    pub fn save_market_attestation(
+        &mut self,
+        attestation: &VerifiedAttestation,
+    ) -> crate::Result<()> {
+        let signed_at = i64::try_from(attestation.signed_at).map_err(|_| {
+            StoreError::InvalidData(format!(
+                "attestation time out of range: {}",
+                attestation.signed_at
+            ))
+        })?;
+        diesel::insert_or_ignore_into(market_attestations::table)
+            .values((
+                market_attestations::market_id.eq(&attestation.market_id),
+                market_attestations::outcome_yes.eq(i32::from(attestation.outcome_yes)),
+                market_attestations::signature_hex.eq(&attestation.signature_hex),
+                market_attestations::nostr_event_id.eq(&attestation.nostr_event_id),
+                market_attestations::signed_at.eq(signed_at),
+            ))
+            .execute(&mut self.conn)?;
+        Ok(())
+    }
+
+    pub fn get_market_attestation(
+        &mut self,
+        market_id: &MarketId,
+    ) -> crate::Result<Option<VerifiedAttestation>> {
+        market_attestations::table
+            .filter(market_attestations::market_id.eq(::hex::encode(market_id.as_bytes())))
+            .first::<MarketAttestationRow>(&mut self.conn)
+            .optional()?
+            .map(market_attestation_from_row)
+            .transpose()
+    }
+
+    // ==================== Payment Swaps ====================
+
+    /// Insert `swap`, or replace every field of the swap with the same id.
+    pub fn upsert_payment_swap(&mut self, swap: &PaymentSwapRecord) -> crate::Result<()> {
+        let sat = |value: u64, field: &str| {
+            i64::try_from(value)
+                .map_err(|_| StoreError::InvalidData(format!("{field} out of range: {value}")))
+        };
+        let invoice_amount_sat = sat(swap.invoice_amount_sat, "invoice amount")?;
+        let expected_amount_sat = swap
+            .expected_amount_sat
+            .map(|v| sat(v, "expected amount"))
+            .transpose()?;
+        let timeout_block_height = swap
+            .timeout_block_height
+            .map(|v| sat(v, "timeout height"))
+            .transpose()?;
+        let invoice_expiry_seconds = swap
+            .invoice_expiry_seconds
+            .map(|v| sat(v, "invoice expiry"))
+            .transpose()?;
+        let values = (
+            payment_swaps::flow.eq(&swap.flow),
+            payment_swaps::network.eq(&swap.network),
+            payment_swaps::status.eq(&swap.status),
+            payment_swaps::invoice_amount_sat.eq(invoice_amount_sat),
+            payment_swaps::expected_amount_sat.eq(expected_amount_sat),
+            payment_swaps::lockup_address.eq(swap.lockup_address.as_deref()),
+            payment_swaps::timeout_block_height.eq(timeout_block_height),
+            payment_swaps::pair_hash.eq(swap.pair_hash.as_deref()),
+            payment_swaps::invoice.eq(swap.invoice.as_deref()),
+            payment_swaps::invoice_expiry_seconds.eq(invoice_expiry_seconds),
+            payment_swaps::invoice_expires_at.eq(swap.invoice_expires_at.as_deref()),
+            payment_swaps::lockup_txid.eq(swap.lockup_txid.as_deref()),
+            payment_swaps::claim_txid.eq(swap.claim_txid.as_deref()),
+            payment_swaps::preimage_hash.eq(swap.preimage_hash.as_deref()),
+            payment_swaps::preimage.eq(swap.preimage.as_deref()),
+            payment_swaps::claim_details.eq(swap.claim_details.as_deref()),
+            payment_swaps::updated_at.eq(&swap.updated_at),
+        );
+        diesel::insert_into(payment_swaps::table)
+            .values((
+                payment_swaps::id.eq(&swap.id),
+                payment_swaps::created_at.eq(&swap.created_at),
+                values,
+            ))
+            .on_conflict(payment_swaps::id)
+            .do_update()
+            .set(values)
+            .execute(&mut self.conn)?;
+        Ok(())
+    }
+
+    pub fn get_payment_swap(&mut self, id: &str) -> crate::Result<Option<PaymentSwapRecord>> {
+        payment_swaps::table
+            .filter(payment_swaps::id.eq(id))
+            .first::<PaymentSwapRow>(&mut self.conn)
+            .optional()?
+            .map(payment_swap_from_row)
+            .transpose()
+    }
+
+    /// All payment swaps, oldest first.
+    pub fn list_payment_swaps(&mut self) -> crate::Result<Vec<PaymentSwapRecord>> {
+        payment_swaps::table
+            .order((payment_swaps::created_at.asc(), payment_swaps::id.asc()))
+            .load::<PaymentSwapRow>(&mut self.conn)?
+            .into_iter()
+            .map(payment_swap_from_row)
+            .collect()
+    }
+
+    /// Remove a swap; returns whether it existed.
+    pub fn delete_payment_swap(&mut self, id: &str) -> crate::Result<bool> {
+        let deleted = diesel::delete(payment_swaps::table.filter(payment_swaps::id.eq(id)))
+            .execute(&mut self.conn)?;
+        Ok(deleted > 0)
+    }
+
     // ==================== State Updates ====================
 
     pub fn update_market_state(
@@ -1489,6 +2622,126 @@ impl DeadcatStore {
         Ok(())
     }
 
+    /// Record the new size of one of our orders after a top-up, along with
+    /// the announcement that replaced its earlier one.
+    pub fn record_order_top_up(
+        &mut self,
+        cmr: &[u8],
+        maker_base_pubkey: &[u8; 32],
+        offered_amount: u64,
+        nostr_event_id: &str,
+    ) -> crate::Result<()> {
+        diesel::update(
+            maker_orders::table.filter(
+                maker_orders::cmr
+                    .eq(cmr)
+                    .and(maker_orders::maker_base_pubkey.eq(maker_base_pubkey.to_vec())),
+            ),
+        )
+        .set((
+            maker_orders::offered_amount.eq(offered_amount as i64),
+            maker_orders::nostr_event_id.eq(nostr_event_id),
+            maker_orders::updated_at.eq(diesel::dsl::sql::<diesel::sql_types::Text>(DATETIME_NOW)),
+        ))
+        .execute(&mut self.conn)?;
+
+        Ok(())
+    }
+
+    /// Record the verdict of checking an LMSR pool against the chain,
+    /// replacing any earlier one.
+    pub fn record_lmsr_pool_verification(
+        &mut self,
+        pool_id: &str,
+        status: &str,
+        reason: Option<&str>,
+    ) -> crate::Result<()> {
+        use diesel::sql_types::{Nullable, Text};
+
+        let rows = diesel::sql_query(
+            "UPDATE lmsr_pools
+             SET verification_status = ?, verification_reason = ?,
+                 verified_at = datetime('now')
+             WHERE pool_id = ?",
+        )
+        .bind::<Text, _>(status)
+        .bind::<Nullable<Text>, _>(reason)
+        .bind::<Text, _>(pool_id)
+        .execute(&mut self.conn)?;
+        if rows == 0 {
+            return Err(StoreError::InvalidData(format!(
+                "lmsr pool {pool_id} not found"
+            )));
+        }
+        Ok(())
+    }
+
+    // ==================== Wallet Ownership ====================
+
+    /// Mark an LMSR pool as created by the local wallet.
+    pub fn record_lmsr_pool_creator(
+        &mut self,
+        pool_id: &str,
+        creator_pubkey: &[u8],
+    ) -> crate::Result<()> {
+        use diesel::sql_types::{Binary, Text};
+
+        let rows = diesel::sql_query(
+            "UPDATE lmsr_pools
+             SET creator_pubkey = ?, updated_at = datetime('now')
+             WHERE pool_id = ?",
+        )
+        .bind::<Binary, _>(creator_pubkey)
+        .bind::<Text, _>(pool_id)
+        .execute(&mut self.conn)?;
+        if rows == 0 {
+            return Err(StoreError::InvalidData(format!(
+                "lmsr pool {pool_id} not found"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Markets announced by `creator_pubkey`.
+    pub fn list_my_markets(&mut self, creator_pubkey: &[u8]) -> crate::Result<Vec<MarketInfo>> {
+        let created = market_candidates::table
+            .filter(market_candidates::creator_pubkey.eq(creator_pubkey))
+            .select(market_candidates::candidate_id);
+        let rows: Vec<MarketRow> = markets::table
+            .filter(markets::candidate_id.eq_any(created))
+            .load(&mut self.conn)?;
+        rows.iter()
+            .map(|market| {
+                let candidate = self.load_candidate(market.candidate_id)?;
+                crate::conversions::market_info_from_rows(market, &candidate)
+            })
+            .collect()
+    }
+
+    /// Maker orders whose covenant is keyed to `maker_base_pubkey`.
+    pub fn list_my_maker_orders(
+        &mut self,
+        maker_base_pubkey: &[u8; 32],
+    ) -> crate::Result<Vec<MakerOrderInfo>> {
+        self.list_maker_orders(&OrderFilter {
+            maker_base_pubkey: Some(*maker_base_pubkey),
+            ..Default::default()
+        })
+    }
+
+    /// LMSR pools recorded as created by `creator_pubkey`.
+    pub fn list_my_lmsr_pools(
+        &mut self,
+        creator_pubkey: &[u8],
+    ) -> crate::Result<Vec<LmsrPoolInfo>> {
+        let rows: Vec<LmsrPoolRow> = diesel::sql_query(format!(
+            "{LMSR_POOL_SELECT} WHERE creator_pubkey = ? ORDER BY updated_at DESC"
+        ))
+        .bind::<diesel::sql_types::Binary, _>(creator_pubkey)
+        .load(&mut self.conn)?;
+        Ok(rows.into_iter().map(LmsrPoolInfo::from).collect())
+    }
+
     // ==================== Issuance Data ====================
 
     /// Manually set issuance entropy for a market (fallback if not yet synced from chain).
@@ -1515,6 +2768,67 @@ impl DeadcatStore {
         Ok(())
     }
 
+    /// Rebuild a market's issuance data from its creation transaction and
+    /// store it, for markets whose data sync never extracted.
+    ///
+    /// The blinding nonces are the dormant reissuance-token openings from the
+    /// market's anchor, which is what the first issuance spends. Fails if the
+    /// recomputed entropy does not derive the market's reissuance tokens.
+    pub fn recover_issuance_data<C: ChainSource>(
+        &mut self,
+        mid: &MarketId,
+        chain: &C,
+    ) -> crate::Result<IssuanceData> {
+        use deadcat_sdk::elements::hashes::sha256::Midstate;
+        use deadcat_sdk::elements::{AssetId, Transaction};
+
+        let market = self
+            .get_market(mid)?
+            .ok_or_else(|| StoreError::InvalidData(format!("market {mid} not found")))?;
+        let anchor =
+            parse_prediction_market_anchor(&market.anchor).map_err(StoreError::InvalidData)?;
+        let creation_txid = anchor.creation_txid;
+        let raw_tx = chain
+            .get_transaction(&creation_txid.to_byte_array())
+            .map_err(|e| StoreError::Sync(e.to_string()))?
+            .ok_or_else(|| {
+                StoreError::Sync(format!("creation transaction {creation_txid} not found"))
+            })?;
+        let creation_tx: Transaction = deadcat_sdk::elements::encode::deserialize(&raw_tx)
+            .map_err(|e| {
+                StoreError::InvalidData(format!(
+                    "failed to decode creation transaction {creation_txid}: {e}"
+                ))
+            })?;
+
+        let entropy = deadcat_sdk::compute_issuance_entropy(
+            &creation_tx,
+            &anchor.yes_dormant_opening.asset_blinding_factor,
+            &anchor.no_dormant_opening.asset_blinding_factor,
+        )?;
+        let reissuance_token = |entropy: [u8; 32]| {
+            AssetId::reissuance_token_from_entropy(Midstate::from_byte_array(entropy), false)
+                .into_inner()
+                .to_byte_array()
+        };
+        if reissuance_token(entropy.yes_entropy) != market.params.yes_reissuance_token
+            || reissuance_token(entropy.no_entropy) != market.params.no_reissuance_token
+        {
+            return Err(StoreError::InvalidData(format!(
+                "creation transaction {creation_txid} does not issue the market's reissuance tokens"
+            )));
+        }
+
+        let data = IssuanceData {
+            yes_entropy: entropy.yes_entropy,
+            no_entropy: entropy.no_entropy,
+            yes_blinding_nonce: entropy.yes_blinding_nonce,
+            no_blinding_nonce: entropy.no_blinding_nonce,
+        };
+        self.set_market_issuance_data(mid, &data)?;
+        Ok(data)
+    }
+
     // ==================== Maker Receive SPKs ====================
 
     /// Returns all maker receive SPKs (for LWK registration).
@@ -1538,29 +2852,159 @@ impl DeadcatStore {
 
         let market_rows: Vec<MarketRow> = markets::table.load(&mut self.conn)?;
 
-        for row in market_rows {
-            let candidate = self.load_candidate(row.candidate_id)?;
-            spks.push(candidate.dormant_yes_rt_spk);
-            spks.push(candidate.dormant_no_rt_spk);
-            spks.push(candidate.unresolved_yes_rt_spk);
-            spks.push(candidate.unresolved_no_rt_spk);
-            spks.push(candidate.unresolved_collateral_spk);
-            spks.push(candidate.resolved_yes_collateral_spk);
-            spks.push(candidate.resolved_no_collateral_spk);
-            spks.push(candidate.expired_collateral_spk);
-        }
+        for row in market_rows {
+            let candidate = self.load_candidate(row.candidate_id)?;
+            spks.push(candidate.dormant_yes_rt_spk);
+            spks.push(candidate.dormant_no_rt_spk);
+            spks.push(candidate.unresolved_yes_rt_spk);
+            spks.push(candidate.unresolved_no_rt_spk);
+            spks.push(candidate.unresolved_collateral_spk);
+            spks.push(candidate.resolved_yes_collateral_spk);
+            spks.push(candidate.resolved_no_collateral_spk);
+            spks.push(candidate.expired_collateral_spk);
+        }
+
+        let order_spks: Vec<Vec<u8>> = maker_orders::table
+            .select(maker_orders::covenant_spk)
+            .filter(maker_orders::covenant_spk.is_not_null())
+            .load::<Option<Vec<u8>>>(&mut self.conn)?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        spks.extend(order_spks);
+
+        Ok(spks)
+    }
+
+    /// Map every known market-slot and maker-order covenant scriptPubKey to
+    /// the contract it locks, for labelling outputs in transaction views.
+    pub fn covenant_script_labels(
+        &mut self,
+    ) -> crate::Result<HashMap<Vec<u8>, CovenantScriptLabel>> {
+        let mut labels = HashMap::new();
+
+        let market_rows: Vec<MarketRow> = markets::table.load(&mut self.conn)?;
+        for row in market_rows {
+            let market_id = MarketId(vec_to_array32(&row.market_id, "market_id")?);
+            let candidate = self.load_candidate(row.candidate_id)?;
+            let spks = [
+                candidate.dormant_yes_rt_spk,
+                candidate.dormant_no_rt_spk,
+                candidate.unresolved_yes_rt_spk,
+                candidate.unresolved_no_rt_spk,
+                candidate.unresolved_collateral_spk,
+                candidate.resolved_yes_collateral_spk,
+                candidate.resolved_no_collateral_spk,
+                candidate.expired_collateral_spk,
+            ];
+            for (spk, slot) in spks.into_iter().zip(MarketSlot::ALL) {
+                labels.insert(spk, CovenantScriptLabel::Market { market_id, slot });
+            }
+        }
+
+        let order_spks: Vec<(i32, Option<Vec<u8>>)> = maker_orders::table
+            .select((maker_orders::id, maker_orders::covenant_spk))
+            .filter(maker_orders::covenant_spk.is_not_null())
+            .load(&mut self.conn)?;
+        for (order_id, spk) in order_spks {
+            if let Some(spk) = spk {
+                labels.insert(spk, CovenantScriptLabel::MakerOrder { order_id });
+            }
+        }
+
+        Ok(labels)
+    }
+
+    /// Run SQLite's `PRAGMA integrity_check` and return the problems it
+    /// reports; an empty list means the database is intact.
+    pub fn integrity_errors(&mut self) -> crate::Result<Vec<String>> {
+        let rows = diesel::sql_query("PRAGMA integrity_check")
+            .load::<IntegrityCheckRow>(&mut self.conn)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| row.integrity_check)
+            .filter(|line| line != "ok")
+            .collect())
+    }
+
+    /// Whether `PRAGMA integrity_check` reports the database as intact.
+    pub fn integrity_check(&mut self) -> crate::Result<bool> {
+        Ok(self.integrity_errors()?.is_empty())
+    }
+
+    /// Rebuild the database file to reclaim space left by deleted rows.
+    pub fn vacuum(&mut self) -> crate::Result<()> {
+        diesel::sql_query("VACUUM").execute(&mut self.conn)?;
+        Ok(())
+    }
+
+    /// Copy `tables` from the database file at `source_path` into this
+    /// store, to carry rows over a rebuild.
+    ///
+    /// The source is read as-is, without migrating it, and only columns
+    /// present in both schemas are copied, so an older or half-migrated
+    /// database works. Rows whose key already exists here are kept. Each
+    /// table reports how many rows it copied, or why it could not be read;
+    /// a table the source doesn't have copies nothing.
+    pub fn copy_tables_from(
+        &mut self,
+        source_path: &str,
+        tables: &[&str],
+    ) -> crate::Result<Vec<(String, crate::Result<usize>)>> {
+        use diesel::sql_types::Text;
+
+        diesel::sql_query("ATTACH DATABASE ? AS rebuild_source")
+            .bind::<Text, _>(source_path)
+            .execute(&mut self.conn)?;
+        let results = tables
+            .iter()
+            .map(|table| (table.to_string(), self.copy_attached_table(table)))
+            .collect();
+        diesel::sql_query("DETACH DATABASE rebuild_source").execute(&mut self.conn)?;
+        Ok(results)
+    }
 
-        let order_spks: Vec<Vec<u8>> = maker_orders::table
-            .select(maker_orders::covenant_spk)
-            .filter(maker_orders::covenant_spk.is_not_null())
-            .load::<Option<Vec<u8>>>(&mut self.conn)?
+    fn copy_attached_table(&mut self, table: &str) -> crate::Result<usize> {
+        let source = self.table_columns("rebuild_source", table)?;
+        let columns: Vec<String> = self
+            .table_columns("main", table)?
             .into_iter()
-            .flatten()
+            .filter(|column| source.contains(column))
+            .map(|column| format!("\"{column}\""))
             .collect();
+        if columns.is_empty() {
+            return Ok(0);
+        }
+        let columns = columns.join(", ");
+        let copied = diesel::sql_query(format!(
+            "INSERT OR IGNORE INTO main.\"{table}\" ({columns}) \
+             SELECT {columns} FROM rebuild_source.\"{table}\""
+        ))
+        .execute(&mut self.conn)?;
+        Ok(copied)
+    }
 
-        spks.extend(order_spks);
+    /// Column names of `table` in the attached database `schema`; empty
+    /// when the table doesn't exist.
+    fn table_columns(&mut self, schema: &str, table: &str) -> crate::Result<Vec<String>> {
+        use diesel::sql_types::Text;
 
-        Ok(spks)
+        let rows = diesel::sql_query("SELECT name FROM pragma_table_info(?, ?)")
+            .bind::<Text, _>(table)
+            .bind::<Text, _>(schema)
+            .load::<TableColumnRow>(&mut self.conn)?;
+        Ok(rows.into_iter().map(|row| row.name).collect())
+    }
+
+    /// Version of the most recently applied migration, or `None` for a
+    /// database that has never been migrated.
+    pub fn schema_version(&mut self) -> crate::Result<Option<String>> {
+        let applied = self
+            .conn
+            .applied_migrations()
+            .map_err(|e| StoreError::Migration(e.to_string()))?;
+        Ok(applied.into_iter().max().map(|v| v.to_string()))
     }
 
     pub fn last_synced_height(&mut self) -> crate::Result<u32> {
@@ -1571,16 +3015,53 @@ impl DeadcatStore {
         Ok(height as u32)
     }
 
+    /// Depth of a market's live covenant outputs at `tip_height`, counted the
+    /// way `sync` does: the shallowest output decides, and an unconfirmed one
+    /// gives 0. `None` when the store holds no live outputs for the market.
+    pub fn market_state_confirmations(
+        &mut self,
+        mid: &MarketId,
+        tip_height: u32,
+    ) -> crate::Result<Option<u32>> {
+        let heights: Vec<Option<i32>> = utxos::table
+            .filter(utxos::market_id.eq(mid.as_bytes().to_vec()))
+            .filter(utxos::spent.eq(0))
+            .select(utxos::block_height)
+            .load(&mut self.conn)?;
+        Ok(heights
+            .into_iter()
+            .map(|height| match height {
+                Some(height) => tip_height.saturating_sub(height as u32).saturating_add(1),
+                None => 0,
+            })
+            .min())
+    }
+
+    /// Confirmations a market transition needs before `sync` records it.
+    pub fn min_confirmations(&self) -> u32 {
+        self.min_confirmations
+    }
+
+    /// Set how deep a market's latest covenant transition must be before
+    /// `sync` moves the market to the new state. Shallower transitions are
+    /// reported in `SyncReport::tentative_market_states` instead. `0` (the
+    /// default) accepts unconfirmed transitions.
+    pub fn set_min_confirmations(&mut self, confirmations: u32) {
+        self.min_confirmations = confirmations;
+    }
+
     /// Run canonical market/order sync against a chain source.
     ///
     /// Only promoted canonical markets participate in this sync.
     ///
-    /// 1. Rebuild each market's canonical live slot bundle from its promoted anchor
+    /// 1. Rebuild each market's canonical live slot bundle from its promoted anchor,
+    ///    holding back state changes shallower than `min_confirmations`
     /// 2. For each watched order SPK, discover new UTXOs via `chain.list_unspent`
     /// 3. For each existing unspent UTXO, check if spent via `chain.is_spent`
     /// 4. Derive order statuses from UTXO presence/absence
     /// 5. Update sync_state with block height
     pub fn sync<C: ChainSource>(&mut self, chain: &C) -> crate::Result<SyncReport> {
+        let min_confirmations = self.min_confirmations;
         self.conn.transaction(|conn| {
             let mut report = SyncReport::default();
 
@@ -1589,7 +3070,7 @@ impl DeadcatStore {
                 .map_err(|e| StoreError::Sync(e.to_string()))?;
             report.block_height = best_height;
 
-            sync_market_utxos(conn, chain, &mut report)?;
+            sync_market_utxos(conn, chain, best_height, min_confirmations, &mut report)?;
             sync_order_utxos(conn, chain, &mut report)?;
             sync_spent_utxos(conn, chain, &mut report)?;
             derive_order_statuses(conn, &mut report)?;
@@ -1684,6 +3165,42 @@ impl deadcat_sdk::DiscoveryStore for DeadcatStore {
         self.upsert_lmsr_pool_state(input)
             .map_err(|e| format!("{e}"))
     }
+
+    fn get_cached_transaction(&mut self, txid: &[u8; 32]) -> Result<Option<Vec<u8>>, String> {
+        DeadcatStore::get_cached_transaction(self, txid).map_err(|e| format!("{e}"))
+    }
+
+    fn cache_transaction(&mut self, txid: &[u8; 32], raw_tx: &[u8]) -> Result<(), String> {
+        DeadcatStore::cache_transaction(self, txid, raw_tx).map_err(|e| format!("{e}"))
+    }
+
+    fn save_publish_record(&mut self, record: &PublishRecord) -> Result<(), String> {
+        DeadcatStore::save_publish_record(self, record).map_err(|e| format!("{e}"))
+    }
+
+    fn get_publish_record(&mut self, event_id: &str) -> Result<Option<PublishRecord>, String> {
+        DeadcatStore::get_publish_record(self, event_id).map_err(|e| format!("{e}"))
+    }
+
+    fn list_pending_publishes(&mut self) -> Result<Vec<PublishRecord>, String> {
+        DeadcatStore::list_pending_publishes(self).map_err(|e| format!("{e}"))
+    }
+
+    fn save_broadcast_intent(&mut self, intent: &BroadcastIntent) -> Result<(), String> {
+        DeadcatStore::save_broadcast_intent(self, intent).map_err(|e| format!("{e}"))
+    }
+
+    fn set_broadcast_intent_status(
+        &mut self,
+        txid: &str,
+        status: IntentStatus,
+    ) -> Result<(), String> {
+        DeadcatStore::set_broadcast_intent_status(self, txid, status).map_err(|e| format!("{e}"))
+    }
+
+    fn list_pending_broadcast_intents(&mut self) -> Result<Vec<BroadcastIntent>, String> {
+        DeadcatStore::list_pending_broadcast_intents(self).map_err(|e| format!("{e}"))
+    }
 }
 
 impl deadcat_sdk::NodeStore for DeadcatStore {
@@ -1724,6 +3241,19 @@ impl deadcat_sdk::NodeStore for DeadcatStore {
         DeadcatStore::get_pool_price_history(self, pool_id, since_block_height, limit)
             .map_err(|e| format!("{e}"))
     }
+
+    fn record_lmsr_pool_verification(
+        &mut self,
+        pool_id: &str,
+        status: &deadcat_sdk::PoolVerificationStatus,
+    ) -> Result<(), String> {
+        let reason = match status {
+            deadcat_sdk::PoolVerificationStatus::Invalid(reason) => Some(reason.as_str()),
+            _ => None,
+        };
+        DeadcatStore::record_lmsr_pool_verification(self, pool_id, status.as_str(), reason)
+            .map_err(|e| format!("{e}"))
+    }
 }
 
 // ==================== Sync internals (free functions taking &mut conn) ====================
@@ -1826,10 +3356,13 @@ fn upsert_market_chain_utxo(
     Ok(inserted)
 }
 
+/// `tentative` marks a scan whose latest transition is still too shallow to
+/// trust; a state change from it is reported but not written.
 fn update_market_state_from_scan(
     conn: &mut SqliteConnection,
     row: &MarketRow,
     scan: &CanonicalMarketScan,
+    tentative: bool,
     report: &mut SyncReport,
 ) -> crate::Result<()> {
     let new_state = scan.state;
@@ -1838,6 +3371,15 @@ fn update_market_state_from_scan(
         StoreError::InvalidData(format!("invalid market state: {}", row.current_state))
     })?;
 
+    if tentative && old_state != new_state {
+        report.tentative_market_states.push(MarketStateChange {
+            market_id: MarketId(vec_to_array32(&row.market_id, "market_id")?),
+            old_state,
+            new_state,
+        });
+        return Ok(());
+    }
+
     if scan.utxos.is_empty() {
         diesel::update(markets::table.filter(markets::market_id.eq(&row.market_id)))
             .set((
@@ -1915,6 +3457,8 @@ fn update_market_state_from_scan(
 fn sync_market_utxos<C: ChainSource>(
     conn: &mut SqliteConnection,
     chain: &C,
+    best_height: u32,
+    min_confirmations: u32,
     report: &mut SyncReport,
 ) -> crate::Result<()> {
     let rows: Vec<MarketRow> = markets::table.load(conn)?;
@@ -1956,8 +3500,8 @@ fn sync_market_utxos<C: ChainSource>(
         let needs_entropy = !issuance_data_complete(&candidate);
         let mut candidate_txids = vec![parsed_anchor.creation_txid.to_byte_array()];
 
-        clear_market_utxo_tags(conn, &row.market_id)?;
-
+        let mut confirmations = u32::MAX;
+        let mut chain_utxos = Vec::with_capacity(scan.utxos.len());
         for canonical_utxo in &scan.utxos {
             let spk = market_slot_script_pubkey(&candidate, canonical_utxo.slot);
             let chain_utxo = chain
@@ -1975,23 +3519,32 @@ fn sync_market_utxos<C: ChainSource>(
                     ))
                 })?;
 
+            confirmations = confirmations.min(match chain_utxo.block_height {
+                Some(height) => best_height.saturating_sub(height).saturating_add(1),
+                None => 0,
+            });
+
             if needs_entropy && !candidate_txids.contains(&chain_utxo.txid) {
                 candidate_txids.push(chain_utxo.txid);
             }
+            chain_utxos.push((chain_utxo, spk, canonical_utxo.slot));
+        }
 
-            let inserted = upsert_market_chain_utxo(
-                conn,
-                &chain_utxo,
-                spk,
-                &row.market_id,
-                canonical_utxo.slot,
-            )?;
-            if inserted {
-                report.new_utxos += 1;
+        let tentative = confirmations < min_confirmations;
+        // A held-back transition leaves the market and its UTXO tags in the
+        // old state until it is deep enough to commit.
+        let held_back = tentative && row.current_state != scan.state.as_u64() as i32;
+        if !held_back {
+            clear_market_utxo_tags(conn, &row.market_id)?;
+            for (chain_utxo, spk, slot) in &chain_utxos {
+                let inserted =
+                    upsert_market_chain_utxo(conn, chain_utxo, spk, &row.market_id, *slot)?;
+                if inserted {
+                    report.new_utxos += 1;
+                }
             }
         }
-
-        update_market_state_from_scan(conn, row, &scan, report)?;
+        update_market_state_from_scan(conn, row, &scan, tentative, report)?;
 
         if needs_entropy {
             for txid in candidate_txids {
@@ -2665,6 +4218,77 @@ mod tests {
         assert_eq!(pools[0].pool_id, "bb".repeat(32));
     }
 
+    #[test]
+    fn list_my_lmsr_pools_returns_only_recorded_creator() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let mut p1 = sample_lmsr_pool_ingest();
+        p1.pool_id = "aa".repeat(32);
+        let mut p2 = sample_lmsr_pool_ingest();
+        p2.pool_id = "bb".repeat(32);
+        store.ingest_lmsr_pool(&p1).unwrap();
+        store.ingest_lmsr_pool(&p2).unwrap();
+
+        store
+            .record_lmsr_pool_creator(&"bb".repeat(32), &[7u8; 32])
+            .unwrap();
+
+        let mine = store.list_my_lmsr_pools(&[7u8; 32]).unwrap();
+        assert_eq!(mine.len(), 1);
+        assert_eq!(mine[0].pool_id, "bb".repeat(32));
+        assert!(store.list_my_lmsr_pools(&[8u8; 32]).unwrap().is_empty());
+
+        // Re-ingesting a pool snapshot must not clear its creator.
+        store.ingest_lmsr_pool(&p2).unwrap();
+        assert_eq!(store.list_my_lmsr_pools(&[7u8; 32]).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn record_lmsr_pool_creator_rejects_unknown_pool() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        assert!(
+            store
+                .record_lmsr_pool_creator(&"cc".repeat(32), &[7u8; 32])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn record_lmsr_pool_verification_keeps_latest_verdict() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let pool = sample_lmsr_pool_ingest();
+        store.ingest_lmsr_pool(&pool).unwrap();
+        let verdict = |store: &mut DeadcatStore| {
+            let info = store
+                .list_lmsr_pools(&LmsrPoolFilter {
+                    pool_id: Some(pool.pool_id.clone()),
+                    ..Default::default()
+                })
+                .unwrap()
+                .remove(0);
+            (info.verification_status, info.verification_reason)
+        };
+        assert_eq!(verdict(&mut store), (None, None));
+
+        store
+            .record_lmsr_pool_verification(&pool.pool_id, "invalid", Some("no covenant"))
+            .unwrap();
+        assert_eq!(
+            verdict(&mut store),
+            (Some("invalid".into()), Some("no covenant".into()))
+        );
+
+        store
+            .record_lmsr_pool_verification(&pool.pool_id, "verified", None)
+            .unwrap();
+        store.ingest_lmsr_pool(&pool).unwrap();
+        assert_eq!(verdict(&mut store), (Some("verified".into()), None));
+        assert!(
+            store
+                .record_lmsr_pool_verification(&"cc".repeat(32), "verified", None)
+                .is_err()
+        );
+    }
+
     // ── price history tests ──────────────────────────────────────────────
 
     fn sample_price_transition(
@@ -2687,6 +4311,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn thinned_snapshot_ids_keeps_endpoints_and_spacing() {
+        let ids: Vec<i32> = (1..=10).collect();
+        assert_eq!(thinned_snapshot_ids(&ids, 4), vec![2, 3, 5, 6, 8, 9]);
+        assert_eq!(thinned_snapshot_ids(&ids, 0), (2..=9).collect::<Vec<_>>());
+        assert!(thinned_snapshot_ids(&ids, 10).is_empty());
+        assert!(thinned_snapshot_ids(&[7], 1).is_empty());
+    }
+
+    #[test]
+    fn prune_thins_price_history_below_cutoff_per_pool() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        for height in 1..=6 {
+            for pool in ["pool-1", "pool-2"] {
+                store
+                    .record_price_transition(&sample_price_transition(
+                        pool,
+                        "market-1",
+                        &format!("tx-{height}"),
+                        height * 10,
+                    ))
+                    .unwrap();
+            }
+        }
+
+        let report = store.prune(55, 3).unwrap();
+        assert_eq!(report.price_snapshots, 4);
+        assert_eq!(report.spent_utxos, 0);
+
+        let heights: Vec<u32> = store
+            .get_pool_price_history("pool-1", None, None)
+            .unwrap()
+            .iter()
+            .map(|entry| entry.block_height)
+            .collect();
+        assert_eq!(heights, vec![10, 30, 50, 60]);
+    }
+
     #[test]
     fn record_price_transition_and_query() {
         let mut store = DeadcatStore::open_in_memory().unwrap();
@@ -2986,4 +4648,321 @@ mod tests {
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].pool_id, "pool-B");
     }
+
+    #[test]
+    fn cached_transaction_round_trips_and_ignores_duplicates() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let txid = [0x11; 32];
+        assert_eq!(store.get_cached_transaction(&txid).unwrap(), None);
+
+        store.cache_transaction(&txid, &[1, 2, 3]).unwrap();
+        store.cache_transaction(&txid, &[4, 5, 6]).unwrap();
+        assert_eq!(
+            store.get_cached_transaction(&txid).unwrap(),
+            Some(vec![1, 2, 3])
+        );
+        assert_eq!(store.get_cached_transaction(&[0x22; 32]).unwrap(), None);
+    }
+
+    fn sample_publish_record(event_id: &str, status: PublishStatus) -> PublishRecord {
+        PublishRecord {
+            event_id: event_id.to_string(),
+            event_json: format!("{{\"id\":\"{event_id}\"}}"),
+            accepted_relays: vec!["wss://relay.one".to_string()],
+            attempts: 1,
+            status,
+            last_error: Some("published to 1 of 2 relays".to_string()),
+        }
+    }
+
+    #[test]
+    fn publish_records_upsert_and_list_pending() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let pending = sample_publish_record("aa", PublishStatus::Pending);
+        store.save_publish_record(&pending).unwrap();
+        store
+            .save_publish_record(&sample_publish_record("bb", PublishStatus::Published))
+            .unwrap();
+
+        assert_eq!(
+            store.list_pending_publishes().unwrap(),
+            vec![pending.clone()]
+        );
+
+        let mut retried = pending;
+        retried.accepted_relays.push("wss://relay.two".to_string());
+        retried.attempts = 2;
+        retried.status = PublishStatus::Published;
+        retried.last_error = None;
+        store.save_publish_record(&retried).unwrap();
+
+        assert!(store.list_pending_publishes().unwrap().is_empty());
+        assert_eq!(store.get_publish_record("aa").unwrap(), Some(retried));
+        assert_eq!(store.get_publish_record("cc").unwrap(), None);
+    }
+
+    #[test]
+    fn broadcast_intents_stay_pending_until_settled() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let intent = |txid: &str| BroadcastIntent {
+            txid: txid.to_string(),
+            kind: deadcat_sdk::IntentKind::IssueTokens,
+            market_id: Some("aa".to_string()),
+            raw_tx: vec![1, 2, 3],
+            status: IntentStatus::Pending,
+        };
+        store.save_broadcast_intent(&intent("t1")).unwrap();
+        store.save_broadcast_intent(&intent("t2")).unwrap();
+        assert_eq!(
+            store.list_pending_broadcast_intents().unwrap(),
+            vec![intent("t1"), intent("t2")]
+        );
+
+        store
+            .set_broadcast_intent_status("t1", IntentStatus::Completed)
+            .unwrap();
+        store
+            .set_broadcast_intent_status("t2", IntentStatus::Abandoned)
+            .unwrap();
+        assert!(store.list_pending_broadcast_intents().unwrap().is_empty());
+
+        // Journaling the same transaction again re-opens it.
+        store.save_broadcast_intent(&intent("t1")).unwrap();
+        assert_eq!(
+            store.list_pending_broadcast_intents().unwrap(),
+            vec![intent("t1")]
+        );
+    }
+
+    #[test]
+    fn payment_swaps_round_trip_and_update_in_place() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let swap = |id: &str, created_at: &str| PaymentSwapRecord {
+            id: id.to_string(),
+            flow: "lightning_to_liquid".to_string(),
+            network: "regtest".to_string(),
+            status: "swap.created".to_string(),
+            invoice_amount_sat: 100_000,
+            expected_amount_sat: Some(99_500),
+            lockup_address: Some("el1qq".to_string()),
+            timeout_block_height: Some(1_234),
+            pair_hash: None,
+            invoice: Some("lnbcrt1".to_string()),
+            invoice_expiry_seconds: Some(3_600),
+            invoice_expires_at: None,
+            lockup_txid: None,
+            claim_txid: None,
+            preimage_hash: Some("aa".to_string()),
+            preimage: None,
+            claim_details: None,
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+        };
+        store
+            .upsert_payment_swap(&swap("s2", "2026-01-02T00:00:00Z"))
+            .unwrap();
+        store
+            .upsert_payment_swap(&swap("s1", "2026-01-01T00:00:00Z"))
+            .unwrap();
+
+        let mut settled = swap("s1", "2026-01-01T00:00:00Z");
+        settled.status = "invoice.settled".to_string();
+        settled.lockup_txid = Some("bb".to_string());
+        settled.updated_at = "2026-01-03T00:00:00Z".to_string();
+        store.upsert_payment_swap(&settled).unwrap();
+
+        assert_eq!(store.get_payment_swap("s1").unwrap(), Some(settled.clone()));
+        assert_eq!(
+            store.list_payment_swaps().unwrap(),
+            vec![settled, swap("s2", "2026-01-02T00:00:00Z")]
+        );
+
+        assert!(store.delete_payment_swap("s2").unwrap());
+        assert!(!store.delete_payment_swap("s2").unwrap());
+        assert_eq!(store.list_payment_swaps().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn copy_tables_from_reads_an_older_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("old.db");
+        let source = source.to_str().unwrap();
+        let mut conn = SqliteConnection::establish(source).unwrap();
+        diesel::sql_query(
+            "CREATE TABLE payment_swaps (id TEXT PRIMARY KEY NOT NULL, flow TEXT NOT NULL,
+             network TEXT NOT NULL, status TEXT NOT NULL, invoice_amount_sat BIGINT NOT NULL,
+             preimage TEXT, created_at TEXT NOT NULL, updated_at TEXT NOT NULL)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        diesel::sql_query(
+            "INSERT INTO payment_swaps VALUES ('s1', 'lightning_to_liquid', 'regtest',
+             'swap.created', 5000, 'ff', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z')",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        drop(conn);
+
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let copied = store
+            .copy_tables_from(source, &["payment_swaps", "action_log"])
+            .unwrap();
+        let copied: Vec<(String, usize)> = copied
+            .into_iter()
+            .map(|(table, result)| (table, result.unwrap()))
+            .collect();
+        assert_eq!(
+            copied,
+            [
+                ("payment_swaps".to_string(), 1),
+                ("action_log".to_string(), 0)
+            ]
+        );
+
+        let swap = store.get_payment_swap("s1").unwrap().unwrap();
+        assert_eq!(swap.preimage.as_deref(), Some("ff"));
+        assert_eq!(swap.claim_details, None);
+        // Copying again keeps the existing rows.
+        let again = store.copy_tables_from(source, &["payment_swaps"]).unwrap();
+        assert_eq!(again[0].1.as_ref().unwrap(), &0);
+    }
+
+    #[test]
+    fn action_log_records_and_filters_newest_first() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let issued = store
+            .log_action(
+                ActionKind::IssueTokens,
+                Some("aa"),
+                Some("11"),
+                r#"{"pairs":5}"#,
+            )
+            .unwrap();
+        assert_eq!(issued.kind, ActionKind::IssueTokens);
+        assert_eq!(issued.txid.as_deref(), Some("11"));
+        assert!(!issued.created_at.is_empty());
+        store
+            .log_action(ActionKind::Swap, Some("bb"), Some("22"), "{}")
+            .unwrap();
+        store
+            .log_action(ActionKind::ResolveMarket, Some("aa"), Some("33"), "{}")
+            .unwrap();
+        assert!(
+            store
+                .log_action(ActionKind::Swap, None, None, "not json")
+                .is_err()
+        );
+
+        let all = store.get_action_log(&ActionLogFilter::default()).unwrap();
+        let txids: Vec<_> = all.iter().map(|e| e.txid.as_deref().unwrap()).collect();
+        assert_eq!(txids, vec!["33", "22", "11"]);
+
+        let market = store
+            .get_action_log(&ActionLogFilter {
+                market_id: Some("aa".to_string()),
+                limit: Some(1),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(market.len(), 1);
+        assert_eq!(market[0].kind, ActionKind::ResolveMarket);
+
+        let swaps = store
+            .get_action_log(&ActionLogFilter {
+                kind: Some(ActionKind::Swap),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(swaps.len(), 1);
+        assert_eq!(swaps[0].market_id.as_deref(), Some("bb"));
+    }
+
+    #[test]
+    fn price_alerts_create_list_and_delete() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let above = store
+            .create_price_alert("pool-a", PriceAlertDirection::Above, 6_000)
+            .unwrap();
+        let below = store
+            .create_price_alert("pool-b", PriceAlertDirection::Below, 2_500)
+            .unwrap();
+        assert_eq!(above.direction, PriceAlertDirection::Above);
+        assert_eq!(above.threshold_bps, 6_000);
+        assert!(!above.triggered);
+        assert!(
+            store
+                .create_price_alert("pool-a", PriceAlertDirection::Above, 10_001)
+                .is_err()
+        );
+
+        store.set_price_alert_triggered(above.id, true).unwrap();
+        let listed = store.list_price_alerts(Some("pool-a")).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(listed[0].triggered);
+        assert_eq!(store.list_price_alerts(None).unwrap().len(), 2);
+
+        assert!(store.delete_price_alert(below.id).unwrap());
+        assert!(!store.delete_price_alert(below.id).unwrap());
+        assert!(store.list_price_alerts(Some("pool-b")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn price_alert_fires_once_per_crossing() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let id = store
+            .create_price_alert("pool-a", PriceAlertDirection::Above, 6_000)
+            .unwrap()
+            .id;
+        let mut observe = |price_bps: u16| {
+            let alert = store
+                .list_price_alerts(Some("pool-a"))
+                .unwrap()
+                .into_iter()
+                .find(|alert| alert.id == id)
+                .unwrap();
+            store.observe_price_alert(&alert, price_bps).unwrap()
+        };
+
+        let fired: Vec<bool> = [5_000, 6_000, 6_500, 7_000, 5_999, 5_500, 6_200]
+            .into_iter()
+            .map(&mut observe)
+            .collect();
+        assert_eq!(fired, [false, true, false, false, false, false, true]);
+    }
+
+    #[test]
+    fn integrity_check_passes_on_fresh_store() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        assert!(store.integrity_errors().unwrap().is_empty());
+        assert!(store.integrity_check().unwrap());
+        store.vacuum().unwrap();
+        assert!(store.integrity_check().unwrap());
+    }
+
+    #[test]
+    fn market_attestation_keeps_first_verified() {
+        let mut store = DeadcatStore::open_in_memory().unwrap();
+        let market_id = MarketId([0x11; 32]);
+        assert_eq!(store.get_market_attestation(&market_id).unwrap(), None);
+
+        let attestation = VerifiedAttestation {
+            market_id: ::hex::encode(market_id.as_bytes()),
+            outcome_yes: true,
+            signature_hex: "aa".repeat(64),
+            nostr_event_id: "bb".repeat(32),
+            signed_at: 1_700_000_000,
+        };
+        store.save_market_attestation(&attestation).unwrap();
+        store
+            .save_market_attestation(&VerifiedAttestation {
+                outcome_yes: false,
+                signed_at: 1_700_000_100,
+                ..attestation.clone()
+            })
+            .unwrap();
+        assert_eq!(
+            store.get_market_attestation(&market_id).unwrap(),
+            Some(attestation)
+        );
+    }
 }
@@ -51,6 +51,8 @@ pub struct SyncReport {
     pub new_utxos: u32,
     pub spent_utxos: u32,
     pub market_state_changes: Vec<MarketStateChange>,
+    /// Transitions seen on chain but held back by `min_confirmations`.
+    pub tentative_market_states: Vec<MarketStateChange>,
     pub order_status_changes: Vec<OrderStatusChange>,
     pub block_height: u32,
 }
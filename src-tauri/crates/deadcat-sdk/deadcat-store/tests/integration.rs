@@ -15,12 +15,15 @@ use deadcat_sdk::{
     PredictionMarketAnchor, PredictionMarketParams, UnblindedUtxo, derive_maker_receive,
     maker_receive_script_pubkey,
 };
+use diesel::migration::MigrationSource;
 use diesel::prelude::*;
-use diesel::sqlite::SqliteConnection;
+use diesel::sqlite::{Sqlite, SqliteConnection};
+use diesel_migrations::MigrationHarness;
 
 use deadcat_store::{
-    ChainSource, ChainUtxo, DeadcatStore, IssuanceData, MarketCandidateFilter, MarketFilter,
-    OrderFilter, OrderStatus, PredictionMarketCandidateIngestInput,
+    CategoryCount, ChainSource, ChainUtxo, CovenantScriptLabel, DeadcatStore, IssuanceData,
+    MIGRATIONS, MarketCandidateFilter, MarketFilter, OrderFilter, OrderStatus,
+    PredictionMarketCandidateIngestInput, PriceTransitionInput, SortBy, StoreCounts, StoreError,
 };
 
 // ==================== Test Helpers ====================
@@ -971,6 +974,63 @@ fn test_open_migrates_lmsr_pools_without_fabricated_initial_anchors() {
     assert_eq!(pool.stored_initial_reserve_outpoints, None);
 }
 
+/// Version of the newest embedded migration, e.g. `"20260329000001"`.
+fn latest_migration_version() -> String {
+    MigrationSource::<Sqlite>::migrations(&MIGRATIONS)
+        .unwrap()
+        .iter()
+        .map(|migration| migration.name().version().to_string())
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn test_open_reports_schema_version_and_discards_migration_backup() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("legacy-schema-version.db");
+    let db_path = db_path.to_str().unwrap();
+    bootstrap_pre_node_owned_history_schema(db_path);
+
+    let mut store = DeadcatStore::open(db_path).unwrap();
+    assert_eq!(
+        store.schema_version().unwrap(),
+        Some(latest_migration_version())
+    );
+    assert!(!std::path::Path::new(&format!("{db_path}.pre-migration")).exists());
+}
+
+#[test]
+fn test_open_restores_backup_when_migration_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("failed-migration.db");
+    let db_path = db_path.to_str().unwrap();
+    drop(DeadcatStore::open(db_path).unwrap());
+
+    // Mark every migration after 2026-03-21 as pending; re-running the
+    // first of them fails because its column already exists.
+    let mut conn = SqliteConnection::establish(db_path).unwrap();
+    diesel::sql_query(
+        "DELETE FROM __diesel_schema_migrations
+         WHERE version > '20260321000001'",
+    )
+    .execute(&mut conn)
+    .unwrap();
+    drop(conn);
+
+    let err = DeadcatStore::open(db_path).err().unwrap();
+    assert!(matches!(err, StoreError::MigrationRolledBack { .. }));
+    assert!(!std::path::Path::new(&format!("{db_path}.pre-migration")).exists());
+
+    let mut conn = SqliteConnection::establish(db_path).unwrap();
+    let latest = conn
+        .applied_migrations()
+        .unwrap()
+        .into_iter()
+        .max()
+        .unwrap();
+    assert_eq!(latest.to_string(), "20260321000001");
+}
+
 // ==================== Market Tests ====================
 
 #[test]
@@ -1084,6 +1144,58 @@ fn test_list_markets_filter_by_collateral_asset() {
     assert_eq!(store.list_markets(&filter).unwrap().len(), 0);
 }
 
+#[test]
+fn test_list_markets_filter_by_category_and_list_categories() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let params1 = test_params();
+    let params2 = test_params_2();
+    let sports1 = ingest_test_market_with_metadata(
+        &mut store,
+        &params1,
+        ContractMetadataInput {
+            category: Some("Sports ".to_string()),
+            ..test_market_metadata(&params1)
+        },
+    );
+    let sports2 = ingest_test_market_with_metadata(
+        &mut store,
+        &params2,
+        ContractMetadataInput {
+            category: Some("sports".to_string()),
+            ..test_market_metadata(&params2)
+        },
+    );
+
+    let filter = MarketFilter {
+        category: Some("  SPORTS".to_string()),
+        ..Default::default()
+    };
+    let mut ids: Vec<_> = store
+        .list_markets(&filter)
+        .unwrap()
+        .into_iter()
+        .map(|m| m.market_id)
+        .collect();
+    ids.sort_by_key(|id| *id.as_bytes());
+    let mut expected = vec![sports1, sports2];
+    expected.sort_by_key(|id| *id.as_bytes());
+    assert_eq!(ids, expected);
+
+    let filter = MarketFilter {
+        category: Some("politics".to_string()),
+        ..Default::default()
+    };
+    assert!(store.list_markets(&filter).unwrap().is_empty());
+
+    assert_eq!(
+        store.list_categories().unwrap(),
+        vec![CategoryCount {
+            category: "Sports".to_string(),
+            market_count: 2,
+        }]
+    );
+}
+
 #[test]
 fn test_list_markets_with_limit() {
     let mut store = DeadcatStore::open_in_memory().unwrap();
@@ -1097,6 +1209,179 @@ fn test_list_markets_with_limit() {
     assert_eq!(store.list_markets(&filter).unwrap().len(), 1);
 }
 
+#[test]
+fn test_list_markets_sorted() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let id1 = ingest_test_market(&mut store, &test_params()); // expiry = 1_000_000
+    let id2 = ingest_test_market(&mut store, &test_params_2()); // expiry = 2_000_000
+
+    let sorted = |store: &mut DeadcatStore, sort_by, descending| {
+        let filter = MarketFilter {
+            sort_by: Some(sort_by),
+            descending,
+            ..Default::default()
+        };
+        store
+            .list_markets(&filter)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.market_id)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(sorted(&mut store, SortBy::Expiry, false), vec![id1, id2]);
+    assert_eq!(sorted(&mut store, SortBy::Expiry, true), vec![id2, id1]);
+
+    // Only market 1 has pool activity; market 2 has no price and sorts lowest.
+    for (txid, bps) in [("tx-1", 4_000), ("tx-2", 6_000)] {
+        store
+            .record_price_transition(&PriceTransitionInput {
+                pool_id: "pool-1".to_string(),
+                market_id: hex::encode(id1.as_bytes()),
+                transition_txid: txid.to_string(),
+                old_s_index: 1,
+                new_s_index: 2,
+                reserve_yes: 100,
+                reserve_no: 100,
+                reserve_collateral: 100,
+                implied_yes_price_bps: bps,
+                block_height: 10,
+            })
+            .unwrap();
+    }
+    assert_eq!(sorted(&mut store, SortBy::Volume, true), vec![id1, id2]);
+    assert_eq!(sorted(&mut store, SortBy::Price, false), vec![id2, id1]);
+
+    let filter = MarketFilter {
+        sort_by: Some(SortBy::Expiry),
+        descending: true,
+        limit: Some(1),
+        ..Default::default()
+    };
+    assert_eq!(store.list_markets(&filter).unwrap()[0].market_id, id2);
+}
+
+#[test]
+fn test_market_metrics_aggregates_utxos_orders_and_history() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let params = test_params();
+    let market_id = ingest_test_market(&mut store, &params);
+    let market_hex = hex::encode(market_id.as_bytes());
+
+    let locked = test_utxo_with_outpoint([0xC1; 32], 0, params.collateral_asset_id, 40_000);
+    let spent = test_utxo_with_outpoint([0xC2; 32], 0, params.collateral_asset_id, 9_000);
+    let token = test_utxo_with_outpoint([0xC3; 32], 0, [0x01; 32], 7);
+    for (utxo, slot) in [
+        (&locked, MarketSlot::UnresolvedCollateral),
+        (&spent, MarketSlot::UnresolvedCollateral),
+        (&token, MarketSlot::UnresolvedYesRt),
+    ] {
+        store
+            .add_market_slot_utxo(&market_id, slot, utxo, Some(10))
+            .unwrap();
+    }
+    store
+        .mark_spent(&[0xC2; 32], 0, &[0xFF; 32], Some(11))
+        .unwrap();
+
+    let order_id = store
+        .ingest_maker_order(
+            &test_maker_order_params(),
+            Some(&[0xaa; 32]),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    let order = store.get_maker_order(order_id).unwrap().unwrap();
+    store
+        .record_order_creation(&order.cmr, &[0xaa; 32], "tx-order", &market_hex, "sell", 1)
+        .unwrap();
+    let pending = store.market_metrics(&market_id).unwrap().unwrap();
+    assert_eq!(pending.open_order_count, 1);
+    store
+        .update_order_status(order_id, OrderStatus::Active)
+        .unwrap();
+
+    store
+        .record_price_transition(&PriceTransitionInput {
+            pool_id: "pool-1".to_string(),
+            market_id: market_hex,
+            transition_txid: "tx-1".to_string(),
+            old_s_index: 1,
+            new_s_index: 2,
+            reserve_yes: 100,
+            reserve_no: 100,
+            reserve_collateral: 100,
+            implied_yes_price_bps: 5_000,
+            block_height: 10,
+        })
+        .unwrap();
+
+    let metrics = store.market_metrics(&market_id).unwrap().unwrap();
+    assert_eq!(metrics.total_collateral_locked, 40_000);
+    assert_eq!(metrics.pool_tvl, 0);
+    assert_eq!(metrics.open_order_count, 1);
+    assert_eq!(metrics.snapshot_count, 1);
+    assert!(!metrics.last_activity.is_empty());
+
+    assert!(
+        store
+            .market_metrics(&test_params_2().market_id())
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_record_counts() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    assert_eq!(store.record_counts().unwrap(), StoreCounts::default());
+
+    ingest_test_market(&mut store, &test_params());
+    store
+        .ingest_maker_order(
+            &test_maker_order_params(),
+            Some(&[0xaa; 32]),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+    let counts = store.record_counts().unwrap();
+    assert_eq!(counts.markets, 1);
+    assert_eq!(counts.maker_orders, 1);
+    assert_eq!(counts.lmsr_pools, 0);
+}
+
+#[test]
+fn test_record_order_top_up_updates_size_and_announcement() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let order_id = store
+        .ingest_maker_order(
+            &test_maker_order_params(),
+            Some(&[0xaa; 32]),
+            None,
+            Some("evt-1"),
+            None,
+        )
+        .unwrap();
+    let order = store.get_maker_order(order_id).unwrap().unwrap();
+    store
+        .record_order_creation(&order.cmr, &[0xaa; 32], "tx-order", "mkt", "sell", 100)
+        .unwrap();
+
+    store
+        .record_order_top_up(&order.cmr, &[0xaa; 32], 250, "evt-2")
+        .unwrap();
+
+    let order = store.get_maker_order(order_id).unwrap().unwrap();
+    assert_eq!(order.offered_amount, Some(250));
+    assert_eq!(order.nostr_event_id.as_deref(), Some("evt-2"));
+    assert_eq!(order.creation_txid.as_deref(), Some("tx-order"));
+}
+
 #[test]
 fn test_update_market_state() {
     let mut store = DeadcatStore::open_in_memory().unwrap();
@@ -1254,6 +1539,32 @@ fn test_filter_orders_by_maker_pubkey() {
     assert_eq!(results[0].maker_base_pubkey, Some([0xaa; 32]));
 }
 
+#[test]
+fn test_list_maker_orders_sorted_by_price() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    for params in [test_maker_order_params_2(), test_maker_order_params()] {
+        store
+            .ingest_maker_order(&params, Some(&[0xaa; 32]), None, None, None)
+            .unwrap();
+    }
+
+    let prices = |store: &mut DeadcatStore, descending| {
+        let filter = OrderFilter {
+            sort_by: Some(SortBy::Price),
+            descending,
+            ..Default::default()
+        };
+        store
+            .list_maker_orders(&filter)
+            .unwrap()
+            .into_iter()
+            .map(|o| o.params.price)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(prices(&mut store, false), vec![50_000, 75_000]);
+    assert_eq!(prices(&mut store, true), vec![75_000, 50_000]);
+}
+
 #[test]
 fn test_list_maker_orders_with_limit() {
     let mut store = DeadcatStore::open_in_memory().unwrap();
@@ -1392,6 +1703,61 @@ fn test_order_utxo_lifecycle() {
     assert_eq!(store.get_order_utxos(order_id).unwrap().len(), 0);
 }
 
+#[test]
+fn test_prune_deletes_old_spent_utxos_only() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let market_id = ingest_test_market(&mut store, &test_params());
+    let order_id = store
+        .ingest_maker_order(
+            &test_maker_order_params(),
+            Some(&[0xaa; 32]),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+    let old_spent = test_utxo_with_outpoint([0xA1; 32], 0, [0xbb; 32], 1_000);
+    let new_spent = test_utxo_with_outpoint([0xA2; 32], 0, [0xbb; 32], 1_000);
+    let unspent = test_utxo_with_outpoint([0xA3; 32], 0, [0xbb; 32], 1_000);
+    let order_spent = test_utxo_with_outpoint([0xA4; 32], 0, [0x01; 32], 1_000);
+    for utxo in [&old_spent, &new_spent, &unspent] {
+        store
+            .add_market_slot_utxo(&market_id, MarketSlot::DormantYesRt, utxo, Some(10))
+            .unwrap();
+    }
+    store
+        .add_order_utxo(order_id, &order_spent, Some(10))
+        .unwrap();
+    store
+        .mark_spent(&[0xA1; 32], 0, &[0xFF; 32], Some(20))
+        .unwrap();
+    store
+        .mark_spent(&[0xA2; 32], 0, &[0xFF; 32], Some(200))
+        .unwrap();
+    store
+        .mark_spent(&[0xA4; 32], 0, &[0xFF; 32], Some(20))
+        .unwrap();
+
+    let report = store.prune(100, 10).unwrap();
+    assert_eq!(report.spent_utxos, 1);
+    assert_eq!(
+        store
+            .get_market_utxos(&market_id, Some(MarketState::Dormant))
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // The order's spent output was kept so its fill status stays derivable;
+    // once the order is cancelled it can go too.
+    store
+        .update_order_status(order_id, OrderStatus::Cancelled)
+        .unwrap();
+    assert_eq!(store.prune(100, 10).unwrap().spent_utxos, 1);
+    assert_eq!(store.prune(100, 10).unwrap().spent_utxos, 0);
+}
+
 #[test]
 fn test_get_market_utxos_filter_by_state() {
     let mut store = DeadcatStore::open_in_memory().unwrap();
@@ -1482,6 +1848,40 @@ fn test_watched_script_pubkeys() {
     assert_eq!(store.watched_script_pubkeys().unwrap().len(), 9);
 }
 
+#[test]
+fn test_covenant_script_labels() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let params = test_params();
+    let mid = ingest_test_market(&mut store, &params);
+    store
+        .ingest_maker_order(
+            &test_maker_order_params(),
+            Some(&[0xaa; 32]),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+    let labels = store.covenant_script_labels().unwrap();
+    assert_eq!(labels.len(), 9);
+    let collateral_spk = get_market_script(&params, MarketSlot::UnresolvedCollateral);
+    assert_eq!(
+        labels.get(collateral_spk.as_bytes()),
+        Some(&CovenantScriptLabel::Market {
+            market_id: mid,
+            slot: MarketSlot::UnresolvedCollateral,
+        })
+    );
+    assert_eq!(
+        labels
+            .values()
+            .filter(|label| matches!(label, CovenantScriptLabel::MakerOrder { .. }))
+            .count(),
+        1
+    );
+}
+
 // ==================== Sync Tests ====================
 
 #[test]
@@ -1606,6 +2006,34 @@ fn test_sync_derives_market_state() {
     );
 }
 
+#[test]
+fn test_market_state_confirmations_follow_live_outputs() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let params = test_params();
+    let market_id = ingest_test_market(&mut store, &params);
+    assert_eq!(
+        store.market_state_confirmations(&market_id, 700).unwrap(),
+        None
+    );
+
+    // Test chain UTXOs confirm at height 100.
+    let mut chain = MockChainSource {
+        block_height: 700,
+        ..Default::default()
+    };
+    add_chain_market_state_utxos(&mut chain, &params, MarketState::Unresolved, 0xDD);
+    store.sync(&chain).unwrap();
+
+    assert_eq!(
+        store.market_state_confirmations(&market_id, 700).unwrap(),
+        Some(601)
+    );
+    assert_eq!(
+        store.market_state_confirmations(&market_id, 100).unwrap(),
+        Some(1)
+    );
+}
+
 #[test]
 fn test_sync_derives_resolved_yes_from_terminal_slot() {
     let mut store = DeadcatStore::open_in_memory().unwrap();
@@ -1632,6 +2060,45 @@ fn test_sync_derives_resolved_yes_from_terminal_slot() {
     );
 }
 
+#[test]
+fn test_sync_holds_back_shallow_market_transitions() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    store.set_min_confirmations(3);
+    let params = test_params();
+    let market_id = ingest_test_market(&mut store, &params);
+
+    // Test chain UTXOs confirm at height 100, so this tip gives 2 confirmations.
+    let mut chain = MockChainSource {
+        block_height: 101,
+        ..Default::default()
+    };
+    add_chain_market_state_utxos(&mut chain, &params, MarketState::ResolvedYes, 0xE1);
+
+    let report = store.sync(&chain).unwrap();
+    assert!(report.market_state_changes.is_empty());
+    // The held-back transition's outputs are not tagged to the market yet.
+    assert_eq!(report.new_utxos, 0);
+    assert_eq!(report.tentative_market_states.len(), 1);
+    assert_eq!(
+        report.tentative_market_states[0].new_state,
+        MarketState::ResolvedYes
+    );
+    assert_eq!(
+        store.get_market(&market_id).unwrap().unwrap().state,
+        MarketState::Dormant
+    );
+
+    chain.block_height = 102;
+    let report = store.sync(&chain).unwrap();
+    assert!(report.tentative_market_states.is_empty());
+    assert_eq!(report.new_utxos, 1);
+    assert_eq!(report.market_state_changes.len(), 1);
+    assert_eq!(
+        store.get_market(&market_id).unwrap().unwrap().state,
+        MarketState::ResolvedYes
+    );
+}
+
 #[test]
 fn test_sync_derives_resolved_no_from_terminal_slot() {
     let mut store = DeadcatStore::open_in_memory().unwrap();
@@ -2306,6 +2773,54 @@ fn test_set_market_issuance_data() {
     assert_eq!(issuance.no_blinding_nonce, [0x04; 32]);
 }
 
+#[test]
+fn test_recover_issuance_data_from_creation_tx() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let params = test_params();
+    let market_id = ingest_test_market(&mut store, &params);
+
+    let mut chain = MockChainSource::default();
+    let err = store.recover_issuance_data(&market_id, &chain).unwrap_err();
+    assert!(matches!(err, StoreError::Sync(_)));
+    assert!(
+        store
+            .get_market(&market_id)
+            .unwrap()
+            .unwrap()
+            .issuance
+            .is_none()
+    );
+
+    let specs = test_creation_specs();
+    let creation_tx = build_canonical_creation_tx(&params, specs);
+    chain
+        .transactions
+        .insert(creation_tx.txid().to_byte_array(), serialize(&creation_tx));
+
+    let recovered = store.recover_issuance_data(&market_id, &chain).unwrap();
+    let expected_entropy = |spec: CreationInputSpec| {
+        AssetId::generate_asset_entropy(
+            OutPoint::new(Txid::from_byte_array(spec.prevout_txid), spec.prevout_vout),
+            ContractHash::from_byte_array(spec.contract_hash),
+        )
+        .to_byte_array()
+    };
+    assert_eq!(recovered.yes_entropy, expected_entropy(specs[0]));
+    assert_eq!(recovered.no_entropy, expected_entropy(specs[1]));
+    // Dormant openings from `test_anchor`.
+    assert_eq!(recovered.yes_blinding_nonce, [0x11; 32]);
+    assert_eq!(recovered.no_blinding_nonce, [0x21; 32]);
+
+    let stored = store
+        .get_market(&market_id)
+        .unwrap()
+        .unwrap()
+        .issuance
+        .expect("recovered issuance data should be stored");
+    assert_eq!(stored.yes_entropy, recovered.yes_entropy);
+    assert_eq!(stored.no_blinding_nonce, recovered.no_blinding_nonce);
+}
+
 // ==================== Sync Entropy Extraction Tests ====================
 
 #[test]
@@ -2885,6 +3400,40 @@ fn test_ingest_market_with_metadata_roundtrip() {
     assert_eq!(info.nevent.as_deref(), Some("nevent1qtest"));
 }
 
+#[test]
+fn test_get_market_by_nevent_and_event_id() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let params = test_params();
+
+    let metadata = ContractMetadataInput {
+        nevent: Some("nevent1qtest".to_string()),
+        nostr_event_id: Some("ee".repeat(32)),
+        ..test_market_metadata(&params)
+    };
+    let market_id = ingest_test_market_with_metadata(&mut store, &params, metadata);
+
+    let by_nevent = store.get_market_by_nevent("nevent1qtest").unwrap().unwrap();
+    assert_eq!(by_nevent.market_id, market_id);
+    let by_event = store
+        .get_market_by_event_id(&"ee".repeat(32))
+        .unwrap()
+        .unwrap();
+    assert_eq!(by_event.market_id, market_id);
+
+    assert!(
+        store
+            .get_market_by_nevent("nevent1qother")
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        store
+            .get_market_by_event_id(&"ff".repeat(32))
+            .unwrap()
+            .is_none()
+    );
+}
+
 #[test]
 fn test_ingest_candidate_rejects_invalid_creation_tx_bytes() {
     let mut store = DeadcatStore::open_in_memory().unwrap();
@@ -3255,6 +3804,46 @@ fn test_promoting_second_candidate_after_canonicalization_is_rejected() {
     assert!(err.contains("already has a canonical candidate"));
 }
 
+#[test]
+fn test_search_markets_ranks_by_field_and_requires_every_term() {
+    let mut store = DeadcatStore::open_in_memory().unwrap();
+    let params1 = test_params();
+    let params2 = test_params_2();
+
+    let metadata1 = ContractMetadataInput {
+        question: Some("Will BTC hit 100k?".to_string()),
+        category: Some("Bitcoin".to_string()),
+        ..test_market_metadata(&params1)
+    };
+    let metadata2 = ContractMetadataInput {
+        question: Some("Who wins the election?".to_string()),
+        description: Some("Campaigns may accept bitcoin donations.".to_string()),
+        category: Some("Politics".to_string()),
+        ..test_market_metadata(&params2)
+    };
+    let market1 = ingest_test_market_with_metadata(&mut store, &params1, metadata1);
+    let market2 = ingest_test_market_with_metadata(&mut store, &params2, metadata2);
+
+    let ids = |results: Vec<deadcat_store::MarketInfo>| {
+        results.into_iter().map(|m| m.market_id).collect::<Vec<_>>()
+    };
+    assert_eq!(
+        ids(store.search_markets("BITCOIN").unwrap()),
+        vec![market1, market2]
+    );
+    assert_eq!(
+        ids(store.search_markets("btc 100k").unwrap()),
+        vec![market1]
+    );
+    assert_eq!(
+        ids(store.search_markets("election").unwrap()),
+        vec![market2]
+    );
+    assert!(store.search_markets("btc election").unwrap().is_empty());
+    assert!(store.search_markets("100%").unwrap().is_empty());
+    assert!(store.search_markets("   ").unwrap().is_empty());
+}
+
 #[test]
 fn test_ingest_market_metadata_persists_across_reopen() {
     let dir = tempfile::tempdir().unwrap();
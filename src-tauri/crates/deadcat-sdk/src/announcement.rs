@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 use crate::prediction_market::anchor::PredictionMarketAnchor;
 use crate::prediction_market::params::PredictionMarketParams;
 
-pub const CONTRACT_ANNOUNCEMENT_VERSION: u8 = 4;
+/// Bumped whenever the covenant scripts change, since that moves every
+/// market's CMR and covenant addresses.
+pub const CONTRACT_ANNOUNCEMENT_VERSION: u8 = 5;
 
 /// Off-chain, human-readable fields from the UI create form.
 #[derive(Debug, Clone, Serialize, Deserialize)]
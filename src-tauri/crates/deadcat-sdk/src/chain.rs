@@ -4,6 +4,9 @@ use simplicityhl::elements::{OutPoint, Script, Transaction, TxOut, Txid};
 
 use crate::error::{Error, Result};
 
+/// Most `blockchain.transaction.get` requests sent in one Electrum batch.
+const MAX_TX_BATCH: usize = 50;
+
 /// Backend for interacting with the Liquid blockchain.
 pub trait ChainBackend {
     /// Return the current best block height.
@@ -18,6 +21,14 @@ pub trait ChainBackend {
     /// Fetch a transaction by its txid.
     fn fetch_transaction(&self, txid: &Txid) -> Result<Transaction>;
 
+    /// Fetch several transactions, returned in the order of `txids`.
+    fn fetch_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        txids
+            .iter()
+            .map(|txid| self.fetch_transaction(txid))
+            .collect()
+    }
+
     /// Return the confirmed block height for a transaction, if known.
     fn transaction_height(&self, txid: &Txid) -> Result<Option<u32>>;
 
@@ -28,12 +39,15 @@ pub trait ChainBackend {
 /// Electrum-based chain backend for Liquid.
 pub struct ElectrumBackend {
     electrum_url: String,
+    /// Socket timeout for each Electrum connection; `None` waits indefinitely.
+    timeout: Option<Duration>,
 }
 
 impl ElectrumBackend {
     pub fn new(electrum_url: &str) -> Self {
         Self {
             electrum_url: electrum_url.to_string(),
+            timeout: None,
         }
     }
 
@@ -41,12 +55,84 @@ impl ElectrumBackend {
         &self.electrum_url
     }
 
+    /// Set the socket timeout used by connections opened after this call.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Electrum takes whole seconds in a `u8`; round up and saturate.
+    fn timeout_secs(&self) -> Option<u8> {
+        self.timeout.map(|timeout| {
+            let secs = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+            u8::try_from(secs.max(1)).unwrap_or(u8::MAX)
+        })
+    }
+
+    fn client(&self) -> std::result::Result<electrum_client::Client, electrum_client::Error> {
+        let config = electrum_client::ConfigBuilder::new()
+            .timeout(self.timeout_secs())
+            .build();
+        electrum_client::Client::from_config(&self.electrum_url, config)
+    }
+
+    /// LWK client for wallet scans, fetches and broadcasts.
+    pub(crate) fn lwk_client(&self) -> Result<lwk_wollet::ElectrumClient> {
+        let url: lwk_wollet::ElectrumUrl = self
+            .electrum_url
+            .parse()
+            .map_err(|e| Error::Electrum(format!("{:?}", e)))?;
+        let options = lwk_wollet::ElectrumOptions {
+            timeout: self.timeout_secs(),
+        };
+        lwk_wollet::ElectrumClient::with_options(&url, options)
+            .map_err(|e| Error::Electrum(e.to_string()))
+    }
+
     fn is_transient_missing_tx_error(msg: &str) -> bool {
         let lower = msg.to_ascii_lowercase();
         lower.contains("missing transaction")
             || lower.contains("no such mempool or blockchain transaction")
     }
 
+    /// Run `fetch`, retrying while the server doesn't know a transaction yet
+    /// (a broadcast that hasn't reached its mempool).
+    fn retry_missing_tx<T>(
+        what: &str,
+        mut fetch: impl FnMut() -> std::result::Result<T, String>,
+    ) -> Result<T> {
+        const MAX_ATTEMPTS: usize = 10;
+        const RETRY_DELAY: Duration = Duration::from_millis(350);
+
+        for attempt in 0..MAX_ATTEMPTS {
+            match fetch() {
+                Ok(value) => return Ok(value),
+                Err(msg) => {
+                    let can_retry =
+                        attempt + 1 < MAX_ATTEMPTS && Self::is_transient_missing_tx_error(&msg);
+                    if can_retry {
+                        std::thread::sleep(RETRY_DELAY);
+                        continue;
+                    }
+                    return Err(Error::Electrum(msg));
+                }
+            }
+        }
+
+        Err(Error::Electrum(format!(
+            "failed to fetch {what} after {MAX_ATTEMPTS} attempts"
+        )))
+    }
+
+    /// Whether a broadcast rejection means one of the inputs is already spent
+    /// (or unknown to the node), as opposed to a fee or policy failure.
+    pub(crate) fn is_input_conflict_error(msg: &str) -> bool {
+        let lower = msg.to_ascii_lowercase();
+        lower.contains("missingorspent")
+            || lower.contains("missing-inputs")
+            || lower.contains("txn-mempool-conflict")
+            || lower.contains("spends-conflicting-tx")
+    }
+
     fn script_hash_hex(script_pubkey: &[u8]) -> String {
         use sha2::{Digest, Sha256};
 
@@ -60,8 +146,7 @@ impl ChainBackend for ElectrumBackend {
     fn best_block_height(&self) -> Result<u32> {
         use electrum_client::ElectrumApi;
 
-        let client = electrum_client::Client::new(&self.electrum_url)
-            .map_err(|e| Error::Electrum(e.to_string()))?;
+        let client = self.client().map_err(|e| Error::Electrum(e.to_string()))?;
         let resp = client
             .raw_call("blockchain.headers.subscribe", [])
             .map_err(|e| Error::Electrum(e.to_string()))?;
@@ -76,7 +161,8 @@ impl ChainBackend for ElectrumBackend {
 
         let btc_script = lwk_wollet::bitcoin::ScriptBuf::from(script_pubkey.to_bytes());
 
-        let client = electrum_client::Client::new(&self.electrum_url)
+        let client = self
+            .client()
             .map_err(|e| Error::CovenantScan(e.to_string()))?;
 
         let script_hash_hex = Self::script_hash_hex(btc_script.as_bytes());
@@ -124,7 +210,8 @@ impl ChainBackend for ElectrumBackend {
 
         let btc_script = lwk_wollet::bitcoin::ScriptBuf::from(script_pubkey.to_bytes());
 
-        let client = electrum_client::Client::new(&self.electrum_url)
+        let client = self
+            .client()
             .map_err(|e| Error::CovenantScan(e.to_string()))?;
 
         let script_hash_hex = Self::script_hash_hex(btc_script.as_bytes());
@@ -156,47 +243,68 @@ impl ChainBackend for ElectrumBackend {
     fn fetch_transaction(&self, txid: &Txid) -> Result<Transaction> {
         use lwk_wollet::blocking::BlockchainBackend;
 
-        let url: lwk_wollet::ElectrumUrl = self
-            .electrum_url
-            .parse()
-            .map_err(|e| Error::Electrum(format!("{:?}", e)))?;
-        let client =
-            lwk_wollet::ElectrumClient::new(&url).map_err(|e| Error::Electrum(e.to_string()))?;
+        let client = self.lwk_client()?;
+        Self::retry_missing_tx(&format!("transaction {txid}"), || {
+            client.get_transactions(&[*txid]).map_err(|e| e.to_string())
+        })?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::Query(format!("transaction {} not found", txid)))
+    }
 
-        const MAX_ATTEMPTS: usize = 10;
-        const RETRY_DELAY: Duration = Duration::from_millis(350);
+    /// Fetch all of `txids` over one connection, in Electrum batches of at
+    /// most [`MAX_TX_BATCH`] requests.
+    fn fetch_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        use electrum_client::ElectrumApi;
 
-        for attempt in 0..MAX_ATTEMPTS {
-            match client.get_transactions(&[*txid]) {
-                Ok(txs) => {
-                    return txs
-                        .into_iter()
-                        .next()
-                        .ok_or_else(|| Error::Query(format!("transaction {} not found", txid)));
-                }
-                Err(err) => {
-                    let msg = err.to_string();
-                    let can_retry =
-                        attempt + 1 < MAX_ATTEMPTS && Self::is_transient_missing_tx_error(&msg);
-                    if can_retry {
-                        std::thread::sleep(RETRY_DELAY);
-                        continue;
-                    }
-                    return Err(Error::Electrum(msg));
+        if txids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let client = self.client().map_err(|e| Error::Electrum(e.to_string()))?;
+        let mut txs = Vec::with_capacity(txids.len());
+        for chunk in txids.chunks(MAX_TX_BATCH) {
+            let mut batch = electrum_client::Batch::default();
+            for txid in chunk {
+                batch.raw(
+                    "blockchain.transaction.get".into(),
+                    vec![electrum_client::Param::String(txid.to_string())],
+                );
+            }
+
+            let resp = Self::retry_missing_tx(&format!("{} transactions", chunk.len()), || {
+                client.batch_call(&batch).map_err(|e| e.to_string())
+            })?;
+            if resp.len() != chunk.len() {
+                return Err(Error::Electrum(format!(
+                    "expected {} transactions, got {}",
+                    chunk.len(),
+                    resp.len()
+                )));
+            }
+            for (txid, raw) in chunk.iter().zip(resp) {
+                let raw_hex = raw
+                    .as_str()
+                    .ok_or_else(|| Error::Query(format!("transaction {txid} not found")))?;
+                let raw_tx = hex::decode(raw_hex)
+                    .map_err(|e| Error::Electrum(format!("bad transaction {txid} hex: {e}")))?;
+                let tx: Transaction = simplicityhl::elements::encode::deserialize(&raw_tx)
+                    .map_err(|e| Error::Electrum(format!("bad transaction {txid}: {e}")))?;
+                if tx.txid() != *txid {
+                    return Err(Error::Electrum(format!(
+                        "server returned transaction {} for {txid}",
+                        tx.txid()
+                    )));
                 }
+                txs.push(tx);
             }
         }
-
-        Err(Error::Electrum(format!(
-            "failed to fetch transaction {txid} after {MAX_ATTEMPTS} attempts"
-        )))
+        Ok(txs)
     }
 
     fn transaction_height(&self, txid: &Txid) -> Result<Option<u32>> {
         use electrum_client::ElectrumApi;
 
-        let client = electrum_client::Client::new(&self.electrum_url)
-            .map_err(|e| Error::Electrum(e.to_string()))?;
+        let client = self.client().map_err(|e| Error::Electrum(e.to_string()))?;
         let tx = self.fetch_transaction(txid)?;
         let first_output = tx
             .output
@@ -232,12 +340,7 @@ impl ChainBackend for ElectrumBackend {
     fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
         use lwk_wollet::blocking::BlockchainBackend;
 
-        let url: lwk_wollet::ElectrumUrl = self
-            .electrum_url
-            .parse()
-            .map_err(|e| Error::Electrum(format!("{:?}", e)))?;
-        let client =
-            lwk_wollet::ElectrumClient::new(&url).map_err(|e| Error::Electrum(e.to_string()))?;
+        let client = self.lwk_client()?;
         client
             .broadcast(tx)
             .map_err(|e| Error::Broadcast(e.to_string()))
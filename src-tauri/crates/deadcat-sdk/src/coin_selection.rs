@@ -0,0 +1,194 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// Excess over the fee, in sats, that [`CoinSelection::ExactMatchOrLargest`]
+/// treats as an exact match.
+pub const EXACT_MATCH_WINDOW_SATS: u64 = 1_000;
+
+/// How a covenant operation picks the wallet UTXO that pays its fee.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CoinSelection {
+    /// Smallest UTXO that covers the fee, leaving large UTXOs intact.
+    #[default]
+    SmallestFirst,
+    /// Largest UTXO, consolidating value instead of splitting off small
+    /// change from mid-sized UTXOs.
+    LargestFirst,
+    /// Smallest UTXO within [`EXACT_MATCH_WINDOW_SATS`] of the fee, so no
+    /// meaningful change is created; without such a UTXO, the largest.
+    /// Covenant transactions spend a single fee UTXO, so only single UTXOs
+    /// are considered.
+    ExactMatchOrLargest,
+}
+
+impl CoinSelection {
+    pub const ALL: [CoinSelection; 3] = [
+        CoinSelection::SmallestFirst,
+        CoinSelection::LargestFirst,
+        CoinSelection::ExactMatchOrLargest,
+    ];
+
+    /// Name this strategy was stored under before it was renamed.
+    const LEGACY_EXACT_MATCH_NAME: &'static str = "branch_and_bound";
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CoinSelection::SmallestFirst => "smallest_first",
+            CoinSelection::LargestFirst => "largest_first",
+            CoinSelection::ExactMatchOrLargest => "exact_match_or_largest",
+        }
+    }
+
+    /// Index of the value in `values` to spend for a `target` fee, or `None`
+    /// when no value covers it.
+    pub fn select(self, values: &[u64], target: u64) -> Option<usize> {
+        let sufficient = values
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, value)| *value >= target);
+        match self {
+            CoinSelection::SmallestFirst => sufficient.min_by_key(|(_, value)| *value),
+            CoinSelection::LargestFirst => sufficient.max_by_key(|(_, value)| *value),
+            CoinSelection::ExactMatchOrLargest => {
+                let candidates: Vec<_> = sufficient.collect();
+                candidates
+                    .iter()
+                    .filter(|(_, value)| value - target <= EXACT_MATCH_WINDOW_SATS)
+                    .min_by_key(|(_, value)| *value)
+                    .or_else(|| candidates.iter().max_by_key(|(_, value)| *value))
+                    .copied()
+            }
+        }
+        .map(|(index, _)| index)
+    }
+}
+
+impl fmt::Display for CoinSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CoinSelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == CoinSelection::LEGACY_EXACT_MATCH_NAME {
+            return Ok(CoinSelection::ExactMatchOrLargest);
+        }
+        CoinSelection::ALL
+            .into_iter()
+            .find(|strategy| strategy.as_str() == s)
+            .ok_or_else(|| Error::Query(format!("unknown coin selection strategy: {s}")))
+    }
+}
+
+/// Wallet operations that pay their fee from a separately selected UTXO,
+/// each with its own [`CoinSelection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeeOperation {
+    IssueTokens,
+    CancelTokens,
+    ResolveMarket,
+    RedeemTokens,
+    RedeemExpired,
+    CreateLimitOrder,
+    CancelLimitOrder,
+    FillLimitOrder,
+    ConsolidateUtxos,
+}
+
+impl FeeOperation {
+    pub const ALL: [FeeOperation; 9] = [
+        FeeOperation::IssueTokens,
+        FeeOperation::CancelTokens,
+        FeeOperation::ResolveMarket,
+        FeeOperation::RedeemTokens,
+        FeeOperation::RedeemExpired,
+        FeeOperation::CreateLimitOrder,
+        FeeOperation::CancelLimitOrder,
+        FeeOperation::FillLimitOrder,
+        FeeOperation::ConsolidateUtxos,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FeeOperation::IssueTokens => "issue_tokens",
+            FeeOperation::CancelTokens => "cancel_tokens",
+            FeeOperation::ResolveMarket => "resolve_market",
+            FeeOperation::RedeemTokens => "redeem_tokens",
+            FeeOperation::RedeemExpired => "redeem_expired",
+            FeeOperation::CreateLimitOrder => "create_limit_order",
+            FeeOperation::CancelLimitOrder => "cancel_limit_order",
+            FeeOperation::FillLimitOrder => "fill_limit_order",
+            FeeOperation::ConsolidateUtxos => "consolidate_utxos",
+        }
+    }
+}
+
+impl fmt::Display for FeeOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FeeOperation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        FeeOperation::ALL
+            .into_iter()
+            .find(|operation| operation.as_str() == s)
+            .ok_or_else(|| Error::Query(format!("unknown fee operation: {s}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: [u64; 4] = [400, 5_000, 1_800, 90_000];
+
+    #[test]
+    fn strategies_pick_expected_utxo() {
+        assert_eq!(CoinSelection::SmallestFirst.select(&VALUES, 500), Some(2));
+        assert_eq!(CoinSelection::LargestFirst.select(&VALUES, 500), Some(3));
+        // 1_800 is within the exact-match window of a 1_000 fee.
+        assert_eq!(
+            CoinSelection::ExactMatchOrLargest.select(&VALUES, 1_000),
+            Some(2)
+        );
+        // Nothing within the window of 3_000: fall back to the largest.
+        assert_eq!(
+            CoinSelection::ExactMatchOrLargest.select(&VALUES, 3_000),
+            Some(3)
+        );
+        for strategy in CoinSelection::ALL {
+            assert_eq!(strategy.select(&VALUES, 100_000), None);
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for strategy in CoinSelection::ALL {
+            assert_eq!(
+                strategy.as_str().parse::<CoinSelection>().unwrap(),
+                strategy
+            );
+        }
+        for operation in FeeOperation::ALL {
+            assert_eq!(
+                operation.as_str().parse::<FeeOperation>().unwrap(),
+                operation
+            );
+        }
+        assert_eq!(
+            "branch_and_bound".parse::<CoinSelection>().unwrap(),
+            CoinSelection::ExactMatchOrLargest
+        );
+        assert!("random".parse::<CoinSelection>().is_err());
+    }
+}
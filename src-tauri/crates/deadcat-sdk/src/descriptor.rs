@@ -0,0 +1,103 @@
+use std::fmt;
+use std::str::FromStr;
+
+use lwk_signer::SwSigner;
+use lwk_wollet::WolletDescriptor;
+
+use crate::error::{Error, Result};
+
+/// Script shape of the wallet's receive/change descriptor.
+///
+/// Every template blinds with the signer's SLIP-77 master key: covenant
+/// flows re-derive change blinding keys from it, so only the spending script
+/// is configurable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DescriptorTemplate {
+    /// `ct(slip77(..),elwpkh(xpub/*))` — native segwit.
+    #[default]
+    Wpkh,
+    /// `ct(slip77(..),elsh(wpkh(xpub/*)))` — segwit nested in P2SH.
+    ShWpkh,
+}
+
+impl DescriptorTemplate {
+    pub const ALL: [DescriptorTemplate; 2] = [DescriptorTemplate::Wpkh, DescriptorTemplate::ShWpkh];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DescriptorTemplate::Wpkh => "wpkh",
+            DescriptorTemplate::ShWpkh => "sh_wpkh",
+        }
+    }
+
+    /// Build the confidential descriptor for `signer`'s account xpub.
+    pub fn descriptor(self, signer: &SwSigner) -> Result<WolletDescriptor> {
+        let slip77_key = signer
+            .slip77_master_blinding_key()
+            .map_err(|e| Error::Signer(e.to_string()))?;
+        let xpub = signer.xpub();
+        let descriptor_str = match self {
+            DescriptorTemplate::Wpkh => format!("ct(slip77({slip77_key}),elwpkh({xpub}/*))"),
+            DescriptorTemplate::ShWpkh => {
+                format!("ct(slip77({slip77_key}),elsh(wpkh({xpub}/*)))")
+            }
+        };
+        descriptor_str
+            .parse()
+            .map_err(|e: lwk_wollet::Error| Error::Descriptor(e.to_string()))
+    }
+
+    /// Check that the template compiles to a wallet descriptor for `mnemonic`.
+    ///
+    /// Callers persisting a template alongside a wallet run this first so a
+    /// later unlock can't fail on a descriptor that never worked.
+    pub fn validate(self, mnemonic: &str, is_mainnet: bool) -> Result<()> {
+        let signer =
+            SwSigner::new(mnemonic, is_mainnet).map_err(|e| Error::Signer(e.to_string()))?;
+        self.descriptor(&signer).map(|_| ())
+    }
+}
+
+impl fmt::Display for DescriptorTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DescriptorTemplate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        DescriptorTemplate::ALL
+            .into_iter()
+            .find(|template| template.as_str() == s)
+            .ok_or_else(|| Error::Descriptor(format!("unknown descriptor template: {s}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn every_template_compiles_and_round_trips() {
+        for template in DescriptorTemplate::ALL {
+            template.validate(MNEMONIC, false).unwrap();
+            assert_eq!(
+                template.as_str().parse::<DescriptorTemplate>().unwrap(),
+                template
+            );
+        }
+        assert!("tr".parse::<DescriptorTemplate>().is_err());
+    }
+
+    #[test]
+    fn templates_produce_distinct_descriptors() {
+        let signer = SwSigner::new(MNEMONIC, false).unwrap();
+        let wpkh = DescriptorTemplate::Wpkh.descriptor(&signer).unwrap();
+        let sh_wpkh = DescriptorTemplate::ShWpkh.descriptor(&signer).unwrap();
+        assert_ne!(wpkh.to_string(), sh_wpkh.to_string());
+    }
+}
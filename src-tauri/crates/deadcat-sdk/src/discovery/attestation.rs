@@ -26,6 +26,17 @@ pub struct AttestationResult {
     pub nostr_event_id: String,
 }
 
+/// An attestation whose signature was checked against the market's oracle key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifiedAttestation {
+    pub market_id: String,
+    pub outcome_yes: bool,
+    pub signature_hex: String,
+    pub nostr_event_id: String,
+    /// Unix time of the attestation event.
+    pub signed_at: u64,
+}
+
 /// Build a Nostr event for an oracle attestation.
 pub fn build_attestation_event(
     keys: &Keys,
@@ -133,6 +144,28 @@ pub fn sign_attestation(
     Ok((sig.serialize(), msg))
 }
 
+/// Check that `content` carries `oracle_public_key`'s signature over the
+/// oracle message for `market_id` and the attested outcome.
+pub fn verify_attestation(
+    content: &AttestationContent,
+    market_id: &MarketId,
+    oracle_public_key: &[u8; 32],
+) -> Result<(), String> {
+    if content.market_id != hex::encode(market_id.as_bytes()) {
+        return Err("attestation is for a different market".to_string());
+    }
+    let sig_bytes = hex::decode(&content.oracle_signature)
+        .map_err(|e| format!("invalid attestation signature hex: {e}"))?;
+    let sig = secp256k1::schnorr::Signature::from_slice(&sig_bytes)
+        .map_err(|e| format!("invalid attestation signature: {e}"))?;
+    let pubkey = secp256k1::XOnlyPublicKey::from_slice(oracle_public_key)
+        .map_err(|e| format!("invalid oracle public key: {e}"))?;
+    let message = secp256k1::Message::from_digest(oracle_message(market_id, content.outcome_yes));
+    secp256k1::Secp256k1::verification_only()
+        .verify_schnorr(&sig, &message, &pubkey)
+        .map_err(|_| "attestation signature does not match the oracle key".to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,4 +229,36 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    fn signed_content(keys: &Keys, market_id: &MarketId, outcome_yes: bool) -> AttestationContent {
+        let (sig, msg) = sign_attestation(keys, market_id, outcome_yes).unwrap();
+        AttestationContent {
+            market_id: hex::encode(market_id.as_bytes()),
+            outcome_yes,
+            oracle_signature: hex::encode(sig),
+            message: hex::encode(msg),
+        }
+    }
+
+    #[test]
+    fn verify_attestation_accepts_only_the_oracle_signature() {
+        let oracle = Keys::generate();
+        let oracle_pubkey: [u8; 32] = hex::decode(oracle.public_key().to_hex())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let market_id = MarketId([0xab; 32]);
+
+        let content = signed_content(&oracle, &market_id, true);
+        assert!(verify_attestation(&content, &market_id, &oracle_pubkey).is_ok());
+
+        let forged = signed_content(&Keys::generate(), &market_id, true);
+        assert!(verify_attestation(&forged, &market_id, &oracle_pubkey).is_err());
+
+        let mut flipped = content.clone();
+        flipped.outcome_yes = false;
+        assert!(verify_attestation(&flipped, &market_id, &oracle_pubkey).is_err());
+
+        assert!(verify_attestation(&content, &MarketId([0xcd; 32]), &oracle_pubkey).is_err());
+    }
 }
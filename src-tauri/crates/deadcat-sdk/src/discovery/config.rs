@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use super::DEFAULT_RELAYS;
+use crate::network::Network;
 
 /// Configuration for the `DiscoveryService`.
 #[derive(Debug, Clone)]
@@ -11,6 +12,23 @@ pub struct DiscoveryConfig {
     pub network_tag: String,
     /// Timeout for one-shot fetch operations.
     pub fetch_timeout: Duration,
+    /// Per-relay timeout when publishing an event.
+    pub publish_timeout: Duration,
+}
+
+impl DiscoveryConfig {
+    /// Default relays and network tag for `network`.
+    pub fn for_network(network: Network) -> Self {
+        Self {
+            relays: network
+                .default_relays()
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            network_tag: network.discovery_tag().to_string(),
+            ..Default::default()
+        }
+    }
 }
 
 impl Default for DiscoveryConfig {
@@ -19,6 +37,7 @@ impl Default for DiscoveryConfig {
             relays: DEFAULT_RELAYS.iter().map(|s| s.to_string()).collect(),
             network_tag: super::NETWORK_TAG.to_string(),
             fetch_timeout: Duration::from_secs(15),
+            publish_timeout: super::RELAY_PUBLISH_TIMEOUT,
         }
     }
 }
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use nostr_sdk::prelude::*;
+
+use super::pool::event_identifier_tag;
+
+/// Nostr event kind for deletion requests (NIP-09).
+pub const DELETION_KIND: Kind = Kind::Custom(5);
+
+/// NIP-33 `kind:pubkey:d` coordinate of a parameterized replaceable event.
+pub fn event_coordinate(event: &Event) -> Option<String> {
+    let d_tag = event_identifier_tag(event)?;
+    Some(format!(
+        "{}:{}:{}",
+        event.kind.as_u16(),
+        event.pubkey,
+        d_tag
+    ))
+}
+
+/// Build a NIP-09 deletion request for one of `keys`' own events.
+///
+/// Tags the event's `a` coordinate as well as its id, so relays also drop
+/// earlier versions of a replaceable announcement.
+pub fn build_deletion_event(keys: &Keys, event: &Event, reason: &str) -> Result<Event, String> {
+    if event.pubkey != keys.public_key() {
+        return Err(format!(
+            "cannot delete event {}: it was published by another key",
+            event.id
+        ));
+    }
+
+    let mut tags = vec![
+        Tag::event(event.id),
+        Tag::custom(TagKind::custom("k"), vec![event.kind.as_u16().to_string()]),
+    ];
+    if let Some(coordinate) = event_coordinate(event) {
+        tags.push(Tag::custom(TagKind::custom("a"), vec![coordinate]));
+    }
+
+    EventBuilder::new(DELETION_KIND, reason)
+        .tags(tags)
+        .sign_with_keys(keys)
+        .map_err(|e| format!("failed to build deletion event: {e}"))
+}
+
+/// Build a Nostr filter for deletion requests published by `authors`.
+pub fn build_deletion_filter(authors: impl IntoIterator<Item = PublicKey>) -> Filter {
+    Filter::new().kind(DELETION_KIND).authors(authors)
+}
+
+/// Keep only the newest version of each parameterized replaceable event.
+///
+/// Relays implementing NIP-33 already do this; the client-side pass covers
+/// those that keep every version. Ties on `created_at` go to the lowest id.
+pub fn latest_replaceable(events: Vec<Event>) -> Vec<Event> {
+    let mut latest: HashMap<String, (Timestamp, EventId)> = HashMap::new();
+    for event in &events {
+        let Some(coordinate) = event_coordinate(event) else {
+            continue;
+        };
+        let candidate = (event.created_at, event.id);
+        latest
+            .entry(coordinate)
+            .and_modify(|current| {
+                if candidate.0 > current.0 || (candidate.0 == current.0 && candidate.1 < current.1)
+                {
+                    *current = candidate;
+                }
+            })
+            .or_insert(candidate);
+    }
+
+    events
+        .into_iter()
+        .filter(|event| match event_coordinate(event) {
+            Some(coordinate) => latest.get(&coordinate).map(|(_, id)| *id) == Some(event.id),
+            None => true,
+        })
+        .collect()
+}
+
+/// Drop events their author has asked to delete.
+///
+/// Relays may ignore NIP-09 requests, so discovery applies them itself. A
+/// request naming an event id removes that event; one naming an `a`
+/// coordinate removes versions created at or before the request.
+pub fn apply_deletions(events: Vec<Event>, deletions: &[Event]) -> Vec<Event> {
+    events
+        .into_iter()
+        .filter(|event| !deletions.iter().any(|deletion| deletes(deletion, event)))
+        .collect()
+}
+
+fn deletes(deletion: &Event, event: &Event) -> bool {
+    if deletion.kind != DELETION_KIND || deletion.pubkey != event.pubkey {
+        return false;
+    }
+    let event_id = event.id.to_hex();
+    let coordinate = event_coordinate(event);
+    deletion.tags.iter().any(|tag| {
+        let fields = tag.as_slice();
+        if fields.len() < 2 {
+            return false;
+        }
+        match fields[0].as_str() {
+            "e" => fields[1] == event_id,
+            "a" => {
+                coordinate.as_deref() == Some(fields[1].as_str())
+                    && deletion.created_at >= event.created_at
+            }
+            _ => false,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn announcement(keys: &Keys, d_tag: &str, created_at: u64) -> Event {
+        EventBuilder::new(Kind::Custom(30078), "{}")
+            .tags(vec![Tag::identifier(d_tag)])
+            .custom_created_at(Timestamp::from(created_at))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    fn deletion_at(keys: &Keys, created_at: u64, tag: Tag) -> Event {
+        EventBuilder::new(DELETION_KIND, "")
+            .tags(vec![tag])
+            .custom_created_at(Timestamp::from(created_at))
+            .sign_with_keys(keys)
+            .unwrap()
+    }
+
+    #[test]
+    fn newest_replaceable_version_wins() {
+        let keys = Keys::generate();
+        let old = announcement(&keys, "order-1", 100);
+        let new = announcement(&keys, "order-1", 200);
+        let other = announcement(&keys, "order-2", 50);
+
+        let kept = latest_replaceable(vec![new.clone(), old, other.clone()]);
+        assert_eq!(kept, vec![new, other]);
+    }
+
+    #[test]
+    fn deletion_request_removes_own_events_only() {
+        let keys = Keys::generate();
+        let target = announcement(&keys, "order-1", 100);
+        let deletion = build_deletion_event(&keys, &target, "cancelled").unwrap();
+        assert!(apply_deletions(vec![target.clone()], &[deletion]).is_empty());
+
+        let stranger = Keys::generate();
+        assert!(build_deletion_event(&stranger, &target, "cancelled").is_err());
+        let forged = deletion_at(&stranger, 300, Tag::event(target.id));
+        assert_eq!(
+            apply_deletions(vec![target.clone()], &[forged]),
+            vec![target]
+        );
+    }
+
+    #[test]
+    fn coordinate_deletion_spares_later_versions() {
+        let keys = Keys::generate();
+        let old = announcement(&keys, "order-1", 100);
+        let republished = announcement(&keys, "order-1", 300);
+        let coordinate = event_coordinate(&old).unwrap();
+        let deletion = deletion_at(
+            &keys,
+            200,
+            Tag::custom(TagKind::custom("a"), vec![coordinate]),
+        );
+
+        assert_eq!(
+            apply_deletions(vec![old, republished.clone()], &[deletion]),
+            vec![republished]
+        );
+    }
+}
@@ -7,7 +7,7 @@ use crate::network::Network;
 use crate::prediction_market::anchor::{PredictionMarketAnchor, parse_prediction_market_anchor};
 use crate::prediction_market_scan::validate_prediction_market_creation_tx;
 
-use super::{APP_EVENT_KIND, CONTRACT_TAG, DEFAULT_RELAYS, bytes_to_hex};
+use super::{APP_EVENT_KIND, CONTRACT_TAG, bytes_to_hex};
 
 /// What the frontend receives — maps to existing Market type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -134,7 +134,7 @@ pub(crate) fn parse_announcement_event_with_ingest(
     event: &Event,
     expected_network_tag: &str,
 ) -> Result<ParsedDiscoveredMarketAnnouncement, String> {
-    expected_network_tag
+    let network = expected_network_tag
         .parse::<Network>()
         .map_err(|e| format!("unsupported network tag '{expected_network_tag}': {e}"))?;
     let network_tag = event_network_tag(event)
@@ -157,9 +157,12 @@ pub(crate) fn parse_announcement_event_with_ingest(
     let params = &announcement.contract_params;
     let market_id = params.market_id();
 
-    let nevent = Nip19Event::new(event.id, DEFAULT_RELAYS.iter().map(|r| r.to_string()))
-        .to_bech32()
-        .unwrap_or_default();
+    let nevent = Nip19Event::new(
+        event.id,
+        network.default_relays().iter().map(|r| r.to_string()),
+    )
+    .to_bech32()
+    .unwrap_or_default();
 
     let metadata = ContractMetadataInput {
         question: Some(announcement.metadata.question.clone()),
@@ -6,12 +6,15 @@
 
 pub(crate) mod attestation;
 pub(crate) mod config;
+pub(crate) mod deletion;
 pub(crate) mod events;
 pub(crate) mod market;
 pub(crate) mod pool;
+pub(crate) mod publish;
 pub(crate) mod service;
 pub(crate) mod store_trait;
 
+use std::collections::HashSet;
 use std::time::Duration;
 
 use nostr_sdk::prelude::*;
@@ -40,10 +43,14 @@ pub const ATTESTATION_TAG: &str = "deadcat-attestation";
 /// Tag value identifying a deadcat pool announcement.
 pub const POOL_TAG: &str = "deadcat-pool";
 
-/// Network tag value for Liquid Testnet.
-pub const NETWORK_TAG: &str = "liquid-testnet";
+/// Network tag of the default network, Liquid Testnet.
+///
+/// Nodes tag events with [`Network::discovery_tag`](crate::Network::discovery_tag)
+/// for the network they run on.
+pub const NETWORK_TAG: &str = crate::Network::LiquidTestnet.discovery_tag();
 
-/// Default relay URLs.
+/// Public relay URLs used by mainnet and testnet; see
+/// [`Network::default_relays`](crate::Network::default_relays).
 pub const DEFAULT_RELAYS: &[&str] = &["wss://relay.damus.io", "wss://relay.primal.net"];
 
 // ---------------------------------------------------------------------------
@@ -59,8 +66,17 @@ pub use market::{
 // ---------------------------------------------------------------------------
 
 pub use attestation::{
-    AttestationContent, AttestationResult, build_attestation_event, build_attestation_filter,
-    sign_attestation,
+    AttestationContent, AttestationResult, VerifiedAttestation, build_attestation_event,
+    build_attestation_filter, parse_attestation_event, sign_attestation, verify_attestation,
+};
+
+// ---------------------------------------------------------------------------
+// Re-exports: deletion
+// ---------------------------------------------------------------------------
+
+pub use deletion::{
+    DELETION_KIND, apply_deletions, build_deletion_event, build_deletion_filter, event_coordinate,
+    latest_replaceable,
 };
 
 // ---------------------------------------------------------------------------
@@ -69,6 +85,15 @@ pub use attestation::{
 
 pub use pool::{DiscoveredPool, PoolAnnouncement, PoolParams, build_pool_event};
 
+// ---------------------------------------------------------------------------
+// Re-exports: publish
+// ---------------------------------------------------------------------------
+
+pub use publish::{
+    MAX_PUBLISH_ATTEMPTS, PUBLISH_QUORUM, PublishRecord, PublishReport, PublishStatus,
+    RELAY_PUBLISH_TIMEOUT, RelayRejection, publish_event, publish_event_with_timeout,
+};
+
 // ---------------------------------------------------------------------------
 // Re-exports: config, events, service, store_trait
 // ---------------------------------------------------------------------------
@@ -155,13 +180,18 @@ pub fn build_order_event(
     network_tag: &str,
 ) -> Result<Event, String> {
     parse_network_tag(network_tag)?;
-    let order_uid_hex = &announcement.market_id;
+    // Unique per order so NIP-33 replacement only supersedes this order's
+    // own earlier announcements.
+    let order_uid = format!(
+        "{}:{}:{}",
+        announcement.market_id, announcement.maker_base_pubkey, announcement.order_nonce
+    );
 
     let content =
         serde_json::to_string(announcement).map_err(|e| format!("failed to serialize: {e}"))?;
 
     let tags = vec![
-        Tag::identifier(order_uid_hex),
+        Tag::identifier(&order_uid),
         Tag::hashtag(ORDER_TAG),
         Tag::hashtag(&announcement.market_id),
         Tag::custom(TagKind::custom("network"), vec![network_tag.to_string()]),
@@ -243,8 +273,9 @@ pub fn parse_order_event(
 // Relay interaction helpers
 // ---------------------------------------------------------------------------
 
-/// Connect a Nostr client to the default relays (or a custom one).
-pub async fn connect_client(relay_url: Option<&str>) -> Result<Client, String> {
+/// Connect a Nostr client to the default relays (or a custom one), waiting
+/// up to `timeout` for the connections.
+pub async fn connect_client(relay_url: Option<&str>, timeout: Duration) -> Result<Client, String> {
     let client = Client::default();
     if let Some(url) = relay_url {
         client
@@ -259,27 +290,71 @@ pub async fn connect_client(relay_url: Option<&str>) -> Result<Client, String> {
                 .map_err(|e| format!("failed to add relay {url}: {e}"))?;
         }
     }
-    client.connect().await;
+    client.connect_with_timeout(timeout).await;
     Ok(client)
 }
 
-/// Publish an event to the connected relays.
-pub async fn publish_event(client: &Client, event: Event) -> Result<EventId, String> {
-    let output = client
-        .send_event(event)
+/// Fetch events matching `filter` from `client`'s relays plus `extra_relays`.
+///
+/// Extra relays the client isn't already using are queried through a
+/// short-lived client so they don't join its pool; `timeout` bounds both
+/// connecting to them and the fetch. A failure there is only
+/// logged. Events are deduplicated by id.
+pub async fn fetch_events_with_relays(
+    client: &Client,
+    filter: Filter,
+    extra_relays: &[String],
+    timeout: Duration,
+) -> Result<Vec<Event>, String> {
+    let mut events: Vec<Event> = client
+        .fetch_events(vec![filter.clone()], timeout)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .collect();
+
+    let connected: HashSet<String> = client
+        .relays()
         .await
-        .map_err(|e| format!("failed to send event: {e}"))?;
-    Ok(*output.id())
+        .keys()
+        .map(|url| url.to_string().trim_end_matches('/').to_string())
+        .collect();
+    let extra: Vec<&str> = extra_relays
+        .iter()
+        .map(|url| url.trim_end_matches('/'))
+        .filter(|url| !connected.contains(*url))
+        .collect();
+    if extra.is_empty() {
+        return Ok(events);
+    }
+
+    let extra_client = Client::default();
+    for url in &extra {
+        if let Err(e) = extra_client.add_relay(*url).await {
+            log::warn!("skipping relay {url}: {e}");
+        }
+    }
+    extra_client.connect_with_timeout(timeout).await;
+    match extra_client.fetch_events(vec![filter], timeout).await {
+        Ok(found) => {
+            let mut seen: HashSet<EventId> = events.iter().map(|event| event.id).collect();
+            events.extend(found.into_iter().filter(|event| seen.insert(event.id)));
+        }
+        Err(e) => log::warn!("failed to fetch from extra relays {extra:?}: {e}"),
+    }
+    let _ = extra_client.disconnect().await;
+    Ok(events)
 }
 
 /// Fetch contract announcements from relays.
 pub async fn fetch_announcements(
     client: &Client,
     expected_network_tag: &str,
+    timeout: Duration,
 ) -> Result<Vec<DiscoveredMarket>, String> {
     let filter = build_contract_filter();
     let events = client
-        .fetch_events(vec![filter], Duration::from_secs(15))
+        .fetch_events(vec![filter], timeout)
         .await
         .map_err(|e| format!("failed to fetch events: {e}"))?;
 
@@ -306,10 +381,11 @@ pub async fn fetch_orders(
     client: &Client,
     market_id_hex: Option<&str>,
     expected_network_tag: &str,
+    timeout: Duration,
 ) -> Result<Vec<DiscoveredOrder>, String> {
     let filter = build_order_filter(market_id_hex);
     let events = client
-        .fetch_events(vec![filter], Duration::from_secs(15))
+        .fetch_events(vec![filter], timeout)
         .await
         .map_err(|e| format!("failed to fetch order events: {e}"))?;
 
@@ -410,4 +486,15 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    #[test]
+    fn network_config_sets_tag_and_relays() {
+        let mainnet = config::DiscoveryConfig::for_network(crate::Network::Liquid);
+        assert_eq!(mainnet.network_tag, "liquid");
+        assert_eq!(mainnet.relays, DEFAULT_RELAYS);
+
+        let regtest = config::DiscoveryConfig::for_network(crate::Network::LiquidRegtest);
+        assert_eq!(regtest.network_tag, "liquid-regtest");
+        assert_ne!(regtest.relays, mainnet.relays);
+    }
 }
@@ -140,7 +140,7 @@ pub(crate) fn derive_lmsr_pool_id_hex(
     .map_err(|e| format!("failed to derive LMSR pool ID: {e}"))
 }
 
-fn event_identifier_tag(event: &Event) -> Option<String> {
+pub(crate) fn event_identifier_tag(event: &Event) -> Option<String> {
     event.tags.iter().find_map(|tag| {
         let fields = tag.as_slice();
         if fields.len() >= 2 && fields[0] == "d" {
@@ -446,10 +446,11 @@ pub async fn fetch_pools(
     client: &Client,
     market_id_hex: Option<&str>,
     expected_network_tag: &str,
+    timeout: std::time::Duration,
 ) -> Result<Vec<DiscoveredPool>, String> {
     let filter = build_pool_filter(market_id_hex);
     let events = client
-        .fetch_events(vec![filter], std::time::Duration::from_secs(15))
+        .fetch_events(vec![filter], timeout)
         .await
         .map_err(|e| format!("failed to fetch pool events: {e}"))?;
 
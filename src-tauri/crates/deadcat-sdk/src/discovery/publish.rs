@@ -0,0 +1,298 @@
+use std::time::Duration;
+
+use nostr_sdk::prelude::*;
+use tokio::task::JoinSet;
+
+/// How long a single relay gets to acknowledge a published event.
+pub const RELAY_PUBLISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Relays that must hold an announcement before it stops being retried,
+/// capped at the number of configured relays.
+pub const PUBLISH_QUORUM: usize = 2;
+
+/// Publish attempts after which an announcement still short of quorum is
+/// marked failed.
+pub const MAX_PUBLISH_ATTEMPTS: u32 = 5;
+
+/// A relay that did not accept a published event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayRejection {
+    pub relay: String,
+    pub reason: String,
+}
+
+/// Per-relay outcome of publishing one event.
+///
+/// Only produced when at least one relay accepted the event; a publish that
+/// reached no relay is an error instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishReport {
+    pub event_id: EventId,
+    pub accepted: Vec<String>,
+    pub rejected: Vec<RelayRejection>,
+}
+
+impl PublishReport {
+    pub fn relay_count(&self) -> usize {
+        self.accepted.len() + self.rejected.len()
+    }
+
+    /// Whether enough relays accepted the event that it needs no retry.
+    pub fn meets_quorum(&self) -> bool {
+        quorum_reached(self.accepted.len(), self.relay_count())
+    }
+
+    /// e.g. "published to 2 of 3 relays".
+    pub fn summary(&self) -> String {
+        format!(
+            "published to {} of {} relays",
+            self.accepted.len(),
+            self.relay_count()
+        )
+    }
+
+    /// Set when some relays accepted the event but others did not.
+    pub fn warning(&self) -> Option<String> {
+        if self.rejected.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "{} ({})",
+            self.summary(),
+            rejection_details(&self.rejected)
+        ))
+    }
+}
+
+pub(crate) fn quorum_reached(accepted: usize, relay_count: usize) -> bool {
+    accepted >= PUBLISH_QUORUM.min(relay_count)
+}
+
+/// Retry state of an announcement this node published.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublishStatus {
+    /// Short of quorum; the background retry will publish it again.
+    Pending,
+    /// Reached quorum.
+    Published,
+    /// Gave up after [`MAX_PUBLISH_ATTEMPTS`] without reaching quorum.
+    Failed,
+    /// Withdrawn with a deletion request; never retried.
+    Deleted,
+}
+
+impl PublishStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PublishStatus::Pending => "pending",
+            PublishStatus::Published => "published",
+            PublishStatus::Failed => "failed",
+            PublishStatus::Deleted => "deleted",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(PublishStatus::Pending),
+            "published" => Some(PublishStatus::Published),
+            "failed" => Some(PublishStatus::Failed),
+            "deleted" => Some(PublishStatus::Deleted),
+            _ => None,
+        }
+    }
+}
+
+/// A persisted announcement plus the relays known to hold it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishRecord {
+    pub event_id: String,
+    pub event_json: String,
+    /// Union of relays that accepted the event across all attempts.
+    pub accepted_relays: Vec<String>,
+    pub attempts: u32,
+    pub status: PublishStatus,
+    pub last_error: Option<String>,
+}
+
+impl PublishRecord {
+    /// Start tracking `event` after its first publish attempt.
+    pub fn new(event: &Event, outcome: &Result<PublishReport, String>) -> Self {
+        let mut record = Self {
+            event_id: event.id.to_hex(),
+            event_json: event.as_json(),
+            accepted_relays: Vec::new(),
+            attempts: 0,
+            status: PublishStatus::Pending,
+            last_error: None,
+        };
+        record.apply_attempt(outcome);
+        record
+    }
+
+    /// Fold one publish attempt into the record and advance its status.
+    pub fn apply_attempt(&mut self, outcome: &Result<PublishReport, String>) {
+        self.attempts += 1;
+        match outcome {
+            Ok(report) => {
+                for relay in &report.accepted {
+                    if !self.accepted_relays.contains(relay) {
+                        self.accepted_relays.push(relay.clone());
+                    }
+                }
+                self.accepted_relays.sort();
+                self.last_error = report.warning();
+                if quorum_reached(self.accepted_relays.len(), report.relay_count()) {
+                    self.status = PublishStatus::Published;
+                    return;
+                }
+            }
+            Err(e) => self.last_error = Some(e.clone()),
+        }
+        self.status = if self.attempts >= MAX_PUBLISH_ATTEMPTS {
+            PublishStatus::Failed
+        } else {
+            PublishStatus::Pending
+        };
+    }
+}
+
+fn rejection_details(rejected: &[RelayRejection]) -> String {
+    rejected
+        .iter()
+        .map(|r| format!("{}: {}", r.relay, r.reason))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Publish an event to every relay on `client` concurrently, waiting at most
+/// [`RELAY_PUBLISH_TIMEOUT`] for each.
+pub async fn publish_event(client: &Client, event: Event) -> Result<PublishReport, String> {
+    publish_event_with_timeout(client, event, RELAY_PUBLISH_TIMEOUT).await
+}
+
+/// [`publish_event`] with a caller-chosen per-relay timeout.
+pub async fn publish_event_with_timeout(
+    client: &Client,
+    event: Event,
+    timeout: Duration,
+) -> Result<PublishReport, String> {
+    let relays = client.relays().await;
+    if relays.is_empty() {
+        return Err("failed to send event: no relays configured".to_string());
+    }
+
+    let event_id = event.id;
+    let mut tasks = JoinSet::new();
+    for (url, relay) in relays {
+        let event = event.clone();
+        tasks.spawn(async move {
+            let outcome = match tokio::time::timeout(timeout, relay.send_event(event)).await {
+                Ok(Ok(_)) => Ok(()),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(_) => Err(format!("timed out after {}s", timeout.as_secs())),
+            };
+            (url.to_string(), outcome)
+        });
+    }
+
+    let mut accepted = Vec::new();
+    let mut rejected = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((relay, Ok(()))) => accepted.push(relay),
+            Ok((relay, Err(reason))) => rejected.push(RelayRejection { relay, reason }),
+            Err(e) => log::warn!("relay publish task failed: {e}"),
+        }
+    }
+    accepted.sort();
+    rejected.sort_by(|a, b| a.relay.cmp(&b.relay));
+
+    if accepted.is_empty() {
+        return Err(format!(
+            "failed to send event: no relay accepted it ({})",
+            rejection_details(&rejected)
+        ));
+    }
+
+    Ok(PublishReport {
+        event_id,
+        accepted,
+        rejected,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(accepted: &[&str], rejected: &[(&str, &str)]) -> PublishReport {
+        PublishReport {
+            event_id: EventId::all_zeros(),
+            accepted: accepted.iter().map(|s| s.to_string()).collect(),
+            rejected: rejected
+                .iter()
+                .map(|(relay, reason)| RelayRejection {
+                    relay: relay.to_string(),
+                    reason: reason.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn full_acceptance_has_no_warning() {
+        let report = report(&["wss://a", "wss://b"], &[]);
+        assert_eq!(report.summary(), "published to 2 of 2 relays");
+        assert_eq!(report.warning(), None);
+    }
+
+    #[test]
+    fn record_accumulates_relays_until_quorum() {
+        let keys = Keys::generate();
+        let event = EventBuilder::text_note("hi").sign_with_keys(&keys).unwrap();
+        let one_of_three = report(&["wss://a"], &[("wss://b", "x"), ("wss://c", "x")]);
+        let mut record = PublishRecord::new(&event, &Ok(one_of_three.clone()));
+        assert_eq!(record.status, PublishStatus::Pending);
+        assert_eq!(record.event_id, event.id.to_hex());
+
+        record.apply_attempt(&Ok(one_of_three));
+        assert_eq!(record.status, PublishStatus::Pending);
+        assert_eq!(record.accepted_relays, vec!["wss://a".to_string()]);
+
+        record.apply_attempt(&Ok(report(
+            &["wss://b"],
+            &[("wss://a", "x"), ("wss://c", "x")],
+        )));
+        assert_eq!(record.status, PublishStatus::Published);
+        assert_eq!(record.attempts, 3);
+    }
+
+    #[test]
+    fn record_fails_after_max_attempts() {
+        let keys = Keys::generate();
+        let event = EventBuilder::text_note("hi").sign_with_keys(&keys).unwrap();
+        let offline = Err("failed to send event: no relay accepted it".to_string());
+        let mut record = PublishRecord::new(&event, &offline);
+        for _ in 1..MAX_PUBLISH_ATTEMPTS {
+            assert_eq!(record.status, PublishStatus::Pending);
+            record.apply_attempt(&offline);
+        }
+        assert_eq!(record.status, PublishStatus::Failed);
+        assert_eq!(
+            record.last_error.as_deref(),
+            offline.as_ref().err().map(String::as_str)
+        );
+    }
+
+    #[test]
+    fn partial_acceptance_warns_with_reasons() {
+        let report = report(
+            &["wss://a", "wss://b"],
+            &[("wss://c", "timed out after 10s")],
+        );
+        assert_eq!(
+            report.warning().as_deref(),
+            Some("published to 2 of 3 relays (wss://c: timed out after 10s)")
+        );
+    }
+}
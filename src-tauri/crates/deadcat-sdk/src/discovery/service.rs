@@ -1,5 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use nostr_sdk::prelude::*;
 use tokio::sync::broadcast;
@@ -8,10 +10,14 @@ use crate::announcement::ContractAnnouncement;
 use crate::prediction_market::params::MarketId;
 
 use super::attestation::{
-    AttestationContent, AttestationResult, build_attestation_event, build_attestation_filter,
-    build_attestation_subscription_filter, parse_attestation_event, sign_attestation,
+    AttestationContent, AttestationResult, VerifiedAttestation, build_attestation_event,
+    build_attestation_filter, build_attestation_subscription_filter, parse_attestation_event,
+    sign_attestation, verify_attestation,
 };
 use super::config::DiscoveryConfig;
+use super::deletion::{
+    apply_deletions, build_deletion_event, build_deletion_filter, latest_replaceable,
+};
 use super::events::DiscoveryEvent;
 use super::market::{
     DiscoveredMarket, ParsedDiscoveredMarketAnnouncement, build_announcement_event,
@@ -20,13 +26,14 @@ use super::market::{
 use super::pool::{
     DiscoveredPool, PoolAnnouncement, build_pool_event, build_pool_filter, parse_pool_event,
 };
+use super::publish::{PublishRecord, PublishReport, PublishStatus, publish_event_with_timeout};
 use super::store_trait::{
     DiscoveryStore, LmsrPoolIngestInput, LmsrPoolStateSource, LmsrPoolStateUpdateInput,
     PredictionMarketCandidateIngestInput,
 };
 use super::{
     ATTESTATION_TAG, CONTRACT_TAG, DiscoveredOrder, ORDER_TAG, OrderAnnouncement, POOL_TAG,
-    build_order_event, build_order_filter, parse_order_event,
+    build_order_event, build_order_filter, fetch_events_with_relays, parse_order_event,
 };
 
 /// Unified Nostr discovery service for markets, orders, and attestations.
@@ -37,6 +44,9 @@ pub struct DiscoveryService<S: DiscoveryStore = NoopStore> {
     client: Client,
     keys: Keys,
     config: DiscoveryConfig,
+    /// Fetch timeout in milliseconds; starts at `config.fetch_timeout` and
+    /// can be changed while the service runs.
+    fetch_timeout_ms: AtomicU64,
     store: Option<Arc<Mutex<S>>>,
     tx: broadcast::Sender<DiscoveryEvent>,
 }
@@ -84,6 +94,7 @@ impl DiscoveryService<NoopStore> {
             Self {
                 client,
                 keys,
+                fetch_timeout_ms: AtomicU64::new(duration_ms(config.fetch_timeout)),
                 config,
                 store: None,
                 tx,
@@ -108,6 +119,7 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
             Self {
                 client,
                 keys,
+                fetch_timeout_ms: AtomicU64::new(duration_ms(config.fetch_timeout)),
                 config,
                 store: Some(store),
                 tx,
@@ -150,17 +162,63 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
 
     /// One-shot: fetch all markets from relays, optionally persist, and return.
     pub async fn fetch_markets(&self) -> Result<Vec<DiscoveredMarket>, String> {
-        self.ensure_connected().await?;
+        self.fetch_markets_matching(build_contract_filter(), &[])
+            .await
+    }
 
-        let filter = build_contract_filter();
-        let events = self
-            .client
-            .fetch_events(vec![filter], self.config.fetch_timeout)
+    /// One-shot: fetch `author`'s markets, also querying `author_relays`
+    /// (typically their NIP-65 list) alongside the configured relays.
+    pub async fn fetch_markets_by_author(
+        &self,
+        author: PublicKey,
+        author_relays: &[String],
+    ) -> Result<Vec<DiscoveredMarket>, String> {
+        self.fetch_markets_matching(build_contract_filter().author(author), author_relays)
             .await
-            .map_err(|e| format!("failed to fetch events: {e}"))?;
+    }
+
+    /// One-shot: fetch the market announced by `event_id`, also querying
+    /// `extra_relays` (typically an `nevent`'s relay hints), and persist it.
+    pub async fn fetch_market_by_event(
+        &self,
+        event_id: EventId,
+        extra_relays: &[String],
+    ) -> Result<DiscoveredMarket, String> {
+        self.fetch_markets_matching(build_contract_filter().id(event_id), extra_relays)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("market announcement {event_id} not found on relays"))
+    }
+
+    /// One-shot: fetch the market whose announcement carries `market_id_hex`
+    /// as its identifier, and persist it. `None` when no relay has it.
+    pub async fn fetch_market_by_id(
+        &self,
+        market_id_hex: &str,
+    ) -> Result<Option<DiscoveredMarket>, String> {
+        Ok(self
+            .fetch_markets_matching(build_contract_filter().identifier(market_id_hex), &[])
+            .await?
+            .into_iter()
+            .find(|market| market.market_id == market_id_hex))
+    }
+
+    async fn fetch_markets_matching(
+        &self,
+        filter: Filter,
+        extra_relays: &[String],
+    ) -> Result<Vec<DiscoveredMarket>, String> {
+        self.ensure_connected().await?;
+
+        let events =
+            fetch_events_with_relays(&self.client, filter, extra_relays, self.fetch_timeout())
+                .await
+                .map_err(|e| format!("failed to fetch events: {e}"))?;
+        let events = self.current_announcements(events).await;
 
         let mut markets = Vec::new();
-        for event in events.iter() {
+        for event in &events {
             match parse_announcement_event_with_ingest(event, &self.config.network_tag) {
                 Ok(parsed) => {
                     self.persist_market(&parsed);
@@ -184,17 +242,36 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
         &self,
         market_id_hex: Option<&str>,
     ) -> Result<Vec<DiscoveredOrder>, String> {
-        self.ensure_connected().await?;
+        self.fetch_orders_matching(build_order_filter(market_id_hex), &[])
+            .await
+    }
 
-        let filter = build_order_filter(market_id_hex);
-        let events = self
-            .client
-            .fetch_events(vec![filter], self.config.fetch_timeout)
+    /// One-shot: fetch `author`'s orders, also querying `author_relays`
+    /// alongside the configured relays.
+    pub async fn fetch_orders_by_author(
+        &self,
+        author: PublicKey,
+        author_relays: &[String],
+    ) -> Result<Vec<DiscoveredOrder>, String> {
+        self.fetch_orders_matching(build_order_filter(None).author(author), author_relays)
             .await
-            .map_err(|e| format!("failed to fetch order events: {e}"))?;
+    }
+
+    async fn fetch_orders_matching(
+        &self,
+        filter: Filter,
+        extra_relays: &[String],
+    ) -> Result<Vec<DiscoveredOrder>, String> {
+        self.ensure_connected().await?;
+
+        let events =
+            fetch_events_with_relays(&self.client, filter, extra_relays, self.fetch_timeout())
+                .await
+                .map_err(|e| format!("failed to fetch order events: {e}"))?;
+        let events = self.current_announcements(events).await;
 
         let mut orders = Vec::new();
-        for event in events.iter() {
+        for event in &events {
             match parse_order_event(event, &self.config.network_tag) {
                 Ok(mut order) => {
                     order.nostr_event_json = serde_json::to_string(event).ok();
@@ -220,7 +297,7 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
         let filter = build_attestation_filter(market_id_hex);
         let events = self
             .client
-            .fetch_events(vec![filter], self.config.fetch_timeout)
+            .fetch_events(vec![filter], self.fetch_timeout())
             .await
             .map_err(|e| format!("failed to fetch attestation events: {e}"))?;
 
@@ -233,36 +310,74 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
         }
     }
 
+    /// One-shot: fetch a market's attestations and return the earliest one
+    /// signed by `oracle_public_key`. Events that fail verification, such as
+    /// attestations published under someone else's key, are ignored. Valid
+    /// attestations for both outcomes mean the oracle equivocated, which is
+    /// reported as an error rather than resolved by picking one.
+    pub async fn fetch_verified_attestation(
+        &self,
+        market_id: &MarketId,
+        oracle_public_key: &[u8; 32],
+    ) -> Result<Option<VerifiedAttestation>, String> {
+        self.ensure_connected().await?;
+
+        let market_id_hex = hex::encode(market_id.as_bytes());
+        let filter = build_attestation_filter(&market_id_hex);
+        let events = self
+            .client
+            .fetch_events(vec![filter], self.fetch_timeout())
+            .await
+            .map_err(|e| format!("failed to fetch attestation events: {e}"))?;
+
+        let attestations: Vec<VerifiedAttestation> = events
+            .iter()
+            .filter_map(|event| {
+                let content = parse_attestation_event(event, &self.config.network_tag).ok()?;
+                verify_attestation(&content, market_id, oracle_public_key).ok()?;
+                Some(VerifiedAttestation {
+                    market_id: market_id_hex.clone(),
+                    outcome_yes: content.outcome_yes,
+                    signature_hex: content.oracle_signature,
+                    nostr_event_id: event.id.to_hex(),
+                    signed_at: event.created_at.as_u64(),
+                })
+            })
+            .collect();
+
+        if attestations
+            .windows(2)
+            .any(|pair| pair[0].outcome_yes != pair[1].outcome_yes)
+        {
+            return Err(format!(
+                "oracle equivocated: conflicting attestations for market {market_id_hex}"
+            ));
+        }
+        Ok(attestations
+            .into_iter()
+            .min_by_key(|attestation| attestation.signed_at))
+    }
+
     /// Publish a market announcement to relays.
     pub async fn announce_market(
         &self,
         announcement: &ContractAnnouncement,
-    ) -> Result<EventId, String> {
+    ) -> Result<PublishReport, String> {
         self.ensure_connected().await?;
 
         let event = build_announcement_event(&self.keys, announcement, &self.config.network_tag)?;
-        let output = self
-            .client
-            .send_event(event)
-            .await
-            .map_err(|e| format!("failed to send event: {e}"))?;
-        Ok(*output.id())
+        self.publish_tracked(event).await
     }
 
     /// Publish a limit order announcement to relays.
     pub async fn announce_order(
         &self,
         announcement: &OrderAnnouncement,
-    ) -> Result<EventId, String> {
+    ) -> Result<PublishReport, String> {
         self.ensure_connected().await?;
 
         let event = build_order_event(&self.keys, announcement, &self.config.network_tag)?;
-        let output = self
-            .client
-            .send_event(event)
-            .await
-            .map_err(|e| format!("failed to send order event: {e}"))?;
-        Ok(*output.id())
+        self.publish_tracked(event).await
     }
 
     /// Sign and publish an oracle attestation.
@@ -271,17 +386,35 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
         market_id: &MarketId,
         announcement_event_id: &str,
         outcome_yes: bool,
+    ) -> Result<AttestationResult, String> {
+        self.publish_attestation_with_keys(
+            &self.keys,
+            market_id,
+            announcement_event_id,
+            outcome_yes,
+        )
+        .await
+    }
+
+    /// Sign and publish an oracle attestation with `oracle_keys` rather than
+    /// the service's own identity, for oracles that use a dedicated key.
+    pub async fn publish_attestation_with_keys(
+        &self,
+        oracle_keys: &Keys,
+        market_id: &MarketId,
+        announcement_event_id: &str,
+        outcome_yes: bool,
     ) -> Result<AttestationResult, String> {
         self.ensure_connected().await?;
 
         let market_id_hex = hex::encode(market_id.as_bytes());
 
-        let (sig_bytes, msg_bytes) = sign_attestation(&self.keys, market_id, outcome_yes)?;
+        let (sig_bytes, msg_bytes) = sign_attestation(oracle_keys, market_id, outcome_yes)?;
         let sig_hex = hex::encode(sig_bytes);
         let msg_hex = hex::encode(msg_bytes);
 
         let event = build_attestation_event(
-            &self.keys,
+            oracle_keys,
             &market_id_hex,
             announcement_event_id,
             outcome_yes,
@@ -290,31 +423,83 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
             &self.config.network_tag,
         )?;
 
-        let output = self
-            .client
-            .send_event(event)
-            .await
-            .map_err(|e| format!("failed to send attestation event: {e}"))?;
+        let report = self.publish(event).await?;
+        if let Some(warning) = report.warning() {
+            log::warn!("attestation for {market_id_hex} {warning}");
+        }
 
         Ok(AttestationResult {
             market_id: market_id_hex,
             outcome_yes,
             signature_hex: sig_hex,
-            nostr_event_id: output.id().to_hex(),
+            nostr_event_id: report.event_id.to_hex(),
         })
     }
 
     /// Publish a pool announcement to relays.
-    pub async fn announce_pool(&self, announcement: &PoolAnnouncement) -> Result<EventId, String> {
+    pub async fn announce_pool(
+        &self,
+        announcement: &PoolAnnouncement,
+    ) -> Result<PublishReport, String> {
         self.ensure_connected().await?;
 
         let event = build_pool_event(&self.keys, announcement, &self.config.network_tag)?;
-        let output = self
-            .client
-            .send_event(event)
-            .await
-            .map_err(|e| format!("failed to send pool event: {e}"))?;
-        Ok(*output.id())
+        self.publish_tracked(event).await
+    }
+
+    /// Publish a tracked announcement again, whatever its retry status.
+    pub async fn republish_event(&self, event_id: &str) -> Result<PublishRecord, String> {
+        let record = self
+            .with_store(|s| s.get_publish_record(event_id))?
+            .ok_or_else(|| format!("no tracked announcement with event id {event_id}"))?;
+        self.ensure_connected().await?;
+        self.retry_publish(record).await
+    }
+
+    /// Publish every announcement still short of quorum once more.
+    ///
+    /// Returns the updated records; those that hit the attempt limit come
+    /// back as [`PublishStatus::Failed`].
+    pub async fn retry_pending_publishes(&self) -> Result<Vec<PublishRecord>, String> {
+        let pending = self.with_store(|s| s.list_pending_publishes())?;
+        if pending.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.ensure_connected().await?;
+
+        let mut updated = Vec::with_capacity(pending.len());
+        for record in pending {
+            let event_id = record.event_id.clone();
+            match self.retry_publish(record).await {
+                Ok(record) => updated.push(record),
+                Err(e) => log::warn!("retrying publish of {event_id} failed: {e}"),
+            }
+        }
+        Ok(updated)
+    }
+
+    /// Retract one of this node's announcements with a NIP-09 deletion
+    /// request.
+    ///
+    /// Relays may ignore the request, so fetches also drop deleted
+    /// announcements client-side.
+    pub async fn delete_announcement(&self, event_id: &str) -> Result<PublishReport, String> {
+        self.ensure_connected().await?;
+
+        let record = self.with_store(|s| s.get_publish_record(event_id))?;
+        let event = match &record {
+            Some(record) => Event::from_json(&record.event_json)
+                .map_err(|e| format!("stored event {event_id} is unreadable: {e}"))?,
+            None => self.fetch_event(event_id).await?,
+        };
+        let deletion = build_deletion_event(&self.keys, &event, "announcement withdrawn")?;
+        let report = self.publish(deletion).await?;
+
+        if let Some(mut record) = record {
+            record.status = PublishStatus::Deleted;
+            self.with_store(|s| s.save_publish_record(&record))?;
+        }
+        Ok(report)
     }
 
     /// One-shot: fetch pools from relays, optionally for a specific market.
@@ -327,12 +512,13 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
         let filter = build_pool_filter(market_id_hex);
         let events = self
             .client
-            .fetch_events(vec![filter], self.config.fetch_timeout)
+            .fetch_events(vec![filter], self.fetch_timeout())
             .await
             .map_err(|e| format!("failed to fetch pool events: {e}"))?;
+        let events = self.current_announcements(events).await;
 
         let mut pools = Vec::new();
-        for event in events.iter() {
+        for event in &events {
             match parse_pool_event(event, &self.config.network_tag) {
                 Ok(mut pool) => {
                     pool.nostr_event_json = serde_json::to_string(event).ok();
@@ -352,6 +538,18 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
         Ok(pools)
     }
 
+    /// Re-ingest previously fetched Nostr events (e.g. from a backup).
+    ///
+    /// Events are handled exactly like live subscription events: markets,
+    /// orders, and pools are persisted to the store and a `DiscoveryEvent` is
+    /// broadcast for each. Returns the number of events that were recognised.
+    pub fn ingest_events(&self, events: &[Event]) -> usize {
+        events
+            .iter()
+            .filter(|event| dispatch_event(event, &self.store, &self.tx, &self.config.network_tag))
+            .count()
+    }
+
     /// Get a reference to the underlying Nostr client.
     pub fn client(&self) -> &Client {
         &self.client
@@ -362,8 +560,93 @@ impl<S: DiscoveryStore> DiscoveryService<S> {
         &self.keys
     }
 
+    /// Timeout for one-shot fetch operations.
+    pub fn fetch_timeout(&self) -> Duration {
+        Duration::from_millis(self.fetch_timeout_ms.load(Ordering::Relaxed))
+    }
+
+    /// Change the timeout used by fetches started after this call.
+    pub fn set_fetch_timeout(&self, timeout: Duration) {
+        self.fetch_timeout_ms
+            .store(duration_ms(timeout), Ordering::Relaxed);
+    }
+
     // --- internal helpers ---
 
+    async fn publish(&self, event: Event) -> Result<PublishReport, String> {
+        publish_event_with_timeout(&self.client, event, self.config.publish_timeout).await
+    }
+
+    /// Drop superseded versions of replaceable announcements and any their
+    /// authors have deleted. If deletions can't be fetched the announcements
+    /// are returned unfiltered rather than failing the fetch.
+    async fn current_announcements(&self, events: impl IntoIterator<Item = Event>) -> Vec<Event> {
+        let events = latest_replaceable(events.into_iter().collect());
+        let authors: HashSet<PublicKey> = events.iter().map(|event| event.pubkey).collect();
+        if authors.is_empty() {
+            return events;
+        }
+
+        let filter = build_deletion_filter(authors);
+        match self
+            .client
+            .fetch_events(vec![filter], self.fetch_timeout())
+            .await
+        {
+            Ok(deletions) => apply_deletions(events, &deletions.into_iter().collect::<Vec<_>>()),
+            Err(e) => {
+                log::warn!("failed to fetch deletion requests: {e}");
+                events
+            }
+        }
+    }
+
+    async fn fetch_event(&self, event_id: &str) -> Result<Event, String> {
+        let id = EventId::from_hex(event_id).map_err(|e| format!("invalid event id: {e}"))?;
+        let events = self
+            .client
+            .fetch_events(vec![Filter::new().id(id)], self.fetch_timeout())
+            .await
+            .map_err(|e| format!("failed to fetch event {event_id}: {e}"))?;
+        events
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("event {event_id} not found on relays"))
+    }
+
+    /// Publish an announcement and record the outcome so it can be retried.
+    async fn publish_tracked(&self, event: Event) -> Result<PublishReport, String> {
+        let outcome = self.publish(event.clone()).await;
+        let record = PublishRecord::new(&event, &outcome);
+        if let Err(e) = self.with_store(|s| s.save_publish_record(&record)) {
+            log::warn!("failed to record publish of {}: {e}", record.event_id);
+        }
+        outcome
+    }
+
+    async fn retry_publish(&self, mut record: PublishRecord) -> Result<PublishRecord, String> {
+        let event = Event::from_json(&record.event_json)
+            .map_err(|e| format!("stored event {} is unreadable: {e}", record.event_id))?;
+        let outcome = self.publish(event).await;
+        record.apply_attempt(&outcome);
+        self.with_store(|s| s.save_publish_record(&record))?;
+        Ok(record)
+    }
+
+    /// Run `f` against the store, or return `T::default()` without one.
+    fn with_store<T: Default>(
+        &self,
+        f: impl FnOnce(&mut S) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let Some(store) = &self.store else {
+            return Ok(T::default());
+        };
+        let mut store = store
+            .lock()
+            .map_err(|_| "store lock poisoned".to_string())?;
+        f(&mut store)
+    }
+
     async fn ensure_connected(&self) -> Result<(), String> {
         if self.client.relays().await.is_empty() {
             for url in &self.config.relays {
@@ -473,47 +756,68 @@ async fn run_subscription_loop<S: DiscoveryStore>(
 
     while let Ok(notification) = notifications.recv().await {
         if let RelayPoolNotification::Event { event, .. } = notification {
-            let hashtags: Vec<String> = event
-                .tags
-                .iter()
-                .filter_map(|t| {
-                    let tag_vec = t.as_slice();
-                    if tag_vec.len() >= 2 && tag_vec[0] == "t" {
-                        Some(tag_vec[1].to_string())
-                    } else {
-                        None
-                    }
-                })
-                .collect();
+            dispatch_event(&event, &store, &tx, &network_tag);
+        }
+    }
+}
 
-            if hashtags.iter().any(|t| t == CONTRACT_TAG) {
-                match parse_announcement_event_with_ingest(&event, &network_tag) {
-                    Ok(parsed) => {
-                        persist_market_to_store(&store, &parsed);
-                        let _ = tx.send(DiscoveryEvent::MarketDiscovered(parsed.market));
-                    }
-                    Err(e) => {
-                        log::warn!("skipping unparseable market announcement {}: {e}", event.id);
-                    }
-                }
-            } else if hashtags.iter().any(|t| t == ORDER_TAG) {
-                if let Ok(mut order) = parse_order_event(&event, &network_tag) {
-                    order.nostr_event_json = serde_json::to_string(&*event).ok();
-                    persist_order_to_store(&store, &order);
-                    let _ = tx.send(DiscoveryEvent::OrderDiscovered(order));
-                }
-            } else if hashtags.iter().any(|t| t == ATTESTATION_TAG) {
-                if let Ok(attestation) = parse_attestation_event(&event, &network_tag) {
-                    let _ = tx.send(DiscoveryEvent::AttestationDiscovered(attestation));
-                }
-            } else if hashtags.iter().any(|t| t == POOL_TAG)
-                && let Ok(mut pool) = parse_pool_event(&event, &network_tag)
-            {
-                pool.nostr_event_json = serde_json::to_string(&*event).ok();
-                persist_pool_to_store(&store, &pool, &network_tag);
-                let _ = tx.send(DiscoveryEvent::PoolDiscovered(pool));
+/// Parse a single discovery event, persist it, and broadcast the result.
+///
+/// Returns `true` if the event was recognised and parsed successfully.
+fn dispatch_event<S: DiscoveryStore>(
+    event: &Event,
+    store: &Option<Arc<Mutex<S>>>,
+    tx: &broadcast::Sender<DiscoveryEvent>,
+    network_tag: &str,
+) -> bool {
+    let hashtags: Vec<String> = event
+        .tags
+        .iter()
+        .filter_map(|t| {
+            let tag_vec = t.as_slice();
+            if tag_vec.len() >= 2 && tag_vec[0] == "t" {
+                Some(tag_vec[1].to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if hashtags.iter().any(|t| t == CONTRACT_TAG) {
+        match parse_announcement_event_with_ingest(event, network_tag) {
+            Ok(parsed) => {
+                persist_market_to_store(store, &parsed);
+                let _ = tx.send(DiscoveryEvent::MarketDiscovered(parsed.market));
+                true
+            }
+            Err(e) => {
+                log::warn!("skipping unparseable market announcement {}: {e}", event.id);
+                false
             }
         }
+    } else if hashtags.iter().any(|t| t == ORDER_TAG) {
+        let Ok(mut order) = parse_order_event(event, network_tag) else {
+            return false;
+        };
+        order.nostr_event_json = serde_json::to_string(event).ok();
+        persist_order_to_store(store, &order);
+        let _ = tx.send(DiscoveryEvent::OrderDiscovered(order));
+        true
+    } else if hashtags.iter().any(|t| t == ATTESTATION_TAG) {
+        let Ok(attestation) = parse_attestation_event(event, network_tag) else {
+            return false;
+        };
+        let _ = tx.send(DiscoveryEvent::AttestationDiscovered(attestation));
+        true
+    } else if hashtags.iter().any(|t| t == POOL_TAG)
+        && let Ok(mut pool) = parse_pool_event(event, network_tag)
+    {
+        pool.nostr_event_json = serde_json::to_string(event).ok();
+        persist_pool_to_store(store, &pool, network_tag);
+        let _ = tx.send(DiscoveryEvent::PoolDiscovered(pool));
+        true
+    } else {
+        false
     }
 }
 
@@ -639,6 +943,10 @@ fn dedup_latest_pools_by_id(pools: Vec<DiscoveredPool>) -> Vec<DiscoveredPool> {
     pools
 }
 
+fn duration_ms(timeout: Duration) -> u64 {
+    u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,14 @@
 use crate::history::{
     LmsrPoolSyncInfo, LmsrPoolSyncRepairInput, LmsrPriceHistoryEntry, LmsrPriceTransitionInput,
 };
+use crate::intent::{BroadcastIntent, IntentStatus};
 use crate::maker_order::params::MakerOrderParams;
+use crate::node::PoolVerificationStatus;
 use crate::prediction_market::anchor::PredictionMarketAnchor;
 use crate::prediction_market::params::PredictionMarketParams;
 
+use super::publish::PublishRecord;
+
 /// Human/provenance metadata passed alongside a prediction-market candidate.
 #[derive(Debug, Clone)]
 pub struct ContractMetadataInput {
@@ -127,6 +131,59 @@ pub trait DiscoveryStore: Send + 'static {
 
     /// Persist canonical LMSR live-state produced by chain scan.
     fn upsert_lmsr_pool_state(&mut self, input: &LmsrPoolStateUpdateInput) -> Result<(), String>;
+
+    /// Return a previously cached raw transaction, if any.
+    ///
+    /// Stores without a transaction cache can rely on the default, which
+    /// always misses.
+    fn get_cached_transaction(&mut self, _txid: &[u8; 32]) -> Result<Option<Vec<u8>>, String> {
+        Ok(None)
+    }
+
+    /// Cache a raw transaction under its txid. Re-caching a txid is a no-op.
+    fn cache_transaction(&mut self, _txid: &[u8; 32], _raw_tx: &[u8]) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Persist the publish state of an announcement, replacing any earlier
+    /// record for the same event id.
+    ///
+    /// Stores that don't track publishes can rely on the defaults; their
+    /// announcements are then never retried.
+    fn save_publish_record(&mut self, _record: &PublishRecord) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Look up the publish record for an event id.
+    fn get_publish_record(&mut self, _event_id: &str) -> Result<Option<PublishRecord>, String> {
+        Ok(None)
+    }
+
+    /// Records still short of quorum, oldest first.
+    fn list_pending_publishes(&mut self) -> Result<Vec<PublishRecord>, String> {
+        Ok(Vec::new())
+    }
+
+    /// Persist a signed covenant transaction before it is broadcast.
+    ///
+    /// Stores that don't journal broadcasts can rely on the defaults; an
+    /// interrupted broadcast is then simply lost.
+    fn save_broadcast_intent(&mut self, _intent: &BroadcastIntent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn set_broadcast_intent_status(
+        &mut self,
+        _txid: &str,
+        _status: IntentStatus,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Intents still [`IntentStatus::Pending`], oldest first.
+    fn list_pending_broadcast_intents(&mut self) -> Result<Vec<BroadcastIntent>, String> {
+        Ok(Vec::new())
+    }
 }
 
 /// Store operations needed by `DeadcatNode` for LMSR history sync and reads.
@@ -160,4 +217,16 @@ pub trait NodeStore: DiscoveryStore {
         since_block_height: Option<u32>,
         limit: Option<i64>,
     ) -> Result<Vec<LmsrPriceHistoryEntry>, String>;
+
+    /// Persist the latest verdict of checking a pool's announcement against
+    /// the chain, replacing any earlier one.
+    ///
+    /// Stores that don't track verdicts can rely on the default.
+    fn record_lmsr_pool_verification(
+        &mut self,
+        _pool_id: &str,
+        _status: &PoolVerificationStatus,
+    ) -> Result<(), String> {
+        Ok(())
+    }
 }
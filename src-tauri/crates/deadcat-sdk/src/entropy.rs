@@ -0,0 +1,32 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng, thread_rng};
+
+/// Randomness used by [`DeadcatSdk`](crate::sdk::DeadcatSdk) for maker order
+/// nonces and PSET blinding factors.
+///
+/// The default, [`ThreadRngEntropy`], draws from the OS-seeded thread RNG.
+/// Tests can install a deterministic source so that assembled transactions
+/// are byte-for-byte reproducible.
+pub trait EntropySource: Send {
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+/// Production entropy source backed by [`rand::thread_rng`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThreadRngEntropy;
+
+impl EntropySource for ThreadRngEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        thread_rng().fill_bytes(dest);
+    }
+}
+
+/// Seed a fresh CSPRNG for one operation from `source`.
+///
+/// `blind_last` needs a concrete `RngCore + CryptoRng`, so each operation
+/// draws a 32-byte seed rather than borrowing the source for its duration.
+pub(crate) fn seeded_rng(source: &mut dyn EntropySource) -> StdRng {
+    let mut seed = [0u8; 32];
+    source.fill_bytes(&mut seed);
+    StdRng::from_seed(seed)
+}
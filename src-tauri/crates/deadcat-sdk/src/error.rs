@@ -1,4 +1,5 @@
 use crate::prediction_market::state::MarketState;
+use simplicityhl::elements::{AssetId, OutPoint, Txid};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -39,9 +40,24 @@ pub enum Error {
     #[error("order amount is zero")]
     ZeroOrderAmount,
 
+    #[error("fee asset {0} is not accepted on this network; fees must be paid in L-BTC")]
+    UnsupportedFeeAsset(String),
+
     #[error("price must be non-zero")]
     ZeroPrice,
 
+    #[error("order amount {order_amount} is not a whole number of lots at price {price}")]
+    OrderAmountNotLotMultiple { order_amount: u64, price: u64 },
+
+    #[error("order of {order_lots} lots is smaller than min_fill_lots ({min_fill_lots})")]
+    OrderBelowMinimumFill { order_lots: u64, min_fill_lots: u64 },
+
+    #[error("min_remainder_lots ({min_remainder_lots}) exceeds the order's {order_lots} lots")]
+    MinRemainderExceedsOrder {
+        order_lots: u64,
+        min_remainder_lots: u64,
+    },
+
     #[error("conservation check failed: payment does not match expected")]
     ConservationViolation,
 
@@ -63,6 +79,9 @@ pub enum Error {
     #[error("wallet initialization error: {0}")]
     WalletInit(String),
 
+    #[error("data directory holds {found} data, not {expected}")]
+    NetworkMismatch { expected: String, found: String },
+
     #[error("electrum error: {0}")]
     Electrum(String),
 
@@ -75,12 +94,40 @@ pub enum Error {
     #[error("broadcast error: {0}")]
     Broadcast(String),
 
+    #[error("input {outpoint} was already spent by a competing transaction")]
+    CovenantConflict { outpoint: OutPoint },
+
+    #[error("order {outpoint} is being filled by unconfirmed transaction {fill_txid}")]
+    OrderBeingFilled { outpoint: OutPoint, fill_txid: Txid },
+
     #[error("blinding error: {0}")]
     Blinding(String),
 
     #[error("insufficient UTXOs: {0}")]
     InsufficientUtxos(String),
 
+    /// The wallet's only spendable UTXO of the fee asset is the one selected
+    /// for collateral. [`DeadcatSdk::split_utxo`](crate::DeadcatSdk::split_utxo)
+    /// creates a second one.
+    #[error(
+        "need a second UTXO of asset {asset_id} with >= {min_amount} for the fee \
+         (split a wallet UTXO first to create another one)"
+    )]
+    SecondUtxoRequired { asset_id: AssetId, min_amount: u64 },
+
+    /// The wallet holds fewer separate UTXOs of `asset_id` than the
+    /// transaction needs wallet inputs of it. Checked before any selection,
+    /// so splitting or funding the wallet and retrying is always safe.
+    #[error(
+        "need {count_needed} separate UTXOs of asset {asset_id} but the wallet has \
+         {count_available} (split or fund the wallet first)"
+    )]
+    NeedMoreUtxos {
+        asset_id: AssetId,
+        count_needed: usize,
+        count_available: usize,
+    },
+
     #[error("covenant UTXO scanning failed: {0}")]
     CovenantScan(String),
 
@@ -99,6 +146,30 @@ pub enum Error {
         reason: String,
     },
 
+    #[error("collateral draw needs {0} transactions; a dry run can only preview one")]
+    FragmentedCollateral(usize),
+
+    #[error("a covenant spend takes 1 to {max} collateral UTXOs, got {count}")]
+    CollateralInputCount { count: usize, max: usize },
+
+    #[error("collateral draw stopped after broadcasting {completed_txids:?}: {reason}")]
+    PartialCollateralDraw {
+        completed_txids: Vec<String>,
+        reason: String,
+    },
+
+    #[error("market expires at height {expiry_height}, but the chain tip is {tip_height}")]
+    MarketNotExpired { expiry_height: u32, tip_height: u32 },
+
+    #[error("wallet holds no YES or NO tokens for this market")]
+    NoTokensToRedeem,
+
+    #[error("expired redemption stopped after broadcasting {completed_txids:?}: {reason}")]
+    PartialExpiredRedemption {
+        completed_txids: Vec<String>,
+        reason: String,
+    },
+
     #[error("market not in cancellable state (found {0:?})")]
     NotCancellable(MarketState),
 
@@ -114,6 +185,9 @@ pub enum Error {
     #[error("LMSR pool error: {0}")]
     LmsrPool(String),
 
+    #[error("pool fee of {fee_bps} bps is outside the allowed 0..={max_bps} bps")]
+    PoolFeeOutOfRange { fee_bps: u64, max_bps: u64 },
+
     #[error("reserve depleted (zero)")]
     ReserveDepleted,
 
@@ -128,6 +202,21 @@ pub enum Error {
 
     #[error("no liquidity available for this trade")]
     NoLiquidity,
+
+    #[error("fee bump not possible: {0}")]
+    FeeBump(String),
+
+    #[error("key sweep failed: {0}")]
+    Sweep(String),
+
+    #[error("UTXO consolidation failed: {0}")]
+    Consolidation(String),
+
+    #[error("fee estimation failed: {0}")]
+    FeeEstimate(String),
+
+    #[error("broadcast intent: {0}")]
+    Intent(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
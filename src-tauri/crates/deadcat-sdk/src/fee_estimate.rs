@@ -0,0 +1,387 @@
+use crate::error::{Error, Result};
+use crate::prediction_market::contract::CompiledPredictionMarket;
+use crate::prediction_market::pset::max_collateral_inputs;
+use crate::prediction_market::state::MarketSlot;
+use crate::prediction_market::witness::{
+    AllBlindingFactors, PredictionMarketSpendingPath, satisfy_contract, serialize_satisfied,
+};
+
+/// Fee rate (sat/kvB) for covenant transactions when the caller has no
+/// better figure: Liquid's minimum relay fee.
+pub const DEFAULT_COVENANT_FEE_RATE: f32 = 100.0;
+
+/// Non-witness bytes of any input: outpoint, empty script sig and sequence.
+const INPUT_BASE_BYTES: u64 = 41;
+/// Extra non-witness bytes of a reissuance input: blinding nonce, entropy,
+/// explicit amount and null inflation keys.
+const REISSUANCE_BASE_BYTES: u64 = 74;
+/// Witness bytes Elements adds to every input: the two issuance proof
+/// lengths, the script witness count and the peg-in witness count.
+const INPUT_WITNESS_OVERHEAD_BYTES: u64 = 4;
+/// Signed wallet input, priced as P2SH-wrapped segwit.
+const WALLET_INPUT_VSIZE: u64 = 100;
+/// Blinded output including its range and surjection proofs.
+const BLINDED_OUTPUT_VSIZE: u64 = 1_200;
+/// Explicit output to a script of up to 34 bytes.
+const EXPLICIT_OUTPUT_VSIZE: u64 = 80;
+/// Explicit fee output (empty script).
+const FEE_OUTPUT_VSIZE: u64 = 45;
+/// Version, locktime, witness flag and input/output counts.
+const TX_OVERHEAD_VSIZE: u64 = 11;
+
+/// A transaction that spends or creates covenant outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CovenantOp {
+    IssueTokens,
+    CancelTokens,
+    ResolveMarket,
+    ExpireMarket,
+    RedeemResolved,
+    RedeemExpired,
+    /// Funds a new LMSR pool's reserve outputs from the wallet. Spends no
+    /// covenant, so its size comes from inputs and outputs alone.
+    CreateLmsrPool,
+}
+
+impl CovenantOp {
+    pub const ALL: [CovenantOp; 7] = [
+        CovenantOp::IssueTokens,
+        CovenantOp::CancelTokens,
+        CovenantOp::ResolveMarket,
+        CovenantOp::ExpireMarket,
+        CovenantOp::RedeemResolved,
+        CovenantOp::RedeemExpired,
+        CovenantOp::CreateLmsrPool,
+    ];
+
+    /// Every transaction shape the op can take. Which one is built depends
+    /// on market state (initial vs. subsequent issuance, partial vs. full
+    /// cancellation, the winning side) and on how many collateral UTXOs get
+    /// merged, so estimates cover the largest.
+    fn layouts(self, contract: &CompiledPredictionMarket) -> Vec<Layout> {
+        use PredictionMarketSpendingPath as Path;
+
+        let blinding = AllBlindingFactors::default();
+        match self {
+            CovenantOp::IssueTokens => vec![
+                Layout {
+                    spends: vec![
+                        (
+                            MarketSlot::DormantYesRt,
+                            Path::InitialIssuancePrimary { blinding },
+                        ),
+                        (
+                            MarketSlot::DormantNoRt,
+                            Path::InitialIssuanceSecondaryNoRt { blinding },
+                        ),
+                    ],
+                    reissuance_inputs: 2,
+                    wallet_inputs: 2,
+                    blinded_outputs: 6,
+                    explicit_outputs: 1,
+                },
+                Layout {
+                    spends: vec![
+                        (
+                            MarketSlot::UnresolvedYesRt,
+                            Path::SubsequentIssuancePrimary { blinding },
+                        ),
+                        (
+                            MarketSlot::UnresolvedNoRt,
+                            Path::SubsequentIssuanceSecondaryNoRt { blinding },
+                        ),
+                        (
+                            MarketSlot::UnresolvedCollateral,
+                            Path::SubsequentIssuanceSecondaryCollateral,
+                        ),
+                    ],
+                    reissuance_inputs: 2,
+                    wallet_inputs: 2,
+                    blinded_outputs: 6,
+                    explicit_outputs: 1,
+                },
+            ],
+            CovenantOp::CancelTokens => vec![
+                Layout {
+                    spends: with_merged_collateral(
+                        vec![(
+                            MarketSlot::UnresolvedCollateral,
+                            Path::CancellationPartial { pairs_burned: 1 },
+                        )],
+                        MarketSlot::UnresolvedCollateral,
+                        1,
+                    ),
+                    reissuance_inputs: 0,
+                    wallet_inputs: 3,
+                    blinded_outputs: 4,
+                    explicit_outputs: 3,
+                },
+                Layout {
+                    spends: with_merged_collateral(
+                        vec![
+                            (
+                                MarketSlot::UnresolvedCollateral,
+                                Path::CancellationFullPrimary {
+                                    pairs_burned: 1,
+                                    blinding,
+                                },
+                            ),
+                            (
+                                MarketSlot::UnresolvedYesRt,
+                                Path::CancellationFullSecondaryYesRt { blinding },
+                            ),
+                            (
+                                MarketSlot::UnresolvedNoRt,
+                                Path::CancellationFullSecondaryNoRt { blinding },
+                            ),
+                        ],
+                        MarketSlot::UnresolvedCollateral,
+                        3,
+                    ),
+                    reissuance_inputs: 0,
+                    wallet_inputs: 3,
+                    blinded_outputs: 4,
+                    explicit_outputs: 2,
+                },
+            ],
+            CovenantOp::ResolveMarket => vec![Layout {
+                spends: vec![
+                    (
+                        MarketSlot::UnresolvedYesRt,
+                        Path::OracleResolvePrimary {
+                            outcome_yes: true,
+                            oracle_signature: [0; 64],
+                            blinding,
+                        },
+                    ),
+                    (
+                        MarketSlot::UnresolvedNoRt,
+                        Path::OracleResolveSecondaryNoRt { blinding },
+                    ),
+                    (
+                        MarketSlot::UnresolvedCollateral,
+                        Path::OracleResolveSecondaryCollateral,
+                    ),
+                ],
+                reissuance_inputs: 0,
+                wallet_inputs: 1,
+                blinded_outputs: 1,
+                explicit_outputs: 3,
+            }],
+            CovenantOp::ExpireMarket => vec![Layout {
+                spends: vec![
+                    (
+                        MarketSlot::UnresolvedYesRt,
+                        Path::ExpireTransitionPrimary { blinding },
+                    ),
+                    (
+                        MarketSlot::UnresolvedNoRt,
+                        Path::ExpireTransitionSecondaryNoRt { blinding },
+                    ),
+                    (
+                        MarketSlot::UnresolvedCollateral,
+                        Path::ExpireTransitionSecondaryCollateral,
+                    ),
+                ],
+                reissuance_inputs: 0,
+                wallet_inputs: 1,
+                blinded_outputs: 1,
+                explicit_outputs: 3,
+            }],
+            CovenantOp::RedeemResolved => [
+                MarketSlot::ResolvedYesCollateral,
+                MarketSlot::ResolvedNoCollateral,
+            ]
+            .into_iter()
+            .map(|slot| Layout {
+                spends: with_merged_collateral(
+                    vec![(slot, Path::PostResolutionRedemption { tokens_burned: 1 })],
+                    slot,
+                    1,
+                ),
+                reissuance_inputs: 0,
+                wallet_inputs: 2,
+                blinded_outputs: 3,
+                explicit_outputs: 2,
+            })
+            .collect(),
+            CovenantOp::RedeemExpired => vec![Layout {
+                spends: with_merged_collateral(
+                    vec![(
+                        MarketSlot::ExpiredCollateral,
+                        Path::ExpiryRedemption {
+                            tokens_burned: 1,
+                            burn_token_asset: contract.params().yes_token_asset,
+                        },
+                    )],
+                    MarketSlot::ExpiredCollateral,
+                    1,
+                ),
+                reissuance_inputs: 0,
+                wallet_inputs: 2,
+                blinded_outputs: 3,
+                explicit_outputs: 2,
+            }],
+            // YES, NO and L-BTC reserves plus the fee UTXO in; three explicit
+            // reserve outputs, the fee and blinded change for each asset out.
+            CovenantOp::CreateLmsrPool => vec![Layout {
+                spends: Vec::new(),
+                reissuance_inputs: 0,
+                wallet_inputs: 4,
+                blinded_outputs: 3,
+                explicit_outputs: 4,
+            }],
+        }
+    }
+}
+
+/// Append as many collateral merge inputs as a spend whose merges start at
+/// `first_merge_index` can take.
+fn with_merged_collateral(
+    mut spends: Vec<(MarketSlot, PredictionMarketSpendingPath)>,
+    slot: MarketSlot,
+    first_merge_index: usize,
+) -> Vec<(MarketSlot, PredictionMarketSpendingPath)> {
+    let merges = max_collateral_inputs(first_merge_index) - 1;
+    spends.extend(std::iter::repeat_n(
+        (slot, PredictionMarketSpendingPath::CollateralMergeSecondary),
+        merges,
+    ));
+    spends
+}
+
+/// Inputs and outputs of one covenant transaction shape. Wallet inputs
+/// assume one UTXO per asset, and every output that may go to the wallet
+/// is counted as blinded.
+struct Layout {
+    spends: Vec<(MarketSlot, PredictionMarketSpendingPath)>,
+    reissuance_inputs: u64,
+    wallet_inputs: u64,
+    blinded_outputs: u64,
+    explicit_outputs: u64,
+}
+
+impl Layout {
+    fn witness_bytes(&self, contract: &CompiledPredictionMarket) -> Result<u64> {
+        self.spends.iter().try_fold(0, |total, (slot, path)| {
+            Ok(total + witness_stack_len(&covenant_witness_stack(contract, *slot, path)?))
+        })
+    }
+
+    fn vbytes(&self, contract: &CompiledPredictionMarket) -> Result<u64> {
+        let covenant_inputs = self.spends.len() as u64;
+        let base_vsize = TX_OVERHEAD_VSIZE
+            + covenant_inputs * INPUT_BASE_BYTES
+            + self.reissuance_inputs * REISSUANCE_BASE_BYTES
+            + self.wallet_inputs * WALLET_INPUT_VSIZE
+            + self.blinded_outputs * BLINDED_OUTPUT_VSIZE
+            + self.explicit_outputs * EXPLICIT_OUTPUT_VSIZE
+            + FEE_OUTPUT_VSIZE;
+        let witness_weight =
+            self.witness_bytes(contract)? + covenant_inputs * INPUT_WITNESS_OVERHEAD_BYTES;
+        Ok(base_vsize + witness_weight.div_ceil(4))
+    }
+}
+
+/// Witness stack for a covenant input, built from the unpruned program.
+///
+/// The transaction doesn't exist yet, so the program can't be pruned
+/// against it; the full program bounds whatever pruning would leave.
+fn covenant_witness_stack(
+    contract: &CompiledPredictionMarket,
+    slot: MarketSlot,
+    path: &PredictionMarketSpendingPath,
+) -> Result<Vec<Vec<u8>>> {
+    let satisfied = satisfy_contract(contract, path, slot)
+        .map_err(|e| Error::FeeEstimate(format!("slot {slot:?} path {path:?}: {e}")))?;
+    let (program_bytes, witness_bytes) = serialize_satisfied(&satisfied);
+    Ok(vec![
+        witness_bytes,
+        program_bytes,
+        contract.cmr().to_byte_array().to_vec(),
+        contract.control_block(slot),
+    ])
+}
+
+/// Serialized length of a script witness stack: item count, then each item
+/// with its length prefix.
+pub(crate) fn witness_stack_len(stack: &[Vec<u8>]) -> u64 {
+    let items: u64 = stack
+        .iter()
+        .map(|item| varint_len(item.len() as u64) + item.len() as u64)
+        .sum();
+    varint_len(stack.len() as u64) + items
+}
+
+fn varint_len(n: u64) -> u64 {
+    match n {
+        0..=0xfc => 1,
+        0xfd..=0xffff => 3,
+        0x1_0000..=0xffff_ffff => 5,
+        _ => 9,
+    }
+}
+
+/// Upper bound on the bytes of Simplicity witness stacks (witness, program,
+/// CMR, control block) that `op`'s covenant inputs carry.
+pub fn estimate_covenant_witness_bytes(
+    contract: &CompiledPredictionMarket,
+    op: CovenantOp,
+) -> Result<u64> {
+    op.layouts(contract)
+        .iter()
+        .map(|layout| layout.witness_bytes(contract))
+        .try_fold(0, |max, bytes| Ok(max.max(bytes?)))
+}
+
+/// Upper bound on the virtual size of `op`'s transaction, counting the
+/// serialized Simplicity program and witness of every covenant input.
+pub fn estimate_covenant_vbytes(
+    contract: &CompiledPredictionMarket,
+    op: CovenantOp,
+) -> Result<u64> {
+    op.layouts(contract)
+        .iter()
+        .map(|layout| layout.vbytes(contract))
+        .try_fold(0, |max, vbytes| Ok(max.max(vbytes?)))
+}
+
+/// Fee for `op`'s transaction at `fee_rate` (sat/kvB).
+pub fn estimate_covenant_fee(
+    contract: &CompiledPredictionMarket,
+    op: CovenantOp,
+    fee_rate: f32,
+) -> Result<u64> {
+    if !fee_rate.is_finite() || fee_rate <= 0.0 {
+        return Err(Error::FeeEstimate(format!("invalid fee rate {fee_rate}")));
+    }
+    let vbytes = estimate_covenant_vbytes(contract, op)?;
+    Ok((vbytes as f64 * f64::from(fee_rate) / 1000.0).ceil() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::test_contract_params;
+
+    #[test]
+    fn witness_stack_len_counts_length_prefixes() {
+        assert_eq!(witness_stack_len(&[]), 1);
+        assert_eq!(witness_stack_len(&[vec![0; 32], vec![0; 33]]), 1 + 33 + 34);
+        assert_eq!(witness_stack_len(&[vec![0; 300]]), 1 + 3 + 300);
+    }
+
+    #[test]
+    fn fee_scales_with_rate() {
+        let contract = CompiledPredictionMarket::new(test_contract_params()).unwrap();
+        for op in CovenantOp::ALL {
+            let vbytes = estimate_covenant_vbytes(&contract, op).unwrap();
+            assert!(vbytes > estimate_covenant_witness_bytes(&contract, op).unwrap() / 4);
+            assert_eq!(
+                estimate_covenant_fee(&contract, op, 1_000.0).unwrap(),
+                vbytes
+            );
+        }
+        assert!(estimate_covenant_fee(&contract, CovenantOp::IssueTokens, 0.0).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::{Arc, Mutex};
+
+use lwk_wollet::elements::Txid;
+
+use crate::discovery::DiscoveryStore;
+
+/// Covenant operation whose transaction is journaled before broadcast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntentKind {
+    IssueTokens,
+    ResolveMarket,
+    /// A trade plan: an LMSR pool swap and/or maker order fills.
+    PoolSwap,
+}
+
+impl IntentKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IntentKind::IssueTokens => "issue_tokens",
+            IntentKind::ResolveMarket => "resolve_market",
+            IntentKind::PoolSwap => "pool_swap",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "issue_tokens" => Some(IntentKind::IssueTokens),
+            "resolve_market" => Some(IntentKind::ResolveMarket),
+            "pool_swap" => Some(IntentKind::PoolSwap),
+            _ => None,
+        }
+    }
+}
+
+/// Where a journaled broadcast stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntentStatus {
+    /// Recorded before broadcast and not yet seen on chain. Left behind when
+    /// the app stops mid-broadcast; reconciled on the next unlock.
+    Pending,
+    /// The transaction reached the mempool or a block.
+    Completed,
+    /// An input was spent by another transaction, or the user discarded it.
+    Abandoned,
+}
+
+impl IntentStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IntentStatus::Pending => "pending",
+            IntentStatus::Completed => "completed",
+            IntentStatus::Abandoned => "abandoned",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(IntentStatus::Pending),
+            "completed" => Some(IntentStatus::Completed),
+            "abandoned" => Some(IntentStatus::Abandoned),
+            _ => None,
+        }
+    }
+}
+
+/// A signed covenant transaction persisted before it is broadcast, so an
+/// interrupted operation can be checked against the chain and retried with
+/// the same transaction rather than rebuilt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastIntent {
+    pub txid: String,
+    pub kind: IntentKind,
+    /// Hex market id, when the operation belongs to a market.
+    pub market_id: Option<String>,
+    /// Consensus-serialized signed transaction.
+    pub raw_tx: Vec<u8>,
+    pub status: IntentStatus,
+}
+
+/// Journal `DeadcatSdk` writes covenant broadcasts to.
+///
+/// Unlike [`TransactionCache`](crate::TransactionCache), a failed
+/// [`record_intent`](Self::record_intent) aborts the operation: broadcasting
+/// without a record is what the journal exists to prevent.
+pub trait IntentJournal: Send + Sync {
+    fn record_intent(&self, intent: &BroadcastIntent) -> Result<(), String>;
+    fn set_intent_status(&self, txid: &Txid, status: IntentStatus);
+}
+
+/// Journal that records nothing; interrupted broadcasts are not recoverable.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoIntentJournal;
+
+impl IntentJournal for NoIntentJournal {
+    fn record_intent(&self, _intent: &BroadcastIntent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn set_intent_status(&self, _txid: &Txid, _status: IntentStatus) {}
+}
+
+/// Backs the journal with the node's shared persistence store.
+impl<S: DiscoveryStore> IntentJournal for Arc<Mutex<S>> {
+    fn record_intent(&self, intent: &BroadcastIntent) -> Result<(), String> {
+        self.lock()
+            .map_err(|_| "store lock poisoned".to_string())?
+            .save_broadcast_intent(intent)
+    }
+
+    fn set_intent_status(&self, txid: &Txid, status: IntentStatus) {
+        if let Ok(mut store) = self.lock()
+            && let Err(e) = store.set_broadcast_intent_status(&txid.to_string(), status)
+        {
+            log::warn!(
+                "failed to mark broadcast intent {txid} {}: {e}",
+                status.as_str()
+            );
+        }
+    }
+}
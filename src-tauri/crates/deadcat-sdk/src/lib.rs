@@ -4,9 +4,14 @@ pub use simplicityhl::simplicity;
 pub(crate) mod announcement;
 pub(crate) mod assembly;
 pub(crate) mod chain;
+pub(crate) mod coin_selection;
+pub(crate) mod descriptor;
 pub(crate) mod discovery;
+pub(crate) mod entropy;
 pub(crate) mod error;
+pub(crate) mod fee_estimate;
 pub(crate) mod history;
+pub(crate) mod intent;
 pub(crate) mod lmsr_pool;
 #[cfg(any(test, feature = "testing"))]
 pub mod maker_order;
@@ -24,42 +29,65 @@ pub(crate) mod sdk;
 pub mod taproot;
 #[cfg(not(any(test, feature = "testing")))]
 pub(crate) mod taproot;
+pub(crate) mod telemetry;
 #[cfg(any(test, feature = "testing"))]
 pub mod testing;
 pub(crate) mod trade;
+pub(crate) mod tx_cache;
+
+/// Version of this crate, as published in its manifest.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // ── Core types ─────────────────────────────────────────────────────
 pub use announcement::{CONTRACT_ANNOUNCEMENT_VERSION, ContractAnnouncement, ContractMetadata};
+pub use coin_selection::{CoinSelection, EXACT_MATCH_WINDOW_SATS, FeeOperation};
+pub use descriptor::DescriptorTemplate;
+pub use entropy::{EntropySource, ThreadRngEntropy};
 pub use error::{Error, NodeError, Result};
+pub use fee_estimate::{
+    CovenantOp, DEFAULT_COVENANT_FEE_RATE, estimate_covenant_fee, estimate_covenant_vbytes,
+    estimate_covenant_witness_bytes,
+};
 pub use history::{
     LmsrPoolSyncInfo, LmsrPoolSyncRepairInput, LmsrPriceHistoryEntry, LmsrPriceTransitionInput,
 };
-pub use network::Network;
+pub use intent::{BroadcastIntent, IntentJournal, IntentKind, IntentStatus, NoIntentJournal};
+pub use network::{NETWORK_MARKER_FILE, Network, ensure_network_marker};
 pub use node::DeadcatNode;
 pub use prediction_market::anchor::{
     DormantOutputOpening, PredictionMarketAnchor, parse_market_creation_txid,
     parse_prediction_market_anchor,
 };
 pub use prediction_market::contract::CompiledPredictionMarket;
+pub use prediction_market::params::amounts;
 pub use prediction_market::params::{MarketId, PredictionMarketParams};
 pub use prediction_market::state::{MarketSlot, MarketState};
 pub use pset::UnblindedUtxo;
 pub use sdk::{
-    CancelOrderResult, CancellationResult, CreateOrderResult, FillOrderResult, IssuanceResult,
-    RedemptionResult, ResolutionResult,
+    CancelAllOutcome, CancelOrderResult, CancelOrderTarget, CancellationResult,
+    ConsolidationResult, CreateOrderResult, ExpiredRedemptionSweep, ExplicitUtxo, FeeBumpResult,
+    FillOrderResult, FillSimulation, IssuanceResult, RedemptionPreview, RedemptionResult,
+    RepriceOrderResult, ResolutionResult, SweepKey, SweepResult, TopUpOrderResult,
+    TransactionDetail, TransactionDetailEntry,
 };
 pub use taproot::NUMS_KEY_BYTES;
+pub use telemetry::TARGET as TELEMETRY_LOG_TARGET;
+pub use tx_cache::{NoTransactionCache, TransactionCache};
 
 // Re-export LWK for app-layer use
 pub use lwk_wollet;
 
 // ── Node ──────────────────────────────────────────────────────────
-pub use node::WalletSnapshot;
+pub use node::{
+    ORDER_SCAN_TTL, OwnedAssetRole, OwnedPosition, OwnedPositionScan, PoolVerification,
+    PoolVerificationStatus, WalletInfo, WalletSnapshot,
+};
 
 // ── Maker orders ───────────────────────────────────────────────────
 pub use maker_order::contract::CompiledMakerOrder;
 pub use maker_order::params::{
     MakerOrderParams, OrderDirection, derive_maker_receive, maker_receive_script_pubkey,
+    validate_order_amounts,
 };
 
 // ── LMSR pools ─────────────────────────────────────────────────────
@@ -70,8 +98,8 @@ pub use lmsr_pool::api::{
 };
 pub use lmsr_pool::contract::CompiledLmsrPool;
 pub use lmsr_pool::math::{
-    LmsrQuote, LmsrTradeKind, fee_free_yes_spot_price_bps, max_collateral_out, min_collateral_in,
-    quote_exact_input_from_manifest, quote_from_table,
+    LmsrQuote, LmsrTradeKind, MAX_CREATION_FEE_BPS, fee_free_yes_spot_price_bps,
+    max_collateral_out, min_collateral_in, quote_exact_input_from_manifest, quote_from_table,
 };
 pub use lmsr_pool::params::{LmsrInitialOutpoint, LmsrPoolId, LmsrPoolIdInput, LmsrPoolParams};
 pub use lmsr_pool::table::{
@@ -80,11 +108,12 @@ pub use lmsr_pool::table::{
 };
 
 // ── Pool helpers ───────────────────────────────────────────────────
-pub use pool::{PoolReserves, implied_probability_bps};
+pub use pool::{PoolAsset, PoolReserves, implied_probability_bps};
 
 // ── Trade routing ──────────────────────────────────────────────────
 pub use trade::types::{
-    LiquiditySource, RouteLeg, TradeAmount, TradeDirection, TradeQuote, TradeResult, TradeSide,
+    LiquiditySource, RouteLeg, RouteOutcome, TradeAmount, TradeDirection, TradeQuote, TradeResult,
+    TradeRouteComparison, TradeSide, TradeVenue,
 };
 
 // ── Discovery ─────────────────────────────────────────────────────
@@ -98,6 +127,7 @@ pub use discovery::{
     CONTRACT_TAG,
     ContractMetadataInput,
     DEFAULT_RELAYS,
+    DELETION_KIND,
     DiscoveredMarket,
     DiscoveredOrder,
     DiscoveredPool,
@@ -108,25 +138,42 @@ pub use discovery::{
     LmsrPoolIngestInput,
     LmsrPoolStateSource,
     LmsrPoolStateUpdateInput,
+    MAX_PUBLISH_ATTEMPTS,
     NETWORK_TAG,
     NodeStore,
     NoopStore,
     OrderAnnouncement,
+    PUBLISH_QUORUM,
     PoolAnnouncement,
     PoolParams,
     PredictionMarketCandidateIngestInput,
+    PublishRecord,
+    PublishReport,
+    PublishStatus,
+    RELAY_PUBLISH_TIMEOUT,
+    RelayRejection,
+    VerifiedAttestation,
     // Functions
+    apply_deletions,
     build_announcement_event,
     build_attestation_event,
     build_attestation_filter,
     build_contract_filter,
+    build_deletion_event,
+    build_deletion_filter,
     build_pool_event,
     connect_client,
     discovered_market_to_contract_params,
+    event_coordinate,
     fetch_announcements,
+    fetch_events_with_relays,
+    latest_replaceable,
     parse_announcement_event,
+    parse_attestation_event,
     publish_event,
+    publish_event_with_timeout,
     sign_attestation,
+    verify_attestation,
 };
 
 // ── Testing-only re-exports ────────────────────────────────────────
@@ -137,7 +184,8 @@ pub use discovery::{
 pub use discovery::build_order_event;
 #[cfg(feature = "testing")]
 pub use prediction_market::assembly::{
-    CollateralSource, IssuanceAssemblyInputs, IssuanceEntropy, compute_issuance_entropy,
+    BlindingAudit, CollateralSource, IssuanceAssemblyInputs, IssuanceEntropy,
+    OutputBlindingFactors, compute_issuance_entropy,
 };
 #[cfg(feature = "testing")]
 pub use prediction_market::oracle::oracle_message;
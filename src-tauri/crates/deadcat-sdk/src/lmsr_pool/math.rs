@@ -7,6 +7,11 @@ use crate::lmsr_pool::table::LmsrTableManifest;
 /// Basis-point denominator used by fee checks.
 pub const FEE_DENOM: u64 = 10_000;
 
+/// Highest swap fee a new pool may be created with (10%). The covenant only
+/// needs `fee_bps < FEE_DENOM`, so pools discovered from others are held to
+/// that looser bound instead.
+pub const MAX_CREATION_FEE_BPS: u64 = 1_000;
+
 /// Supported swap trade kinds for LMSR v0.1.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
@@ -32,7 +32,11 @@ pub struct LmsrPoolParams {
     pub s_max_index: u64,
     /// Half payout in sats (`U / 2` in the design doc).
     pub half_payout_sats: u64,
-    /// Swap fee in basis points.
+    /// Swap fee in basis points. Buys pay `ceil(base * 10000 / (10000 - fee_bps))`
+    /// collateral and sells receive `floor(base * (10000 - fee_bps) / 10000)`,
+    /// where `base` is the fee-free LMSR cost; see
+    /// [`min_collateral_in`](crate::lmsr_pool::math::min_collateral_in) and
+    /// [`max_collateral_out`](crate::lmsr_pool::math::max_collateral_out).
     pub fee_bps: u64,
     /// Minimum YES reserve enforced after each transition.
     pub min_r_yes: u64,
@@ -9,6 +9,7 @@ use simplicityhl::str::WitnessName;
 use simplicityhl::value::ValueConstructible;
 use simplicityhl::{Arguments, Value};
 
+use crate::error::{Error, Result};
 use crate::taproot::NUMS_KEY_BYTES;
 
 /// Order direction: whether the maker is selling BASE or QUOTE.
@@ -177,6 +178,52 @@ pub(crate) fn order_tweak(order_uid: &[u8; 32]) -> [u8; 32] {
     hasher.finalize().into()
 }
 
+/// Check that an order of `order_amount` offered units can actually be filled
+/// under the given lot constraints, returning its size in lots.
+///
+/// `order_amount` is in BASE lots for [`OrderDirection::SellBase`] and in QUOTE
+/// units for [`OrderDirection::SellQuote`], where it must be a whole multiple of
+/// `price`.
+pub fn validate_order_amounts(
+    price: u64,
+    order_amount: u64,
+    direction: OrderDirection,
+    min_fill_lots: u64,
+    min_remainder_lots: u64,
+) -> Result<u64> {
+    if price == 0 {
+        return Err(Error::ZeroPrice);
+    }
+    if order_amount == 0 {
+        return Err(Error::ZeroOrderAmount);
+    }
+    let order_lots = match direction {
+        OrderDirection::SellBase => order_amount,
+        OrderDirection::SellQuote => {
+            if order_amount % price != 0 {
+                return Err(Error::OrderAmountNotLotMultiple {
+                    order_amount,
+                    price,
+                });
+            }
+            order_amount / price
+        }
+    };
+    if order_lots < min_fill_lots {
+        return Err(Error::OrderBelowMinimumFill {
+            order_lots,
+            min_fill_lots,
+        });
+    }
+    if min_remainder_lots > order_lots {
+        return Err(Error::MinRemainderExceedsOrder {
+            order_lots,
+            min_remainder_lots,
+        });
+    }
+    Ok(order_lots)
+}
+
 /// Derive P_order (the maker's unique receive x-only pubkey) from their base
 /// pubkey and the order tweak.
 ///
@@ -335,4 +382,42 @@ mod tests {
         let params2 = params;
         assert_eq!(params, params2);
     }
+
+    #[test]
+    fn validate_order_amounts_accepts_fillable_orders() {
+        let v = validate_order_amounts;
+        assert_eq!(v(50_000, 10, OrderDirection::SellBase, 1, 1).unwrap(), 10);
+        assert_eq!(
+            v(50_000, 500_000, OrderDirection::SellQuote, 10, 0).unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn validate_order_amounts_rejects_unfillable_orders() {
+        let v = validate_order_amounts;
+        assert!(matches!(
+            v(0, 10, OrderDirection::SellBase, 1, 1),
+            Err(Error::ZeroPrice)
+        ));
+        assert!(matches!(
+            v(100, 0, OrderDirection::SellBase, 1, 1),
+            Err(Error::ZeroOrderAmount)
+        ));
+        assert!(matches!(
+            v(100, 250, OrderDirection::SellQuote, 1, 1),
+            Err(Error::OrderAmountNotLotMultiple { .. })
+        ));
+        assert!(matches!(
+            v(100, 5, OrderDirection::SellBase, 6, 1),
+            Err(Error::OrderBelowMinimumFill {
+                order_lots: 5,
+                min_fill_lots: 6
+            })
+        ));
+        assert!(matches!(
+            v(100, 300, OrderDirection::SellQuote, 1, 4),
+            Err(Error::MinRemainderExceedsOrder { order_lots: 3, .. })
+        ));
+    }
 }
@@ -1,3 +1,4 @@
 pub mod cancel_order;
 pub mod create_order;
 pub mod fill_order;
+pub mod top_up_order;
@@ -0,0 +1,252 @@
+use simplicityhl::elements::Script;
+use simplicityhl::elements::pset::PartiallySignedTransaction;
+
+use crate::error::{Error, Result};
+use crate::maker_order::contract::CompiledMakerOrder;
+use crate::maker_order::params::OrderDirection;
+use crate::pset::{
+    UnblindedUtxo, add_pset_input, add_pset_output, explicit_txout, fee_txout, new_pset,
+};
+
+/// Parameters for constructing a top-up-order PSET.
+///
+/// The order UTXO is spent through the cancel path and recreated at the same
+/// covenant address with the additional amount, so the order keeps its
+/// params and announcement identifier.
+pub struct TopUpOrderParams {
+    /// The live order UTXO (cancel path — maker signature).
+    pub order_utxo: UnblindedUtxo,
+    /// The UTXO providing the additional offered asset.
+    pub funding_utxo: UnblindedUtxo,
+    /// The UTXO providing the fee. Same outpoint as `funding_utxo` when the
+    /// offered asset is the fee asset.
+    pub fee_utxo: UnblindedUtxo,
+    /// Amount of offered asset to add to the order.
+    pub additional_amount: u64,
+    /// Fee amount in sats.
+    pub fee_amount: u64,
+    /// The fee asset ID (typically L-BTC).
+    pub fee_asset_id: [u8; 32],
+    /// Where to send change from the funding UTXO (if any).
+    pub change_destination: Option<Script>,
+    /// Where to send fee change (if any).
+    pub fee_change_destination: Option<Script>,
+    /// The maker's base pubkey (used to derive the covenant address).
+    pub maker_base_pubkey: [u8; 32],
+}
+
+/// Build the top-up-order PSET.
+///
+/// ```text
+/// Inputs:  [0] order UTXO (cancel path — maker signature)
+///          [1] funding input (offered asset)
+///          [2] fee input (omitted when funding also pays the fee)
+/// Outputs: [0] order UTXO -> same covenant address, old + additional amount
+///          [1] fee output
+///          [2] change (optional)
+///          [3] fee change (optional)
+/// ```
+pub fn build_top_up_order_pset(
+    contract: &CompiledMakerOrder,
+    params: &TopUpOrderParams,
+) -> Result<PartiallySignedTransaction> {
+    if params.additional_amount == 0 {
+        return Err(Error::ZeroOrderAmount);
+    }
+
+    let combined = params.funding_utxo.outpoint == params.fee_utxo.outpoint;
+    let funding_needed = if combined {
+        params.additional_amount + params.fee_amount
+    } else {
+        params.additional_amount
+    };
+    if params.funding_utxo.value < funding_needed {
+        return Err(Error::InsufficientCollateral);
+    }
+    if !combined && params.fee_utxo.value < params.fee_amount {
+        return Err(Error::InsufficientFee);
+    }
+    let funding_change = params.funding_utxo.value - funding_needed;
+    if funding_change > 0 && params.change_destination.is_none() {
+        return Err(Error::MissingChangeDestination);
+    }
+    let fee_change = if combined {
+        0
+    } else {
+        params.fee_utxo.value - params.fee_amount
+    };
+    if fee_change > 0 && params.fee_change_destination.is_none() {
+        return Err(Error::MissingChangeDestination);
+    }
+
+    let offered_asset = match contract.params().direction {
+        OrderDirection::SellBase => &contract.params().base_asset_id,
+        OrderDirection::SellQuote => &contract.params().quote_asset_id,
+    };
+    if params.order_utxo.asset_id != *offered_asset
+        || params.funding_utxo.asset_id != *offered_asset
+    {
+        return Err(Error::MakerOrder(
+            "top-up inputs must hold the order's offered asset".into(),
+        ));
+    }
+    let order_amount = params
+        .order_utxo
+        .value
+        .checked_add(params.additional_amount)
+        .ok_or_else(|| Error::MakerOrder("topped-up order amount overflows".into()))?;
+
+    let covenant_spk = contract.script_pubkey(&params.maker_base_pubkey);
+
+    let mut pset = new_pset();
+
+    // Inputs
+    add_pset_input(&mut pset, &params.order_utxo);
+    add_pset_input(&mut pset, &params.funding_utxo);
+    if !combined {
+        add_pset_input(&mut pset, &params.fee_utxo);
+    }
+
+    // Output 0: order UTXO -> covenant address
+    add_pset_output(
+        &mut pset,
+        explicit_txout(offered_asset, order_amount, &covenant_spk),
+    );
+
+    // Output 1: fee
+    add_pset_output(
+        &mut pset,
+        fee_txout(&params.fee_asset_id, params.fee_amount),
+    );
+
+    // Output 2: change from funding (optional)
+    if funding_change > 0
+        && let Some(ref change_spk) = params.change_destination
+    {
+        add_pset_output(
+            &mut pset,
+            explicit_txout(offered_asset, funding_change, change_spk),
+        );
+    }
+
+    // Output 3: fee change (optional)
+    if fee_change > 0
+        && let Some(ref change_spk) = params.fee_change_destination
+    {
+        add_pset_output(
+            &mut pset,
+            explicit_txout(&params.fee_asset_id, fee_change, change_spk),
+        );
+    }
+
+    Ok(pset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maker_order::params::MakerOrderParams;
+    use crate::taproot::NUMS_KEY_BYTES;
+    use crate::testing::test_explicit_utxo;
+
+    const BASE_ASSET: [u8; 32] = [0x01; 32];
+    const QUOTE_ASSET: [u8; 32] = [0xbb; 32];
+    const MAKER_PUBKEY: [u8; 32] = [0xaa; 32];
+
+    fn contract(direction: OrderDirection) -> CompiledMakerOrder {
+        let (params, _) = MakerOrderParams::new(
+            BASE_ASSET,
+            QUOTE_ASSET,
+            50_000,
+            1,
+            1,
+            direction,
+            NUMS_KEY_BYTES,
+            &MAKER_PUBKEY,
+            &[0x11; 32],
+        );
+        CompiledMakerOrder::new(params).unwrap()
+    }
+
+    fn top_up_params(
+        contract: &CompiledMakerOrder,
+        order_asset: [u8; 32],
+        funding_utxo: UnblindedUtxo,
+        fee_utxo: UnblindedUtxo,
+    ) -> TopUpOrderParams {
+        let covenant_spk = contract.script_pubkey(&MAKER_PUBKEY);
+        TopUpOrderParams {
+            order_utxo: test_explicit_utxo(&order_asset, 300, &covenant_spk, 9),
+            funding_utxo,
+            fee_utxo,
+            additional_amount: 200,
+            fee_amount: 100,
+            fee_asset_id: QUOTE_ASSET,
+            change_destination: Some(Script::new()),
+            fee_change_destination: Some(Script::new()),
+            maker_base_pubkey: MAKER_PUBKEY,
+        }
+    }
+
+    #[test]
+    fn recreates_order_at_same_covenant_with_combined_amount() {
+        let contract = contract(OrderDirection::SellBase);
+        let params = top_up_params(
+            &contract,
+            BASE_ASSET,
+            test_explicit_utxo(&BASE_ASSET, 250, &Script::new(), 0),
+            test_explicit_utxo(&QUOTE_ASSET, 150, &Script::new(), 1),
+        );
+        let pset = build_top_up_order_pset(&contract, &params).unwrap();
+
+        assert_eq!(pset.inputs().len(), 3);
+        // order + fee + funding change + fee change
+        assert_eq!(pset.outputs().len(), 4);
+        let order_out = &pset.outputs()[0];
+        assert_eq!(
+            order_out.script_pubkey,
+            contract.script_pubkey(&MAKER_PUBKEY)
+        );
+        assert_eq!(order_out.amount, Some(500));
+    }
+
+    #[test]
+    fn combined_funding_pays_fee() {
+        let contract = contract(OrderDirection::SellQuote);
+        let funding = test_explicit_utxo(&QUOTE_ASSET, 300, &Script::new(), 0);
+        let params = top_up_params(&contract, QUOTE_ASSET, funding.clone(), funding);
+        let pset = build_top_up_order_pset(&contract, &params).unwrap();
+
+        assert_eq!(pset.inputs().len(), 2);
+        // order + fee, no change
+        assert_eq!(pset.outputs().len(), 2);
+        assert_eq!(pset.outputs()[0].amount, Some(500));
+    }
+
+    #[test]
+    fn rejects_funding_below_additional_amount() {
+        let contract = contract(OrderDirection::SellBase);
+        let params = top_up_params(
+            &contract,
+            BASE_ASSET,
+            test_explicit_utxo(&BASE_ASSET, 150, &Script::new(), 0),
+            test_explicit_utxo(&QUOTE_ASSET, 150, &Script::new(), 1),
+        );
+        let result = build_top_up_order_pset(&contract, &params);
+        assert!(matches!(result, Err(Error::InsufficientCollateral)));
+    }
+
+    #[test]
+    fn rejects_zero_additional_amount() {
+        let contract = contract(OrderDirection::SellBase);
+        let mut params = top_up_params(
+            &contract,
+            BASE_ASSET,
+            test_explicit_utxo(&BASE_ASSET, 250, &Script::new(), 0),
+            test_explicit_utxo(&QUOTE_ASSET, 150, &Script::new(), 1),
+        );
+        params.additional_amount = 0;
+        let result = build_top_up_order_pset(&contract, &params);
+        assert!(matches!(result, Err(Error::ZeroOrderAmount)));
+    }
+}
@@ -1,8 +1,15 @@
+use std::path::Path;
+
 use lwk_wollet::ElementsNetwork;
 use lwk_wollet::elements::AddressParams;
 use lwk_wollet::elements::hashes::Hash as _;
 use serde::Deserialize;
 
+use crate::error::{Error, Result};
+
+/// File in a per-network data directory recording which network it holds.
+pub const NETWORK_MARKER_FILE: &str = "network";
+
 /// Network variants for Liquid.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -49,6 +56,14 @@ impl Network {
         }
     }
 
+    /// Display ticker of the policy asset.
+    pub fn policy_asset_ticker(self) -> &'static str {
+        match self {
+            Network::Liquid => "L-BTC",
+            Network::LiquidTestnet | Network::LiquidRegtest => "tL-BTC",
+        }
+    }
+
     pub fn as_str(self) -> &'static str {
         match self {
             Network::Liquid => "mainnet",
@@ -57,7 +72,7 @@ impl Network {
         }
     }
 
-    pub fn discovery_tag(self) -> &'static str {
+    pub const fn discovery_tag(self) -> &'static str {
         match self {
             Network::Liquid => "liquid",
             Network::LiquidTestnet => "liquid-testnet",
@@ -65,6 +80,17 @@ impl Network {
         }
     }
 
+    /// Nostr relays discovery connects to until the user picks their own.
+    ///
+    /// Mainnet and testnet share public relays and are kept apart by
+    /// [`discovery_tag`](Self::discovery_tag); regtest expects a local relay.
+    pub fn default_relays(self) -> &'static [&'static str] {
+        match self {
+            Network::Liquid | Network::LiquidTestnet => crate::discovery::DEFAULT_RELAYS,
+            Network::LiquidRegtest => &["ws://localhost:7777"],
+        }
+    }
+
     pub fn genesis_hash(self) -> [u8; 32] {
         self.into_lwk().genesis_block_hash().to_byte_array()
     }
@@ -90,6 +116,31 @@ impl Network {
     }
 }
 
+/// Check that `dir` holds data for `network`, writing the marker on first
+/// use. Directories created before the marker existed are adopted.
+pub fn ensure_network_marker(dir: &Path, network: Network) -> Result<()> {
+    let marker = dir.join(NETWORK_MARKER_FILE);
+    match std::fs::read_to_string(&marker) {
+        Ok(found) => {
+            let found = found.trim();
+            if found.parse::<Network>().ok() == Some(network) {
+                Ok(())
+            } else {
+                Err(Error::NetworkMismatch {
+                    expected: network.as_str().to_string(),
+                    found: found.to_string(),
+                })
+            }
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => std::fs::create_dir_all(dir)
+            .and_then(|()| std::fs::write(&marker, network.as_str()))
+            .map_err(|e| Error::WalletInit(format!("failed to write network marker: {e}"))),
+        Err(e) => Err(Error::WalletInit(format!(
+            "failed to read network marker: {e}"
+        ))),
+    }
+}
+
 impl std::str::FromStr for Network {
     type Err = String;
 
@@ -7,14 +7,17 @@
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use lwk_wollet::elements::{AssetId, Transaction, Txid};
+use lwk_wollet::elements::{Address, AssetId, Script, Transaction, Txid};
 use lwk_wollet::{AddressResult, WalletTx, WalletTxOut};
 use nostr_sdk::prelude::*;
 use tokio::sync::{broadcast, watch};
 use tokio::task::JoinHandle;
 
 use crate::announcement::{CONTRACT_ANNOUNCEMENT_VERSION, ContractAnnouncement, ContractMetadata};
+use crate::coin_selection::{CoinSelection, FeeOperation};
+use crate::descriptor::DescriptorTemplate;
 use crate::discovery::config::DiscoveryConfig;
 use crate::discovery::events::DiscoveryEvent;
 use crate::discovery::market::{DiscoveredMarket, ParsedDiscoveredMarketAnnouncement};
@@ -27,10 +30,11 @@ use crate::discovery::store_trait::{
     PredictionMarketCandidateIngestInput,
 };
 use crate::discovery::{
-    AttestationContent, AttestationResult, DEFAULT_RELAYS, DiscoveredOrder, OrderAnnouncement,
-    bytes_to_hex,
+    AttestationContent, AttestationResult, DiscoveredOrder, OrderAnnouncement, PublishRecord,
+    PublishReport, VerifiedAttestation, bytes_to_hex,
 };
 use crate::error::{Error, NodeError};
+use crate::intent::{BroadcastIntent, IntentStatus};
 use crate::lmsr_pool::api::{
     CreateLmsrPoolRequest, CreateLmsrPoolResult, LmsrPoolLocator, LmsrPoolSnapshot,
     build_pool_announcement_from_snapshot, txid_to_canonical_bytes,
@@ -41,15 +45,21 @@ use crate::lmsr_pool::params::LmsrPoolId;
 use crate::lmsr_pool::table::LmsrTableManifest;
 use crate::maker_order::params::{MakerOrderParams, OrderDirection};
 use crate::network::Network;
+use crate::pool::PoolReserves;
 use crate::prediction_market::anchor::PredictionMarketAnchor;
 use crate::prediction_market::contract::CompiledPredictionMarket;
 use crate::prediction_market::params::{MarketId, PredictionMarketParams};
 use crate::prediction_market::state::MarketState;
 use crate::sdk::{
-    CancelOrderResult, CancellationResult, CreateOrderResult, DeadcatSdk, FillOrderResult,
-    IssuanceResult, RedemptionResult, ResolutionResult,
+    CancelAllOutcome, CancelOrderResult, CancelOrderTarget, CancellationResult,
+    ConsolidationResult, CreateOrderResult, DeadcatSdk, ExpiredRedemptionSweep, ExplicitUtxo,
+    FeeBumpResult, FillOrderResult, FillSimulation, IssuanceResult, RedemptionPreview,
+    RedemptionResult, RepriceOrderResult, ResolutionResult, SweepKey, SweepResult,
+    TopUpOrderResult, TransactionDetail,
+};
+use crate::trade::types::{
+    TradeAmount, TradeDirection, TradeQuote, TradeResult, TradeRouteComparison, TradeSide,
 };
-use crate::trade::types::{TradeAmount, TradeDirection, TradeQuote, TradeResult, TradeSide};
 use crate::{LmsrPoolSyncRepairInput, LmsrPriceHistoryEntry, LmsrPriceTransitionInput};
 
 // ── Wallet snapshot ────────────────────────────────────────────────────────
@@ -65,6 +75,78 @@ pub struct WalletSnapshot {
     pub transactions: Vec<WalletTx>,
 }
 
+/// Signer identity and sync height of the unlocked wallet, from
+/// [`DeadcatNode::wallet_info`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalletInfo {
+    pub fingerprint: String,
+    pub xpub: String,
+    /// Height of the last block the wallet synced to.
+    pub synced_height: u32,
+}
+
+/// What a wallet-held asset is within a discovered market.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OwnedAssetRole {
+    YesToken,
+    NoToken,
+    YesReissuanceToken,
+    NoReissuanceToken,
+}
+
+/// A wallet balance matched to a market announced on Nostr.
+#[derive(Clone, Debug)]
+pub struct OwnedPosition {
+    pub asset_id: AssetId,
+    pub amount: u64,
+    pub market_id: String,
+    pub role: OwnedAssetRole,
+}
+
+/// Outcome of [`DeadcatNode::discover_owned_positions`].
+#[derive(Clone, Debug, Default)]
+pub struct OwnedPositionScan {
+    pub matched: Vec<OwnedPosition>,
+    /// Non-policy assets held by the wallet that no announced market or pool uses.
+    pub unmatched: Vec<(AssetId, u64)>,
+}
+
+/// How an announced LMSR pool compares with the chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoolVerificationStatus {
+    /// The announced state index and reserves match the chain.
+    Verified,
+    /// The pool is live, but its announcement no longer matches the chain.
+    Stale,
+    /// The announced anchors don't lead to a live pool covenant.
+    Invalid(String),
+}
+
+impl PoolVerificationStatus {
+    /// Stable name of the verdict, as stored and sent to the UI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PoolVerificationStatus::Verified => "verified",
+            PoolVerificationStatus::Stale => "stale",
+            PoolVerificationStatus::Invalid(_) => "invalid",
+        }
+    }
+}
+
+/// Outcome of [`DeadcatNode::verify_pool`].
+#[derive(Clone, Debug)]
+pub struct PoolVerification {
+    pub pool_id: String,
+    pub status: PoolVerificationStatus,
+    /// `(current_s_index, reserves)` from the stored announcement, if any.
+    pub announced: Option<(u64, PoolReserves)>,
+    /// Canonical scan result; `None` for invalid pools.
+    pub snapshot: Option<LmsrPoolSnapshot>,
+}
+
+/// How long [`DeadcatNode::discover_live_orders`] reuses a covenant scan.
+pub const ORDER_SCAN_TTL: Duration = Duration::from_secs(30);
+
 // ── Struct ──────────────────────────────────────────────────────────────────
 
 /// Unified coordinator that owns the SDK wallet, Nostr discovery service,
@@ -80,7 +162,11 @@ pub struct DeadcatNode<S: DiscoveryStore = NoopStore> {
     discovery: DiscoveryService<S>,
     keys: Keys,
     network: Network,
+    /// `network`'s policy asset, computed once at construction.
+    policy_asset: AssetId,
     store: Option<Arc<Mutex<S>>>,
+    /// Covenant address → (scanned at, offered amount still locked there).
+    order_scan_cache: Mutex<HashMap<String, (Instant, u64)>>,
 }
 
 // ── Construction ────────────────────────────────────────────────────────────
@@ -103,7 +189,9 @@ impl DeadcatNode<NoopStore> {
                 discovery,
                 keys,
                 network,
+                policy_asset: network.into_lwk().policy_asset(),
                 store: None,
+                order_scan_cache: Mutex::new(HashMap::new()),
             },
             rx,
         )
@@ -129,7 +217,9 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
                 discovery,
                 keys,
                 network,
+                policy_asset: network.into_lwk().policy_asset(),
                 store: Some(store),
+                order_scan_cache: Mutex::new(HashMap::new()),
             },
             rx,
         )
@@ -137,19 +227,46 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
 
     // ── Wallet lifecycle ────────────────────────────────────────────────
 
-    /// Unlock the wallet by initializing the SDK with the given mnemonic.
+    /// Unlock the wallet by initializing the SDK with the given mnemonic and
+    /// the default descriptor template.
     pub fn unlock_wallet(
         &self,
         mnemonic: &str,
         electrum_url: &str,
         datadir: &Path,
+    ) -> Result<(), NodeError> {
+        self.unlock_wallet_with_descriptor(
+            mnemonic,
+            electrum_url,
+            datadir,
+            DescriptorTemplate::default(),
+        )
+    }
+
+    /// Unlock the wallet using the descriptor shape it was created with.
+    pub fn unlock_wallet_with_descriptor(
+        &self,
+        mnemonic: &str,
+        electrum_url: &str,
+        datadir: &Path,
+        template: DescriptorTemplate,
     ) -> Result<(), NodeError> {
         let mut guard = self.sdk.lock().map_err(|_| NodeError::MutexPoisoned)?;
         if guard.is_some() {
             return Err(NodeError::WalletAlreadyUnlocked);
         }
-        let sdk = DeadcatSdk::new(mnemonic, self.network, electrum_url, datadir)
-            .map_err(NodeError::Sdk)?;
+        let mut sdk = DeadcatSdk::with_descriptor_template(
+            mnemonic,
+            self.network,
+            electrum_url,
+            datadir,
+            template,
+        )
+        .map_err(NodeError::Sdk)?;
+        if let Some(store) = &self.store {
+            sdk.set_transaction_cache(Box::new(Arc::clone(store)));
+            sdk.set_intent_journal(Box::new(Arc::clone(store)));
+        }
         // Seed the snapshot so balance/utxos/transactions are available
         // immediately, without waiting for the first with_sdk call.
         let snapshot = WalletSnapshot {
@@ -265,9 +382,8 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
 
     /// Create a market on-chain and announce it via Nostr.
     ///
-    /// Returns the parsed `DiscoveredMarket` (with Nostr event data) and the
     /// Returns the discovered market announcement persisted for the newly
-    /// created on-chain market.
+    /// created on-chain market, along with which relays accepted it.
     ///
     /// **Non-atomic:** If the on-chain transaction succeeds but the Nostr
     /// announcement fails, the caller receives an error even though on-chain
@@ -281,7 +397,7 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         min_utxo_value: u64,
         fee_amount: u64,
         metadata: ContractMetadata,
-    ) -> Result<DiscoveredMarket, NodeError> {
+    ) -> Result<(DiscoveredMarket, PublishReport), NodeError> {
         // 1. On-chain via spawn_blocking
         let (anchor, params) = self
             .with_sdk(move |sdk| {
@@ -317,17 +433,21 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             creation_tx_hex: creation_tx_hex.clone(),
         };
 
-        let event_id = self
+        let report = self
             .discovery
             .announce_market(&announcement)
             .await
             .map_err(NodeError::Discovery)?;
+        let event_id = report.event_id;
 
         // 3. Build DiscoveredMarket from announcement data + the real event ID
         let market_id = params.market_id();
-        let nevent = Nip19Event::new(event_id, DEFAULT_RELAYS.iter().map(|r| r.to_string()))
-            .to_bech32()
-            .unwrap_or_default();
+        let nevent = Nip19Event::new(
+            event_id,
+            self.network.default_relays().iter().map(|r| r.to_string()),
+        )
+        .to_bech32()
+        .unwrap_or_default();
 
         let market = DiscoveredMarket {
             id: event_id.to_hex(),
@@ -376,14 +496,14 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         // 4. Persist to store
         self.persist_market(&parsed);
 
-        Ok(market)
+        Ok((market, report))
     }
 
     /// Announce an existing market to Nostr (no on-chain operation).
     pub async fn announce_market(
         &self,
         announcement: &ContractAnnouncement,
-    ) -> Result<EventId, NodeError> {
+    ) -> Result<PublishReport, NodeError> {
         self.discovery
             .announce_market(announcement)
             .await
@@ -397,9 +517,13 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         anchor: PredictionMarketAnchor,
         pairs: u64,
         fee_amount: u64,
+        fee_asset_id: Option<[u8; 32]>,
+        dry_run: bool,
     ) -> Result<IssuanceResult, NodeError> {
-        self.with_sdk(move |sdk| sdk.issue_tokens(&params, &anchor, pairs, fee_amount))
-            .await
+        self.with_sdk(move |sdk| {
+            sdk.issue_tokens(&params, &anchor, pairs, fee_amount, fee_asset_id, dry_run)
+        })
+        .await
     }
 
     /// Create a limit order on-chain and announce it via Nostr.
@@ -427,7 +551,7 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         fee_amount: u64,
         market_id: String,
         direction_label: String,
-    ) -> Result<(CreateOrderResult, EventId), NodeError> {
+    ) -> Result<(CreateOrderResult, PublishReport), NodeError> {
         // 1. On-chain
         let result = self
             .with_sdk(move |sdk| {
@@ -457,29 +581,140 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             direction_label,
         };
 
-        let event_id = self
+        let report = self
             .discovery
             .announce_order(&announcement)
             .await
             .map_err(NodeError::Discovery)?;
 
-        Ok((result, event_id))
+        Ok((result, report))
     }
 
-    /// Cancel a limit order on-chain.
+    /// Cancel a limit order on-chain, optionally replacing a pending fill.
     pub async fn cancel_limit_order(
         &self,
         params: MakerOrderParams,
         maker_pubkey: [u8; 32],
         order_index: u32,
         fee_amount: u64,
+        outbid_pending_fill: bool,
     ) -> Result<CancelOrderResult, NodeError> {
         self.with_sdk(move |sdk| {
-            sdk.cancel_limit_order(&params, maker_pubkey, order_index, fee_amount)
+            sdk.cancel_limit_order(
+                &params,
+                maker_pubkey,
+                order_index,
+                fee_amount,
+                outbid_pending_fill,
+            )
         })
         .await
     }
 
+    /// Add funds to a live limit order and re-announce it with the new size.
+    ///
+    /// The announcement replaces the order's earlier one (same NIP-33
+    /// identifier). **Non-atomic** like
+    /// [`create_limit_order`](Self::create_limit_order): a failed
+    /// announcement is reported after the top-up has been broadcast.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn top_up_limit_order(
+        &self,
+        params: MakerOrderParams,
+        maker_pubkey: [u8; 32],
+        order_nonce: [u8; 32],
+        order_index: u32,
+        additional_amount: u64,
+        fee_amount: u64,
+        market_id: String,
+        direction_label: String,
+    ) -> Result<(TopUpOrderResult, PublishReport), NodeError> {
+        let result = self
+            .with_sdk(move |sdk| {
+                sdk.top_up_limit_order(
+                    &params,
+                    maker_pubkey,
+                    order_index,
+                    additional_amount,
+                    fee_amount,
+                )
+            })
+            .await?;
+
+        let announcement = OrderAnnouncement {
+            version: 1,
+            params,
+            market_id,
+            maker_base_pubkey: hex::encode(maker_pubkey),
+            order_nonce: hex::encode(order_nonce),
+            covenant_address: result.covenant_address.clone(),
+            offered_amount: result.order_amount,
+            direction_label,
+        };
+        let report = self
+            .discovery
+            .announce_order(&announcement)
+            .await
+            .map_err(NodeError::Discovery)?;
+
+        Ok((result, report))
+    }
+
+    /// Move a live limit order to `new_price` and announce the new order.
+    ///
+    /// The new order has its own nonce, so its announcement does not replace
+    /// the old one; the caller deletes that separately. **Non-atomic** like
+    /// [`create_limit_order`](Self::create_limit_order). Nothing is announced
+    /// when the order was cancelled but not recreated.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reprice_limit_order(
+        &self,
+        params: MakerOrderParams,
+        maker_pubkey: [u8; 32],
+        order_index: u32,
+        new_price: u64,
+        fee_amount: u64,
+        market_id: String,
+        direction_label: String,
+    ) -> Result<(RepriceOrderResult, Option<PublishReport>), NodeError> {
+        let result = self
+            .with_sdk(move |sdk| {
+                sdk.reprice_limit_order(&params, maker_pubkey, order_index, new_price, fee_amount)
+            })
+            .await?;
+
+        let Ok(created) = &result.create else {
+            return Ok((result, None));
+        };
+        let announcement = OrderAnnouncement {
+            version: 1,
+            params: created.order_params,
+            market_id,
+            maker_base_pubkey: hex::encode(created.maker_base_pubkey),
+            order_nonce: hex::encode(created.order_nonce),
+            covenant_address: created.covenant_address.clone(),
+            offered_amount: created.order_amount,
+            direction_label,
+        };
+        let report = self
+            .discovery
+            .announce_order(&announcement)
+            .await
+            .map_err(NodeError::Discovery)?;
+
+        Ok((result, Some(report)))
+    }
+
+    /// Cancel several limit orders, reporting the outcome of each.
+    pub async fn cancel_all_orders(
+        &self,
+        targets: Vec<CancelOrderTarget>,
+        fee_amount: u64,
+    ) -> Result<Vec<CancelAllOutcome>, NodeError> {
+        self.with_sdk(move |sdk| Ok(sdk.cancel_all_orders(&targets, fee_amount)))
+            .await
+    }
+
     /// Fill a limit order on-chain.
     pub async fn fill_limit_order(
         &self,
@@ -495,6 +730,18 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         .await
     }
 
+    /// Dry-run a limit order fill against the current wallet balance.
+    pub async fn simulate_fill(
+        &self,
+        params: MakerOrderParams,
+        maker_pubkey: [u8; 32],
+        lots: u64,
+        fee_amount: u64,
+    ) -> Result<FillSimulation, NodeError> {
+        self.with_sdk(move |sdk| sdk.simulate_fill(&params, maker_pubkey, lots, fee_amount))
+            .await
+    }
+
     // ── Oracle ──────────────────────────────────────────────────────────
 
     /// Sign and publish an oracle attestation via Nostr.
@@ -510,6 +757,39 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             .map_err(NodeError::Discovery)
     }
 
+    /// Sign and publish an oracle attestation with a dedicated oracle key
+    /// instead of the node's Nostr identity.
+    pub async fn attest_market_with_keys(
+        &self,
+        oracle_keys: &Keys,
+        market_id: &MarketId,
+        announcement_event_id: &str,
+        outcome_yes: bool,
+    ) -> Result<AttestationResult, NodeError> {
+        self.discovery
+            .publish_attestation_with_keys(
+                oracle_keys,
+                market_id,
+                announcement_event_id,
+                outcome_yes,
+            )
+            .await
+            .map_err(NodeError::Discovery)
+    }
+
+    /// Fetch the market's attestation from relays, verified against
+    /// `oracle_public_key`.
+    pub async fn fetch_market_attestation(
+        &self,
+        market_id: &MarketId,
+        oracle_public_key: &[u8; 32],
+    ) -> Result<Option<VerifiedAttestation>, NodeError> {
+        self.discovery
+            .fetch_verified_attestation(market_id, oracle_public_key)
+            .await
+            .map_err(NodeError::Discovery)
+    }
+
     /// Resolve a market on-chain with an oracle signature.
     pub async fn resolve_market(
         &self,
@@ -518,9 +798,17 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         outcome_yes: bool,
         oracle_sig: [u8; 64],
         fee_amount: u64,
+        dry_run: bool,
     ) -> Result<ResolutionResult, NodeError> {
         self.with_sdk(move |sdk| {
-            sdk.resolve_market(&params, &anchor, outcome_yes, oracle_sig, fee_amount)
+            sdk.resolve_market(
+                &params,
+                &anchor,
+                outcome_yes,
+                oracle_sig,
+                fee_amount,
+                dry_run,
+            )
         })
         .await
     }
@@ -534,8 +822,9 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         anchor: PredictionMarketAnchor,
         tokens: u64,
         fee_amount: u64,
+        dry_run: bool,
     ) -> Result<RedemptionResult, NodeError> {
-        self.with_sdk(move |sdk| sdk.redeem_tokens(&params, &anchor, tokens, fee_amount))
+        self.with_sdk(move |sdk| sdk.redeem_tokens(&params, &anchor, tokens, fee_amount, dry_run))
             .await
     }
 
@@ -547,13 +836,38 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         token_asset: [u8; 32],
         tokens: u64,
         fee_amount: u64,
+        dry_run: bool,
     ) -> Result<RedemptionResult, NodeError> {
         self.with_sdk(move |sdk| {
-            sdk.redeem_expired(&params, &anchor, token_asset, tokens, fee_amount)
+            sdk.redeem_expired(&params, &anchor, token_asset, tokens, fee_amount, dry_run)
         })
         .await
     }
 
+    /// Redeem every YES and NO token held for an expired market.
+    pub async fn redeem_expired_all(
+        &self,
+        params: PredictionMarketParams,
+        anchor: PredictionMarketAnchor,
+        fee_amount: u64,
+        dry_run: bool,
+    ) -> Result<ExpiredRedemptionSweep, NodeError> {
+        self.with_sdk(move |sdk| sdk.redeem_expired_all(&params, &anchor, fee_amount, dry_run))
+            .await
+    }
+
+    /// Preview a redemption's payout and any token or collateral shortfall.
+    pub async fn preview_redemption(
+        &self,
+        params: PredictionMarketParams,
+        anchor: PredictionMarketAnchor,
+        tokens: u64,
+        token_asset: Option<[u8; 32]>,
+    ) -> Result<RedemptionPreview, NodeError> {
+        self.with_sdk(move |sdk| sdk.preview_redemption(&params, &anchor, tokens, token_asset))
+            .await
+    }
+
     /// Cancel token pairs by burning equal YES and NO tokens.
     pub async fn cancel_tokens(
         &self,
@@ -561,9 +875,13 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         anchor: PredictionMarketAnchor,
         pairs: u64,
         fee_amount: u64,
+        fee_asset_id: Option<[u8; 32]>,
+        dry_run: bool,
     ) -> Result<CancellationResult, NodeError> {
-        self.with_sdk(move |sdk| sdk.cancel_tokens(&params, &anchor, pairs, fee_amount))
-            .await
+        self.with_sdk(move |sdk| {
+            sdk.cancel_tokens(&params, &anchor, pairs, fee_amount, fee_asset_id, dry_run)
+        })
+        .await
     }
 
     // ── Trade routing ────────────────────────────────────────────────────
@@ -582,13 +900,7 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         direction: TradeDirection,
         amount: TradeAmount,
     ) -> Result<TradeQuote, NodeError> {
-        use crate::lmsr_pool::table::LmsrTableManifest;
-        use crate::maker_order::params::OrderDirection as OD;
-        use crate::pset::UnblindedUtxo;
-        use crate::trade::convert::{parse_discovered_lmsr_pool, parse_discovered_order};
-        use crate::trade::router::{
-            ScannedLmsrPool, ScannedOrder, build_execution_plan, plan_to_route_legs,
-        };
+        use crate::trade::router::{build_execution_plan, plan_to_route_legs};
 
         // Only ExactInput supported for now
         let total_input = match amount {
@@ -598,6 +910,89 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             }
         };
 
+        self.route_with_liquidity(market_id, move |lmsr_pool, orders| {
+            let plan = build_execution_plan(
+                lmsr_pool,
+                orders,
+                side,
+                direction,
+                total_input,
+                &contract_params.collateral_asset_id,
+                &contract_params.yes_token_asset,
+                &contract_params.no_token_asset,
+            )?;
+
+            let legs = plan_to_route_legs(&plan, orders);
+
+            let effective_price = if plan.total_taker_output > 0 {
+                plan.total_taker_input as f64 / plan.total_taker_output as f64
+            } else {
+                f64::INFINITY
+            };
+
+            Ok(TradeQuote {
+                side,
+                direction,
+                amount,
+                total_input: plan.total_taker_input,
+                total_output: plan.total_taker_output,
+                effective_price,
+                legs,
+                plan,
+            })
+        })
+        .await
+    }
+
+    /// Quote an exact-input trade through the pool alone, the order book
+    /// alone, and both together, and recommend the best.
+    ///
+    /// `fee_amount` is the network fee the trade would pay, charged to every
+    /// route so their net prices compare directly. A venue with no liquidity
+    /// leaves its route `None`.
+    pub async fn compare_trade_routes(
+        &self,
+        contract_params: PredictionMarketParams,
+        market_id: &str,
+        side: TradeSide,
+        direction: TradeDirection,
+        total_input: u64,
+        fee_amount: u64,
+    ) -> Result<TradeRouteComparison, NodeError> {
+        use crate::trade::router::compare_routes;
+
+        self.route_with_liquidity(market_id, move |lmsr_pool, orders| {
+            Ok(compare_routes(
+                lmsr_pool,
+                orders,
+                side,
+                direction,
+                total_input,
+                fee_amount,
+                &contract_params,
+            ))
+        })
+        .await
+    }
+
+    /// Discover a market's LMSR pool and limit orders, scan their live UTXOs,
+    /// and hand them to `route` on the SDK thread.
+    async fn route_with_liquidity<F, R>(&self, market_id: &str, route: F) -> Result<R, NodeError>
+    where
+        F: FnOnce(
+                Option<&crate::trade::router::ScannedLmsrPool>,
+                &[crate::trade::router::ScannedOrder],
+            ) -> Result<R, Error>
+            + Send
+            + 'static,
+        R: Send + 'static,
+    {
+        use crate::lmsr_pool::table::LmsrTableManifest;
+        use crate::maker_order::params::OrderDirection as OD;
+        use crate::pset::UnblindedUtxo;
+        use crate::trade::convert::{parse_discovered_lmsr_pool, parse_discovered_order};
+        use crate::trade::router::{ScannedLmsrPool, ScannedOrder};
+
         // 1. Fetch Nostr data
         let pools = self.fetch_pools(Some(market_id)).await?;
         let orders = self.fetch_orders(Some(market_id)).await?;
@@ -733,36 +1128,7 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
                 None
             };
 
-            // Route
-            let plan = build_execution_plan(
-                scanned_lmsr_pool.as_ref(),
-                &scanned_orders,
-                side,
-                direction,
-                total_input,
-                &contract_params.collateral_asset_id,
-                &contract_params.yes_token_asset,
-                &contract_params.no_token_asset,
-            )?;
-
-            let legs = plan_to_route_legs(&plan, &scanned_orders);
-
-            let effective_price = if plan.total_taker_output > 0 {
-                plan.total_taker_input as f64 / plan.total_taker_output as f64
-            } else {
-                f64::INFINITY
-            };
-
-            Ok(TradeQuote {
-                side,
-                direction,
-                amount,
-                total_input: plan.total_taker_input,
-                total_output: plan.total_taker_output,
-                effective_price,
-                legs,
-                plan,
-            })
+            route(scanned_lmsr_pool.as_ref(), &scanned_orders)
         })
         .await
     }
@@ -774,10 +1140,11 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         &self,
         quote: TradeQuote,
         fee_amount: u64,
-        _market_id: &str,
+        market_id: &str,
     ) -> Result<TradeResult, NodeError> {
         let plan = quote.plan;
-        self.with_sdk(move |sdk| sdk.execute_trade_plan(&plan, fee_amount))
+        let market_id = market_id.to_string();
+        self.with_sdk(move |sdk| sdk.execute_trade_plan(&plan, fee_amount, Some(market_id)))
             .await
     }
 
@@ -1017,6 +1384,77 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             .await
     }
 
+    // ── Broadcast intents ───────────────────────────────────────────────
+
+    fn pending_intent(&self, txid: &str) -> Result<BroadcastIntent, NodeError> {
+        let store = self
+            .store
+            .as_ref()
+            .ok_or_else(|| NodeError::Store("node store not configured".into()))?;
+        let mut guard = store.lock().map_err(|_| NodeError::MutexPoisoned)?;
+        guard
+            .list_pending_broadcast_intents()
+            .map_err(NodeError::Store)?
+            .into_iter()
+            .find(|intent| intent.txid == txid)
+            .ok_or_else(|| NodeError::Store(format!("no pending broadcast intent {txid}")))
+    }
+
+    /// Settle every pending broadcast intent against the chain.
+    ///
+    /// Run after unlock: intents left pending by an interrupted broadcast are
+    /// completed if their transaction landed and abandoned if an input was
+    /// spent elsewhere. Returns those that can still be retried.
+    pub async fn reconcile_broadcast_intents(&self) -> Result<Vec<BroadcastIntent>, NodeError> {
+        let Some(store) = &self.store else {
+            return Ok(Vec::new());
+        };
+        let pending = store
+            .lock()
+            .map_err(|_| NodeError::MutexPoisoned)?
+            .list_pending_broadcast_intents()
+            .map_err(NodeError::Store)?;
+        if pending.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.with_sdk(move |sdk| {
+            let mut retryable = Vec::new();
+            for intent in pending {
+                match sdk.reconcile_intent(&intent) {
+                    Ok(IntentStatus::Pending) => retryable.push(intent),
+                    Ok(_) => {}
+                    Err(e) => {
+                        log::warn!("could not reconcile broadcast intent {}: {e}", intent.txid);
+                        retryable.push(intent);
+                    }
+                }
+            }
+            Ok(retryable)
+        })
+        .await
+    }
+
+    /// Rebroadcast a pending intent's journaled transaction, unless it
+    /// already landed or its inputs were spent by another transaction.
+    pub async fn retry_broadcast_intent(&self, txid: &str) -> Result<Txid, NodeError> {
+        let intent = self.pending_intent(txid)?;
+        self.with_sdk(move |sdk| sdk.retry_intent(&intent)).await
+    }
+
+    /// Give up on a pending intent without broadcasting it.
+    pub fn discard_broadcast_intent(&self, txid: &str) -> Result<(), NodeError> {
+        let intent = self.pending_intent(txid)?;
+        let store = self
+            .store
+            .as_ref()
+            .ok_or_else(|| NodeError::Store("node store not configured".into()))?;
+        store
+            .lock()
+            .map_err(|_| NodeError::MutexPoisoned)?
+            .set_broadcast_intent_status(&intent.txid, IntentStatus::Abandoned)
+            .map_err(NodeError::Store)
+    }
+
     // ── Discovery (delegated to DiscoveryService) ───────────────────────
 
     /// Fetch all markets from Nostr relays.
@@ -1027,6 +1465,45 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             .map_err(NodeError::Discovery)
     }
 
+    /// Fetch the market announced by `event_id` from the configured relays
+    /// plus `extra_relays`, persisting it like any discovered market.
+    pub async fn fetch_market_by_event(
+        &self,
+        event_id: EventId,
+        extra_relays: &[String],
+    ) -> Result<DiscoveredMarket, NodeError> {
+        self.discovery
+            .fetch_market_by_event(event_id, extra_relays)
+            .await
+            .map_err(NodeError::Discovery)
+    }
+
+    /// Fetch one creator's markets from the configured relays plus
+    /// `author_relays`.
+    pub async fn fetch_markets_by_author(
+        &self,
+        author: PublicKey,
+        author_relays: &[String],
+    ) -> Result<Vec<DiscoveredMarket>, NodeError> {
+        self.discovery
+            .fetch_markets_by_author(author, author_relays)
+            .await
+            .map_err(NodeError::Discovery)
+    }
+
+    /// Fetch one maker's orders from the configured relays plus
+    /// `author_relays`.
+    pub async fn fetch_orders_by_author(
+        &self,
+        author: PublicKey,
+        author_relays: &[String],
+    ) -> Result<Vec<DiscoveredOrder>, NodeError> {
+        self.discovery
+            .fetch_orders_by_author(author, author_relays)
+            .await
+            .map_err(NodeError::Discovery)
+    }
+
     /// Fetch orders from Nostr relays, optionally for a specific market.
     pub async fn fetch_orders(
         &self,
@@ -1038,6 +1515,72 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             .map_err(NodeError::Discovery)
     }
 
+    /// Fetch order announcements and keep only those whose covenant still
+    /// holds the offered asset on-chain.
+    ///
+    /// Filled or cancelled orders leave an empty covenant and are dropped.
+    /// Survivors carry the amount currently locked in the covenant as
+    /// `offered_amount`, which is below the announced amount after a partial
+    /// fill. Scan results are reused for [`ORDER_SCAN_TTL`].
+    pub async fn discover_live_orders(
+        &self,
+        market_id: Option<&str>,
+    ) -> Result<Vec<DiscoveredOrder>, NodeError> {
+        let orders = self.fetch_orders(market_id).await?;
+
+        let now = Instant::now();
+        let mut amounts: HashMap<String, u64> = HashMap::new();
+        let mut to_scan: HashMap<String, (Script, AssetId)> = HashMap::new();
+        {
+            let mut cache = self
+                .order_scan_cache
+                .lock()
+                .map_err(|_| NodeError::MutexPoisoned)?;
+            cache.retain(|_, (scanned_at, _)| now.duration_since(*scanned_at) < ORDER_SCAN_TTL);
+            for order in &orders {
+                let address = &order.covenant_address;
+                if let Some((_, amount)) = cache.get(address) {
+                    amounts.insert(address.clone(), *amount);
+                    continue;
+                }
+                match order_covenant_target(order) {
+                    Some(target) => {
+                        to_scan.insert(address.clone(), target);
+                    }
+                    None => log::warn!("skipping order {} with unreadable covenant", order.id),
+                }
+            }
+        }
+
+        if !to_scan.is_empty() {
+            let scanned = self
+                .with_sdk(move |sdk| {
+                    let mut scanned = Vec::with_capacity(to_scan.len());
+                    for (address, (script_pubkey, asset)) in to_scan {
+                        let amount = sdk
+                            .scan_covenant_utxos(&script_pubkey)?
+                            .iter()
+                            .filter(|(_, txout)| txout.asset.explicit() == Some(asset))
+                            .filter_map(|(_, txout)| txout.value.explicit())
+                            .fold(0u64, u64::saturating_add);
+                        scanned.push((address, amount));
+                    }
+                    Ok(scanned)
+                })
+                .await?;
+            let mut cache = self
+                .order_scan_cache
+                .lock()
+                .map_err(|_| NodeError::MutexPoisoned)?;
+            for (address, amount) in scanned {
+                cache.insert(address.clone(), (now, amount));
+                amounts.insert(address, amount);
+            }
+        }
+
+        Ok(retain_live_orders(orders, &amounts))
+    }
+
     /// Fetch pool announcements from Nostr relays, optionally for a specific market.
     pub async fn fetch_pools(
         &self,
@@ -1053,13 +1596,38 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
     pub async fn announce_pool(
         &self,
         announcement: &crate::discovery::pool::PoolAnnouncement,
-    ) -> Result<EventId, NodeError> {
+    ) -> Result<PublishReport, NodeError> {
         self.discovery
             .announce_pool(announcement)
             .await
             .map_err(NodeError::Discovery)
     }
 
+    /// Publish a previously announced market, order, or pool again.
+    pub async fn republish_event(&self, event_id: &str) -> Result<PublishRecord, NodeError> {
+        self.discovery
+            .republish_event(event_id)
+            .await
+            .map_err(NodeError::Discovery)
+    }
+
+    /// Retract a market, order, or pool announcement with a NIP-09 deletion
+    /// request.
+    pub async fn delete_announcement(&self, event_id: &str) -> Result<PublishReport, NodeError> {
+        self.discovery
+            .delete_announcement(event_id)
+            .await
+            .map_err(NodeError::Discovery)
+    }
+
+    /// Retry announcements that have not yet reached a quorum of relays.
+    pub async fn retry_pending_publishes(&self) -> Result<Vec<PublishRecord>, NodeError> {
+        self.discovery
+            .retry_pending_publishes()
+            .await
+            .map_err(NodeError::Discovery)
+    }
+
     /// Fetch an attestation for a specific market from Nostr relays.
     pub async fn fetch_attestation(
         &self,
@@ -1071,6 +1639,116 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             .map_err(NodeError::Discovery)
     }
 
+    /// Match every non-policy asset in the wallet against markets and pools
+    /// announced on Nostr.
+    ///
+    /// Fetching persists every announcement to the store, so matched markets
+    /// are known locally afterwards, including markets only reached through
+    /// a pool for a held token. Balances come from the latest wallet
+    /// snapshot; call [`sync_wallet`](Self::sync_wallet) first after a restore.
+    pub async fn discover_owned_positions(&self) -> Result<OwnedPositionScan, NodeError> {
+        let balance = self.balance()?;
+        let policy_asset = self.policy_asset();
+
+        let markets = self.fetch_markets().await?;
+        // Pools are only a fallback for identifying markets, so a relay
+        // failure here shouldn't cost the positions the markets already match.
+        let pools = self.fetch_pools(None).await.unwrap_or_else(|e| {
+            log::warn!("recovering positions without pool announcements: {e}");
+            Vec::new()
+        });
+
+        let parse_asset = |asset_hex: &str| {
+            hex::decode(asset_hex)
+                .ok()
+                .and_then(|bytes| AssetId::from_slice(&bytes).ok())
+        };
+        let holds = |asset_hex: &str| {
+            parse_asset(asset_hex).is_some_and(|asset| balance.get(&asset).is_some_and(|&v| v > 0))
+        };
+
+        // A market announced only through its pool is missing from the market
+        // fetch; look up the ones behind held tokens so they get ingested too.
+        let mut missing: Vec<&str> = pools
+            .iter()
+            .filter(|pool| holds(&pool.yes_asset_id) || holds(&pool.no_asset_id))
+            .map(|pool| pool.market_id.as_str())
+            .filter(|id| !markets.iter().any(|market| market.market_id == *id))
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        let mut pool_markets = Vec::new();
+        for market_id in missing {
+            match self.discovery.fetch_market_by_id(market_id).await {
+                Ok(Some(market)) => pool_markets.push(market),
+                Ok(None) => log::warn!("market {market_id} of a held pool token is not on relays"),
+                Err(e) => log::warn!("failed to fetch market {market_id} of a pool: {e}"),
+            }
+        }
+
+        let mut known: HashMap<AssetId, (String, OwnedAssetRole)> = HashMap::new();
+        let mut learn = |asset_hex: &str, market_id: &str, role: OwnedAssetRole| {
+            let Some(asset_id) = parse_asset(asset_hex) else {
+                return;
+            };
+            known
+                .entry(asset_id)
+                .or_insert_with(|| (market_id.to_string(), role));
+        };
+        for market in markets.iter().chain(&pool_markets) {
+            learn(
+                &market.yes_asset_id,
+                &market.market_id,
+                OwnedAssetRole::YesToken,
+            );
+            learn(
+                &market.no_asset_id,
+                &market.market_id,
+                OwnedAssetRole::NoToken,
+            );
+            learn(
+                &market.yes_reissuance_token,
+                &market.market_id,
+                OwnedAssetRole::YesReissuanceToken,
+            );
+            learn(
+                &market.no_reissuance_token,
+                &market.market_id,
+                OwnedAssetRole::NoReissuanceToken,
+            );
+        }
+        // Pools only cover outcome tokens, but still identify the market when
+        // its own announcement is missing from the relays.
+        for pool in &pools {
+            learn(
+                &pool.yes_asset_id,
+                &pool.market_id,
+                OwnedAssetRole::YesToken,
+            );
+            learn(&pool.no_asset_id, &pool.market_id, OwnedAssetRole::NoToken);
+        }
+
+        let mut scan = OwnedPositionScan::default();
+        for (asset_id, amount) in balance {
+            if asset_id == policy_asset || amount == 0 {
+                continue;
+            }
+            match known.get(&asset_id) {
+                Some((market_id, role)) => scan.matched.push(OwnedPosition {
+                    asset_id,
+                    amount,
+                    market_id: market_id.clone(),
+                    role: *role,
+                }),
+                None => scan.unmatched.push((asset_id, amount)),
+            }
+        }
+        scan.matched
+            .sort_by(|a, b| (&a.market_id, a.asset_id).cmp(&(&b.market_id, b.asset_id)));
+        scan.unmatched.sort();
+        Ok(scan)
+    }
+
     /// Start the background Nostr subscription loop.
     pub async fn start_subscription(&self) -> Result<JoinHandle<()>, NodeError> {
         self.discovery.start().await.map_err(NodeError::Discovery)
@@ -1095,17 +1773,69 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         .await
     }
 
+    /// Set the socket timeout for the wallet's Electrum calls.
+    ///
+    /// Applies to the unlocked wallet only; callers re-apply it after unlock.
+    pub async fn set_electrum_timeout(&self, timeout: Option<Duration>) -> Result<(), NodeError> {
+        self.with_sdk(move |sdk| {
+            sdk.set_electrum_timeout(timeout);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Set the timeout for one-shot Nostr fetches.
+    pub fn set_nostr_timeout(&self, timeout: Duration) {
+        self.discovery.set_fetch_timeout(timeout);
+    }
+
+    /// Choose how `operation` picks the wallet UTXO that pays its fee.
+    ///
+    /// Applies to the unlocked wallet only; callers re-apply it after unlock.
+    pub async fn set_fee_coin_selection(
+        &self,
+        operation: FeeOperation,
+        strategy: CoinSelection,
+    ) -> Result<(), NodeError> {
+        self.with_sdk(move |sdk| {
+            sdk.set_fee_coin_selection(operation, strategy);
+            Ok(())
+        })
+        .await
+    }
+
     /// Derive the x-only admin public key for the given pool index.
     pub async fn pool_admin_pubkey(&self, pool_index: u32) -> Result<[u8; 32], NodeError> {
         self.with_sdk(move |sdk| sdk.pool_admin_pubkey(pool_index))
             .await
     }
 
+    /// The wallet's oracle signing keys, derived from the seed; see
+    /// [`DeadcatSdk::oracle_secret_key`].
+    pub async fn oracle_keys(&self) -> Result<Keys, NodeError> {
+        let secret = self.with_sdk(|sdk| sdk.oracle_secret_key()).await?;
+        let secret = SecretKey::from_slice(&secret.secret_bytes())
+            .map_err(|e| NodeError::Sdk(Error::Signer(e.to_string())))?;
+        Ok(Keys::new(secret))
+    }
+
+    /// Derive the x-only maker base public key for the given order index.
+    pub async fn maker_base_pubkey(&self, order_index: u32) -> Result<[u8; 32], NodeError> {
+        self.with_sdk(move |sdk| sdk.maker_base_pubkey(order_index))
+            .await
+    }
+
     /// Sync the wallet with the Electrum backend.
     pub async fn sync_wallet(&self) -> Result<(), NodeError> {
         self.with_sdk(|sdk| sdk.sync()).await
     }
 
+    /// Drop the wallet's scan cache and full-scan it again; see
+    /// [`DeadcatSdk::rescan_wallet`].
+    pub async fn rescan_wallet(&self) -> Result<(), NodeError> {
+        self.with_sdk(|sdk| sdk.rescan_wallet()).await
+    }
+
     /// Get the wallet balance by asset (from cached snapshot — lock-free).
     pub fn balance(&self) -> Result<HashMap<AssetId, u64>, NodeError> {
         self.snapshot_rx
@@ -1120,6 +1850,24 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         self.with_sdk(move |sdk| sdk.address(index)).await
     }
 
+    /// Unconfidential receive address for `index`; see
+    /// [`DeadcatSdk::explicit_address`].
+    pub async fn explicit_address(
+        &self,
+        index: Option<u32>,
+    ) -> Result<(u32, lwk_wollet::elements::Address), NodeError> {
+        self.with_sdk(move |sdk| sdk.explicit_address(index)).await
+    }
+
+    /// Unspent explicit outputs received on the explicit addresses at `indices`.
+    pub async fn explicit_address_utxos(
+        &self,
+        indices: Vec<u32>,
+    ) -> Result<Vec<ExplicitUtxo>, NodeError> {
+        self.with_sdk(move |sdk| sdk.explicit_address_utxos(&indices))
+            .await
+    }
+
     /// Get unspent wallet outputs (from cached snapshot — lock-free).
     pub fn utxos(&self) -> Result<Vec<WalletTxOut>, NodeError> {
         self.snapshot_rx
@@ -1143,9 +1891,10 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
         self.with_sdk(move |sdk| sdk.fetch_transaction(&txid)).await
     }
 
-    /// Return the L-BTC policy asset ID for this network.
-    pub async fn policy_asset(&self) -> Result<AssetId, NodeError> {
-        self.with_sdk(|sdk| Ok(sdk.policy_asset())).await
+    /// Return the L-BTC policy asset ID for this network. Available while
+    /// the wallet is locked.
+    pub fn policy_asset(&self) -> AssetId {
+        self.policy_asset
     }
 
     /// Walk the canonical market lineage from the proof-carrying dormant anchor and return the
@@ -1175,6 +1924,53 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             .await
     }
 
+    /// Split off `amount` of L-BTC into a new wallet UTXO for paying fees.
+    pub async fn split_utxo(
+        &self,
+        amount: u64,
+        fee_rate: Option<f32>,
+    ) -> Result<(Txid, u64), NodeError> {
+        self.with_sdk(move |sdk| sdk.split_utxo(amount, fee_rate))
+            .await
+    }
+
+    /// Bump a stuck transaction's fee with a CPFP child (`fee_rate` in sat/kvB).
+    pub async fn bump_fee_cpfp(
+        &self,
+        parent_txid: Txid,
+        fee_rate: f32,
+    ) -> Result<FeeBumpResult, NodeError> {
+        self.with_sdk(move |sdk| sdk.bump_fee_cpfp(&parent_txid, fee_rate))
+            .await
+    }
+
+    /// Input/output breakdown of a wallet or chain transaction.
+    pub async fn transaction_detail(&self, txid: Txid) -> Result<TransactionDetail, NodeError> {
+        self.with_sdk(move |sdk| sdk.transaction_detail(&txid))
+            .await
+    }
+
+    /// Sweep an external private key's funds into the wallet (`fee_rate` in sat/kvB).
+    pub async fn sweep_private_key(
+        &self,
+        key: SweepKey,
+        fee_rate: f32,
+    ) -> Result<SweepResult, NodeError> {
+        self.with_sdk(move |sdk| sdk.sweep_private_key(&key, fee_rate))
+            .await
+    }
+
+    /// Merge the wallet's smallest `asset_id` UTXOs (`fee_rate` in sat/kvB).
+    pub async fn consolidate_utxos(
+        &self,
+        asset_id: AssetId,
+        max_inputs: usize,
+        fee_rate: f32,
+    ) -> Result<ConsolidationResult, NodeError> {
+        self.with_sdk(move |sdk| sdk.consolidate_utxos(asset_id, max_inputs, fee_rate))
+            .await
+    }
+
     /// Validate a market was created with the canonical proof-carrying dormant bootstrap.
     pub async fn validate_market_creation(
         &self,
@@ -1233,6 +2029,14 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             .await
     }
 
+    /// Derive the Boltz reverse swap claim secret key.
+    pub async fn boltz_reverse_claim_secret_key(
+        &self,
+    ) -> Result<lwk_wollet::elements::secp256k1_zkp::SecretKey, NodeError> {
+        self.with_sdk(|sdk| sdk.boltz_reverse_claim_secret_key())
+            .await
+    }
+
     // ── Electrum URL accessors ──────────────────────────────────────────
 
     /// Return the Electrum URL from the active SDK, or `None` if the wallet is locked.
@@ -1243,6 +2047,19 @@ impl<S: DiscoveryStore> DeadcatNode<S> {
             .and_then(|guard| guard.as_ref().map(|sdk| sdk.electrum_url().to_string()))
     }
 
+    /// Return the signer identity and sync height, or `None` if the wallet is
+    /// locked. Reads the SDK in place rather than through `with_sdk`, so no
+    /// snapshot is published.
+    pub fn wallet_info(&self) -> Option<WalletInfo> {
+        self.sdk.lock().ok().and_then(|guard| {
+            guard.as_ref().map(|sdk| WalletInfo {
+                fingerprint: sdk.signer_fingerprint(),
+                xpub: sdk.signer_xpub(),
+                synced_height: sdk.wallet_tip_height(),
+            })
+        })
+    }
+
     /// Return the default Electrum URL for this node's network.
     pub fn default_electrum_url(&self) -> &str {
         self.network.default_electrum_url()
@@ -1254,6 +2071,49 @@ fn market_id_from_hex(market_id: &str) -> Result<MarketId, NodeError> {
     Ok(MarketId(bytes))
 }
 
+/// State index and reserves claimed by a pool's stored Nostr announcement.
+fn announced_lmsr_pool_state(
+    network: Network,
+    pool: &crate::LmsrPoolSyncInfo,
+) -> Option<(u64, PoolReserves)> {
+    let event: Event = serde_json::from_str(pool.nostr_event_json.as_deref()?).ok()?;
+    let discovered = parse_pool_event(&event, network.discovery_tag()).ok()?;
+    Some((discovered.current_s_index, discovered.reserves))
+}
+
+/// Keep orders whose covenant was scanned and still holds the offered asset,
+/// replacing the announced `offered_amount` with the amount found on-chain.
+fn retain_live_orders(
+    orders: Vec<DiscoveredOrder>,
+    amounts: &HashMap<String, u64>,
+) -> Vec<DiscoveredOrder> {
+    orders
+        .into_iter()
+        .filter_map(|mut order| {
+            let amount = *amounts.get(&order.covenant_address)?;
+            if amount == 0 {
+                return None;
+            }
+            order.offered_amount = amount;
+            Some(order)
+        })
+        .collect()
+}
+
+/// Covenant script and offered asset of an announced order.
+fn order_covenant_target(order: &DiscoveredOrder) -> Option<(Script, AssetId)> {
+    let address: Address = order.covenant_address.parse().ok()?;
+    let asset_hex = match order.direction.as_str() {
+        "sell-base" => &order.base_asset_id,
+        "sell-quote" => &order.quote_asset_id,
+        _ => return None,
+    };
+    let asset = hex::decode(asset_hex)
+        .ok()
+        .and_then(|bytes| AssetId::from_slice(&bytes).ok())?;
+    Some((address.script_pubkey(), asset))
+}
+
 #[derive(Clone, Debug)]
 struct ResolvedPoolSyncMetadata {
     locator: LmsrPoolLocator,
@@ -1535,26 +2395,83 @@ impl<S: NodeStore> DeadcatNode<S> {
     /// recoverable metadata first. Stored initial reserve outpoints are read in
     /// canonical raw-hex byte order so pool-id derivation matches discovery.
     pub fn resolve_lmsr_pool_locator(&self, pool_id: &str) -> Result<LmsrPoolLocator, NodeError> {
+        let pool = self.stored_lmsr_pool_sync_info(pool_id)?;
+        self.resolve_and_repair_pool_sync_metadata(pool)
+            .map(|resolved| resolved.locator)
+    }
+
+    fn stored_lmsr_pool_sync_info(
+        &self,
+        pool_id: &str,
+    ) -> Result<crate::LmsrPoolSyncInfo, NodeError> {
         let store = self
             .store
             .as_ref()
             .cloned()
             .ok_or_else(|| NodeError::Store("node store not configured".into()))?;
-        let pool = {
+        let mut guard = store.lock().map_err(|_| NodeError::MutexPoisoned)?;
+        guard
+            .list_lmsr_pool_sync_info()
+            .map_err(NodeError::Store)?
+            .into_iter()
+            .find(|pool| pool.pool_id == pool_id)
+            .ok_or_else(|| NodeError::Store(format!("unknown LMSR pool_id {pool_id}")))
+    }
+
+    /// Check a stored LMSR pool's announcement against the chain.
+    ///
+    /// Walks the canonical reserve bundle from the announced anchors and
+    /// records the result as a fresh snapshot, so the store reflects real
+    /// reserves whatever the announcement claimed. Anchors that don't lead
+    /// to a live pool covenant mark the pool invalid. The verdict is stored
+    /// with the pool. Chain backend and query failures are returned as
+    /// errors rather than flagged, and leave any earlier verdict in place.
+    pub async fn verify_pool(&self, pool_id: &str) -> Result<PoolVerification, NodeError> {
+        let pool = self.stored_lmsr_pool_sync_info(pool_id)?;
+        let announced = announced_lmsr_pool_state(self.network, &pool);
+        let scanned = match self.resolve_and_repair_pool_sync_metadata(pool) {
+            Ok(resolved) => self.scan_lmsr_pool(resolved.locator).await,
+            Err(e) => Err(e),
+        };
+
+        let (status, snapshot) = match scanned {
+            Ok(snapshot) => {
+                let onchain = (snapshot.current_s_index, snapshot.reserves);
+                let status = match announced {
+                    Some(claimed) if claimed != onchain => PoolVerificationStatus::Stale,
+                    _ => PoolVerificationStatus::Verified,
+                };
+                (status, Some(snapshot))
+            }
+            Err(NodeError::Sdk(e @ (Error::Electrum(_) | Error::Query(_)))) => {
+                return Err(NodeError::Sdk(e));
+            }
+            Err(NodeError::Sdk(e)) => {
+                log::warn!("LMSR pool {pool_id} failed verification: {e}");
+                (PoolVerificationStatus::Invalid(e.to_string()), None)
+            }
+            Err(e) => return Err(e),
+        };
+
+        if let Some(store) = self.store.as_ref() {
             let mut guard = store.lock().map_err(|_| NodeError::MutexPoisoned)?;
             guard
-                .list_lmsr_pool_sync_info()
-                .map_err(NodeError::Store)?
-                .into_iter()
-                .find(|pool| pool.pool_id == pool_id)
-                .ok_or_else(|| NodeError::Store(format!("unknown LMSR pool_id {pool_id}")))?
-        };
-        self.resolve_and_repair_pool_sync_metadata(pool)
-            .map(|resolved| resolved.locator)
+                .record_lmsr_pool_verification(pool_id, &status)
+                .map_err(NodeError::Store)?;
+        }
+
+        Ok(PoolVerification {
+            pool_id: pool_id.to_string(),
+            status,
+            announced,
+            snapshot,
+        })
     }
 
     /// Sync wallet state and backfill irreversible LMSR transition history.
-    pub async fn sync(&self) -> Result<(), NodeError> {
+    ///
+    /// Returns the number of LMSR pool snapshots refreshed in the store.
+    pub async fn sync(&self) -> Result<usize, NodeError> {
         self.sync_wallet().await?;
 
         let store = self
@@ -1567,6 +2484,7 @@ impl<S: NodeStore> DeadcatNode<S> {
             guard.list_lmsr_pool_sync_info().map_err(NodeError::Store)?
         };
 
+        let mut snapshots = 0;
         for pool in pools {
             let resolved = match self.resolve_and_repair_pool_sync_metadata(pool.clone()) {
                 Ok(resolved) => resolved,
@@ -1598,6 +2516,7 @@ impl<S: NodeStore> DeadcatNode<S> {
 
             let snapshot = build_snapshot_from_history(locator, &scan);
             self.persist_lmsr_pool_snapshot(&snapshot, resolved.lmsr_table_values.clone());
+            snapshots += 1;
 
             let Some(table_values) = resolved.lmsr_table_values.clone() else {
                 log::warn!(
@@ -1648,7 +2567,7 @@ impl<S: NodeStore> DeadcatNode<S> {
             }
         }
 
-        Ok(())
+        Ok(snapshots)
     }
 
     pub fn get_market_price_history(
@@ -1882,4 +2801,48 @@ mod tests {
                 .contains("cannot resolve LMSR sync metadata")
         );
     }
+
+    /// A sell-base test order whose announced covenant address is the real
+    /// maker-order address for its params.
+    fn funded_order_fixture() -> DiscoveredOrder {
+        let network = Network::LiquidTestnet;
+        let mut announcement = crate::testing::test_order_announcement("market123");
+        announcement.covenant_address = crate::CompiledMakerOrder::new(announcement.params)
+            .unwrap()
+            .address(&[0xaa; 32], network.address_params())
+            .to_string();
+        let event = crate::discovery::build_order_event(
+            &Keys::generate(),
+            &announcement,
+            network.discovery_tag(),
+        )
+        .unwrap();
+        crate::discovery::parse_order_event(&event, network.discovery_tag()).unwrap()
+    }
+
+    #[test]
+    fn live_orders_keep_funded_covenants_at_their_on_chain_amount() {
+        let order = funded_order_fixture();
+        let (script_pubkey, asset) = order_covenant_target(&order).expect("readable covenant");
+        let params = crate::testing::test_order_announcement("market123").params;
+        assert_eq!(
+            script_pubkey,
+            crate::CompiledMakerOrder::new(params)
+                .unwrap()
+                .script_pubkey(&[0xaa; 32])
+        );
+        assert_eq!(asset, AssetId::from_slice(&[0x01; 32]).unwrap());
+
+        // Partially filled: still live, offering what is left on-chain.
+        let amounts = HashMap::from([(order.covenant_address.clone(), 60)]);
+        let live = retain_live_orders(vec![order.clone()], &amounts);
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].id, order.id);
+        assert_eq!(live[0].offered_amount, 60);
+
+        // Emptied by a fill or cancel, or never scanned: dropped.
+        let emptied = HashMap::from([(order.covenant_address.clone(), 0)]);
+        assert!(retain_live_orders(vec![order.clone()], &emptied).is_empty());
+        assert!(retain_live_orders(vec![order], &HashMap::new()).is_empty());
+    }
 }
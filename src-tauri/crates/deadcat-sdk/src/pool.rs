@@ -8,6 +8,71 @@ pub struct PoolReserves {
     pub r_lbtc: u64,
 }
 
+/// One of a pool's three reserves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolAsset {
+    Yes,
+    No,
+    Lbtc,
+}
+
+impl PoolReserves {
+    pub fn get(&self, asset: PoolAsset) -> u64 {
+        match asset {
+            PoolAsset::Yes => self.r_yes,
+            PoolAsset::No => self.r_no,
+            PoolAsset::Lbtc => self.r_lbtc,
+        }
+    }
+
+    /// Reserves after depositing `amount` of `asset` and topping up the other
+    /// two in proportion, so every reserve grows by the same factor. The
+    /// proportional deposits round up.
+    ///
+    /// `None` when the `asset` reserve is empty or a new reserve overflows.
+    pub fn balanced_deposit(&self, asset: PoolAsset, amount: u64) -> Option<PoolReserves> {
+        let base = u128::from(self.get(asset));
+        if base == 0 {
+            return None;
+        }
+        let grow = |reserve: u64| -> Option<u64> {
+            let deposit = (u128::from(reserve) * u128::from(amount)).div_ceil(base);
+            u64::try_from(u128::from(reserve) + deposit).ok()
+        };
+        Some(PoolReserves {
+            r_yes: grow(self.r_yes)?,
+            r_no: grow(self.r_no)?,
+            r_lbtc: grow(self.r_lbtc)?,
+        })
+    }
+
+    /// Reserves after withdrawing `amount` of `asset` and the other two in
+    /// proportion, the mirror of [`balanced_deposit`](Self::balanced_deposit).
+    /// The proportional withdrawals round down, in the pool's favour.
+    ///
+    /// `None` when `amount` exceeds the `asset` reserve.
+    pub fn balanced_withdrawal(&self, asset: PoolAsset, amount: u64) -> Option<PoolReserves> {
+        let base = u128::from(self.get(asset));
+        if u128::from(amount) > base {
+            return None;
+        }
+        if amount == 0 {
+            return Some(*self);
+        }
+        let shrink = |reserve: u64| -> u64 {
+            let withdrawal = u128::from(reserve) * u128::from(amount) / base;
+            // withdrawal <= reserve since amount <= base.
+            reserve - withdrawal as u64
+        };
+        Some(PoolReserves {
+            r_yes: shrink(self.r_yes),
+            r_no: shrink(self.r_no),
+            r_lbtc: shrink(self.r_lbtc),
+        })
+    }
+}
+
 /// Compute implied YES/NO probabilities from YES/NO reserve weights.
 pub fn implied_probability_bps(reserves: &PoolReserves) -> Option<(u16, u16)> {
     let denom = reserves.r_yes.checked_add(reserves.r_no)?;
@@ -18,3 +83,83 @@ pub fn implied_probability_bps(reserves: &PoolReserves) -> Option<(u16, u16)> {
     let no = 10_000u16.saturating_sub(yes);
     Some((yes, no))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESERVES: PoolReserves = PoolReserves {
+        r_yes: 1_000,
+        r_no: 3_000,
+        r_lbtc: 50_000,
+    };
+
+    #[test]
+    fn balanced_deposit_scales_every_reserve() {
+        let after = RESERVES.balanced_deposit(PoolAsset::Yes, 500).unwrap();
+        assert_eq!(
+            after,
+            PoolReserves {
+                r_yes: 1_500,
+                r_no: 4_500,
+                r_lbtc: 75_000,
+            }
+        );
+        assert_eq!(
+            RESERVES.balanced_deposit(PoolAsset::Lbtc, 25_000),
+            Some(after)
+        );
+    }
+
+    #[test]
+    fn balanced_deposit_rounds_other_deposits_up() {
+        let after = RESERVES.balanced_deposit(PoolAsset::No, 1).unwrap();
+        assert_eq!(after.r_no, 3_001);
+        assert_eq!(after.r_yes, 1_001);
+        assert_eq!(after.r_lbtc, 50_017);
+    }
+
+    #[test]
+    fn balanced_withdrawal_mirrors_deposit() {
+        let grown = RESERVES.balanced_deposit(PoolAsset::Yes, 500).unwrap();
+        assert_eq!(
+            grown.balanced_withdrawal(PoolAsset::Yes, 500),
+            Some(RESERVES)
+        );
+        assert_eq!(
+            RESERVES.balanced_withdrawal(PoolAsset::Yes, 1_000),
+            Some(PoolReserves {
+                r_yes: 0,
+                r_no: 0,
+                r_lbtc: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn balanced_withdrawal_rounds_other_withdrawals_down() {
+        let after = RESERVES.balanced_withdrawal(PoolAsset::No, 1).unwrap();
+        assert_eq!(after.r_no, 2_999);
+        assert_eq!(after.r_yes, 1_000);
+        assert_eq!(after.r_lbtc, 49_984);
+    }
+
+    #[test]
+    fn balanced_withdrawal_rejects_more_than_the_reserve() {
+        assert_eq!(RESERVES.balanced_withdrawal(PoolAsset::Yes, 1_001), None);
+        assert_eq!(
+            RESERVES.balanced_withdrawal(PoolAsset::Lbtc, u64::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn balanced_deposit_rejects_empty_reserve_and_overflow() {
+        let empty = PoolReserves {
+            r_yes: 0,
+            ..RESERVES
+        };
+        assert_eq!(empty.balanced_deposit(PoolAsset::Yes, 10), None);
+        assert_eq!(RESERVES.balanced_deposit(PoolAsset::Yes, u64::MAX), None);
+    }
+}
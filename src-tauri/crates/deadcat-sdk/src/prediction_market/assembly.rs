@@ -1,11 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use lwk_wollet::elements::confidential::{AssetBlindingFactor, ValueBlindingFactor};
 use lwk_wollet::elements::pset::PartiallySignedTransaction;
 use lwk_wollet::elements::secp256k1_zkp::{self, PublicKey};
 use lwk_wollet::elements::{AssetId, BlockHash, ContractHash, Script, Transaction};
-use rand::thread_rng;
+use rand::{CryptoRng, RngCore};
 use simplicityhl::elements::hashes::Hash;
 use simplicityhl::elements::taproot::ControlBlock;
 use simplicityhl::simplicity::jet::elements::{ElementsEnv, ElementsUtxo};
@@ -13,7 +13,7 @@ use simplicityhl::simplicity::jet::elements::{ElementsEnv, ElementsUtxo};
 use crate::assembly::{pset_to_pruning_transaction, txout_secrets_from_unblinded};
 use crate::error::{Error, Result};
 use crate::prediction_market::contract::CompiledPredictionMarket;
-use crate::prediction_market::pset::UnblindedUtxo;
+use crate::prediction_market::params::amounts::collateral_for_pairs;
 use crate::prediction_market::pset::expire_transition::build_expire_transition_pset;
 use crate::prediction_market::pset::initial_issuance::{
     InitialIssuanceParams, build_initial_issuance_pset,
@@ -21,6 +21,7 @@ use crate::prediction_market::pset::initial_issuance::{
 use crate::prediction_market::pset::issuance::{
     SubsequentIssuanceParams, build_subsequent_issuance_pset,
 };
+use crate::prediction_market::pset::{UnblindedUtxo, collateral_input_indices, collateral_total};
 use crate::prediction_market::state::{MarketSlot, MarketState};
 use crate::prediction_market::witness::{
     AllBlindingFactors, PredictionMarketSpendingPath, ReissuanceBlindingFactors,
@@ -35,6 +36,33 @@ pub struct IssuanceEntropy {
     pub no_entropy: [u8; 32],
 }
 
+/// Blinding factors `blind_last` chose for one confidential output.
+#[cfg_attr(not(any(test, feature = "testing")), allow(dead_code))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputBlindingFactors {
+    pub asset_bf: [u8; 32],
+    pub value_bf: [u8; 32],
+}
+
+/// Blinding secrets of a transaction the SDK blinded, enough to check
+/// its confidential commitments outside the SDK.
+#[cfg_attr(not(any(test, feature = "testing")), allow(dead_code))]
+#[derive(Debug, Clone, Default)]
+pub struct BlindingAudit {
+    /// Factors for each blinded output, by output index.
+    pub outputs: BTreeMap<usize, OutputBlindingFactors>,
+    /// Reissuance-token factors the covenant witnesses were satisfied with;
+    /// `None` for transactions whose witnesses don't commit to them.
+    pub reissuance: Option<AllBlindingFactors>,
+}
+
+/// A covenant PSET together with the blinding secrets chosen for it.
+pub(crate) struct AssembledPset {
+    pub pset: PartiallySignedTransaction,
+    #[cfg_attr(not(any(test, feature = "testing")), allow(dead_code))]
+    pub audit: BlindingAudit,
+}
+
 /// Where collateral comes from depends on the market state.
 #[allow(clippy::large_enum_variant)]
 pub enum CollateralSource {
@@ -57,6 +85,7 @@ pub struct IssuanceAssemblyInputs {
     pub fee_utxo: UnblindedUtxo,
     pub pairs: u64,
     pub fee_amount: u64,
+    pub fee_asset_id: [u8; 32],
     pub token_destination: Script,
     pub change_destination: Option<Script>,
     pub issuance_entropy: IssuanceEntropy,
@@ -126,15 +155,16 @@ fn ensure_fee_output_last(pset: &mut PartiallySignedTransaction) {
 /// - `rt_setup`: If Some, sets up outputs 0,1 as RT outputs with the given asset IDs
 /// - `wallet_output_indices`: output indices to mark for blinding (wallet destinations)
 /// - `input_utxos`: (index, utxo) pairs for ALL inputs that need secrets
-fn blind_pset(
+fn blind_pset<R: RngCore + CryptoRng>(
     pset: &mut PartiallySignedTransaction,
     rt_setup: Option<(&UnblindedUtxo, &UnblindedUtxo)>,
     wallet_output_indices: &[usize],
     input_utxos: &[(usize, &UnblindedUtxo)],
     blinding_pubkey: PublicKey,
-) -> Result<()> {
+    rng: &mut R,
+) -> Result<BTreeMap<usize, OutputBlindingFactors>> {
     if rt_setup.is_none() && wallet_output_indices.is_empty() {
-        return Ok(());
+        return Ok(BTreeMap::new());
     }
 
     let pset_blinding_key = lwk_wollet::elements::bitcoin::PublicKey {
@@ -174,11 +204,30 @@ fn blind_pset(
     }
 
     let secp = secp256k1_zkp::Secp256k1::new();
-    let mut rng = thread_rng();
-    pset.blind_last(&mut rng, &secp, &inp_txout_sec)
+    blind_last_with_factors(pset, rng, &secp, &inp_txout_sec)
+}
+
+/// Run `blind_last` and keep the factors it chose for each output.
+pub(crate) fn blind_last_with_factors<R: RngCore + CryptoRng>(
+    pset: &mut PartiallySignedTransaction,
+    rng: &mut R,
+    secp: &secp256k1_zkp::Secp256k1<secp256k1_zkp::All>,
+    inp_txout_sec: &HashMap<usize, lwk_wollet::elements::TxOutSecrets>,
+) -> Result<BTreeMap<usize, OutputBlindingFactors>> {
+    let factors = pset
+        .blind_last(rng, secp, inp_txout_sec)
         .map_err(|e| Error::Blinding(format!("{e:?}")))?;
 
-    Ok(())
+    Ok(factors
+        .into_iter()
+        .map(|(idx, (abf, vbf, _))| {
+            let mut asset_bf = [0u8; 32];
+            asset_bf.copy_from_slice(abf.into_inner().as_ref());
+            let mut value_bf = [0u8; 32];
+            value_bf.copy_from_slice(vbf.into_inner().as_ref());
+            (idx, OutputBlindingFactors { asset_bf, value_bf })
+        })
+        .collect())
 }
 
 /// Identify wallet output indices: all outputs with non-empty script_pubkey
@@ -225,6 +274,26 @@ fn build_input_refs<'a>(
     refs
 }
 
+/// Covenant spends of a cancellation or redemption's collateral: `primary` at
+/// input 0, and the merge path at every other collateral input.
+pub(crate) fn collateral_spends(
+    collateral_indices: &[usize],
+    slot: MarketSlot,
+    primary: PredictionMarketSpendingPath,
+) -> Vec<(usize, MarketSlot, PredictionMarketSpendingPath)> {
+    collateral_indices
+        .iter()
+        .map(|&idx| {
+            let path = if idx == 0 {
+                primary.clone()
+            } else {
+                PredictionMarketSpendingPath::CollateralMergeSecondary
+            };
+            (idx, slot, path)
+        })
+        .collect()
+}
+
 /// Build the PSET for an issuance transaction (step E).
 pub(crate) fn build_issuance_pset(
     inputs: &IssuanceAssemblyInputs,
@@ -244,6 +313,7 @@ pub(crate) fn build_issuance_pset(
                     fee_utxo: inputs.fee_utxo.clone(),
                     pairs: inputs.pairs,
                     fee_amount: inputs.fee_amount,
+                    fee_asset_id: inputs.fee_asset_id,
                     yes_token_destination: inputs.token_destination.clone(),
                     no_token_destination: inputs.token_destination.clone(),
                     collateral_change_destination: inputs.change_destination.clone(),
@@ -274,6 +344,7 @@ pub(crate) fn build_issuance_pset(
                     fee_utxo: inputs.fee_utxo.clone(),
                     pairs: inputs.pairs,
                     fee_amount: inputs.fee_amount,
+                    fee_asset_id: inputs.fee_asset_id,
                     yes_token_destination: inputs.token_destination.clone(),
                     no_token_destination: inputs.token_destination.clone(),
                     collateral_change_destination: inputs.change_destination.clone(),
@@ -294,11 +365,12 @@ pub(crate) fn build_issuance_pset(
 ///
 /// Sets up blinding keys on RT outputs and change outputs, provides input
 /// txout secrets, and calls `blind_last()`.
-pub(crate) fn blind_issuance_pset(
+pub(crate) fn blind_issuance_pset<R: RngCore + CryptoRng>(
     pset: &mut PartiallySignedTransaction,
     inputs: &IssuanceAssemblyInputs,
     blinding_pubkey: PublicKey,
-) -> Result<()> {
+    rng: &mut R,
+) -> Result<BTreeMap<usize, OutputBlindingFactors>> {
     let yes_rt_id = AssetId::from_slice(&inputs.contract.params().yes_reissuance_token)
         .map_err(|e| Error::Blinding(format!("bad YES reissuance asset: {e}")))?;
     let no_rt_id = AssetId::from_slice(&inputs.contract.params().no_reissuance_token)
@@ -334,6 +406,8 @@ pub(crate) fn blind_issuance_pset(
 
     let collateral_id = AssetId::from_slice(&inputs.contract.params().collateral_asset_id)
         .map_err(|e| Error::Blinding(format!("bad collateral asset: {e}")))?;
+    let fee_asset_id = AssetId::from_slice(&inputs.fee_asset_id)
+        .map_err(|e| Error::Blinding(format!("bad fee asset: {e}")))?;
 
     let mut inp_txout_sec = HashMap::new();
     inp_txout_sec.insert(
@@ -372,7 +446,7 @@ pub(crate) fn blind_issuance_pset(
             inp_txout_sec.insert(2, txout_secrets_from_unblinded(wallet_utxo, collateral_id)?);
             inp_txout_sec.insert(
                 3,
-                txout_secrets_from_unblinded(&inputs.fee_utxo, collateral_id)?,
+                txout_secrets_from_unblinded(&inputs.fee_utxo, fee_asset_id)?,
             );
         }
         (
@@ -399,19 +473,14 @@ pub(crate) fn blind_issuance_pset(
             );
             inp_txout_sec.insert(
                 4,
-                txout_secrets_from_unblinded(&inputs.fee_utxo, collateral_id)?,
+                txout_secrets_from_unblinded(&inputs.fee_utxo, fee_asset_id)?,
             );
         }
         _ => return Err(Error::InvalidState),
     }
 
     let secp = secp256k1_zkp::Secp256k1::new();
-    let mut rng = thread_rng();
-
-    pset.blind_last(&mut rng, &secp, &inp_txout_sec)
-        .map_err(|e| Error::Blinding(format!("{e:?}")))?;
-
-    Ok(())
+    blind_last_with_factors(pset, rng, &secp, &inp_txout_sec)
 }
 
 /// Recover blinding factors for RT outputs (0, 1) using SLIP77 (step G).
@@ -532,16 +601,17 @@ pub(crate) fn attach_witnesses(
 }
 
 /// Full production assembly pipeline: build → blind → recover → attach witnesses.
-pub(crate) fn assemble_issuance(
+pub(crate) fn assemble_issuance<R: RngCore + CryptoRng>(
     inputs: IssuanceAssemblyInputs,
     slip77_key: &lwk_wollet::elements_miniscript::confidential::slip77::MasterBlindingKey,
     blinding_pubkey: PublicKey,
     change_spk: &Script,
-) -> Result<PartiallySignedTransaction> {
+    rng: &mut R,
+) -> Result<AssembledPset> {
     let state = inputs.current_state;
 
     let mut pset = build_issuance_pset(&inputs)?;
-    blind_issuance_pset(&mut pset, &inputs, blinding_pubkey)?;
+    let outputs = blind_issuance_pset(&mut pset, &inputs, blinding_pubkey, rng)?;
 
     let blinding = recover_blinding_factors(
         &pset,
@@ -553,7 +623,13 @@ pub(crate) fn assemble_issuance(
 
     attach_witnesses(&mut pset, &inputs.contract, state, blinding)?;
 
-    Ok(pset)
+    Ok(AssembledPset {
+        pset,
+        audit: BlindingAudit {
+            outputs,
+            reissuance: Some(blinding),
+        },
+    })
 }
 
 /// Attach Simplicity witness stacks to parameterized covenant inputs.
@@ -627,11 +703,12 @@ fn attach_covenant_witnesses(
 }
 
 /// Assemble a post-resolution redemption transaction.
-pub(crate) fn assemble_post_resolution_redemption(
+pub(crate) fn assemble_post_resolution_redemption<R: RngCore + CryptoRng>(
     contract: &CompiledPredictionMarket,
     params: &crate::prediction_market::pset::post_resolution_redemption::PostResolutionRedemptionParams,
     blinding_pubkey: PublicKey,
-) -> Result<PartiallySignedTransaction> {
+    rng: &mut R,
+) -> Result<AssembledPset> {
     let mut pset =
         crate::prediction_market::pset::post_resolution_redemption::build_post_resolution_redemption_pset(
             contract, params,
@@ -639,17 +716,23 @@ pub(crate) fn assemble_post_resolution_redemption(
     ensure_fee_output_last(&mut pset);
 
     let wallet_outputs = find_wallet_output_indices(&pset, contract);
+    let collateral_indices = collateral_input_indices(params.collateral_utxos.len(), 1)?;
     let input_refs = build_input_refs(
-        vec![(0, &params.collateral_utxo)],
+        collateral_indices
+            .iter()
+            .copied()
+            .zip(&params.collateral_utxos)
+            .collect(),
         &[&params.token_utxos],
         &params.fee_utxo,
     );
-    blind_pset(
+    let outputs = blind_pset(
         &mut pset,
         None,
         &wallet_outputs,
         &input_refs,
         blinding_pubkey,
+        rng,
     )?;
 
     let spending_path = PredictionMarketSpendingPath::PostResolutionRedemption {
@@ -660,34 +743,51 @@ pub(crate) fn assemble_post_resolution_redemption(
         .resolved_state
         .collateral_slot()
         .ok_or(Error::InvalidState)?;
-    attach_covenant_witnesses(&mut pset, contract, &[(0, collateral_slot, spending_path)])?;
+    attach_covenant_witnesses(
+        &mut pset,
+        contract,
+        &collateral_spends(&collateral_indices, collateral_slot, spending_path),
+    )?;
 
-    Ok(pset)
+    Ok(AssembledPset {
+        pset,
+        audit: BlindingAudit {
+            outputs,
+            reissuance: None,
+        },
+    })
 }
 
 /// Assemble an expiry redemption transaction.
-pub(crate) fn assemble_expiry_redemption(
+pub(crate) fn assemble_expiry_redemption<R: RngCore + CryptoRng>(
     contract: &CompiledPredictionMarket,
     params: &crate::prediction_market::pset::expiry_redemption::ExpiryRedemptionParams,
     blinding_pubkey: PublicKey,
-) -> Result<PartiallySignedTransaction> {
+    rng: &mut R,
+) -> Result<AssembledPset> {
     let mut pset = crate::prediction_market::pset::expiry_redemption::build_expiry_redemption_pset(
         contract, params,
     )?;
     ensure_fee_output_last(&mut pset);
 
     let wallet_outputs = find_wallet_output_indices(&pset, contract);
+    let collateral_indices = collateral_input_indices(params.collateral_utxos.len(), 1)?;
     let input_refs = build_input_refs(
-        vec![(0, &params.collateral_utxo)],
+        collateral_indices
+            .iter()
+            .copied()
+            .zip(&params.collateral_utxos)
+            .collect(),
         &[&params.token_utxos],
         &params.fee_utxo,
     );
-    blind_pset(
+    let outputs = blind_pset(
         &mut pset,
         None,
         &wallet_outputs,
         &input_refs,
         blinding_pubkey,
+        rng,
     )?;
 
     let spending_path = PredictionMarketSpendingPath::ExpiryRedemption {
@@ -698,31 +798,39 @@ pub(crate) fn assemble_expiry_redemption(
     attach_covenant_witnesses(
         &mut pset,
         contract,
-        &[(0, MarketSlot::ExpiredCollateral, spending_path)],
+        &collateral_spends(
+            &collateral_indices,
+            MarketSlot::ExpiredCollateral,
+            spending_path,
+        ),
     )?;
 
-    Ok(pset)
+    Ok(AssembledPset {
+        pset,
+        audit: BlindingAudit {
+            outputs,
+            reissuance: None,
+        },
+    })
 }
 
 /// Assemble a cancellation transaction.
 ///
-/// For partial cancellation: blind wallet outputs, witness at index 0 only.
+/// For partial cancellation: blind wallet outputs, witness the collateral at
+/// index 0 and any merged in after it.
 /// For full cancellation: blind RT + wallet outputs, recover blinding factors,
-/// witness at index 0 with secondaries [1, 2].
-pub(crate) fn assemble_cancellation(
+/// witness at index 0 with secondaries [1, 2] and merged collateral from 3.
+pub(crate) fn assemble_cancellation<R: RngCore + CryptoRng>(
     contract: &CompiledPredictionMarket,
     params: &crate::prediction_market::pset::cancellation::CancellationParams,
     slip77_key: &lwk_wollet::elements_miniscript::confidential::slip77::MasterBlindingKey,
     blinding_pubkey: PublicKey,
     change_spk: &Script,
-) -> Result<PartiallySignedTransaction> {
+    rng: &mut R,
+) -> Result<AssembledPset> {
     let cpt = contract.params().collateral_per_token;
-    let refund = params
-        .pairs_burned
-        .checked_mul(2)
-        .and_then(|v| v.checked_mul(cpt))
-        .ok_or(Error::CollateralOverflow)?;
-    let remaining = params.collateral_utxo.value.saturating_sub(refund);
+    let refund = collateral_for_pairs(params.pairs_burned, cpt)?;
+    let remaining = collateral_total(&params.collateral_utxos)?.saturating_sub(refund);
     let is_full = remaining == 0;
 
     let mut pset =
@@ -740,31 +848,41 @@ pub(crate) fn assemble_cancellation(
             .ok_or(Error::MissingReissuanceUtxos)?;
 
         let wallet_outputs = find_wallet_output_indices(&pset, contract);
+        let collateral_indices = collateral_input_indices(params.collateral_utxos.len(), 3)?;
+        let mut fixed_inputs = vec![(0, &params.collateral_utxos[0]), (1, yes_rt), (2, no_rt)];
+        fixed_inputs.extend(
+            collateral_indices
+                .iter()
+                .copied()
+                .zip(&params.collateral_utxos)
+                .skip(1),
+        );
         let input_refs = build_input_refs(
-            vec![(0, &params.collateral_utxo), (1, yes_rt), (2, no_rt)],
+            fixed_inputs,
             &[&params.yes_token_utxos, &params.no_token_utxos],
             &params.fee_utxo,
         );
 
-        blind_pset(
+        let outputs = blind_pset(
             &mut pset,
             Some((yes_rt, no_rt)),
             &wallet_outputs,
             &input_refs,
             blinding_pubkey,
+            rng,
         )?;
 
         let blinding = recover_blinding_factors(&pset, slip77_key, change_spk, yes_rt, no_rt)?;
 
-        let covenant_inputs = vec![
-            (
-                0,
-                MarketSlot::UnresolvedCollateral,
-                PredictionMarketSpendingPath::CancellationFullPrimary {
-                    pairs_burned: params.pairs_burned,
-                    blinding,
-                },
-            ),
+        let mut covenant_inputs = collateral_spends(
+            &collateral_indices,
+            MarketSlot::UnresolvedCollateral,
+            PredictionMarketSpendingPath::CancellationFullPrimary {
+                pairs_burned: params.pairs_burned,
+                blinding,
+            },
+        );
+        covenant_inputs.extend([
             (
                 1,
                 MarketSlot::UnresolvedYesRt,
@@ -775,46 +893,64 @@ pub(crate) fn assemble_cancellation(
                 MarketSlot::UnresolvedNoRt,
                 PredictionMarketSpendingPath::CancellationFullSecondaryNoRt { blinding },
             ),
-        ];
+        ]);
 
         attach_covenant_witnesses(&mut pset, contract, &covenant_inputs)?;
 
-        Ok(pset)
+        Ok(AssembledPset {
+            pset,
+            audit: BlindingAudit {
+                outputs,
+                reissuance: Some(blinding),
+            },
+        })
     } else {
         let wallet_outputs = find_wallet_output_indices(&pset, contract);
+        let collateral_indices = collateral_input_indices(params.collateral_utxos.len(), 1)?;
         let input_refs = build_input_refs(
-            vec![(0, &params.collateral_utxo)],
+            collateral_indices
+                .iter()
+                .copied()
+                .zip(&params.collateral_utxos)
+                .collect(),
             &[&params.yes_token_utxos, &params.no_token_utxos],
             &params.fee_utxo,
         );
 
-        blind_pset(
+        let outputs = blind_pset(
             &mut pset,
             None,
             &wallet_outputs,
             &input_refs,
             blinding_pubkey,
+            rng,
         )?;
 
         attach_covenant_witnesses(
             &mut pset,
             contract,
-            &[(
-                0,
+            &collateral_spends(
+                &collateral_indices,
                 MarketSlot::UnresolvedCollateral,
                 PredictionMarketSpendingPath::CancellationPartial {
                     pairs_burned: params.pairs_burned,
                 },
-            )],
+            ),
         )?;
 
-        Ok(pset)
+        Ok(AssembledPset {
+            pset,
+            audit: BlindingAudit {
+                outputs,
+                reissuance: None,
+            },
+        })
     }
 }
 
 /// Assemble an expire-transition transaction (state 1 -> 4).
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn assemble_expire_transition(
+pub(crate) fn assemble_expire_transition<R: RngCore + CryptoRng>(
     contract: &CompiledPredictionMarket,
     params: &crate::prediction_market::pset::expire_transition::ExpireTransitionParams,
     slip77_key: &lwk_wollet::elements_miniscript::confidential::slip77::MasterBlindingKey,
@@ -822,7 +958,8 @@ pub(crate) fn assemble_expire_transition(
     change_spk: &Script,
     yes_rt_input: &UnblindedUtxo,
     no_rt_input: &UnblindedUtxo,
-) -> Result<PartiallySignedTransaction> {
+    rng: &mut R,
+) -> Result<AssembledPset> {
     let mut pset = build_expire_transition_pset(contract, params)?;
 
     // Expire transition always blinds the RT burn outputs at 0 and 1.
@@ -836,12 +973,13 @@ pub(crate) fn assemble_expire_transition(
         (3, &params.fee_utxo),
     ];
     let wallet_outputs = find_wallet_output_indices(&pset, contract);
-    blind_pset(
+    let outputs = blind_pset(
         &mut pset,
         Some((yes_rt_input, no_rt_input)),
         &wallet_outputs,
         &input_refs,
         blinding_pubkey,
+        rng,
     )?;
 
     let blinding =
@@ -867,12 +1005,18 @@ pub(crate) fn assemble_expire_transition(
 
     attach_covenant_witnesses(&mut pset, contract, &covenant_inputs)?;
 
-    Ok(pset)
+    Ok(AssembledPset {
+        pset,
+        audit: BlindingAudit {
+            outputs,
+            reissuance: Some(blinding),
+        },
+    })
 }
 
 /// Assemble an oracle resolve transaction.
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn assemble_oracle_resolve(
+pub(crate) fn assemble_oracle_resolve<R: RngCore + CryptoRng>(
     contract: &CompiledPredictionMarket,
     params: &crate::prediction_market::pset::oracle_resolve::OracleResolveParams,
     oracle_signature: [u8; 64],
@@ -881,7 +1025,8 @@ pub(crate) fn assemble_oracle_resolve(
     change_spk: &Script,
     yes_rt_input: &UnblindedUtxo,
     no_rt_input: &UnblindedUtxo,
-) -> Result<PartiallySignedTransaction> {
+    rng: &mut R,
+) -> Result<AssembledPset> {
     let mut pset = crate::prediction_market::pset::oracle_resolve::build_oracle_resolve_pset(
         contract, params,
     )?;
@@ -897,12 +1042,13 @@ pub(crate) fn assemble_oracle_resolve(
         (3, &params.fee_utxo),
     ];
     let wallet_outputs = find_wallet_output_indices(&pset, contract);
-    blind_pset(
+    let outputs = blind_pset(
         &mut pset,
         Some((yes_rt_input, no_rt_input)),
         &wallet_outputs,
         &input_refs,
         blinding_pubkey,
+        rng,
     )?;
 
     let blinding =
@@ -932,5 +1078,11 @@ pub(crate) fn assemble_oracle_resolve(
 
     attach_covenant_witnesses(&mut pset, contract, &covenant_inputs)?;
 
-    Ok(pset)
+    Ok(AssembledPset {
+        pset,
+        audit: BlindingAudit {
+            outputs,
+            reissuance: Some(blinding),
+        },
+    })
 }
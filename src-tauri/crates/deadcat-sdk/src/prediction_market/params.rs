@@ -1,3 +1,5 @@
+pub mod amounts;
+
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
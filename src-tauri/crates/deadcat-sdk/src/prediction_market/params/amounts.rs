@@ -0,0 +1,120 @@
+//! Overflow-checked collateral arithmetic for prediction markets.
+//!
+//! Every amount derived from `collateral_per_token` goes through these
+//! helpers so an oversized pair or token count surfaces as
+//! [`Error::CollateralOverflow`] instead of wrapping or panicking.
+
+use crate::error::{Error, Result};
+
+/// Collateral backing `pairs` YES+NO pairs: `pairs * 2 * collateral_per_token`.
+///
+/// This is both what issuing the pairs locks and what cancelling them refunds.
+pub fn collateral_for_pairs(pairs: u64, collateral_per_token: u64) -> Result<u64> {
+    pairs
+        .checked_mul(2)
+        .and_then(|v| v.checked_mul(collateral_per_token))
+        .ok_or(Error::CollateralOverflow)
+}
+
+/// Collateral paid out per redeemed token: twice `collateral_per_token` for
+/// a resolved market's winning token, once on the expiry path.
+pub fn payout_per_token(collateral_per_token: u64, is_expiry: bool) -> Result<u64> {
+    if is_expiry {
+        Ok(collateral_per_token)
+    } else {
+        collateral_per_token
+            .checked_mul(2)
+            .ok_or(Error::CollateralOverflow)
+    }
+}
+
+/// Collateral paid out for redeeming `tokens`; see [`payout_per_token`].
+pub fn payout_for_tokens(tokens: u64, collateral_per_token: u64, is_expiry: bool) -> Result<u64> {
+    tokens
+        .checked_mul(payout_per_token(collateral_per_token, is_expiry)?)
+        .ok_or(Error::CollateralOverflow)
+}
+
+/// Sum of two collateral amounts.
+pub fn add_collateral(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b).ok_or(Error::CollateralOverflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const EDGES: [u64; 8] = [
+        0,
+        1,
+        2,
+        u32::MAX as u64,
+        u64::MAX / 4,
+        u64::MAX / 2,
+        u64::MAX - 1,
+        u64::MAX,
+    ];
+
+    /// Edge values crossed with seeded random ones.
+    fn inputs() -> Vec<(u64, u64)> {
+        let mut rng = StdRng::seed_from_u64(0xdeadca7);
+        let mut values = EDGES.to_vec();
+        values.extend((0..64).map(|_| rng.r#gen::<u64>() >> rng.gen_range(0..64)));
+        values
+            .iter()
+            .flat_map(|&a| values.iter().map(move |&b| (a, b)))
+            .collect()
+    }
+
+    #[test]
+    fn collateral_for_pairs_matches_wide_arithmetic() {
+        for (pairs, cpt) in inputs() {
+            let wide = pairs as u128 * 2 * cpt as u128;
+            match collateral_for_pairs(pairs, cpt) {
+                Ok(v) => assert_eq!(v as u128, wide),
+                Err(Error::CollateralOverflow) => assert!(wide > u64::MAX as u128),
+                Err(e) => panic!("unexpected error: {e}"),
+            }
+        }
+    }
+
+    #[test]
+    fn payout_for_tokens_matches_wide_arithmetic() {
+        for (tokens, cpt) in inputs() {
+            for is_expiry in [false, true] {
+                let multiplier = if is_expiry { 1 } else { 2 };
+                let wide = tokens as u128 * multiplier * cpt as u128;
+                match payout_for_tokens(tokens, cpt, is_expiry) {
+                    Ok(v) => assert_eq!(v as u128, wide),
+                    Err(Error::CollateralOverflow) => assert!(wide > u64::MAX as u128),
+                    Err(e) => panic!("unexpected error: {e}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn add_collateral_matches_wide_arithmetic() {
+        for (a, b) in inputs() {
+            let wide = a as u128 + b as u128;
+            match add_collateral(a, b) {
+                Ok(v) => assert_eq!(v as u128, wide),
+                Err(Error::CollateralOverflow) => assert!(wide > u64::MAX as u128),
+                Err(e) => panic!("unexpected error: {e}"),
+            }
+        }
+    }
+
+    #[test]
+    fn max_inputs_overflow_without_panicking() {
+        assert!(collateral_for_pairs(u64::MAX, 1).is_err());
+        assert!(collateral_for_pairs(1, u64::MAX).is_err());
+        assert!(payout_per_token(u64::MAX, false).is_err());
+        assert_eq!(payout_per_token(u64::MAX, true).unwrap(), u64::MAX);
+        assert!(payout_for_tokens(u64::MAX, u64::MAX, true).is_err());
+        assert!(add_collateral(u64::MAX, 1).is_err());
+    }
+}
@@ -3,11 +3,12 @@ use simplicityhl::elements::pset::PartiallySignedTransaction;
 
 use crate::error::{Error, Result};
 use crate::prediction_market::contract::CompiledPredictionMarket;
+use crate::prediction_market::params::amounts::collateral_for_pairs;
 use crate::prediction_market::state::MarketSlot;
 
 use super::{
-    UnblindedUtxo, add_pset_input, add_pset_output, burn_txout, covenant_spk, explicit_txout,
-    fee_txout, new_pset, reissuance_token_output,
+    UnblindedUtxo, add_pset_input, add_pset_output, burn_txout, collateral_input_indices,
+    collateral_total, covenant_spk, explicit_txout, fee_txout, new_pset, reissuance_token_output,
 };
 
 /// Parameters for constructing a cancellation PSET.
@@ -16,7 +17,9 @@ use super::{
 /// For full cancellation (remaining == 0): reissuance UTXOs are required to
 /// cycle reissuance tokens back to the Dormant state (1 → 0).
 pub struct CancellationParams {
-    pub collateral_utxo: UnblindedUtxo,
+    /// Covenant collateral the refund is drawn from, merged into one spend.
+    /// A full cancellation must include every collateral UTXO.
+    pub collateral_utxos: Vec<UnblindedUtxo>,
     pub yes_reissuance_utxo: Option<UnblindedUtxo>,
     pub no_reissuance_utxo: Option<UnblindedUtxo>,
     pub yes_token_utxos: Vec<UnblindedUtxo>,
@@ -24,6 +27,8 @@ pub struct CancellationParams {
     pub fee_utxo: UnblindedUtxo,
     pub pairs_burned: u64,
     pub fee_amount: u64,
+    /// Asset the fee is paid in; `fee_utxo` must hold it.
+    pub fee_asset_id: [u8; 32],
     pub refund_destination: Script,
     pub fee_change_destination: Option<Script>,
     /// Where to send excess tokens if token UTXOs hold more than `pairs_burned`.
@@ -36,17 +41,20 @@ pub fn build_cancellation_pset(
     params: &CancellationParams,
 ) -> Result<PartiallySignedTransaction> {
     let cpt = contract.params().collateral_per_token;
-    let refund = params
-        .pairs_burned
-        .checked_mul(2)
-        .and_then(|v| v.checked_mul(cpt))
-        .ok_or(Error::CollateralOverflow)?;
+    let refund = collateral_for_pairs(params.pairs_burned, cpt)?;
+    let collateral = collateral_total(&params.collateral_utxos)?;
 
-    if params.collateral_utxo.value < refund {
+    if collateral < refund {
         return Err(Error::InsufficientCollateral);
     }
 
-    let remaining = params.collateral_utxo.value - refund;
+    let remaining = collateral - refund;
+
+    // Collateral past input 0 must sit where the covenant counts it: right
+    // after it for a partial cancellation, after the reissuance tokens for a
+    // full one.
+    let first_merge_index = if remaining > 0 { 1 } else { 3 };
+    collateral_input_indices(params.collateral_utxos.len(), first_merge_index)?;
 
     let mut pset = new_pset();
 
@@ -54,7 +62,10 @@ pub fn build_cancellation_pset(
         // Partial cancellation (1 → 1)
         let unresolved_spk = covenant_spk(contract, MarketSlot::UnresolvedCollateral);
 
-        add_pset_input(&mut pset, &params.collateral_utxo);
+        // Inputs 0..n: collateral
+        for utxo in &params.collateral_utxos {
+            add_pset_input(&mut pset, utxo);
+        }
         for utxo in &params.yes_token_utxos {
             add_pset_input(&mut pset, utxo);
         }
@@ -120,13 +131,22 @@ pub fn build_cancellation_pset(
         let dormant_yes_spk = covenant_spk(contract, MarketSlot::DormantYesRt);
         let dormant_no_spk = covenant_spk(contract, MarketSlot::DormantNoRt);
 
+        let (first_collateral, merged_collateral) = params
+            .collateral_utxos
+            .split_first()
+            .ok_or(Error::InsufficientCollateral)?;
+
         // Input 0: collateral
-        add_pset_input(&mut pset, &params.collateral_utxo);
+        add_pset_input(&mut pset, first_collateral);
         // Input 1: YES reissuance token
         add_pset_input(&mut pset, yes_reissuance);
         // Input 2: NO reissuance token
         add_pset_input(&mut pset, no_reissuance);
-        // Inputs 3+: YES tokens
+        // Inputs 3+: remaining collateral
+        for utxo in merged_collateral {
+            add_pset_input(&mut pset, utxo);
+        }
+        // Inputs: YES tokens
         for utxo in &params.yes_token_utxos {
             add_pset_input(&mut pset, utxo);
         }
@@ -168,17 +188,13 @@ pub fn build_cancellation_pset(
     {
         add_pset_output(
             &mut pset,
-            explicit_txout(
-                &contract.params().collateral_asset_id,
-                fee_change,
-                change_spk,
-            ),
+            explicit_txout(&params.fee_asset_id, fee_change, change_spk),
         );
     }
 
     add_pset_output(
         &mut pset,
-        fee_txout(&contract.params().collateral_asset_id, params.fee_amount),
+        fee_txout(&params.fee_asset_id, params.fee_amount),
     );
 
     Ok(pset)
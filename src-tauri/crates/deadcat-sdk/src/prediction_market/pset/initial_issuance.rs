@@ -4,6 +4,7 @@ use simplicityhl::elements::{LockTime, Script};
 
 use crate::error::{Error, Result};
 use crate::prediction_market::contract::CompiledPredictionMarket;
+use crate::prediction_market::params::amounts::collateral_for_pairs;
 use crate::prediction_market::state::MarketSlot;
 
 use super::{
@@ -23,6 +24,8 @@ pub struct InitialIssuanceParams {
     pub fee_utxo: UnblindedUtxo,
     pub pairs: u64,
     pub fee_amount: u64,
+    /// Asset the fee is paid in; `fee_utxo` must hold it.
+    pub fee_asset_id: [u8; 32],
     pub yes_token_destination: Script,
     pub no_token_destination: Script,
     pub collateral_change_destination: Option<Script>,
@@ -53,11 +56,7 @@ pub fn build_initial_issuance_pset(
     params: &InitialIssuanceParams,
 ) -> Result<PartiallySignedTransaction> {
     let cpt = contract.params().collateral_per_token;
-    let required_collateral = params
-        .pairs
-        .checked_mul(2)
-        .and_then(|v| v.checked_mul(cpt))
-        .ok_or(Error::CollateralOverflow)?;
+    let required_collateral = collateral_for_pairs(params.pairs, cpt)?;
 
     if params.collateral_utxo.value < required_collateral {
         return Err(Error::InsufficientCollateral);
@@ -129,7 +128,7 @@ pub fn build_initial_issuance_pset(
     // Output 5: fee
     add_pset_output(
         &mut pset,
-        fee_txout(&contract.params().collateral_asset_id, params.fee_amount),
+        fee_txout(&params.fee_asset_id, params.fee_amount),
     );
 
     // Collateral change
@@ -150,11 +149,7 @@ pub fn build_initial_issuance_pset(
     {
         add_pset_output(
             &mut pset,
-            explicit_txout(
-                &contract.params().collateral_asset_id,
-                fee_change,
-                change_spk,
-            ),
+            explicit_txout(&params.fee_asset_id, fee_change, change_spk),
         );
     }
 
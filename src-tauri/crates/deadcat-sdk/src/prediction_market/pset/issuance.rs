@@ -4,6 +4,7 @@ use simplicityhl::elements::{LockTime, Script};
 
 use crate::error::{Error, Result};
 use crate::prediction_market::contract::CompiledPredictionMarket;
+use crate::prediction_market::params::amounts::{add_collateral, collateral_for_pairs};
 use crate::prediction_market::state::MarketSlot;
 
 use super::{
@@ -20,6 +21,8 @@ pub struct SubsequentIssuanceParams {
     pub fee_utxo: UnblindedUtxo,
     pub pairs: u64,
     pub fee_amount: u64,
+    /// Asset the fee is paid in; `fee_utxo` must hold it.
+    pub fee_asset_id: [u8; 32],
     pub yes_token_destination: Script,
     pub no_token_destination: Script,
     pub collateral_change_destination: Option<Script>,
@@ -37,17 +40,8 @@ pub fn build_subsequent_issuance_pset(
     params: &SubsequentIssuanceParams,
 ) -> Result<PartiallySignedTransaction> {
     let cpt = contract.params().collateral_per_token;
-    let new_collateral = params
-        .pairs
-        .checked_mul(2)
-        .and_then(|v| v.checked_mul(cpt))
-        .ok_or(Error::CollateralOverflow)?;
-
-    let total_collateral = params
-        .collateral_utxo
-        .value
-        .checked_add(new_collateral)
-        .ok_or(Error::CollateralOverflow)?;
+    let new_collateral = collateral_for_pairs(params.pairs, cpt)?;
+    let total_collateral = add_collateral(params.collateral_utxo.value, new_collateral)?;
 
     if params.new_collateral_utxo.value < new_collateral {
         return Err(Error::InsufficientCollateral);
@@ -110,7 +104,7 @@ pub fn build_subsequent_issuance_pset(
     );
     add_pset_output(
         &mut pset,
-        fee_txout(&contract.params().collateral_asset_id, params.fee_amount),
+        fee_txout(&params.fee_asset_id, params.fee_amount),
     );
 
     let change = params.new_collateral_utxo.value - new_collateral;
@@ -129,11 +123,7 @@ pub fn build_subsequent_issuance_pset(
     {
         add_pset_output(
             &mut pset,
-            explicit_txout(
-                &contract.params().collateral_asset_id,
-                fee_change,
-                change_spk,
-            ),
+            explicit_txout(&params.fee_asset_id, fee_change, change_spk),
         );
     }
 
@@ -9,6 +9,7 @@ pub mod post_resolution_redemption;
 
 use simplicityhl::elements::Script;
 
+use crate::error::{Error, Result};
 use crate::prediction_market::contract::CompiledPredictionMarket;
 use crate::prediction_market::state::MarketSlot;
 
@@ -23,6 +24,40 @@ pub(crate) fn covenant_spk(contract: &CompiledPredictionMarket, slot: MarketSlot
     contract.script_pubkey(slot)
 }
 
+/// Highest input index at which the covenant counts collateral merged into a
+/// cancellation or redemption alongside the collateral at input 0.
+pub(crate) const MAX_COLLATERAL_INPUT_INDEX: usize = 5;
+
+/// Most collateral UTXOs one spend can take when merges start at
+/// `first_merge_index`.
+pub(crate) const fn max_collateral_inputs(first_merge_index: usize) -> usize {
+    MAX_COLLATERAL_INPUT_INDEX + 2 - first_merge_index
+}
+
+/// Input indices of `count` collateral UTXOs: the first at input 0, the rest
+/// merged in from `first_merge_index` onward.
+pub(crate) fn collateral_input_indices(
+    count: usize,
+    first_merge_index: usize,
+) -> Result<Vec<usize>> {
+    let max = max_collateral_inputs(first_merge_index);
+    if count == 0 || count > max {
+        return Err(Error::CollateralInputCount { count, max });
+    }
+    Ok(std::iter::once(0)
+        .chain(first_merge_index..first_merge_index + count - 1)
+        .collect())
+}
+
+/// Combined value of a spend's collateral UTXOs.
+pub(crate) fn collateral_total(collateral: &[UnblindedUtxo]) -> Result<u64> {
+    collateral.iter().try_fold(0u64, |total, utxo| {
+        total
+            .checked_add(utxo.value)
+            .ok_or(Error::CollateralOverflow)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,6 +65,7 @@ mod tests {
     use crate::prediction_market::params::PredictionMarketParams;
     use crate::prediction_market::state::MarketState;
     use simplicityhl::elements::LockTime;
+    use simplicityhl::elements::confidential::Value as ConfValue;
     use simplicityhl::elements::{AssetId, OutPoint};
 
     const TEST_ASSET: [u8; 32] = [0xaa; 32];
@@ -213,6 +249,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: None,
@@ -245,6 +282,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: None,
@@ -275,6 +313,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: Some(Script::new()),
@@ -300,6 +339,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: None,
@@ -325,6 +365,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: u64::MAX,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: None,
@@ -353,6 +394,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: None,
@@ -386,6 +428,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: None,
@@ -413,6 +456,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: None,
@@ -439,6 +483,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs: u64::MAX,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             yes_token_destination: Script::new(),
             no_token_destination: Script::new(),
             collateral_change_destination: None,
@@ -528,7 +573,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = post_resolution_redemption::PostResolutionRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             tokens_burned: 5,
@@ -552,7 +597,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = post_resolution_redemption::PostResolutionRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 10)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             tokens_burned: 10,
@@ -574,7 +619,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = post_resolution_redemption::PostResolutionRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 1_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 1_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500_000),
             tokens_burned: 3,
@@ -595,7 +640,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = post_resolution_redemption::PostResolutionRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             tokens_burned: 5,
@@ -615,7 +660,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = post_resolution_redemption::PostResolutionRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 1_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 1_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 20)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             tokens_burned: 20,
@@ -630,6 +675,33 @@ mod tests {
         assert!(matches!(result, Err(Error::InsufficientCollateral)));
     }
 
+    #[test]
+    fn post_res_redemption_merges_split_collateral() {
+        let contract = test_contract();
+        let p = contract.params();
+        let params = post_resolution_redemption::PostResolutionRedemptionParams {
+            collateral_utxos: vec![
+                test_utxo(p.collateral_asset_id, 1_000_000),
+                test_utxo(p.collateral_asset_id, 1_000_000),
+            ],
+            token_utxos: vec![test_utxo(p.yes_token_asset, 8)],
+            fee_utxo: test_utxo(p.collateral_asset_id, 500),
+            tokens_burned: 8,
+            resolved_state: MarketState::ResolvedYes,
+            fee_amount: 500,
+            payout_destination: Script::new(),
+            fee_change_destination: None,
+            token_change_destination: None,
+        };
+        let pset =
+            post_resolution_redemption::build_post_resolution_redemption_pset(&contract, &params)
+                .unwrap();
+        // 2 collateral + 1 token + 1 fee = 4 inputs
+        assert_eq!(pset.inputs().len(), 4);
+        // Both UTXOs back one payout; the rest returns to the covenant.
+        assert_eq!(pset.outputs()[0].amount, Some(400_000));
+    }
+
     // ===== build_expiry_redemption_pset =====
 
     #[test]
@@ -637,7 +709,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = expiry_redemption::ExpiryRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             tokens_burned: 5,
@@ -661,7 +733,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = expiry_redemption::ExpiryRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 20)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             tokens_burned: 20,
@@ -682,7 +754,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = expiry_redemption::ExpiryRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 1_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 1_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500_000),
             tokens_burned: 3,
@@ -702,7 +774,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = expiry_redemption::ExpiryRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 30)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             tokens_burned: 30,
@@ -722,7 +794,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = expiry_redemption::ExpiryRedemptionParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             tokens_burned: 5,
@@ -815,7 +887,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = cancellation::CancellationParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             yes_reissuance_utxo: None,
             no_reissuance_utxo: None,
             yes_token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
@@ -823,6 +895,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs_burned: 5,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             refund_destination: Script::new(),
             fee_change_destination: None,
             token_change_destination: None,
@@ -834,12 +907,42 @@ mod tests {
         assert_eq!(pset.outputs().len(), 5);
     }
 
+    #[test]
+    fn cancellation_fee_in_separate_asset() {
+        let contract = test_contract();
+        let p = contract.params();
+        let fee_asset = [0xfe; 32];
+        let params = cancellation::CancellationParams {
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
+            yes_reissuance_utxo: None,
+            no_reissuance_utxo: None,
+            yes_token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
+            no_token_utxos: vec![test_utxo(p.no_token_asset, 5)],
+            fee_utxo: test_utxo(fee_asset, 800),
+            pairs_burned: 5,
+            fee_amount: 500,
+            fee_asset_id: fee_asset,
+            refund_destination: Script::new(),
+            fee_change_destination: Some(dummy_change_script()),
+            token_change_destination: None,
+        };
+        let pset = cancellation::build_cancellation_pset(&contract, &params).unwrap();
+        let fee_asset_id = AssetId::from_slice(&fee_asset).unwrap();
+        let outputs = pset.outputs();
+        let fee_change = &outputs[outputs.len() - 2];
+        assert_eq!(fee_change.asset, Some(fee_asset_id));
+        assert_eq!(fee_change.amount, Some(300));
+        let fee = outputs.last().unwrap();
+        assert_eq!(fee.asset, Some(fee_asset_id));
+        assert_eq!(fee.amount, Some(500));
+    }
+
     #[test]
     fn cancellation_full() {
         let contract = test_contract();
         let p = contract.params();
         let params = cancellation::CancellationParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             yes_reissuance_utxo: Some(test_utxo(p.yes_reissuance_token, 1)),
             no_reissuance_utxo: Some(test_utxo(p.no_reissuance_token, 1)),
             yes_token_utxos: vec![test_utxo(p.yes_token_asset, 10)],
@@ -847,6 +950,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs_burned: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             refund_destination: Script::new(),
             fee_change_destination: None,
             token_change_destination: None,
@@ -863,7 +967,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = cancellation::CancellationParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 1_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 1_000_000)],
             yes_reissuance_utxo: None,
             no_reissuance_utxo: None,
             yes_token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
@@ -871,6 +975,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500_000),
             pairs_burned: 3,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             refund_destination: Script::from(vec![0x57]),
             fee_change_destination: Some(Script::from(vec![0x58])),
             token_change_destination: Some(Script::from(vec![0x59])),
@@ -884,7 +989,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = cancellation::CancellationParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             yes_reissuance_utxo: None,
             no_reissuance_utxo: None,
             yes_token_utxos: vec![test_utxo(p.yes_token_asset, 5)],
@@ -892,6 +997,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs_burned: 5,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             refund_destination: Script::new(),
             fee_change_destination: None,
             token_change_destination: None,
@@ -907,7 +1013,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = cancellation::CancellationParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             yes_reissuance_utxo: Some(test_utxo(p.yes_reissuance_token, 1)),
             no_reissuance_utxo: Some(test_utxo(p.no_reissuance_token, 1)),
             yes_token_utxos: vec![test_utxo(p.yes_token_asset, 10)],
@@ -915,6 +1021,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs_burned: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             refund_destination: Script::new(),
             fee_change_destination: None,
             token_change_destination: None,
@@ -931,7 +1038,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = cancellation::CancellationParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 2_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 2_000_000)],
             yes_reissuance_utxo: None,
             no_reissuance_utxo: None,
             yes_token_utxos: vec![test_utxo(p.yes_token_asset, 10)],
@@ -939,6 +1046,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs_burned: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             refund_destination: Script::new(),
             fee_change_destination: None,
             token_change_destination: None,
@@ -952,7 +1060,7 @@ mod tests {
         let contract = test_contract();
         let p = contract.params();
         let params = cancellation::CancellationParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, 1_000_000),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 1_000_000)],
             yes_reissuance_utxo: None,
             no_reissuance_utxo: None,
             yes_token_utxos: vec![test_utxo(p.yes_token_asset, 10)],
@@ -960,6 +1068,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs_burned: 10,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             refund_destination: Script::new(),
             fee_change_destination: None,
             token_change_destination: None,
@@ -968,12 +1077,95 @@ mod tests {
         assert!(matches!(result, Err(Error::InsufficientCollateral)));
     }
 
+    #[test]
+    fn cancellation_partial_merges_split_collateral() {
+        let contract = test_contract();
+        let p = contract.params();
+        let params = cancellation::CancellationParams {
+            collateral_utxos: vec![
+                test_utxo(p.collateral_asset_id, 1_200_000),
+                test_utxo(p.collateral_asset_id, 1_000_000),
+            ],
+            yes_reissuance_utxo: None,
+            no_reissuance_utxo: None,
+            yes_token_utxos: vec![test_utxo(p.yes_token_asset, 10)],
+            no_token_utxos: vec![test_utxo(p.no_token_asset, 10)],
+            fee_utxo: test_utxo(p.collateral_asset_id, 500),
+            pairs_burned: 10,
+            fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
+            refund_destination: Script::new(),
+            fee_change_destination: None,
+            token_change_destination: None,
+        };
+        let pset = cancellation::build_cancellation_pset(&contract, &params).unwrap();
+        // 2 collateral + 1 yes + 1 no + 1 fee = 5 inputs
+        assert_eq!(pset.inputs().len(), 5);
+        let unresolved_spk = contract.script_pubkey(MarketSlot::UnresolvedCollateral);
+        assert_eq!(pset.outputs()[0].script_pubkey, unresolved_spk);
+        assert_eq!(pset.outputs()[0].amount, Some(200_000));
+    }
+
+    #[test]
+    fn cancellation_full_merges_collateral_after_reissuance() {
+        let contract = test_contract();
+        let p = contract.params();
+        let params = cancellation::CancellationParams {
+            collateral_utxos: vec![
+                test_utxo(p.collateral_asset_id, 1_200_000),
+                test_utxo(p.collateral_asset_id, 800_000),
+            ],
+            yes_reissuance_utxo: Some(test_utxo(p.yes_reissuance_token, 1)),
+            no_reissuance_utxo: Some(test_utxo(p.no_reissuance_token, 1)),
+            yes_token_utxos: vec![test_utxo(p.yes_token_asset, 10)],
+            no_token_utxos: vec![test_utxo(p.no_token_asset, 10)],
+            fee_utxo: test_utxo(p.collateral_asset_id, 500),
+            pairs_burned: 10,
+            fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
+            refund_destination: Script::new(),
+            fee_change_destination: None,
+            token_change_destination: None,
+        };
+        let pset = cancellation::build_cancellation_pset(&contract, &params).unwrap();
+        // collateral, 2 reissuance, collateral, yes, no, fee = 7 inputs
+        assert_eq!(pset.inputs().len(), 7);
+        let input_value = |idx: usize| pset.inputs()[idx].witness_utxo.as_ref().unwrap().value;
+        assert_eq!(input_value(0), ConfValue::Explicit(1_200_000));
+        assert_eq!(input_value(3), ConfValue::Explicit(800_000));
+    }
+
+    #[test]
+    fn cancellation_rejects_collateral_past_merge_window() {
+        let contract = test_contract();
+        let p = contract.params();
+        let params = cancellation::CancellationParams {
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, 400_000); 5],
+            yes_reissuance_utxo: Some(test_utxo(p.yes_reissuance_token, 1)),
+            no_reissuance_utxo: Some(test_utxo(p.no_reissuance_token, 1)),
+            yes_token_utxos: vec![test_utxo(p.yes_token_asset, 10)],
+            no_token_utxos: vec![test_utxo(p.no_token_asset, 10)],
+            fee_utxo: test_utxo(p.collateral_asset_id, 500),
+            pairs_burned: 10,
+            fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
+            refund_destination: Script::new(),
+            fee_change_destination: None,
+            token_change_destination: None,
+        };
+        let result = cancellation::build_cancellation_pset(&contract, &params);
+        assert!(matches!(
+            result,
+            Err(Error::CollateralInputCount { count: 5, max: 4 })
+        ));
+    }
+
     #[test]
     fn cancellation_collateral_overflow() {
         let contract = test_contract();
         let p = contract.params();
         let params = cancellation::CancellationParams {
-            collateral_utxo: test_utxo(p.collateral_asset_id, u64::MAX),
+            collateral_utxos: vec![test_utxo(p.collateral_asset_id, u64::MAX)],
             yes_reissuance_utxo: None,
             no_reissuance_utxo: None,
             yes_token_utxos: vec![test_utxo(p.yes_token_asset, u64::MAX)],
@@ -981,6 +1173,7 @@ mod tests {
             fee_utxo: test_utxo(p.collateral_asset_id, 500),
             pairs_burned: u64::MAX,
             fee_amount: 500,
+            fee_asset_id: p.collateral_asset_id,
             refund_destination: Script::new(),
             fee_change_destination: None,
             token_change_destination: None,
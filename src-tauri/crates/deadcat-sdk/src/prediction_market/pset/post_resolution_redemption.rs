@@ -3,16 +3,18 @@ use simplicityhl::elements::pset::PartiallySignedTransaction;
 
 use crate::error::{Error, Result};
 use crate::prediction_market::contract::CompiledPredictionMarket;
+use crate::prediction_market::params::amounts::payout_for_tokens;
 use crate::prediction_market::state::MarketState;
 
 use super::{
-    UnblindedUtxo, add_pset_input, add_pset_output, burn_txout, covenant_spk, explicit_txout,
-    fee_txout, new_pset,
+    UnblindedUtxo, add_pset_input, add_pset_output, burn_txout, collateral_input_indices,
+    collateral_total, covenant_spk, explicit_txout, fee_txout, new_pset,
 };
 
 /// Parameters for constructing a post-resolution redemption PSET.
 pub struct PostResolutionRedemptionParams {
-    pub collateral_utxo: UnblindedUtxo,
+    /// Covenant collateral the payout is drawn from, merged into one spend.
+    pub collateral_utxos: Vec<UnblindedUtxo>,
     pub token_utxos: Vec<UnblindedUtxo>,
     pub fee_utxo: UnblindedUtxo,
     pub tokens_burned: u64,
@@ -35,17 +37,15 @@ pub fn build_post_resolution_redemption_pset(
     }
 
     let cpt = contract.params().collateral_per_token;
-    let payout = params
-        .tokens_burned
-        .checked_mul(2)
-        .and_then(|v| v.checked_mul(cpt))
-        .ok_or(Error::CollateralOverflow)?;
+    let payout = payout_for_tokens(params.tokens_burned, cpt, false)?;
 
-    if params.collateral_utxo.value < payout {
+    let collateral = collateral_total(&params.collateral_utxos)?;
+    if collateral < payout {
         return Err(Error::InsufficientCollateral);
     }
+    collateral_input_indices(params.collateral_utxos.len(), 1)?;
 
-    let remaining = params.collateral_utxo.value - payout;
+    let remaining = collateral - payout;
     let winning_asset = params
         .resolved_state
         .winning_token_asset(contract.params())
@@ -61,7 +61,9 @@ pub fn build_post_resolution_redemption_pset(
 
     let mut pset = new_pset();
 
-    add_pset_input(&mut pset, &params.collateral_utxo);
+    for utxo in &params.collateral_utxos {
+        add_pset_input(&mut pset, utxo);
+    }
     for utxo in &params.token_utxos {
         add_pset_input(&mut pset, utxo);
     }
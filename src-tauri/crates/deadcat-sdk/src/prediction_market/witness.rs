@@ -80,6 +80,9 @@ pub enum PredictionMarketSpendingPath {
     CancellationFullSecondaryNoRt {
         blinding: AllBlindingFactors,
     },
+    /// An extra collateral input (index 1..=5) whose value the primary at
+    /// input 0 adds to its own.
+    CollateralMergeSecondary,
 }
 
 fn unit_ty() -> ResolvedType {
@@ -130,20 +133,24 @@ fn path13_or_14_ty() -> ResolvedType {
     ResolvedType::either(unit_ty(), unit_ty())
 }
 
-fn path16_or_17_ty() -> ResolvedType {
+fn path15_or_16_ty() -> ResolvedType {
     ResolvedType::either(unit_ty(), unit_ty())
 }
 
-fn path15_to_17_ty() -> ResolvedType {
-    ResolvedType::either(unit_ty(), path16_or_17_ty())
+fn path17_or_18_ty() -> ResolvedType {
+    ResolvedType::either(unit_ty(), unit_ty())
+}
+
+fn path15_to_18_ty() -> ResolvedType {
+    ResolvedType::either(path15_or_16_ty(), path17_or_18_ty())
 }
 
-fn path13_to_17_ty() -> ResolvedType {
-    ResolvedType::either(path13_or_14_ty(), path15_to_17_ty())
+fn path13_to_18_ty() -> ResolvedType {
+    ResolvedType::either(path13_or_14_ty(), path15_to_18_ty())
 }
 
-fn path9_to_17_ty() -> ResolvedType {
-    ResolvedType::either(path9_to_12_ty(), path13_to_17_ty())
+fn path9_to_18_ty() -> ResolvedType {
+    ResolvedType::either(path9_to_12_ty(), path13_to_18_ty())
 }
 
 fn build_path_value(path: &PredictionMarketSpendingPath) -> Value {
@@ -154,94 +161,101 @@ fn build_path_value(path: &PredictionMarketSpendingPath) -> Value {
             let v = Value::left(u(), unit_ty());
             let v = Value::left(v, path3_or_4_ty());
             let v = Value::left(v, path5_to_8_ty());
-            Value::left(v, path9_to_17_ty())
+            Value::left(v, path9_to_18_ty())
         }
         PredictionMarketSpendingPath::InitialIssuanceSecondaryNoRt { .. } => {
             let v = Value::right(unit_ty(), u());
             let v = Value::left(v, path3_or_4_ty());
             let v = Value::left(v, path5_to_8_ty());
-            Value::left(v, path9_to_17_ty())
+            Value::left(v, path9_to_18_ty())
         }
         PredictionMarketSpendingPath::SubsequentIssuancePrimary { .. } => {
             let v = Value::left(u(), unit_ty());
             let v = Value::right(path1_or_2_ty(), v);
             let v = Value::left(v, path5_to_8_ty());
-            Value::left(v, path9_to_17_ty())
+            Value::left(v, path9_to_18_ty())
         }
         PredictionMarketSpendingPath::SubsequentIssuanceSecondaryNoRt { .. } => {
             let v = Value::right(unit_ty(), u());
             let v = Value::right(path1_or_2_ty(), v);
             let v = Value::left(v, path5_to_8_ty());
-            Value::left(v, path9_to_17_ty())
+            Value::left(v, path9_to_18_ty())
         }
         PredictionMarketSpendingPath::SubsequentIssuanceSecondaryCollateral => {
             let v = Value::left(u(), unit_ty());
             let v = Value::left(v, path7_or_8_ty());
             let v = Value::right(path1_to_4_ty(), v);
-            Value::left(v, path9_to_17_ty())
+            Value::left(v, path9_to_18_ty())
         }
         PredictionMarketSpendingPath::OracleResolvePrimary { .. } => {
             let v = Value::right(unit_ty(), u());
             let v = Value::left(v, path5_or_6_ty());
             let v = Value::right(path1_to_4_ty(), v);
-            Value::left(v, path9_to_17_ty())
+            Value::left(v, path9_to_18_ty())
         }
         PredictionMarketSpendingPath::OracleResolveSecondaryNoRt { .. } => {
             let v = Value::left(u(), unit_ty());
             let v = Value::right(path1_or_2_ty(), v);
             let v = Value::right(path1_to_4_ty(), v);
-            Value::left(v, path9_to_17_ty())
+            Value::left(v, path9_to_18_ty())
         }
         PredictionMarketSpendingPath::OracleResolveSecondaryCollateral => {
             let v = Value::right(unit_ty(), u());
             let v = Value::right(path1_or_2_ty(), v);
             let v = Value::right(path1_to_4_ty(), v);
-            Value::left(v, path9_to_17_ty())
+            Value::left(v, path9_to_18_ty())
         }
         PredictionMarketSpendingPath::PostResolutionRedemption { .. } => {
             let v = Value::left(u(), unit_ty());
             let v = Value::left(v, path11_or_12_ty());
-            Value::right(path1_to_8_ty(), Value::left(v, path13_to_17_ty()))
+            Value::right(path1_to_8_ty(), Value::left(v, path13_to_18_ty()))
         }
         PredictionMarketSpendingPath::ExpireTransitionPrimary { .. } => {
             let v = Value::right(unit_ty(), u());
             let v = Value::left(v, path11_or_12_ty());
-            Value::right(path1_to_8_ty(), Value::left(v, path13_to_17_ty()))
+            Value::right(path1_to_8_ty(), Value::left(v, path13_to_18_ty()))
         }
         PredictionMarketSpendingPath::ExpireTransitionSecondaryNoRt { .. } => {
             let v = Value::left(u(), unit_ty());
             let v = Value::right(path9_or_10_ty(), v);
-            Value::right(path1_to_8_ty(), Value::left(v, path13_to_17_ty()))
+            Value::right(path1_to_8_ty(), Value::left(v, path13_to_18_ty()))
         }
         PredictionMarketSpendingPath::ExpireTransitionSecondaryCollateral => {
             let v = Value::right(unit_ty(), u());
             let v = Value::right(path9_or_10_ty(), v);
-            Value::right(path1_to_8_ty(), Value::left(v, path13_to_17_ty()))
+            Value::right(path1_to_8_ty(), Value::left(v, path13_to_18_ty()))
         }
         PredictionMarketSpendingPath::ExpiryRedemption { .. } => {
             let v = Value::left(u(), unit_ty());
-            let v = Value::left(v, path15_to_17_ty());
+            let v = Value::left(v, path15_to_18_ty());
             Value::right(path1_to_8_ty(), Value::right(path9_to_12_ty(), v))
         }
         PredictionMarketSpendingPath::CancellationPartial { .. } => {
             let v = Value::right(unit_ty(), u());
-            let v = Value::left(v, path15_to_17_ty());
+            let v = Value::left(v, path15_to_18_ty());
             Value::right(path1_to_8_ty(), Value::right(path9_to_12_ty(), v))
         }
         PredictionMarketSpendingPath::CancellationFullPrimary { .. } => {
-            let v = Value::left(u(), path16_or_17_ty());
+            let v = Value::left(u(), unit_ty());
+            let v = Value::left(v, path17_or_18_ty());
             let v = Value::right(path13_or_14_ty(), v);
             Value::right(path1_to_8_ty(), Value::right(path9_to_12_ty(), v))
         }
         PredictionMarketSpendingPath::CancellationFullSecondaryYesRt { .. } => {
-            let v = Value::left(u(), unit_ty());
-            let v = Value::right(unit_ty(), v);
+            let v = Value::right(unit_ty(), u());
+            let v = Value::left(v, path17_or_18_ty());
             let v = Value::right(path13_or_14_ty(), v);
             Value::right(path1_to_8_ty(), Value::right(path9_to_12_ty(), v))
         }
         PredictionMarketSpendingPath::CancellationFullSecondaryNoRt { .. } => {
+            let v = Value::left(u(), unit_ty());
+            let v = Value::right(path15_or_16_ty(), v);
+            let v = Value::right(path13_or_14_ty(), v);
+            Value::right(path1_to_8_ty(), Value::right(path9_to_12_ty(), v))
+        }
+        PredictionMarketSpendingPath::CollateralMergeSecondary => {
             let v = Value::right(unit_ty(), u());
-            let v = Value::right(unit_ty(), v);
+            let v = Value::right(path15_or_16_ty(), v);
             let v = Value::right(path13_or_14_ty(), v);
             Value::right(path1_to_8_ty(), Value::right(path9_to_12_ty(), v))
         }
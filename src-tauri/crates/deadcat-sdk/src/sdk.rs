@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use lwk_common::Signer;
 use lwk_signer::SwSigner;
@@ -9,15 +9,15 @@ use lwk_wollet::elements::pset::PartiallySignedTransaction;
 use lwk_wollet::elements::secp256k1_zkp::{self, Keypair};
 use lwk_wollet::elements::{AssetId, OutPoint, Script, Transaction, TxOut, Txid};
 use lwk_wollet::elements_miniscript::confidential::slip77::MasterBlindingKey;
-use lwk_wollet::{
-    ElectrumClient, ElectrumUrl, TxBuilder, WalletTx, WalletTxOut, Wollet, WolletDescriptor,
-};
-use rand::RngCore;
-use rand::thread_rng;
+use lwk_wollet::{TxBuilder, WalletTx, WalletTxOut, Wollet};
 
 use crate::assembly::{pset_to_pruning_transaction, txout_secrets_from_unblinded};
 use crate::chain::{ChainBackend, ElectrumBackend};
+use crate::coin_selection::{CoinSelection, FeeOperation};
+use crate::descriptor::DescriptorTemplate;
+use crate::entropy::{EntropySource, ThreadRngEntropy, seeded_rng};
 use crate::error::{Error, Result};
+use crate::intent::{BroadcastIntent, IntentJournal, IntentKind, IntentStatus, NoIntentJournal};
 use crate::lmsr_pool::api::{
     AdjustLmsrPoolRequest, AdjustLmsrPoolResult, CloseLmsrPoolRequest, CloseLmsrPoolResult,
     CreateLmsrPoolRequest, LmsrPoolLocator, LmsrPoolSnapshot, txid_to_canonical_bytes,
@@ -28,35 +28,44 @@ use crate::lmsr_pool::chain_walk::{
 };
 use crate::lmsr_pool::contract::CompiledLmsrPool;
 use crate::lmsr_pool::identity::derive_lmsr_pool_id;
-use crate::lmsr_pool::math::LmsrTradeKind;
+use crate::lmsr_pool::math::{LmsrTradeKind, MAX_CREATION_FEE_BPS};
 use crate::lmsr_pool::params::{LmsrInitialOutpoint, LmsrPoolParams};
 use crate::lmsr_pool::table::LmsrTableManifest;
 use crate::maker_order::contract::CompiledMakerOrder;
 use crate::maker_order::params::{
     MakerOrderParams, OrderDirection, derive_maker_receive, maker_receive_script_pubkey,
+    validate_order_amounts,
 };
 use crate::maker_order::pset::cancel_order::{CancelOrderParams, build_cancel_order_pset};
 use crate::maker_order::pset::create_order::{CreateOrderParams, build_create_order_pset};
 use crate::maker_order::pset::fill_order::{
     FillOrderParams, MakerOrderFill, TakerFill, build_fill_order_pset,
 };
+use crate::maker_order::pset::top_up_order::{TopUpOrderParams, build_top_up_order_pset};
 use crate::maker_order::witness::serialize_satisfied as serialize_maker_order_satisfied;
-use crate::network::Network;
+use crate::network::{Network, ensure_network_marker};
 use crate::pool::PoolReserves;
 use crate::prediction_market::anchor::{PredictionMarketAnchor, parse_prediction_market_anchor};
+#[cfg(any(test, feature = "testing"))]
+use crate::prediction_market::assembly::BlindingAudit;
 use crate::prediction_market::assembly::{
-    CollateralSource, IssuanceAssemblyInputs, assemble_cancellation, assemble_expire_transition,
-    assemble_expiry_redemption, assemble_issuance, assemble_oracle_resolve,
-    assemble_post_resolution_redemption, compute_issuance_entropy,
+    AssembledPset, CollateralSource, IssuanceAssemblyInputs, assemble_cancellation,
+    assemble_expire_transition, assemble_expiry_redemption, assemble_issuance,
+    assemble_oracle_resolve, assemble_post_resolution_redemption, blind_last_with_factors,
+    compute_issuance_entropy,
 };
 use crate::prediction_market::contract::CompiledPredictionMarket;
 use crate::prediction_market::params::PredictionMarketParams;
+use crate::prediction_market::params::amounts::{
+    collateral_for_pairs, payout_for_tokens, payout_per_token,
+};
 use crate::prediction_market::pset::cancellation::CancellationParams;
 use crate::prediction_market::pset::creation::{CreationParams, build_creation_pset};
 use crate::prediction_market::pset::expire_transition::ExpireTransitionParams;
 use crate::prediction_market::pset::expiry_redemption::ExpiryRedemptionParams;
 use crate::prediction_market::pset::oracle_resolve::OracleResolveParams;
 use crate::prediction_market::pset::post_resolution_redemption::PostResolutionRedemptionParams;
+use crate::prediction_market::pset::{collateral_total, max_collateral_inputs};
 use crate::prediction_market::state::MarketState;
 use crate::prediction_market_scan::{
     PredictionMarketScanBackend, scan_prediction_market_canonical,
@@ -66,7 +75,9 @@ use crate::pset::{
     UnblindedUtxo, add_pset_input, add_pset_output, explicit_txout, fee_txout, new_pset,
 };
 use crate::taproot::NUMS_KEY_BYTES;
+use crate::telemetry::OpSpan;
 use crate::trade::types::{LmsrPoolSwapLeg, LmsrPoolUtxos, LmsrPrimaryPath};
+use crate::tx_cache::{NoTransactionCache, TransactionCache};
 
 use crate::discovery::pool::LMSR_WITNESS_SCHEMA_V2;
 
@@ -77,6 +88,9 @@ pub struct IssuanceResult {
     pub previous_state: MarketState,
     pub new_state: MarketState,
     pub pairs_issued: u64,
+    /// Base64 PSET, fully built and witnessed but unsigned, when run with
+    /// `dry_run`; `None` once broadcast.
+    pub unsigned_pset: Option<String>,
 }
 
 /// Result of a successful token cancellation.
@@ -87,6 +101,9 @@ pub struct CancellationResult {
     pub new_state: MarketState,
     pub pairs_burned: u64,
     pub is_full_cancellation: bool,
+    /// Base64 PSET, fully built and witnessed but unsigned, when run with
+    /// `dry_run`; `None` once broadcast.
+    pub unsigned_pset: Option<String>,
 }
 
 /// Result of a successful oracle resolution.
@@ -96,15 +113,49 @@ pub struct ResolutionResult {
     pub previous_state: MarketState,
     pub new_state: MarketState,
     pub outcome_yes: bool,
+    /// Base64 PSET, fully built and witnessed but unsigned, when run with
+    /// `dry_run`; `None` once broadcast.
+    pub unsigned_pset: Option<String>,
 }
 
 /// Result of a successful token redemption (post-resolution or expiry).
 #[derive(Debug, Clone)]
 pub struct RedemptionResult {
     pub txid: Txid,
+    /// Follow-up transactions, present when the covenant collateral was split
+    /// across several UTXOs and had to be drawn one transaction at a time.
+    pub extra_txids: Vec<Txid>,
     pub previous_state: MarketState,
     pub tokens_redeemed: u64,
     pub payout_sats: u64,
+    /// Base64 PSET, fully built and witnessed but unsigned, when run with
+    /// `dry_run`; `None` once broadcast.
+    pub unsigned_pset: Option<String>,
+}
+
+/// Result of redeeming every YES and NO token held for an expired market.
+#[derive(Debug, Clone)]
+pub struct ExpiredRedemptionSweep {
+    /// One redemption per token type held, YES first.
+    pub redemptions: Vec<RedemptionResult>,
+    pub total_tokens: u64,
+    pub total_payout_sats: u64,
+}
+
+/// What a redemption would pay out, computed without building a transaction.
+#[derive(Debug, Clone)]
+pub struct RedemptionPreview {
+    pub current_state: MarketState,
+    /// True when the tokens redeem through the expiry path at 1x collateral
+    /// per token rather than as winners at 2x.
+    pub is_expiry: bool,
+    /// The token that would be burned.
+    pub winning_asset: [u8; 32],
+    pub payout_sats: u64,
+    /// Tokens of `winning_asset` the wallet is missing for this redemption.
+    pub token_shortfall: u64,
+    /// Collateral the covenant is missing to cover `payout_sats`.
+    pub collateral_shortfall_sats: u64,
 }
 
 /// Result of a successful limit order creation.
@@ -126,11 +177,223 @@ pub struct FillOrderResult {
     pub is_partial: bool,
 }
 
+/// Dry-run of a limit order fill against the current wallet balance.
+///
+/// Shortfalls are zero when the wallet holds a single UTXO large enough for
+/// each leg, mirroring the coin selection used by
+/// [`DeadcatSdk::fill_limit_order`].
+#[derive(Debug, Clone)]
+pub struct FillSimulation {
+    pub can_afford: bool,
+    pub lots_to_fill: u64,
+    pub is_partial: bool,
+    pub input_asset: AssetId,
+    pub input_needed: u64,
+    pub input_shortfall: u64,
+    pub output_asset: AssetId,
+    pub output_received: u64,
+    pub fee_est: u64,
+    pub fee_shortfall: u64,
+}
+
 /// Result of a successful limit order cancellation.
 #[derive(Debug, Clone)]
 pub struct CancelOrderResult {
     pub txid: Txid,
     pub refunded_amount: u64,
+    /// Unconfirmed fill this cancel replaced via RBF, if any.
+    pub replaced_fill_txid: Option<Txid>,
+}
+
+/// Result of adding funds to a live limit order.
+#[derive(Debug, Clone)]
+pub struct TopUpOrderResult {
+    pub txid: Txid,
+    /// Amount locked in the order before the top-up.
+    pub previous_amount: u64,
+    /// Amount now offered by the order.
+    pub order_amount: u64,
+    pub covenant_address: String,
+}
+
+/// Result of moving a limit order to a new price.
+#[derive(Debug, Clone)]
+pub struct RepriceOrderResult {
+    pub cancel: CancelOrderResult,
+    /// The recreated order, or why it could not be created. The cancel has
+    /// been broadcast either way; on failure the refund stays in the wallet.
+    pub create: std::result::Result<CreateOrderResult, String>,
+}
+
+/// Virtual size assumed for an order cancel: the covenant input with its
+/// Simplicity witness, a fee input, and two blinded outputs. Used only to
+/// price an RBF replacement, so it errs high.
+const CANCEL_VSIZE_ESTIMATE: u64 = 3_000;
+
+/// Incremental relay fee (sat/kvB) a replacement must add on top of the fee
+/// of the transaction it replaces.
+const RBF_INCREMENTAL_RELAY_FEE_RATE: u64 = 100;
+
+/// A maker order to cancel, identified by the data recorded at creation.
+#[derive(Debug, Clone)]
+pub struct CancelOrderTarget {
+    pub params: MakerOrderParams,
+    pub maker_base_pubkey: [u8; 32],
+    pub order_index: u32,
+}
+
+/// Per-order outcome of [`DeadcatSdk::cancel_all_orders`].
+#[derive(Debug)]
+pub struct CancelAllOutcome {
+    pub target: CancelOrderTarget,
+    pub result: Result<CancelOrderResult>,
+}
+
+/// Result of a successful CPFP fee bump.
+#[derive(Debug, Clone)]
+pub struct FeeBumpResult {
+    /// Txid of the child transaction.
+    pub txid: Txid,
+    pub parent_txid: Txid,
+    /// Fee paid by the child alone.
+    pub child_fee_sats: u64,
+}
+
+/// Virtual size assumed for a CPFP child: one confidential input and one
+/// blinded change output plus the fee output. The change rangeproof dominates,
+/// so this errs high rather than under-paying.
+const CPFP_CHILD_VSIZE_ESTIMATE: u64 = 1_300;
+
+/// An external private key to sweep, erased when dropped.
+///
+/// Unlike `PrivateKey` it isn't `Copy`, so moving it into a sweep hands over
+/// the only copy instead of leaving one behind with the caller.
+pub struct SweepKey(lwk_wollet::elements::bitcoin::PrivateKey);
+
+impl SweepKey {
+    pub fn new(key: lwk_wollet::elements::bitcoin::PrivateKey) -> Self {
+        Self(key)
+    }
+}
+
+impl Drop for SweepKey {
+    fn drop(&mut self) {
+        self.0.inner.non_secure_erase();
+    }
+}
+
+/// Result of sweeping an external private key into the wallet.
+#[derive(Debug, Clone)]
+pub struct SweepResult {
+    pub txid: Txid,
+    /// Amount received per asset; the L-BTC entry is net of the fee.
+    pub swept: Vec<(AssetId, u64)>,
+    pub fee_sats: u64,
+}
+
+/// Result of merging wallet UTXOs of one asset into a single output.
+#[derive(Debug, Clone)]
+pub struct ConsolidationResult {
+    pub txid: Txid,
+    pub asset_id: AssetId,
+    pub inputs_consolidated: usize,
+    /// Value of the merged output; net of the fee when consolidating L-BTC.
+    pub output_value: u64,
+    pub fee_sats: u64,
+}
+
+/// Full breakdown of a transaction for a wallet detail view.
+#[derive(Debug, Clone)]
+pub struct TransactionDetail {
+    pub txid: Txid,
+    pub height: Option<u32>,
+    /// Zero while unconfirmed.
+    pub confirmations: u32,
+    /// Explicit fee paid in the policy asset.
+    pub fee: u64,
+    pub inputs: Vec<TransactionDetailEntry>,
+    pub outputs: Vec<TransactionDetailEntry>,
+}
+
+/// One input (keyed by the outpoint it spends) or output of a
+/// [`TransactionDetail`].
+#[derive(Debug, Clone)]
+pub struct TransactionDetailEntry {
+    pub outpoint: OutPoint,
+    /// Empty for inputs whose previous output could not be resolved, such as
+    /// coinbase and peg-in inputs.
+    pub script_pubkey: Script,
+    /// `None` when the output is blinded to someone other than this wallet.
+    pub asset: Option<AssetId>,
+    pub value: Option<u64>,
+    /// Unconfidential address for foreign outputs, `None` for scripts with no
+    /// address form (fee outputs, OP_RETURN).
+    pub address: Option<String>,
+    pub is_mine: bool,
+    /// Wallet output on the internal (change) chain.
+    pub is_change: bool,
+    pub is_fee: bool,
+}
+
+/// Unspent explicit output received on one of the wallet's explicit
+/// addresses.
+#[derive(Debug, Clone)]
+pub struct ExplicitUtxo {
+    /// External derivation index of the receiving address.
+    pub index: u32,
+    pub outpoint: OutPoint,
+    pub asset: AssetId,
+    pub value: u64,
+}
+
+/// Sweep transaction size model. Inputs are priced as P2PKH, the largest of
+/// the supported script types, and every asset gets one blinded output.
+const SWEEP_BASE_VSIZE: u64 = 100;
+const SWEEP_INPUT_VSIZE: u64 = 150;
+const SWEEP_OUTPUT_VSIZE: u64 = 1_200;
+
+/// Consolidation transaction size model. Wallet inputs are priced as
+/// P2SH-wrapped segwit, the larger of the supported descriptor shapes.
+const CONSOLIDATION_INPUT_VSIZE: u64 = 100;
+
+/// Wallet syncs, 500ms apart, that [`DeadcatSdk::split_utxo`] waits for its
+/// transaction to show up in.
+const SPLIT_VISIBILITY_ATTEMPTS: u32 = 10;
+
+/// Script types a bare private key may have received funds on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SweepScript {
+    P2wpkh,
+    P2shP2wpkh,
+    P2pkh,
+}
+
+impl SweepScript {
+    /// Segwit scripts are only valid for compressed keys.
+    fn for_key(pubkey: &lwk_wollet::elements::bitcoin::PublicKey) -> &'static [SweepScript] {
+        if pubkey.compressed {
+            &[
+                SweepScript::P2wpkh,
+                SweepScript::P2shP2wpkh,
+                SweepScript::P2pkh,
+            ]
+        } else {
+            &[SweepScript::P2pkh]
+        }
+    }
+
+    fn address(
+        self,
+        pubkey: &lwk_wollet::elements::bitcoin::PublicKey,
+        params: &'static lwk_wollet::elements::AddressParams,
+    ) -> lwk_wollet::elements::Address {
+        use lwk_wollet::elements::Address;
+        match self {
+            SweepScript::P2wpkh => Address::p2wpkh(pubkey, None, params),
+            SweepScript::P2shP2wpkh => Address::p2shwpkh(pubkey, None, params),
+            SweepScript::P2pkh => Address::p2pkh(pubkey, None, params),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -158,6 +421,17 @@ pub(crate) struct LmsrPoolScanHistoryResult {
     pub best_block_height: u32,
 }
 
+/// Amounts moved by filling `lots` of a maker order, from the taker's side.
+struct FillAmounts {
+    taker_pays_amount: u64,
+    taker_pays_asset: [u8; 32],
+    taker_receives_amount: u64,
+    taker_receives_asset: [u8; 32],
+    maker_receive_amount: u64,
+    is_partial: bool,
+    remainder_amount: u64,
+}
+
 struct LmsrBootstrapPset {
     pset: PartiallySignedTransaction,
     wallet_inputs: Vec<UnblindedUtxo>,
@@ -167,7 +441,12 @@ struct LmsrBootstrapPset {
 pub struct DeadcatSdk {
     signer: SwSigner,
     wollet: Wollet,
+    /// Where `wollet` persists its scan cache; wiped by
+    /// [`rescan_wallet`](Self::rescan_wallet).
+    wallet_persist_dir: PathBuf,
     network: Network,
+    /// `network`'s policy asset, computed once at construction.
+    policy_asset: AssetId,
     chain: ElectrumBackend,
     /// Genesis hash for the Simplicity C runtime.
     ///
@@ -175,6 +454,21 @@ pub struct DeadcatSdk {
     /// For regtest, this must be set to the actual chain genesis hash
     /// via [`set_chain_genesis_hash`](Self::set_chain_genesis_hash).
     chain_genesis_override: Option<[u8; 32]>,
+    /// Source of order nonces and blinding randomness.
+    entropy: Box<dyn EntropySource>,
+    /// Lookaside cache for [`fetch_transaction`](Self::fetch_transaction).
+    tx_cache: Box<dyn TransactionCache>,
+    /// Journal of covenant broadcasts, for recovery after an interruption.
+    intents: Box<dyn IntentJournal>,
+    /// Fee UTXO selection per operation; unset operations use the default.
+    fee_coin_selection: HashMap<FeeOperation, CoinSelection>,
+    /// Whether blinding keeps the factors it chose.
+    #[cfg(any(test, feature = "testing"))]
+    audit_blinding: bool,
+    /// Blinding secrets of the last transaction blinded with
+    /// `audit_blinding` on.
+    #[cfg(any(test, feature = "testing"))]
+    last_blinding_audit: Option<BlindingAudit>,
 }
 
 struct SdkPredictionMarketScanBackend<'a> {
@@ -222,28 +516,50 @@ impl DeadcatSdk {
         electrum_url: &str,
         datadir: &Path,
     ) -> Result<Self> {
-        let signer = SwSigner::new(mnemonic, network.is_mainnet())
-            .map_err(|e| Error::Signer(e.to_string()))?;
+        Self::with_descriptor_template(
+            mnemonic,
+            network,
+            electrum_url,
+            datadir,
+            DescriptorTemplate::default(),
+        )
+    }
 
-        let slip77_key = signer
-            .slip77_master_blinding_key()
+    /// Like [`new`](Self::new), but builds the wallet descriptor from
+    /// `template` instead of the default native-segwit shape.
+    pub fn with_descriptor_template(
+        mnemonic: &str,
+        network: Network,
+        electrum_url: &str,
+        datadir: &Path,
+        template: DescriptorTemplate,
+    ) -> Result<Self> {
+        let signer = SwSigner::new(mnemonic, network.is_mainnet())
             .map_err(|e| Error::Signer(e.to_string()))?;
-        let xpub = signer.xpub();
-        let descriptor_str = format!("ct(slip77({}),elwpkh({}/*))", slip77_key, xpub);
-        let descriptor: WolletDescriptor = descriptor_str
-            .parse()
-            .map_err(|e: lwk_wollet::Error| Error::Descriptor(e.to_string()))?;
+        let descriptor = template.descriptor(&signer)?;
 
-        let persist_dir = datadir.join(network.as_str()).join("wallet_db");
+        let network_dir = datadir.join(network.as_str());
+        ensure_network_marker(&network_dir, network)?;
+        let persist_dir = network_dir.join("wallet_db");
         let wollet = Wollet::with_fs_persist(network.into_lwk(), descriptor, &persist_dir)
             .map_err(|e| Error::WalletInit(e.to_string()))?;
 
         Ok(Self {
             signer,
             wollet,
+            wallet_persist_dir: persist_dir,
             network,
+            policy_asset: network.into_lwk().policy_asset(),
             chain: ElectrumBackend::new(electrum_url),
             chain_genesis_override: None,
+            entropy: Box::new(ThreadRngEntropy),
+            tx_cache: Box::new(NoTransactionCache),
+            intents: Box::new(NoIntentJournal),
+            fee_coin_selection: HashMap::new(),
+            #[cfg(any(test, feature = "testing"))]
+            audit_blinding: false,
+            #[cfg(any(test, feature = "testing"))]
+            last_blinding_audit: None,
         })
     }
 
@@ -256,17 +572,32 @@ impl DeadcatSdk {
     // ── Wallet queries ───────────────────────────────────────────────────
 
     pub fn sync(&mut self) -> Result<()> {
-        let url: ElectrumUrl = self
-            .chain
-            .electrum_url()
-            .parse()
-            .map_err(|e| Error::Electrum(format!("{:?}", e)))?;
-        let mut client = ElectrumClient::new(&url).map_err(|e| Error::Electrum(e.to_string()))?;
+        let mut client = self.chain.lwk_client()?;
         lwk_wollet::full_scan_with_electrum_client(&mut self.wollet, &mut client)
             .map_err(|e| Error::Electrum(e.to_string()))?;
         Ok(())
     }
 
+    /// Discard the wallet's scan cache and rebuild it with a full scan.
+    ///
+    /// Recovery path for a deleted or corrupted wallet database. LWK scans by
+    /// script history, so the rebuilt cache covers the wallet's whole history
+    /// regardless of when funds arrived.
+    pub fn rescan_wallet(&mut self) -> Result<()> {
+        let descriptor = self.wollet.wollet_descriptor();
+        if self.wallet_persist_dir.exists() {
+            std::fs::remove_dir_all(&self.wallet_persist_dir)
+                .map_err(|e| Error::WalletInit(e.to_string()))?;
+        }
+        self.wollet = Wollet::with_fs_persist(
+            self.network.into_lwk(),
+            descriptor,
+            &self.wallet_persist_dir,
+        )
+        .map_err(|e| Error::WalletInit(e.to_string()))?;
+        self.sync()
+    }
+
     /// Get the genesis block hash for Simplicity operations.
     ///
     /// Returns the override if set (required for regtest), otherwise
@@ -286,6 +617,71 @@ impl DeadcatSdk {
         self.chain_genesis_override = Some(hash);
     }
 
+    /// Replace the randomness used for order nonces and PSET blinding.
+    ///
+    /// Defaults to [`ThreadRngEntropy`]. Tests install a deterministic source
+    /// to make assembled transactions reproducible.
+    pub fn set_entropy_source(&mut self, source: Box<dyn EntropySource>) {
+        self.entropy = source;
+    }
+
+    /// Keep the blinding factors of every transaction the SDK blinds from now
+    /// on (prediction-market, maker-order and LMSR pool flows alike), for [`take_blinding_audit`](Self::take_blinding_audit).
+    ///
+    /// Off by default: the factors let anyone holding them unblind the
+    /// transaction's outputs.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn set_blinding_audit(&mut self, enabled: bool) {
+        self.audit_blinding = enabled;
+        if !enabled {
+            self.last_blinding_audit = None;
+        }
+    }
+
+    /// Blinding secrets of the last transaction blinded while audit mode was
+    /// on, including dry runs.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn take_blinding_audit(&mut self) -> Option<BlindingAudit> {
+        self.last_blinding_audit.take()
+    }
+
+    /// Install a cache consulted before fetching transactions from Electrum.
+    ///
+    /// Defaults to [`NoTransactionCache`]. The node installs its persistence
+    /// store here so UTXO scans don't refetch the same parent transactions.
+    pub fn set_transaction_cache(&mut self, cache: Box<dyn TransactionCache>) {
+        self.tx_cache = cache;
+    }
+
+    /// Install the journal issuance, resolution and pool swap transactions
+    /// are recorded in before broadcast.
+    ///
+    /// Defaults to [`NoIntentJournal`]. The node installs its persistence
+    /// store here so broadcasts interrupted by a crash can be reconciled.
+    pub fn set_intent_journal(&mut self, journal: Box<dyn IntentJournal>) {
+        self.intents = journal;
+    }
+
+    /// Socket timeout for Electrum calls; `None` (the default) waits
+    /// indefinitely.
+    pub fn set_electrum_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        self.chain.set_timeout(timeout);
+    }
+
+    /// Choose how `operation` picks the wallet UTXO that pays its fee.
+    ///
+    /// Every operation defaults to [`CoinSelection::SmallestFirst`].
+    pub fn set_fee_coin_selection(&mut self, operation: FeeOperation, strategy: CoinSelection) {
+        self.fee_coin_selection.insert(operation, strategy);
+    }
+
+    pub fn fee_coin_selection(&self, operation: FeeOperation) -> CoinSelection {
+        self.fee_coin_selection
+            .get(&operation)
+            .copied()
+            .unwrap_or_default()
+    }
+
     pub fn balance(&self) -> Result<HashMap<AssetId, u64>> {
         let balance = self
             .wollet
@@ -300,6 +696,42 @@ impl DeadcatSdk {
             .map_err(|e| Error::Query(e.to_string()))
     }
 
+    /// Unconfidential form of the external address at `index` (next unused
+    /// when `None`).
+    ///
+    /// Shares the confidential address's script, but senders won't blind
+    /// outputs to it, so amounts and assets received here are public.
+    pub fn explicit_address(
+        &self,
+        index: Option<u32>,
+    ) -> Result<(u32, lwk_wollet::elements::Address)> {
+        let result = self.address(index)?;
+        Ok((result.index(), result.address().to_unconfidential()))
+    }
+
+    /// Unspent explicit outputs paid to the explicit addresses at `indices`.
+    ///
+    /// The wallet scan only tracks outputs it can unblind, so explicit
+    /// receipts are looked up directly on the chain backend.
+    pub fn explicit_address_utxos(&self, indices: &[u32]) -> Result<Vec<ExplicitUtxo>> {
+        let mut found = Vec::new();
+        for &index in indices {
+            let (_, address) = self.explicit_address(Some(index))?;
+            for (outpoint, txout) in self.chain.scan_script_utxos(&address.script_pubkey())? {
+                if let (Some(asset), Some(value)) = (txout.asset.explicit(), txout.value.explicit())
+                {
+                    found.push(ExplicitUtxo {
+                        index,
+                        outpoint,
+                        asset,
+                        value,
+                    });
+                }
+            }
+        }
+        Ok(found)
+    }
+
     pub fn utxos(&self) -> Result<Vec<WalletTxOut>> {
         self.wollet.utxos().map_err(|e| Error::Query(e.to_string()))
     }
@@ -310,6 +742,82 @@ impl DeadcatSdk {
             .map_err(|e| Error::Query(e.to_string()))
     }
 
+    /// Break a transaction down into its inputs and outputs.
+    ///
+    /// Wallet-owned inputs and outputs are unblinded with the wallet's keys;
+    /// foreign ones only report explicit amounts. Transactions outside the
+    /// wallet are fetched from the chain backend.
+    pub fn transaction_detail(&self, txid: &Txid) -> Result<TransactionDetail> {
+        let wallet_tx = self.transactions()?.into_iter().find(|tx| tx.txid == *txid);
+        let (tx, height, owned_inputs, owned_outputs) = match wallet_tx {
+            Some(wallet_tx) => (
+                wallet_tx.tx,
+                wallet_tx.height,
+                wallet_tx.inputs,
+                wallet_tx.outputs,
+            ),
+            None => (
+                self.fetch_transaction(txid)?,
+                self.chain.transaction_height(txid)?,
+                Vec::new(),
+                Vec::new(),
+            ),
+        };
+        let tip_height = self.wollet.tip().height();
+        let confirmations = height.map_or(0, |h| tip_height.saturating_sub(h) + 1);
+        let params = self.network.address_params();
+
+        let owned_input = |index: usize| owned_inputs.get(index).and_then(Option::as_ref);
+        let parent_txids: Vec<Txid> = tx
+            .input
+            .iter()
+            .enumerate()
+            .filter(|(index, input)| {
+                owned_input(*index).is_none() && !input.is_coinbase() && !input.is_pegin
+            })
+            .map(|(_, input)| input.previous_output.txid)
+            .collect();
+        let parents: HashMap<Txid, Transaction> = parent_txids
+            .iter()
+            .copied()
+            .zip(self.fetch_transactions(&parent_txids)?)
+            .collect();
+
+        let inputs = tx
+            .input
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                let prevout = parents
+                    .get(&input.previous_output.txid)
+                    .and_then(|parent| parent.output.get(input.previous_output.vout as usize));
+                transaction_detail_entry(input.previous_output, prevout, owned_input(index), params)
+            })
+            .collect();
+        let outputs = tx
+            .output
+            .iter()
+            .enumerate()
+            .map(|(vout, txout)| {
+                transaction_detail_entry(
+                    OutPoint::new(*txid, vout as u32),
+                    Some(txout),
+                    owned_outputs.get(vout).and_then(Option::as_ref),
+                    params,
+                )
+            })
+            .collect();
+
+        Ok(TransactionDetail {
+            txid: *txid,
+            height,
+            confirmations,
+            fee: tx.fee_in(self.policy_asset()),
+            inputs,
+            outputs,
+        })
+    }
+
     pub fn sign_pset(&self, mut pset: PartiallySignedTransaction) -> Result<Transaction> {
         self.wollet
             .add_details(&mut pset)
@@ -322,6 +830,77 @@ impl DeadcatSdk {
             .map_err(|e| Error::Finalize(e.to_string()))
     }
 
+    /// Unwrap an assembled covenant PSET, keeping its blinding secrets when
+    /// audit mode is on.
+    fn record_blinding_audit(&mut self, assembled: AssembledPset) -> PartiallySignedTransaction {
+        #[cfg(any(test, feature = "testing"))]
+        {
+            if self.audit_blinding {
+                self.last_blinding_audit = Some(assembled.audit);
+            }
+        }
+        assembled.pset
+    }
+
+    /// Run `blind_last` on a PSET the SDK built itself (maker orders, LMSR
+    /// pools, market creation, wallet sweeps), keeping the factors it chose
+    /// when audit mode is on.
+    fn blind_with_audit(
+        &mut self,
+        pset: &mut PartiallySignedTransaction,
+        inp_txout_sec: &HashMap<usize, lwk_wollet::elements::TxOutSecrets>,
+    ) -> Result<()> {
+        let secp = secp256k1_zkp::Secp256k1::new();
+        let mut rng = seeded_rng(&mut *self.entropy);
+        let outputs = blind_last_with_factors(pset, &mut rng, &secp, inp_txout_sec)?;
+        #[cfg(any(test, feature = "testing"))]
+        {
+            if self.audit_blinding {
+                self.last_blinding_audit = Some(BlindingAudit {
+                    outputs,
+                    reissuance: None,
+                });
+            }
+        }
+        #[cfg(not(any(test, feature = "testing")))]
+        let _ = outputs;
+        Ok(())
+    }
+
+    /// Sign and broadcast a fully built covenant PSET, or with `dry_run`
+    /// return it base64-encoded instead.
+    ///
+    /// The returned txid is the same either way: wallet inputs are segwit,
+    /// so signing only adds witness data.
+    ///
+    /// With `intent`, the signed transaction is journaled before broadcast.
+    fn submit_covenant_pset(
+        &mut self,
+        assembled: AssembledPset,
+        dry_run: bool,
+        intent: Option<(IntentKind, &PredictionMarketParams)>,
+    ) -> Result<(Txid, Option<String>)> {
+        let mut pset = self.record_blinding_audit(assembled);
+        if !dry_run {
+            let tx = self.sign_pset(pset)?;
+            let txid = match intent {
+                Some((kind, params)) => {
+                    self.broadcast_journaled(&tx, kind, Some(params.market_id().to_string()))?
+                }
+                None => self.broadcast_and_sync(&tx)?,
+            };
+            return Ok((txid, None));
+        }
+        self.wollet
+            .add_details(&mut pset)
+            .map_err(|e| Error::Signer(format!("add_details: {}", e)))?;
+        let txid = pset
+            .extract_tx()
+            .map_err(|e| Error::Pset(format!("extract unsigned tx: {e}")))?
+            .txid();
+        Ok((txid, Some(pset.to_string())))
+    }
+
     pub fn send_lbtc(
         &mut self,
         address_str: &str,
@@ -352,8 +931,384 @@ impl DeadcatSdk {
         Ok((txid, fee_sat))
     }
 
+    /// Send `amount_sat` of L-BTC to a fresh wallet address, leaving the
+    /// wallet with a second spendable L-BTC UTXO to pay fees from.
+    ///
+    /// Only returns once the new output is visible to the wallet (in the
+    /// mempool at least), so an operation retried afterwards can select it.
+    pub fn split_utxo(&mut self, amount_sat: u64, fee_rate: Option<f32>) -> Result<(Txid, u64)> {
+        if amount_sat == 0 {
+            return Err(Error::InsufficientUtxos(
+                "split amount must be non-zero".into(),
+            ));
+        }
+        let address = self.address(None)?.address().to_string();
+        let (txid, fee_sat) = self.send_lbtc(&address, amount_sat, fee_rate)?;
+
+        for attempt in 0..SPLIT_VISIBILITY_ATTEMPTS {
+            if attempt > 0 {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                self.sync()?;
+            }
+            if self
+                .utxos()?
+                .iter()
+                .any(|u| !u.is_spent && u.outpoint.txid == txid)
+            {
+                return Ok((txid, fee_sat));
+            }
+        }
+        Err(Error::Broadcast(format!(
+            "split transaction {txid} was broadcast but the wallet has not seen it yet"
+        )))
+    }
+
+    /// Bump the effective fee rate of an unconfirmed transaction by spending
+    /// one of its wallet-owned L-BTC outputs in a higher-fee child (CPFP).
+    ///
+    /// `fee_rate` is in sat/kvB, like [`send_lbtc`](Self::send_lbtc), and is
+    /// the target rate for the parent and child together. Covenant
+    /// transactions without wallet change (e.g. a resolve whose fee UTXO was
+    /// spent exactly) can't be bumped this way.
+    pub fn bump_fee_cpfp(&mut self, parent_txid: &Txid, fee_rate: f32) -> Result<FeeBumpResult> {
+        if !fee_rate.is_finite() || fee_rate <= 0.0 {
+            return Err(Error::FeeBump(format!("invalid fee rate {fee_rate}")));
+        }
+        self.sync()?;
+
+        let parent_wallet_tx = self
+            .transactions()?
+            .into_iter()
+            .find(|t| t.txid == *parent_txid)
+            .ok_or_else(|| {
+                Error::FeeBump(format!("transaction {parent_txid} not found in wallet"))
+            })?;
+        if parent_wallet_tx.height.is_some() {
+            return Err(Error::FeeBump(format!(
+                "transaction {parent_txid} is already confirmed"
+            )));
+        }
+
+        let policy_asset = self.policy_asset();
+        let change_utxo = self
+            .utxos()?
+            .into_iter()
+            .filter(|u| {
+                u.outpoint.txid == *parent_txid && !u.is_spent && u.unblinded.asset == policy_asset
+            })
+            .max_by_key(|u| u.unblinded.value)
+            .ok_or_else(|| {
+                Error::FeeBump(format!(
+                    "transaction {parent_txid} has no wallet-owned L-BTC output to spend; \
+                     re-run the covenant operation with a higher fee instead"
+                ))
+            })?;
+
+        let parent_tx = self.fetch_transaction(parent_txid)?;
+        let parent_fee: u64 = parent_tx
+            .output
+            .iter()
+            .filter(|o| o.script_pubkey.is_empty())
+            .map(|o| o.value.explicit().unwrap_or(0))
+            .sum();
+        let parent_vsize = parent_tx.weight().div_ceil(4) as u64;
+        let child_fee = cpfp_child_fee(
+            parent_fee,
+            parent_vsize,
+            CPFP_CHILD_VSIZE_ESTIMATE,
+            fee_rate,
+        );
+        if change_utxo.unblinded.value <= child_fee {
+            return Err(Error::FeeBump(format!(
+                "wallet output {} holds {} sats, not enough for a {} sat child fee",
+                change_utxo.outpoint, change_utxo.unblinded.value, child_fee
+            )));
+        }
+
+        let txout = parent_tx
+            .output
+            .get(change_utxo.outpoint.vout as usize)
+            .ok_or_else(|| Error::Query("CPFP UTXO vout out of range".into()))?
+            .clone();
+        let input = wallet_txout_to_unblinded(&change_utxo, &txout);
+        let change_addr: lwk_wollet::elements::Address = self
+            .address(None)?
+            .address()
+            .to_string()
+            .parse()
+            .map_err(|e| Error::Query(format!("bad change address: {}", e)))?;
+
+        let policy_bytes: [u8; 32] = policy_asset.into_inner().to_byte_array();
+        let mut pset = new_pset();
+        add_pset_input(&mut pset, &input);
+        add_pset_output(
+            &mut pset,
+            explicit_txout(
+                &policy_bytes,
+                input.value - child_fee,
+                &change_addr.script_pubkey(),
+            ),
+        );
+        add_pset_output(&mut pset, fee_txout(&policy_bytes, child_fee));
+        self.blind_order_pset(&mut pset, &[input], &[0], &change_addr)?;
+
+        let tx = self.sign_pset(pset)?;
+        let txid = self.broadcast_and_sync(&tx)?;
+
+        Ok(FeeBumpResult {
+            txid,
+            parent_txid: *parent_txid,
+            child_fee_sats: child_fee,
+        })
+    }
+
+    /// Sweep every explicit UTXO held by an external private key into this
+    /// wallet.
+    ///
+    /// The key's P2WPKH, P2SH-P2WPKH and P2PKH addresses are scanned and each
+    /// asset found is sent to a single blinded output at a fresh wallet
+    /// address. The fee (`fee_rate` in sat/kvB) comes out of the swept L-BTC.
+    /// Confidential outputs can't be unblinded without their blinding key, so
+    /// they are skipped.
+    pub fn sweep_private_key(&mut self, key: &SweepKey, fee_rate: f32) -> Result<SweepResult> {
+        let key = &key.0;
+        if !fee_rate.is_finite() || fee_rate <= 0.0 {
+            return Err(Error::Sweep(format!("invalid fee rate {fee_rate}")));
+        }
+
+        let secp = lwk_wollet::elements::bitcoin::secp256k1::Secp256k1::new();
+        let pubkey = key.public_key(&secp);
+        let params = self.network.address_params();
+
+        let mut inputs: Vec<(UnblindedUtxo, SweepScript)> = Vec::new();
+        for &kind in SweepScript::for_key(&pubkey) {
+            let script_pubkey = kind.address(&pubkey, params).script_pubkey();
+            for (outpoint, txout) in self.chain.scan_script_utxos(&script_pubkey)? {
+                let (Some(asset), Some(value)) = (txout.asset.explicit(), txout.value.explicit())
+                else {
+                    log::warn!("skipping confidential output {outpoint} on swept key");
+                    continue;
+                };
+                inputs.push((
+                    UnblindedUtxo {
+                        outpoint,
+                        txout,
+                        asset_id: asset.into_inner().to_byte_array(),
+                        value,
+                        asset_blinding_factor: [0u8; 32],
+                        value_blinding_factor: [0u8; 32],
+                    },
+                    kind,
+                ));
+            }
+        }
+        if inputs.is_empty() {
+            return Err(Error::Sweep(
+                "no spendable explicit outputs found for this key".into(),
+            ));
+        }
+
+        let mut totals: std::collections::BTreeMap<[u8; 32], u64> = Default::default();
+        for (utxo, _) in &inputs {
+            let total = totals.entry(utxo.asset_id).or_default();
+            *total = total
+                .checked_add(utxo.value)
+                .ok_or_else(|| Error::Sweep("swept amount overflows".into()))?;
+        }
+        totals.retain(|_, amount| *amount > 0);
+
+        let policy_bytes: [u8; 32] = self.policy_asset().into_inner().to_byte_array();
+        let vsize = SWEEP_BASE_VSIZE
+            + SWEEP_INPUT_VSIZE * inputs.len() as u64
+            + SWEEP_OUTPUT_VSIZE * totals.len() as u64;
+        let fee = (vsize as f64 * f64::from(fee_rate) / 1000.0).ceil() as u64;
+        let lbtc = totals.get(&policy_bytes).copied().unwrap_or(0);
+        if lbtc <= fee {
+            return Err(Error::Sweep(format!(
+                "key holds {lbtc} sats of L-BTC, not enough for the {fee} sat fee"
+            )));
+        }
+        totals.insert(policy_bytes, lbtc - fee);
+
+        let dest_addr: lwk_wollet::elements::Address = self
+            .address(None)?
+            .address()
+            .to_string()
+            .parse()
+            .map_err(|e| Error::Query(format!("bad destination address: {}", e)))?;
+
+        let mut pset = new_pset();
+        for (utxo, _) in &inputs {
+            add_pset_input(&mut pset, utxo);
+        }
+        let mut swept = Vec::with_capacity(totals.len());
+        for (asset_id, amount) in &totals {
+            add_pset_output(
+                &mut pset,
+                explicit_txout(asset_id, *amount, &dest_addr.script_pubkey()),
+            );
+            let asset = AssetId::from_slice(asset_id)
+                .map_err(|e| Error::Sweep(format!("bad asset id: {e}")))?;
+            swept.push((asset, *amount));
+        }
+        add_pset_output(&mut pset, fee_txout(&policy_bytes, fee));
+
+        let unblinded: Vec<UnblindedUtxo> = inputs.iter().map(|(u, _)| u.clone()).collect();
+        let blind_idx: Vec<usize> = (0..totals.len()).collect();
+        self.blind_order_pset(&mut pset, &unblinded, &blind_idx, &dest_addr)?;
+
+        let mut tx = pset.extract_tx().map_err(|e| Error::Pset(e.to_string()))?;
+        sign_sweep_inputs(&mut tx, &inputs, key, params);
+        let txid = self.broadcast_and_sync(&tx)?;
+
+        Ok(SweepResult {
+            txid,
+            swept,
+            fee_sats: fee,
+        })
+    }
+
+    /// Merge up to `max_inputs` of the wallet's smallest `asset_id` UTXOs into
+    /// one blinded output at a fresh wallet address.
+    ///
+    /// UTXOs worth less than the fee to spend them (`fee_rate` in sat/kvB)
+    /// are left alone. L-BTC pays its own fee; other assets take the fee
+    /// from a separate L-BTC UTXO.
+    pub fn consolidate_utxos(
+        &mut self,
+        asset_id: AssetId,
+        max_inputs: usize,
+        fee_rate: f32,
+    ) -> Result<ConsolidationResult> {
+        if !fee_rate.is_finite() || fee_rate <= 0.0 {
+            return Err(Error::Consolidation(format!("invalid fee rate {fee_rate}")));
+        }
+        if max_inputs < 2 {
+            return Err(Error::Consolidation(
+                "at least two inputs are needed to consolidate".into(),
+            ));
+        }
+        self.sync()?;
+
+        let fee_for = |vsize: u64| (vsize as f64 * f64::from(fee_rate) / 1000.0).ceil() as u64;
+        let input_cost = fee_for(CONSOLIDATION_INPUT_VSIZE);
+        let policy_asset = self.policy_asset();
+        let is_lbtc = asset_id == policy_asset;
+
+        let min_value = if is_lbtc { input_cost + 1 } else { 1 };
+        let candidates = consolidation_candidates(&self.utxos()?, asset_id, min_value, max_inputs);
+        if candidates.len() < 2 {
+            return Err(Error::Consolidation(format!(
+                "wallet has {} economic UTXOs of asset {asset_id}; nothing to consolidate",
+                candidates.len()
+            )));
+        }
+
+        let mut inputs = self.unblind_wallet_utxos(&candidates, "consolidation")?;
+        let total = inputs
+            .iter()
+            .try_fold(0u64, |acc, u| acc.checked_add(u.value))
+            .ok_or_else(|| Error::Consolidation("consolidated amount overflows".into()))?;
+        let dest_addr: lwk_wollet::elements::Address = self
+            .address(None)?
+            .address()
+            .to_string()
+            .parse()
+            .map_err(|e| Error::Query(format!("bad destination address: {}", e)))?;
+        let asset_bytes: [u8; 32] = asset_id.into_inner().to_byte_array();
+        let policy_bytes: [u8; 32] = policy_asset.into_inner().to_byte_array();
+
+        let mut pset = new_pset();
+        let (output_value, fee) = if is_lbtc {
+            let fee = fee_for(
+                SWEEP_BASE_VSIZE
+                    + CONSOLIDATION_INPUT_VSIZE * inputs.len() as u64
+                    + SWEEP_OUTPUT_VSIZE,
+            );
+            if total <= fee {
+                return Err(Error::Consolidation(format!(
+                    "{total} sats of L-BTC can't cover the {fee} sat fee"
+                )));
+            }
+            for input in &inputs {
+                add_pset_input(&mut pset, input);
+            }
+            add_pset_output(
+                &mut pset,
+                explicit_txout(&asset_bytes, total - fee, &dest_addr.script_pubkey()),
+            );
+            (total - fee, fee)
+        } else {
+            let fee = fee_for(
+                SWEEP_BASE_VSIZE
+                    + CONSOLIDATION_INPUT_VSIZE * (inputs.len() as u64 + 1)
+                    + 2 * SWEEP_OUTPUT_VSIZE,
+            );
+            let exclude: Vec<OutPoint> = inputs.iter().map(|u| u.outpoint).collect();
+            let (fee_utxo, _) = self.select_fee_utxo_in(
+                FeeOperation::ConsolidateUtxos,
+                &policy_bytes,
+                fee,
+                &exclude,
+            )?;
+            for input in &inputs {
+                add_pset_input(&mut pset, input);
+            }
+            add_pset_input(&mut pset, &fee_utxo);
+            add_pset_output(
+                &mut pset,
+                explicit_txout(&asset_bytes, total, &dest_addr.script_pubkey()),
+            );
+            if fee_utxo.value > fee {
+                add_pset_output(
+                    &mut pset,
+                    explicit_txout(
+                        &policy_bytes,
+                        fee_utxo.value - fee,
+                        &dest_addr.script_pubkey(),
+                    ),
+                );
+            }
+            inputs.push(fee_utxo);
+            (total, fee)
+        };
+        add_pset_output(&mut pset, fee_txout(&policy_bytes, fee));
+
+        let blind_idx: Vec<usize> = (0..pset.outputs().len() - 1).collect();
+        self.blind_order_pset(&mut pset, &inputs, &blind_idx, &dest_addr)?;
+        let tx = self.sign_pset(pset)?;
+        let txid = self.broadcast_and_sync(&tx)?;
+
+        Ok(ConsolidationResult {
+            txid,
+            asset_id,
+            inputs_consolidated: candidates.len(),
+            output_value,
+            fee_sats: fee,
+        })
+    }
+
+    /// Broadcast `tx` and resync the wallet.
+    ///
+    /// If the node rejects it because an input is already spent, the chain
+    /// is asked which one. [`Error::CovenantConflict`] names it when it is a
+    /// covenant output a competing transaction got to first; a spent wallet
+    /// input stays an [`Error::Broadcast`]. Either way the wallet is left
+    /// unsynced for the caller to refresh.
     pub fn broadcast_and_sync(&mut self, tx: &Transaction) -> Result<Txid> {
-        let txid = self.chain.broadcast(tx)?;
+        let txid = match self.chain.broadcast(tx) {
+            Ok(txid) => txid,
+            Err(Error::Broadcast(msg)) if ElectrumBackend::is_input_conflict_error(&msg) => {
+                return Err(match self.conflicting_input(tx) {
+                    Some((outpoint, false)) => Error::CovenantConflict { outpoint },
+                    Some((outpoint, true)) => {
+                        Error::Broadcast(format!("wallet input {outpoint} already spent: {msg}"))
+                    }
+                    None => Error::Broadcast(msg),
+                });
+            }
+            Err(e) => return Err(e),
+        };
         // Re-sync wallet after broadcast, retrying briefly if the electrum
         // server hasn't indexed the mempool tx yet.
         for attempt in 0..3 {
@@ -368,8 +1323,187 @@ impl DeadcatSdk {
         Ok(txid)
     }
 
+    /// [`broadcast_and_sync`](Self::broadcast_and_sync) with `tx` journaled
+    /// as a pending intent first.
+    ///
+    /// The intent is completed once the broadcast succeeds and abandoned on
+    /// a covenant conflict. Any other failure leaves it pending, since the
+    /// transaction may still have reached the node;
+    /// [`reconcile_intent`](Self::reconcile_intent) settles it from the
+    /// chain.
+    fn broadcast_journaled(
+        &mut self,
+        tx: &Transaction,
+        kind: IntentKind,
+        market_id: Option<String>,
+    ) -> Result<Txid> {
+        self.intents
+            .record_intent(&BroadcastIntent {
+                txid: tx.txid().to_string(),
+                kind,
+                market_id,
+                raw_tx: lwk_wollet::elements::encode::serialize(tx),
+                status: IntentStatus::Pending,
+            })
+            .map_err(Error::Intent)?;
+        self.broadcast_settling_intent(tx)
+    }
+
+    /// Broadcast a journaled transaction and record the outcome.
+    fn broadcast_settling_intent(&mut self, tx: &Transaction) -> Result<Txid> {
+        match self.broadcast_and_sync(tx) {
+            Ok(txid) => {
+                self.intents
+                    .set_intent_status(&txid, IntentStatus::Completed);
+                Ok(txid)
+            }
+            Err(e @ Error::CovenantConflict { .. }) => {
+                self.intents
+                    .set_intent_status(&tx.txid(), IntentStatus::Abandoned);
+                Err(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Settle a pending intent against the chain.
+    ///
+    /// `Completed` when its transaction is in the mempool or a block,
+    /// `Abandoned` when another transaction spent one of its inputs, and
+    /// `Pending` when it can still be broadcast. The journal is updated to
+    /// match.
+    pub fn reconcile_intent(&mut self, intent: &BroadcastIntent) -> Result<IntentStatus> {
+        let tx = intent_transaction(intent)?;
+        let txid = tx.txid();
+        let status = if self.chain.fetch_transaction(&txid).is_ok() {
+            IntentStatus::Completed
+        } else if self.any_input_spent(&tx)? {
+            IntentStatus::Abandoned
+        } else {
+            IntentStatus::Pending
+        };
+        if status != IntentStatus::Pending {
+            self.intents.set_intent_status(&txid, status);
+        }
+        Ok(status)
+    }
+
+    /// Broadcast a pending intent's journaled transaction again.
+    ///
+    /// Reconciles first, so a transaction that already landed is never sent
+    /// twice and one whose inputs were spent elsewhere is not retried.
+    pub fn retry_intent(&mut self, intent: &BroadcastIntent) -> Result<Txid> {
+        let tx = intent_transaction(intent)?;
+        match self.reconcile_intent(intent)? {
+            IntentStatus::Completed => {
+                self.sync()?;
+                Ok(tx.txid())
+            }
+            IntentStatus::Abandoned => Err(Error::Intent(format!(
+                "{}: an input was spent by another transaction",
+                intent.txid
+            ))),
+            IntentStatus::Pending => self.broadcast_settling_intent(&tx),
+        }
+    }
+
+    /// Whether any outpoint `tx` spends is no longer unspent on chain.
+    fn any_input_spent(&self, tx: &Transaction) -> Result<bool> {
+        Ok(self.spent_input(tx)?.is_some())
+    }
+
+    /// The first outpoint `tx` spends that is no longer unspent on chain.
+    fn spent_input(&self, tx: &Transaction) -> Result<Option<OutPoint>> {
+        for input in &tx.input {
+            let prev = input.previous_output;
+            let parent = self.fetch_transaction(&prev.txid)?;
+            let spk = &parent
+                .output
+                .get(prev.vout as usize)
+                .ok_or_else(|| Error::Intent(format!("input {prev} does not exist")))?
+                .script_pubkey;
+            let unspent = self.chain.scan_script_utxos(spk)?;
+            if !unspent.iter().any(|(outpoint, _)| *outpoint == prev) {
+                return Ok(Some(prev));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the input of a rejected broadcast that another transaction
+    /// already spent, and whether it was one of the wallet's own UTXOs.
+    fn conflicting_input(&self, tx: &Transaction) -> Option<(OutPoint, bool)> {
+        match self.spent_input(tx) {
+            Ok(spent) => {
+                let wallet_outpoints: Vec<OutPoint> = self
+                    .utxos()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|u| u.outpoint)
+                    .collect();
+                spent.map(|outpoint| (outpoint, wallet_outpoints.contains(&outpoint)))
+            }
+            Err(e) => {
+                log::warn!("could not find the input of a broadcast conflict: {e}");
+                None
+            }
+        }
+    }
+
     pub fn fetch_transaction(&self, txid: &Txid) -> Result<Transaction> {
-        self.chain.fetch_transaction(txid)
+        if let Some(tx) = self.tx_cache.get_transaction(txid) {
+            return Ok(tx);
+        }
+        let tx = self.chain.fetch_transaction(txid)?;
+        self.tx_cache.put_transaction(&tx);
+        Ok(tx)
+    }
+
+    /// Fetch several transactions, returned in the order of `txids`.
+    ///
+    /// Cache misses are fetched over one connection in batched requests.
+    fn fetch_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        let mut fetched: HashMap<Txid, Transaction> = HashMap::new();
+        let mut misses: Vec<Txid> = Vec::new();
+        for txid in txids {
+            if fetched.contains_key(txid) || misses.contains(txid) {
+                continue;
+            }
+            match self.tx_cache.get_transaction(txid) {
+                Some(tx) => {
+                    fetched.insert(*txid, tx);
+                }
+                None => misses.push(*txid),
+            }
+        }
+
+        for (txid, tx) in misses.iter().zip(self.chain.fetch_transactions(&misses)?) {
+            self.tx_cache.put_transaction(&tx);
+            fetched.insert(*txid, tx);
+        }
+
+        Ok(txids.iter().map(|txid| fetched[txid].clone()).collect())
+    }
+
+    /// Pair wallet UTXOs with their on-chain outputs, fetching parents in bulk.
+    fn unblind_wallet_utxos(
+        &self,
+        utxos: &[WalletTxOut],
+        label: &str,
+    ) -> Result<Vec<UnblindedUtxo>> {
+        let txids: Vec<Txid> = utxos.iter().map(|u| u.outpoint.txid).collect();
+        let txs = self.fetch_transactions(&txids)?;
+        utxos
+            .iter()
+            .zip(&txs)
+            .map(|(utxo, tx)| {
+                let txout = tx
+                    .output
+                    .get(utxo.outpoint.vout as usize)
+                    .ok_or_else(|| Error::Query(format!("{label} UTXO vout out of range")))?;
+                Ok(wallet_txout_to_unblinded(utxo, txout))
+            })
+            .collect()
     }
 
     #[cfg_attr(not(any(test, feature = "testing")), allow(dead_code))]
@@ -381,8 +1515,23 @@ impl DeadcatSdk {
         self.chain.electrum_url()
     }
 
+    /// BIP-32 fingerprint of the wallet signer's master key.
+    pub fn signer_fingerprint(&self) -> String {
+        self.signer.fingerprint().to_string()
+    }
+
+    /// Account xpub the wallet descriptor is built from.
+    pub fn signer_xpub(&self) -> String {
+        self.signer.xpub().to_string()
+    }
+
+    /// Height of the last block the wallet synced to.
+    pub fn wallet_tip_height(&self) -> u32 {
+        self.wollet.tip().height()
+    }
+
     pub fn policy_asset(&self) -> AssetId {
-        self.network.into_lwk().policy_asset()
+        self.policy_asset
     }
 
     pub(crate) fn create_lmsr_pool_bootstrap(
@@ -392,6 +1541,44 @@ impl DeadcatSdk {
         self.sync()?;
         validate_create_lmsr_pool_request(request)?;
 
+        let policy_asset = self.policy_asset();
+        let policy_asset_bytes = policy_asset.into_inner().to_byte_array();
+        let collateral_is_policy_asset =
+            request.pool_params.collateral_asset_id == policy_asset_bytes;
+        let reserve_collateral_target = if collateral_is_policy_asset {
+            request
+                .initial_reserves
+                .r_lbtc
+                .checked_add(request.fee_amount)
+                .ok_or(Error::CollateralOverflow)?
+        } else {
+            request.initial_reserves.r_lbtc
+        };
+        let asset = |bytes: &[u8; 32]| {
+            AssetId::from_slice(bytes).map_err(|e| Error::Query(format!("bad asset id: {e}")))
+        };
+        require_distinct_utxos(
+            &self.utxos()?,
+            &[
+                (
+                    asset(&request.pool_params.yes_asset_id)?,
+                    usize::from(request.initial_reserves.r_yes > 0),
+                ),
+                (
+                    asset(&request.pool_params.no_asset_id)?,
+                    usize::from(request.initial_reserves.r_no > 0),
+                ),
+                (
+                    asset(&request.pool_params.collateral_asset_id)?,
+                    usize::from(reserve_collateral_target > 0),
+                ),
+                (
+                    policy_asset,
+                    usize::from(!collateral_is_policy_asset && request.fee_amount > 0),
+                ),
+            ],
+        )?;
+
         let contract = CompiledLmsrPool::new(request.pool_params)?;
         let change_addr: lwk_wollet::elements::Address = self
             .address(None)?
@@ -415,19 +1602,6 @@ impl DeadcatSdk {
         )?;
         exclude.extend(reserve_no_inputs.iter().map(|utxo| utxo.outpoint));
 
-        let policy_asset = self.policy_asset();
-        let policy_asset_bytes = policy_asset.into_inner().to_byte_array();
-        let collateral_is_policy_asset =
-            request.pool_params.collateral_asset_id == policy_asset_bytes;
-        let reserve_collateral_target = if collateral_is_policy_asset {
-            request
-                .initial_reserves
-                .r_lbtc
-                .checked_add(request.fee_amount)
-                .ok_or(Error::CollateralOverflow)?
-        } else {
-            request.initial_reserves.r_lbtc
-        };
         let reserve_collateral_inputs = self.collect_wallet_utxos_for_asset(
             &request.pool_params.collateral_asset_id,
             reserve_collateral_target,
@@ -438,24 +1612,14 @@ impl DeadcatSdk {
         let fee_inputs = if collateral_is_policy_asset {
             Vec::new()
         } else {
-            select_wallet_utxo_set(
+            let selected = select_wallet_utxo_set(
                 &self.utxos()?,
                 policy_asset,
                 request.fee_amount,
                 &exclude,
                 &policy_asset_bytes,
-            )?
-            .into_iter()
-            .map(|wallet_utxo| {
-                let tx = self.fetch_transaction(&wallet_utxo.outpoint.txid)?;
-                let txout = tx
-                    .output
-                    .get(wallet_utxo.outpoint.vout as usize)
-                    .ok_or_else(|| Error::Query("fee UTXO vout out of range".into()))?
-                    .clone();
-                Ok(wallet_txout_to_unblinded(&wallet_utxo, &txout))
-            })
-            .collect::<Result<Vec<_>>>()?
+            )?;
+            self.unblind_wallet_utxos(&selected, "fee")?
         };
 
         let mut built = build_lmsr_bootstrap_pset(
@@ -844,10 +2008,7 @@ impl DeadcatSdk {
                 inp_txout_sec.insert(pset_idx, txout_secrets_from_unblinded(utxo, asset_id)?);
             }
 
-            let secp = secp256k1_zkp::Secp256k1::new();
-            let mut rng = rand::thread_rng();
-            pset.blind_last(&mut rng, &secp, &inp_txout_sec)
-                .map_err(|e| Error::Blinding(format!("{e:?}")))?;
+            self.blind_with_audit(&mut pset, &inp_txout_sec)?;
         }
 
         // Compute admin signature
@@ -959,11 +2120,25 @@ impl DeadcatSdk {
     }
 
     pub fn boltz_reverse_claim_pubkey_hex(&self) -> Result<String> {
+        let secret = self.boltz_reverse_claim_secret_key()?;
+        let keypair = Keypair::from_secret_key(&secp256k1_zkp::Secp256k1::new(), &secret);
+        Ok(keypair.public_key().to_string())
+    }
+
+    /// Secret key behind [`Self::boltz_reverse_claim_pubkey_hex`], used to
+    /// sign claims of reverse and chain swap lockups.
+    pub fn boltz_reverse_claim_secret_key(&self) -> Result<secp256k1_zkp::SecretKey> {
         let network_path = if self.network.is_mainnet() { 1776 } else { 1 };
-        self.derive_boltz_pubkey_hex(format!("m/84'/{network_path}'/42'/0/0"))
+        self.derive_boltz_secret_key(format!("m/84'/{network_path}'/42'/0/0"))
     }
 
     fn derive_boltz_pubkey_hex(&self, path_str: String) -> Result<String> {
+        let secret = self.derive_boltz_secret_key(path_str)?;
+        let keypair = Keypair::from_secret_key(&secp256k1_zkp::Secp256k1::new(), &secret);
+        Ok(keypair.public_key().to_string())
+    }
+
+    fn derive_boltz_secret_key(&self, path_str: String) -> Result<secp256k1_zkp::SecretKey> {
         let path: lwk_wollet::bitcoin::bip32::DerivationPath = path_str
             .parse()
             .map_err(|e| Error::Signer(format!("{}", e)))?;
@@ -971,11 +2146,8 @@ impl DeadcatSdk {
             .signer
             .derive_xprv(&path)
             .map_err(|e| Error::Signer(format!("{:?}", e)))?;
-        let secp = secp256k1_zkp::Secp256k1::new();
-        let secret = secp256k1_zkp::SecretKey::from_slice(&derived.private_key.secret_bytes())
-            .map_err(|e| Error::Signer(format!("{}", e)))?;
-        let keypair = Keypair::from_secret_key(&secp, &secret);
-        Ok(keypair.public_key().to_string())
+        secp256k1_zkp::SecretKey::from_slice(&derived.private_key.secret_bytes())
+            .map_err(|e| Error::Signer(format!("{}", e)))
     }
 
     // ── On-chain contract creation ───────────────────────────────────────
@@ -1078,11 +2250,7 @@ impl DeadcatSdk {
             inp_txout_sec.insert(0usize, yes_utxo.unblinded);
             inp_txout_sec.insert(1usize, no_utxo.unblinded);
 
-            let secp = lwk_wollet::elements::secp256k1_zkp::Secp256k1::new();
-            let mut rng = thread_rng();
-            sdk_pset
-                .blind_last(&mut rng, &secp, &inp_txout_sec)
-                .map_err(|e| Error::Blinding(format!("{e:?}")))?;
+            self.blind_with_audit(&mut sdk_pset, &inp_txout_sec)?;
         }
 
         let tx = self.sign_pset(sdk_pset)?;
@@ -1102,22 +2270,34 @@ impl DeadcatSdk {
     /// Issue prediction market token pairs.
     ///
     /// Detects whether the market is in Dormant (initial issuance) or Unresolved
-    /// (subsequent issuance) state and builds the appropriate transaction.
+    /// (subsequent issuance) state and builds the appropriate transaction. The
+    /// fee is paid in `fee_asset_id`, defaulting to the policy asset.
     pub fn issue_tokens(
         &mut self,
         params: &PredictionMarketParams,
         anchor: &PredictionMarketAnchor,
         pairs: u64,
         fee_amount: u64,
+        fee_asset_id: Option<[u8; 32]>,
+        dry_run: bool,
     ) -> Result<IssuanceResult> {
+        let span = OpSpan::enter("issue_tokens");
+        span.record("dry_run", &dry_run);
+        span.record("market_id", &params.market_id());
+        span.record("pairs", &pairs);
+        span.record("fee_amount", &fee_amount);
+        let fee_asset_id = self.resolve_fee_asset(fee_asset_id)?;
         let contract = CompiledPredictionMarket::new(*params)?;
 
         // A. Scan market state
         let (current_state, covenant_utxos) = self.scan_market_state(&contract, anchor)?;
+        span.record("state", &format_args!("{current_state:?}"));
 
         // B. Classify and unblind covenant UTXOs
-        let (yes_rt, no_rt, collateral_covenant_utxo) =
+        let (yes_rt, no_rt, collateral_utxos) =
             self.classify_covenant_utxos(&covenant_utxos, params, current_state)?;
+        span.record("yes_rt_utxo", &yes_rt.outpoint);
+        span.record("no_rt_utxo", &no_rt.outpoint);
 
         // C. Compute issuance entropy
         let parsed_anchor = parse_prediction_market_anchor(anchor).map_err(Error::Query)?;
@@ -1130,7 +2310,10 @@ impl DeadcatSdk {
 
         // D. Select wallet UTXOs for collateral + fee
         let (collateral_unblinded, fee_unblinded, change_addr) =
-            self.select_wallet_utxos(params, pairs, fee_amount)?;
+            self.select_wallet_utxos(params, pairs, fee_amount, &fee_asset_id)?;
+        span.record("collateral_utxo", &collateral_unblinded.outpoint);
+        span.record("collateral_value", &collateral_unblinded.value);
+        span.record("fee_utxo", &fee_unblinded.outpoint);
 
         let change_spk = change_addr.script_pubkey();
 
@@ -1139,7 +2322,7 @@ impl DeadcatSdk {
                 wallet_utxo: collateral_unblinded,
             },
             MarketState::Unresolved => {
-                let cov_collateral = collateral_covenant_utxo.ok_or_else(|| {
+                let cov_collateral = collateral_utxos.into_iter().next().ok_or_else(|| {
                     Error::CovenantScan("collateral UTXO not found at covenant".into())
                 })?;
                 CollateralSource::Subsequent {
@@ -1169,6 +2352,7 @@ impl DeadcatSdk {
                 fee_utxo: fee_unblinded,
                 pairs,
                 fee_amount,
+                fee_asset_id,
                 token_destination: change_spk.clone(),
                 change_destination: Some(change_spk.clone()),
                 issuance_entropy,
@@ -1177,19 +2361,24 @@ impl DeadcatSdk {
             &master_blinding_key,
             blinding_pk,
             &change_spk,
+            &mut seeded_rng(&mut *self.entropy),
         )?;
 
-        // I. Sign and finalize
-        let tx = self.sign_pset(assembled)?;
-
-        // J. Broadcast
-        let txid = self.broadcast_and_sync(&tx)?;
+        // I. Sign, finalize and broadcast (or hand back the PSET)
+        let (txid, unsigned_pset) =
+            self.submit_covenant_pset(assembled, dry_run, Some((IntentKind::IssueTokens, params)))?;
+        if dry_run {
+            span.finish_dry_run(&txid);
+        } else {
+            span.finish(&txid);
+        }
 
         Ok(IssuanceResult {
             txid,
             previous_state: current_state,
             new_state: MarketState::Unresolved,
             pairs_issued: pairs,
+            unsigned_pset,
         })
     }
 
@@ -1212,13 +2401,27 @@ impl DeadcatSdk {
         ))
     }
 
-    /// Classify and unblind covenant UTXOs into YES RT, NO RT, and optional collateral.
+    /// Current lifecycle state of a market, for asserting on transitions in
+    /// end-to-end tests without going through [`DeadcatNode`](crate::DeadcatNode).
+    #[cfg(any(test, feature = "testing"))]
+    pub fn market_state(
+        &self,
+        params: &PredictionMarketParams,
+        anchor: &PredictionMarketAnchor,
+    ) -> Result<MarketState> {
+        let contract = CompiledPredictionMarket::new(*params)?;
+        let (state, _utxos) = self.scan_market_state(&contract, anchor)?;
+        Ok(state)
+    }
+
+    /// Classify and unblind covenant UTXOs into YES RT, NO RT, and any
+    /// collateral, largest collateral UTXO first.
     fn classify_covenant_utxos(
         &self,
         covenant_utxos: &[(OutPoint, TxOut)],
         params: &PredictionMarketParams,
         _current_state: MarketState,
-    ) -> Result<(UnblindedUtxo, UnblindedUtxo, Option<UnblindedUtxo>)> {
+    ) -> Result<(UnblindedUtxo, UnblindedUtxo, Vec<UnblindedUtxo>)> {
         let yes_rt_id = AssetId::from_slice(&params.yes_reissuance_token)
             .map_err(|e| Error::Unblind(format!("bad YES reissuance asset: {e}")))?;
         let no_rt_id = AssetId::from_slice(&params.no_reissuance_token)
@@ -1228,7 +2431,7 @@ impl DeadcatSdk {
 
         let mut yes_rt_utxo: Option<UnblindedUtxo> = None;
         let mut no_rt_utxo: Option<UnblindedUtxo> = None;
-        let mut collateral_covenant_utxo: Option<UnblindedUtxo> = None;
+        let mut collateral_utxos: Vec<UnblindedUtxo> = Vec::new();
 
         for (outpoint, txout) in covenant_utxos {
             match txout.asset {
@@ -1244,7 +2447,7 @@ impl DeadcatSdk {
                     };
 
                     if asset == collateral_id {
-                        collateral_covenant_utxo = Some(utxo);
+                        collateral_utxos.push(utxo);
                     } else if asset == yes_rt_id {
                         yes_rt_utxo = Some(utxo);
                     } else if asset == no_rt_id {
@@ -1266,7 +2469,7 @@ impl DeadcatSdk {
                     } else if asset == no_rt_id {
                         no_rt_utxo = Some(utxo);
                     } else if asset == collateral_id {
-                        collateral_covenant_utxo = Some(utxo);
+                        collateral_utxos.push(utxo);
                     }
                 }
                 _ => {}
@@ -1278,57 +2481,63 @@ impl DeadcatSdk {
         let no_rt = no_rt_utxo
             .ok_or_else(|| Error::CovenantScan("NO reissuance token not found".into()))?;
 
-        Ok((yes_rt, no_rt, collateral_covenant_utxo))
+        collateral_utxos.sort_by(|a, b| b.value.cmp(&a.value));
+
+        Ok((yes_rt, no_rt, collateral_utxos))
     }
 
     /// Select wallet UTXOs for collateral and fee, returning unblinded UTXOs and change address.
+    ///
+    /// The fee may be paid in the collateral asset itself (L-BTC collateral),
+    /// in which case it comes from a second UTXO of that asset.
     fn select_wallet_utxos(
         &mut self,
         params: &PredictionMarketParams,
         pairs: u64,
         fee_amount: u64,
+        fee_asset_id: &[u8; 32],
     ) -> Result<(UnblindedUtxo, UnblindedUtxo, lwk_wollet::elements::Address)> {
         self.sync()?;
-        let cpt = params.collateral_per_token;
-        let required_collateral = pairs
-            .checked_mul(2)
-            .and_then(|v| v.checked_mul(cpt))
-            .ok_or(Error::CollateralOverflow)?;
+        let required_collateral = collateral_for_pairs(pairs, params.collateral_per_token)?;
 
-        let policy_asset = self.policy_asset();
+        let collateral_asset = AssetId::from_slice(&params.collateral_asset_id)
+            .map_err(|e| Error::Query(format!("bad collateral asset: {e}")))?;
+        let fee_asset = AssetId::from_slice(fee_asset_id)
+            .map_err(|e| Error::Query(format!("bad fee asset id: {e}")))?;
         let raw_utxos = self.utxos()?;
 
         let collateral_wallet_utxo = raw_utxos
             .iter()
             .filter(|u| {
                 !u.is_spent
-                    && u.unblinded.asset == policy_asset
+                    && u.unblinded.asset == collateral_asset
                     && u.unblinded.value >= required_collateral
             })
             .max_by_key(|u| u.unblinded.value)
             .ok_or_else(|| {
                 Error::InsufficientUtxos(format!(
-                    "need L-BTC UTXO with >= {} sats for collateral",
-                    required_collateral
+                    "need a collateral UTXO with >= {} of asset {}",
+                    required_collateral, collateral_asset
                 ))
             })?
             .clone();
 
-        let fee_wallet_utxo = raw_utxos
+        let fee_candidates: Vec<_> = raw_utxos
             .iter()
             .filter(|u| {
                 !u.is_spent
-                    && u.unblinded.asset == policy_asset
-                    && u.unblinded.value >= fee_amount
+                    && u.unblinded.asset == fee_asset
                     && u.outpoint != collateral_wallet_utxo.outpoint
             })
-            .min_by_key(|u| u.unblinded.value)
-            .ok_or_else(|| {
-                Error::InsufficientUtxos(format!(
-                    "need a second L-BTC UTXO with >= {} sats for the fee \
-                     (send yourself a small amount first to create another UTXO)",
-                    fee_amount
-                ))
+            .collect();
+        let fee_values: Vec<u64> = fee_candidates.iter().map(|u| u.unblinded.value).collect();
+        let fee_wallet_utxo = self
+            .fee_coin_selection(FeeOperation::IssueTokens)
+            .select(&fee_values, fee_amount)
+            .map(|index| fee_candidates[index])
+            .ok_or(Error::SecondUtxoRequired {
+                asset_id: fee_asset,
+                min_amount: fee_amount,
             })?
             .clone();
 
@@ -1371,7 +2580,10 @@ impl DeadcatSdk {
         anchor: &PredictionMarketAnchor,
         pairs_to_burn: u64,
         fee_amount: u64,
+        fee_asset_id: Option<[u8; 32]>,
+        dry_run: bool,
     ) -> Result<CancellationResult> {
+        let fee_asset_id = self.resolve_fee_asset(fee_asset_id)?;
         self.sync()?;
         let contract = CompiledPredictionMarket::new(*params)?;
 
@@ -1380,18 +2592,12 @@ impl DeadcatSdk {
             return Err(Error::NotCancellable(current_state));
         }
 
-        let (yes_rt, no_rt, collateral_covenant_utxo) =
+        let (yes_rt, no_rt, collateral_utxos) =
             self.classify_covenant_utxos(&covenant_utxos, params, current_state)?;
 
-        let collateral = collateral_covenant_utxo
-            .ok_or_else(|| Error::CovenantScan("collateral UTXO not found at covenant".into()))?;
-
-        let cpt = params.collateral_per_token;
-        let refund = pairs_to_burn
-            .checked_mul(2)
-            .and_then(|v| v.checked_mul(cpt))
-            .ok_or(Error::CollateralOverflow)?;
-        let is_full = collateral.value == refund;
+        let refund = collateral_for_pairs(pairs_to_burn, params.collateral_per_token)?;
+        let collateral = select_cancellation_collateral(&collateral_utxos, refund)?;
+        let is_full = collateral_total(&collateral)? == refund;
 
         // Find YES and NO token UTXOs in wallet
         let (yes_token_utxos, no_token_utxos) = self.find_token_utxos_for_burn(
@@ -1400,11 +2606,12 @@ impl DeadcatSdk {
             pairs_to_burn,
         )?;
 
-        let (fee_unblinded, change_addr) = self.select_fee_utxo(fee_amount)?;
+        let (fee_unblinded, change_addr) =
+            self.select_fee_utxo(FeeOperation::CancelTokens, &fee_asset_id, fee_amount)?;
         let change_spk = change_addr.script_pubkey();
 
         let cancellation_params = CancellationParams {
-            collateral_utxo: collateral,
+            collateral_utxos: collateral,
             yes_reissuance_utxo: if is_full { Some(yes_rt.clone()) } else { None },
             no_reissuance_utxo: if is_full { Some(no_rt.clone()) } else { None },
             yes_token_utxos,
@@ -1412,6 +2619,7 @@ impl DeadcatSdk {
             fee_utxo: fee_unblinded,
             pairs_burned: pairs_to_burn,
             fee_amount,
+            fee_asset_id,
             refund_destination: change_spk.clone(),
             fee_change_destination: Some(change_spk.clone()),
             token_change_destination: Some(change_spk.clone()),
@@ -1432,10 +2640,10 @@ impl DeadcatSdk {
             &master_blinding_key,
             blinding_pk,
             &change_spk,
+            &mut seeded_rng(&mut *self.entropy),
         )?;
 
-        let tx = self.sign_pset(assembled)?;
-        let txid = self.broadcast_and_sync(&tx)?;
+        let (txid, unsigned_pset) = self.submit_covenant_pset(assembled, dry_run, None)?;
 
         let new_state = if is_full {
             MarketState::Dormant
@@ -1449,6 +2657,7 @@ impl DeadcatSdk {
             new_state,
             pairs_burned: pairs_to_burn,
             is_full_cancellation: is_full,
+            unsigned_pset,
         })
     }
 
@@ -1464,22 +2673,35 @@ impl DeadcatSdk {
         outcome_yes: bool,
         oracle_signature: [u8; 64],
         fee_amount: u64,
+        dry_run: bool,
     ) -> Result<ResolutionResult> {
+        let span = OpSpan::enter("resolve_market");
+        span.record("dry_run", &dry_run);
+        span.record("market_id", &params.market_id());
+        span.record("outcome_yes", &outcome_yes);
+        span.record("fee_amount", &fee_amount);
         self.sync()?;
         let contract = CompiledPredictionMarket::new(*params)?;
 
         let (current_state, covenant_utxos) = self.scan_market_state(&contract, anchor)?;
+        span.record("state", &format_args!("{current_state:?}"));
         if current_state != MarketState::Unresolved {
             return Err(Error::NotResolvable(current_state));
         }
 
-        let (yes_rt, no_rt, collateral_covenant_utxo) =
+        let (yes_rt, no_rt, collateral_utxos) =
             self.classify_covenant_utxos(&covenant_utxos, params, current_state)?;
 
-        let collateral = collateral_covenant_utxo
+        let collateral = collateral_utxos
+            .into_iter()
+            .next()
             .ok_or_else(|| Error::CovenantScan("collateral UTXO not found at covenant".into()))?;
+        span.record("collateral_utxo", &collateral.outpoint);
+        span.record("collateral_value", &collateral.value);
 
-        let (fee_unblinded, change_addr) = self.select_fee_utxo(fee_amount)?;
+        let (fee_unblinded, change_addr) =
+            self.select_fee_utxo_excluding(FeeOperation::ResolveMarket, fee_amount, &[])?;
+        span.record("fee_utxo", &fee_unblinded.outpoint);
         let change_spk = change_addr.script_pubkey();
 
         let resolve_params = OracleResolveParams {
@@ -1511,9 +2733,19 @@ impl DeadcatSdk {
             &change_spk,
             &yes_rt,
             &no_rt,
+            &mut seeded_rng(&mut *self.entropy),
         )?;
 
-        let txid = self.broadcast_and_sync(&self.sign_pset(assembled)?)?;
+        let (txid, unsigned_pset) = self.submit_covenant_pset(
+            assembled,
+            dry_run,
+            Some((IntentKind::ResolveMarket, params)),
+        )?;
+        if dry_run {
+            span.finish_dry_run(&txid);
+        } else {
+            span.finish(&txid);
+        }
 
         let new_state = if outcome_yes {
             MarketState::ResolvedYes
@@ -1526,6 +2758,7 @@ impl DeadcatSdk {
             previous_state: current_state,
             new_state,
             outcome_yes,
+            unsigned_pset,
         })
     }
 
@@ -1540,6 +2773,7 @@ impl DeadcatSdk {
         anchor: &PredictionMarketAnchor,
         tokens_to_burn: u64,
         fee_amount: u64,
+        dry_run: bool,
     ) -> Result<RedemptionResult> {
         self.sync()?;
         let contract = CompiledPredictionMarket::new(*params)?;
@@ -1553,47 +2787,55 @@ impl DeadcatSdk {
             .winning_token_asset(params)
             .ok_or(Error::InvalidState)?;
 
-        let collateral = Self::find_collateral_utxo(&covenant_utxos, params)?;
-
-        let cpt = params.collateral_per_token;
-        let payout = tokens_to_burn
-            .checked_mul(2)
-            .and_then(|v| v.checked_mul(cpt))
-            .ok_or(Error::CollateralOverflow)?;
+        let per_token = payout_per_token(params.collateral_per_token, false)?;
+        let payout = payout_for_tokens(tokens_to_burn, params.collateral_per_token, false)?;
+        let draws = plan_collateral_draws(
+            &Self::collect_collateral_utxos(&covenant_utxos, params)?,
+            tokens_to_burn,
+            per_token,
+        )?;
 
-        // Find winning token UTXOs in wallet
-        let token_utxos = self.find_single_token_utxos(&winning_asset, tokens_to_burn)?;
+        let (txid, extra_txids, unsigned_pset) =
+            self.run_collateral_draws(draws, dry_run, |sdk, collateral, tokens| {
+                let token_utxos = sdk.find_single_token_utxos(&winning_asset, tokens)?;
 
-        let (fee_unblinded, change_addr) = self.select_fee_utxo(fee_amount)?;
-        let change_spk = change_addr.script_pubkey();
+                let (fee_unblinded, change_addr) =
+                    sdk.select_fee_utxo_excluding(FeeOperation::RedeemTokens, fee_amount, &[])?;
+                let change_spk = change_addr.script_pubkey();
 
-        let redemption_params = PostResolutionRedemptionParams {
-            collateral_utxo: collateral,
-            token_utxos,
-            fee_utxo: fee_unblinded,
-            tokens_burned: tokens_to_burn,
-            resolved_state: current_state,
-            fee_amount,
-            payout_destination: change_spk.clone(),
-            fee_change_destination: Some(change_spk.clone()),
-            token_change_destination: Some(change_spk),
-        };
+                let redemption_params = PostResolutionRedemptionParams {
+                    collateral_utxos: collateral,
+                    token_utxos,
+                    fee_utxo: fee_unblinded,
+                    tokens_burned: tokens,
+                    resolved_state: current_state,
+                    fee_amount,
+                    payout_destination: change_spk.clone(),
+                    fee_change_destination: Some(change_spk.clone()),
+                    token_change_destination: Some(change_spk),
+                };
 
-        let blinding_pk = change_addr
-            .blinding_pubkey
-            .ok_or_else(|| Error::Blinding("change address has no blinding key".to_string()))?;
+                let blinding_pk = change_addr.blinding_pubkey.ok_or_else(|| {
+                    Error::Blinding("change address has no blinding key".to_string())
+                })?;
 
-        let assembled =
-            assemble_post_resolution_redemption(&contract, &redemption_params, blinding_pk)?;
+                let assembled = assemble_post_resolution_redemption(
+                    &contract,
+                    &redemption_params,
+                    blinding_pk,
+                    &mut seeded_rng(&mut *sdk.entropy),
+                )?;
 
-        let tx = self.sign_pset(assembled)?;
-        let txid = self.broadcast_and_sync(&tx)?;
+                sdk.submit_covenant_pset(assembled, dry_run, None)
+            })?;
 
         Ok(RedemptionResult {
             txid,
+            extra_txids,
             previous_state: current_state,
             tokens_redeemed: tokens_to_burn,
             payout_sats: payout,
+            unsigned_pset,
         })
     }
 
@@ -1614,13 +2856,16 @@ impl DeadcatSdk {
             return Err(Error::NotRedeemable(current_state));
         }
 
-        let (yes_rt, no_rt, collateral_covenant_utxo) =
+        let (yes_rt, no_rt, collateral_utxos) =
             self.classify_covenant_utxos(&covenant_utxos, params, current_state)?;
 
-        let collateral = collateral_covenant_utxo
+        let collateral = collateral_utxos
+            .into_iter()
+            .next()
             .ok_or_else(|| Error::CovenantScan("collateral UTXO not found at covenant".into()))?;
 
-        let (fee_unblinded, change_addr) = self.select_fee_utxo(fee_amount)?;
+        let (fee_unblinded, change_addr) =
+            self.select_fee_utxo_excluding(FeeOperation::RedeemExpired, fee_amount, &[])?;
         let change_spk = change_addr.script_pubkey();
 
         let expire_params = ExpireTransitionParams {
@@ -1650,15 +2895,19 @@ impl DeadcatSdk {
             &change_spk,
             &yes_rt,
             &no_rt,
+            &mut seeded_rng(&mut *self.entropy),
         )?;
 
-        self.broadcast_and_sync(&self.sign_pset(assembled)?)
+        let pset = self.record_blinding_audit(assembled);
+        self.broadcast_and_sync(&self.sign_pset(pset)?)
     }
 
     /// Redeem tokens after market expiry (no oracle resolution).
     ///
     /// Burns tokens and reclaims 1x collateral_per_token per token. If the market
     /// is still Unresolved, this auto-finalizes Unresolved -> Expired first.
+    /// That finalization has to broadcast, so a `dry_run` requires a market
+    /// that is already Expired.
     pub fn redeem_expired(
         &mut self,
         params: &PredictionMarketParams,
@@ -1666,6 +2915,7 @@ impl DeadcatSdk {
         token_asset: [u8; 32],
         tokens_to_burn: u64,
         fee_amount: u64,
+        dry_run: bool,
     ) -> Result<RedemptionResult> {
         self.sync()?;
         let contract = CompiledPredictionMarket::new(*params)?;
@@ -1673,6 +2923,9 @@ impl DeadcatSdk {
         let (mut current_state, mut covenant_utxos) = self.scan_market_state(&contract, anchor)?;
         let mut finalize_txid: Option<Txid> = None;
 
+        if current_state == MarketState::Unresolved && dry_run {
+            return Err(Error::NotRedeemable(current_state));
+        }
         if current_state == MarketState::Unresolved {
             let txid = self.expire_market(params, anchor, fee_amount)?;
             finalize_txid = Some(txid);
@@ -1687,45 +2940,59 @@ impl DeadcatSdk {
         }
 
         let redemption = (|| -> Result<RedemptionResult> {
-            let collateral = Self::find_collateral_utxo(&covenant_utxos, params)?;
-
-            let cpt = params.collateral_per_token;
-            let payout = tokens_to_burn
-                .checked_mul(cpt)
-                .ok_or(Error::CollateralOverflow)?;
-
-            let token_utxos = self.find_single_token_utxos(&token_asset, tokens_to_burn)?;
-
-            let (fee_unblinded, change_addr) = self.select_fee_utxo(fee_amount)?;
-            let change_spk = change_addr.script_pubkey();
+            let per_token = payout_per_token(params.collateral_per_token, true)?;
+            let payout = payout_for_tokens(tokens_to_burn, params.collateral_per_token, true)?;
+            let draws = plan_collateral_draws(
+                &Self::collect_collateral_utxos(&covenant_utxos, params)?,
+                tokens_to_burn,
+                per_token,
+            )?;
 
-            let expiry_params = ExpiryRedemptionParams {
-                collateral_utxo: collateral,
-                token_utxos,
-                fee_utxo: fee_unblinded,
-                tokens_burned: tokens_to_burn,
-                burn_token_asset: token_asset,
-                fee_amount,
-                payout_destination: change_spk.clone(),
-                fee_change_destination: Some(change_spk.clone()),
-                token_change_destination: Some(change_spk),
-                lock_time: params.expiry_time,
-            };
+            let (txid, extra_txids, unsigned_pset) =
+                self.run_collateral_draws(draws, dry_run, |sdk, collateral, tokens| {
+                    let token_utxos = sdk.find_single_token_utxos(&token_asset, tokens)?;
+
+                    let (fee_unblinded, change_addr) = sdk.select_fee_utxo_excluding(
+                        FeeOperation::RedeemExpired,
+                        fee_amount,
+                        &[],
+                    )?;
+                    let change_spk = change_addr.script_pubkey();
+
+                    let expiry_params = ExpiryRedemptionParams {
+                        collateral_utxos: collateral,
+                        token_utxos,
+                        fee_utxo: fee_unblinded,
+                        tokens_burned: tokens,
+                        burn_token_asset: token_asset,
+                        fee_amount,
+                        payout_destination: change_spk.clone(),
+                        fee_change_destination: Some(change_spk.clone()),
+                        token_change_destination: Some(change_spk),
+                        lock_time: params.expiry_time,
+                    };
 
-            let blinding_pk = change_addr
-                .blinding_pubkey
-                .ok_or_else(|| Error::Blinding("change address has no blinding key".to_string()))?;
+                    let blinding_pk = change_addr.blinding_pubkey.ok_or_else(|| {
+                        Error::Blinding("change address has no blinding key".to_string())
+                    })?;
 
-            let assembled = assemble_expiry_redemption(&contract, &expiry_params, blinding_pk)?;
+                    let assembled = assemble_expiry_redemption(
+                        &contract,
+                        &expiry_params,
+                        blinding_pk,
+                        &mut seeded_rng(&mut *sdk.entropy),
+                    )?;
 
-            let tx = self.sign_pset(assembled)?;
-            let txid = self.broadcast_and_sync(&tx)?;
+                    sdk.submit_covenant_pset(assembled, dry_run, None)
+                })?;
 
             Ok(RedemptionResult {
                 txid,
+                extra_txids,
                 previous_state: current_state,
                 tokens_redeemed: tokens_to_burn,
                 payout_sats: payout,
+                unsigned_pset,
             })
         })();
 
@@ -1744,6 +3011,147 @@ impl DeadcatSdk {
         }
     }
 
+    /// Redeem all YES and NO tokens the wallet holds for an expired market.
+    ///
+    /// Refuses to start until the chain tip has reached `expiry_time`. Each
+    /// token type is burned in its own [`redeem_expired`](Self::redeem_expired)
+    /// call, so an unresolved market is finalized by the first one.
+    pub fn redeem_expired_all(
+        &mut self,
+        params: &PredictionMarketParams,
+        anchor: &PredictionMarketAnchor,
+        fee_amount: u64,
+        dry_run: bool,
+    ) -> Result<ExpiredRedemptionSweep> {
+        self.sync()?;
+        let tip_height = self.chain.best_block_height()?;
+        if tip_height < params.expiry_time {
+            return Err(Error::MarketNotExpired {
+                expiry_height: params.expiry_time,
+                tip_height,
+            });
+        }
+
+        let balance = self.balance()?;
+        let mut holdings = Vec::new();
+        for token_asset in [params.yes_token_asset, params.no_token_asset] {
+            let id = AssetId::from_slice(&token_asset)
+                .map_err(|e| Error::Query(format!("bad token asset: {e}")))?;
+            let amount = balance.get(&id).copied().unwrap_or(0);
+            if amount > 0 {
+                holdings.push((token_asset, amount));
+            }
+        }
+        if holdings.is_empty() {
+            return Err(Error::NoTokensToRedeem);
+        }
+
+        let mut sweep = ExpiredRedemptionSweep {
+            redemptions: Vec::new(),
+            total_tokens: 0,
+            total_payout_sats: 0,
+        };
+        for (token_asset, amount) in holdings {
+            let result =
+                match self.redeem_expired(params, anchor, token_asset, amount, fee_amount, dry_run)
+                {
+                    Ok(result) => result,
+                    Err(e) if dry_run || sweep.redemptions.is_empty() => return Err(e),
+                    Err(e) => {
+                        return Err(Error::PartialExpiredRedemption {
+                            completed_txids: sweep
+                                .redemptions
+                                .iter()
+                                .flat_map(|r| std::iter::once(r.txid).chain(r.extra_txids.clone()))
+                                .map(|txid| txid.to_string())
+                                .collect(),
+                            reason: e.to_string(),
+                        });
+                    }
+                };
+            sweep.total_tokens = sweep.total_tokens.saturating_add(result.tokens_redeemed);
+            sweep.total_payout_sats = sweep.total_payout_sats.saturating_add(result.payout_sats);
+            sweep.redemptions.push(result);
+        }
+        Ok(sweep)
+    }
+
+    /// Preview redeeming `tokens`: the payout, and how far the wallet's tokens
+    /// and the covenant's collateral fall short of it.
+    ///
+    /// Resolved markets redeem the winning token. Expired markets, and
+    /// unresolved ones past their expiry height, redeem `token_asset`, which
+    /// is then required; an unresolved market would also pay a fee to expire.
+    pub fn preview_redemption(
+        &mut self,
+        params: &PredictionMarketParams,
+        anchor: &PredictionMarketAnchor,
+        tokens: u64,
+        token_asset: Option<[u8; 32]>,
+    ) -> Result<RedemptionPreview> {
+        self.sync()?;
+        let contract = CompiledPredictionMarket::new(*params)?;
+        let (current_state, covenant_utxos) = self.scan_market_state(&contract, anchor)?;
+
+        let is_expiry = match current_state {
+            MarketState::ResolvedYes | MarketState::ResolvedNo => false,
+            MarketState::Expired => true,
+            MarketState::Unresolved => {
+                let tip_height = self.chain.best_block_height()?;
+                if tip_height < params.expiry_time {
+                    return Err(Error::MarketNotExpired {
+                        expiry_height: params.expiry_time,
+                        tip_height,
+                    });
+                }
+                true
+            }
+            MarketState::Dormant => return Err(Error::NotRedeemable(current_state)),
+        };
+
+        let winning_asset = if is_expiry {
+            let asset = token_asset.ok_or_else(|| {
+                Error::Query("expiry redemption needs the token asset to burn".into())
+            })?;
+            if asset != params.yes_token_asset && asset != params.no_token_asset {
+                return Err(Error::Query(
+                    "token asset is not this market's YES or NO".into(),
+                ));
+            }
+            asset
+        } else {
+            current_state
+                .winning_token_asset(params)
+                .ok_or(Error::InvalidState)?
+        };
+        let payout_sats = payout_for_tokens(tokens, params.collateral_per_token, is_expiry)?;
+
+        let token_id = AssetId::from_slice(&winning_asset)
+            .map_err(|e| Error::Query(format!("bad token asset: {e}")))?;
+        let tokens_held = self.balance()?.get(&token_id).copied().unwrap_or(0);
+
+        // Unresolved collateral may still be confidential; the terminal
+        // states hold it explicitly.
+        let collateral_utxos = if current_state == MarketState::Unresolved {
+            self.classify_covenant_utxos(&covenant_utxos, params, current_state)?
+                .2
+        } else {
+            Self::collect_collateral_utxos(&covenant_utxos, params)?
+        };
+        let covenant_collateral = collateral_utxos
+            .iter()
+            .fold(0u64, |total, utxo| total.saturating_add(utxo.value));
+
+        Ok(RedemptionPreview {
+            current_state,
+            is_expiry,
+            winning_asset,
+            payout_sats,
+            token_shortfall: tokens.saturating_sub(tokens_held),
+            collateral_shortfall_sats: payout_sats.saturating_sub(covenant_collateral),
+        })
+    }
+
     // ── Maker order key derivation ─────────────────────────────────────
 
     /// Derive a secp256k1 keypair for maker orders at the given index.
@@ -1765,6 +3173,13 @@ impl DeadcatSdk {
         Ok(Keypair::from_secret_key(&secp, &secret))
     }
 
+    /// Get the x-only maker base pubkey for maker orders at the given index.
+    pub fn maker_base_pubkey(&self, order_index: u32) -> Result<[u8; 32]> {
+        let keypair = self.derive_maker_keypair(order_index)?;
+        let (xonly, _parity) = keypair.x_only_public_key();
+        Ok(xonly.serialize())
+    }
+
     // ── Pool admin key derivation ────────────────────────────────────
 
     /// Derive a secp256k1 keypair for LMSR pool admin at the given index.
@@ -1793,6 +3208,26 @@ impl DeadcatSdk {
         Ok(xonly.serialize())
     }
 
+    // ── Oracle key derivation ──────────────────────────────────────────
+
+    /// Secret key this wallet signs market attestations with as an oracle.
+    ///
+    /// Path: `m/86'/{network}'/3'/0/0` where `3'` = oracle account. Deriving
+    /// it keeps the key off disk and recoverable from the seed.
+    pub fn oracle_secret_key(&self) -> Result<secp256k1_zkp::SecretKey> {
+        let network_path = if self.network.is_mainnet() { 1776 } else { 1 };
+        let path: lwk_wollet::bitcoin::bip32::DerivationPath =
+            format!("m/86'/{network_path}'/3'/0/0")
+                .parse()
+                .map_err(|e| Error::Signer(format!("{}", e)))?;
+        let derived = self
+            .signer
+            .derive_xprv(&path)
+            .map_err(|e| Error::Signer(format!("{:?}", e)))?;
+        secp256k1_zkp::SecretKey::from_slice(&derived.private_key.secret_bytes())
+            .map_err(|e| Error::Signer(format!("{}", e)))
+    }
+
     fn collect_wallet_utxos_for_asset(
         &self,
         asset_id: &[u8; 32],
@@ -1805,18 +3240,7 @@ impl DeadcatSdk {
         let selected =
             select_wallet_utxo_set(&raw_utxos, target_asset, required_amount, exclude, asset_id)?;
 
-        selected
-            .into_iter()
-            .map(|wallet_utxo| {
-                let tx = self.fetch_transaction(&wallet_utxo.outpoint.txid)?;
-                let txout = tx
-                    .output
-                    .get(wallet_utxo.outpoint.vout as usize)
-                    .ok_or_else(|| Error::Query("funding UTXO vout out of range".into()))?
-                    .clone();
-                Ok(wallet_txout_to_unblinded(&wallet_utxo, &txout))
-            })
-            .collect()
+        self.unblind_wallet_utxos(&selected, "funding")
     }
 
     /// Select a wallet UTXO for a specific asset with enough value, excluding certain outpoints.
@@ -1859,27 +3283,47 @@ impl DeadcatSdk {
         Ok(wallet_txout_to_unblinded(&wallet_utxo, &txout))
     }
 
-    /// Select a fee UTXO excluding certain outpoints, returning it with a change address.
+    /// Select an L-BTC fee UTXO excluding certain outpoints, returning it with a change address.
     fn select_fee_utxo_excluding(
         &mut self,
+        operation: FeeOperation,
         fee_amount: u64,
         exclude: &[OutPoint],
     ) -> Result<(UnblindedUtxo, lwk_wollet::elements::Address)> {
-        let policy_asset = self.policy_asset();
+        let policy_bytes: [u8; 32] = self.policy_asset().into_inner().to_byte_array();
+        self.select_fee_utxo_in(operation, &policy_bytes, fee_amount, exclude)
+    }
+
+    /// Select a UTXO of `fee_asset_id` able to pay `fee_amount`, excluding
+    /// certain outpoints, returning it with a change address.
+    ///
+    /// The UTXO is picked with `operation`'s [`CoinSelection`].
+    fn select_fee_utxo_in(
+        &mut self,
+        operation: FeeOperation,
+        fee_asset_id: &[u8; 32],
+        fee_amount: u64,
+        exclude: &[OutPoint],
+    ) -> Result<(UnblindedUtxo, lwk_wollet::elements::Address)> {
+        let fee_asset = AssetId::from_slice(fee_asset_id)
+            .map_err(|e| Error::Query(format!("bad fee asset id: {e}")))?;
         let raw_utxos = self.utxos()?;
 
-        let fee_wallet_utxo = raw_utxos
+        let candidates: Vec<_> = raw_utxos
             .iter()
             .filter(|u| {
-                !u.is_spent
-                    && u.unblinded.asset == policy_asset
-                    && u.unblinded.value >= fee_amount
-                    && !exclude.contains(&u.outpoint)
+                !u.is_spent && u.unblinded.asset == fee_asset && !exclude.contains(&u.outpoint)
             })
-            .min_by_key(|u| u.unblinded.value)
+            .collect();
+        let values: Vec<u64> = candidates.iter().map(|u| u.unblinded.value).collect();
+        let fee_wallet_utxo = self
+            .fee_coin_selection(operation)
+            .select(&values, fee_amount)
+            .map(|index| candidates[index])
             .ok_or_else(|| {
                 Error::InsufficientUtxos(format!(
-                    "need an L-BTC UTXO with >= {} sats for the fee (excluding {} outpoints)",
+                    "need a UTXO of asset {} with >= {} for the fee (excluding {} outpoints)",
+                    fee_asset,
                     fee_amount,
                     exclude.len()
                 ))
@@ -1913,7 +3357,7 @@ impl DeadcatSdk {
     /// `first_wallet_output`: index of the first output to blind (all subsequent
     ///   non-fee outputs are also blinded).
     fn blind_order_pset(
-        &self,
+        &mut self,
         pset: &mut PartiallySignedTransaction,
         wallet_inputs: &[UnblindedUtxo],
         blind_output_indices: &[usize],
@@ -1943,12 +3387,7 @@ impl DeadcatSdk {
             inp_txout_sec.insert(idx, txout_secrets_from_unblinded(utxo, asset_id)?);
         }
 
-        let secp = secp256k1_zkp::Secp256k1::new();
-        let mut rng = thread_rng();
-        pset.blind_last(&mut rng, &secp, &inp_txout_sec)
-            .map_err(|e| Error::Blinding(format!("{e:?}")))?;
-
-        Ok(())
+        self.blind_with_audit(pset, &inp_txout_sec)
     }
 
     // ── Limit order methods ─────────────────────────────────────────────
@@ -1967,6 +3406,13 @@ impl DeadcatSdk {
         order_index: u32,
         fee_amount: u64,
     ) -> Result<CreateOrderResult> {
+        validate_order_amounts(
+            price,
+            order_amount,
+            direction,
+            min_fill_lots,
+            min_remainder_lots,
+        )?;
         self.sync()?;
 
         // 1. Derive maker keypair
@@ -1976,7 +3422,7 @@ impl DeadcatSdk {
 
         // 2. Generate random order nonce
         let mut order_nonce = [0u8; 32];
-        thread_rng().fill_bytes(&mut order_nonce);
+        self.entropy.fill_bytes(&mut order_nonce);
 
         // 3. Build MakerOrderParams
         let (params, _p_order) = MakerOrderParams::new(
@@ -2020,7 +3466,11 @@ impl DeadcatSdk {
                 .map_err(|e| Error::Query(format!("bad change address: {}", e)))?;
             (funding_utxo.clone(), change_addr)
         } else {
-            self.select_fee_utxo_excluding(fee_amount, &[funding_utxo.outpoint])?
+            self.select_fee_utxo_excluding(
+                FeeOperation::CreateLimitOrder,
+                fee_amount,
+                &[funding_utxo.outpoint],
+            )?
         };
         let change_spk = change_addr.script_pubkey();
 
@@ -2069,81 +3519,362 @@ impl DeadcatSdk {
         })
     }
 
-    /// Cancel a limit order by script-path spending the covenant UTXO.
+    /// Cancel a limit order by script-path spending the covenant UTXO.
+    ///
+    /// Uses the Simplicity cancel path (Right branch) with a BIP-340 signature
+    /// over SHA256(prev_outpoint) to authorize reclaiming funds.
+    ///
+    /// If a taker's fill of the order is already in the mempool, this fails
+    /// with [`Error::OrderBeingFilled`] unless `outbid_pending_fill` is set and
+    /// the fill signals RBF, in which case the cancel is built to replace it,
+    /// raising the fee as far as replacement requires.
+    pub fn cancel_limit_order(
+        &mut self,
+        params: &MakerOrderParams,
+        maker_base_pubkey: [u8; 32],
+        order_index: u32,
+        fee_amount: u64,
+        outbid_pending_fill: bool,
+    ) -> Result<CancelOrderResult> {
+        self.sync()?;
+
+        // 1. Derive maker keypair
+        let maker_keypair = self.derive_maker_keypair(order_index)?;
+
+        // 2. Compile the contract
+        let contract = CompiledMakerOrder::new(*params)?;
+
+        // 3. Compute covenant SPK and scan for order UTXO
+        let covenant_spk = contract.script_pubkey(&maker_base_pubkey);
+        let covenant_utxos = self.scan_covenant_utxos(&covenant_spk)?;
+        let mut fee_amount = fee_amount;
+        let mut replaced_fill_txid = None;
+        let (order_outpoint, order_txout) = match covenant_utxos.into_iter().next() {
+            Some(utxo) => utxo,
+            None => {
+                let (outpoint, txout, fill) =
+                    self.pending_covenant_spend(&covenant_spk)?.ok_or_else(|| {
+                        Error::MakerOrder("no UTXO found at order covenant address".into())
+                    })?;
+                let fill_txid = fill.txid();
+                if !outbid_pending_fill || !fill.input.iter().any(|i| i.sequence.is_rbf()) {
+                    return Err(Error::OrderBeingFilled {
+                        outpoint,
+                        fill_txid,
+                    });
+                }
+                let fill_fee: u64 = fill
+                    .output
+                    .iter()
+                    .filter(|o| o.script_pubkey.is_empty())
+                    .map(|o| o.value.explicit().unwrap_or(0))
+                    .sum();
+                fee_amount = fee_amount.max(rbf_replacement_fee(fill_fee, CANCEL_VSIZE_ESTIMATE));
+                replaced_fill_txid = Some(fill_txid);
+                (outpoint, txout)
+            }
+        };
+
+        // 4. Convert to UnblindedUtxo (explicit asset, zeroed blinding factors)
+        let order_value = order_txout.value.explicit().unwrap_or(0);
+        let order_asset = match params.direction {
+            OrderDirection::SellBase => params.base_asset_id,
+            OrderDirection::SellQuote => params.quote_asset_id,
+        };
+        let order_utxo = UnblindedUtxo {
+            outpoint: order_outpoint,
+            txout: order_txout,
+            asset_id: order_asset,
+            value: order_value,
+            asset_blinding_factor: [0u8; 32],
+            value_blinding_factor: [0u8; 32],
+        };
+
+        // 5. Select fee UTXO
+        let (fee_utxo, change_addr) = self.select_fee_utxo_excluding(
+            FeeOperation::CancelLimitOrder,
+            fee_amount,
+            &[order_outpoint],
+        )?;
+        let change_spk = change_addr.script_pubkey();
+        let policy_bytes: [u8; 32] = self.policy_asset().into_inner().to_byte_array();
+
+        // 6. Build cancel PSET
+        let input_utxos = [order_utxo.clone(), fee_utxo.clone()];
+
+        let cancel_params = CancelOrderParams {
+            order_utxo,
+            fee_utxo,
+            fee_amount,
+            fee_asset_id: policy_bytes,
+            order_asset_id: order_asset,
+            refund_destination: change_spk.clone(),
+            fee_change_destination: Some(change_spk),
+        };
+
+        let mut pset = build_cancel_order_pset(&cancel_params)?;
+
+        // 6b. Blind wallet-destination outputs.
+        // Output 0 = refund (blind), Output 1 = fee (skip), Output 2 = fee change (blind).
+        let mut blind_indices = vec![0usize]; // refund
+        let num_outputs = pset.n_outputs();
+        if num_outputs > 2 {
+            blind_indices.push(2); // fee change
+        }
+        self.blind_order_pset(&mut pset, &input_utxos, &blind_indices, &change_addr)?;
+
+        // 7. Attach the maker's cancel witness to the covenant input (input 0)
+        self.attach_order_cancel_witness(&mut pset, &contract, &maker_base_pubkey, &maker_keypair)?;
+
+        // 8. Sign fee input via normal signer
+        self.wollet
+            .add_details(&mut pset)
+            .map_err(|e| Error::Signer(format!("add_details: {}", e)))?;
+        self.signer
+            .sign(&mut pset)
+            .map_err(|e| Error::Signer(format!("{:?}", e)))?;
+        let tx = self
+            .wollet
+            .finalize(&mut pset)
+            .map_err(|e| Error::Finalize(e.to_string()))?;
+
+        let txid = match self.broadcast_and_sync(&tx) {
+            Ok(txid) => txid,
+            // A fill that reached the mempool after the scan above.
+            Err(Error::CovenantConflict { outpoint }) if outpoint == order_outpoint => {
+                return Err(match self.pending_covenant_spend(&covenant_spk) {
+                    Ok(Some((outpoint, _, fill))) => Error::OrderBeingFilled {
+                        outpoint,
+                        fill_txid: fill.txid(),
+                    },
+                    _ => Error::CovenantConflict { outpoint },
+                });
+            }
+            Err(e) => return Err(e),
+        };
+
+        Ok(CancelOrderResult {
+            txid,
+            refunded_amount: order_value,
+            replaced_fill_txid,
+        })
+    }
+
+    /// Add `additional_amount` of the offered asset to a live limit order.
     ///
-    /// Uses the Simplicity cancel path (Right branch) with a BIP-340 signature
-    /// over SHA256(prev_outpoint) to authorize reclaiming funds.
-    pub fn cancel_limit_order(
+    /// The order UTXO is spent through the cancel path and recreated at the
+    /// same covenant address with the combined amount in one transaction, so
+    /// the order keeps its params and announcement identifier and costs a
+    /// single fee.
+    pub fn top_up_limit_order(
         &mut self,
         params: &MakerOrderParams,
         maker_base_pubkey: [u8; 32],
         order_index: u32,
+        additional_amount: u64,
         fee_amount: u64,
-    ) -> Result<CancelOrderResult> {
+    ) -> Result<TopUpOrderResult> {
         self.sync()?;
 
-        // 1. Derive maker keypair
+        // 1. Derive maker keypair and compile the contract
         let maker_keypair = self.derive_maker_keypair(order_index)?;
-
-        // 2. Compile the contract
         let contract = CompiledMakerOrder::new(*params)?;
-        let cmr = *contract.cmr();
-        let cb_bytes = contract.control_block(&maker_base_pubkey);
 
-        // 3. Compute covenant SPK and scan for order UTXO
+        // 2. Find the order UTXO; a fill in flight leaves nothing to top up
         let covenant_spk = contract.script_pubkey(&maker_base_pubkey);
-        let covenant_utxos = self.scan_covenant_utxos(&covenant_spk)?;
-        let (order_outpoint, order_txout) = covenant_utxos
-            .into_iter()
-            .next()
-            .ok_or_else(|| Error::MakerOrder("no UTXO found at order covenant address".into()))?;
-
-        // 4. Convert to UnblindedUtxo (explicit asset, zeroed blinding factors)
-        let order_value = order_txout.value.explicit().unwrap_or(0);
-        let order_asset = match params.direction {
+        let (order_outpoint, order_txout) =
+            match self.scan_covenant_utxos(&covenant_spk)?.into_iter().next() {
+                Some(utxo) => utxo,
+                None => {
+                    return Err(match self.pending_covenant_spend(&covenant_spk)? {
+                        Some((outpoint, _, fill)) => Error::OrderBeingFilled {
+                            outpoint,
+                            fill_txid: fill.txid(),
+                        },
+                        None => Error::MakerOrder("no UTXO found at order covenant address".into()),
+                    });
+                }
+            };
+        let offered_asset = match params.direction {
             OrderDirection::SellBase => params.base_asset_id,
             OrderDirection::SellQuote => params.quote_asset_id,
         };
+        let previous_amount = order_txout.value.explicit().unwrap_or(0);
+        let order_amount = previous_amount
+            .checked_add(additional_amount)
+            .ok_or_else(|| Error::MakerOrder("topped-up order amount overflows".into()))?;
+        validate_order_amounts(
+            params.price,
+            order_amount,
+            params.direction,
+            params.min_fill_lots,
+            params.min_remainder_lots,
+        )?;
         let order_utxo = UnblindedUtxo {
             outpoint: order_outpoint,
             txout: order_txout,
-            asset_id: order_asset,
-            value: order_value,
+            asset_id: offered_asset,
+            value: previous_amount,
             asset_blinding_factor: [0u8; 32],
             value_blinding_factor: [0u8; 32],
         };
 
-        // 5. Select fee UTXO
-        let (fee_utxo, change_addr) =
-            self.select_fee_utxo_excluding(fee_amount, &[order_outpoint])?;
-        let change_spk = change_addr.script_pubkey();
+        // 3. Select funding and fee UTXOs, as for order creation
         let policy_bytes: [u8; 32] = self.policy_asset().into_inner().to_byte_array();
+        let same_asset = offered_asset == policy_bytes;
+        let funding_utxo = if same_asset {
+            self.select_funding_utxo(
+                &offered_asset,
+                additional_amount + fee_amount,
+                &[order_outpoint],
+            )?
+        } else {
+            self.select_funding_utxo(&offered_asset, additional_amount, &[order_outpoint])?
+        };
+        let (fee_utxo, change_addr) = if same_asset {
+            let change_addr: lwk_wollet::elements::Address = self
+                .address(None)?
+                .address()
+                .to_string()
+                .parse()
+                .map_err(|e| Error::Query(format!("bad change address: {}", e)))?;
+            (funding_utxo.clone(), change_addr)
+        } else {
+            self.select_fee_utxo_excluding(
+                FeeOperation::CreateLimitOrder,
+                fee_amount,
+                &[order_outpoint, funding_utxo.outpoint],
+            )?
+        };
+        let change_spk = change_addr.script_pubkey();
 
-        // 6. Build cancel PSET
-        let input_utxos = [order_utxo.clone(), fee_utxo.clone()];
-
-        let cancel_params = CancelOrderParams {
+        // 4. Build and blind the PSET (outputs 2+ are wallet change)
+        let mut input_utxos = vec![order_utxo.clone(), funding_utxo.clone()];
+        if !same_asset {
+            input_utxos.push(fee_utxo.clone());
+        }
+        let top_up_params = TopUpOrderParams {
             order_utxo,
+            funding_utxo,
             fee_utxo,
+            additional_amount,
             fee_amount,
             fee_asset_id: policy_bytes,
-            order_asset_id: order_asset,
-            refund_destination: change_spk.clone(),
+            change_destination: Some(change_spk.clone()),
             fee_change_destination: Some(change_spk),
+            maker_base_pubkey,
         };
+        let mut pset = build_top_up_order_pset(&contract, &top_up_params)?;
+        let blind_indices: Vec<usize> = (2..pset.n_outputs()).collect();
+        self.blind_order_pset(&mut pset, &input_utxos, &blind_indices, &change_addr)?;
 
-        let mut pset = build_cancel_order_pset(&cancel_params)?;
+        // 5. Authorize the covenant input, sign wallet inputs and broadcast
+        self.attach_order_cancel_witness(&mut pset, &contract, &maker_base_pubkey, &maker_keypair)?;
+        self.wollet
+            .add_details(&mut pset)
+            .map_err(|e| Error::Signer(format!("add_details: {}", e)))?;
+        self.signer
+            .sign(&mut pset)
+            .map_err(|e| Error::Signer(format!("{:?}", e)))?;
+        let tx = self
+            .wollet
+            .finalize(&mut pset)
+            .map_err(|e| Error::Finalize(e.to_string()))?;
+        let txid = self.broadcast_and_sync(&tx)?;
 
-        // 6b. Blind wallet-destination outputs.
-        // Output 0 = refund (blind), Output 1 = fee (skip), Output 2 = fee change (blind).
-        let mut blind_indices = vec![0usize]; // refund
-        let num_outputs = pset.n_outputs();
-        if num_outputs > 2 {
-            blind_indices.push(2); // fee change
+        let covenant_address = contract
+            .address(&maker_base_pubkey, self.network.address_params())
+            .to_string();
+
+        Ok(TopUpOrderResult {
+            txid,
+            previous_amount,
+            order_amount,
+            covenant_address,
+        })
+    }
+
+    /// Move a live limit order to `new_price`: cancel it, then create an
+    /// order with the same direction and lot constraints at the new price.
+    ///
+    /// The new order offers the refunded amount, rounded down to whole lots
+    /// at the new price for quote-selling orders; funding selection can use
+    /// the just-refunded output. The new amount is checked before the cancel
+    /// is broadcast, so a price the order's funds cannot meet fails without
+    /// touching the order. A create that fails after the cancel is broadcast
+    /// is reported in the result rather than as an error.
+    pub fn reprice_limit_order(
+        &mut self,
+        params: &MakerOrderParams,
+        maker_base_pubkey: [u8; 32],
+        order_index: u32,
+        new_price: u64,
+        fee_amount: u64,
+    ) -> Result<RepriceOrderResult> {
+        if new_price == params.price {
+            return Err(Error::MakerOrder(format!(
+                "order is already priced at {new_price}"
+            )));
         }
-        self.blind_order_pset(&mut pset, &input_utxos, &blind_indices, &change_addr)?;
+        self.sync()?;
+
+        let covenant_spk = CompiledMakerOrder::new(*params)?.script_pubkey(&maker_base_pubkey);
+        // A missing order UTXO is left to the cancel, which reports a fill in flight.
+        if let Some(current_amount) = self
+            .scan_covenant_utxos(&covenant_spk)?
+            .first()
+            .and_then(|(_, txout)| txout.value.explicit())
+        {
+            validate_order_amounts(
+                new_price,
+                repriced_order_amount(params.direction, current_amount, new_price),
+                params.direction,
+                params.min_fill_lots,
+                params.min_remainder_lots,
+            )?;
+        }
+
+        let cancel =
+            self.cancel_limit_order(params, maker_base_pubkey, order_index, fee_amount, false)?;
+        let order_amount =
+            repriced_order_amount(params.direction, cancel.refunded_amount, new_price);
+        let create = self
+            .create_limit_order(
+                params.base_asset_id,
+                params.quote_asset_id,
+                new_price,
+                order_amount,
+                params.direction,
+                params.min_fill_lots,
+                params.min_remainder_lots,
+                order_index,
+                fee_amount,
+            )
+            .map_err(|e| format!("order cancelled in {} but not recreated: {e}", cancel.txid));
+
+        Ok(RepriceOrderResult { cancel, create })
+    }
 
-        // 7. Compute maker cancel signature: SHA256(txid || vout) of the order outpoint
+    /// Satisfy the order covenant at input 0 through its cancel path.
+    ///
+    /// The maker signs SHA256(txid || vout) of the spent outpoint, so the
+    /// witness does not commit to the outputs and must be attached once the
+    /// PSET's inputs are final.
+    fn attach_order_cancel_witness(
+        &self,
+        pset: &mut PartiallySignedTransaction,
+        contract: &CompiledMakerOrder,
+        maker_base_pubkey: &[u8; 32],
+        maker_keypair: &Keypair,
+    ) -> Result<()> {
+        use simplicityhl::elements::taproot::ControlBlock;
+        use simplicityhl::simplicity::jet::elements::{ElementsEnv, ElementsUtxo};
+
+        let order_outpoint = pset
+            .inputs()
+            .first()
+            .map(|input| OutPoint::new(input.previous_txid, input.previous_output_index))
+            .ok_or_else(|| Error::Pset("missing order input".into()))?;
         let secp = secp256k1_zkp::Secp256k1::new();
         let sighash = {
             use sha2::{Digest, Sha256};
@@ -2154,73 +3885,76 @@ impl DeadcatSdk {
             hash
         };
         let msg = secp256k1_zkp::Message::from_digest(sighash);
-        let sig = secp.sign_schnorr_no_aux_rand(&msg, &maker_keypair);
+        let sig = secp.sign_schnorr_no_aux_rand(&msg, maker_keypair);
         let sig_bytes: [u8; 64] = sig.serialize();
 
-        // 8. Attach Simplicity cancel witness to covenant input (input 0)
-        {
-            use simplicityhl::elements::taproot::ControlBlock;
-            use simplicityhl::simplicity::jet::elements::{ElementsEnv, ElementsUtxo};
-
-            let tx = std::sync::Arc::new(pset_to_pruning_transaction(&pset)?);
-            let utxos: Vec<ElementsUtxo> = pset
-                .inputs()
-                .iter()
-                .enumerate()
-                .map(|(i, inp)| {
-                    inp.witness_utxo
-                        .as_ref()
-                        .map(|u| ElementsUtxo::from(u.clone()))
-                        .ok_or_else(|| Error::Pset(format!("input {i} missing witness_utxo")))
-                })
-                .collect::<Result<Vec<_>>>()?;
-
-            let control_block = ControlBlock::from_slice(&cb_bytes)
-                .map_err(|e| Error::Witness(format!("control block: {e}")))?;
-
-            let env = ElementsEnv::new(
-                std::sync::Arc::clone(&tx),
-                utxos,
-                0, // covenant is input 0 for cancel
-                cmr,
-                control_block,
-                None,
-                self.wollet.network().genesis_block_hash(),
-            );
+        let cmr = *contract.cmr();
+        let cb_bytes = contract.control_block(maker_base_pubkey);
+        let tx = std::sync::Arc::new(pset_to_pruning_transaction(pset)?);
+        let utxos: Vec<ElementsUtxo> = pset
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(i, inp)| {
+                inp.witness_utxo
+                    .as_ref()
+                    .map(|u| ElementsUtxo::from(u.clone()))
+                    .ok_or_else(|| Error::Pset(format!("input {i} missing witness_utxo")))
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-            let witness_values =
-                crate::maker_order::witness::build_maker_order_cancel_witness(&sig_bytes);
-            let satisfied = CompiledMakerOrder::new(*params)?
-                .program()
-                .satisfy_with_env(witness_values, Some(&env))
-                .map_err(|e| {
-                    Error::Compilation(format!("maker order cancel witness satisfaction: {e}"))
-                })?;
-            let (program_bytes, witness_bytes) = serialize_maker_order_satisfied(&satisfied);
-            let cmr_bytes = cmr.to_byte_array().to_vec();
+        let control_block = ControlBlock::from_slice(&cb_bytes)
+            .map_err(|e| Error::Witness(format!("control block: {e}")))?;
 
-            pset.inputs_mut()[0].final_script_witness =
-                Some(vec![witness_bytes, program_bytes, cmr_bytes, cb_bytes]);
-        }
+        let env = ElementsEnv::new(
+            std::sync::Arc::clone(&tx),
+            utxos,
+            0,
+            cmr,
+            control_block,
+            None,
+            self.wollet.network().genesis_block_hash(),
+        );
 
-        // 9. Sign fee input via normal signer
-        self.wollet
-            .add_details(&mut pset)
-            .map_err(|e| Error::Signer(format!("add_details: {}", e)))?;
-        self.signer
-            .sign(&mut pset)
-            .map_err(|e| Error::Signer(format!("{:?}", e)))?;
-        let tx = self
-            .wollet
-            .finalize(&mut pset)
-            .map_err(|e| Error::Finalize(e.to_string()))?;
+        let witness_values =
+            crate::maker_order::witness::build_maker_order_cancel_witness(&sig_bytes);
+        let satisfied = contract
+            .program()
+            .satisfy_with_env(witness_values, Some(&env))
+            .map_err(|e| {
+                Error::Compilation(format!("maker order cancel witness satisfaction: {e}"))
+            })?;
+        let (program_bytes, witness_bytes) = serialize_maker_order_satisfied(&satisfied);
+        let cmr_bytes = cmr.to_byte_array().to_vec();
 
-        let txid = self.broadcast_and_sync(&tx)?;
+        pset.inputs_mut()[0].final_script_witness =
+            Some(vec![witness_bytes, program_bytes, cmr_bytes, cb_bytes]);
+        Ok(())
+    }
 
-        Ok(CancelOrderResult {
-            txid,
-            refunded_amount: order_value,
-        })
+    /// Cancel each of `targets`, continuing past orders that fail.
+    ///
+    /// Each order is refunded in its own transaction via
+    /// [`cancel_limit_order`](Self::cancel_limit_order), so a failure leaves
+    /// the other cancellations unaffected.
+    pub fn cancel_all_orders(
+        &mut self,
+        targets: &[CancelOrderTarget],
+        fee_amount: u64,
+    ) -> Vec<CancelAllOutcome> {
+        targets
+            .iter()
+            .map(|target| CancelAllOutcome {
+                target: target.clone(),
+                result: self.cancel_limit_order(
+                    &target.params,
+                    target.maker_base_pubkey,
+                    target.order_index,
+                    fee_amount,
+                    false,
+                ),
+            })
+            .collect()
     }
 
     /// Fill a limit order by spending the covenant UTXO via Simplicity script-path.
@@ -2232,6 +3966,11 @@ impl DeadcatSdk {
         lots_to_fill: u64,
         fee_amount: u64,
     ) -> Result<FillOrderResult> {
+        let span = OpSpan::enter("fill_limit_order");
+        span.record("price", &params.price);
+        span.record("direction", &format_args!("{:?}", params.direction));
+        span.record("lots_to_fill", &lots_to_fill);
+        span.record("fee_amount", &fee_amount);
         self.sync()?;
 
         // 1. Compile the contract
@@ -2246,6 +3985,8 @@ impl DeadcatSdk {
             .ok_or_else(|| Error::MakerOrder("no UTXO found at order covenant address".into()))?;
 
         let order_value = order_txout.value.explicit().unwrap_or(0);
+        span.record("order_utxo", &order_outpoint);
+        span.record("order_value", &order_value);
         let order_asset = match params.direction {
             OrderDirection::SellBase => params.base_asset_id,
             OrderDirection::SellQuote => params.quote_asset_id,
@@ -2260,7 +4001,7 @@ impl DeadcatSdk {
         };
 
         // 3. Compute fill amounts based on direction
-        let (
+        let FillAmounts {
             taker_pays_amount,
             taker_pays_asset,
             taker_receives_amount,
@@ -2268,50 +4009,15 @@ impl DeadcatSdk {
             maker_receive_amount,
             is_partial,
             remainder_amount,
-        ) = match params.direction {
-            OrderDirection::SellBase => {
-                // Maker sells BASE lots, taker pays QUOTE
-                let taker_payment = lots_to_fill
-                    .checked_mul(params.price)
-                    .ok_or(Error::MakerOrderOverflow)?;
-                let is_partial = lots_to_fill < order_value;
-                let remainder = if is_partial {
-                    order_value - lots_to_fill
-                } else {
-                    0
-                };
-                (
-                    taker_payment,
-                    params.quote_asset_id,
-                    lots_to_fill,
-                    params.base_asset_id,
-                    taker_payment,
-                    is_partial,
-                    remainder,
-                )
-            }
-            OrderDirection::SellQuote => {
-                // Maker sells QUOTE, taker pays BASE lots
-                let quote_consumed = lots_to_fill
-                    .checked_mul(params.price)
-                    .ok_or(Error::MakerOrderOverflow)?;
-                let is_partial = quote_consumed < order_value;
-                let remainder = if is_partial {
-                    order_value - quote_consumed
-                } else {
-                    0
-                };
-                (
-                    lots_to_fill,
-                    params.base_asset_id,
-                    quote_consumed,
-                    params.quote_asset_id,
-                    lots_to_fill,
-                    is_partial,
-                    remainder,
-                )
-            }
-        };
+        } = compute_fill_amounts(params, order_value, lots_to_fill)?;
+
+        // 3b. Taker funding and fee must be separate wallet UTXOs
+        let taker_asset = AssetId::from_slice(&taker_pays_asset)
+            .map_err(|e| Error::Query(format!("bad asset id: {e}")))?;
+        require_distinct_utxos(
+            &self.utxos()?,
+            &[(taker_asset, 1), (self.policy_asset(), 1)],
+        )?;
 
         // 4. Select taker funding UTXO
         let taker_funding =
@@ -2321,8 +4027,11 @@ impl DeadcatSdk {
         let taker_change_amount = taker_funding.value - taker_pays_amount;
 
         // 5. Select fee UTXO
-        let (fee_utxo, change_addr) =
-            self.select_fee_utxo_excluding(fee_amount, &[order_outpoint, taker_funding.outpoint])?;
+        let (fee_utxo, change_addr) = self.select_fee_utxo_excluding(
+            FeeOperation::FillLimitOrder,
+            fee_amount,
+            &[order_outpoint, taker_funding.outpoint],
+        )?;
         let change_spk = change_addr.script_pubkey();
         let policy_bytes: [u8; 32] = self.policy_asset().into_inner().to_byte_array();
 
@@ -2460,6 +4169,7 @@ impl DeadcatSdk {
             .map_err(|e| Error::Finalize(e.to_string()))?;
 
         let txid = self.broadcast_and_sync(&tx)?;
+        span.finish(&txid);
 
         Ok(FillOrderResult {
             txid,
@@ -2468,6 +4178,109 @@ impl DeadcatSdk {
         })
     }
 
+    /// Check whether the wallet could fill `lots_to_fill` of an order without
+    /// building or broadcasting anything.
+    ///
+    /// Order-level constraints (minimum fill, minimum remainder, order size)
+    /// are reported as errors; wallet-level ones as shortfalls.
+    pub fn simulate_fill(
+        &mut self,
+        params: &MakerOrderParams,
+        maker_base_pubkey: [u8; 32],
+        lots_to_fill: u64,
+        fee_amount: u64,
+    ) -> Result<FillSimulation> {
+        self.sync()?;
+
+        let contract = CompiledMakerOrder::new(*params)?;
+        let covenant_spk = contract.script_pubkey(&maker_base_pubkey);
+        let (order_outpoint, order_txout) = self
+            .scan_covenant_utxos(&covenant_spk)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::MakerOrder("no UTXO found at order covenant address".into()))?;
+        let order_value = order_txout.value.explicit().unwrap_or(0);
+
+        if lots_to_fill == 0 || lots_to_fill < params.min_fill_lots {
+            return Err(Error::FillBelowMinimum);
+        }
+        let amounts = compute_fill_amounts(params, order_value, lots_to_fill)?;
+        // The taker receives exactly what the order UTXO gives up.
+        if amounts.taker_receives_amount > order_value {
+            return Err(Error::MakerOrder(format!(
+                "fill of {lots_to_fill} lots exceeds the order's remaining {order_value}"
+            )));
+        }
+        if amounts.is_partial {
+            let min_remainder = match params.direction {
+                OrderDirection::SellBase => params.min_remainder_lots,
+                OrderDirection::SellQuote => params
+                    .min_remainder_lots
+                    .checked_mul(params.price)
+                    .ok_or(Error::MakerOrderOverflow)?,
+            };
+            if amounts.remainder_amount < min_remainder {
+                return Err(Error::RemainderBelowMinimum);
+            }
+        }
+
+        let input_asset = AssetId::from_slice(&amounts.taker_pays_asset)
+            .map_err(|e| Error::Query(format!("bad asset id: {e}")))?;
+        let output_asset = AssetId::from_slice(&amounts.taker_receives_asset)
+            .map_err(|e| Error::Query(format!("bad asset id: {e}")))?;
+        let policy_asset = self.policy_asset();
+        let unspent: Vec<_> = self
+            .utxos()?
+            .into_iter()
+            .filter(|u| !u.is_spent && u.outpoint != order_outpoint)
+            .collect();
+
+        // Same single-UTXO rule as `select_funding_utxo`: the smallest UTXO
+        // that covers the payment, else report against the largest one held.
+        let funding = unspent
+            .iter()
+            .filter(|u| {
+                u.unblinded.asset == input_asset && u.unblinded.value >= amounts.taker_pays_amount
+            })
+            .min_by_key(|u| u.unblinded.value);
+        let input_shortfall = match funding {
+            Some(_) => 0,
+            None => {
+                let largest = unspent
+                    .iter()
+                    .filter(|u| u.unblinded.asset == input_asset)
+                    .map(|u| u.unblinded.value)
+                    .max()
+                    .unwrap_or(0);
+                amounts.taker_pays_amount - largest
+            }
+        };
+
+        let largest_fee_utxo = unspent
+            .iter()
+            .filter(|u| {
+                u.unblinded.asset == policy_asset
+                    && funding.is_none_or(|f| f.outpoint != u.outpoint)
+            })
+            .map(|u| u.unblinded.value)
+            .max()
+            .unwrap_or(0);
+        let fee_shortfall = fee_amount.saturating_sub(largest_fee_utxo);
+
+        Ok(FillSimulation {
+            can_afford: input_shortfall == 0 && fee_shortfall == 0,
+            lots_to_fill,
+            is_partial: amounts.is_partial,
+            input_asset,
+            input_needed: amounts.taker_pays_amount,
+            input_shortfall,
+            output_asset,
+            output_received: amounts.taker_receives_amount,
+            fee_est: fee_amount,
+            fee_shortfall,
+        })
+    }
+
     // ── Trade routing: combined LMSR + limit order execution ─────────────
 
     /// Execute a routed trade plan: build combined PSET, blind, attach
@@ -2476,14 +4289,25 @@ impl DeadcatSdk {
     /// The `plan` must have been produced by the trade router
     /// ([`trade::router::build_execution_plan`]). The caller (typically
     /// [`DeadcatNode::execute_trade`](crate::node::DeadcatNode)) is
-    /// responsible for obtaining a quote first.
+    /// responsible for obtaining a quote first. `market_id` labels the
+    /// journaled broadcast intent.
     pub(crate) fn execute_trade_plan(
         &mut self,
         plan: &crate::trade::types::ExecutionPlan,
         fee_amount: u64,
+        market_id: Option<String>,
     ) -> Result<crate::trade::types::TradeResult> {
         use crate::trade::pset::{TradePsetParams, build_trade_pset};
 
+        let span = OpSpan::enter(if plan.lmsr_pool_leg.is_some() {
+            "pool_swap"
+        } else {
+            "fill_orders"
+        });
+        span.record("total_taker_input", &plan.total_taker_input);
+        span.record("total_taker_output", &plan.total_taker_output);
+        span.record("order_legs", &plan.order_legs.len());
+        span.record("fee_amount", &fee_amount);
         self.sync()?;
 
         if let Some(ref lmsr_leg) = plan.lmsr_pool_leg {
@@ -2491,6 +4315,12 @@ impl DeadcatSdk {
         }
 
         let policy_bytes: [u8; 32] = self.policy_asset().into_inner().to_byte_array();
+        let taker_asset = AssetId::from_slice(&plan.taker_send_asset)
+            .map_err(|e| Error::Query(format!("bad asset id: {e}")))?;
+        require_distinct_utxos(
+            &self.utxos()?,
+            &[(taker_asset, 1), (self.policy_asset(), 1)],
+        )?;
 
         // 1. Compile contracts
         let order_contracts: Vec<CompiledMakerOrder> = plan
@@ -2505,18 +4335,34 @@ impl DeadcatSdk {
             exclude.push(lmsr_leg.pool_utxos.yes.outpoint);
             exclude.push(lmsr_leg.pool_utxos.no.outpoint);
             exclude.push(lmsr_leg.pool_utxos.collateral.outpoint);
+            span.record("pool_id", &lmsr_leg.pool_id);
+            span.record("pool_yes_utxo", &lmsr_leg.pool_utxos.yes.outpoint);
+            span.record("pool_no_utxo", &lmsr_leg.pool_utxos.no.outpoint);
+            span.record(
+                "pool_collateral_utxo",
+                &lmsr_leg.pool_utxos.collateral.outpoint,
+            );
+            span.record("old_s_index", &lmsr_leg.old_s_index);
+            span.record("new_s_index", &lmsr_leg.new_s_index);
+            span.record("pool_delta_in", &lmsr_leg.delta_in);
+            span.record("pool_delta_out", &lmsr_leg.delta_out);
         }
         for leg in &plan.order_legs {
             exclude.push(leg.order_utxo.outpoint);
+            span.record("order_utxo", &leg.order_utxo.outpoint);
         }
 
         // 3. Select taker funding UTXO
         let taker_funding =
             self.select_funding_utxo(&plan.taker_send_asset, plan.total_taker_input, &exclude)?;
         exclude.push(taker_funding.outpoint);
+        span.record("taker_funding_utxo", &taker_funding.outpoint);
+        span.record("taker_funding_value", &taker_funding.value);
 
         // 4. Select fee UTXO + change address
-        let (fee_utxo, change_addr) = self.select_fee_utxo_excluding(fee_amount, &exclude)?;
+        let (fee_utxo, change_addr) =
+            self.select_fee_utxo_excluding(FeeOperation::FillLimitOrder, fee_amount, &exclude)?;
+        span.record("fee_utxo", &fee_utxo.outpoint);
         let change_spk = change_addr.script_pubkey();
 
         let taker_change = if taker_funding.value > plan.total_taker_input {
@@ -2619,9 +4465,10 @@ impl DeadcatSdk {
             }
         }
 
-        // 9. Sign wallet inputs and broadcast
+        // 9. Sign wallet inputs, journal and broadcast
         let tx = self.sign_pset(pset)?;
-        let txid = self.broadcast_and_sync(&tx)?;
+        let txid = self.broadcast_journaled(&tx, IntentKind::PoolSwap, market_id)?;
+        span.finish(&txid);
 
         Ok(crate::trade::types::TradeResult {
             txid,
@@ -2667,33 +4514,79 @@ impl DeadcatSdk {
         Ok(())
     }
 
-    /// Find the explicit collateral UTXO from a set of covenant UTXOs.
-    fn find_collateral_utxo(
+    /// Collect the explicit collateral UTXOs from a set of covenant UTXOs,
+    /// largest first.
+    fn collect_collateral_utxos(
         covenant_utxos: &[(OutPoint, TxOut)],
         params: &PredictionMarketParams,
-    ) -> Result<UnblindedUtxo> {
+    ) -> Result<Vec<UnblindedUtxo>> {
         let collateral_id = AssetId::from_slice(&params.collateral_asset_id)
             .map_err(|e| Error::Unblind(format!("bad collateral asset: {e}")))?;
 
-        for (outpoint, txout) in covenant_utxos {
-            if let Asset::Explicit(asset) = txout.asset
-                && asset == collateral_id
-            {
-                let value = txout.value.explicit().unwrap_or(0);
-                return Ok(UnblindedUtxo {
+        let mut collateral: Vec<UnblindedUtxo> = covenant_utxos
+            .iter()
+            .filter_map(|(outpoint, txout)| match txout.asset {
+                Asset::Explicit(asset) if asset == collateral_id => Some(UnblindedUtxo {
                     outpoint: *outpoint,
                     txout: txout.clone(),
                     asset_id: params.collateral_asset_id,
-                    value,
+                    value: txout.value.explicit().unwrap_or(0),
                     asset_blinding_factor: [0u8; 32],
                     value_blinding_factor: [0u8; 32],
-                });
+                }),
+                _ => None,
+            })
+            .collect();
+        if collateral.is_empty() {
+            return Err(Error::CovenantScan(
+                "collateral UTXO not found at covenant".into(),
+            ));
+        }
+        collateral.sort_by(|a, b| b.value.cmp(&a.value));
+        Ok(collateral)
+    }
+
+    /// Submit one covenant transaction per planned collateral draw.
+    ///
+    /// Returns the first txid, any follow-up txids, and the unsigned PSET of a
+    /// dry run. A dry run can only preview a single transaction, so it is
+    /// refused when the draw needs more than one.
+    fn run_collateral_draws<F>(
+        &mut self,
+        draws: Vec<(Vec<UnblindedUtxo>, u64)>,
+        dry_run: bool,
+        mut submit: F,
+    ) -> Result<(Txid, Vec<Txid>, Option<String>)>
+    where
+        F: FnMut(&mut Self, Vec<UnblindedUtxo>, u64) -> Result<(Txid, Option<String>)>,
+    {
+        if dry_run && draws.len() > 1 {
+            return Err(Error::FragmentedCollateral(draws.len()));
+        }
+
+        let mut txids = Vec::with_capacity(draws.len());
+        let mut unsigned_pset = None;
+        for (collateral, tokens) in draws {
+            match submit(self, collateral, tokens) {
+                Ok((txid, pset)) => {
+                    txids.push(txid);
+                    unsigned_pset = pset;
+                }
+                Err(e) if txids.is_empty() => return Err(e),
+                Err(e) => {
+                    return Err(Error::PartialCollateralDraw {
+                        completed_txids: txids.iter().map(Txid::to_string).collect(),
+                        reason: e.to_string(),
+                    });
+                }
             }
         }
 
-        Err(Error::CovenantScan(
-            "collateral UTXO not found at covenant".into(),
-        ))
+        let mut txids = txids.into_iter();
+        let first = txids
+            .next()
+            .ok_or_else(|| Error::CovenantScan("no collateral to draw from".into()))?;
+        Ok((first, txids.collect(), unsigned_pset))
     }
 
     // ── Token/fee UTXO helpers ───────────────────────────────────────────
@@ -2711,42 +4604,15 @@ impl DeadcatSdk {
             .map_err(|e| Error::Query(format!("bad NO asset: {e}")))?;
 
         let raw_utxos = self.utxos()?;
+        let yes_selected = select_token_utxos(&raw_utxos, yes_id, yes_asset, pairs)?;
+        let no_selected = select_token_utxos(&raw_utxos, no_id, no_asset, pairs)?;
 
-        let collect_tokens = |asset_id: AssetId,
-                              asset_bytes: &[u8; 32],
-                              needed: u64|
-         -> Result<Vec<UnblindedUtxo>> {
-            let mut collected = Vec::new();
-            let mut total = 0u64;
-            for u in raw_utxos
-                .iter()
-                .filter(|u| !u.is_spent && u.unblinded.asset == asset_id)
-            {
-                let tx = self.fetch_transaction(&u.outpoint.txid)?;
-                let txout = tx
-                    .output
-                    .get(u.outpoint.vout as usize)
-                    .ok_or_else(|| Error::Query("token UTXO vout out of range".into()))?
-                    .clone();
-                collected.push(wallet_txout_to_unblinded(u, &txout));
-                total = total.saturating_add(u.unblinded.value);
-                if total >= needed {
-                    break;
-                }
-            }
-            if total < needed {
-                return Err(Error::InsufficientUtxos(format!(
-                    "need {} tokens of asset {:?}, found {}",
-                    needed,
-                    hex::encode(asset_bytes),
-                    total
-                )));
-            }
-            Ok(collected)
-        };
-
-        let yes_utxos = collect_tokens(yes_id, yes_asset, pairs)?;
-        let no_utxos = collect_tokens(no_id, no_asset, pairs)?;
+        // One bulk fetch so YES and NO parents share a batch (and often a tx).
+        let mut both = yes_selected;
+        let yes_count = both.len();
+        both.extend(no_selected);
+        let mut yes_utxos = self.unblind_wallet_utxos(&both, "token")?;
+        let no_utxos = yes_utxos.split_off(yes_count);
 
         Ok((yes_utxos, no_utxos))
     }
@@ -2761,33 +4627,8 @@ impl DeadcatSdk {
             .map_err(|e| Error::Query(format!("bad token asset: {e}")))?;
 
         let raw_utxos = self.utxos()?;
-        let mut collected = Vec::new();
-        let mut total = 0u64;
-        for u in raw_utxos
-            .iter()
-            .filter(|u| !u.is_spent && u.unblinded.asset == asset_id)
-        {
-            let tx = self.fetch_transaction(&u.outpoint.txid)?;
-            let txout = tx
-                .output
-                .get(u.outpoint.vout as usize)
-                .ok_or_else(|| Error::Query("token UTXO vout out of range".into()))?
-                .clone();
-            collected.push(wallet_txout_to_unblinded(u, &txout));
-            total = total.saturating_add(u.unblinded.value);
-            if total >= needed {
-                break;
-            }
-        }
-        if total < needed {
-            return Err(Error::InsufficientUtxos(format!(
-                "need {} tokens of asset {}, found {}",
-                needed,
-                hex::encode(token_asset),
-                total
-            )));
-        }
-        Ok(collected)
+        let selected = select_token_utxos(&raw_utxos, asset_id, token_asset, needed)?;
+        self.unblind_wallet_utxos(&selected, "token")
     }
 
     /// Select a fee UTXO and return it with a change address.
@@ -2795,13 +4636,58 @@ impl DeadcatSdk {
     /// Callers must ensure the wallet is synced before calling this method.
     fn select_fee_utxo(
         &mut self,
+        operation: FeeOperation,
+        fee_asset_id: &[u8; 32],
         fee_amount: u64,
     ) -> Result<(UnblindedUtxo, lwk_wollet::elements::Address)> {
-        self.select_fee_utxo_excluding(fee_amount, &[])
+        self.select_fee_utxo_in(operation, fee_asset_id, fee_amount, &[])
+    }
+
+    /// The asset fees are paid in: `requested`, or the policy asset when unset.
+    ///
+    /// Liquid mainnet only relays transactions whose fee is paid in L-BTC.
+    fn resolve_fee_asset(&self, requested: Option<[u8; 32]>) -> Result<[u8; 32]> {
+        let policy_bytes: [u8; 32] = self.policy_asset().into_inner().to_byte_array();
+        let fee_asset_id = requested.unwrap_or(policy_bytes);
+        if self.network.is_mainnet() && fee_asset_id != policy_bytes {
+            return Err(Error::UnsupportedFeeAsset(hex::encode(fee_asset_id)));
+        }
+        Ok(fee_asset_id)
     }
 
     // ── Covenant scanning helpers ───────────────────────────────────────
 
+    /// Find an unconfirmed transaction spending an output locked to
+    /// `script_pubkey`, returning the spent outpoint and output along with
+    /// the spender.
+    fn pending_covenant_spend(
+        &self,
+        script_pubkey: &Script,
+    ) -> Result<Option<(OutPoint, TxOut, Transaction)>> {
+        let txids = self.chain.script_history_txids(script_pubkey)?;
+        for txid in &txids {
+            if self.chain.transaction_height(txid)?.is_some() {
+                continue;
+            }
+            let tx = self.fetch_transaction(txid)?;
+            for input in &tx.input {
+                let prev = input.previous_output;
+                if !txids.contains(&prev.txid) {
+                    continue;
+                }
+                let funding = self.fetch_transaction(&prev.txid)?;
+                if let Some(txout) = funding
+                    .output
+                    .get(prev.vout as usize)
+                    .filter(|o| o.script_pubkey == *script_pubkey)
+                {
+                    return Ok(Some((prev, txout.clone(), tx)));
+                }
+            }
+        }
+        Ok(None)
+    }
+
     pub(crate) fn scan_covenant_utxos(
         &self,
         script_pubkey: &Script,
@@ -3136,6 +5022,13 @@ fn validate_create_lmsr_pool_request(request: &CreateLmsrPoolRequest) -> Result<
         .validate()
         .map_err(|e| Error::LmsrPool(e.to_string()))?;
 
+    if request.pool_params.fee_bps > MAX_CREATION_FEE_BPS {
+        return Err(Error::PoolFeeOutOfRange {
+            fee_bps: request.pool_params.fee_bps,
+            max_bps: MAX_CREATION_FEE_BPS,
+        });
+    }
+
     if request.pool_params.yes_asset_id != request.market_params.yes_token_asset {
         return Err(Error::LmsrPool(
             "pool yes_asset_id must match market yes_token_asset".into(),
@@ -3532,6 +5425,63 @@ fn make_live_reserve_bundle(
     ))
 }
 
+/// Amount a repriced order can offer from `amount`: all of it when selling
+/// base, whole lots at `new_price` when selling quote.
+fn repriced_order_amount(direction: OrderDirection, amount: u64, new_price: u64) -> u64 {
+    match direction {
+        OrderDirection::SellBase => amount,
+        OrderDirection::SellQuote => amount - amount % new_price.max(1),
+    }
+}
+
+/// Decode an intent's journaled transaction, checking it matches its txid.
+fn intent_transaction(intent: &BroadcastIntent) -> Result<Transaction> {
+    let tx: Transaction = lwk_wollet::elements::encode::deserialize(&intent.raw_tx)
+        .map_err(|e| Error::Intent(format!("{}: undecodable transaction: {e}", intent.txid)))?;
+    if tx.txid().to_string() != intent.txid {
+        return Err(Error::Intent(format!(
+            "{}: journaled transaction has txid {}",
+            intent.txid,
+            tx.txid()
+        )));
+    }
+    Ok(tx)
+}
+
+/// Describe one side of a transaction, preferring the wallet's unblinded view.
+fn transaction_detail_entry(
+    outpoint: OutPoint,
+    txout: Option<&TxOut>,
+    owned: Option<&WalletTxOut>,
+    params: &'static lwk_wollet::elements::AddressParams,
+) -> TransactionDetailEntry {
+    if let Some(owned) = owned {
+        return TransactionDetailEntry {
+            outpoint,
+            script_pubkey: owned.script_pubkey.clone(),
+            asset: Some(owned.unblinded.asset),
+            value: Some(owned.unblinded.value),
+            address: Some(owned.address.to_string()),
+            is_mine: true,
+            is_change: owned.ext_int == lwk_wollet::Chain::Internal,
+            is_fee: false,
+        };
+    }
+    let script_pubkey = txout.map(|o| o.script_pubkey.clone()).unwrap_or_default();
+    let address = lwk_wollet::elements::Address::from_script(&script_pubkey, None, params)
+        .map(|address| address.to_string());
+    TransactionDetailEntry {
+        outpoint,
+        asset: txout.and_then(|o| o.asset.explicit()),
+        value: txout.and_then(|o| o.value.explicit()),
+        address,
+        is_mine: false,
+        is_change: false,
+        is_fee: txout.is_some_and(TxOut::is_fee),
+        script_pubkey,
+    }
+}
+
 /// Convert a LWK `WalletTxOut` + full `TxOut` into the SDK's `UnblindedUtxo`.
 ///
 /// Since both `lwk_wollet::elements` and `deadcat_sdk::elements` resolve to the
@@ -3559,12 +5509,292 @@ fn wallet_txout_to_unblinded(
     }
 }
 
+/// Sign every input of a sweep transaction with the swept key and attach the
+/// matching scriptSig / witness for its script type.
+fn sign_sweep_inputs(
+    tx: &mut Transaction,
+    inputs: &[(UnblindedUtxo, SweepScript)],
+    key: &lwk_wollet::elements::bitcoin::PrivateKey,
+    params: &'static lwk_wollet::elements::AddressParams,
+) {
+    use lwk_wollet::elements::EcdsaSighashType;
+    use lwk_wollet::elements::bitcoin::secp256k1::{Message, Secp256k1};
+    use lwk_wollet::elements::script::Builder;
+    use lwk_wollet::elements::sighash::SighashCache;
+
+    let secp = Secp256k1::new();
+    let pubkey = key.public_key(&secp);
+    // BIP143 script code for P2WPKH spends is the key's P2PKH script.
+    let script_code = SweepScript::P2pkh.address(&pubkey, params).script_pubkey();
+
+    let signatures: Vec<Vec<u8>> = {
+        let mut cache = SighashCache::new(&*tx);
+        inputs
+            .iter()
+            .enumerate()
+            .map(|(index, (utxo, kind))| {
+                let sighash = match kind {
+                    SweepScript::P2pkh => cache.legacy_sighash(
+                        index,
+                        &utxo.txout.script_pubkey,
+                        EcdsaSighashType::All,
+                    ),
+                    SweepScript::P2wpkh | SweepScript::P2shP2wpkh => cache.segwitv0_sighash(
+                        index,
+                        &script_code,
+                        utxo.txout.value,
+                        EcdsaSighashType::All,
+                    ),
+                };
+                let msg = Message::from_digest(sighash.to_byte_array());
+                let mut sig = secp.sign_ecdsa(&msg, &key.inner).serialize_der().to_vec();
+                sig.push(EcdsaSighashType::All as u8);
+                sig
+            })
+            .collect()
+    };
+
+    for ((input, (_, kind)), sig) in tx.input.iter_mut().zip(inputs).zip(signatures) {
+        match kind {
+            SweepScript::P2wpkh => {
+                input.witness.script_witness = vec![sig, pubkey.to_bytes()];
+            }
+            SweepScript::P2shP2wpkh => {
+                let redeem_script = SweepScript::P2wpkh.address(&pubkey, params).script_pubkey();
+                input.script_sig = Builder::new()
+                    .push_slice(redeem_script.as_bytes())
+                    .into_script();
+                input.witness.script_witness = vec![sig, pubkey.to_bytes()];
+            }
+            SweepScript::P2pkh => {
+                input.script_sig = Builder::new()
+                    .push_slice(&sig)
+                    .push_key(&pubkey)
+                    .into_script();
+            }
+        }
+    }
+}
+
+/// Split a burn of `tokens` across covenant collateral UTXOs.
+///
+/// A redemption merges up to [`max_collateral_inputs`] collateral UTXOs into
+/// one transaction, so a payout only needs several transactions when the
+/// collateral is spread over more UTXOs than that. The smallest UTXO that
+/// covers the whole payout is preferred; otherwise UTXOs are merged largest
+/// first.
+fn plan_collateral_draws(
+    collateral: &[UnblindedUtxo],
+    tokens: u64,
+    payout_per_token: u64,
+) -> Result<Vec<(Vec<UnblindedUtxo>, u64)>> {
+    if payout_per_token == 0 {
+        return Err(Error::CollateralOverflow);
+    }
+    let payout = tokens
+        .checked_mul(payout_per_token)
+        .ok_or(Error::CollateralOverflow)?;
+    if let Some(single) = collateral
+        .iter()
+        .filter(|u| u.value >= payout)
+        .min_by_key(|u| u.value)
+    {
+        return Ok(vec![(vec![single.clone()], tokens)]);
+    }
+
+    let max_inputs = max_collateral_inputs(1);
+    let mut by_value: Vec<&UnblindedUtxo> = collateral.iter().collect();
+    by_value.sort_by(|a, b| b.value.cmp(&a.value));
+    let mut draws = Vec::new();
+    let mut group = Vec::new();
+    let mut remaining = tokens;
+    for utxo in by_value {
+        if remaining == 0 {
+            break;
+        }
+        group.push(utxo.clone());
+        let covered = collateral_total(&group)? / payout_per_token;
+        if covered >= remaining || group.len() == max_inputs {
+            let take = covered.min(remaining);
+            let group = std::mem::take(&mut group);
+            if take > 0 {
+                draws.push((group, take));
+                remaining -= take;
+            }
+        }
+    }
+    if remaining > 0 {
+        return Err(Error::InsufficientCollateral);
+    }
+    Ok(draws)
+}
+
+/// Pick the covenant collateral UTXOs a token cancellation refunds from.
+///
+/// A partial cancellation must leave collateral behind, so the smallest UTXO
+/// strictly larger than `refund` is used, or else the largest UTXOs are merged
+/// until they exceed it. Draining collateral exactly returns the market to
+/// Dormant, which is only sound when every collateral UTXO is spent.
+fn select_cancellation_collateral(
+    collateral: &[UnblindedUtxo],
+    refund: u64,
+) -> Result<Vec<UnblindedUtxo>> {
+    if collateral.is_empty() {
+        return Err(Error::CovenantScan(
+            "collateral UTXO not found at covenant".into(),
+        ));
+    }
+    let total = collateral_total(collateral)?;
+    if total < refund {
+        return Err(Error::InsufficientCollateral);
+    }
+    if total == refund {
+        // The reissuance tokens take inputs 1 and 2 of a full cancellation.
+        let max = max_collateral_inputs(3);
+        if collateral.len() > max {
+            return Err(Error::CollateralInputCount {
+                count: collateral.len(),
+                max,
+            });
+        }
+        return Ok(collateral.to_vec());
+    }
+
+    if let Some(single) = collateral
+        .iter()
+        .filter(|u| u.value > refund)
+        .min_by_key(|u| u.value)
+    {
+        return Ok(vec![single.clone()]);
+    }
+    let max = max_collateral_inputs(1);
+    let mut by_value: Vec<&UnblindedUtxo> = collateral.iter().collect();
+    by_value.sort_by(|a, b| b.value.cmp(&a.value));
+    let mut selected = Vec::new();
+    for utxo in by_value.into_iter().take(max) {
+        selected.push(utxo.clone());
+        if collateral_total(&selected)? > refund {
+            return Ok(selected);
+        }
+    }
+    Err(Error::CollateralInputCount {
+        count: collateral.len(),
+        max,
+    })
+}
+
+fn compute_fill_amounts(
+    params: &MakerOrderParams,
+    order_value: u64,
+    lots_to_fill: u64,
+) -> Result<FillAmounts> {
+    let amounts = match params.direction {
+        OrderDirection::SellBase => {
+            // Maker sells BASE lots, taker pays QUOTE
+            let taker_payment = lots_to_fill
+                .checked_mul(params.price)
+                .ok_or(Error::MakerOrderOverflow)?;
+            let is_partial = lots_to_fill < order_value;
+            FillAmounts {
+                taker_pays_amount: taker_payment,
+                taker_pays_asset: params.quote_asset_id,
+                taker_receives_amount: lots_to_fill,
+                taker_receives_asset: params.base_asset_id,
+                maker_receive_amount: taker_payment,
+                is_partial,
+                remainder_amount: order_value.saturating_sub(lots_to_fill),
+            }
+        }
+        OrderDirection::SellQuote => {
+            // Maker sells QUOTE, taker pays BASE lots
+            let quote_consumed = lots_to_fill
+                .checked_mul(params.price)
+                .ok_or(Error::MakerOrderOverflow)?;
+            let is_partial = quote_consumed < order_value;
+            FillAmounts {
+                taker_pays_amount: lots_to_fill,
+                taker_pays_asset: params.base_asset_id,
+                taker_receives_amount: quote_consumed,
+                taker_receives_asset: params.quote_asset_id,
+                maker_receive_amount: lots_to_fill,
+                is_partial,
+                remainder_amount: order_value.saturating_sub(quote_consumed),
+            }
+        }
+    };
+    Ok(amounts)
+}
+
+/// Fee a CPFP child must pay so that parent and child together reach
+/// `fee_rate` (sat/kvB), and never less than the child's own share.
+fn cpfp_child_fee(parent_fee: u64, parent_vsize: u64, child_vsize: u64, fee_rate: f32) -> u64 {
+    let fee_for = |vsize: u64| (vsize as f64 * f64::from(fee_rate) / 1000.0).ceil() as u64;
+    fee_for(parent_vsize + child_vsize)
+        .saturating_sub(parent_fee)
+        .max(fee_for(child_vsize))
+}
+
+/// Smallest fee that lets a `replacement_vsize` transaction replace one that
+/// paid `replaced_fee`.
+fn rbf_replacement_fee(replaced_fee: u64, replacement_vsize: u64) -> u64 {
+    replaced_fee + (replacement_vsize * RBF_INCREMENTAL_RELAY_FEE_RATE).div_ceil(1000)
+}
+
 fn sum_unblinded_values(utxos: &[UnblindedUtxo]) -> Result<u64> {
     utxos.iter().try_fold(0u64, |acc, utxo| {
         acc.checked_add(utxo.value).ok_or(Error::CollateralOverflow)
     })
 }
 
+/// Take unspent `asset_id` UTXOs in wallet order until they cover `needed`.
+fn select_token_utxos(
+    raw_utxos: &[WalletTxOut],
+    asset_id: AssetId,
+    asset_bytes: &[u8; 32],
+    needed: u64,
+) -> Result<Vec<WalletTxOut>> {
+    let mut selected = Vec::new();
+    let mut total = 0u64;
+    for u in raw_utxos
+        .iter()
+        .filter(|u| !u.is_spent && u.unblinded.asset == asset_id)
+    {
+        selected.push(u.clone());
+        total = total.saturating_add(u.unblinded.value);
+        if total >= needed {
+            break;
+        }
+    }
+    if total < needed {
+        return Err(Error::InsufficientUtxos(format!(
+            "need {} tokens of asset {}, found {}",
+            needed,
+            hex::encode(asset_bytes),
+            total
+        )));
+    }
+    Ok(selected)
+}
+
+/// Up to `max_inputs` unspent `asset_id` UTXOs worth at least `min_value`,
+/// smallest first.
+fn consolidation_candidates(
+    raw_utxos: &[WalletTxOut],
+    asset_id: AssetId,
+    min_value: u64,
+    max_inputs: usize,
+) -> Vec<WalletTxOut> {
+    let mut candidates: Vec<WalletTxOut> = raw_utxos
+        .iter()
+        .filter(|u| !u.is_spent && u.unblinded.asset == asset_id)
+        .filter(|u| u.unblinded.value >= min_value)
+        .cloned()
+        .collect();
+    candidates.sort_by_key(|u| u.unblinded.value);
+    candidates.truncate(max_inputs);
+    candidates
+}
+
 fn select_wallet_utxo_set(
     raw_utxos: &[WalletTxOut],
     target_asset: AssetId,
@@ -3617,6 +5847,33 @@ fn select_wallet_utxo_set(
     )))
 }
 
+/// Fail with [`Error::NeedMoreUtxos`] unless the wallet holds enough
+/// separate unspent UTXOs for `needs`, given as `(asset, inputs)` pairs.
+/// Pairs naming the same asset add up, since no UTXO can fund two inputs.
+fn require_distinct_utxos(raw_utxos: &[WalletTxOut], needs: &[(AssetId, usize)]) -> Result<()> {
+    let mut needed: Vec<(AssetId, usize)> = Vec::new();
+    for &(asset_id, count) in needs {
+        match needed.iter_mut().find(|(asset, _)| *asset == asset_id) {
+            Some((_, total)) => *total += count,
+            None => needed.push((asset_id, count)),
+        }
+    }
+    for (asset_id, count_needed) in needed {
+        let count_available = raw_utxos
+            .iter()
+            .filter(|u| !u.is_spent && u.unblinded.asset == asset_id)
+            .count();
+        if count_available < count_needed {
+            return Err(Error::NeedMoreUtxos {
+                asset_id,
+                count_needed,
+                count_available,
+            });
+        }
+    }
+    Ok(())
+}
+
 /// Select 2 unspent L-BTC UTXOs suitable as defining outpoints.
 fn select_defining_utxos(
     raw_utxos: &[WalletTxOut],
@@ -3822,6 +6079,22 @@ mod tests {
         assert!(err.to_string().contains("yes_asset_id"));
     }
 
+    #[test]
+    fn validate_create_lmsr_pool_request_rejects_fee_above_creation_cap() {
+        let mut request = sample_lmsr_create_request();
+        request.pool_params.fee_bps = MAX_CREATION_FEE_BPS;
+        assert!(validate_create_lmsr_pool_request(&request).is_ok());
+        request.pool_params.fee_bps = MAX_CREATION_FEE_BPS + 1;
+        let err = validate_create_lmsr_pool_request(&request).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::PoolFeeOutOfRange {
+                fee_bps: 1_001,
+                max_bps: MAX_CREATION_FEE_BPS,
+            }
+        ));
+    }
+
     #[test]
     fn validate_create_lmsr_pool_request_rejects_half_payout_mismatch() {
         let mut request = sample_lmsr_create_request();
@@ -3846,6 +6119,43 @@ mod tests {
         assert!(err.to_string().contains("manifest root"));
     }
 
+    #[test]
+    fn select_token_utxos_keeps_wallet_order_and_stops_once_covered() {
+        let asset = third_asset();
+        let utxos = vec![
+            make_utxo(5, asset, 0, false),
+            make_utxo(50, policy_asset(), 1, false),
+            make_utxo(7, asset, 2, true),
+            make_utxo(3, asset, 3, false),
+            make_utxo(4, asset, 4, false),
+            make_utxo(9, asset, 5, false),
+        ];
+        let selected = select_token_utxos(&utxos, asset, &[0x03; 32], 10).unwrap();
+        let vouts: Vec<u32> = selected.iter().map(|u| u.outpoint.vout).collect();
+        assert_eq!(vouts, vec![0, 3, 4]);
+
+        let err = select_token_utxos(&utxos, asset, &[0x03; 32], 22).unwrap_err();
+        assert!(matches!(err, Error::InsufficientUtxos(_)));
+    }
+
+    #[test]
+    fn consolidation_candidates_take_smallest_economic_utxos() {
+        let asset = policy_asset();
+        let utxos = vec![
+            make_utxo(900, asset, 0, false),
+            make_utxo(40, asset, 1, false),
+            make_utxo(300, asset, 2, false),
+            make_utxo(200, asset, 3, true),
+            make_utxo(150, third_asset(), 4, false),
+            make_utxo(500, asset, 5, false),
+        ];
+        let selected = consolidation_candidates(&utxos, asset, 100, 3);
+        let vouts: Vec<u32> = selected.iter().map(|u| u.outpoint.vout).collect();
+        assert_eq!(vouts, vec![2, 5, 0]);
+
+        assert!(consolidation_candidates(&utxos, asset, 1_000, 3).is_empty());
+    }
+
     #[test]
     fn select_wallet_utxo_set_aggregates_across_multiple_utxos() {
         let asset = policy_asset();
@@ -3863,6 +6173,212 @@ mod tests {
         assert_eq!(selected[1].unblinded.value, 350);
     }
 
+    #[test]
+    fn require_distinct_utxos_counts_shared_assets_together() {
+        let lbtc = policy_asset();
+        let token = third_asset();
+        let utxos = vec![
+            make_utxo(400, lbtc, 0, false),
+            make_utxo(350, lbtc, 1, true),
+            make_utxo(1_000, token, 2, false),
+        ];
+        assert!(require_distinct_utxos(&utxos, &[(token, 1), (lbtc, 1)]).is_ok());
+        assert!(require_distinct_utxos(&utxos, &[(token, 0), (lbtc, 0)]).is_ok());
+
+        let err = require_distinct_utxos(&utxos, &[(lbtc, 1), (lbtc, 1)]).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::NeedMoreUtxos {
+                asset_id,
+                count_needed: 2,
+                count_available: 1,
+            } if asset_id == lbtc
+        ));
+    }
+
+    #[test]
+    fn sign_sweep_inputs_produces_valid_segwit_and_legacy_signatures() {
+        use lwk_wollet::elements::EcdsaSighashType;
+        use lwk_wollet::elements::bitcoin::secp256k1::{Message, Secp256k1, SecretKey};
+        use lwk_wollet::elements::bitcoin::{NetworkKind, PrivateKey};
+        use lwk_wollet::elements::sighash::SighashCache;
+
+        let secp = Secp256k1::new();
+        let key = PrivateKey::new(
+            SecretKey::from_slice(&[0x11; 32]).unwrap(),
+            NetworkKind::Test,
+        );
+        let pubkey = key.public_key(&secp);
+        let params = &AddressParams::LIQUID_TESTNET;
+        let asset = [0xaa; 32];
+
+        let inputs: Vec<(UnblindedUtxo, SweepScript)> = SweepScript::for_key(&pubkey)
+            .iter()
+            .enumerate()
+            .map(|(i, &kind)| {
+                let spk = kind.address(&pubkey, params).script_pubkey();
+                (test_explicit_utxo(&asset, 1_000, &spk, i as u8), kind)
+            })
+            .collect();
+        let mut pset = new_pset();
+        for (utxo, _) in &inputs {
+            add_pset_input(&mut pset, utxo);
+        }
+        add_pset_output(&mut pset, fee_txout(&asset, 3_000));
+        let mut tx = pset.extract_tx().unwrap();
+
+        sign_sweep_inputs(&mut tx, &inputs, &key, params);
+
+        let script_code = SweepScript::P2pkh.address(&pubkey, params).script_pubkey();
+        let mut cache = SighashCache::new(&tx);
+        for (index, (utxo, kind)) in inputs.iter().enumerate() {
+            let (sig, sighash) = match kind {
+                SweepScript::P2pkh => {
+                    let push = tx.input[index].script_sig.instructions().next();
+                    let Some(Ok(lwk_wollet::elements::script::Instruction::PushBytes(sig))) = push
+                    else {
+                        panic!("P2PKH scriptSig must start with the signature");
+                    };
+                    assert!(tx.input[index].witness.script_witness.is_empty());
+                    let sighash = cache.legacy_sighash(
+                        index,
+                        &utxo.txout.script_pubkey,
+                        EcdsaSighashType::All,
+                    );
+                    (sig.to_vec(), sighash)
+                }
+                SweepScript::P2wpkh | SweepScript::P2shP2wpkh => {
+                    let witness = &tx.input[index].witness.script_witness;
+                    assert_eq!(witness[1], pubkey.to_bytes());
+                    assert_eq!(
+                        tx.input[index].script_sig.is_empty(),
+                        *kind == SweepScript::P2wpkh
+                    );
+                    let sighash = cache.segwitv0_sighash(
+                        index,
+                        &script_code,
+                        utxo.txout.value,
+                        EcdsaSighashType::All,
+                    );
+                    (witness[0].clone(), sighash)
+                }
+            };
+            let (der, hash_type) = sig.split_at(sig.len() - 1);
+            assert_eq!(hash_type, [EcdsaSighashType::All as u8]);
+            let sig =
+                lwk_wollet::elements::bitcoin::secp256k1::ecdsa::Signature::from_der(der).unwrap();
+            let msg = Message::from_digest(sighash.to_byte_array());
+            secp.verify_ecdsa(&msg, &sig, &pubkey.inner).unwrap();
+        }
+    }
+
+    #[test]
+    fn cpfp_child_fee_covers_parent_shortfall() {
+        // 100 sat/kvB over 2_000 + 1_000 vB = 300 sats, parent already paid 50.
+        assert_eq!(cpfp_child_fee(50, 2_000, 1_000, 100.0), 250);
+        // A parent already above the target still leaves the child paying its own way.
+        assert_eq!(cpfp_child_fee(1_000, 2_000, 1_000, 100.0), 100);
+        // Fractional sats round up.
+        assert_eq!(cpfp_child_fee(0, 0, 1_001, 100.0), 101);
+    }
+
+    #[test]
+    fn rbf_replacement_fee_adds_incremental_relay_fee() {
+        // 100 sat/kvB over 3_000 vB on top of the 500 sats being replaced.
+        assert_eq!(rbf_replacement_fee(500, 3_000), 800);
+        assert_eq!(rbf_replacement_fee(0, 1_001), 101);
+    }
+
+    fn split_collateral(values: &[u64]) -> Vec<UnblindedUtxo> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| test_explicit_utxo(&[0xcc; 32], value, &Script::new(), i as u8))
+            .collect()
+    }
+
+    fn draw_plan(draws: &[(Vec<UnblindedUtxo>, u64)]) -> Vec<(Vec<u64>, u64)> {
+        draws
+            .iter()
+            .map(|(utxos, tokens)| (utxos.iter().map(|u| u.value).collect(), *tokens))
+            .collect()
+    }
+
+    fn selected_values(selected: &[UnblindedUtxo]) -> Vec<u64> {
+        selected.iter().map(|u| u.value).collect()
+    }
+
+    #[test]
+    fn plan_collateral_draws_prefers_single_covering_utxo() {
+        let collateral = split_collateral(&[10_000, 3_000, 5_000]);
+        let draws = plan_collateral_draws(&collateral, 4, 1_000).unwrap();
+        assert_eq!(draw_plan(&draws), vec![(vec![5_000], 4)]);
+    }
+
+    #[test]
+    fn plan_collateral_draws_merges_split_collateral() {
+        let collateral = split_collateral(&[3_000, 6_500, 2_000]);
+        let draws = plan_collateral_draws(&collateral, 10, 1_000).unwrap();
+        assert_eq!(draw_plan(&draws), vec![(vec![6_500, 3_000, 2_000], 10)]);
+
+        assert!(matches!(
+            plan_collateral_draws(&collateral, 12, 1_000),
+            Err(Error::InsufficientCollateral)
+        ));
+    }
+
+    #[test]
+    fn plan_collateral_draws_splits_past_merge_limit() {
+        let collateral = split_collateral(&[1_000; 8]);
+        let draws = plan_collateral_draws(&collateral, 7, 1_000).unwrap();
+        assert_eq!(
+            draw_plan(&draws),
+            vec![(vec![1_000; 6], 6), (vec![1_000], 1)]
+        );
+    }
+
+    #[test]
+    fn select_cancellation_collateral_keeps_remainder_when_split() {
+        let single = split_collateral(&[4_000]);
+        assert_eq!(
+            selected_values(&select_cancellation_collateral(&single, 4_000).unwrap()),
+            vec![4_000]
+        );
+
+        let split = split_collateral(&[4_000, 9_000, 6_000]);
+        assert_eq!(
+            selected_values(&select_cancellation_collateral(&split, 4_000).unwrap()),
+            vec![6_000]
+        );
+        assert_eq!(
+            selected_values(&select_cancellation_collateral(&split, 12_000).unwrap()),
+            vec![9_000, 6_000]
+        );
+        assert!(matches!(
+            select_cancellation_collateral(&split, 20_000),
+            Err(Error::InsufficientCollateral)
+        ));
+        assert!(matches!(
+            select_cancellation_collateral(&[], 1),
+            Err(Error::CovenantScan(_))
+        ));
+    }
+
+    #[test]
+    fn select_cancellation_collateral_drains_split_collateral_for_full_cancel() {
+        let split = split_collateral(&[4_000, 9_000, 6_000]);
+        assert_eq!(
+            selected_values(&select_cancellation_collateral(&split, 19_000).unwrap()),
+            vec![4_000, 9_000, 6_000]
+        );
+
+        let fragmented = split_collateral(&[1_000; 5]);
+        assert!(matches!(
+            select_cancellation_collateral(&fragmented, 5_000),
+            Err(Error::CollateralInputCount { count: 5, max: 4 })
+        ));
+    }
+
     #[test]
     fn build_lmsr_bootstrap_pset_puts_reserves_first_and_tracks_change_blinding() {
         let request = sample_lmsr_create_request();
@@ -4322,4 +6838,20 @@ mod tests {
 
         assert!(!validate_prediction_market_creation_tx(&params, &tx, &anchor).unwrap());
     }
+
+    #[test]
+    fn repriced_order_amount_rounds_quote_to_whole_lots() {
+        assert_eq!(
+            repriced_order_amount(OrderDirection::SellBase, 1_000, 300),
+            1_000
+        );
+        assert_eq!(
+            repriced_order_amount(OrderDirection::SellQuote, 1_000, 300),
+            900
+        );
+        assert_eq!(
+            repriced_order_amount(OrderDirection::SellQuote, 200, 300),
+            0
+        );
+    }
 }
@@ -0,0 +1,113 @@
+//! Structured operation spans for the SDK's transaction-building flows.
+//!
+//! With the `telemetry` feature enabled, an [`OpSpan`] logs its start, every
+//! recorded field, and its outcome under the [`TARGET`] log target, tagged
+//! with a per-process span id so interleaved operations can be told apart.
+//! Without the feature the span is zero-sized and every call is an inlined
+//! no-op.
+
+/// Log target for operation spans, so they can be filtered independently.
+pub const TARGET: &str = "deadcat_sdk::op";
+
+#[cfg(feature = "telemetry")]
+mod imp {
+    use std::fmt::Display;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Instant;
+
+    use super::TARGET;
+
+    static NEXT_SPAN_ID: AtomicU64 = AtomicU64::new(1);
+
+    pub(crate) struct OpSpan {
+        name: &'static str,
+        id: u64,
+        started: Instant,
+        finished: bool,
+    }
+
+    impl OpSpan {
+        pub(crate) fn enter(name: &'static str) -> Self {
+            let id = NEXT_SPAN_ID.fetch_add(1, Ordering::Relaxed);
+            log::debug!(target: TARGET, "op={name} span={id} event=start");
+            Self {
+                name,
+                id,
+                started: Instant::now(),
+                finished: false,
+            }
+        }
+
+        pub(crate) fn record(&self, key: &str, value: &dyn Display) {
+            log::debug!(
+                target: TARGET,
+                "op={} span={} {key}={value}",
+                self.name,
+                self.id
+            );
+        }
+
+        pub(crate) fn finish(self, txid: &dyn Display) {
+            self.complete("broadcast", txid);
+        }
+
+        pub(crate) fn finish_dry_run(self, txid: &dyn Display) {
+            self.complete("dry_run", txid);
+        }
+
+        fn complete(mut self, event: &str, txid: &dyn Display) {
+            self.finished = true;
+            log::info!(
+                target: TARGET,
+                "op={} span={} event={event} txid={txid} elapsed_ms={}",
+                self.name,
+                self.id,
+                self.started.elapsed().as_millis()
+            );
+        }
+    }
+
+    impl Drop for OpSpan {
+        fn drop(&mut self) {
+            if !self.finished {
+                log::warn!(
+                    target: TARGET,
+                    "op={} span={} event=failed elapsed_ms={}",
+                    self.name,
+                    self.id,
+                    self.started.elapsed().as_millis()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "telemetry"))]
+mod imp {
+    use std::fmt::Display;
+
+    pub(crate) struct OpSpan;
+
+    impl OpSpan {
+        #[inline(always)]
+        pub(crate) fn enter(_name: &'static str) -> Self {
+            Self
+        }
+
+        #[inline(always)]
+        pub(crate) fn record(&self, _key: &str, _value: &dyn Display) {}
+
+        #[inline(always)]
+        pub(crate) fn finish(self, _txid: &dyn Display) {}
+
+        #[inline(always)]
+        pub(crate) fn finish_dry_run(self, _txid: &dyn Display) {}
+    }
+}
+
+/// A span around one SDK operation, from input validation to broadcast.
+///
+/// Dropping the span without calling [`OpSpan::finish`] (or
+/// [`OpSpan::finish_dry_run`] when nothing was broadcast) logs the operation
+/// as failed, so early `?` returns are captured without extra plumbing.
+pub(crate) use imp::OpSpan;
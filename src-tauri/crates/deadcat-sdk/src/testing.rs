@@ -6,6 +6,9 @@
 
 use std::sync::Arc;
 
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
 use lwk_wollet::elements_miniscript::confidential::slip77::MasterBlindingKey;
 use simplicityhl::elements::confidential::{Asset, Nonce, Value as ConfValue};
 use simplicityhl::elements::hashes::Hash;
@@ -23,24 +26,30 @@ use simplicityhl::simplicity::bit_machine::BitMachine;
 use simplicityhl::simplicity::jet::elements::{ElementsEnv, ElementsUtxo};
 
 use crate::assembly::pset_to_pruning_transaction;
+use crate::entropy::{EntropySource, seeded_rng};
 use crate::error::{Error, Result};
+use crate::fee_estimate::witness_stack_len;
 use crate::prediction_market::anchor::PredictionMarketAnchor;
 use crate::prediction_market::assembly::{
-    IssuanceAssemblyInputs, assemble_cancellation, assemble_expire_transition,
-    assemble_expiry_redemption, assemble_oracle_resolve, assemble_post_resolution_redemption,
-    blind_issuance_pset, build_issuance_pset,
+    AssembledPset, BlindingAudit, IssuanceAssemblyInputs, assemble_cancellation,
+    assemble_expire_transition, assemble_expiry_redemption, assemble_oracle_resolve,
+    assemble_post_resolution_redemption, blind_issuance_pset, build_issuance_pset,
+    collateral_spends,
 };
 use crate::prediction_market::contract::CompiledPredictionMarket;
 use crate::prediction_market::oracle::oracle_message;
+use crate::prediction_market::params::amounts::collateral_for_pairs;
 use crate::prediction_market::params::{PredictionMarketParams, compute_issuance_assets};
 use crate::prediction_market::pset::cancellation::CancellationParams;
 use crate::prediction_market::pset::expire_transition::ExpireTransitionParams;
 use crate::prediction_market::pset::expiry_redemption::ExpiryRedemptionParams;
 use crate::prediction_market::pset::oracle_resolve::OracleResolveParams;
 use crate::prediction_market::pset::post_resolution_redemption::PostResolutionRedemptionParams;
+use crate::prediction_market::pset::{collateral_input_indices, collateral_total};
 use crate::prediction_market::state::{MarketSlot, MarketState};
 use crate::prediction_market::witness::{
     AllBlindingFactors, PredictionMarketSpendingPath, ReissuanceBlindingFactors, satisfy_contract,
+    satisfy_contract_with_env, serialize_satisfied,
 };
 use crate::pset::UnblindedUtxo;
 
@@ -284,6 +293,8 @@ pub struct AssembledEnvTx {
     pub tx: Arc<Transaction>,
     pub utxos: Vec<ElementsUtxo>,
     pub covenant_inputs: Vec<EnvCovenantInput>,
+    /// Blinding factors chosen while assembling `tx`.
+    pub audit: BlindingAudit,
 }
 
 pub type TestCancellationParams = CancellationParams;
@@ -293,9 +304,10 @@ pub type TestOracleResolveParams = OracleResolveParams;
 pub type TestPostResolutionRedemptionParams = PostResolutionRedemptionParams;
 
 fn pset_to_env_tx(
-    pset: PartiallySignedTransaction,
+    assembled: AssembledPset,
     covenant_inputs: Vec<EnvCovenantInput>,
 ) -> Result<AssembledEnvTx> {
+    let AssembledPset { pset, audit } = assembled;
     let tx = Arc::new(pset_to_pruning_transaction(&pset)?);
     let utxos = pset
         .inputs()
@@ -314,6 +326,7 @@ fn pset_to_env_tx(
         tx,
         utxos,
         covenant_inputs,
+        audit,
     })
 }
 
@@ -361,6 +374,25 @@ fn recover_rt_blinding_factors(
     })
 }
 
+/// Covenant inputs of a spend over `collateral_count` collateral UTXOs, the
+/// extras merged in from `first_merge_index`.
+fn collateral_env_inputs(
+    collateral_count: usize,
+    first_merge_index: usize,
+    slot: MarketSlot,
+    primary: PredictionMarketSpendingPath,
+) -> Result<Vec<EnvCovenantInput>> {
+    let indices = collateral_input_indices(collateral_count, first_merge_index)?;
+    Ok(collateral_spends(&indices, slot, primary)
+        .into_iter()
+        .map(|(input_index, slot, path)| EnvCovenantInput {
+            input_index: input_index as u32,
+            slot,
+            path,
+        })
+        .collect())
+}
+
 pub fn assemble_issuance_for_env(inputs: IssuanceAssemblyInputs) -> Result<AssembledEnvTx> {
     let state = inputs.current_state;
     let yes_rt_input = inputs.yes_reissuance_utxo.clone();
@@ -369,7 +401,7 @@ pub fn assemble_issuance_for_env(inputs: IssuanceAssemblyInputs) -> Result<Assem
     let slip77_key = test_slip77_master_blinding_key();
     let blinding_pubkey = test_blinding_pubkey(&change_spk);
     let mut pset = build_issuance_pset(&inputs)?;
-    blind_issuance_pset(&mut pset, &inputs, blinding_pubkey)?;
+    let outputs = blind_issuance_pset(&mut pset, &inputs, blinding_pubkey, &mut test_rng())?;
     let blinding =
         recover_rt_blinding_factors(&pset, &slip77_key, &change_spk, &yes_rt_input, &no_rt_input)?;
 
@@ -406,7 +438,16 @@ pub fn assemble_issuance_for_env(inputs: IssuanceAssemblyInputs) -> Result<Assem
         other => return Err(Error::NotIssuable(other)),
     };
 
-    pset_to_env_tx(pset, covenant_inputs)
+    pset_to_env_tx(
+        AssembledPset {
+            pset,
+            audit: BlindingAudit {
+                outputs,
+                reissuance: Some(blinding),
+            },
+        },
+        covenant_inputs,
+    )
 }
 
 pub fn assemble_post_resolution_redemption_for_env(
@@ -416,17 +457,19 @@ pub fn assemble_post_resolution_redemption_for_env(
     let resolved_state = params.resolved_state;
     let tokens_burned = params.tokens_burned;
     let blinding_pubkey = test_blinding_pubkey(&test_change_script());
-    let pset = assemble_post_resolution_redemption(contract, &params, blinding_pubkey)?;
+    let assembled =
+        assemble_post_resolution_redemption(contract, &params, blinding_pubkey, &mut test_rng())?;
     let slot = resolved_state
         .collateral_slot()
         .ok_or(Error::InvalidState)?;
-    let covenant_inputs = vec![EnvCovenantInput {
-        input_index: 0,
+    let covenant_inputs = collateral_env_inputs(
+        params.collateral_utxos.len(),
+        1,
         slot,
-        path: PredictionMarketSpendingPath::PostResolutionRedemption { tokens_burned },
-    }];
+        PredictionMarketSpendingPath::PostResolutionRedemption { tokens_burned },
+    )?;
 
-    pset_to_env_tx(pset, covenant_inputs)
+    pset_to_env_tx(assembled, covenant_inputs)
 }
 
 pub fn assemble_expiry_redemption_for_env(
@@ -436,17 +479,19 @@ pub fn assemble_expiry_redemption_for_env(
     let tokens_burned = params.tokens_burned;
     let burn_token_asset = params.burn_token_asset;
     let blinding_pubkey = test_blinding_pubkey(&test_change_script());
-    let pset = assemble_expiry_redemption(contract, &params, blinding_pubkey)?;
-    let covenant_inputs = vec![EnvCovenantInput {
-        input_index: 0,
-        slot: MarketSlot::ExpiredCollateral,
-        path: PredictionMarketSpendingPath::ExpiryRedemption {
+    let assembled =
+        assemble_expiry_redemption(contract, &params, blinding_pubkey, &mut test_rng())?;
+    let covenant_inputs = collateral_env_inputs(
+        params.collateral_utxos.len(),
+        1,
+        MarketSlot::ExpiredCollateral,
+        PredictionMarketSpendingPath::ExpiryRedemption {
             tokens_burned,
             burn_token_asset,
         },
-    }];
+    )?;
 
-    pset_to_env_tx(pset, covenant_inputs)
+    pset_to_env_tx(assembled, covenant_inputs)
 }
 
 pub fn assemble_cancellation_for_env(
@@ -456,37 +501,47 @@ pub fn assemble_cancellation_for_env(
     let change_spk = test_change_script();
     let slip77_key = test_slip77_master_blinding_key();
     let blinding_pubkey = test_blinding_pubkey(&change_spk);
-    let is_full = params.collateral_utxo.value
-        == params
-            .pairs_burned
-            .checked_mul(2)
-            .and_then(|v| v.checked_mul(contract.params().collateral_per_token))
-            .ok_or(Error::CollateralOverflow)?;
+    let is_full = collateral_total(&params.collateral_utxos)?
+        == collateral_for_pairs(params.pairs_burned, contract.params().collateral_per_token)?;
+    let collateral_count = params.collateral_utxos.len();
     let yes_rt_input = params.yes_reissuance_utxo.clone();
     let no_rt_input = params.no_reissuance_utxo.clone();
     let pairs_burned = params.pairs_burned;
 
-    let pset = assemble_cancellation(contract, &params, &slip77_key, blinding_pubkey, &change_spk)?;
+    let assembled = assemble_cancellation(
+        contract,
+        &params,
+        &slip77_key,
+        blinding_pubkey,
+        &change_spk,
+        &mut test_rng(),
+    )?;
 
     let covenant_inputs = if is_full {
         let yes_rt_input = yes_rt_input.ok_or(Error::MissingReissuanceUtxos)?;
         let no_rt_input = no_rt_input.ok_or(Error::MissingReissuanceUtxos)?;
         let blinding = recover_rt_blinding_factors(
-            &pset,
+            &assembled.pset,
             &slip77_key,
             &change_spk,
             &yes_rt_input,
             &no_rt_input,
         )?;
-        vec![
-            EnvCovenantInput {
-                input_index: 0,
-                slot: MarketSlot::UnresolvedCollateral,
-                path: PredictionMarketSpendingPath::CancellationFullPrimary {
-                    pairs_burned,
-                    blinding,
-                },
+        // Keep `covenant_inputs` in input order: the RT secondaries sit
+        // between the primary collateral and any merged collateral.
+        let mut collateral_inputs = collateral_env_inputs(
+            collateral_count,
+            3,
+            MarketSlot::UnresolvedCollateral,
+            PredictionMarketSpendingPath::CancellationFullPrimary {
+                pairs_burned,
+                blinding,
             },
+        )?
+        .into_iter();
+        let mut covenant_inputs: Vec<EnvCovenantInput> =
+            collateral_inputs.next().into_iter().collect();
+        covenant_inputs.extend([
             EnvCovenantInput {
                 input_index: 1,
                 slot: MarketSlot::UnresolvedYesRt,
@@ -497,16 +552,19 @@ pub fn assemble_cancellation_for_env(
                 slot: MarketSlot::UnresolvedNoRt,
                 path: PredictionMarketSpendingPath::CancellationFullSecondaryNoRt { blinding },
             },
-        ]
+        ]);
+        covenant_inputs.extend(collateral_inputs);
+        covenant_inputs
     } else {
-        vec![EnvCovenantInput {
-            input_index: 0,
-            slot: MarketSlot::UnresolvedCollateral,
-            path: PredictionMarketSpendingPath::CancellationPartial { pairs_burned },
-        }]
+        collateral_env_inputs(
+            collateral_count,
+            1,
+            MarketSlot::UnresolvedCollateral,
+            PredictionMarketSpendingPath::CancellationPartial { pairs_burned },
+        )?
     };
 
-    pset_to_env_tx(pset, covenant_inputs)
+    pset_to_env_tx(assembled, covenant_inputs)
 }
 
 pub fn assemble_expire_transition_for_env(
@@ -518,7 +576,7 @@ pub fn assemble_expire_transition_for_env(
     let change_spk = test_change_script();
     let slip77_key = test_slip77_master_blinding_key();
     let blinding_pubkey = test_blinding_pubkey(&change_spk);
-    let pset = assemble_expire_transition(
+    let assembled = assemble_expire_transition(
         contract,
         &params,
         &slip77_key,
@@ -526,9 +584,15 @@ pub fn assemble_expire_transition_for_env(
         &change_spk,
         &yes_rt_input,
         &no_rt_input,
+        &mut test_rng(),
+    )?;
+    let blinding = recover_rt_blinding_factors(
+        &assembled.pset,
+        &slip77_key,
+        &change_spk,
+        &yes_rt_input,
+        &no_rt_input,
     )?;
-    let blinding =
-        recover_rt_blinding_factors(&pset, &slip77_key, &change_spk, &yes_rt_input, &no_rt_input)?;
     let covenant_inputs = vec![
         EnvCovenantInput {
             input_index: 0,
@@ -547,7 +611,7 @@ pub fn assemble_expire_transition_for_env(
         },
     ];
 
-    pset_to_env_tx(pset, covenant_inputs)
+    pset_to_env_tx(assembled, covenant_inputs)
 }
 
 pub fn assemble_oracle_resolve_for_env(
@@ -561,7 +625,7 @@ pub fn assemble_oracle_resolve_for_env(
     let change_spk = test_change_script();
     let slip77_key = test_slip77_master_blinding_key();
     let blinding_pubkey = test_blinding_pubkey(&change_spk);
-    let pset = assemble_oracle_resolve(
+    let assembled = assemble_oracle_resolve(
         contract,
         &params,
         oracle_signature,
@@ -570,9 +634,15 @@ pub fn assemble_oracle_resolve_for_env(
         &change_spk,
         &yes_rt_input,
         &no_rt_input,
+        &mut test_rng(),
+    )?;
+    let blinding = recover_rt_blinding_factors(
+        &assembled.pset,
+        &slip77_key,
+        &change_spk,
+        &yes_rt_input,
+        &no_rt_input,
     )?;
-    let blinding =
-        recover_rt_blinding_factors(&pset, &slip77_key, &change_spk, &yes_rt_input, &no_rt_input)?;
     let covenant_inputs = vec![
         EnvCovenantInput {
             input_index: 0,
@@ -595,7 +665,72 @@ pub fn assemble_oracle_resolve_for_env(
         },
     ];
 
-    pset_to_env_tx(pset, covenant_inputs)
+    pset_to_env_tx(assembled, covenant_inputs)
+}
+
+/// Attach the witness stacks assembly would produce, pruned against `case`'s
+/// own transaction, to its covenant inputs. Returns the finished transaction
+/// and the total serialized size of those stacks.
+pub fn attach_case_witnesses(
+    contract: &CompiledPredictionMarket,
+    case: &AssembledEnvTx,
+) -> std::result::Result<(Transaction, u64), String> {
+    let mut tx = (*case.tx).clone();
+    let mut witness_bytes = 0;
+    for spend in &case.covenant_inputs {
+        let cb_bytes = contract.control_block(spend.slot);
+        let control_block =
+            ControlBlock::from_slice(&cb_bytes).map_err(|e| format!("control block: {e}"))?;
+        let env = ElementsEnv::new(
+            Arc::clone(&case.tx),
+            case.utxos.clone(),
+            spend.input_index,
+            *contract.cmr(),
+            control_block,
+            None,
+            BlockHash::all_zeros(),
+        );
+        let satisfied = satisfy_contract_with_env(contract, &spend.path, spend.slot, Some(&env))
+            .map_err(|e| format!("satisfy: {e}"))?;
+        let (program_bytes, wit_bytes) = serialize_satisfied(&satisfied);
+        let stack = vec![
+            wit_bytes,
+            program_bytes,
+            contract.cmr().to_byte_array().to_vec(),
+            cb_bytes,
+        ];
+        witness_bytes += witness_stack_len(&stack);
+        tx.input[spend.input_index as usize].witness.script_witness = stack;
+    }
+    Ok((tx, witness_bytes))
+}
+
+// ---------------------------------------------------------------------------
+// Deterministic entropy
+// ---------------------------------------------------------------------------
+
+/// Seeded [`EntropySource`] for reproducible tests: the same seed always
+/// yields the same order nonces and blinding factors.
+pub struct FixedEntropy(StdRng);
+
+impl FixedEntropy {
+    pub fn new(seed: [u8; 32]) -> Self {
+        Self(StdRng::from_seed(seed))
+    }
+}
+
+impl EntropySource for FixedEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+}
+
+/// Seed used by the `assemble_*_for_env` helpers, so their blinded outputs
+/// are identical from run to run.
+pub const TEST_ENTROPY_SEED: [u8; 32] = [0x5e; 32];
+
+fn test_rng() -> StdRng {
+    seeded_rng(&mut FixedEntropy::new(TEST_ENTROPY_SEED))
 }
 
 // ---------------------------------------------------------------------------
@@ -619,6 +754,7 @@ use crate::lmsr_pool::identity::{derive_lmsr_market_id, derive_lmsr_pool_id};
 use crate::lmsr_pool::params::{LmsrInitialOutpoint, LmsrPoolParams};
 use crate::maker_order::params::{MakerOrderParams, OrderDirection};
 use crate::network::Network;
+use crate::node::PoolVerificationStatus;
 use crate::pool::PoolReserves;
 use crate::taproot::NUMS_KEY_BYTES;
 
@@ -632,6 +768,7 @@ pub struct TestStore {
     pub pools: Vec<LmsrPoolIngestInput>,
     pub pool_states: Vec<LmsrPoolStateUpdateInput>,
     pub price_history: Vec<LmsrPriceHistoryEntry>,
+    pub pool_verifications: std::collections::HashMap<String, PoolVerificationStatus>,
 }
 
 fn should_preserve_canonical_lmsr_state(
@@ -934,6 +1071,16 @@ impl NodeStore for TestStore {
             limit,
         ))
     }
+
+    fn record_lmsr_pool_verification(
+        &mut self,
+        pool_id: &str,
+        status: &PoolVerificationStatus,
+    ) -> std::result::Result<(), String> {
+        self.pool_verifications
+            .insert(pool_id.to_string(), status.clone());
+        Ok(())
+    }
 }
 
 fn filter_test_price_history<F>(
@@ -14,6 +14,7 @@ use crate::lmsr_pool::params::LmsrPoolParams;
 use crate::lmsr_pool::table::LmsrTableManifest;
 use crate::maker_order::params::OrderDirection;
 use crate::pool::PoolReserves;
+use crate::prediction_market::params::PredictionMarketParams;
 use crate::pset::UnblindedUtxo;
 
 use super::types::*;
@@ -524,6 +525,117 @@ pub(crate) fn plan_to_route_legs(plan: &ExecutionPlan, orders: &[ScannedOrder])
     legs
 }
 
+/// Route `total_input` through the pool alone, the orders alone, and both,
+/// charging `fee_amount` to each, and recommend one.
+///
+/// A route whose venue is empty, cannot take any of the input or yields no
+/// output is `None`.
+pub(crate) fn compare_routes(
+    lmsr_pool: Option<&ScannedLmsrPool>,
+    orders: &[ScannedOrder],
+    side: TradeSide,
+    direction: TradeDirection,
+    total_input: u64,
+    fee_amount: u64,
+    market: &PredictionMarketParams,
+) -> TradeRouteComparison {
+    let route = |pool: Option<&ScannedLmsrPool>, orders: &[ScannedOrder]| {
+        let plan = build_execution_plan(
+            pool,
+            orders,
+            side,
+            direction,
+            total_input,
+            &market.collateral_asset_id,
+            &market.yes_token_asset,
+            &market.no_token_asset,
+        )
+        .ok()?;
+        route_outcome(&plan, orders, direction, total_input, fee_amount)
+    };
+
+    let via_pool = lmsr_pool.and_then(|pool| route(Some(pool), &[]));
+    let via_orders = if orders.is_empty() {
+        None
+    } else {
+        route(None, orders)
+    };
+    let via_split = if lmsr_pool.is_some() && !orders.is_empty() {
+        route(lmsr_pool, orders)
+    } else {
+        None
+    };
+
+    let mut recommended: Option<(TradeVenue, &RouteOutcome)> = None;
+    for (venue, outcome) in [
+        (TradeVenue::Pool, &via_pool),
+        (TradeVenue::Orders, &via_orders),
+        (TradeVenue::Split, &via_split),
+    ] {
+        let Some(outcome) = outcome else { continue };
+        let better = match recommended {
+            None => true,
+            Some((_, best)) => route_beats(outcome, best, direction),
+        };
+        if better {
+            recommended = Some((venue, outcome));
+        }
+    }
+    let recommended = recommended.map(|(venue, _)| venue);
+
+    TradeRouteComparison {
+        via_pool,
+        via_orders,
+        via_split,
+        recommended,
+    }
+}
+
+fn route_outcome(
+    plan: &ExecutionPlan,
+    orders: &[ScannedOrder],
+    direction: TradeDirection,
+    total_input: u64,
+    fee_amount: u64,
+) -> Option<RouteOutcome> {
+    // Without a pool the plan reports the requested input, so sum the legs.
+    let placed = plan
+        .order_legs
+        .iter()
+        .map(|leg| leg.taker_pays)
+        .chain(plan.lmsr_pool_leg.as_ref().map(|leg| leg.delta_in))
+        .fold(0u64, u64::saturating_add);
+    let output = plan.total_taker_output;
+    let net_price = match direction {
+        TradeDirection::Buy => placed.saturating_add(fee_amount) as f64 / output as f64,
+        TradeDirection::Sell => output.saturating_sub(fee_amount) as f64 / placed as f64,
+    };
+    // Nothing placed or nothing received has no price to compare.
+    if !net_price.is_finite() {
+        return None;
+    }
+    Some(RouteOutcome {
+        total_input: placed,
+        total_output: output,
+        unfilled_input: total_input.saturating_sub(placed),
+        net_price,
+        legs: plan_to_route_legs(plan, orders),
+    })
+}
+
+/// Whether `candidate` is strictly better than `best`. Ties keep `best`, so
+/// the simpler route listed first wins.
+fn route_beats(candidate: &RouteOutcome, best: &RouteOutcome, direction: TradeDirection) -> bool {
+    let fills = |o: &RouteOutcome| o.unfilled_input == 0;
+    if fills(candidate) != fills(best) {
+        return fills(candidate);
+    }
+    match direction {
+        TradeDirection::Buy => candidate.net_price < best.net_price,
+        TradeDirection::Sell => candidate.net_price > best.net_price,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -794,6 +906,78 @@ mod tests {
         assert!(matches!(plan, Err(Error::NoLiquidity)));
     }
 
+    fn mock_market() -> PredictionMarketParams {
+        PredictionMarketParams {
+            oracle_public_key: [0xaa; 32],
+            collateral_asset_id: lbtc_asset(),
+            yes_token_asset: yes_asset(),
+            no_token_asset: no_asset(),
+            yes_reissuance_token: [0x05; 32],
+            no_reissuance_token: [0x06; 32],
+            collateral_per_token: 100,
+            expiry_time: 1_000,
+        }
+    }
+
+    #[test]
+    fn compare_routes_recommends_split_when_orders_beat_pool() {
+        let lmsr_pool = mock_lmsr_pool();
+        let comparison = compare_routes(
+            Some(&lmsr_pool),
+            &[mock_sell_base_order(1, 10)],
+            TradeSide::Yes,
+            TradeDirection::Buy,
+            10_000,
+            500,
+            &mock_market(),
+        );
+
+        let via_pool = comparison.via_pool.unwrap();
+        assert_eq!(via_pool.total_input, 3_115);
+        assert_eq!(via_pool.total_output, 30);
+        assert_eq!(via_pool.unfilled_input, 10_000 - 3_115);
+        assert_eq!(via_pool.net_price, 3_615.0 / 30.0);
+        // Ten lots cannot absorb the input and there is no pool to take the rest.
+        assert!(comparison.via_orders.is_none());
+        let via_split = comparison.via_split.unwrap();
+        assert!(via_split.total_output > via_pool.total_output);
+        assert!(via_split.net_price < via_pool.net_price);
+        assert_eq!(comparison.recommended, Some(TradeVenue::Split));
+    }
+
+    #[test]
+    fn compare_routes_without_liquidity_recommends_nothing() {
+        let comparison = compare_routes(
+            None,
+            &[],
+            TradeSide::Yes,
+            TradeDirection::Buy,
+            10_000,
+            500,
+            &mock_market(),
+        );
+        assert!(comparison.via_pool.is_none());
+        assert!(comparison.via_orders.is_none());
+        assert!(comparison.via_split.is_none());
+        assert_eq!(comparison.recommended, None);
+    }
+
+    #[test]
+    fn route_without_output_has_no_outcome() {
+        let plan = ExecutionPlan {
+            order_legs: Vec::new(),
+            lmsr_pool_leg: None,
+            taker_send_asset: lbtc_asset(),
+            taker_receive_asset: yes_asset(),
+            total_taker_input: 10_000,
+            total_taker_output: 0,
+            quoted_reserves: None,
+        };
+        for direction in [TradeDirection::Buy, TradeDirection::Sell] {
+            assert!(route_outcome(&plan, &[], direction, 10_000, 500).is_none());
+        }
+    }
+
     // ── Sell-side helper ────────────────────────────────────────────────
 
     /// Create a SellQuote order (maker sells L-BTC, buys tokens).
@@ -88,6 +88,46 @@ pub enum LiquiditySource {
     },
 }
 
+// ── Route comparison ────────────────────────────────────────────────────
+
+/// The set of venues a compared route draws liquidity from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradeVenue {
+    /// The market's LMSR pool alone.
+    Pool,
+    /// Limit orders alone.
+    Orders,
+    /// Limit orders that beat the pool, then the pool.
+    Split,
+}
+
+/// One route's result in a [`TradeRouteComparison`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteOutcome {
+    /// Amount of the send asset the route places.
+    pub total_input: u64,
+    /// Amount of the receive asset the route delivers, before the network fee.
+    pub total_output: u64,
+    /// Part of the requested input the route's liquidity could not absorb.
+    pub unfilled_input: u64,
+    /// L-BTC per token with the network fee included: paid on top of the
+    /// input for Buy, deducted from the output for Sell.
+    pub net_price: f64,
+    pub legs: Vec<RouteLeg>,
+}
+
+/// The same exact-input trade routed through each venue set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeRouteComparison {
+    pub via_pool: Option<RouteOutcome>,
+    pub via_orders: Option<RouteOutcome>,
+    /// `None` unless both a pool and orders are available.
+    pub via_split: Option<RouteOutcome>,
+    /// Prefers routes that place the whole input, then the best net price.
+    /// `None` when no venue has liquidity.
+    pub recommended: Option<TradeVenue>,
+}
+
 // ── Execution plan (crate-internal) ─────────────────────────────────────
 
 /// Complete plan for executing a routed trade. Contains all the data
@@ -0,0 +1,50 @@
+use std::sync::{Arc, Mutex};
+
+use lwk_wollet::elements::encode::{deserialize, serialize};
+use lwk_wollet::elements::hashes::Hash as _;
+use lwk_wollet::elements::{Transaction, Txid};
+
+use crate::discovery::DiscoveryStore;
+
+/// Lookaside cache consulted by `DeadcatSdk::fetch_transaction` before going
+/// to Electrum.
+///
+/// A txid commits to everything but the witness, so a cached entry never goes
+/// stale for the purposes of reading outputs and unblinding. Cache failures
+/// are not errors: a miss or a failed write just falls back to the chain.
+pub trait TransactionCache: Send + Sync {
+    fn get_transaction(&self, txid: &Txid) -> Option<Transaction>;
+    fn put_transaction(&self, tx: &Transaction);
+}
+
+/// Cache that never holds anything; every fetch goes to the chain.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoTransactionCache;
+
+impl TransactionCache for NoTransactionCache {
+    fn get_transaction(&self, _txid: &Txid) -> Option<Transaction> {
+        None
+    }
+
+    fn put_transaction(&self, _tx: &Transaction) {}
+}
+
+/// Backs the cache with the node's shared persistence store.
+impl<S: DiscoveryStore> TransactionCache for Arc<Mutex<S>> {
+    fn get_transaction(&self, txid: &Txid) -> Option<Transaction> {
+        let raw = self
+            .lock()
+            .ok()?
+            .get_cached_transaction(&txid.to_byte_array())
+            .ok()??;
+        deserialize::<Transaction>(&raw)
+            .ok()
+            .filter(|tx| tx.txid() == *txid)
+    }
+
+    fn put_transaction(&self, tx: &Transaction) {
+        if let Ok(mut store) = self.lock() {
+            let _ = store.cache_transaction(&tx.txid().to_byte_array(), &serialize(tx));
+        }
+    }
+}
@@ -40,8 +40,10 @@ async fn market_announce_discover_roundtrip() {
     let (announcement, params) = test_market_announcement(oracle_pubkey, 0x11);
 
     // Publish
-    let event_id = service.announce_market(&announcement).await.unwrap();
-    assert!(!event_id.to_hex().is_empty());
+    let report = service.announce_market(&announcement).await.unwrap();
+    assert!(!report.event_id.to_hex().is_empty());
+    assert_eq!(report.accepted.len(), 1);
+    assert!(report.warning().is_none());
 
     tokio::time::sleep(Duration::from_millis(200)).await;
 
@@ -81,7 +83,11 @@ async fn order_announce_discover_roundtrip() {
     let announcement = test_order_announcement("market123");
 
     // Publish
-    let event_id = service.announce_order(&announcement).await.unwrap();
+    let event_id = service
+        .announce_order(&announcement)
+        .await
+        .unwrap()
+        .event_id;
     assert!(!event_id.to_hex().is_empty());
 
     tokio::time::sleep(Duration::from_millis(200)).await;
@@ -226,7 +232,11 @@ async fn attestation_roundtrip() {
     let market_id = params.market_id();
 
     // First publish the announcement
-    let ann_event_id = service.announce_market(&announcement).await.unwrap();
+    let ann_event_id = service
+        .announce_market(&announcement)
+        .await
+        .unwrap()
+        .event_id;
 
     tokio::time::sleep(Duration::from_millis(200)).await;
 
@@ -314,3 +324,61 @@ async fn fetch_orders_filters_by_market() {
         "filtered orders should include marketAAA"
     );
 }
+
+#[tokio::test]
+async fn deleted_order_is_dropped_from_discovery() {
+    let mock = MockRelay::run().await.unwrap();
+    let (service, _rx, _store, _keys) = setup_service_with_store(&mock.url()).await;
+
+    let kept = service
+        .announce_order(&test_order_announcement("marketKEEP"))
+        .await
+        .unwrap()
+        .event_id;
+    let deleted = service
+        .announce_order(&test_order_announcement("marketDROP"))
+        .await
+        .unwrap()
+        .event_id;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let report = service
+        .delete_announcement(&deleted.to_hex())
+        .await
+        .unwrap();
+    assert!(report.warning().is_none());
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let ids: Vec<String> = service
+        .fetch_orders(None)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|order| order.id)
+        .collect();
+    assert!(ids.contains(&kept.to_hex()));
+    assert!(!ids.contains(&deleted.to_hex()));
+}
+
+#[tokio::test]
+async fn author_fetch_also_queries_their_relays() {
+    let home = MockRelay::run().await.unwrap();
+    let niche = MockRelay::run().await.unwrap();
+    let (service, _rx, _store, _keys) = setup_service_with_store(&home.url()).await;
+    let (maker, _maker_rx, _maker_store, maker_keys) = setup_service_with_store(&niche.url()).await;
+
+    let event_id = maker
+        .announce_order(&test_order_announcement("marketNICHE"))
+        .await
+        .unwrap()
+        .event_id;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    assert!(service.fetch_orders(None).await.unwrap().is_empty());
+    let orders = service
+        .fetch_orders_by_author(maker_keys.public_key(), &[niche.url()])
+        .await
+        .unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].id, event_id.to_hex());
+}
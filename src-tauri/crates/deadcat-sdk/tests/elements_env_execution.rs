@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
-use deadcat_sdk::elements::confidential::Value as ConfValue;
+use deadcat_sdk::elements::confidential::{
+    AssetBlindingFactor, Value as ConfValue, ValueBlindingFactor,
+};
 use deadcat_sdk::elements::hashes::Hash;
-use deadcat_sdk::elements::secp256k1_zkp::ZERO_TWEAK;
+use deadcat_sdk::elements::secp256k1_zkp::{Secp256k1, ZERO_TWEAK};
 use deadcat_sdk::elements::{
     AssetIssuance, LockTime, OutPoint, Script, Sequence, Transaction, TxIn, Txid,
 };
@@ -12,15 +14,17 @@ use deadcat_sdk::testing::{
     TestOracleResolveParams, TestPostResolutionRedemptionParams, assemble_cancellation_for_env,
     assemble_expire_transition_for_env, assemble_expiry_redemption_for_env,
     assemble_issuance_for_env, assemble_oracle_resolve_for_env,
-    assemble_post_resolution_redemption_for_env, confidential_rt_txout, execute_against_env,
-    explicit_txout, issuance_txin, simple_txin, test_blinding, test_change_script,
-    test_confidential_rt_utxo, test_contract_params, test_contract_params_with_defining_outpoints,
-    test_contract_params_with_oracle_pubkey, test_explicit_utxo, test_issuance_entropy,
-    test_oracle_keypair, test_oracle_signature, test_outpoint, test_script,
+    assemble_post_resolution_redemption_for_env, attach_case_witnesses, confidential_rt_txout,
+    execute_against_env, explicit_txout, issuance_txin, simple_txin, test_blinding,
+    test_change_script, test_confidential_rt_utxo, test_contract_params,
+    test_contract_params_with_defining_outpoints, test_contract_params_with_oracle_pubkey,
+    test_explicit_utxo, test_issuance_entropy, test_oracle_keypair, test_oracle_signature,
+    test_outpoint, test_script, test_slip77_master_blinding_key,
 };
 use deadcat_sdk::{
-    AllBlindingFactors, CollateralSource, CompiledPredictionMarket, IssuanceAssemblyInputs,
-    MarketSlot, MarketState, PredictionMarketSpendingPath,
+    AllBlindingFactors, CollateralSource, CompiledPredictionMarket, CovenantOp,
+    IssuanceAssemblyInputs, MarketSlot, MarketState, PredictionMarketSpendingPath,
+    estimate_covenant_vbytes, estimate_covenant_witness_bytes,
 };
 
 fn fee_output(asset: &[u8; 32], amount: u64) -> deadcat_sdk::elements::TxOut {
@@ -61,6 +65,26 @@ fn assert_case_input_executes(
     assert!(result.is_ok(), "{result:?}");
 }
 
+fn assert_estimate_covers_case(
+    contract: &CompiledPredictionMarket,
+    case: &AssembledEnvTx,
+    op: CovenantOp,
+) {
+    let (tx, witness_bytes) = attach_case_witnesses(contract, case).expect("attach witnesses");
+    let estimated_witness = estimate_covenant_witness_bytes(contract, op).expect("estimate");
+    assert!(
+        estimated_witness >= witness_bytes,
+        "{op:?}: estimated {estimated_witness} < assembled {witness_bytes} witness bytes"
+    );
+
+    let vsize = tx.weight().div_ceil(4) as u64;
+    let estimated_vsize = estimate_covenant_vbytes(contract, op).expect("estimate");
+    assert!(
+        estimated_vsize >= vsize,
+        "{op:?}: estimated {estimated_vsize} < assembled {vsize} vbytes"
+    );
+}
+
 fn build_subsequent_issuance_case() -> (CompiledPredictionMarket, AssembledEnvTx) {
     let yes_defining_outpoint = test_outpoint(0xa1);
     let no_defining_outpoint = test_outpoint(0xa2);
@@ -111,6 +135,7 @@ fn build_subsequent_issuance_case() -> (CompiledPredictionMarket, AssembledEnvTx
         fee_utxo: test_explicit_utxo(&params.collateral_asset_id, 1_000, &test_script(2), 0x15),
         pairs,
         fee_amount: 1_000,
+        fee_asset_id: params.collateral_asset_id,
         token_destination: test_script(3),
         change_destination: None,
         issuance_entropy: test_issuance_entropy(
@@ -318,12 +343,12 @@ fn build_post_resolution_redemption_case(
     let case = assemble_post_resolution_redemption_for_env(
         &contract,
         TestPostResolutionRedemptionParams {
-            collateral_utxo: test_explicit_utxo(
+            collateral_utxos: vec![test_explicit_utxo(
                 &params.collateral_asset_id,
                 2_000_000,
                 &contract.script_pubkey(collateral_slot),
                 0x41,
-            ),
+            )],
             token_utxos: vec![test_explicit_utxo(&burn_asset, 5, &test_script(6), 0x42)],
             fee_utxo: test_explicit_utxo(&params.collateral_asset_id, 1_000, &test_script(7), 0x43),
             tokens_burned: 5,
@@ -351,12 +376,12 @@ fn build_expiry_redemption_case(burn_yes: bool) -> (CompiledPredictionMarket, As
     let case = assemble_expiry_redemption_for_env(
         &contract,
         TestExpiryRedemptionParams {
-            collateral_utxo: test_explicit_utxo(
+            collateral_utxos: vec![test_explicit_utxo(
                 &params.collateral_asset_id,
                 2_000_000,
                 &contract.script_pubkey(MarketSlot::ExpiredCollateral),
                 0x51,
-            ),
+            )],
             token_utxos: vec![test_explicit_utxo(
                 &burn_token_asset,
                 5,
@@ -389,12 +414,12 @@ fn build_partial_cancellation_case() -> (CompiledPredictionMarket, AssembledEnvT
     let case = assemble_cancellation_for_env(
         &contract,
         TestCancellationParams {
-            collateral_utxo: test_explicit_utxo(
+            collateral_utxos: vec![test_explicit_utxo(
                 &params.collateral_asset_id,
                 2_000_000,
                 &contract.script_pubkey(MarketSlot::UnresolvedCollateral),
                 0x61,
-            ),
+            )],
             yes_reissuance_utxo: None,
             no_reissuance_utxo: None,
             yes_token_utxos: vec![test_explicit_utxo(
@@ -417,6 +442,7 @@ fn build_partial_cancellation_case() -> (CompiledPredictionMarket, AssembledEnvT
             ),
             pairs_burned: 5,
             fee_amount: 1_000,
+            fee_asset_id: params.collateral_asset_id,
             refund_destination: test_script(15),
             fee_change_destination: None,
             token_change_destination: None,
@@ -434,12 +460,12 @@ fn build_full_cancellation_case() -> (CompiledPredictionMarket, AssembledEnvTx)
     let case = assemble_cancellation_for_env(
         &contract,
         TestCancellationParams {
-            collateral_utxo: test_explicit_utxo(
+            collateral_utxos: vec![test_explicit_utxo(
                 &params.collateral_asset_id,
                 2_000_000,
                 &contract.script_pubkey(MarketSlot::UnresolvedCollateral),
                 0x71,
-            ),
+            )],
             yes_reissuance_utxo: Some(test_confidential_rt_utxo(
                 &params.yes_reissuance_token,
                 &contract.script_pubkey(MarketSlot::UnresolvedYesRt),
@@ -469,6 +495,7 @@ fn build_full_cancellation_case() -> (CompiledPredictionMarket, AssembledEnvTx)
             fee_utxo: test_explicit_utxo(&params.collateral_asset_id, 1_000, &test_script(3), 0x76),
             pairs_burned: 10,
             fee_amount: 1_000,
+            fee_asset_id: params.collateral_asset_id,
             refund_destination: test_script(4),
             fee_change_destination: None,
             token_change_destination: None,
@@ -479,6 +506,177 @@ fn build_full_cancellation_case() -> (CompiledPredictionMarket, AssembledEnvTx)
     (contract, case)
 }
 
+fn build_split_partial_cancellation_case() -> (CompiledPredictionMarket, AssembledEnvTx) {
+    let params = test_contract_params();
+    let contract = CompiledPredictionMarket::new(params).expect("compile");
+    let collateral_spk = contract.script_pubkey(MarketSlot::UnresolvedCollateral);
+    let case = assemble_cancellation_for_env(
+        &contract,
+        TestCancellationParams {
+            collateral_utxos: vec![
+                test_explicit_utxo(
+                    &params.collateral_asset_id,
+                    1_200_000,
+                    &collateral_spk,
+                    0x81,
+                ),
+                test_explicit_utxo(
+                    &params.collateral_asset_id,
+                    1_000_000,
+                    &collateral_spk,
+                    0x82,
+                ),
+            ],
+            yes_reissuance_utxo: None,
+            no_reissuance_utxo: None,
+            yes_token_utxos: vec![test_explicit_utxo(
+                &params.yes_token_asset,
+                10,
+                &test_script(12),
+                0x83,
+            )],
+            no_token_utxos: vec![test_explicit_utxo(
+                &params.no_token_asset,
+                10,
+                &test_script(13),
+                0x84,
+            )],
+            fee_utxo: test_explicit_utxo(
+                &params.collateral_asset_id,
+                1_000,
+                &test_script(14),
+                0x85,
+            ),
+            pairs_burned: 10,
+            fee_amount: 1_000,
+            fee_asset_id: params.collateral_asset_id,
+            refund_destination: test_script(15),
+            fee_change_destination: None,
+            token_change_destination: None,
+        },
+    )
+    .expect("assemble split partial cancel");
+
+    (contract, case)
+}
+
+fn build_split_full_cancellation_case() -> (CompiledPredictionMarket, AssembledEnvTx) {
+    let params = test_contract_params();
+    let contract = CompiledPredictionMarket::new(params).expect("compile");
+    let blinding = test_blinding();
+    let collateral_spk = contract.script_pubkey(MarketSlot::UnresolvedCollateral);
+    let case = assemble_cancellation_for_env(
+        &contract,
+        TestCancellationParams {
+            collateral_utxos: vec![
+                test_explicit_utxo(
+                    &params.collateral_asset_id,
+                    1_200_000,
+                    &collateral_spk,
+                    0x91,
+                ),
+                test_explicit_utxo(&params.collateral_asset_id, 800_000, &collateral_spk, 0x92),
+            ],
+            yes_reissuance_utxo: Some(test_confidential_rt_utxo(
+                &params.yes_reissuance_token,
+                &contract.script_pubkey(MarketSlot::UnresolvedYesRt),
+                &blinding.yes.input_abf,
+                &blinding.yes.input_vbf,
+                0x93,
+            )),
+            no_reissuance_utxo: Some(test_confidential_rt_utxo(
+                &params.no_reissuance_token,
+                &contract.script_pubkey(MarketSlot::UnresolvedNoRt),
+                &blinding.no.input_abf,
+                &blinding.no.input_vbf,
+                0x94,
+            )),
+            yes_token_utxos: vec![test_explicit_utxo(
+                &params.yes_token_asset,
+                10,
+                &test_script(1),
+                0x95,
+            )],
+            no_token_utxos: vec![test_explicit_utxo(
+                &params.no_token_asset,
+                10,
+                &test_script(2),
+                0x96,
+            )],
+            fee_utxo: test_explicit_utxo(&params.collateral_asset_id, 1_000, &test_script(3), 0x97),
+            pairs_burned: 10,
+            fee_amount: 1_000,
+            fee_asset_id: params.collateral_asset_id,
+            refund_destination: test_script(4),
+            fee_change_destination: None,
+            token_change_destination: None,
+        },
+    )
+    .expect("assemble split full cancel");
+
+    (contract, case)
+}
+
+fn build_split_post_resolution_redemption_case() -> (CompiledPredictionMarket, AssembledEnvTx) {
+    let params = test_contract_params();
+    let contract = CompiledPredictionMarket::new(params).expect("compile");
+    let collateral_spk = contract.script_pubkey(MarketSlot::ResolvedYesCollateral);
+    let case = assemble_post_resolution_redemption_for_env(
+        &contract,
+        TestPostResolutionRedemptionParams {
+            collateral_utxos: vec![
+                test_explicit_utxo(
+                    &params.collateral_asset_id,
+                    1_000_000,
+                    &collateral_spk,
+                    0xa1,
+                ),
+                test_explicit_utxo(
+                    &params.collateral_asset_id,
+                    1_000_000,
+                    &collateral_spk,
+                    0xa2,
+                ),
+            ],
+            token_utxos: vec![test_explicit_utxo(
+                &params.yes_token_asset,
+                8,
+                &test_script(6),
+                0xa3,
+            )],
+            fee_utxo: test_explicit_utxo(&params.collateral_asset_id, 1_000, &test_script(7), 0xa4),
+            tokens_burned: 8,
+            resolved_state: MarketState::ResolvedYes,
+            fee_amount: 1_000,
+            payout_destination: test_script(8),
+            fee_change_destination: None,
+            token_change_destination: None,
+        },
+    )
+    .expect("assemble split resolved redemption");
+
+    (contract, case)
+}
+
+/// Append a same-slot collateral input to an assembled transaction.
+fn with_extra_collateral_input(
+    tx: &Transaction,
+    utxos: &[ElementsUtxo],
+    collateral_spk: &Script,
+    tag: u8,
+) -> (Arc<Transaction>, Vec<ElementsUtxo>) {
+    let params = test_contract_params();
+    let mut tx = tx.clone();
+    tx.input.push(simple_txin(test_outpoint(tag)));
+    let mut utxos = utxos.to_vec();
+    utxos.push(ElementsUtxo::from(explicit_txout(
+        &params.collateral_asset_id,
+        500_000,
+        collateral_spk,
+    )));
+    (Arc::new(tx), utxos)
+}
+
 #[test]
 fn initial_issuance_primary_executes() {
     let params = test_contract_params();
@@ -677,6 +875,36 @@ fn oracle_resolve_secondary_collateral_yes_executes() {
     assert_case_input_executes(&contract, case, 2);
 }
 
+#[test]
+fn oracle_resolve_audit_factors_unblind_outputs() {
+    let (_, case) = build_oracle_resolve_case(true);
+    let secp = Secp256k1::new();
+    let blinding_sk = test_slip77_master_blinding_key().blinding_private_key(&test_change_script());
+
+    // YES RT, NO RT and the fee change output are blinded.
+    assert_eq!(case.audit.outputs.len(), 3);
+    for (idx, factors) in &case.audit.outputs {
+        let secrets = case.tx.output[*idx]
+            .unblind(&secp, blinding_sk)
+            .expect("unblind audited output");
+        assert_eq!(
+            secrets.asset_bf,
+            AssetBlindingFactor::from_slice(&factors.asset_bf).unwrap()
+        );
+        assert_eq!(
+            secrets.value_bf,
+            ValueBlindingFactor::from_slice(&factors.value_bf).unwrap()
+        );
+    }
+
+    let reissuance = case
+        .audit
+        .reissuance
+        .expect("resolve cycles reissuance tokens");
+    assert_eq!(reissuance.yes.output_abf, case.audit.outputs[&0].asset_bf);
+    assert_eq!(reissuance.no.output_vbf, case.audit.outputs[&1].value_bf);
+}
+
 #[test]
 fn oracle_resolve_primary_yes_executes_without_change() {
     let (contract, case) = build_oracle_resolve_no_change_case(true);
@@ -791,6 +1019,115 @@ fn cancellation_full_secondary_no_rt_executes() {
     assert_case_input_executes(&contract, case, 2);
 }
 
+#[test]
+fn cancellation_partial_merges_split_collateral() {
+    for input in 0..2 {
+        let (contract, case) = build_split_partial_cancellation_case();
+        assert_eq!(case.covenant_inputs.len(), 2);
+        assert_case_input_executes(&contract, case, input);
+    }
+}
+
+#[test]
+fn cancellation_full_merges_split_collateral() {
+    for input in 0..4 {
+        let (contract, case) = build_split_full_cancellation_case();
+        assert_eq!(case.covenant_inputs[3].input_index, 3);
+        assert_case_input_executes(&contract, case, input);
+    }
+}
+
+#[test]
+fn post_resolution_redemption_merges_split_collateral() {
+    for input in 0..2 {
+        let (contract, case) = build_split_post_resolution_redemption_case();
+        assert_case_input_executes(&contract, case, input);
+    }
+}
+
+#[test]
+fn cancellation_partial_rejects_uncounted_collateral() {
+    // Sneaking another collateral UTXO in without returning it to the
+    // covenant must fail: the primary counts it toward the remainder.
+    let (contract, case) = build_partial_cancellation_case();
+    let collateral_spk = contract.script_pubkey(MarketSlot::UnresolvedCollateral);
+    let (tx, utxos) = with_extra_collateral_input(&case.tx, &case.utxos, &collateral_spk, 0xb1);
+
+    let primary = &case.covenant_inputs[0];
+    let result = execute_against_env(&contract, primary.slot, &primary.path, tx, utxos, 0);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn collateral_merge_rejects_input_past_window() {
+    let (contract, case) = build_split_partial_cancellation_case();
+    let collateral_spk = contract.script_pubkey(MarketSlot::UnresolvedCollateral);
+    let (tx, utxos) = with_extra_collateral_input(&case.tx, &case.utxos, &collateral_spk, 0xb2);
+    let (tx, utxos) = with_extra_collateral_input(&tx, &utxos, &collateral_spk, 0xb3);
+    let index = (tx.input.len() - 1) as u32;
+    assert!(index > 5);
+
+    let result = execute_against_env(
+        &contract,
+        MarketSlot::UnresolvedCollateral,
+        &PredictionMarketSpendingPath::CollateralMergeSecondary,
+        tx,
+        utxos,
+        index,
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn cancellation_full_assembly_is_reproducible() {
+    // Blinding draws from the fixed test entropy, so RT outputs come out identical.
+    let (_, first) = build_full_cancellation_case();
+    let (_, second) = build_full_cancellation_case();
+    assert_eq!(first.tx, second.tx);
+}
+
+#[test]
+fn issuance_fee_estimate_covers_assembled_witnesses() {
+    let (contract, case) = build_subsequent_issuance_case();
+    assert_estimate_covers_case(&contract, &case, CovenantOp::IssueTokens);
+}
+
+#[test]
+fn cancellation_fee_estimate_covers_partial_and_full() {
+    let (contract, case) = build_partial_cancellation_case();
+    assert_estimate_covers_case(&contract, &case, CovenantOp::CancelTokens);
+    let (contract, case) = build_full_cancellation_case();
+    assert_estimate_covers_case(&contract, &case, CovenantOp::CancelTokens);
+}
+
+#[test]
+fn oracle_resolve_fee_estimate_covers_assembled_witnesses() {
+    let (contract, case) = build_oracle_resolve_case(true);
+    assert_estimate_covers_case(&contract, &case, CovenantOp::ResolveMarket);
+}
+
+#[test]
+fn expire_transition_fee_estimate_covers_assembled_witnesses() {
+    let (contract, case) = build_expire_transition_case();
+    assert_estimate_covers_case(&contract, &case, CovenantOp::ExpireMarket);
+}
+
+#[test]
+fn post_resolution_redemption_fee_estimate_covers_both_outcomes() {
+    for outcome_yes in [true, false] {
+        let (contract, case) = build_post_resolution_redemption_case(outcome_yes);
+        assert_estimate_covers_case(&contract, &case, CovenantOp::RedeemResolved);
+    }
+}
+
+#[test]
+fn expiry_redemption_fee_estimate_covers_assembled_witnesses() {
+    let (contract, case) = build_expiry_redemption_case(true);
+    assert_estimate_covers_case(&contract, &case, CovenantOp::RedeemExpired);
+}
+
 #[test]
 fn post_resolution_redemption_rejects_unresolved_rt_slot() {
     let params = test_contract_params();
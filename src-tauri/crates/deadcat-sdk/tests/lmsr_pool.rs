@@ -8,8 +8,8 @@ use deadcat_sdk::taproot::NUMS_KEY_BYTES;
 use deadcat_sdk::testing::TestStore;
 use deadcat_sdk::{
     CreateLmsrPoolRequest, DeadcatNode, DiscoveryConfig, Error, LiquiditySource, LmsrPoolId,
-    LmsrPoolParams, Network, NodeError, PoolReserves, PredictionMarketParams, TradeAmount,
-    TradeDirection, TradeSide,
+    LmsrPoolParams, Network, NodeError, PoolReserves, PoolVerificationStatus,
+    PredictionMarketParams, TradeAmount, TradeDirection, TradeSide,
 };
 use lwk_test_util::{TEST_MNEMONIC, TestEnv, TestEnvBuilder, regtest_policy_asset};
 use nostr_relay_builder::prelude::MockRelay;
@@ -480,6 +480,30 @@ async fn scan_lmsr_pool_bootstraps_empty_store_regtest() {
     );
 }
 
+#[tokio::test]
+async fn verify_pool_records_verified_live_pool_regtest() {
+    let _guard = hold_test_lock();
+    let fixture = Fixture::new().await;
+    let (request, created) = bootstrap_pool(&fixture).await;
+    let pool_id = created.snapshot.locator.pool_id.to_hex();
+
+    let verification = fixture
+        .node
+        .verify_pool(&pool_id)
+        .await
+        .expect("verify pool");
+    assert_eq!(verification.status, PoolVerificationStatus::Verified);
+    let snapshot = verification.snapshot.expect("canonical snapshot");
+    assert_eq!(snapshot.current_s_index, request.initial_s_index);
+    assert_eq!(snapshot.reserves, request.initial_reserves);
+
+    let store = fixture.store.lock().unwrap();
+    assert_eq!(
+        store.pool_verifications.get(&pool_id),
+        Some(&PoolVerificationStatus::Verified)
+    );
+}
+
 /// Bootstrap a pool with a real admin cosigner (not NUMS).
 ///
 /// Returns the pool_index used, the create request, and the create result.
@@ -472,7 +472,7 @@ async fn lmsr_mixed_maker_and_pool_route_executes_regtest() {
     let _guard = hold_test_lock();
     let fixture = RegtestFixture::new().await;
 
-    let (_create_result, _event_id) = fixture
+    let (_create_result, _report) = fixture
         .node
         .create_limit_order(
             fixture.yes_asset,
@@ -144,7 +144,7 @@ async fn node_announce_and_fetch_market() {
     let (announcement, _params) = test_market_announcement(oracle_pubkey, 0x11);
 
     // Announce via node (Nostr-only, no on-chain)
-    let event_id = node.announce_market(&announcement).await.unwrap();
+    let event_id = node.announce_market(&announcement).await.unwrap().event_id;
     assert!(!event_id.to_hex().is_empty());
 
     tokio::time::sleep(Duration::from_millis(200)).await;
@@ -179,7 +179,8 @@ async fn node_announce_and_fetch_order() {
         .discovery()
         .announce_order(&announcement)
         .await
-        .unwrap();
+        .unwrap()
+        .event_id;
     assert!(!event_id.to_hex().is_empty());
 
     tokio::time::sleep(Duration::from_millis(200)).await;
@@ -197,6 +198,24 @@ async fn node_announce_and_fetch_order() {
     assert_eq!(s.orders.len(), 1);
 }
 
+#[tokio::test]
+async fn node_live_orders_drop_unverifiable_covenants() {
+    let mock = MockRelay::run().await.unwrap();
+    let (node, _rx, _store, _keys) = setup_node_with_store(&mock.url()).await;
+
+    // The test announcement's covenant address can't be parsed, so there is
+    // nothing to scan and the order can't be confirmed live.
+    node.discovery()
+        .announce_order(&test_order_announcement("market123"))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    assert_eq!(node.fetch_orders(None).await.unwrap().len(), 1);
+    let live = node.discover_live_orders(None).await.unwrap();
+    assert!(live.is_empty());
+}
+
 #[tokio::test]
 async fn node_attestation() {
     let mock = MockRelay::run().await.unwrap();
@@ -207,7 +226,7 @@ async fn node_attestation() {
     let market_id = params.market_id();
 
     // First publish the announcement
-    let ann_event_id = node.announce_market(&announcement).await.unwrap();
+    let ann_event_id = node.announce_market(&announcement).await.unwrap().event_id;
 
     tokio::time::sleep(Duration::from_millis(200)).await;
 
@@ -267,6 +286,23 @@ async fn node_subscription_delivers_events() {
     let _ = publisher.disconnect().await;
 }
 
+#[tokio::test]
+async fn verify_pool_needs_chain_access_for_known_pools() {
+    let (node, store, _keys) = setup_node_with_deadcat_store();
+    let ingest = test_lmsr_pool_ingest_input(deadcat_sdk::Network::LiquidTestnet, 0x41);
+    store.lock().unwrap().ingest_lmsr_pool(&ingest).unwrap();
+
+    // A locked wallet can't scan, which must not be mistaken for a bad pool.
+    match node.verify_pool(&ingest.pool_id).await {
+        Err(NodeError::WalletLocked) => {}
+        other => panic!("expected WalletLocked, got {other:?}"),
+    }
+    match node.verify_pool("00").await {
+        Err(NodeError::Store(msg)) => assert!(msg.contains("unknown LMSR pool_id")),
+        other => panic!("expected unknown pool error, got {other:?}"),
+    }
+}
+
 #[test]
 fn resolve_lmsr_pool_locator_round_trips_real_store_params_json_for_local_pools() {
     let (node, store, _keys) = setup_node_with_deadcat_store();
@@ -1,11 +1,13 @@
 use deadcat_sdk::PredictionMarketParams;
 use deadcat_sdk::{DeadcatSdk, MarketState, OrderDirection, PredictionMarketAnchor};
+use lwk_common::Signer;
 use lwk_signer::SwSigner;
 use lwk_test_util::{
     TEST_MNEMONIC, TestEnv, TestEnvBuilder, generate_mnemonic, regtest_policy_asset,
 };
 use lwk_wollet::blocking::BlockchainBackend;
 use lwk_wollet::elements::Txid;
+use lwk_wollet::elements::confidential::{AssetBlindingFactor, ValueBlindingFactor};
 use lwk_wollet::elements::secp256k1_zkp::{Keypair, Message, Secp256k1, XOnlyPublicKey};
 use lwk_wollet::{ElectrumClient, ElectrumUrl, Wollet};
 use tempfile::TempDir;
@@ -398,7 +400,7 @@ fn test_initial_issuance_from_dormant() {
     // Issue 5 pairs (5 YES + 5 NO tokens).
     let issuance = fixture
         .sdk
-        .issue_tokens(&params, &creation_txid, 5, 500)
+        .issue_tokens(&params, &creation_txid, 5, 500, None, false)
         .unwrap();
 
     assert_eq!(issuance.previous_state, MarketState::Dormant);
@@ -416,6 +418,39 @@ fn test_initial_issuance_from_dormant() {
     assert_eq!(*balance.get(&no_asset).unwrap_or(&0), 5);
 }
 
+#[test]
+fn test_issuance_dry_run_returns_unsigned_pset() {
+    let mut fixture = TestFixture::new();
+    fixture.fund_and_sync(10, 500_000);
+
+    let (creation_txid, params) = fixture
+        .sdk
+        .create_contract_onchain(test_oracle_pubkey(), 10_000, 500_000, 1_000, 500)
+        .unwrap();
+
+    fixture.mine_and_sync(1);
+
+    let preview = fixture
+        .sdk
+        .issue_tokens(&params, &creation_txid, 5, 500, None, true)
+        .unwrap();
+    assert_eq!(preview.previous_state, MarketState::Dormant);
+    assert_eq!(preview.new_state, MarketState::Unresolved);
+    let pset_b64 = preview.unsigned_pset.expect("dry run returns the PSET");
+    let pset: lwk_wollet::elements::pset::PartiallySignedTransaction = pset_b64.parse().unwrap();
+    assert_eq!(pset.extract_tx().unwrap().txid(), preview.txid);
+
+    // Nothing was broadcast: the market is still Dormant and a real issuance
+    // from the same state succeeds.
+    fixture.mine_and_sync(1);
+    let issuance = fixture
+        .sdk
+        .issue_tokens(&params, &creation_txid, 5, 500, None, false)
+        .unwrap();
+    assert_eq!(issuance.previous_state, MarketState::Dormant);
+    assert!(issuance.unsigned_pset.is_none());
+}
+
 #[test]
 fn test_subsequent_issuance_from_unresolved() {
     let mut fixture = TestFixture::new();
@@ -433,7 +468,7 @@ fn test_subsequent_issuance_from_unresolved() {
     // First issuance: Dormant → Unresolved.
     let issuance1 = fixture
         .sdk
-        .issue_tokens(&params, &creation_txid, 3, 500)
+        .issue_tokens(&params, &creation_txid, 3, 500, None, false)
         .unwrap();
     assert_eq!(issuance1.previous_state, MarketState::Dormant);
     assert_eq!(issuance1.new_state, MarketState::Unresolved);
@@ -443,7 +478,7 @@ fn test_subsequent_issuance_from_unresolved() {
     // Second issuance: Unresolved → Unresolved.
     let issuance2 = fixture
         .sdk
-        .issue_tokens(&params, &creation_txid, 2, 500)
+        .issue_tokens(&params, &creation_txid, 2, 500, None, false)
         .unwrap();
     assert_eq!(issuance2.previous_state, MarketState::Unresolved);
     assert_eq!(issuance2.new_state, MarketState::Unresolved);
@@ -471,6 +506,30 @@ fn test_new_with_invalid_mnemonic() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_new_rejects_datadir_of_another_network() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let network_dir = temp_dir.path().join("regtest");
+    std::fs::create_dir_all(&network_dir).unwrap();
+    std::fs::write(
+        network_dir.join(deadcat_sdk::NETWORK_MARKER_FILE),
+        "testnet",
+    )
+    .unwrap();
+
+    let result = DeadcatSdk::new(
+        mnemonic,
+        deadcat_sdk::Network::LiquidRegtest,
+        "tcp://localhost:50001",
+        temp_dir.path(),
+    );
+    assert!(matches!(
+        result,
+        Err(deadcat_sdk::Error::NetworkMismatch { .. })
+    ));
+}
+
 #[test]
 fn test_send_lbtc_invalid_address() {
     let mut fixture = TestFixture::new();
@@ -519,7 +578,7 @@ fn create_and_issue(
 
     let _issuance = fixture
         .sdk
-        .issue_tokens(&params, &creation_txid, pairs, 500)
+        .issue_tokens(&params, &creation_txid, pairs, 500, None, false)
         .unwrap();
 
     fixture.mine_and_sync(1);
@@ -548,7 +607,7 @@ fn test_full_cancellation_and_reissuance() {
     // Full cancel: burn all 5 pairs → Unresolved → Dormant
     let cancel = fixture
         .sdk
-        .cancel_tokens(&params, &creation_txid, 5, 500)
+        .cancel_tokens(&params, &creation_txid, 5, 500, None, false)
         .unwrap();
 
     assert_eq!(cancel.previous_state, MarketState::Unresolved);
@@ -566,7 +625,7 @@ fn test_full_cancellation_and_reissuance() {
     // Re-issue 3 pairs: Dormant → Unresolved
     let reissue = fixture
         .sdk
-        .issue_tokens(&params, &creation_txid, 3, 500)
+        .issue_tokens(&params, &creation_txid, 3, 500, None, false)
         .unwrap();
 
     assert_eq!(reissue.previous_state, MarketState::Dormant);
@@ -595,7 +654,7 @@ fn test_partial_cancellation() {
     // Cancel 3 of 10 pairs
     let cancel = fixture
         .sdk
-        .cancel_tokens(&params, &creation_txid, 3, 500)
+        .cancel_tokens(&params, &creation_txid, 3, 500, None, false)
         .unwrap();
 
     assert_eq!(cancel.previous_state, MarketState::Unresolved);
@@ -623,7 +682,7 @@ fn test_full_cancellation_with_exact_fee_utxo() {
 
     let cancel = fixture
         .sdk
-        .cancel_tokens(&params, &creation_txid, 5, 700)
+        .cancel_tokens(&params, &creation_txid, 5, 700, None, false)
         .unwrap();
 
     assert_eq!(cancel.previous_state, MarketState::Unresolved);
@@ -646,7 +705,7 @@ fn test_partial_cancellation_with_exact_fee_utxo() {
 
     let cancel = fixture
         .sdk
-        .cancel_tokens(&params, &creation_txid, 3, 700)
+        .cancel_tokens(&params, &creation_txid, 3, 700, None, false)
         .unwrap();
 
     assert_eq!(cancel.previous_state, MarketState::Unresolved);
@@ -668,7 +727,7 @@ fn test_oracle_resolve_and_redeem_yes() {
     let signature = oracle_sign(&params, true, &keypair);
     let resolve = fixture
         .sdk
-        .resolve_market(&params, &creation_txid, true, signature, 500)
+        .resolve_market(&params, &creation_txid, true, signature, 500, false)
         .unwrap();
 
     assert_eq!(resolve.previous_state, MarketState::Unresolved);
@@ -680,7 +739,7 @@ fn test_oracle_resolve_and_redeem_yes() {
     // Redeem YES tokens
     let redeem = fixture
         .sdk
-        .redeem_tokens(&params, &creation_txid, 5, 500)
+        .redeem_tokens(&params, &creation_txid, 5, 500, false)
         .unwrap();
 
     assert_eq!(redeem.previous_state, MarketState::ResolvedYes);
@@ -707,7 +766,7 @@ fn test_post_resolution_redeem_with_exact_fee_utxo() {
     let signature = oracle_sign(&params, true, &keypair);
     fixture
         .sdk
-        .resolve_market(&params, &creation_txid, true, signature, 500)
+        .resolve_market(&params, &creation_txid, true, signature, 500, false)
         .unwrap();
 
     fixture.mine_and_sync(1);
@@ -715,7 +774,7 @@ fn test_post_resolution_redeem_with_exact_fee_utxo() {
 
     let redeem = fixture
         .sdk
-        .redeem_tokens(&params, &creation_txid, 5, 700)
+        .redeem_tokens(&params, &creation_txid, 5, 700, false)
         .unwrap();
 
     assert_eq!(redeem.previous_state, MarketState::ResolvedYes);
@@ -739,7 +798,7 @@ fn test_oracle_resolve_with_exact_fee_utxo() {
     let signature = oracle_sign(&params, true, &keypair);
     let resolve = fixture
         .sdk
-        .resolve_market(&params, &creation_txid, true, signature, 700)
+        .resolve_market(&params, &creation_txid, true, signature, 700, false)
         .unwrap();
 
     assert_eq!(resolve.previous_state, MarketState::Unresolved);
@@ -760,7 +819,7 @@ fn test_oracle_resolve_and_redeem_no() {
     let signature = oracle_sign(&params, false, &keypair);
     let resolve = fixture
         .sdk
-        .resolve_market(&params, &creation_txid, false, signature, 500)
+        .resolve_market(&params, &creation_txid, false, signature, 500, false)
         .unwrap();
 
     assert_eq!(resolve.previous_state, MarketState::Unresolved);
@@ -772,7 +831,7 @@ fn test_oracle_resolve_and_redeem_no() {
     // Redeem NO tokens
     let redeem = fixture
         .sdk
-        .redeem_tokens(&params, &creation_txid, 5, 500)
+        .redeem_tokens(&params, &creation_txid, 5, 500, false)
         .unwrap();
 
     assert_eq!(redeem.previous_state, MarketState::ResolvedNo);
@@ -801,10 +860,28 @@ fn test_expiry_redemption() {
     // Generate blocks past expiry
     fixture.mine_and_sync(250);
 
+    // Nobody has expired the market yet, but the preview already prices
+    // the expiry path at 1x collateral.
+    let preview = fixture
+        .sdk
+        .preview_redemption(&params, &creation_txid, 5, Some(params.yes_token_asset))
+        .unwrap();
+    assert_eq!(preview.current_state, MarketState::Unresolved);
+    assert!(preview.is_expiry);
+    assert_eq!(preview.payout_sats, 50_000);
+    assert_eq!(preview.token_shortfall, 0);
+
     // Redeem YES tokens via expiry path
     let redeem = fixture
         .sdk
-        .redeem_expired(&params, &creation_txid, params.yes_token_asset, 5, 500)
+        .redeem_expired(
+            &params,
+            &creation_txid,
+            params.yes_token_asset,
+            5,
+            500,
+            false,
+        )
         .unwrap();
 
     assert_eq!(redeem.previous_state, MarketState::Expired);
@@ -836,7 +913,14 @@ fn test_expiry_redemption_with_exact_fee_auto_finalize() {
 
     let redeem = fixture
         .sdk
-        .redeem_expired(&params, &creation_txid, params.yes_token_asset, 5, 700)
+        .redeem_expired(
+            &params,
+            &creation_txid,
+            params.yes_token_asset,
+            5,
+            700,
+            false,
+        )
         .unwrap();
 
     assert_eq!(redeem.previous_state, MarketState::Expired);
@@ -862,7 +946,7 @@ fn test_multiple_subsequent_issuances() {
     // Issue 3 pairs: Dormant → Unresolved
     let iss1 = fixture
         .sdk
-        .issue_tokens(&params, &creation_txid, 3, 500)
+        .issue_tokens(&params, &creation_txid, 3, 500, None, false)
         .unwrap();
     assert_eq!(iss1.previous_state, MarketState::Dormant);
     assert_eq!(iss1.new_state, MarketState::Unresolved);
@@ -872,7 +956,7 @@ fn test_multiple_subsequent_issuances() {
     // Issue 2 more: Unresolved → Unresolved
     let iss2 = fixture
         .sdk
-        .issue_tokens(&params, &creation_txid, 2, 500)
+        .issue_tokens(&params, &creation_txid, 2, 500, None, false)
         .unwrap();
     assert_eq!(iss2.previous_state, MarketState::Unresolved);
 
@@ -881,7 +965,7 @@ fn test_multiple_subsequent_issuances() {
     // Issue 5 more: Unresolved → Unresolved
     let iss3 = fixture
         .sdk
-        .issue_tokens(&params, &creation_txid, 5, 500)
+        .issue_tokens(&params, &creation_txid, 5, 500, None, false)
         .unwrap();
     assert_eq!(iss3.previous_state, MarketState::Unresolved);
 
@@ -907,7 +991,7 @@ fn test_partial_post_resolution_redemption() {
     let signature = oracle_sign(&params, true, &keypair);
     fixture
         .sdk
-        .resolve_market(&params, &creation_txid, true, signature, 500)
+        .resolve_market(&params, &creation_txid, true, signature, 500, false)
         .unwrap();
 
     fixture.mine_and_sync(1);
@@ -915,7 +999,7 @@ fn test_partial_post_resolution_redemption() {
     // Redeem only 3 of 5 YES tokens (partial redemption with token change)
     let redeem = fixture
         .sdk
-        .redeem_tokens(&params, &creation_txid, 3, 500)
+        .redeem_tokens(&params, &creation_txid, 3, 500, false)
         .unwrap();
 
     assert_eq!(redeem.previous_state, MarketState::ResolvedYes);
@@ -942,7 +1026,7 @@ fn test_partial_post_resolution_redemption_with_exact_fee_utxo() {
     let signature = oracle_sign(&params, true, &keypair);
     fixture
         .sdk
-        .resolve_market(&params, &creation_txid, true, signature, 500)
+        .resolve_market(&params, &creation_txid, true, signature, 500, false)
         .unwrap();
 
     fixture.mine_and_sync(1);
@@ -950,7 +1034,7 @@ fn test_partial_post_resolution_redemption_with_exact_fee_utxo() {
 
     let redeem = fixture
         .sdk
-        .redeem_tokens(&params, &creation_txid, 3, 700)
+        .redeem_tokens(&params, &creation_txid, 3, 700, false)
         .unwrap();
 
     assert_eq!(redeem.previous_state, MarketState::ResolvedYes);
@@ -978,7 +1062,9 @@ fn test_cancel_wrong_state() {
     fixture.mine_and_sync(1);
 
     // Market is Dormant (no issuance) — cancellation should fail
-    let result = fixture.sdk.cancel_tokens(&params, &creation_txid, 1, 500);
+    let result = fixture
+        .sdk
+        .cancel_tokens(&params, &creation_txid, 1, 500, None, false);
     assert!(result.is_err(), "cancel should fail in Dormant state");
 }
 
@@ -991,7 +1077,9 @@ fn test_redeem_wrong_state() {
     let (creation_txid, params) = create_and_issue(&mut fixture, oracle_pubkey, 10_000, 500_000, 5);
 
     // Market is Unresolved — post-resolution redemption should fail
-    let result = fixture.sdk.redeem_tokens(&params, &creation_txid, 5, 500);
+    let result = fixture
+        .sdk
+        .redeem_tokens(&params, &creation_txid, 5, 500, false);
     assert!(result.is_err(), "redeem should fail in Unresolved state");
 }
 
@@ -1055,7 +1143,8 @@ fn test_create_and_cancel_limit_order() {
             &create_result.order_params,
             create_result.maker_base_pubkey,
             order_index,
-            500, // fee_amount
+            500,   // fee_amount
+            false, // outbid_pending_fill
         )
         .unwrap();
 
@@ -1068,6 +1157,64 @@ fn test_create_and_cancel_limit_order() {
     assert_eq!(*balance_after_cancel.get(&yes_asset).unwrap_or(&0), 10);
 }
 
+#[test]
+fn test_limit_order_blinding_audit_opens_change_outputs() {
+    let mut fixture = TestFixture::new();
+    fixture.fund_and_sync(20, 500_000);
+
+    let (_creation_txid, params) = issue_market_tokens(&mut fixture, 10);
+    let lbtc_bytes: [u8; 32] = regtest_policy_asset().into_inner().to_byte_array();
+
+    fixture.sdk.set_blinding_audit(true);
+    let create_result = fixture
+        .sdk
+        .create_limit_order(
+            params.yes_token_asset,
+            lbtc_bytes,
+            10,
+            5,
+            OrderDirection::SellBase,
+            1,
+            1,
+            12,
+            500,
+        )
+        .unwrap();
+    let audit = fixture
+        .sdk
+        .take_blinding_audit()
+        .expect("order creation was audited");
+    assert!(audit.reissuance.is_none());
+    // YES change and fee change are blinded; the covenant output is not.
+    assert!(audit.outputs.len() >= 2);
+    assert!(!audit.outputs.contains_key(&0));
+
+    fixture.mine_and_sync(1);
+    let tx = fixture.sdk.fetch_transaction(&create_result.txid).unwrap();
+    let slip77_key = SwSigner::new(TEST_MNEMONIC, false)
+        .unwrap()
+        .slip77_master_blinding_key()
+        .unwrap();
+    let secp = Secp256k1::new();
+    for (idx, factors) in &audit.outputs {
+        let output = &tx.output[*idx];
+        let secrets = output
+            .unblind(
+                &secp,
+                slip77_key.blinding_private_key(&output.script_pubkey),
+            )
+            .expect("unblind audited output");
+        assert_eq!(
+            secrets.asset_bf,
+            AssetBlindingFactor::from_slice(&factors.asset_bf).unwrap()
+        );
+        assert_eq!(
+            secrets.value_bf,
+            ValueBlindingFactor::from_slice(&factors.value_bf).unwrap()
+        );
+    }
+}
+
 #[test]
 fn test_create_and_full_fill_limit_order() {
     let mut fixture = TestFixture::new();
@@ -1183,7 +1330,8 @@ fn test_partial_fill_limit_order() {
             &create_result.order_params,
             create_result.maker_base_pubkey,
             order_index,
-            500, // fee_amount
+            500,   // fee_amount
+            false, // outbid_pending_fill
         )
         .unwrap();
 
@@ -1195,3 +1343,136 @@ fn test_partial_fill_limit_order() {
     let balance = fixture.sdk.balance().unwrap();
     assert_eq!(*balance.get(&yes_asset).unwrap_or(&0), 10);
 }
+
+// ── End-to-end lifecycle ────────────────────────────────────────────────
+
+fn asset_balance(fixture: &TestFixture, asset: lwk_wollet::elements::AssetId) -> u64 {
+    let balance = fixture.sdk.balance().unwrap();
+    *balance.get(&asset).unwrap_or(&0)
+}
+
+/// create → issue → trade through a limit order → resolve → redeem, checking
+/// the covenant state and wallet balances after every step.
+#[test]
+fn test_market_lifecycle_through_order_resolution_and_redemption() {
+    let mut fixture = TestFixture::new();
+    fixture.fund_and_sync(25, 500_000);
+
+    let lbtc = regtest_policy_asset();
+    let cpt = 10_000;
+    let pairs = 10;
+
+    // Create: the market starts dormant.
+    let (oracle_pubkey, keypair) = generate_oracle_keypair();
+    let (creation_txid, params) = fixture
+        .sdk
+        .create_contract_onchain(oracle_pubkey, cpt, 500_000, 1_000, 500)
+        .unwrap();
+    fixture.mine_and_sync(1);
+    assert_eq!(
+        fixture.sdk.market_state(&params, &creation_txid).unwrap(),
+        MarketState::Dormant
+    );
+
+    let yes_asset = lwk_wollet::elements::AssetId::from_slice(&params.yes_token_asset).unwrap();
+    let no_asset = lwk_wollet::elements::AssetId::from_slice(&params.no_token_asset).unwrap();
+
+    // Issue: each pair locks two tokens' worth of collateral.
+    let lbtc_before = asset_balance(&fixture, lbtc);
+    let issuance = fixture
+        .sdk
+        .issue_tokens(&params, &creation_txid, pairs, 500, None, false)
+        .unwrap();
+    assert_eq!(issuance.new_state, MarketState::Unresolved);
+    fixture.mine_and_sync(1);
+    assert_eq!(asset_balance(&fixture, yes_asset), pairs);
+    assert_eq!(asset_balance(&fixture, no_asset), pairs);
+    assert_eq!(
+        asset_balance(&fixture, lbtc),
+        lbtc_before - pairs * 2 * cpt - 500
+    );
+
+    // Trade: sell 5 YES through a limit order and fill it from the same
+    // wallet, so the tokens come back.
+    let lbtc_bytes: [u8; 32] = lbtc.into_inner().to_byte_array();
+    let order_index = 21u32;
+    let order = fixture
+        .sdk
+        .create_limit_order(
+            params.yes_token_asset,
+            lbtc_bytes,
+            10,
+            5,
+            OrderDirection::SellBase,
+            1,
+            1,
+            order_index,
+            500,
+        )
+        .unwrap();
+    fixture.mine_and_sync(1);
+    assert_eq!(asset_balance(&fixture, yes_asset), pairs - 5);
+
+    let fill = fixture
+        .sdk
+        .fill_limit_order(
+            &order.order_params,
+            order.maker_base_pubkey,
+            order.order_nonce,
+            5,
+            500,
+        )
+        .unwrap();
+    assert_eq!(fill.lots_filled, 5);
+    assert!(!fill.is_partial);
+    fixture.mine_and_sync(1);
+    assert_eq!(asset_balance(&fixture, yes_asset), pairs);
+
+    // Resolve YES: collateral stays locked, only the fee is spent.
+    let lbtc_before = asset_balance(&fixture, lbtc);
+    let signature = oracle_sign(&params, true, &keypair);
+    let resolve = fixture
+        .sdk
+        .resolve_market(&params, &creation_txid, true, signature, 500, false)
+        .unwrap();
+    assert_eq!(resolve.new_state, MarketState::ResolvedYes);
+    fixture.mine_and_sync(1);
+    assert_eq!(
+        fixture.sdk.market_state(&params, &creation_txid).unwrap(),
+        MarketState::ResolvedYes
+    );
+    assert_eq!(asset_balance(&fixture, lbtc), lbtc_before - 500);
+
+    // Redeem: the preview matches the payout, and winning tokens burn for
+    // their full collateral.
+    let preview = fixture
+        .sdk
+        .preview_redemption(&params, &creation_txid, pairs, None)
+        .unwrap();
+    assert!(!preview.is_expiry);
+    assert_eq!(preview.winning_asset, params.yes_token_asset);
+    assert_eq!(preview.payout_sats, pairs * 2 * cpt);
+    assert_eq!(preview.token_shortfall, 0);
+    assert_eq!(preview.collateral_shortfall_sats, 0);
+    let short = fixture
+        .sdk
+        .preview_redemption(&params, &creation_txid, pairs + 1, None)
+        .unwrap();
+    assert_eq!(short.token_shortfall, 1);
+    assert_eq!(short.collateral_shortfall_sats, 2 * cpt);
+
+    let lbtc_before = asset_balance(&fixture, lbtc);
+    let redeem = fixture
+        .sdk
+        .redeem_tokens(&params, &creation_txid, pairs, 500, false)
+        .unwrap();
+    assert_eq!(redeem.tokens_redeemed, pairs);
+    assert_eq!(redeem.payout_sats, pairs * 2 * cpt);
+    fixture.mine_and_sync(1);
+    assert_eq!(asset_balance(&fixture, yes_asset), 0);
+    assert_eq!(asset_balance(&fixture, no_asset), pairs);
+    assert_eq!(
+        asset_balance(&fixture, lbtc),
+        lbtc_before + redeem.payout_sats - 500
+    );
+}
@@ -15,17 +15,31 @@ pub enum ChainAdapterError {
 /// Adapter that implements `deadcat_store::ChainSource` using the `electrum-client` crate.
 pub struct ElectrumChainAdapter {
     electrum_url: String,
+    /// Socket timeout in whole seconds; `None` waits indefinitely.
+    timeout_secs: Option<u8>,
 }
 
 impl ElectrumChainAdapter {
     pub fn new(electrum_url: &str) -> Self {
         Self {
             electrum_url: electrum_url.to_string(),
+            timeout_secs: None,
         }
     }
 
+    /// Give up on reads and writes after `timeout`, rounded up to whole
+    /// seconds and capped at Electrum's `u8` limit.
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        let secs = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+        self.timeout_secs = Some(u8::try_from(secs.max(1)).unwrap_or(u8::MAX));
+        self
+    }
+
     fn client(&self) -> Result<electrum_client::Client, ChainAdapterError> {
-        electrum_client::Client::new(&self.electrum_url)
+        let config = electrum_client::ConfigBuilder::new()
+            .timeout(self.timeout_secs)
+            .build();
+        electrum_client::Client::from_config(&self.electrum_url, config)
             .map_err(|e| ChainAdapterError::Electrum(e.to_string()))
     }
 
@@ -1,10 +1,11 @@
 use std::sync::Mutex;
 use std::time::Duration;
 
-use deadcat_store::MarketFilter;
+use deadcat_store::{ChainSource, MarketFilter};
 use nostr_sdk::prelude::*;
 use serde::{Deserialize, Serialize};
 use tauri::{Emitter, Manager};
+use zeroize::Zeroizing;
 
 use crate::discovery::{
     self, ContractMetadata, CreateContractRequest, DiscoveredMarket, DiscoveredOrder,
@@ -33,8 +34,10 @@ fn validate_request(request: &CreateContractRequest) -> Result<(), String> {
 
 async fn compute_tip_and_now(
     network: crate::WalletNetwork,
+    esplora_url: &str,
+    timeout: Duration,
 ) -> Result<(crate::ChainTipResponse, u64), String> {
-    let tip = crate::fetch_chain_tip_inner(network).await?;
+    let tip = crate::fetch_chain_tip_inner(network, esplora_url, timeout).await?;
     let now_unix = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map_err(|e| format!("time error: {e}"))?
@@ -62,6 +65,45 @@ async fn bump_revision_and_emit<R: tauri::Runtime>(
     Ok(())
 }
 
+/// Map an SDK failure to a command error, replacing a covenant conflict
+/// (another transaction spent the covenant first) with `conflict_message`.
+/// The node is resynced on conflict so the store stops showing the spent
+/// covenant as live.
+async fn describe_node_error(
+    node: &deadcat_sdk::DeadcatNode<deadcat_store::DeadcatStore>,
+    err: deadcat_sdk::NodeError,
+    conflict_message: &str,
+) -> String {
+    let deadcat_sdk::NodeError::Sdk(deadcat_sdk::Error::CovenantConflict { outpoint }) = &err
+    else {
+        return format!("{err}");
+    };
+    log::warn!("broadcast lost a race for {outpoint}: {err}");
+    if let Err(e) = node.sync().await {
+        log::warn!("resync after covenant conflict failed: {e}");
+    }
+    conflict_message.to_string()
+}
+
+/// Check freshly ingested LMSR pools against the chain, so the store holds
+/// a verdict for each. Needs an unlocked wallet to scan; failures are only
+/// logged and leave any earlier verdict in place.
+async fn verify_ingested_pools(app: &tauri::AppHandle, pool_ids: Vec<String>) {
+    let node = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        guard.clone()
+    };
+    let Some(node) = node.filter(|node| node.is_wallet_unlocked()) else {
+        return;
+    };
+    for pool_id in pool_ids {
+        if let Err(e) = node.verify_pool(&pool_id).await {
+            log::warn!("could not verify LMSR pool {pool_id}: {e}");
+        }
+    }
+}
+
 /// Get Nostr keys and a connected client from the node.
 async fn get_keys_and_client(app: &tauri::AppHandle) -> Result<(Keys, nostr_sdk::Client), String> {
     let node_state = app.state::<NodeState>();
@@ -84,7 +126,9 @@ async fn get_keys_and_client(app: &tauri::AppHandle) -> Result<(Keys, nostr_sdk:
         for url in &relays {
             let _ = client.add_relay(url.as_str()).await;
         }
-        client.connect_with_timeout(Duration::from_secs(5)).await;
+        client
+            .connect_with_timeout(crate::network_timeouts(app).nostr_connect())
+            .await;
     }
 
     Ok((keys, client))
@@ -92,7 +136,7 @@ async fn get_keys_and_client(app: &tauri::AppHandle) -> Result<(Keys, nostr_sdk:
 
 /// Construct a DeadcatNode from loaded keys and store it in NodeState.
 /// Called whenever Nostr identity is loaded/generated/imported.
-async fn construct_and_store_node(
+pub(crate) async fn construct_and_store_node(
     app: &tauri::AppHandle,
     keys: nostr_sdk::Keys,
 ) -> Result<(), String> {
@@ -123,6 +167,7 @@ async fn construct_and_store_node(
     let config = deadcat_sdk::DiscoveryConfig {
         relays,
         network_tag: sdk_network.discovery_tag().to_string(),
+        fetch_timeout: crate::network_timeouts(app).nostr(),
         ..Default::default()
     };
 
@@ -132,7 +177,7 @@ async fn construct_and_store_node(
     // Replace any existing node (drops old node if any)
     let node_state = app.state::<NodeState>();
     let mut guard = node_state.node.lock().await;
-    *guard = Some(node);
+    *guard = Some(std::sync::Arc::new(node));
 
     // Start the background Nostr subscription loop
     if let Some(node) = guard.as_ref() {
@@ -159,6 +204,9 @@ async fn construct_and_store_node(
                 }
                 DiscoveryEvent::PoolDiscovered(p) => {
                     let _ = app_handle.emit("discovery:pool", &p);
+                    let app = app_handle.clone();
+                    let pool_ids = vec![p.lmsr_pool_id.clone()];
+                    tokio::spawn(async move { verify_ingested_pools(&app, pool_ids).await });
                 }
             }
         }
@@ -349,7 +397,8 @@ pub async fn delete_nostr_identity(app: tauri::AppHandle) -> Result<(), String>
 // NIP-44 wallet backup commands
 // =========================================================================
 
-/// Encrypt the wallet mnemonic with NIP-44 and publish to relays.
+/// Encrypt the wallet mnemonic and descriptor template with NIP-44 and
+/// publish to relays.
 #[tauri::command]
 pub async fn backup_mnemonic_to_nostr(
     password: String,
@@ -357,8 +406,8 @@ pub async fn backup_mnemonic_to_nostr(
 ) -> Result<String, String> {
     let (keys, client) = get_keys_and_client(&app).await?;
 
-    // Get mnemonic from persister
-    let mnemonic = {
+    // Get mnemonic and template from persister
+    let backup = {
         let manager = app.state::<Mutex<AppStateManager>>();
         let mut mgr = manager
             .lock()
@@ -366,23 +415,32 @@ pub async fn backup_mnemonic_to_nostr(
         let persister = mgr
             .persister_mut()
             .ok_or_else(|| "Persister not initialized".to_string())?;
-        if let Some(cached) = persister.cached() {
+        let mnemonic = if let Some(cached) = persister.cached() {
             cached.to_string()
         } else {
             persister.load(&password).map_err(|e| e.to_string())?
+        };
+        let template = persister.descriptor_template().map_err(|e| e.to_string())?;
+        discovery::WalletBackup {
+            mnemonic,
+            descriptor_template: Some(template.to_string()),
         }
     };
 
-    let encrypted = discovery::nip44_encrypt_to_self(&keys, &mnemonic)?;
+    let plaintext = Zeroizing::new(backup.to_plaintext()?);
+    let encrypted = discovery::nip44_encrypt_to_self(&keys, &plaintext)?;
     let event = discovery::build_wallet_backup_event(&keys, &encrypted)?;
-    let event_id = discovery::publish_event(&client, event).await?;
+    let report = discovery::publish_event(&client, event).await?;
 
-    Ok(event_id.to_hex())
+    Ok(report.event_id.to_hex())
 }
 
-/// Fetch and decrypt wallet mnemonic backup from relays.
+/// Fetch and decrypt the wallet backup from relays. `descriptor_template`
+/// is `None` for backups made before it was recorded.
 #[tauri::command]
-pub async fn restore_mnemonic_from_nostr(app: tauri::AppHandle) -> Result<String, String> {
+pub async fn restore_mnemonic_from_nostr(
+    app: tauri::AppHandle,
+) -> Result<discovery::WalletBackup, String> {
     let (keys, client) = get_keys_and_client(&app).await?;
 
     let filter = discovery::build_backup_query_filter(&keys.public_key());
@@ -399,7 +457,11 @@ pub async fn restore_mnemonic_from_nostr(app: tauri::AppHandle) -> Result<String
         event.content.clone()
     };
 
-    discovery::nip44_decrypt_from_self(&keys, &encrypted_content)
+    let plaintext = Zeroizing::new(discovery::nip44_decrypt_from_self(
+        &keys,
+        &encrypted_content,
+    )?);
+    Ok(discovery::WalletBackup::from_plaintext(&plaintext))
 }
 
 #[tauri::command]
@@ -422,24 +484,27 @@ pub async fn check_nostr_backup(
     };
 
     let filter = discovery::build_backup_query_filter(&keys.public_key());
+    let timeouts = crate::network_timeouts(&app);
+    let (connect_timeout, timeout) = (timeouts.nostr_connect(), timeouts.nostr());
 
     let mut tasks = tokio::task::JoinSet::new();
     for url in relays {
         let f = filter.clone();
         tasks.spawn(async move {
-            let found =
-                match discovery::connect_multi_relay_client(std::slice::from_ref(&url)).await {
-                    Ok(per_relay_client) => {
-                        match per_relay_client
-                            .fetch_events(vec![f], Duration::from_secs(8))
-                            .await
-                        {
-                            Ok(events) => events.iter().next().is_some(),
-                            Err(_) => false,
-                        }
+            let found = match discovery::connect_multi_relay_client(
+                std::slice::from_ref(&url),
+                connect_timeout,
+            )
+            .await
+            {
+                Ok(per_relay_client) => {
+                    match per_relay_client.fetch_events(vec![f], timeout).await {
+                        Ok(events) => events.iter().next().is_some(),
+                        Err(_) => false,
                     }
-                    Err(_) => false,
-                };
+                }
+                Err(_) => false,
+            };
             discovery::RelayBackupResult {
                 url,
                 has_backup: found,
@@ -464,14 +529,550 @@ pub async fn check_nostr_backup(
     })
 }
 
+/// Derive the account xpub for `mnemonic`, or `None` if it is not a valid mnemonic.
+fn mnemonic_xpub(mnemonic: &str, is_mainnet: bool) -> Option<String> {
+    lwk_signer::SwSigner::new(mnemonic, is_mainnet)
+        .ok()
+        .map(|signer| signer.xpub().to_string())
+}
+
+/// Check that the wallet backup on relays decrypts to the currently loaded
+/// wallet. Only verdicts are returned; the decrypted mnemonic never leaves
+/// this function.
+#[tauri::command]
+pub async fn verify_nostr_backup(
+    password: String,
+    app: tauri::AppHandle,
+) -> Result<discovery::NostrBackupVerification, String> {
+    let (current_xpub, is_mainnet) = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let is_mainnet = mgr.network().ok_or("Network not initialized")?.is_mainnet();
+        let persister = mgr
+            .persister()
+            .ok_or_else(|| "Persister not initialized".to_string())?;
+        // Decrypt even when unlocked so the supplied password is checked,
+        // without caching the seed on a locked wallet.
+        let mnemonic = persister
+            .decrypt_uncached(&password)
+            .map_err(|e| e.to_string())?;
+        let xpub = mnemonic_xpub(&mnemonic, is_mainnet)
+            .ok_or_else(|| "current wallet mnemonic is invalid".to_string())?;
+        (xpub, is_mainnet)
+    };
+
+    let (keys, client) = get_keys_and_client(&app).await?;
+
+    let filter = discovery::build_backup_query_filter(&keys.public_key());
+    let events = client
+        .fetch_events(vec![filter], Duration::from_secs(8))
+        .await
+        .map_err(|e| format!("failed to fetch backup: {e}"))?;
+
+    // An empty replacement event means the backup was deleted.
+    let Some(event) = events
+        .iter()
+        .max_by_key(|e| e.created_at)
+        .filter(|e| !e.content.is_empty())
+    else {
+        return Ok(discovery::NostrBackupVerification {
+            exists: false,
+            decryptable: false,
+            matches_current_wallet: false,
+        });
+    };
+
+    let backup_xpub = discovery::nip44_decrypt_from_self(&keys, &event.content)
+        .ok()
+        .map(Zeroizing::new)
+        .map(|plaintext| {
+            Zeroizing::new(discovery::WalletBackup::from_plaintext(&plaintext).mnemonic)
+        })
+        .and_then(|mnemonic| mnemonic_xpub(&mnemonic, is_mainnet));
+
+    Ok(discovery::NostrBackupVerification {
+        exists: true,
+        decryptable: backup_xpub.is_some(),
+        matches_current_wallet: backup_xpub.as_deref() == Some(current_xpub.as_str()),
+    })
+}
+
 #[tauri::command]
 pub async fn delete_nostr_backup(app: tauri::AppHandle) -> Result<String, String> {
     let (keys, client) = get_keys_and_client(&app).await?;
 
     let event = discovery::build_backup_deletion_event(&keys)?;
-    let event_id = discovery::publish_event(&client, event).await?;
+    let report = discovery::publish_event(&client, event).await?;
+
+    Ok(report.event_id.to_hex())
+}
+
+// =========================================================================
+// NIP-44 app state backup commands
+// =========================================================================
+
+const APP_STATE_BACKUP_VERSION: u32 = 1;
+
+/// Plaintext of an app state backup before chunking and encryption.
+#[derive(Serialize, Deserialize)]
+struct AppStateBackup {
+    version: u32,
+    network: String,
+    /// Raw Nostr events for markets, orders and pools, re-ingested on restore.
+    events: Vec<String>,
+    pool_creators: Vec<PoolCreatorLabel>,
+    own_orders: Vec<OwnOrderLabel>,
+}
+
+/// Local-only creator attribution for a pool we created.
+#[derive(Serialize, Deserialize)]
+struct PoolCreatorLabel {
+    pool_id: String,
+    creator_pubkey: String,
+}
+
+/// Local-only creation metadata for one of our own limit orders.
+#[derive(Serialize, Deserialize)]
+struct OwnOrderLabel {
+    cmr: String,
+    maker_base_pubkey: String,
+    creation_txid: String,
+    market_id: String,
+    direction_label: String,
+    offered_amount: u64,
+}
+
+fn collect_app_state_backup(app: &tauri::AppHandle) -> Result<AppStateBackup, String> {
+    let (network, store_arc) = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let network = mgr.network().ok_or("Network not initialized")?;
+        let store = mgr
+            .store()
+            .cloned()
+            .ok_or_else(|| "Store not initialized".to_string())?;
+        (network, store)
+    };
+
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+
+    let markets = store
+        .list_markets(&MarketFilter::default())
+        .map_err(|e| format!("list markets: {e}"))?;
+    let candidates = store
+        .list_unpromoted_prediction_market_candidates()
+        .map_err(|e| format!("list market candidates: {e}"))?;
+    let orders = store
+        .list_maker_orders(&deadcat_store::OrderFilter::default())
+        .map_err(|e| format!("list orders: {e}"))?;
+    let pools = store
+        .list_lmsr_pools(&deadcat_store::LmsrPoolFilter::default())
+        .map_err(|e| format!("list pools: {e}"))?;
+
+    let mut events = Vec::new();
+    events.extend(markets.iter().filter_map(|m| m.nostr_event_json.clone()));
+    events.extend(candidates.iter().filter_map(|c| c.nostr_event_json.clone()));
+    events.extend(orders.iter().filter_map(|o| o.nostr_event_json.clone()));
+    events.extend(pools.iter().filter_map(|p| p.nostr_event_json.clone()));
+
+    let pool_creators = pools
+        .iter()
+        .filter_map(|p| {
+            Some(PoolCreatorLabel {
+                pool_id: p.pool_id.clone(),
+                creator_pubkey: hex::encode(p.creator_pubkey.as_ref()?),
+            })
+        })
+        .collect();
+
+    let own_orders = orders
+        .iter()
+        .filter_map(|o| {
+            Some(OwnOrderLabel {
+                cmr: hex::encode(o.cmr),
+                maker_base_pubkey: hex::encode(o.maker_base_pubkey?),
+                creation_txid: o.creation_txid.clone()?,
+                market_id: o.market_id.clone()?,
+                direction_label: o.direction_label.clone()?,
+                offered_amount: o.offered_amount?,
+            })
+        })
+        .collect();
+
+    Ok(AppStateBackup {
+        version: APP_STATE_BACKUP_VERSION,
+        network: crate::state::to_sdk_network(network)
+            .discovery_tag()
+            .to_string(),
+        events,
+        pool_creators,
+        own_orders,
+    })
+}
+
+/// Encrypt the discovered markets, orders, pools and local labels with NIP-44
+/// and publish them to relays, split across as many events as needed.
+#[tauri::command]
+pub async fn backup_app_state_to_nostr(
+    app: tauri::AppHandle,
+) -> Result<discovery::AppStateBackupResponse, String> {
+    let backup = collect_app_state_backup(&app)?;
+    let event_count = backup.events.len();
+    let payload =
+        serde_json::to_string(&backup).map_err(|e| format!("failed to encode backup: {e}"))?;
+
+    let (keys, client) = get_keys_and_client(&app).await?;
+
+    let backup_id = {
+        use sha2::{Digest, Sha256};
+        hex::encode(&Sha256::digest(payload.as_bytes())[..16])
+    };
+    let chunks = discovery::split_backup_payload(&payload, discovery::APP_STATE_BACKUP_CHUNK_BYTES);
+
+    // Publish every chunk before the manifest so a restore never sees a
+    // manifest whose chunks have not reached the relays yet.
+    for (index, chunk) in chunks.iter().enumerate() {
+        let encrypted = discovery::nip44_encrypt_to_self(&keys, chunk)?;
+        let event = discovery::build_app_state_chunk_event(&keys, &backup_id, index, &encrypted)?;
+        discovery::publish_event(&client, event).await?;
+    }
+
+    let manifest = discovery::build_app_state_manifest_event(&keys, &backup_id, chunks.len())?;
+    let report = discovery::publish_event(&client, manifest).await?;
+
+    Ok(discovery::AppStateBackupResponse {
+        event_id: report.event_id.to_hex(),
+        chunk_count: chunks.len(),
+        event_count,
+    })
+}
+
+/// Fetch, decrypt and re-ingest the app state backup published by
+/// `backup_app_state_to_nostr`.
+#[tauri::command]
+pub async fn restore_app_state_from_nostr(
+    app: tauri::AppHandle,
+) -> Result<discovery::AppStateRestoreResponse, String> {
+    let (keys, client) = get_keys_and_client(&app).await?;
+
+    let filter = discovery::build_app_state_manifest_filter(&keys.public_key());
+    let manifests = client
+        .fetch_events(vec![filter], Duration::from_secs(8))
+        .await
+        .map_err(|e| format!("failed to fetch app state backup: {e}"))?;
+    let manifest = manifests
+        .iter()
+        .max_by_key(|e| e.created_at)
+        .ok_or_else(|| "No app state backup found on relays".to_string())?;
+    let (backup_id, chunk_count) = discovery::parse_app_state_manifest(manifest)?;
+
+    let filter = discovery::build_app_state_chunks_filter(&keys.public_key(), chunk_count);
+    let chunk_events = client
+        .fetch_events(vec![filter], Duration::from_secs(8))
+        .await
+        .map_err(|e| format!("failed to fetch app state backup: {e}"))?;
+    let encrypted_chunks =
+        discovery::collect_app_state_chunks(chunk_events.iter(), &backup_id, chunk_count)?;
+
+    let mut payload = String::new();
+    for chunk in &encrypted_chunks {
+        payload.push_str(&discovery::nip44_decrypt_from_self(&keys, chunk)?);
+    }
+    let backup: AppStateBackup =
+        serde_json::from_str(&payload).map_err(|e| format!("invalid app state backup: {e}"))?;
+    if backup.version != APP_STATE_BACKUP_VERSION {
+        return Err(format!(
+            "unsupported app state backup version {}",
+            backup.version
+        ));
+    }
+
+    let (network, store_arc) = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let network = mgr.network().ok_or("Network not initialized")?;
+        let store = mgr
+            .store()
+            .cloned()
+            .ok_or_else(|| "Store not initialized".to_string())?;
+        (network, store)
+    };
+    let network_tag = crate::state::to_sdk_network(network).discovery_tag();
+    if backup.network != network_tag {
+        return Err(format!(
+            "app state backup is for {}, but the wallet is on {network_tag}",
+            backup.network
+        ));
+    }
+
+    let events: Vec<Event> = backup
+        .events
+        .iter()
+        .filter_map(|json| match Event::from_json(json) {
+            Ok(event) => Some(event),
+            Err(e) => {
+                log::warn!("skipping unreadable event in app state backup: {e}");
+                None
+            }
+        })
+        .collect();
+
+    let events_restored = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref().ok_or("Node not initialized")?;
+        node.discovery().ingest_events(&events)
+    };
+
+    // Labels must be applied after the events so the rows they update exist.
+    let mut labels_restored = 0;
+    {
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        for label in &backup.pool_creators {
+            let Ok(creator_pubkey) = hex::decode(&label.creator_pubkey) else {
+                continue;
+            };
+            match store.record_lmsr_pool_creator(&label.pool_id, &creator_pubkey) {
+                Ok(()) => labels_restored += 1,
+                Err(e) => log::warn!("failed to restore creator of pool {}: {e}", label.pool_id),
+            }
+        }
+        for label in &backup.own_orders {
+            let (Ok(cmr), Some(maker_base_pubkey)) = (
+                hex::decode(&label.cmr),
+                hex::decode(&label.maker_base_pubkey)
+                    .ok()
+                    .and_then(|b| <[u8; 32]>::try_from(b.as_slice()).ok()),
+            ) else {
+                continue;
+            };
+            match store.record_order_creation(
+                &cmr,
+                &maker_base_pubkey,
+                &label.creation_txid,
+                &label.market_id,
+                &label.direction_label,
+                label.offered_amount,
+            ) {
+                Ok(()) => labels_restored += 1,
+                Err(e) => log::warn!("failed to restore order {}: {e}", label.creation_txid),
+            }
+        }
+    }
+
+    bump_revision_and_emit(&app).await?;
+
+    Ok(discovery::AppStateRestoreResponse {
+        events_restored,
+        labels_restored,
+    })
+}
+
+/// A wallet asset identified as part of an announced market.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoveredPosition {
+    pub asset_id: String,
+    pub amount: u64,
+    pub market_id: String,
+    /// One of `yes`, `no`, `yes_reissuance` or `no_reissuance`.
+    pub role: &'static str,
+}
+
+/// A wallet asset that no announced market or pool uses.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnidentifiedAsset {
+    pub asset_id: String,
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoverPositionsResponse {
+    pub positions: Vec<RecoveredPosition>,
+    pub unidentified: Vec<UnidentifiedAsset>,
+}
+
+/// Identify the markets behind every token the wallet holds.
+///
+/// Meant to run after `sync_wallet` on a restored wallet: matched market
+/// announcements are persisted so the positions show up in the portfolio.
+#[tauri::command]
+pub async fn recover_positions(app: tauri::AppHandle) -> Result<RecoverPositionsResponse, String> {
+    let scan = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref().ok_or("Node not initialized")?;
+        node.discover_owned_positions()
+            .await
+            .map_err(|e| format!("{e}"))?
+    };
+
+    bump_revision_and_emit(&app).await?;
+
+    Ok(RecoverPositionsResponse {
+        positions: scan
+            .matched
+            .into_iter()
+            .map(|position| RecoveredPosition {
+                asset_id: position.asset_id.to_string(),
+                amount: position.amount,
+                market_id: position.market_id,
+                role: match position.role {
+                    deadcat_sdk::OwnedAssetRole::YesToken => "yes",
+                    deadcat_sdk::OwnedAssetRole::NoToken => "no",
+                    deadcat_sdk::OwnedAssetRole::YesReissuanceToken => "yes_reissuance",
+                    deadcat_sdk::OwnedAssetRole::NoReissuanceToken => "no_reissuance",
+                },
+            })
+            .collect(),
+        unidentified: scan
+            .unmatched
+            .into_iter()
+            .map(|(asset_id, amount)| UnidentifiedAsset {
+                asset_id: asset_id.to_string(),
+                amount,
+            })
+            .collect(),
+    })
+}
+
+/// Known contract locked by a transaction input or output.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CovenantLabel {
+    Market {
+        market_id: String,
+        slot: deadcat_sdk::MarketSlot,
+    },
+    MakerOrder {
+        order_id: i32,
+    },
+    LmsrPool {
+        pool_id: String,
+        market_id: String,
+    },
+}
 
-    Ok(event_id.to_hex())
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionDetailEntry {
+    pub txid: String,
+    pub vout: u32,
+    pub asset_id: Option<String>,
+    pub amount: Option<u64>,
+    pub address: Option<String>,
+    pub is_mine: bool,
+    pub is_change: bool,
+    pub is_fee: bool,
+    pub covenant: Option<CovenantLabel>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionDetailResponse {
+    pub txid: String,
+    pub height: Option<u32>,
+    pub confirmations: u32,
+    pub fee: u64,
+    pub inputs: Vec<TransactionDetailEntry>,
+    pub outputs: Vec<TransactionDetailEntry>,
+}
+
+/// Input/output breakdown of a transaction for the wallet's detail view.
+///
+/// Wallet-owned entries are unblinded; covenant entries are labelled with the
+/// market, maker order or LMSR pool they belong to when the store knows it.
+#[tauri::command]
+pub async fn get_transaction_detail(
+    txid: String,
+    app: tauri::AppHandle,
+) -> Result<TransactionDetailResponse, String> {
+    let txid: deadcat_sdk::elements::Txid =
+        txid.parse().map_err(|e| format!("invalid txid: {e}"))?;
+    let detail = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref().ok_or("Node not initialized")?;
+        node.transaction_detail(txid)
+            .await
+            .map_err(|e| format!("{e}"))?
+    };
+
+    let (script_labels, pools) = {
+        let store_arc = get_store(&app)?;
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        let script_labels = store
+            .covenant_script_labels()
+            .map_err(|e| format!("covenant labels: {e}"))?;
+        let pools = store
+            .list_lmsr_pools(&deadcat_store::LmsrPoolFilter::default())
+            .map_err(|e| format!("list pools: {e}"))?;
+        (script_labels, pools)
+    };
+    // Pool reserve scripts move with the pool's price index, so pools are
+    // matched by the reserve outpoints the store has seen instead.
+    let mut pool_outpoints = std::collections::HashMap::new();
+    for pool in &pools {
+        let outpoints = pool.initial_reserve_outpoints.iter().chain([
+            &pool.reserve_yes_outpoint,
+            &pool.reserve_no_outpoint,
+            &pool.reserve_collateral_outpoint,
+        ]);
+        for outpoint in outpoints {
+            pool_outpoints.insert(outpoint.clone(), pool);
+        }
+    }
+
+    let describe = |entry: deadcat_sdk::TransactionDetailEntry| {
+        let covenant = match script_labels.get(entry.script_pubkey.as_bytes()) {
+            Some(deadcat_store::CovenantScriptLabel::Market { market_id, slot }) => {
+                Some(CovenantLabel::Market {
+                    market_id: hex::encode(market_id.as_bytes()),
+                    slot: *slot,
+                })
+            }
+            Some(deadcat_store::CovenantScriptLabel::MakerOrder { order_id }) => {
+                Some(CovenantLabel::MakerOrder {
+                    order_id: *order_id,
+                })
+            }
+            None => pool_outpoints.get(&entry.outpoint.to_string()).map(|pool| {
+                CovenantLabel::LmsrPool {
+                    pool_id: pool.pool_id.clone(),
+                    market_id: pool.market_id.clone(),
+                }
+            }),
+        };
+        TransactionDetailEntry {
+            txid: entry.outpoint.txid.to_string(),
+            vout: entry.outpoint.vout,
+            asset_id: entry.asset.map(|asset| asset.to_string()),
+            amount: entry.value,
+            address: entry.address,
+            is_mine: entry.is_mine,
+            is_change: entry.is_change,
+            is_fee: entry.is_fee,
+            covenant,
+        }
+    };
+
+    Ok(TransactionDetailResponse {
+        txid: detail.txid.to_string(),
+        height: detail.height,
+        confirmations: detail.confirmations,
+        fee: detail.fee,
+        inputs: detail.inputs.into_iter().map(&describe).collect(),
+        outputs: detail.outputs.into_iter().map(&describe).collect(),
+    })
 }
 
 // =========================================================================
@@ -502,7 +1103,42 @@ pub async fn set_relay_list(relays: Vec<String>, app: tauri::AppHandle) -> Resul
         .iter()
         .map(|u| discovery::normalize_relay_url(u))
         .collect();
+    replace_relay_list(normalized, &app).await
+}
 
+/// Restore the default relays for the active network.
+#[tauri::command]
+pub async fn reset_relays_to_default(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    let network = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.network().ok_or("Network not initialized")?
+    };
+    let defaults = crate::default_relays(network);
+    replace_relay_list(defaults.clone(), &app).await?;
+    Ok(defaults)
+}
+
+/// Default relays for the active network, or the shared public relays
+/// before a network has been chosen.
+fn network_default_relays(app: &tauri::AppHandle) -> Vec<String> {
+    let network = app
+        .state::<Mutex<AppStateManager>>()
+        .lock()
+        .ok()
+        .and_then(|mgr| mgr.network());
+    match network {
+        Some(network) => crate::default_relays(network),
+        None => discovery::DEFAULT_RELAYS
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    }
+}
+
+async fn replace_relay_list(normalized: Vec<String>, app: &tauri::AppHandle) -> Result<(), String> {
     // Update relay list
     {
         let nostr_state = app.state::<NostrAppState>();
@@ -525,7 +1161,9 @@ pub async fn set_relay_list(relays: Vec<String>, app: tauri::AppHandle) -> Resul
         for url in &normalized {
             let _ = client.add_relay(url.as_str()).await;
         }
-        client.connect_with_timeout(Duration::from_secs(5)).await;
+        client
+            .connect_with_timeout(crate::network_timeouts(app).nostr_connect())
+            .await;
 
         let event = discovery::build_relay_list_event(&keys, &normalized)?;
         discovery::publish_event(&client, event).await?;
@@ -538,7 +1176,8 @@ pub async fn set_relay_list(relays: Vec<String>, app: tauri::AppHandle) -> Resul
 pub async fn fetch_nip65_relay_list(app: tauri::AppHandle) -> Result<Vec<String>, String> {
     let (keys, client) = get_keys_and_client(&app).await?;
 
-    match discovery::fetch_relay_list(&client, &keys.public_key()).await? {
+    let timeout = crate::network_timeouts(&app).nostr();
+    match discovery::fetch_relay_list(&client, &keys.public_key(), timeout).await? {
         Some(relays) => {
             let nostr_state = app.state::<NostrAppState>();
             let mut list = nostr_state
@@ -584,7 +1223,9 @@ pub async fn add_relay(url: String, app: tauri::AppHandle) -> Result<Vec<String>
         drop(guard);
 
         let _ = client.add_relay(normalized.as_str()).await;
-        client.connect_with_timeout(Duration::from_secs(5)).await;
+        client
+            .connect_with_timeout(crate::network_timeouts(&app).nostr_connect())
+            .await;
 
         let event = discovery::build_relay_list_event(&keys, &new_list)?;
         discovery::publish_event(&client, event).await?;
@@ -596,6 +1237,7 @@ pub async fn add_relay(url: String, app: tauri::AppHandle) -> Result<Vec<String>
 #[tauri::command]
 pub async fn remove_relay(url: String, app: tauri::AppHandle) -> Result<Vec<String>, String> {
     let normalized = discovery::normalize_relay_url(&url);
+    let defaults = network_default_relays(&app);
     let new_list = {
         let nostr_state = app.state::<NostrAppState>();
         let mut list = nostr_state
@@ -604,10 +1246,7 @@ pub async fn remove_relay(url: String, app: tauri::AppHandle) -> Result<Vec<Stri
             .map_err(|_| "failed to write relay_list".to_string())?;
         list.retain(|u| u != &normalized);
         if list.is_empty() {
-            *list = discovery::DEFAULT_RELAYS
-                .iter()
-                .map(|s| s.to_string())
-                .collect();
+            *list = defaults;
         }
         list.clone()
     };
@@ -628,46 +1267,444 @@ pub async fn remove_relay(url: String, app: tauri::AppHandle) -> Result<Vec<Stri
 }
 
 // =========================================================================
-// Kind 0 profile command
+// Announcement republish commands
 // =========================================================================
 
-#[tauri::command]
-pub async fn fetch_nostr_profile(
-    app: tauri::AppHandle,
-) -> Result<Option<discovery::NostrProfile>, String> {
-    let (keys, client) = get_keys_and_client(&app).await?;
-    discovery::fetch_profile(&client, &keys.public_key()).await
+/// Relay coverage of a market, order, or pool announcement this node
+/// published.
+#[derive(Serialize, Deserialize)]
+pub struct PublishStatusResponse {
+    pub event_id: String,
+    /// "pending", "published", or "failed".
+    pub status: String,
+    pub relays_accepted: usize,
+    pub attempts: u32,
+    pub last_error: Option<String>,
 }
 
-// =========================================================================
-// Contract discovery commands
-// =========================================================================
-
-#[tauri::command]
-pub async fn discover_contracts(app: tauri::AppHandle) -> Result<Vec<DiscoveredMarket>, String> {
-    // Fetch from Nostr (persists to store as side-effect)
-    {
-        let node_state = app.state::<NodeState>();
-        let guard = node_state.node.lock().await;
-        let node = guard.as_ref().ok_or("Node not initialized")?;
-        if let Err(e) = node.fetch_markets().await {
-            log::warn!("Nostr fetch failed (serving from store): {e}");
+impl From<deadcat_sdk::PublishRecord> for PublishStatusResponse {
+    fn from(record: deadcat_sdk::PublishRecord) -> Self {
+        Self {
+            event_id: record.event_id,
+            status: record.status.as_str().to_string(),
+            relays_accepted: record.accepted_relays.len(),
+            attempts: record.attempts,
+            last_error: record.last_error,
         }
     }
-    // Return from store — single source of truth
-    list_contracts(app)
 }
 
+/// Publish a tracked announcement to the relays again, e.g. after it was
+/// marked failed.
 #[tauri::command]
-pub async fn fetch_orders(
-    market_id: Option<String>,
+pub async fn republish_event(
+    event_id: String,
     app: tauri::AppHandle,
-) -> Result<Vec<DiscoveredOrder>, String> {
+) -> Result<PublishStatusResponse, String> {
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
-    match node.fetch_orders(market_id.as_deref()).await {
-        Ok(orders) => Ok(orders),
+    let record = node
+        .republish_event(&event_id)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    Ok(record.into())
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeleteAnnouncementResponse {
+    /// Id of the NIP-09 deletion request.
+    pub deletion_event_id: String,
+    pub relays_accepted: usize,
+    pub relays_total: usize,
+    /// Set when some relays did not accept the deletion request.
+    pub publish_warning: Option<String>,
+}
+
+/// Retract a market, order, or pool announcement this node published.
+///
+/// Relays may keep serving the announcement; discovery fetches filter it
+/// out either way.
+#[tauri::command]
+pub async fn delete_announcement(
+    event_id: String,
+    app: tauri::AppHandle,
+) -> Result<DeleteAnnouncementResponse, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let report = node
+        .delete_announcement(&event_id)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    Ok(DeleteAnnouncementResponse {
+        deletion_event_id: report.event_id.to_hex(),
+        relays_accepted: report.accepted.len(),
+        relays_total: report.relay_count(),
+        publish_warning: report.warning(),
+    })
+}
+
+/// Retry announcements that reached fewer than a quorum of relays. Runs
+/// periodically in the background; records persist in the store, so
+/// retries resume after a restart.
+pub(crate) async fn retry_pending_publishes_inner<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+) -> Result<Vec<PublishStatusResponse>, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let records = node
+        .retry_pending_publishes()
+        .await
+        .map_err(|e| format!("{e}"))?;
+    Ok(records.into_iter().map(Into::into).collect())
+}
+
+// =========================================================================
+// Broadcast intent commands
+// =========================================================================
+
+/// A signed covenant transaction whose broadcast was interrupted and has not
+/// been seen on chain.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BroadcastIntentResponse {
+    pub txid: String,
+    /// "issue_tokens", "resolve_market", or "pool_swap".
+    pub kind: String,
+    pub market_id: Option<String>,
+    pub status: String,
+}
+
+impl From<deadcat_sdk::BroadcastIntent> for BroadcastIntentResponse {
+    fn from(intent: deadcat_sdk::BroadcastIntent) -> Self {
+        Self {
+            txid: intent.txid,
+            kind: intent.kind.as_str().to_string(),
+            market_id: intent.market_id,
+            status: intent.status.as_str().to_string(),
+        }
+    }
+}
+
+/// Check pending intents against the chain, settling those that landed or
+/// can no longer confirm. Runs after unlock.
+pub(crate) async fn reconcile_broadcast_intents_inner<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+) -> Result<Vec<BroadcastIntentResponse>, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let intents = node
+        .reconcile_broadcast_intents()
+        .await
+        .map_err(|e| format!("{e}"))?;
+    Ok(intents.into_iter().map(Into::into).collect())
+}
+
+#[tauri::command]
+pub async fn list_pending_broadcast_intents(
+    app: tauri::AppHandle,
+) -> Result<Vec<BroadcastIntentResponse>, String> {
+    reconcile_broadcast_intents_inner(&app).await
+}
+
+/// Broadcast an interrupted operation's transaction again. Fails without
+/// broadcasting if it already landed or an input was spent elsewhere.
+#[tauri::command]
+pub async fn retry_broadcast_intent(txid: String, app: tauri::AppHandle) -> Result<String, String> {
+    let _in_flight = crate::InFlightGuard::begin(&app, format!("broadcast_intent:{txid}"))?;
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let txid = node
+        .retry_broadcast_intent(&txid)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    bump_revision_and_emit(&app).await?;
+    Ok(txid.to_string())
+}
+
+#[tauri::command]
+pub async fn discard_broadcast_intent(txid: String, app: tauri::AppHandle) -> Result<(), String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    node.discard_broadcast_intent(&txid)
+        .map_err(|e| format!("{e}"))
+}
+
+// =========================================================================
+// Kind 0 profile command
+// =========================================================================
+
+#[tauri::command]
+pub async fn fetch_nostr_profile(
+    app: tauri::AppHandle,
+) -> Result<Option<discovery::NostrProfile>, String> {
+    let (keys, client) = get_keys_and_client(&app).await?;
+    discovery::fetch_profile(
+        &client,
+        &keys.public_key(),
+        crate::network_timeouts(&app).nostr(),
+    )
+    .await
+}
+
+/// Kind 0 profile of another user, also looked up on their NIP-65 relays.
+#[tauri::command]
+pub async fn fetch_creator_profile(
+    pubkey: String,
+    app: tauri::AppHandle,
+) -> Result<Option<discovery::NostrProfile>, String> {
+    let author = parse_author(&pubkey)?;
+    let (_, client) = get_keys_and_client(&app).await?;
+    let relays = author_relays(&app, &client, &author).await;
+    let timeout = crate::network_timeouts(&app).nostr();
+    discovery::fetch_profile_with_relays(&client, &author, &relays, timeout).await
+}
+
+/// Parse a hex or npub public key.
+fn parse_author(pubkey: &str) -> Result<PublicKey, String> {
+    PublicKey::parse(pubkey).map_err(|e| format!("invalid pubkey: {e}"))
+}
+
+/// Parse an `nevent`, `note` or hex event id, with or without a `nostr:`
+/// prefix, or a market [share link](share_link). Returns the id and any
+/// relay hints. Order and pool links are rejected: their announcements are
+/// not markets.
+fn parse_event_reference(reference: &str) -> Result<(EventId, Vec<String>), String> {
+    let reference = reference.trim();
+    let reference = match reference.strip_prefix(SHARE_LINK_SCHEME) {
+        Some(path) => match path.split_once('/') {
+            Some(("market", nevent)) => nevent,
+            Some((kind, _)) => return Err(format!("a {kind} link does not point to a market")),
+            None => return Err(format!("invalid share link: {reference}")),
+        },
+        None => reference.strip_prefix("nostr:").unwrap_or(reference),
+    };
+    if reference.starts_with("nevent1") {
+        let nevent =
+            Nip19Event::from_bech32(reference).map_err(|e| format!("invalid nevent: {e}"))?;
+        let relays = nevent.relays.iter().map(|r| r.to_string()).collect();
+        return Ok((nevent.event_id, relays));
+    }
+    let event_id = EventId::parse(reference).map_err(|e| format!("invalid event id: {e}"))?;
+    Ok((event_id, Vec::new()))
+}
+
+/// `author`'s NIP-65 write relays, cached per pubkey. Empty when they have
+/// no relay list (or it can't be fetched), leaving only the default relays.
+async fn author_relays(
+    app: &tauri::AppHandle,
+    client: &nostr_sdk::Client,
+    author: &PublicKey,
+) -> Vec<String> {
+    let nostr_state = app.state::<NostrAppState>();
+    let cached = nostr_state
+        .relay_list_cache
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(author));
+    if let Some(relays) = cached {
+        return relays.unwrap_or_default();
+    }
+
+    let timeout = crate::network_timeouts(app).nostr();
+    match discovery::fetch_write_relays(client, author, timeout).await {
+        Ok(relays) => {
+            if let Ok(mut cache) = nostr_state.relay_list_cache.lock() {
+                cache.insert(*author, relays.clone());
+            }
+            relays.unwrap_or_default()
+        }
+        Err(e) => {
+            log::warn!("failed to fetch NIP-65 relays for {author}: {e}");
+            Vec::new()
+        }
+    }
+}
+
+// =========================================================================
+// Contract discovery commands
+// =========================================================================
+
+#[tauri::command]
+pub async fn discover_contracts(
+    app: tauri::AppHandle,
+    op_id: Option<String>,
+) -> Result<Vec<DiscoveredMarket>, String> {
+    let task_app = app.clone();
+    crate::run_cancellable(&app, op_id, move |_| async move {
+        // Fetch from Nostr (persists to store as side-effect)
+        {
+            let node_state = task_app.state::<NodeState>();
+            let guard = node_state.node.lock().await;
+            let node = guard.as_ref().ok_or("Node not initialized")?;
+            if let Err(e) = node.fetch_markets().await {
+                log::warn!("Nostr fetch failed (serving from store): {e}");
+            }
+        }
+        // Return from store — single source of truth
+        list_contracts(task_app, None, None)
+    })
+    .await
+}
+
+/// Resolve a shared market link, given as an `nevent` or event id. Served
+/// from the store when the announcement is known, otherwise fetched from
+/// relays (plus the `nevent`'s relay hints) and ingested.
+#[tauri::command]
+pub async fn get_market_by_event(
+    event: String,
+    app: tauri::AppHandle,
+) -> Result<DiscoveredMarket, String> {
+    let (event_id, relays) = parse_event_reference(&event)?;
+    {
+        let store_arc = get_store(&app)?;
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        let info = store
+            .get_market_by_event_id(&event_id.to_hex())
+            .map_err(|e| format!("look up market: {e}"))?;
+        if let Some(info) = info {
+            return Ok(market_info_to_discovered(&info, None, None));
+        }
+    }
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    node.fetch_market_by_event(event_id, &relays)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// =========================================================================
+// Share link commands
+// =========================================================================
+
+const SHARE_LINK_SCHEME: &str = "deadcat:";
+
+/// Link to a market, order or pool announcement that can be opened outside
+/// the app.
+#[derive(Serialize)]
+pub struct ShareLinkResponse {
+    /// `deadcat:<market|order|pool>/<nevent>`.
+    pub uri: String,
+    pub nevent: String,
+    /// Relays the `nevent` tells recipients to fetch from.
+    pub relays: Vec<String>,
+}
+
+/// Share link for announcement `event_id`. Points at the relays that
+/// accepted it when this node published it, otherwise at the configured
+/// relays it was discovered on.
+fn share_link(
+    app: &tauri::AppHandle,
+    store: &mut deadcat_store::DeadcatStore,
+    kind: &str,
+    event_id: &str,
+) -> Result<ShareLinkResponse, String> {
+    let id = EventId::from_hex(event_id).map_err(|e| format!("invalid event id: {e}"))?;
+    let published = store
+        .get_publish_record(event_id)
+        .map_err(|e| format!("publish record: {e}"))?
+        .map(|record| record.accepted_relays)
+        .filter(|relays| !relays.is_empty());
+    let relays = match published {
+        Some(relays) => relays,
+        None => app
+            .state::<NostrAppState>()
+            .relay_list
+            .read()
+            .map_err(|_| "failed to read relay_list".to_string())?
+            .clone(),
+    };
+    let nevent = Nip19Event::new(id, relays.iter().cloned())
+        .to_bech32()
+        .map_err(|e| format!("failed to encode nevent: {e}"))?;
+    Ok(ShareLinkResponse {
+        uri: format!("{SHARE_LINK_SCHEME}{kind}/{nevent}"),
+        nevent,
+        relays,
+    })
+}
+
+#[tauri::command]
+pub fn get_market_share_link(
+    market_id: String,
+    app: tauri::AppHandle,
+) -> Result<ShareLinkResponse, String> {
+    let mid = deadcat_sdk::MarketId(decode_hex_32(&market_id, "market_id")?);
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let event_id = store
+        .get_market(&mid)
+        .map_err(|e| format!("get market: {e}"))?
+        .ok_or_else(|| format!("market {market_id} not found"))?
+        .nostr_event_id
+        .ok_or("market has no Nostr announcement")?;
+    share_link(&app, &mut store, "market", &event_id)
+}
+
+#[tauri::command]
+pub fn get_order_share_link(
+    order_id: i32,
+    app: tauri::AppHandle,
+) -> Result<ShareLinkResponse, String> {
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let event_id = store
+        .get_maker_order(order_id)
+        .map_err(|e| format!("get order: {e}"))?
+        .ok_or_else(|| format!("order {order_id} not found"))?
+        .nostr_event_id
+        .ok_or("order has no Nostr announcement")?;
+    share_link(&app, &mut store, "order", &event_id)
+}
+
+#[tauri::command]
+pub fn get_pool_share_link(
+    pool_id: String,
+    app: tauri::AppHandle,
+) -> Result<ShareLinkResponse, String> {
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let event_id = store
+        .list_lmsr_pools(&deadcat_store::LmsrPoolFilter {
+            pool_id: Some(pool_id.clone()),
+            limit: Some(1),
+            ..Default::default()
+        })
+        .map_err(|e| format!("list pools: {e}"))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("pool {pool_id} not found"))?
+        .nostr_event_id
+        .ok_or("pool has no Nostr announcement")?;
+    share_link(&app, &mut store, "pool", &event_id)
+}
+
+#[tauri::command]
+pub async fn fetch_orders(
+    market_id: Option<String>,
+    app: tauri::AppHandle,
+) -> Result<Vec<DiscoveredOrder>, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    match node.fetch_orders(market_id.as_deref()).await {
+        Ok(orders) => Ok(orders),
         Err(e) => {
             log::warn!("Nostr order fetch failed: {e}");
             Ok(vec![])
@@ -675,6 +1712,55 @@ pub async fn fetch_orders(
     }
 }
 
+/// Markets announced by `pubkey`, also queried on their NIP-65 relays.
+#[tauri::command]
+pub async fn fetch_creator_markets(
+    pubkey: String,
+    app: tauri::AppHandle,
+) -> Result<Vec<DiscoveredMarket>, String> {
+    let author = parse_author(&pubkey)?;
+    let (_, client) = get_keys_and_client(&app).await?;
+    let relays = author_relays(&app, &client, &author).await;
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    node.fetch_markets_by_author(author, &relays)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Orders announced by `pubkey`, also queried on their NIP-65 relays.
+#[tauri::command]
+pub async fn fetch_creator_orders(
+    pubkey: String,
+    app: tauri::AppHandle,
+) -> Result<Vec<DiscoveredOrder>, String> {
+    let author = parse_author(&pubkey)?;
+    let (_, client) = get_keys_and_client(&app).await?;
+    let relays = author_relays(&app, &client, &author).await;
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    node.fetch_orders_by_author(author, &relays)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Orders whose covenant still holds funds, with `offered_amount` set to the
+/// amount locked on-chain.
+#[tauri::command]
+pub async fn discover_live_orders(
+    market_id: Option<String>,
+    app: tauri::AppHandle,
+) -> Result<Vec<DiscoveredOrder>, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    node.discover_live_orders(market_id.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
 /// Publish a contract to Nostr (Nostr-only mode — no on-chain tx).
 #[tauri::command]
 pub async fn publish_contract(
@@ -731,16 +1817,98 @@ pub async fn oracle_attest(
     Ok(result)
 }
 
+/// The oracle's attestation for a stored market, verified against the
+/// market's oracle key. An attestation is cached in the store, and served
+/// from there offline, only once the market has resolved on-chain to the
+/// same outcome; one that contradicts the on-chain resolution is an error.
+/// `None` when no valid attestation has been published.
+#[tauri::command]
+pub async fn fetch_market_attestation(
+    market_id: String,
+    app: tauri::AppHandle,
+) -> Result<Option<deadcat_sdk::VerifiedAttestation>, String> {
+    let mid = deadcat_sdk::MarketId(decode_hex_32(&market_id, "market_id")?);
+    let store_arc = get_store(&app)?;
+    let (oracle_public_key, state) = {
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        if let Some(cached) = store
+            .get_market_attestation(&mid)
+            .map_err(|e| format!("get attestation: {e}"))?
+        {
+            return Ok(Some(cached));
+        }
+        let market = store
+            .get_market(&mid)
+            .map_err(|e| format!("get market: {e}"))?
+            .ok_or_else(|| format!("market {market_id} not found"))?;
+        (market.params.oracle_public_key, market.state)
+    };
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let attestation = node
+        .fetch_market_attestation(&mid, &oracle_public_key)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    let resolved_yes = match state {
+        deadcat_sdk::MarketState::ResolvedYes => Some(true),
+        deadcat_sdk::MarketState::ResolvedNo => Some(false),
+        _ => None,
+    };
+    if let (Some(attestation), Some(resolved_yes)) = (&attestation, resolved_yes) {
+        if attestation.outcome_yes != resolved_yes {
+            return Err(format!(
+                "oracle attestation for market {market_id} contradicts its on-chain resolution"
+            ));
+        }
+        let saved = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())
+            .and_then(|mut store| {
+                store
+                    .save_market_attestation(attestation)
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = saved {
+            log::warn!("failed to cache attestation for market {market_id}: {e}");
+        }
+    }
+    Ok(attestation)
+}
+
 // =========================================================================
 // On-chain contract creation command
 // =========================================================================
 
+/// A newly created market plus how many relays accepted its announcement.
+#[derive(Serialize, Deserialize)]
+pub struct CreateContractResponse {
+    #[serde(flatten)]
+    pub market: DiscoveredMarket,
+    pub relays_accepted: usize,
+    pub relays_total: usize,
+    /// Set when the announcement reached some relays but not all of them.
+    pub publish_warning: Option<String>,
+}
+
 #[tauri::command]
 pub async fn create_contract_onchain(
     request: CreateContractRequest,
+    confirm_mainnet: Option<bool>,
     app: tauri::AppHandle,
-) -> Result<DiscoveredMarket, String> {
+) -> Result<CreateContractResponse, String> {
     validate_request(&request)?;
+    crate::require_mainnet_confirmation(&app, confirm_mainnet)?;
+
+    let _in_flight = crate::InFlightGuard::begin(
+        &app,
+        format!("create_contract_onchain:{}", request.question),
+    )?;
 
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
@@ -765,7 +1933,13 @@ pub async fn create_contract_onchain(
             .ok_or_else(|| "network not configured".to_string())?
             .into()
     };
-    let (tip, now_unix) = compute_tip_and_now(wallet_network).await?;
+    let esplora_url = crate::esplora_url(&app, wallet_network.into());
+    let (tip, now_unix) = compute_tip_and_now(
+        wallet_network,
+        &esplora_url,
+        crate::network_timeouts(&app).esplora(),
+    )
+    .await?;
 
     let expiry_time = if request.settlement_deadline_unix > now_unix {
         let seconds_until = request.settlement_deadline_unix - now_unix;
@@ -782,7 +1956,7 @@ pub async fn create_contract_onchain(
         resolution_source: request.resolution_source,
     };
 
-    let market = node
+    let (market, report) = node
         .create_market(
             oracle_pubkey_bytes,
             request.collateral_per_token,
@@ -795,51 +1969,223 @@ pub async fn create_contract_onchain(
         .map_err(|e| format!("{e}"))?;
     drop(guard);
 
+    record_action(
+        &app,
+        deadcat_store::ActionKind::CreateMarket,
+        Some(market.market_id.clone()),
+        Some(market.anchor.creation_txid.clone()),
+        serde_json::json!({ "question": market.question, "expiry_height": market.expiry_height }),
+    );
     bump_revision_and_emit(&app).await?;
 
-    Ok(market)
+    Ok(CreateContractResponse {
+        market,
+        relays_accepted: report.accepted.len(),
+        relays_total: report.relay_count(),
+        publish_warning: report.warning(),
+    })
 }
 
 // =========================================================================
 // Token issuance command
 // =========================================================================
 
-#[derive(Serialize, Deserialize)]
-pub struct IssuanceResultResponse {
-    pub txid: String,
-    pub previous_state: u8,
-    pub new_state: u8,
-    pub pairs_issued: u64,
+/// Fee for a prediction-market covenant transaction at the default rate,
+/// sized from the Simplicity witnesses of its spending paths. Flows that may
+/// build a transaction for each of `ops` pay the largest of their fees.
+fn covenant_fee(
+    params: &deadcat_sdk::PredictionMarketParams,
+    ops: &[deadcat_sdk::CovenantOp],
+) -> Result<u64, String> {
+    let contract =
+        deadcat_sdk::CompiledPredictionMarket::new(*params).map_err(|e| format!("{e}"))?;
+    ops.iter().try_fold(0, |fee, op| {
+        deadcat_sdk::estimate_covenant_fee(&contract, *op, deadcat_sdk::DEFAULT_COVENANT_FEE_RATE)
+            .map(|op_fee| fee.max(op_fee))
+            .map_err(|e| format!("{e}"))
+    })
 }
 
-/// Issue new YES+NO token pairs by locking collateral.
-#[tauri::command]
-pub async fn issue_tokens(
-    contract_params_json: String,
-    anchor: deadcat_sdk::PredictionMarketAnchor,
-    pairs: u64,
-    app: tauri::AppHandle,
+/// `fee_amount` when the caller passed one, otherwise [`covenant_fee`].
+///
+/// The fee is paid from a single wallet UTXO of the fee asset (L-BTC when
+/// omitted), so a computed fee larger than the largest such UTXO is rejected
+/// here, before any transaction is built.
+fn resolve_covenant_fee(
+    node: &deadcat_sdk::DeadcatNode<deadcat_store::DeadcatStore>,
+    params: &deadcat_sdk::PredictionMarketParams,
+    ops: &[deadcat_sdk::CovenantOp],
+    fee_amount: Option<u64>,
+    fee_asset_id: Option<[u8; 32]>,
+) -> Result<u64, String> {
+    if let Some(fee_amount) = fee_amount {
+        return Ok(fee_amount);
+    }
+    let fee = covenant_fee(params, ops)?;
+    let fee_asset = match fee_asset_id {
+        Some(bytes) => deadcat_sdk::elements::AssetId::from_slice(&bytes)
+            .map_err(|e| format!("invalid fee asset id: {e}"))?,
+        None => node.policy_asset(),
+    };
+    let largest = node
+        .utxos()
+        .map_err(|e| format!("{e}"))?
+        .iter()
+        .filter(|u| u.unblinded.asset == fee_asset)
+        .map(|u| u.unblinded.value)
+        .max()
+        .unwrap_or(0);
+    if fee > largest {
+        return Err(format!(
+            "estimated fee of {fee} sats exceeds the largest {fee_asset} UTXO \
+             in the wallet ({largest} sats); consolidate or fund the wallet, \
+             or pass fee_amount explicitly"
+        ));
+    }
+    Ok(fee)
+}
+
+/// Map a funding failure the UI can act on to its [`crate::CommandError`].
+fn describe_funding_error(err: deadcat_sdk::NodeError) -> String {
+    match &err {
+        deadcat_sdk::NodeError::Sdk(deadcat_sdk::Error::SecondUtxoRequired {
+            min_amount, ..
+        }) => crate::CommandError::SplitUtxoRequired {
+            min_amount: *min_amount,
+        }
+        .into(),
+        deadcat_sdk::NodeError::Sdk(deadcat_sdk::Error::NeedMoreUtxos {
+            asset_id,
+            count_needed,
+            count_available,
+        }) => crate::CommandError::NeedMoreUtxos {
+            asset_id: asset_id.to_string(),
+            needed: *count_needed,
+            available: *count_available,
+        }
+        .into(),
+        _ => format!("{err}"),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IssuanceResultResponse {
+    pub txid: String,
+    pub previous_state: u8,
+    pub new_state: u8,
+    pub pairs_issued: u64,
+    /// Base64 unsigned PSET when requested as a dry run; nothing was broadcast.
+    pub unsigned_pset: Option<String>,
+}
+
+/// Issue new YES+NO token pairs by locking collateral.
+#[tauri::command]
+pub async fn issue_tokens(
+    contract_params_json: String,
+    anchor: deadcat_sdk::PredictionMarketAnchor,
+    pairs: u64,
+    dry_run: Option<bool>,
+    fee_amount: Option<u64>,
+    fee_asset_id: Option<String>,
+    app: tauri::AppHandle,
 ) -> Result<IssuanceResultResponse, String> {
     let params: deadcat_sdk::PredictionMarketParams =
         serde_json::from_str(&contract_params_json)
             .map_err(|e| format!("invalid contract params: {e}"))?;
+    let dry_run = dry_run.unwrap_or(false);
+    let fee_asset_id = fee_asset_id
+        .map(|hex| decode_hex_32(&hex, "fee_asset_id"))
+        .transpose()?;
+
+    let _in_flight =
+        crate::InFlightGuard::begin(&app, format!("issue_tokens:{}", params.market_id()))?;
 
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
+    let fee_amount = resolve_covenant_fee(
+        node,
+        &params,
+        &[deadcat_sdk::CovenantOp::IssueTokens],
+        fee_amount,
+        fee_asset_id,
+    )?;
     let result = node
-        .issue_tokens(params, anchor, pairs, 500)
+        .issue_tokens(params, anchor, pairs, fee_amount, fee_asset_id, dry_run)
         .await
-        .map_err(|e| format!("{e}"))?;
+        .map_err(describe_funding_error)?;
     drop(guard);
 
-    bump_revision_and_emit(&app).await?;
+    if !dry_run {
+        record_action(
+            &app,
+            deadcat_store::ActionKind::IssueTokens,
+            Some(hex::encode(params.market_id().as_bytes())),
+            Some(result.txid.to_string()),
+            serde_json::json!({ "pairs": result.pairs_issued, "fee_amount": fee_amount }),
+        );
+        bump_revision_and_emit(&app).await?;
+    }
 
     Ok(IssuanceResultResponse {
         txid: result.txid.to_string(),
         previous_state: result.previous_state as u8,
         new_state: result.new_state as u8,
         pairs_issued: result.pairs_issued,
+        unsigned_pset: result.unsigned_pset,
+    })
+}
+
+#[derive(Serialize)]
+pub struct PreviewIssuanceResponse {
+    pub required_collateral: u64,
+    pub yes_asset: String,
+    pub no_asset: String,
+    pub current_state: u8,
+    /// True when the market is still Dormant, so this would be its first issuance.
+    pub initial_issuance: bool,
+}
+
+/// Show the collateral an `issue_tokens` call for `pairs` would lock and the
+/// assets it would mint, without building a transaction.
+#[tauri::command]
+pub async fn preview_issuance(
+    contract_params_json: String,
+    anchor: deadcat_sdk::PredictionMarketAnchor,
+    pairs: u64,
+    app: tauri::AppHandle,
+) -> Result<PreviewIssuanceResponse, String> {
+    let params: deadcat_sdk::PredictionMarketParams =
+        serde_json::from_str(&contract_params_json)
+            .map_err(|e| format!("invalid contract params: {e}"))?;
+    if pairs == 0 {
+        return Err("pairs must be non-zero".to_string());
+    }
+    let required_collateral =
+        deadcat_sdk::amounts::collateral_for_pairs(pairs, params.collateral_per_token)
+            .map_err(|e| format!("{e}"))?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let current_state = node
+        .market_state(params, anchor)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    let initial_issuance = match current_state {
+        deadcat_sdk::MarketState::Dormant => true,
+        deadcat_sdk::MarketState::Unresolved => false,
+        other => return Err(format!("cannot issue tokens in market state {other:?}")),
+    };
+
+    Ok(PreviewIssuanceResponse {
+        required_collateral,
+        yes_asset: hex::encode(params.yes_token_asset),
+        no_asset: hex::encode(params.no_token_asset),
+        current_state: current_state as u8,
+        initial_issuance,
     })
 }
 
@@ -854,6 +2200,8 @@ pub struct CancellationResultResponse {
     pub new_state: u8,
     pub pairs_burned: u64,
     pub is_full_cancellation: bool,
+    /// Base64 unsigned PSET when requested as a dry run; nothing was broadcast.
+    pub unsigned_pset: Option<String>,
 }
 
 /// Cancel paired YES+NO tokens back into collateral.
@@ -862,22 +2210,52 @@ pub async fn cancel_tokens(
     contract_params_json: String,
     anchor: deadcat_sdk::PredictionMarketAnchor,
     pairs: u64,
+    dry_run: Option<bool>,
+    fee_amount: Option<u64>,
+    fee_asset_id: Option<String>,
     app: tauri::AppHandle,
 ) -> Result<CancellationResultResponse, String> {
     let params: deadcat_sdk::PredictionMarketParams =
         serde_json::from_str(&contract_params_json)
             .map_err(|e| format!("invalid contract params: {e}"))?;
+    let dry_run = dry_run.unwrap_or(false);
+    let fee_asset_id = fee_asset_id
+        .map(|hex| decode_hex_32(&hex, "fee_asset_id"))
+        .transpose()?;
+
+    let _in_flight =
+        crate::InFlightGuard::begin(&app, format!("cancel_tokens:{}", params.market_id()))?;
 
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
+    let fee_amount = resolve_covenant_fee(
+        node,
+        &params,
+        &[deadcat_sdk::CovenantOp::CancelTokens],
+        fee_amount,
+        fee_asset_id,
+    )?;
     let result = node
-        .cancel_tokens(params, anchor, pairs, 500)
+        .cancel_tokens(params, anchor, pairs, fee_amount, fee_asset_id, dry_run)
         .await
         .map_err(|e| format!("{e}"))?;
     drop(guard);
 
-    bump_revision_and_emit(&app).await?;
+    if !dry_run {
+        record_action(
+            &app,
+            deadcat_store::ActionKind::CancelTokens,
+            Some(hex::encode(params.market_id().as_bytes())),
+            Some(result.txid.to_string()),
+            serde_json::json!({
+                "pairs": result.pairs_burned,
+                "full_cancellation": result.is_full_cancellation,
+                "fee_amount": fee_amount,
+            }),
+        );
+        bump_revision_and_emit(&app).await?;
+    }
 
     Ok(CancellationResultResponse {
         txid: result.txid.to_string(),
@@ -885,6 +2263,7 @@ pub async fn cancel_tokens(
         new_state: result.new_state as u8,
         pairs_burned: result.pairs_burned,
         is_full_cancellation: result.is_full_cancellation,
+        unsigned_pset: result.unsigned_pset,
     })
 }
 
@@ -898,6 +2277,8 @@ pub struct ResolutionResultResponse {
     pub previous_state: u8,
     pub new_state: u8,
     pub outcome_yes: bool,
+    /// Base64 unsigned PSET when requested as a dry run; nothing was broadcast.
+    pub unsigned_pset: Option<String>,
 }
 
 /// Resolve a market with an oracle signature.
@@ -907,6 +2288,9 @@ pub async fn resolve_market(
     anchor: deadcat_sdk::PredictionMarketAnchor,
     outcome_yes: bool,
     oracle_signature_hex: String,
+    dry_run: Option<bool>,
+    fee_amount: Option<u64>,
+    confirm_mainnet: Option<bool>,
     app: tauri::AppHandle,
 ) -> Result<ResolutionResultResponse, String> {
     let params: deadcat_sdk::PredictionMarketParams =
@@ -917,23 +2301,249 @@ pub async fn resolve_market(
         .map_err(|e| format!("invalid signature hex: {e}"))?
         .try_into()
         .map_err(|_| "oracle signature must be exactly 64 bytes".to_string())?;
+    let dry_run = dry_run.unwrap_or(false);
+    if !dry_run {
+        crate::require_mainnet_confirmation(&app, confirm_mainnet)?;
+    }
+
+    let _in_flight =
+        crate::InFlightGuard::begin(&app, format!("resolve_market:{}", params.market_id()))?;
 
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
-    let result = node
-        .resolve_market(params, anchor, outcome_yes, sig_bytes, 500)
+    let fee_amount = resolve_covenant_fee(
+        node,
+        &params,
+        &[deadcat_sdk::CovenantOp::ResolveMarket],
+        fee_amount,
+        None,
+    )?;
+    let result = match node
+        .resolve_market(params, anchor, outcome_yes, sig_bytes, fee_amount, dry_run)
         .await
-        .map_err(|e| format!("{e}"))?;
+    {
+        Ok(result) => result,
+        Err(e) => return Err(describe_node_error(node, e, "market already resolved").await),
+    };
     drop(guard);
 
-    bump_revision_and_emit(&app).await?;
+    if !dry_run {
+        record_action(
+            &app,
+            deadcat_store::ActionKind::ResolveMarket,
+            Some(hex::encode(params.market_id().as_bytes())),
+            Some(result.txid.to_string()),
+            serde_json::json!({ "outcome_yes": result.outcome_yes, "fee_amount": fee_amount }),
+        );
+        bump_revision_and_emit(&app).await?;
+    }
 
     Ok(ResolutionResultResponse {
         txid: result.txid.to_string(),
         previous_state: result.previous_state as u8,
         new_state: result.new_state as u8,
         outcome_yes: result.outcome_yes,
+        unsigned_pset: result.unsigned_pset,
+    })
+}
+
+// =========================================================================
+// Oracle operator commands
+// =========================================================================
+
+#[derive(Serialize)]
+pub struct OracleKeyResponse {
+    pub pubkey_hex: String,
+}
+
+#[derive(Serialize)]
+pub struct OracleResolutionResponse {
+    pub attestation: discovery::AttestationResult,
+    /// Set when the on-chain resolution was requested and broadcast.
+    pub resolution: Option<ResolutionResultResponse>,
+}
+
+fn app_data_dir(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("failed to get app data dir: {e}"))
+}
+
+/// The wallet's oracle key, derived from its seed. `None` while the wallet
+/// is locked.
+#[tauri::command]
+pub async fn get_oracle_key(app: tauri::AppHandle) -> Result<Option<OracleKeyResponse>, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let Some(node) = guard.as_ref().filter(|node| node.is_wallet_unlocked()) else {
+        return Ok(None);
+    };
+    let keys = node.oracle_keys().await.map_err(|e| format!("{e}"))?;
+    Ok(Some(OracleKeyResponse {
+        pubkey_hex: keys.public_key().to_hex(),
+    }))
+}
+
+/// Unresolved stored markets committed to `oracle_pubkey`, soonest expiry
+/// first.
+#[tauri::command]
+pub fn list_markets_to_resolve(
+    oracle_pubkey: String,
+    app: tauri::AppHandle,
+) -> Result<Vec<DiscoveredMarket>, String> {
+    let oracle_public_key = decode_hex_32(&oracle_pubkey, "oracle_pubkey")?;
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let infos = store
+        .list_markets(&MarketFilter {
+            oracle_public_key: Some(oracle_public_key),
+            current_state: Some(deadcat_sdk::MarketState::Unresolved),
+            sort_by: Some(deadcat_store::SortBy::Expiry),
+            ..MarketFilter::default()
+        })
+        .map_err(|e| format!("list markets: {e}"))?;
+    Ok(infos
+        .iter()
+        .map(|info| market_info_to_discovered(info, None, None))
+        .collect())
+}
+
+/// The key this app holds for `oracle_public_key`: the wallet's derived
+/// oracle key, a legacy oracle key file or, for markets created from this
+/// wallet, the Nostr identity.
+async fn oracle_keys_for(
+    app: &tauri::AppHandle,
+    oracle_public_key: &[u8; 32],
+) -> Result<Keys, String> {
+    let oracle_pubkey_hex = hex::encode(oracle_public_key);
+    let matches = |keys: &Keys| keys.public_key().to_hex() == oracle_pubkey_hex;
+    if let Some(keys) = discovery::load_legacy_oracle_keys(&app_data_dir(app)?)? {
+        if matches(&keys) {
+            return Ok(keys);
+        }
+    }
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    if matches(node.keys()) {
+        return Ok(node.keys().clone());
+    }
+    if node.is_wallet_unlocked() {
+        let keys = node.oracle_keys().await.map_err(|e| format!("{e}"))?;
+        if matches(&keys) {
+            return Ok(keys);
+        }
+    }
+    Err("this app does not hold the market's oracle key".to_string())
+}
+
+/// Sign `outcome_yes` for a stored market with the matching oracle key and
+/// publish the attestation. With `resolve_onchain`, also broadcast the
+/// resolution transaction.
+#[tauri::command]
+pub async fn publish_oracle_resolution(
+    market_id: String,
+    outcome_yes: bool,
+    resolve_onchain: Option<bool>,
+    fee_amount: Option<u64>,
+    confirm_mainnet: Option<bool>,
+    app: tauri::AppHandle,
+) -> Result<OracleResolutionResponse, String> {
+    let mid = deadcat_sdk::MarketId(decode_hex_32(&market_id, "market_id")?);
+    let info = {
+        let store_arc = get_store(&app)?;
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        store
+            .get_market(&mid)
+            .map_err(|e| format!("get market: {e}"))?
+            .ok_or_else(|| format!("market {market_id} not found"))?
+    };
+    if info.state != deadcat_sdk::MarketState::Unresolved {
+        return Err("market is not awaiting resolution".to_string());
+    }
+    let resolve_onchain = resolve_onchain.unwrap_or(false);
+    if resolve_onchain {
+        crate::require_mainnet_confirmation(&app, confirm_mainnet)?;
+    }
+    let announcement_event_id = info
+        .nostr_event_id
+        .clone()
+        .ok_or("market has no announcement event to attest against")?;
+    let oracle_keys = oracle_keys_for(&app, &info.params.oracle_public_key).await?;
+
+    let _in_flight =
+        crate::InFlightGuard::begin(&app, format!("resolve_market:{}", info.params.market_id()))?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let attestation = node
+        .attest_market_with_keys(&oracle_keys, &mid, &announcement_event_id, outcome_yes)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    if !resolve_onchain {
+        return Ok(OracleResolutionResponse {
+            attestation,
+            resolution: None,
+        });
+    }
+
+    let sig_bytes: [u8; 64] = hex::decode(&attestation.signature_hex)
+        .map_err(|e| format!("invalid signature hex: {e}"))?
+        .try_into()
+        .map_err(|_| "oracle signature must be exactly 64 bytes".to_string())?;
+    let fee_amount = resolve_covenant_fee(
+        node,
+        &info.params,
+        &[deadcat_sdk::CovenantOp::ResolveMarket],
+        fee_amount,
+        None,
+    )?;
+    let result = match node
+        .resolve_market(
+            info.params,
+            info.anchor,
+            outcome_yes,
+            sig_bytes,
+            fee_amount,
+            false,
+        )
+        .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            let e = describe_node_error(node, e, "market already resolved").await;
+            return Err(format!(
+                "attestation published in {} but market not resolved on-chain: {e}",
+                attestation.nostr_event_id
+            ));
+        }
+    };
+    drop(guard);
+
+    record_action(
+        &app,
+        deadcat_store::ActionKind::ResolveMarket,
+        Some(market_id),
+        Some(result.txid.to_string()),
+        serde_json::json!({ "outcome_yes": result.outcome_yes, "fee_amount": fee_amount }),
+    );
+    bump_revision_and_emit(&app).await?;
+
+    Ok(OracleResolutionResponse {
+        attestation,
+        resolution: Some(ResolutionResultResponse {
+            txid: result.txid.to_string(),
+            previous_state: result.previous_state as u8,
+            new_state: result.new_state as u8,
+            outcome_yes: result.outcome_yes,
+            unsigned_pset: None,
+        }),
     })
 }
 
@@ -944,9 +2554,14 @@ pub async fn resolve_market(
 #[derive(Serialize, Deserialize)]
 pub struct RedemptionResultResponse {
     pub txid: String,
+    /// Further transactions when the market's collateral was split across UTXOs.
+    #[serde(default)]
+    pub extra_txids: Vec<String>,
     pub previous_state: u8,
     pub tokens_redeemed: u64,
     pub payout_sats: u64,
+    /// Base64 unsigned PSET when requested as a dry run; nothing was broadcast.
+    pub unsigned_pset: Option<String>,
 }
 
 /// Redeem winning tokens after market resolution.
@@ -955,28 +2570,52 @@ pub async fn redeem_tokens(
     contract_params_json: String,
     anchor: deadcat_sdk::PredictionMarketAnchor,
     tokens: u64,
+    dry_run: Option<bool>,
+    fee_amount: Option<u64>,
     app: tauri::AppHandle,
 ) -> Result<RedemptionResultResponse, String> {
     let params: deadcat_sdk::PredictionMarketParams =
         serde_json::from_str(&contract_params_json)
             .map_err(|e| format!("invalid contract params: {e}"))?;
+    let dry_run = dry_run.unwrap_or(false);
+
+    let _in_flight =
+        crate::InFlightGuard::begin(&app, format!("redeem_tokens:{}", params.market_id()))?;
 
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
+    let fee_amount = resolve_covenant_fee(
+        node,
+        &params,
+        &[deadcat_sdk::CovenantOp::RedeemResolved],
+        fee_amount,
+        None,
+    )?;
     let result = node
-        .redeem_tokens(params, anchor, tokens, 500)
+        .redeem_tokens(params, anchor, tokens, fee_amount, dry_run)
         .await
         .map_err(|e| format!("{e}"))?;
     drop(guard);
 
-    bump_revision_and_emit(&app).await?;
+    if !dry_run {
+        record_redemption(
+            &app,
+            deadcat_store::ActionKind::RedeemTokens,
+            &params,
+            &result,
+            fee_amount,
+        );
+        bump_revision_and_emit(&app).await?;
+    }
 
     Ok(RedemptionResultResponse {
         txid: result.txid.to_string(),
+        extra_txids: result.extra_txids.iter().map(|t| t.to_string()).collect(),
         previous_state: result.previous_state as u8,
         tokens_redeemed: result.tokens_redeemed,
         payout_sats: result.payout_sats,
+        unsigned_pset: result.unsigned_pset,
     })
 }
 
@@ -991,6 +2630,8 @@ pub async fn redeem_expired(
     anchor: deadcat_sdk::PredictionMarketAnchor,
     token_asset_hex: String,
     tokens: u64,
+    dry_run: Option<bool>,
+    fee_amount: Option<u64>,
     app: tauri::AppHandle,
 ) -> Result<RedemptionResultResponse, String> {
     let params: deadcat_sdk::PredictionMarketParams =
@@ -1001,122 +2642,486 @@ pub async fn redeem_expired(
         .map_err(|e| format!("invalid token asset hex: {e}"))?
         .try_into()
         .map_err(|_| "token asset must be exactly 32 bytes".to_string())?;
+    let dry_run = dry_run.unwrap_or(false);
+
+    let _in_flight =
+        crate::InFlightGuard::begin(&app, format!("redeem_expired:{}", params.market_id()))?;
 
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
+    let fee_amount = resolve_covenant_fee(
+        node,
+        &params,
+        &[
+            deadcat_sdk::CovenantOp::ExpireMarket,
+            deadcat_sdk::CovenantOp::RedeemExpired,
+        ],
+        fee_amount,
+        None,
+    )?;
     let result = node
-        .redeem_expired(params, anchor, token_asset, tokens, 500)
+        .redeem_expired(params, anchor, token_asset, tokens, fee_amount, dry_run)
         .await
         .map_err(|e| format!("{e}"))?;
     drop(guard);
 
-    bump_revision_and_emit(&app).await?;
+    if !dry_run {
+        record_redemption(
+            &app,
+            deadcat_store::ActionKind::RedeemExpired,
+            &params,
+            &result,
+            fee_amount,
+        );
+        bump_revision_and_emit(&app).await?;
+    }
 
     Ok(RedemptionResultResponse {
         txid: result.txid.to_string(),
+        extra_txids: result.extra_txids.iter().map(|t| t.to_string()).collect(),
         previous_state: result.previous_state as u8,
         tokens_redeemed: result.tokens_redeemed,
         payout_sats: result.payout_sats,
+        unsigned_pset: result.unsigned_pset,
     })
 }
 
-// =========================================================================
-// Market state query command
-// =========================================================================
-
 #[derive(Serialize, Deserialize)]
-pub struct MarketStateResponse {
-    pub state: u8,
+pub struct ExpiredRedemptionSweepResponse {
+    pub redemptions: Vec<RedemptionResultResponse>,
+    pub total_tokens: u64,
+    pub total_payout_sats: u64,
 }
 
+/// Redeem every YES and NO token the wallet holds for an expired market.
 #[tauri::command]
-pub async fn get_market_state(
+pub async fn redeem_expired_all(
     contract_params_json: String,
     anchor: deadcat_sdk::PredictionMarketAnchor,
+    dry_run: Option<bool>,
+    fee_amount: Option<u64>,
     app: tauri::AppHandle,
-) -> Result<MarketStateResponse, String> {
+) -> Result<ExpiredRedemptionSweepResponse, String> {
     let params: deadcat_sdk::PredictionMarketParams =
         serde_json::from_str(&contract_params_json)
             .map_err(|e| format!("invalid contract params: {e}"))?;
+    let dry_run = dry_run.unwrap_or(false);
+
+    let _in_flight =
+        crate::InFlightGuard::begin(&app, format!("redeem_expired:{}", params.market_id()))?;
 
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
-    let state = node
-        .market_state(params, anchor)
+    let fee_amount = resolve_covenant_fee(
+        node,
+        &params,
+        &[
+            deadcat_sdk::CovenantOp::ExpireMarket,
+            deadcat_sdk::CovenantOp::RedeemExpired,
+        ],
+        fee_amount,
+        None,
+    )?;
+    let sweep = node
+        .redeem_expired_all(params, anchor, fee_amount, dry_run)
         .await
         .map_err(|e| format!("{e}"))?;
+    drop(guard);
 
-    Ok(MarketStateResponse {
-        state: market_state_to_u8(state),
+    if !dry_run {
+        for result in &sweep.redemptions {
+            record_redemption(
+                &app,
+                deadcat_store::ActionKind::RedeemExpired,
+                &params,
+                result,
+                fee_amount,
+            );
+        }
+        bump_revision_and_emit(&app).await?;
+    }
+
+    Ok(ExpiredRedemptionSweepResponse {
+        redemptions: sweep
+            .redemptions
+            .into_iter()
+            .map(|result| RedemptionResultResponse {
+                txid: result.txid.to_string(),
+                extra_txids: result.extra_txids.iter().map(|t| t.to_string()).collect(),
+                previous_state: result.previous_state as u8,
+                tokens_redeemed: result.tokens_redeemed,
+                payout_sats: result.payout_sats,
+                unsigned_pset: result.unsigned_pset,
+            })
+            .collect(),
+        total_tokens: sweep.total_tokens,
+        total_payout_sats: sweep.total_payout_sats,
     })
 }
 
-// =========================================================================
-// Trade quote / execute commands
-// =========================================================================
-
-#[derive(Serialize, Deserialize)]
-pub struct TradeQuoteRequest {
-    pub contract_params_json: String,
-    pub market_id: String,
-    pub side: String,
-    pub direction: String,
-    pub exact_input: u64,
+#[derive(Serialize)]
+pub struct RedemptionPreviewResponse {
+    pub payout_sats: u64,
+    pub is_expiry: bool,
+    pub winning_asset: String,
+    pub current_state: u8,
+    /// Winning tokens the wallet lacks; non-zero means the redemption would fail.
+    pub token_shortfall: u64,
+    /// Collateral the covenant lacks to cover `payout_sats`.
+    pub collateral_shortfall_sats: u64,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct TradeQuoteResponse {
-    pub total_input: u64,
-    pub total_output: u64,
-    pub effective_price: f64,
-    pub legs: Vec<RouteLegResponse>,
-}
+/// Show what redeeming `tokens` would pay out, for a confirmation before
+/// `redeem_tokens` or `redeem_expired`. `token_asset_hex` picks the token to
+/// burn on the expiry path and is ignored once the market is resolved.
+#[tauri::command]
+pub async fn preview_redemption(
+    contract_params_json: String,
+    anchor: deadcat_sdk::PredictionMarketAnchor,
+    tokens: u64,
+    token_asset_hex: Option<String>,
+    app: tauri::AppHandle,
+) -> Result<RedemptionPreviewResponse, String> {
+    let params: deadcat_sdk::PredictionMarketParams =
+        serde_json::from_str(&contract_params_json)
+            .map_err(|e| format!("invalid contract params: {e}"))?;
+    if tokens == 0 {
+        return Err("tokens must be non-zero".to_string());
+    }
+    let token_asset = token_asset_hex
+        .map(|hex| decode_hex_32(&hex, "token_asset_hex"))
+        .transpose()?;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(tag = "kind", rename_all = "snake_case")]
-pub enum RouteLegSourceResponse {
-    LmsrPool {
-        pool_id: String,
-        old_s_index: u64,
-        new_s_index: u64,
-    },
-    LimitOrder {
-        order_id: String,
-        price: u64,
-        lots: u64,
-    },
-}
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let preview = node
+        .preview_redemption(params, anchor, tokens, token_asset)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub struct RouteLegResponse {
-    pub source: RouteLegSourceResponse,
-    pub input_amount: u64,
-    pub output_amount: u64,
+    Ok(RedemptionPreviewResponse {
+        payout_sats: preview.payout_sats,
+        is_expiry: preview.is_expiry,
+        winning_asset: hex::encode(preview.winning_asset),
+        current_state: preview.current_state as u8,
+        token_shortfall: preview.token_shortfall,
+        collateral_shortfall_sats: preview.collateral_shortfall_sats,
+    })
 }
 
-#[derive(Serialize, Deserialize)]
-pub struct ExecuteTradeRequest {
-    pub contract_params_json: String,
-    pub market_id: String,
-    pub side: String,
-    pub direction: String,
-    pub exact_input: u64,
-    #[serde(default)]
-    pub fee_amount: Option<u64>,
-    #[serde(default)]
-    pub expected_quote: Option<TradeQuoteResponse>,
-}
+// =========================================================================
+// CPFP fee bump command
+// =========================================================================
 
 #[derive(Serialize, Deserialize)]
-pub struct ExecuteTradeResponse {
+pub struct FeeBumpResponse {
+    /// Txid of the child transaction.
     pub txid: String,
-    pub total_input: u64,
-    pub total_output: u64,
-    pub num_orders_filled: usize,
-    pub pool_used: bool,
-    pub new_reserves: Option<deadcat_sdk::PoolReserves>,
+    pub parent_txid: String,
+    pub child_fee_sats: u64,
+}
+
+/// Speed up a stuck transaction by spending its wallet change in a child that
+/// pays for both (CPFP). `fee_rate` is in sat/kvB and applies to the package.
+#[tauri::command]
+pub async fn bump_transaction_fee(
+    txid: String,
+    fee_rate: f32,
+    app: tauri::AppHandle,
+) -> Result<FeeBumpResponse, String> {
+    let parent_txid: deadcat_sdk::lwk_wollet::elements::Txid =
+        txid.parse().map_err(|e| format!("invalid txid: {e}"))?;
+
+    let _in_flight =
+        crate::InFlightGuard::begin(&app, format!("bump_transaction_fee:{parent_txid}"))?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let result = node
+        .bump_fee_cpfp(parent_txid, fee_rate)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    bump_revision_and_emit(&app).await?;
+
+    Ok(FeeBumpResponse {
+        txid: result.txid.to_string(),
+        parent_txid: result.parent_txid.to_string(),
+        child_fee_sats: result.child_fee_sats,
+    })
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SweepResponse {
+    pub txid: String,
+    /// Amount received per asset id; L-BTC is net of the fee.
+    pub swept: std::collections::HashMap<String, u64>,
+    pub fee_sats: u64,
+}
+
+/// Parse a WIF or 64-char hex private key. Intermediate buffers are zeroized,
+/// and the key itself is erased when the returned `SweepKey` drops.
+fn parse_sweep_key(input: &str) -> Result<deadcat_sdk::SweepKey, String> {
+    use deadcat_sdk::lwk_wollet::elements::bitcoin::secp256k1::SecretKey;
+    use deadcat_sdk::lwk_wollet::elements::bitcoin::{NetworkKind, PrivateKey};
+
+    let input = input.trim();
+    if let Ok(key) = PrivateKey::from_wif(input) {
+        return Ok(deadcat_sdk::SweepKey::new(key));
+    }
+    let bytes = Zeroizing::new(
+        hex::decode(input).map_err(|_| "private key must be WIF or 32-byte hex".to_string())?,
+    );
+    let secret = SecretKey::from_slice(&bytes).map_err(|_| "invalid private key".to_string())?;
+    Ok(deadcat_sdk::SweepKey::new(PrivateKey::new(
+        secret,
+        NetworkKind::Main,
+    )))
+}
+
+/// Move all funds held by an external private key (paper wallet, WIF export)
+/// into a fresh wallet address. `fee_rate` is in sat/kvB.
+#[tauri::command]
+pub async fn sweep_private_key(
+    wif_or_hex: String,
+    fee_rate: f32,
+    app: tauri::AppHandle,
+) -> Result<SweepResponse, String> {
+    let wif_or_hex = Zeroizing::new(wif_or_hex);
+    let key = parse_sweep_key(&wif_or_hex)?;
+    drop(wif_or_hex);
+
+    let _in_flight = crate::InFlightGuard::begin(&app, "sweep_private_key".to_string())?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let result = node
+        .sweep_private_key(key, fee_rate)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    bump_revision_and_emit(&app).await?;
+
+    Ok(SweepResponse {
+        txid: result.txid.to_string(),
+        swept: result
+            .swept
+            .into_iter()
+            .map(|(asset, amount)| (asset.to_string(), amount))
+            .collect(),
+        fee_sats: result.fee_sats,
+    })
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConsolidationResponse {
+    pub txid: String,
+    pub asset_id: String,
+    pub inputs_consolidated: usize,
+    pub output_value: u64,
+    pub fee_sats: u64,
+}
+
+/// Merge up to `max_inputs` of the wallet's smallest UTXOs of `asset_id`
+/// (L-BTC when omitted) into one output. `fee_rate` is in sat/kvB.
+#[tauri::command]
+pub async fn consolidate_wallet(
+    asset_id: Option<String>,
+    max_inputs: usize,
+    fee_rate: f32,
+    confirm_mainnet: Option<bool>,
+    app: tauri::AppHandle,
+) -> Result<ConsolidationResponse, String> {
+    crate::require_mainnet_confirmation(&app, confirm_mainnet)?;
+    let _operation = crate::OperationGuard::begin(&app)?;
+    let _in_flight = crate::InFlightGuard::begin(&app, "consolidate_wallet".to_string())?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let asset_id = match asset_id {
+        Some(hex) => hex
+            .parse::<deadcat_sdk::elements::AssetId>()
+            .map_err(|e| format!("invalid asset id: {e}"))?,
+        None => node.policy_asset(),
+    };
+    let result = node
+        .consolidate_utxos(asset_id, max_inputs, fee_rate)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    bump_revision_and_emit(&app).await?;
+
+    Ok(ConsolidationResponse {
+        txid: result.txid.to_string(),
+        asset_id: result.asset_id.to_string(),
+        inputs_consolidated: result.inputs_consolidated,
+        output_value: result.output_value,
+        fee_sats: result.fee_sats,
+    })
+}
+
+// =========================================================================
+// Market state query command
+// =========================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct MarketStateResponse {
+    pub state: u8,
+}
+
+#[tauri::command]
+pub async fn get_market_state(
+    contract_params_json: String,
+    anchor: deadcat_sdk::PredictionMarketAnchor,
+    app: tauri::AppHandle,
+) -> Result<MarketStateResponse, String> {
+    let params: deadcat_sdk::PredictionMarketParams =
+        serde_json::from_str(&contract_params_json)
+            .map_err(|e| format!("invalid contract params: {e}"))?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let state = node
+        .market_state(params, anchor)
+        .await
+        .map_err(|e| format!("{e}"))?;
+
+    Ok(MarketStateResponse {
+        state: market_state_to_u8(state),
+    })
+}
+
+#[derive(Serialize)]
+pub struct CovenantSlotAddress {
+    pub slot: deadcat_sdk::MarketSlot,
+    pub address: String,
+    pub script_pubkey: String,
+    pub control_block: String,
+}
+
+#[derive(Serialize)]
+pub struct MarketAddressesResponse {
+    /// Network whose address params were used, e.g. `liquid-testnet`.
+    pub network: String,
+    pub market_id: String,
+    pub cmr: String,
+    pub slots: Vec<CovenantSlotAddress>,
+}
+
+/// Derive every covenant address for a market from its params alone, so the
+/// addresses shown in the UI can be checked by an independent tool.
+#[tauri::command]
+pub fn get_market_addresses(
+    contract_params_json: String,
+    app: tauri::AppHandle,
+) -> Result<MarketAddressesResponse, String> {
+    let params: deadcat_sdk::PredictionMarketParams =
+        serde_json::from_str(&contract_params_json)
+            .map_err(|e| format!("invalid contract params: {e}"))?;
+
+    let network = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        crate::state::to_sdk_network(mgr.network().ok_or("Network not initialized")?)
+    };
+
+    let contract = deadcat_sdk::CompiledPredictionMarket::new(params)
+        .map_err(|e| format!("failed to compile contract: {e}"))?;
+    let slots = deadcat_sdk::MarketSlot::ALL
+        .into_iter()
+        .map(|slot| CovenantSlotAddress {
+            slot,
+            address: contract.address(slot, network.address_params()).to_string(),
+            script_pubkey: hex::encode(contract.script_pubkey(slot).as_bytes()),
+            control_block: hex::encode(contract.control_block(slot)),
+        })
+        .collect();
+
+    Ok(MarketAddressesResponse {
+        network: network.discovery_tag().to_string(),
+        market_id: hex::encode(params.market_id().as_bytes()),
+        cmr: contract.cmr().to_string(),
+        slots,
+    })
+}
+
+// =========================================================================
+// Trade quote / execute commands
+// =========================================================================
+
+#[derive(Serialize, Deserialize)]
+pub struct TradeQuoteRequest {
+    pub contract_params_json: String,
+    pub market_id: String,
+    pub side: String,
+    pub direction: String,
+    pub exact_input: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TradeQuoteResponse {
+    pub total_input: u64,
+    pub total_output: u64,
+    pub effective_price: f64,
+    pub legs: Vec<RouteLegResponse>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RouteLegSourceResponse {
+    LmsrPool {
+        pool_id: String,
+        old_s_index: u64,
+        new_s_index: u64,
+    },
+    LimitOrder {
+        order_id: String,
+        price: u64,
+        lots: u64,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteLegResponse {
+    pub source: RouteLegSourceResponse,
+    pub input_amount: u64,
+    pub output_amount: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExecuteTradeRequest {
+    pub contract_params_json: String,
+    pub market_id: String,
+    pub side: String,
+    pub direction: String,
+    pub exact_input: u64,
+    #[serde(default)]
+    pub fee_amount: Option<u64>,
+    #[serde(default)]
+    pub expected_quote: Option<TradeQuoteResponse>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExecuteTradeResponse {
+    pub txid: String,
+    pub total_input: u64,
+    pub total_output: u64,
+    pub num_orders_filled: usize,
+    pub pool_used: bool,
+    pub new_reserves: Option<deadcat_sdk::PoolReserves>,
 }
 
 fn parse_trade_side(side: &str) -> Result<deadcat_sdk::TradeSide, String> {
@@ -1198,13 +3203,14 @@ fn validate_expected_quote(
 mod trade_command_tests {
     use std::path::PathBuf;
     use std::sync::{Arc, Mutex};
-    use std::time::{SystemTime, UNIX_EPOCH};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
     use super::{
-        execute_trade_inner, get_pool_price_history_inner, get_price_history_inner,
-        parse_trade_direction, parse_trade_side, quote_matches_expected, quote_trade_inner,
-        scan_lmsr_pool_inner, validate_expected_quote, ExecuteTradeRequest, ExecuteTradeResponse,
-        RouteLegResponse, RouteLegSourceResponse, TradeQuoteRequest, TradeQuoteResponse,
+        aggregate_order_book_levels, execute_trade_inner, get_pool_price_history_inner,
+        get_price_history_inner, parse_trade_direction, parse_trade_side, pool_creation_warnings,
+        quote_matches_expected, quote_trade_inner, rescan_start_height, scan_lmsr_pool_inner,
+        validate_expected_quote, ExecuteTradeRequest, ExecuteTradeResponse, RouteLegResponse,
+        RouteLegSourceResponse, TradeQuoteRequest, TradeQuoteResponse,
     };
     use crate::state::AppStateManager;
     use crate::NodeState;
@@ -1331,50 +3337,250 @@ mod trade_command_tests {
         assert!(parse_trade_direction("hold").is_err());
     }
 
-    #[test]
-    fn quote_match_ignores_effective_price() {
-        let expected = sample_quote(1.0);
-        let actual = sample_quote(1.2345);
-        assert!(quote_matches_expected(&actual, &expected));
+    fn sample_order(
+        direction: deadcat_sdk::OrderDirection,
+        price: u64,
+        offered: u64,
+        status: deadcat_store::OrderStatus,
+    ) -> deadcat_store::MakerOrderInfo {
+        deadcat_store::MakerOrderInfo {
+            id: 0,
+            params: deadcat_sdk::MakerOrderParams {
+                base_asset_id: [1; 32],
+                quote_asset_id: [2; 32],
+                price,
+                min_fill_lots: 1,
+                min_remainder_lots: 1,
+                direction,
+                maker_receive_spk_hash: [0; 32],
+                cosigner_pubkey: [0; 32],
+                maker_pubkey: [0; 32],
+            },
+            status,
+            cmr: [0; 32],
+            maker_base_pubkey: None,
+            order_nonce: None,
+            nostr_event_id: None,
+            nostr_event_json: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+            creation_txid: None,
+            market_id: None,
+            direction_label: None,
+            offered_amount: Some(offered),
+        }
     }
 
     #[test]
-    fn quote_match_rejects_leg_differences() {
-        let expected = sample_quote(1.0);
-        let mut actual = sample_quote(1.0);
-        actual.legs[0].output_amount += 1;
-        assert!(!quote_matches_expected(&actual, &expected));
+    fn in_flight_guard_rejects_duplicate_until_released() {
+        let app = mock_trade_app();
+        let handle = app.handle();
+
+        let first = crate::InFlightGuard::begin(handle, "send_lbtc:addr:1000".to_string())
+            .expect("first claim succeeds");
+        let err = match crate::InFlightGuard::begin(handle, "send_lbtc:addr:1000".to_string()) {
+            Ok(_) => panic!("expected duplicate claim to fail"),
+            Err(err) => err,
+        };
+        assert_eq!(err, String::from(crate::CommandError::OperationInProgress));
+
+        // A different key is independent.
+        let _other = crate::InFlightGuard::begin(handle, "send_lbtc:addr:2000".to_string())
+            .expect("different key succeeds");
+
+        drop(first);
+        let _again = crate::InFlightGuard::begin(handle, "send_lbtc:addr:1000".to_string())
+            .expect("claim succeeds after release");
     }
 
-    #[test]
-    fn validate_expected_quote_rejects_mismatch() {
-        let live = sample_quote(1.0);
-        let expected = sample_quote(1.0);
-        let mut mismatched = expected.clone();
-        mismatched.total_output += 1;
-        let err = validate_expected_quote(&live, Some(&mismatched)).unwrap_err();
-        assert!(err.contains("quote changed before execution"));
+    #[tokio::test]
+    async fn cancel_operation_aborts_running_task() {
+        let app = mock_trade_app();
+        let handle = app.handle().clone();
+        let running = tokio::spawn(async move {
+            crate::run_cancellable(&handle, Some("sync".to_string()), |_| async {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(())
+            })
+            .await
+        });
+        while !app
+            .state::<NodeState>()
+            .operations
+            .lock()
+            .unwrap()
+            .contains_key("sync")
+        {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(crate::cancel_operation_inner(app.handle(), "sync").unwrap());
+        assert_eq!(
+            running.await.unwrap(),
+            Err(String::from(crate::CommandError::Cancelled))
+        );
+        assert!(!crate::cancel_operation_inner(app.handle(), "sync").unwrap());
+    }
+
+    #[tokio::test]
+    async fn finished_operation_keeps_a_newer_entry_under_its_id() {
+        let app = mock_trade_app();
+        let newer = crate::CancellationToken::default();
+        let task = tokio::spawn(async {});
+        app.state::<NodeState>().operations.lock().unwrap().insert(
+            "sync".to_string(),
+            crate::RunningOperation {
+                token: newer,
+                abort: task.abort_handle(),
+            },
+        );
+
+        drop(crate::RunningOperationGuard {
+            app: app.handle().clone(),
+            op_id: "sync".to_string(),
+            token: crate::CancellationToken::default(),
+        });
+
+        assert!(app
+            .state::<NodeState>()
+            .operations
+            .lock()
+            .unwrap()
+            .contains_key("sync"));
     }
 
     #[test]
-    fn trade_quote_request_roundtrip() {
-        let request = TradeQuoteRequest {
-            contract_params_json: "{\"oracle_public_key\":[1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1],\"collateral_asset_id\":[2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2],\"yes_token_asset\":[3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3],\"no_token_asset\":[4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4],\"yes_reissuance_token\":[5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5],\"no_reissuance_token\":[6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6],\"collateral_per_token\":1000,\"expiry_time\":12345}".to_string(),
-            market_id: "market-a".to_string(),
-            side: "yes".to_string(),
-            direction: "buy".to_string(),
-            exact_input: 1000,
-        };
-        let json = serde_json::to_string(&request).unwrap();
-        let parsed: TradeQuoteRequest = serde_json::from_str(&json).unwrap();
-        assert_eq!(parsed.market_id, "market-a");
-        assert_eq!(parsed.side, "yes");
-        assert_eq!(parsed.direction, "buy");
-        assert_eq!(parsed.exact_input, 1000);
+    fn command_errors_carry_their_code() {
+        let err = String::from(crate::CommandError::MainnetConfirmationRequired);
+        let parsed: serde_json::Value = serde_json::from_str(&err).unwrap();
+        assert_eq!(parsed["code"], "mainnet_confirmation_required");
+        assert_eq!(parsed["message"], "mainnet confirmation required");
     }
 
     #[test]
-    fn trade_quote_response_roundtrip() {
+    fn mainnet_commands_require_confirmation() {
+        let mut manager = AppStateManager::new(unique_test_app_dir("mainnet-confirm"));
+        manager.set_network(crate::Network::Mainnet);
+        let app = mock_builder()
+            .manage(Mutex::new(manager))
+            .build(mock_context(noop_assets()))
+            .expect("build mock tauri app");
+        let handle = app.handle();
+
+        for unconfirmed in [None, Some(false)] {
+            assert_eq!(
+                crate::require_mainnet_confirmation(handle, unconfirmed),
+                Err(String::from(
+                    crate::CommandError::MainnetConfirmationRequired
+                ))
+            );
+        }
+        assert_eq!(
+            crate::require_mainnet_confirmation(handle, Some(true)),
+            Ok(())
+        );
+
+        let manager = handle.state::<Mutex<AppStateManager>>();
+        manager.lock().unwrap().set_network(crate::Network::Testnet);
+        assert_eq!(crate::require_mainnet_confirmation(handle, None), Ok(()));
+    }
+
+    #[test]
+    fn order_book_levels_aggregate_and_sort_best_first() {
+        use deadcat_sdk::OrderDirection::{SellBase, SellQuote};
+        use deadcat_store::OrderStatus::{Active, Cancelled, PartiallyFilled};
+
+        let orders = vec![
+            sample_order(SellQuote, 40, 100, Active),
+            sample_order(SellQuote, 45, 50, Active),
+            sample_order(SellQuote, 40, 25, PartiallyFilled),
+            sample_order(SellQuote, 48, 10, Cancelled),
+            sample_order(SellBase, 60, 7, Active),
+            sample_order(SellBase, 55, 3, Active),
+            sample_order(SellBase, 70, 1, Active),
+        ];
+
+        let book = aggregate_order_book_levels(&orders, 2);
+        let bids: Vec<_> = book
+            .bids
+            .iter()
+            .map(|l| (l.price, l.order_count, l.total_offered))
+            .collect();
+        let asks: Vec<_> = book
+            .asks
+            .iter()
+            .map(|l| (l.price, l.order_count, l.total_offered))
+            .collect();
+        assert_eq!(bids, vec![(45, 1, 50), (40, 2, 125)]);
+        assert_eq!(asks, vec![(55, 1, 3), (60, 1, 7)]);
+    }
+
+    #[test]
+    fn pool_creation_warns_on_imbalanced_reserves() {
+        let balanced = deadcat_sdk::PoolReserves {
+            r_yes: 1_000,
+            r_no: 1_000,
+            r_lbtc: 1_000_000,
+        };
+        assert!(pool_creation_warnings(&balanced, 5_000, 1_000_000).is_empty());
+
+        let skewed = deadcat_sdk::PoolReserves {
+            r_yes: 9_000,
+            ..balanced
+        };
+        let warnings = pool_creation_warnings(&skewed, 5_000, 1_000_000);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("curve starts at 5000 bps"));
+
+        let thin_lbtc = pool_creation_warnings(&balanced, 5_000, 3_000_000);
+        assert_eq!(thin_lbtc.len(), 1);
+        assert!(thin_lbtc[0].contains("1000000 sats of L-BTC"));
+    }
+
+    #[test]
+    fn quote_match_ignores_effective_price() {
+        let expected = sample_quote(1.0);
+        let actual = sample_quote(1.2345);
+        assert!(quote_matches_expected(&actual, &expected));
+    }
+
+    #[test]
+    fn quote_match_rejects_leg_differences() {
+        let expected = sample_quote(1.0);
+        let mut actual = sample_quote(1.0);
+        actual.legs[0].output_amount += 1;
+        assert!(!quote_matches_expected(&actual, &expected));
+    }
+
+    #[test]
+    fn validate_expected_quote_rejects_mismatch() {
+        let live = sample_quote(1.0);
+        let expected = sample_quote(1.0);
+        let mut mismatched = expected.clone();
+        mismatched.total_output += 1;
+        let err = validate_expected_quote(&live, Some(&mismatched)).unwrap_err();
+        assert!(err.contains("quote changed before execution"));
+    }
+
+    #[test]
+    fn trade_quote_request_roundtrip() {
+        let request = TradeQuoteRequest {
+            contract_params_json: "{\"oracle_public_key\":[1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1],\"collateral_asset_id\":[2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2],\"yes_token_asset\":[3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3,3],\"no_token_asset\":[4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4,4],\"yes_reissuance_token\":[5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5,5],\"no_reissuance_token\":[6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6,6],\"collateral_per_token\":1000,\"expiry_time\":12345}".to_string(),
+            market_id: "market-a".to_string(),
+            side: "yes".to_string(),
+            direction: "buy".to_string(),
+            exact_input: 1000,
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        let parsed: TradeQuoteRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.market_id, "market-a");
+        assert_eq!(parsed.side, "yes");
+        assert_eq!(parsed.direction, "buy");
+        assert_eq!(parsed.exact_input, 1000);
+    }
+
+    #[test]
+    fn trade_quote_response_roundtrip() {
         let response = sample_quote(12.34);
         let json = serde_json::to_string(&response).unwrap();
         let parsed: TradeQuoteResponse = serde_json::from_str(&json).unwrap();
@@ -1435,6 +3641,44 @@ mod trade_command_tests {
         );
     }
 
+    #[test]
+    fn rescan_height_clamps_and_rejects_future_heights() {
+        assert_eq!(rescan_start_height(-5, 1_000).unwrap(), 0);
+        assert_eq!(rescan_start_height(1_000, 1_000).unwrap(), 1_000);
+        assert!(rescan_start_height(1_001, 1_000).is_err());
+        assert!(rescan_start_height(i64::MAX, 1_000).is_err());
+    }
+
+    #[test]
+    fn funding_error_flags_missing_fee_utxo() {
+        let err = deadcat_sdk::NodeError::Sdk(deadcat_sdk::Error::SecondUtxoRequired {
+            asset_id: deadcat_sdk::elements::AssetId::from_slice(&[0x11; 32]).unwrap(),
+            min_amount: 500,
+        });
+        let parsed: serde_json::Value = serde_json::from_str(&describe_funding_error(err)).unwrap();
+        assert_eq!(parsed["code"], "split_utxo_required");
+        assert_eq!(parsed["min_amount"], 500);
+        assert_eq!(
+            describe_funding_error(deadcat_sdk::NodeError::WalletLocked),
+            deadcat_sdk::NodeError::WalletLocked.to_string()
+        );
+    }
+
+    #[test]
+    fn funding_error_flags_missing_utxos() {
+        let asset_id = deadcat_sdk::elements::AssetId::from_slice(&[0x11; 32]).unwrap();
+        let err = deadcat_sdk::NodeError::Sdk(deadcat_sdk::Error::NeedMoreUtxos {
+            asset_id,
+            count_needed: 2,
+            count_available: 1,
+        });
+        let parsed: serde_json::Value = serde_json::from_str(&describe_funding_error(err)).unwrap();
+        assert_eq!(parsed["code"], "need_more_utxos");
+        assert_eq!(parsed["asset_id"], asset_id.to_string());
+        assert_eq!(parsed["needed"], 2);
+        assert_eq!(parsed["available"], 1);
+    }
+
     #[tokio::test]
     async fn quote_trade_command_path_rejects_uninitialized_node() {
         let app = mock_trade_app();
@@ -1524,7 +3768,7 @@ mod trade_command_tests {
         {
             let node_state = app.state::<NodeState>();
             let mut guard = node_state.node.lock().await;
-            *guard = Some(node);
+            *guard = Some(std::sync::Arc::new(node));
         }
 
         let announcement = deadcat_sdk::testing::test_lmsr_pool_announcement(
@@ -1680,6 +3924,9 @@ async fn quote_trade_inner<R: tauri::Runtime>(
     Ok(map_trade_quote(&quote))
 }
 
+/// Network fee for a trade, and for quoting one, when the caller sets none.
+const DEFAULT_TRADE_FEE_SATS: u64 = 500;
+
 async fn execute_trade_inner<R: tauri::Runtime>(
     request: ExecuteTradeRequest,
     app: tauri::AppHandle<R>,
@@ -1689,7 +3936,15 @@ async fn execute_trade_inner<R: tauri::Runtime>(
             .map_err(|e| format!("invalid contract params: {e}"))?;
     let side = parse_trade_side(&request.side)?;
     let direction = parse_trade_direction(&request.direction)?;
-    let fee_amount = request.fee_amount.unwrap_or(500);
+    let fee_amount = request.fee_amount.unwrap_or(DEFAULT_TRADE_FEE_SATS);
+
+    let _in_flight = crate::InFlightGuard::begin(
+        &app,
+        format!(
+            "execute_trade:{}:{}:{}:{}",
+            request.market_id, request.side, request.direction, request.exact_input
+        ),
+    )?;
 
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
@@ -1706,12 +3961,32 @@ async fn execute_trade_inner<R: tauri::Runtime>(
         .map_err(|e| format!("{e}"))?;
     let live_quote = map_trade_quote(&quote);
     validate_expected_quote(&live_quote, request.expected_quote.as_ref())?;
-    let result = node
+    let result = match node
         .execute_trade(quote, fee_amount, &request.market_id)
         .await
-        .map_err(|e| format!("{e}"))?;
+    {
+        Ok(result) => result,
+        Err(e) => {
+            let message = "order already filled; refresh the quote and try again";
+            return Err(describe_node_error(node, e, message).await);
+        }
+    };
     drop(guard);
 
+    record_action(
+        &app,
+        deadcat_store::ActionKind::Swap,
+        Some(request.market_id.clone()),
+        Some(result.txid.to_string()),
+        serde_json::json!({
+            "side": request.side,
+            "direction": request.direction,
+            "total_input": result.total_input,
+            "total_output": result.total_output,
+            "orders_filled": result.num_orders_filled,
+            "pool_used": result.pool_used,
+        }),
+    );
     bump_revision_and_emit(&app).await?;
 
     Ok(ExecuteTradeResponse {
@@ -1732,6 +4007,88 @@ pub async fn quote_trade(
     quote_trade_inner(request, app).await
 }
 
+#[derive(Deserialize)]
+pub struct BestQuoteRequest {
+    pub contract_params_json: String,
+    pub market_id: String,
+    pub side: String,
+    pub direction: String,
+    pub exact_input: u64,
+    /// Network fee charged to every route; defaults to
+    /// [`DEFAULT_TRADE_FEE_SATS`], like a trade.
+    #[serde(default)]
+    pub fee_amount: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct RouteOutcomeResponse {
+    pub total_input: u64,
+    pub total_output: u64,
+    pub unfilled_input: u64,
+    pub net_price: f64,
+    pub legs: Vec<RouteLegResponse>,
+}
+
+#[derive(Serialize)]
+pub struct BestQuoteResponse {
+    pub via_pool: Option<RouteOutcomeResponse>,
+    pub via_orders: Option<RouteOutcomeResponse>,
+    pub via_split: Option<RouteOutcomeResponse>,
+    /// "pool", "orders" or "split"; absent when no venue has liquidity.
+    pub recommended: Option<String>,
+}
+
+/// Quote a trade through the pool alone, the order book alone and both, so
+/// the UI can show which route nets the most.
+#[tauri::command]
+pub async fn get_best_quote(
+    request: BestQuoteRequest,
+    app: tauri::AppHandle,
+) -> Result<BestQuoteResponse, String> {
+    let params: deadcat_sdk::PredictionMarketParams =
+        serde_json::from_str(&request.contract_params_json)
+            .map_err(|e| format!("invalid contract params: {e}"))?;
+    let side = parse_trade_side(&request.side)?;
+    let direction = parse_trade_direction(&request.direction)?;
+    let fee_amount = request.fee_amount.unwrap_or(DEFAULT_TRADE_FEE_SATS);
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let comparison = node
+        .compare_trade_routes(
+            params,
+            &request.market_id,
+            side,
+            direction,
+            request.exact_input,
+            fee_amount,
+        )
+        .await
+        .map_err(|e| format!("{e}"))?;
+
+    let map_outcome = |outcome: deadcat_sdk::RouteOutcome| RouteOutcomeResponse {
+        total_input: outcome.total_input,
+        total_output: outcome.total_output,
+        unfilled_input: outcome.unfilled_input,
+        net_price: outcome.net_price,
+        legs: outcome.legs.into_iter().map(map_route_leg).collect(),
+    };
+    Ok(BestQuoteResponse {
+        via_pool: comparison.via_pool.map(map_outcome),
+        via_orders: comparison.via_orders.map(map_outcome),
+        via_split: comparison.via_split.map(map_outcome),
+        recommended: comparison.recommended.map(|venue| {
+            match venue {
+                deadcat_sdk::TradeVenue::Pool => "pool",
+                deadcat_sdk::TradeVenue::Orders => "orders",
+                deadcat_sdk::TradeVenue::Split => "split",
+            }
+            .to_string()
+        }),
+    })
+}
+
 #[tauri::command]
 pub async fn execute_trade(
     request: ExecuteTradeRequest,
@@ -1768,8 +4125,33 @@ pub async fn get_wallet_utxos(
 // Market store commands
 // =========================================================================
 
+/// Sort key accepted by the listing commands.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListSortBy {
+    CreatedAt,
+    Expiry,
+    Volume,
+    Price,
+}
+
+impl From<ListSortBy> for deadcat_store::SortBy {
+    fn from(sort_by: ListSortBy) -> Self {
+        match sort_by {
+            ListSortBy::CreatedAt => deadcat_store::SortBy::CreatedAt,
+            ListSortBy::Expiry => deadcat_store::SortBy::Expiry,
+            ListSortBy::Volume => deadcat_store::SortBy::Volume,
+            ListSortBy::Price => deadcat_store::SortBy::Price,
+        }
+    }
+}
+
 #[tauri::command]
-pub fn list_contracts(app: tauri::AppHandle) -> Result<Vec<DiscoveredMarket>, String> {
+pub fn list_contracts(
+    app: tauri::AppHandle,
+    sort_by: Option<ListSortBy>,
+    descending: Option<bool>,
+) -> Result<Vec<DiscoveredMarket>, String> {
     let store_arc = {
         let state_handle = app.state::<Mutex<AppStateManager>>();
         let mgr = state_handle
@@ -1785,7 +4167,11 @@ pub fn list_contracts(app: tauri::AppHandle) -> Result<Vec<DiscoveredMarket>, St
         .map_err(|_| "store lock failed".to_string())?;
 
     let infos = store
-        .list_markets(&MarketFilter::default())
+        .list_markets(&MarketFilter {
+            sort_by: sort_by.map(Into::into),
+            descending: descending.unwrap_or(false),
+            ..MarketFilter::default()
+        })
         .map_err(|e| format!("list markets: {e}"))?;
 
     let mut result = Vec::with_capacity(infos.len());
@@ -1795,6 +4181,74 @@ pub fn list_contracts(app: tauri::AppHandle) -> Result<Vec<DiscoveredMarket>, St
     Ok(result)
 }
 
+/// Search stored markets by question, category and description, best
+/// matches first.
+#[tauri::command]
+pub fn search_markets(
+    query: String,
+    app: tauri::AppHandle,
+) -> Result<Vec<DiscoveredMarket>, String> {
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+
+    let infos = store
+        .search_markets(&query)
+        .map_err(|e| format!("search markets: {e}"))?;
+    Ok(infos
+        .iter()
+        .map(|info| market_info_to_discovered(info, None, None))
+        .collect())
+}
+
+#[derive(Serialize)]
+pub struct MarketCategoryCount {
+    pub category: String,
+    pub market_count: usize,
+}
+
+/// Distinct market categories with how many stored markets use each.
+#[tauri::command]
+pub fn list_market_categories(app: tauri::AppHandle) -> Result<Vec<MarketCategoryCount>, String> {
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let categories = store
+        .list_categories()
+        .map_err(|e| format!("list categories: {e}"))?;
+    Ok(categories
+        .into_iter()
+        .map(|c| MarketCategoryCount {
+            category: c.category,
+            market_count: c.market_count,
+        })
+        .collect())
+}
+
+/// Stored markets in `category`, matched case-insensitively.
+#[tauri::command]
+pub fn list_markets_by_category(
+    category: String,
+    app: tauri::AppHandle,
+) -> Result<Vec<DiscoveredMarket>, String> {
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let infos = store
+        .list_markets(&MarketFilter {
+            category: Some(category),
+            ..MarketFilter::default()
+        })
+        .map_err(|e| format!("list markets: {e}"))?;
+    Ok(infos
+        .iter()
+        .map(|info| market_info_to_discovered(info, None, None))
+        .collect())
+}
+
 /// Convert a `MarketInfo` (store type) back to `DiscoveredMarket` (frontend type).
 fn market_info_to_discovered(
     info: &deadcat_store::MarketInfo,
@@ -1862,6 +4316,10 @@ pub struct CreateLimitOrderResponse {
     pub covenant_address: String,
     pub order_amount: u64,
     pub order_index: u32,
+    pub relays_accepted: usize,
+    pub relays_total: usize,
+    /// Set when the announcement reached some relays but not all of them.
+    pub publish_warning: Option<String>,
 }
 
 #[tauri::command]
@@ -1898,25 +4356,33 @@ pub async fn create_limit_order(
         }
     );
 
-    let order_index: u32 = 0;
+    let order_index: u32 = WALLET_KEY_INDEX;
 
     let fee_amount = request.fee_amount.unwrap_or(500);
 
+    let _in_flight = crate::InFlightGuard::begin(
+        &app,
+        format!(
+            "create_limit_order:{}:{}:{}:{}:{}",
+            request.market_id, request.side, request.direction, request.price, request.amount
+        ),
+    )?;
+
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
     let market_id_for_store = request.market_id.clone();
     let direction_label_for_store = direction_label.clone();
 
-    let (result, event_id) = node
+    let (result, report) = node
         .create_limit_order(
             base_asset_id,
             quote_asset_id,
             request.price,
             request.amount,
             order_direction,
-            1,
-            1,
+            ORDER_MIN_FILL_LOTS,
+            ORDER_MIN_REMAINDER_LOTS,
             order_index,
             fee_amount,
             request.market_id,
@@ -1927,54 +4393,126 @@ pub async fn create_limit_order(
     drop(guard);
 
     // Persist the order to the local store for transaction labeling
-    {
-        let store_arc = {
-            let state_handle = app.state::<Mutex<AppStateManager>>();
-            let mgr = state_handle.lock().ok();
-            mgr.and_then(|m| m.store().cloned())
-        };
-        if let Some(store_arc) = store_arc {
-            if let Ok(mut store) = store_arc.lock() {
-                // Ingest the order (deduplicates on cmr + maker_base_pubkey)
-                let event_id_hex = event_id.to_hex();
-                if let Err(e) = store.ingest_maker_order(
-                    &result.order_params,
-                    Some(&result.maker_base_pubkey),
-                    Some(&result.order_nonce),
-                    Some(event_id_hex.as_str()),
-                    None,
-                ) {
-                    log::warn!("failed to ingest order into store: {e}");
-                }
-                // Record the creation metadata
-                let compiled = deadcat_sdk::CompiledMakerOrder::new(result.order_params);
-                if let Ok(compiled) = compiled {
-                    if let Err(e) = store.record_order_creation(
-                        compiled.cmr().as_ref(),
-                        &result.maker_base_pubkey,
-                        &result.txid.to_string(),
-                        &market_id_for_store,
-                        &direction_label_for_store,
-                        result.order_amount,
-                    ) {
-                        log::warn!("failed to record order creation: {e}");
-                    }
-                }
-            }
-        }
-    }
+    record_created_order(
+        &app,
+        &result,
+        &report.event_id.to_hex(),
+        &market_id_for_store,
+        &direction_label_for_store,
+    );
 
+    record_action(
+        &app,
+        deadcat_store::ActionKind::CreateLimitOrder,
+        Some(market_id_for_store),
+        Some(result.txid.to_string()),
+        serde_json::json!({
+            "direction": direction_label_for_store,
+            "price": request.price,
+            "amount": result.order_amount,
+            "nostr_event_id": report.event_id.to_hex(),
+        }),
+    );
     bump_revision_and_emit(&app).await?;
 
     Ok(CreateLimitOrderResponse {
         txid: result.txid.to_string(),
-        nostr_event_id: event_id.to_hex(),
+        nostr_event_id: report.event_id.to_hex(),
         covenant_address: result.covenant_address,
         order_amount: result.order_amount,
         order_index,
+        relays_accepted: report.accepted.len(),
+        relays_total: report.relay_count(),
+        publish_warning: report.warning(),
     })
 }
 
+/// Store an order this wallet just created, with the local metadata used
+/// to label its transactions.
+fn record_created_order(
+    app: &tauri::AppHandle,
+    result: &deadcat_sdk::CreateOrderResult,
+    event_id_hex: &str,
+    market_id: &str,
+    direction_label: &str,
+) {
+    let store_arc = {
+        let state_handle = app.state::<Mutex<AppStateManager>>();
+        let mgr = state_handle.lock().ok();
+        mgr.and_then(|m| m.store().cloned())
+    };
+    if let Some(store_arc) = store_arc {
+        if let Ok(mut store) = store_arc.lock() {
+            // Ingest the order (deduplicates on cmr + maker_base_pubkey)
+            if let Err(e) = store.ingest_maker_order(
+                &result.order_params,
+                Some(&result.maker_base_pubkey),
+                Some(&result.order_nonce),
+                Some(event_id_hex),
+                None,
+            ) {
+                log::warn!("failed to ingest order into store: {e}");
+            }
+            // Record the creation metadata
+            let compiled = deadcat_sdk::CompiledMakerOrder::new(result.order_params);
+            if let Ok(compiled) = compiled {
+                if let Err(e) = store.record_order_creation(
+                    compiled.cmr().as_ref(),
+                    &result.maker_base_pubkey,
+                    &result.txid.to_string(),
+                    market_id,
+                    direction_label,
+                    result.order_amount,
+                ) {
+                    log::warn!("failed to record order creation: {e}");
+                }
+            }
+        }
+    }
+}
+
+/// Lot constraints applied to orders created from the app.
+const ORDER_MIN_FILL_LOTS: u64 = 1;
+const ORDER_MIN_REMAINDER_LOTS: u64 = 1;
+
+#[derive(Serialize, Deserialize)]
+pub struct ValidateOrderParamsRequest {
+    pub direction: String,
+    pub price: u64,
+    pub amount: u64,
+    #[serde(default)]
+    pub min_fill_lots: Option<u64>,
+    #[serde(default)]
+    pub min_remainder_lots: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ValidateOrderParamsResponse {
+    pub order_lots: u64,
+}
+
+/// Check a limit order's amounts before any funds are locked in its covenant.
+#[tauri::command]
+pub fn validate_order_params(
+    request: ValidateOrderParamsRequest,
+) -> Result<ValidateOrderParamsResponse, String> {
+    let order_direction = match parse_trade_direction(&request.direction)? {
+        deadcat_sdk::TradeDirection::Buy => deadcat_sdk::OrderDirection::SellQuote,
+        deadcat_sdk::TradeDirection::Sell => deadcat_sdk::OrderDirection::SellBase,
+    };
+    let order_lots = deadcat_sdk::validate_order_amounts(
+        request.price,
+        request.amount,
+        order_direction,
+        request.min_fill_lots.unwrap_or(ORDER_MIN_FILL_LOTS),
+        request
+            .min_remainder_lots
+            .unwrap_or(ORDER_MIN_REMAINDER_LOTS),
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(ValidateOrderParamsResponse { order_lots })
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct CancelLimitOrderRequest {
     pub market_id: String,
@@ -1992,15 +4530,28 @@ pub struct CancelLimitOrderRequest {
     pub fee_amount: Option<u64>,
     #[serde(default)]
     pub order_index: Option<u32>,
+    /// Replace a taker's unconfirmed fill with the cancel when it signals RBF.
+    #[serde(default)]
+    pub outbid_pending_fill: bool,
+    /// Event id of the order's Nostr announcement. When set, the
+    /// announcement is deleted once the cancel is broadcast so the order
+    /// stops showing in discovery.
+    #[serde(default)]
+    pub delete_announcement_id: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct CancelLimitOrderResponse {
     pub txid: String,
     pub refunded_amount: u64,
+    pub replaced_fill_txid: Option<String>,
+    pub announcement_deleted: bool,
+    /// Why the announcement could not be deleted, or which relays missed
+    /// the deletion. The cancel itself succeeded either way.
+    pub announcement_warning: Option<String>,
 }
 
-fn decode_hex_32(hex_str: &str, field: &str) -> Result<[u8; 32], String> {
+pub(crate) fn decode_hex_32(hex_str: &str, field: &str) -> Result<[u8; 32], String> {
     hex::decode(hex_str)
         .map_err(|e| format!("invalid {field} hex: {e}"))?
         .try_into()
@@ -2046,72 +4597,1749 @@ pub async fn cancel_limit_order(
 
     let order_index: u32 = request.order_index.unwrap_or(0);
 
+    let _in_flight = crate::InFlightGuard::begin(
+        &app,
+        format!(
+            "cancel_limit_order:{}:{}",
+            request.maker_base_pubkey, request.order_nonce
+        ),
+    )?;
+
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
     let result = node
-        .cancel_limit_order(params, maker_pubkey, order_index, fee_amount)
+        .cancel_limit_order(
+            params,
+            maker_pubkey,
+            order_index,
+            fee_amount,
+            request.outbid_pending_fill,
+        )
         .await
         .map_err(|e| format!("{e}"))?;
+
+    let (announcement_deleted, announcement_warning) = match &request.delete_announcement_id {
+        Some(event_id) => match node.delete_announcement(event_id).await {
+            Ok(report) => (true, report.warning()),
+            Err(e) => (false, Some(format!("announcement not deleted: {e}"))),
+        },
+        None => (false, None),
+    };
     drop(guard);
 
+    record_action(
+        &app,
+        deadcat_store::ActionKind::CancelLimitOrder,
+        None,
+        Some(result.txid.to_string()),
+        serde_json::json!({
+            "order_nonce": request.order_nonce,
+            "refunded_amount": result.refunded_amount,
+        }),
+    );
     bump_revision_and_emit(&app).await?;
 
     Ok(CancelLimitOrderResponse {
         txid: result.txid.to_string(),
         refunded_amount: result.refunded_amount,
+        replaced_fill_txid: result.replaced_fill_txid.map(|txid| txid.to_string()),
+        announcement_deleted,
+        announcement_warning,
     })
 }
 
-// =========================================================================
-// Own order listing (for transaction labeling)
-// =========================================================================
-
-#[derive(Serialize)]
-pub struct OwnOrderSummary {
-    pub creation_txid: Option<String>,
-    pub market_id: Option<String>,
-    pub direction_label: Option<String>,
+#[derive(Serialize, Deserialize)]
+pub struct TopUpLimitOrderRequest {
+    pub market_id: String,
+    pub base_asset_id: String,
+    pub quote_asset_id: String,
     pub price: u64,
-    pub offered_amount: Option<u64>,
-    pub order_status: String,
-}
-
-#[tauri::command]
-pub fn list_own_orders(app: tauri::AppHandle) -> Result<Vec<OwnOrderSummary>, String> {
+    pub min_fill_lots: u64,
+    pub min_remainder_lots: u64,
+    pub direction: String,
+    pub direction_label: String,
+    pub maker_base_pubkey: String,
+    pub order_nonce: String,
+    pub cosigner_pubkey: String,
+    pub maker_receive_spk_hash: String,
+    pub additional_amount: u64,
+    #[serde(default)]
+    pub fee_amount: Option<u64>,
+    #[serde(default)]
+    pub order_index: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TopUpLimitOrderResponse {
+    pub txid: String,
+    pub previous_amount: u64,
+    pub offered_amount: u64,
+    /// Event id of the announcement that replaced the order's earlier one.
+    pub nostr_event_id: String,
+    pub relays_accepted: usize,
+    pub relays_total: usize,
+    pub publish_warning: Option<String>,
+}
+
+/// Add funds to one of our open orders in a single transaction and
+/// re-announce it with the new size.
+#[tauri::command]
+pub async fn top_up_limit_order(
+    request: TopUpLimitOrderRequest,
+    app: tauri::AppHandle,
+) -> Result<TopUpLimitOrderResponse, String> {
+    let base_asset_id = decode_hex_32(&request.base_asset_id, "base_asset_id")?;
+    let quote_asset_id = decode_hex_32(&request.quote_asset_id, "quote_asset_id")?;
+    let maker_pubkey = decode_hex_32(&request.maker_base_pubkey, "maker_base_pubkey")?;
+    let order_nonce = decode_hex_32(&request.order_nonce, "order_nonce")?;
+    let cosigner_pubkey = decode_hex_32(&request.cosigner_pubkey, "cosigner_pubkey")?;
+    let maker_receive_spk_hash =
+        decode_hex_32(&request.maker_receive_spk_hash, "maker_receive_spk_hash")?;
+    let direction = parse_order_direction(&request.direction)?;
+
+    let params = deadcat_sdk::MakerOrderParams {
+        base_asset_id,
+        quote_asset_id,
+        price: request.price,
+        min_fill_lots: request.min_fill_lots,
+        min_remainder_lots: request.min_remainder_lots,
+        direction,
+        maker_receive_spk_hash,
+        cosigner_pubkey,
+        maker_pubkey,
+    };
+
+    let fee_amount = request.fee_amount.unwrap_or(500);
+
+    let order_index: u32 = request.order_index.unwrap_or(0);
+
+    // Shares the cancel key: a top-up spends the order like a cancel does.
+    let _in_flight = crate::InFlightGuard::begin(
+        &app,
+        format!(
+            "cancel_limit_order:{}:{}",
+            request.maker_base_pubkey, request.order_nonce
+        ),
+    )?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let (result, report) = node
+        .top_up_limit_order(
+            params,
+            maker_pubkey,
+            order_nonce,
+            order_index,
+            request.additional_amount,
+            fee_amount,
+            request.market_id.clone(),
+            request.direction_label,
+        )
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    let event_id_hex = report.event_id.to_hex();
+    {
+        let store_arc = get_store(&app)?;
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        let recorded = deadcat_sdk::CompiledMakerOrder::new(params)
+            .map_err(|e| e.to_string())
+            .and_then(|compiled| {
+                store
+                    .record_order_top_up(
+                        compiled.cmr().as_ref(),
+                        &maker_pubkey,
+                        result.order_amount,
+                        &event_id_hex,
+                    )
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = recorded {
+            log::warn!("failed to record order top-up: {e}");
+        }
+    }
+
+    record_action(
+        &app,
+        deadcat_store::ActionKind::TopUpLimitOrder,
+        Some(request.market_id),
+        Some(result.txid.to_string()),
+        serde_json::json!({
+            "order_nonce": request.order_nonce,
+            "additional_amount": request.additional_amount,
+            "amount": result.order_amount,
+            "nostr_event_id": event_id_hex,
+        }),
+    );
+    bump_revision_and_emit(&app).await?;
+
+    Ok(TopUpLimitOrderResponse {
+        txid: result.txid.to_string(),
+        previous_amount: result.previous_amount,
+        offered_amount: result.order_amount,
+        nostr_event_id: event_id_hex,
+        relays_accepted: report.accepted.len(),
+        relays_total: report.relay_count(),
+        publish_warning: report.warning(),
+    })
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RepriceLimitOrderRequest {
+    pub market_id: String,
+    pub base_asset_id: String,
+    pub quote_asset_id: String,
+    pub price: u64,
+    pub min_fill_lots: u64,
+    pub min_remainder_lots: u64,
+    pub direction: String,
+    pub direction_label: String,
+    pub maker_base_pubkey: String,
+    pub order_nonce: String,
+    pub cosigner_pubkey: String,
+    pub maker_receive_spk_hash: String,
+    pub new_price: u64,
+    #[serde(default)]
+    pub fee_amount: Option<u64>,
+    #[serde(default)]
+    pub order_index: Option<u32>,
+    /// Event id of the old order's announcement, deleted once the new
+    /// order is announced.
+    #[serde(default)]
+    pub delete_announcement_id: Option<String>,
+}
+
+/// Outcome of a reprice. When the cancel went through but the new order
+/// could not be created, the create fields are `None` and `recreate_error`
+/// says why; the refund is then back in the wallet.
+#[derive(Serialize, Deserialize)]
+pub struct RepriceLimitOrderResponse {
+    pub cancel_txid: String,
+    pub create_txid: Option<String>,
+    pub refunded_amount: u64,
+    pub order_amount: Option<u64>,
+    pub nostr_event_id: Option<String>,
+    pub covenant_address: Option<String>,
+    pub relays_accepted: usize,
+    pub relays_total: usize,
+    pub publish_warning: Option<String>,
+    pub announcement_deleted: bool,
+    pub announcement_warning: Option<String>,
+    pub recreate_error: Option<String>,
+}
+
+/// Move one of our open orders to a new price: cancel it, recreate it at
+/// the new price from the refund, and swap the announcements. The cancel
+/// is recorded even when the recreate fails.
+#[tauri::command]
+pub async fn reprice_limit_order(
+    request: RepriceLimitOrderRequest,
+    app: tauri::AppHandle,
+) -> Result<RepriceLimitOrderResponse, String> {
+    let base_asset_id = decode_hex_32(&request.base_asset_id, "base_asset_id")?;
+    let quote_asset_id = decode_hex_32(&request.quote_asset_id, "quote_asset_id")?;
+    let maker_pubkey = decode_hex_32(&request.maker_base_pubkey, "maker_base_pubkey")?;
+    let cosigner_pubkey = decode_hex_32(&request.cosigner_pubkey, "cosigner_pubkey")?;
+    let maker_receive_spk_hash =
+        decode_hex_32(&request.maker_receive_spk_hash, "maker_receive_spk_hash")?;
+    let direction = parse_order_direction(&request.direction)?;
+
+    let params = deadcat_sdk::MakerOrderParams {
+        base_asset_id,
+        quote_asset_id,
+        price: request.price,
+        min_fill_lots: request.min_fill_lots,
+        min_remainder_lots: request.min_remainder_lots,
+        direction,
+        maker_receive_spk_hash,
+        cosigner_pubkey,
+        maker_pubkey,
+    };
+
+    let fee_amount = request.fee_amount.unwrap_or(500);
+
+    let order_index: u32 = request.order_index.unwrap_or(0);
+
+    // Shares the cancel key: a reprice starts by cancelling the order.
+    let _in_flight = crate::InFlightGuard::begin(
+        &app,
+        format!(
+            "cancel_limit_order:{}:{}",
+            request.maker_base_pubkey, request.order_nonce
+        ),
+    )?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let (result, report) = node
+        .reprice_limit_order(
+            params,
+            maker_pubkey,
+            order_index,
+            request.new_price,
+            fee_amount,
+            request.market_id.clone(),
+            request.direction_label.clone(),
+        )
+        .await
+        .map_err(|e| format!("{e}"))?;
+
+    let (announcement_deleted, announcement_warning) = match &request.delete_announcement_id {
+        Some(event_id) => match node.delete_announcement(event_id).await {
+            Ok(report) => (true, report.warning()),
+            Err(e) => (false, Some(format!("announcement not deleted: {e}"))),
+        },
+        None => (false, None),
+    };
+    drop(guard);
+
+    record_action(
+        &app,
+        deadcat_store::ActionKind::CancelLimitOrder,
+        Some(request.market_id.clone()),
+        Some(result.cancel.txid.to_string()),
+        serde_json::json!({
+            "order_nonce": request.order_nonce,
+            "refunded_amount": result.cancel.refunded_amount,
+        }),
+    );
+
+    let mut response = RepriceLimitOrderResponse {
+        cancel_txid: result.cancel.txid.to_string(),
+        create_txid: None,
+        refunded_amount: result.cancel.refunded_amount,
+        order_amount: None,
+        nostr_event_id: None,
+        covenant_address: None,
+        relays_accepted: 0,
+        relays_total: 0,
+        publish_warning: None,
+        announcement_deleted,
+        announcement_warning,
+        recreate_error: None,
+    };
+    if let (Ok(created), Some(report)) = (&result.create, &report) {
+        let event_id_hex = report.event_id.to_hex();
+        record_created_order(
+            &app,
+            created,
+            &event_id_hex,
+            &request.market_id,
+            &request.direction_label,
+        );
+        record_action(
+            &app,
+            deadcat_store::ActionKind::CreateLimitOrder,
+            Some(request.market_id),
+            Some(created.txid.to_string()),
+            serde_json::json!({
+                "direction": request.direction_label,
+                "price": request.new_price,
+                "amount": created.order_amount,
+                "nostr_event_id": event_id_hex,
+                "repriced_from": request.price,
+            }),
+        );
+        response.create_txid = Some(created.txid.to_string());
+        response.order_amount = Some(created.order_amount);
+        response.nostr_event_id = Some(event_id_hex);
+        response.covenant_address = Some(created.covenant_address.clone());
+        response.relays_accepted = report.accepted.len();
+        response.relays_total = report.relay_count();
+        response.publish_warning = report.warning();
+    }
+    response.recreate_error = result.create.err();
+    bump_revision_and_emit(&app).await?;
+
+    Ok(response)
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct CancelAllLimitOrdersRequest {
+    /// Store ids of the orders to cancel; all open own orders when omitted.
+    #[serde(default)]
+    pub order_ids: Option<Vec<i32>>,
+    #[serde(default)]
+    pub fee_amount: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CancelAllOrderOutcome {
+    pub order_id: i32,
+    pub market_id: Option<String>,
+    pub txid: Option<String>,
+    pub refunded_amount: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[tauri::command]
+pub async fn cancel_all_limit_orders(
+    request: CancelAllLimitOrdersRequest,
+    app: tauri::AppHandle,
+) -> Result<Vec<CancelAllOrderOutcome>, String> {
+    let fee_amount = request.fee_amount.unwrap_or(500);
+    let _in_flight = crate::InFlightGuard::begin(&app, "cancel_all_limit_orders".to_string())?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let maker_base_pubkey = node
+        .maker_base_pubkey(WALLET_KEY_INDEX)
+        .await
+        .map_err(|e| format!("{e}"))?;
+
+    let store_arc = get_store(&app)?;
+    let orders: Vec<deadcat_store::MakerOrderInfo> = {
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        store
+            .list_my_maker_orders(&maker_base_pubkey)
+            .map_err(|e| format!("list orders: {e}"))?
+            .into_iter()
+            .filter(|o| o.status.is_open())
+            .filter(|o| match &request.order_ids {
+                Some(ids) => ids.contains(&o.id),
+                None => true,
+            })
+            .collect()
+    };
+    if orders.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let targets = orders
+        .iter()
+        .map(|o| deadcat_sdk::CancelOrderTarget {
+            params: o.params,
+            maker_base_pubkey,
+            order_index: WALLET_KEY_INDEX,
+        })
+        .collect();
+    let outcomes = node
+        .cancel_all_orders(targets, fee_amount)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    let mut results = Vec::with_capacity(outcomes.len());
+    for (order, outcome) in orders.into_iter().zip(outcomes) {
+        match outcome.result {
+            Ok(cancelled) => {
+                if let Ok(mut store) = store_arc.lock() {
+                    if let Err(e) =
+                        store.update_order_status(order.id, deadcat_store::OrderStatus::Cancelled)
+                    {
+                        log::warn!("failed to mark order {} cancelled: {e}", order.id);
+                    }
+                }
+                record_action(
+                    &app,
+                    deadcat_store::ActionKind::CancelLimitOrder,
+                    order.market_id.clone(),
+                    Some(cancelled.txid.to_string()),
+                    serde_json::json!({
+                        "order_id": order.id,
+                        "refunded_amount": cancelled.refunded_amount,
+                    }),
+                );
+                results.push(CancelAllOrderOutcome {
+                    order_id: order.id,
+                    market_id: order.market_id,
+                    txid: Some(cancelled.txid.to_string()),
+                    refunded_amount: Some(cancelled.refunded_amount),
+                    error: None,
+                });
+            }
+            Err(e) => results.push(CancelAllOrderOutcome {
+                order_id: order.id,
+                market_id: order.market_id,
+                txid: None,
+                refunded_amount: None,
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+
+    bump_revision_and_emit(&app).await?;
+
+    Ok(results)
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SimulateOrderFillRequest {
+    pub base_asset_id: String,
+    pub quote_asset_id: String,
+    pub price: u64,
+    pub min_fill_lots: u64,
+    pub min_remainder_lots: u64,
+    pub direction: String,
+    pub maker_base_pubkey: String,
+    pub cosigner_pubkey: String,
+    pub maker_receive_spk_hash: String,
+    pub lots: u64,
+    #[serde(default)]
+    pub fee_amount: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SimulateOrderFillResponse {
+    pub can_afford: bool,
+    pub is_partial: bool,
+    pub input_asset_id: String,
+    pub input_needed: u64,
+    pub output_asset_id: String,
+    pub output_received: u64,
+    pub fee_est: u64,
+    /// Missing amount of the input asset; zero when affordable.
+    pub input_shortfall: u64,
+    /// Missing L-BTC for the fee; zero when affordable.
+    pub fee_shortfall: u64,
+}
+
+#[tauri::command]
+pub async fn simulate_order_fill(
+    request: SimulateOrderFillRequest,
+    app: tauri::AppHandle,
+) -> Result<SimulateOrderFillResponse, String> {
+    let maker_pubkey = decode_hex_32(&request.maker_base_pubkey, "maker_base_pubkey")?;
+    let params = deadcat_sdk::MakerOrderParams {
+        base_asset_id: decode_hex_32(&request.base_asset_id, "base_asset_id")?,
+        quote_asset_id: decode_hex_32(&request.quote_asset_id, "quote_asset_id")?,
+        price: request.price,
+        min_fill_lots: request.min_fill_lots,
+        min_remainder_lots: request.min_remainder_lots,
+        direction: parse_order_direction(&request.direction)?,
+        maker_receive_spk_hash: decode_hex_32(
+            &request.maker_receive_spk_hash,
+            "maker_receive_spk_hash",
+        )?,
+        cosigner_pubkey: decode_hex_32(&request.cosigner_pubkey, "cosigner_pubkey")?,
+        maker_pubkey,
+    };
+    let fee_amount = request.fee_amount.unwrap_or(500);
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let sim = node
+        .simulate_fill(params, maker_pubkey, request.lots, fee_amount)
+        .await
+        .map_err(|e| format!("{e}"))?;
+
+    Ok(SimulateOrderFillResponse {
+        can_afford: sim.can_afford,
+        is_partial: sim.is_partial,
+        input_asset_id: sim.input_asset.to_string(),
+        input_needed: sim.input_needed,
+        output_asset_id: sim.output_asset.to_string(),
+        output_received: sim.output_received,
+        fee_est: sim.fee_est,
+        input_shortfall: sim.input_shortfall,
+        fee_shortfall: sim.fee_shortfall,
+    })
+}
+
+// =========================================================================
+// Own order listing (for transaction labeling)
+// =========================================================================
+
+#[derive(Serialize)]
+pub struct OwnOrderSummary {
+    pub creation_txid: Option<String>,
+    pub market_id: Option<String>,
+    pub direction_label: Option<String>,
+    pub price: u64,
+    pub offered_amount: Option<u64>,
+    pub order_status: String,
+}
+
+fn map_own_order(o: deadcat_store::MakerOrderInfo) -> OwnOrderSummary {
+    OwnOrderSummary {
+        creation_txid: o.creation_txid,
+        market_id: o.market_id,
+        direction_label: o.direction_label,
+        price: o.params.price,
+        offered_amount: o.offered_amount,
+        order_status: format!("{:?}", o.status),
+    }
+}
+
+#[tauri::command]
+pub fn list_own_orders(
+    app: tauri::AppHandle,
+    sort_by: Option<ListSortBy>,
+    descending: Option<bool>,
+) -> Result<Vec<OwnOrderSummary>, String> {
     let store_arc = {
         let state_handle = app.state::<Mutex<AppStateManager>>();
         let mgr = state_handle
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.store()
-            .cloned()
-            .ok_or_else(|| "Store not initialized".to_string())?
+        mgr.store()
+            .cloned()
+            .ok_or_else(|| "Store not initialized".to_string())?
+    };
+
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+
+    // Only return orders that have local creation metadata (creation_txid IS NOT NULL)
+    let all_orders = store
+        .list_maker_orders(&deadcat_store::OrderFilter {
+            sort_by: sort_by.map(Into::into),
+            descending: descending.unwrap_or(false),
+            ..deadcat_store::OrderFilter::default()
+        })
+        .map_err(|e| format!("list orders: {e}"))?;
+
+    let own: Vec<OwnOrderSummary> = all_orders
+        .into_iter()
+        .filter(|o| o.creation_txid.is_some())
+        .map(map_own_order)
+        .collect();
+
+    Ok(own)
+}
+
+// =========================================================================
+// Wallet sync summary
+// =========================================================================
+
+#[derive(Serialize)]
+pub struct MarketStateChangeSummary {
+    pub market_id: String,
+    pub old_state: u8,
+    pub new_state: u8,
+}
+
+#[derive(Serialize)]
+pub struct OrderStatusChangeSummary {
+    pub order_id: i32,
+    pub old_status: String,
+    pub new_status: String,
+}
+
+/// Unspent explicit output found on one of the wallet's explicit addresses.
+#[derive(Serialize)]
+pub struct ExplicitUtxoSummary {
+    pub index: u32,
+    pub txid: String,
+    pub vout: u32,
+    pub asset_id: String,
+    pub amount: u64,
+}
+
+/// What changed during `sync_wallet`.
+///
+/// Store sync failures don't fail the wallet sync; they are reported in
+/// `warnings` and the store-derived fields are left empty.
+#[derive(Serialize, Default)]
+pub struct WalletSyncSummary {
+    pub block_height: Option<u32>,
+    pub new_utxos: u32,
+    pub spent_utxos: u32,
+    pub market_state_changes: Vec<MarketStateChangeSummary>,
+    /// Market transitions seen on chain but not yet deep enough to be final.
+    pub tentative_market_states: Vec<MarketStateChangeSummary>,
+    pub order_status_changes: Vec<OrderStatusChangeSummary>,
+    pub pool_snapshots: usize,
+    /// Funds sitting on explicit receive addresses. The confidential wallet
+    /// scan can't see these, so they are not part of the wallet balance.
+    pub explicit_utxos: Vec<ExplicitUtxoSummary>,
+    pub warnings: Vec<String>,
+}
+
+impl WalletSyncSummary {
+    pub(crate) fn record_store_report(&mut self, report: deadcat_store::SyncReport) {
+        self.block_height = Some(report.block_height);
+        self.new_utxos = report.new_utxos;
+        self.spent_utxos = report.spent_utxos;
+        let summarize = |change: deadcat_store::MarketStateChange| MarketStateChangeSummary {
+            market_id: hex::encode(change.market_id.as_bytes()),
+            old_state: market_state_to_u8(change.old_state),
+            new_state: market_state_to_u8(change.new_state),
+        };
+        self.market_state_changes = report
+            .market_state_changes
+            .into_iter()
+            .map(summarize)
+            .collect();
+        self.tentative_market_states = report
+            .tentative_market_states
+            .into_iter()
+            .map(summarize)
+            .collect();
+        self.order_status_changes = report
+            .order_status_changes
+            .into_iter()
+            .map(|change| OrderStatusChangeSummary {
+                order_id: change.order_id,
+                old_status: format!("{:?}", change.old_status),
+                new_status: format!("{:?}", change.new_status),
+            })
+            .collect();
+    }
+}
+
+/// Payload of the `market_resolved` event.
+#[derive(Serialize, Clone)]
+pub struct MarketResolvedEvent {
+    pub market_id: String,
+    /// `"yes"` or `"no"`.
+    pub outcome: &'static str,
+    pub winning_tokens: u64,
+    pub losing_tokens: u64,
+}
+
+/// Resolutions in `report` for markets the wallet holds YES or NO tokens in.
+pub(crate) fn held_market_resolutions(
+    store: &mut deadcat_store::DeadcatStore,
+    report: &deadcat_store::SyncReport,
+    balance: &std::collections::HashMap<deadcat_sdk::elements::AssetId, u64>,
+) -> Vec<MarketResolvedEvent> {
+    let held = |asset: &[u8; 32]| {
+        deadcat_sdk::elements::AssetId::from_slice(asset)
+            .ok()
+            .and_then(|id| balance.get(&id).copied())
+            .unwrap_or(0)
+    };
+
+    let mut events = Vec::new();
+    for change in &report.market_state_changes {
+        let yes_won = match change.new_state {
+            deadcat_sdk::MarketState::ResolvedYes => true,
+            deadcat_sdk::MarketState::ResolvedNo => false,
+            _ => continue,
+        };
+        let market = match store.get_market(&change.market_id) {
+            Ok(Some(market)) => market,
+            Ok(None) => continue,
+            Err(e) => {
+                log::warn!("failed to load resolved market {}: {e}", change.market_id);
+                continue;
+            }
+        };
+        let yes_tokens = held(&market.params.yes_token_asset);
+        let no_tokens = held(&market.params.no_token_asset);
+        if yes_tokens == 0 && no_tokens == 0 {
+            continue;
+        }
+        let (outcome, winning_tokens, losing_tokens) = if yes_won {
+            ("yes", yes_tokens, no_tokens)
+        } else {
+            ("no", no_tokens, yes_tokens)
+        };
+        events.push(MarketResolvedEvent {
+            market_id: hex::encode(change.market_id.as_bytes()),
+            outcome,
+            winning_tokens,
+            losing_tokens,
+        });
+    }
+    events
+}
+
+// =========================================================================
+// Expiry warnings
+// =========================================================================
+
+/// Payload of the `market_expiring` event.
+#[derive(Serialize, Clone)]
+pub struct MarketExpiringEvent {
+    pub market_id: String,
+    pub expiry_height: u32,
+    pub blocks_remaining: u32,
+    pub yes_tokens: u64,
+    pub no_tokens: u64,
+}
+
+/// Payload of the `order_expiring` event. Maker orders have no deadline of
+/// their own, so this tracks the expiry of the order's market.
+#[derive(Serialize, Clone)]
+pub struct OrderExpiringEvent {
+    pub order_id: i32,
+    pub market_id: String,
+    pub expiry_height: u32,
+    pub blocks_remaining: u32,
+}
+
+#[derive(Default)]
+pub(crate) struct ExpiringPositions {
+    pub markets: Vec<MarketExpiringEvent>,
+    pub orders: Vec<OrderExpiringEvent>,
+}
+
+/// Held tokens and open orders in unresolved markets that expire within
+/// `window` blocks after `tip_height`.
+pub(crate) async fn expiring_positions(
+    app: &tauri::AppHandle,
+    tip_height: u32,
+    window: u32,
+) -> Result<ExpiringPositions, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let balance = node.balance().map_err(|e| format!("{e}"))?;
+    let maker_base_pubkey = node
+        .maker_base_pubkey(WALLET_KEY_INDEX)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    drop(guard);
+
+    let store_arc = get_store(app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let expiring = store
+        .list_markets(&MarketFilter {
+            current_state: Some(deadcat_sdk::MarketState::Unresolved),
+            expiry_after: Some(tip_height),
+            expiry_before: Some(tip_height.saturating_add(window).saturating_add(1)),
+            ..MarketFilter::default()
+        })
+        .map_err(|e| format!("list markets: {e}"))?;
+    if expiring.is_empty() {
+        return Ok(ExpiringPositions::default());
+    }
+    let open_orders: Vec<_> = store
+        .list_my_maker_orders(&maker_base_pubkey)
+        .map_err(|e| format!("list orders: {e}"))?
+        .into_iter()
+        .filter(|o| o.status.is_open())
+        .collect();
+    drop(store);
+
+    let held = |asset: &[u8; 32]| {
+        deadcat_sdk::elements::AssetId::from_slice(asset)
+            .ok()
+            .and_then(|id| balance.get(&id).copied())
+            .unwrap_or(0)
+    };
+
+    let mut positions = ExpiringPositions::default();
+    for market in expiring {
+        let market_id = hex::encode(market.market_id.as_bytes());
+        let expiry_height = market.params.expiry_time;
+        let blocks_remaining = expiry_height.saturating_sub(tip_height);
+
+        let yes_tokens = held(&market.params.yes_token_asset);
+        let no_tokens = held(&market.params.no_token_asset);
+        if yes_tokens > 0 || no_tokens > 0 {
+            positions.markets.push(MarketExpiringEvent {
+                market_id: market_id.clone(),
+                expiry_height,
+                blocks_remaining,
+                yes_tokens,
+                no_tokens,
+            });
+        }
+
+        for order in open_orders.iter().filter(|o| {
+            o.market_id
+                .as_deref()
+                .is_some_and(|id| id.eq_ignore_ascii_case(&market_id))
+        }) {
+            positions.orders.push(OrderExpiringEvent {
+                order_id: order.id,
+                market_id: market_id.clone(),
+                expiry_height,
+                blocks_remaining,
+            });
+        }
+    }
+    Ok(positions)
+}
+
+// =========================================================================
+// Price alerts
+// =========================================================================
+
+#[derive(Serialize)]
+pub struct PriceAlertResponse {
+    pub id: i32,
+    pub pool_id: String,
+    pub direction: String,
+    pub threshold_bps: u16,
+    pub triggered: bool,
+    pub created_at: String,
+}
+
+impl From<deadcat_store::PriceAlert> for PriceAlertResponse {
+    fn from(alert: deadcat_store::PriceAlert) -> Self {
+        Self {
+            id: alert.id,
+            pool_id: alert.pool_id,
+            direction: alert.direction.as_str().to_string(),
+            threshold_bps: alert.threshold_bps,
+            triggered: alert.triggered,
+            created_at: alert.created_at,
+        }
+    }
+}
+
+/// Payload of the `price_alert` event.
+#[derive(Serialize, Clone)]
+pub struct PriceAlertEvent {
+    pub alert_id: i32,
+    pub pool_id: String,
+    pub market_id: String,
+    pub direction: String,
+    pub threshold_bps: u16,
+    pub yes_price_bps: u16,
+}
+
+#[tauri::command]
+pub fn create_price_alert(
+    pool_id: String,
+    direction: String,
+    threshold_bps: u16,
+    app: tauri::AppHandle,
+) -> Result<PriceAlertResponse, String> {
+    let direction = deadcat_store::PriceAlertDirection::parse(&direction)
+        .ok_or_else(|| format!("invalid alert direction: {direction}"))?;
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let known = store
+        .list_lmsr_pools(&deadcat_store::LmsrPoolFilter {
+            pool_id: Some(pool_id.clone()),
+            limit: Some(1),
+            ..Default::default()
+        })
+        .map_err(|e| format!("list pools: {e}"))?;
+    if known.is_empty() {
+        return Err(format!("unknown pool: {pool_id}"));
+    }
+    store
+        .create_price_alert(&pool_id, direction, threshold_bps)
+        .map(PriceAlertResponse::from)
+        .map_err(|e| format!("create price alert: {e}"))
+}
+
+#[tauri::command]
+pub fn list_price_alerts(
+    pool_id: Option<String>,
+    app: tauri::AppHandle,
+) -> Result<Vec<PriceAlertResponse>, String> {
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let alerts = store
+        .list_price_alerts(pool_id.as_deref())
+        .map_err(|e| format!("list price alerts: {e}"))?;
+    Ok(alerts.into_iter().map(PriceAlertResponse::from).collect())
+}
+
+#[tauri::command]
+pub fn delete_price_alert(id: i32, app: tauri::AppHandle) -> Result<(), String> {
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let deleted = store
+        .delete_price_alert(id)
+        .map_err(|e| format!("delete price alert: {e}"))?;
+    if !deleted {
+        return Err(format!("price alert {id} not found"));
+    }
+    Ok(())
+}
+
+/// Compare every alert against its pool's current YES spot price.
+///
+/// Returns the alerts that crossed their threshold since the last check and
+/// re-arms those whose price moved back, so each crossing fires once.
+pub(crate) fn crossed_price_alerts(app: &tauri::AppHandle) -> Result<Vec<PriceAlertEvent>, String> {
+    let store_arc = get_store(app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let alerts = store
+        .list_price_alerts(None)
+        .map_err(|e| format!("list price alerts: {e}"))?;
+    if alerts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pools: std::collections::HashMap<String, deadcat_store::LmsrPoolInfo> = store
+        .list_lmsr_pools(&deadcat_store::LmsrPoolFilter::default())
+        .map_err(|e| format!("list pools: {e}"))?
+        .into_iter()
+        .map(|pool| (pool.pool_id.clone(), pool))
+        .collect();
+
+    let mut crossed = Vec::new();
+    for alert in alerts {
+        let Some(pool) = pools.get(&alert.pool_id) else {
+            continue;
+        };
+        let Some((yes_price_bps, _)) = lmsr_pool_spot_prices(pool) else {
+            continue;
+        };
+        let fired = store
+            .observe_price_alert(&alert, yes_price_bps)
+            .map_err(|e| format!("update price alert: {e}"))?;
+        if fired {
+            crossed.push(PriceAlertEvent {
+                alert_id: alert.id,
+                pool_id: alert.pool_id,
+                market_id: pool.market_id.clone(),
+                direction: alert.direction.as_str().to_string(),
+                threshold_bps: alert.threshold_bps,
+                yes_price_bps,
+            });
+        }
+    }
+    Ok(crossed)
+}
+
+// =========================================================================
+// Diagnostics
+// =========================================================================
+
+/// Upper bound on any single diagnostic probe.
+const DIAGNOSTIC_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize)]
+pub struct DiagnosticCheck {
+    pub ok: bool,
+    pub detail: String,
+    pub elapsed_ms: u64,
+}
+
+#[derive(Serialize)]
+pub struct RelayDiagnostic {
+    pub url: String,
+    pub check: DiagnosticCheck,
+}
+
+#[derive(Serialize)]
+pub struct DiagnosticsReport {
+    pub wallet_unlocked: bool,
+    pub electrum_url: String,
+    pub electrum: DiagnosticCheck,
+    pub esplora: DiagnosticCheck,
+    pub boltz: DiagnosticCheck,
+    pub relays: Vec<RelayDiagnostic>,
+    pub store: DiagnosticCheck,
+}
+
+/// Run `probe` under [`DIAGNOSTIC_CHECK_TIMEOUT`], describing success with
+/// `describe`.
+async fn run_diagnostic_check<T>(
+    probe: impl std::future::Future<Output = Result<T, String>>,
+    describe: impl FnOnce(T) -> String,
+) -> DiagnosticCheck {
+    let started = std::time::Instant::now();
+    let outcome = tokio::time::timeout(DIAGNOSTIC_CHECK_TIMEOUT, probe).await;
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    let (ok, detail) = match outcome {
+        Ok(Ok(value)) => (true, describe(value)),
+        Ok(Err(e)) => (false, e),
+        Err(_) => (
+            false,
+            format!("timed out after {}s", DIAGNOSTIC_CHECK_TIMEOUT.as_secs()),
+        ),
+    };
+    DiagnosticCheck {
+        ok,
+        detail,
+        elapsed_ms,
+    }
+}
+
+/// Check wallet, chain, Boltz, relay and store health in one report.
+///
+/// Every probe runs concurrently with its own timeout, so a hung endpoint
+/// only fails its own check.
+#[tauri::command]
+pub async fn run_diagnostics(app: tauri::AppHandle) -> Result<DiagnosticsReport, String> {
+    let (network, boltz_config) = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.boltz_service_config()
+            .ok_or("Not initialized - select a network first")?
+    };
+
+    let (wallet_unlocked, electrum_url) = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref();
+        (
+            node.is_some_and(|n| n.is_wallet_unlocked()),
+            node.and_then(|n| n.electrum_url()).unwrap_or_else(|| {
+                crate::state::to_sdk_network(network)
+                    .default_electrum_url()
+                    .to_string()
+            }),
+        )
+    };
+
+    let relay_urls = {
+        let nostr_state = app.state::<NostrAppState>();
+        let guard = nostr_state
+            .relay_list
+            .read()
+            .map_err(|_| "failed to read relay_list".to_string())?;
+        guard.clone()
+    };
+
+    let electrum = {
+        let url = electrum_url.clone();
+        run_diagnostic_check(
+            async move {
+                // The blocking call outlives a timed-out check, so bound the
+                // socket too; the client is dropped, closing it, on return.
+                tokio::task::spawn_blocking(move || {
+                    crate::chain_adapter::ElectrumChainAdapter::new(&url)
+                        .with_timeout(DIAGNOSTIC_CHECK_TIMEOUT)
+                        .best_block_height()
+                        .map_err(|e| e.to_string())
+                })
+                .await
+                .map_err(|e| format!("electrum check failed: {e}"))?
+            },
+            |height| format!("tip height {height}"),
+        )
+    };
+
+    let esplora_timeout = crate::network_timeouts(&app).esplora();
+    let esplora_url = crate::esplora_url(&app, network);
+    let esplora = run_diagnostic_check(
+        crate::fetch_chain_tip_inner(network.into(), &esplora_url, esplora_timeout),
+        |tip| format!("tip height {}", tip.height),
+    );
+
+    let boltz = run_diagnostic_check(
+        async move {
+            crate::payments::boltz::BoltzService::new(
+                network,
+                crate::payments::boltz::BoltzServiceConfig {
+                    retry_policy: crate::payments::boltz::RetryPolicy::none(),
+                    ..boltz_config
+                },
+            )
+            .get_chain_swap_pairs_info()
+            .await
+            .map_err(|e| e.to_string())
+        },
+        |_| "chain swap pairs fetched".to_string(),
+    );
+
+    let nostr_timeout = crate::network_timeouts(&app).nostr_connect();
+    let relays = async move {
+        let mut tasks = tokio::task::JoinSet::new();
+        for url in relay_urls {
+            tasks.spawn(async move {
+                let probe_url = url.clone();
+                let check = run_diagnostic_check(
+                    async move {
+                        let client = discovery::connect_multi_relay_client(
+                            std::slice::from_ref(&probe_url),
+                            nostr_timeout,
+                        )
+                        .await?;
+                        let connected = client
+                            .relays()
+                            .await
+                            .values()
+                            .any(|relay| relay.is_connected());
+                        let _ = client.disconnect().await;
+                        if connected {
+                            Ok(())
+                        } else {
+                            Err("could not connect".to_string())
+                        }
+                    },
+                    |()| "connected".to_string(),
+                )
+                .await;
+                RelayDiagnostic { url, check }
+            });
+        }
+        let mut results = Vec::new();
+        while let Some(result) = tasks.join_next().await {
+            if let Ok(r) = result {
+                results.push(r);
+            }
+        }
+        results.sort_by(|a, b| a.url.cmp(&b.url));
+        results
+    };
+
+    let store = {
+        let store_arc = get_store(&app);
+        run_diagnostic_check(
+            async move {
+                let store_arc = store_arc?;
+                tokio::task::spawn_blocking(move || {
+                    let mut store = store_arc
+                        .lock()
+                        .map_err(|_| "store lock failed".to_string())?;
+                    let problems = store.integrity_errors().map_err(|e| e.to_string())?;
+                    if problems.is_empty() {
+                        Ok(())
+                    } else {
+                        Err(format!("integrity check failed: {}", problems.join("; ")))
+                    }
+                })
+                .await
+                .map_err(|e| format!("store check failed: {e}"))?
+            },
+            |()| "integrity check ok".to_string(),
+        )
+    };
+
+    let (electrum, esplora, boltz, relays, store) =
+        tokio::join!(electrum, esplora, boltz, relays, store);
+
+    Ok(DiagnosticsReport {
+        wallet_unlocked,
+        electrum_url,
+        electrum,
+        esplora,
+        boltz,
+        relays,
+        store,
+    })
+}
+
+#[derive(Serialize)]
+pub struct PolicyAssetResponse {
+    pub asset_id: String,
+    /// "L-BTC" on mainnet, "tL-BTC" on testnet and regtest.
+    pub ticker: String,
+}
+
+/// The network's policy asset, for labelling balances and fee inputs.
+#[tauri::command]
+pub async fn get_policy_asset(app: tauri::AppHandle) -> Result<PolicyAssetResponse, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    Ok(PolicyAssetResponse {
+        asset_id: node.policy_asset().to_string(),
+        ticker: node.network().policy_asset_ticker().to_string(),
+    })
+}
+
+#[derive(Serialize)]
+pub struct NodeInfoResponse {
+    pub network: String,
+    pub wallet_unlocked: bool,
+    /// Signer fingerprint and account xpub; `None` while the wallet is locked.
+    pub fingerprint: Option<String>,
+    pub xpub: Option<String>,
+    /// Last block the wallet synced to; `None` while the wallet is locked.
+    pub wallet_synced_height: Option<u32>,
+    /// Last block the store's chain sync processed.
+    pub store_synced_height: Option<u32>,
+    /// Current chain tip; `None` when the chain backend is unreachable.
+    pub tip_height: Option<u32>,
+    pub market_count: usize,
+    pub order_count: usize,
+    pub pool_count: usize,
+    pub sdk_version: String,
+}
+
+/// Summarize the wallet, store and chain in one read-only report.
+#[tauri::command]
+pub async fn get_node_info(app: tauri::AppHandle) -> Result<NodeInfoResponse, String> {
+    let network = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.network()
+            .ok_or("Not initialized - select a network first")?
+    };
+
+    let (wallet_unlocked, wallet_info) = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref();
+        (
+            node.is_some_and(|n| n.is_wallet_unlocked()),
+            node.and_then(|n| n.wallet_info()),
+        )
+    };
+
+    let store_arc = get_store(&app)?;
+    let (counts, store_synced_height) = tokio::task::spawn_blocking(move || {
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        let counts = store
+            .record_counts()
+            .map_err(|e| format!("store counts: {e}"))?;
+        Ok::<_, String>((counts, store.last_synced_height().ok()))
+    })
+    .await
+    .map_err(|e| format!("task join: {e}"))??;
+
+    let esplora_timeout = crate::network_timeouts(&app).esplora();
+    let esplora_url = crate::esplora_url(&app, network);
+    let tip_height =
+        match crate::fetch_chain_tip_inner(network.into(), &esplora_url, esplora_timeout).await {
+            Ok(tip) => Some(tip.height),
+            Err(e) => {
+                log::debug!("node info: chain tip unavailable: {e}");
+                None
+            }
+        };
+
+    Ok(NodeInfoResponse {
+        network: network.as_str().to_string(),
+        wallet_unlocked,
+        fingerprint: wallet_info.as_ref().map(|w| w.fingerprint.clone()),
+        xpub: wallet_info.as_ref().map(|w| w.xpub.clone()),
+        wallet_synced_height: wallet_info.as_ref().map(|w| w.synced_height),
+        store_synced_height,
+        tip_height,
+        market_count: counts.markets,
+        order_count: counts.maker_orders,
+        pool_count: counts.lmsr_pools,
+        sdk_version: deadcat_sdk::VERSION.to_string(),
+    })
+}
+
+// =========================================================================
+// Regtest faucet
+// =========================================================================
+
+#[cfg(feature = "regtest")]
+#[derive(Serialize)]
+pub struct RegtestFundResponse {
+    pub txid: String,
+    pub blocks_mined: u64,
+}
+
+/// Send `amount_sat` L-BTC from the configured regtest node to `address` and
+/// mine a block to confirm it. Errors on any other network. Only built and
+/// registered with the `regtest` feature.
+#[cfg(feature = "regtest")]
+#[tauri::command]
+pub async fn regtest_fund(
+    app: tauri::AppHandle,
+    address: String,
+    amount_sat: u64,
+) -> Result<RegtestFundResponse, String> {
+    let network = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.network()
+            .ok_or("Not initialized - select a network first")?
+    };
+    if network != crate::Network::Regtest {
+        return Err(format!(
+            "regtest_fund is only available on regtest, not {}",
+            network.as_str()
+        ));
+    }
+
+    let funding = tokio::task::spawn_blocking(move || crate::regtest::fund(&address, amount_sat))
+        .await
+        .map_err(|e| format!("task join: {e}"))??;
+    Ok(RegtestFundResponse {
+        txid: funding.txid,
+        blocks_mined: funding.blocks_mined,
+    })
+}
+
+// =========================================================================
+// Store maintenance
+// =========================================================================
+
+#[derive(Serialize)]
+pub struct StoreIntegrityResponse {
+    pub ok: bool,
+    pub problems: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct StoreRebuildSummary {
+    pub markets: usize,
+    pub orders: usize,
+    pub pools: usize,
+    /// Result of the follow-up wallet sync; `None` while the wallet is locked.
+    pub sync: Option<WalletSyncSummary>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct StoreSchemaVersionResponse {
+    /// Latest applied migration version.
+    pub version: Option<String>,
+    /// Set when the on-disk store failed to migrate and was rolled back; the
+    /// app is then running on an in-memory store until [`rebuild_store`].
+    pub migration_error: Option<String>,
+}
+
+#[tauri::command]
+pub async fn get_store_schema_version(
+    app: tauri::AppHandle,
+) -> Result<StoreSchemaVersionResponse, String> {
+    let (store_arc, migration_error) = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let store = mgr.store().cloned().ok_or("Store not initialized")?;
+        (store, mgr.store_migration_error().map(str::to_string))
     };
-
     let mut store = store_arc
         .lock()
         .map_err(|_| "store lock failed".to_string())?;
+    let version = store
+        .schema_version()
+        .map_err(|e| format!("schema version: {e}"))?;
+    Ok(StoreSchemaVersionResponse {
+        version,
+        migration_error,
+    })
+}
 
-    // Only return orders that have local creation metadata (creation_txid IS NOT NULL)
-    let all_orders = store
-        .list_maker_orders(&deadcat_store::OrderFilter::default())
-        .map_err(|e| format!("list orders: {e}"))?;
+/// Run SQLite's integrity check on the store. When it fails, the frontend
+/// should offer [`rebuild_store`].
+#[tauri::command]
+pub async fn check_store_integrity(
+    app: tauri::AppHandle,
+) -> Result<StoreIntegrityResponse, String> {
+    let store_arc = get_store(&app)?;
+    let problems = tokio::task::spawn_blocking(move || {
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        store
+            .integrity_errors()
+            .map_err(|e| format!("integrity check: {e}"))
+    })
+    .await
+    .map_err(|e| format!("task join: {e}"))??;
+    Ok(StoreIntegrityResponse {
+        ok: problems.is_empty(),
+        problems,
+    })
+}
 
-    let own: Vec<OwnOrderSummary> = all_orders
-        .into_iter()
-        .filter(|o| o.creation_txid.is_some())
-        .map(|o| OwnOrderSummary {
-            creation_txid: o.creation_txid,
-            market_id: o.market_id,
-            direction_label: o.direction_label,
-            price: o.params.price,
-            offered_amount: o.offered_amount,
-            order_status: format!("{:?}", o.status),
+/// Compact the store database with `VACUUM`.
+#[tauri::command]
+pub async fn compact_store(app: tauri::AppHandle) -> Result<(), String> {
+    let store_arc = get_store(&app)?;
+    tokio::task::spawn_blocking(move || {
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        store.vacuum().map_err(|e| format!("compact store: {e}"))
+    })
+    .await
+    .map_err(|e| format!("task join: {e}"))?
+}
+
+/// Wipe the store and repopulate it from Nostr discovery and the chain.
+///
+/// Markets, orders, pools and wallet history are re-derived at the cost of
+/// re-fetching announcements; tables that can't be re-derived are carried
+/// over by [`AppStateManager::reset_store`]. Fetch failures and tables
+/// that couldn't be carried over are reported as warnings rather than
+/// aborting the rebuild.
+#[tauri::command]
+pub async fn rebuild_store(app: tauri::AppHandle) -> Result<StoreRebuildSummary, String> {
+    let _in_flight = crate::InFlightGuard::begin(&app, "rebuild_store".to_string())?;
+
+    // Settle journaled broadcasts against the chain first; the ones still
+    // pending are carried over by the reset.
+    let mut warnings = Vec::new();
+    {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        if let Some(node) = guard.as_ref().filter(|node| node.is_wallet_unlocked()) {
+            if let Err(e) = node.reconcile_broadcast_intents().await {
+                warnings.push(format!("reconcile broadcast intents: {e}"));
+            }
+        }
+    }
+
+    {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        warnings.extend(mgr.reset_store()?);
+    }
+
+    let mut summary = StoreRebuildSummary {
+        markets: 0,
+        orders: 0,
+        pools: 0,
+        sync: None,
+        warnings,
+    };
+
+    let mut pool_ids = Vec::new();
+    let wallet_unlocked = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        match guard.as_ref() {
+            Some(node) => {
+                match node.fetch_markets().await {
+                    Ok(markets) => summary.markets = markets.len(),
+                    Err(e) => summary.warnings.push(format!("fetch markets: {e}")),
+                }
+                match node.fetch_orders(None).await {
+                    Ok(orders) => summary.orders = orders.len(),
+                    Err(e) => summary.warnings.push(format!("fetch orders: {e}")),
+                }
+                match node.fetch_pools(None).await {
+                    Ok(pools) => {
+                        summary.pools = pools.len();
+                        pool_ids = pools.into_iter().map(|pool| pool.lmsr_pool_id).collect();
+                    }
+                    Err(e) => summary.warnings.push(format!("fetch pools: {e}")),
+                }
+                node.is_wallet_unlocked()
+            }
+            None => {
+                summary
+                    .warnings
+                    .push("node not initialized; discovery data not re-fetched".to_string());
+                false
+            }
+        }
+    };
+
+    if wallet_unlocked {
+        match crate::sync_wallet(app.clone(), None).await {
+            Ok(sync) => summary.sync = Some(sync),
+            Err(e) => summary.warnings.push(format!("sync wallet: {e}")),
+        }
+        verify_ingested_pools(&app, pool_ids).await;
+    }
+
+    bump_revision_and_emit(&app).await?;
+    Ok(summary)
+}
+
+#[derive(Serialize, Clone)]
+pub struct RescanProgress {
+    /// `"scanning_wallet"`, `"syncing_store"` or `"complete"`.
+    pub phase: &'static str,
+    pub from_height: u32,
+    pub tip_height: u32,
+}
+
+#[derive(Serialize)]
+pub struct RescanSummary {
+    pub from_height: u32,
+    pub tip_height: u32,
+    /// Wallet transactions at or above `from_height`, plus unconfirmed ones.
+    pub transactions_found: usize,
+    /// Result of the follow-up store sync.
+    pub sync: Option<WalletSyncSummary>,
+    pub warnings: Vec<String>,
+}
+
+/// Clamp a requested report height to the chain: negative means "from
+/// genesis", anything above the tip is rejected.
+fn rescan_start_height(requested: i64, tip_height: u32) -> Result<u32, String> {
+    let height = u32::try_from(requested.max(0)).unwrap_or(u32::MAX);
+    if height > tip_height {
+        return Err(format!(
+            "report height {height} is above the chain tip {tip_height}"
+        ));
+    }
+    Ok(height)
+}
+
+/// Throw away the wallet's scan cache and rebuild it from the chain, then
+/// re-sync the store. Progress is reported through `rescan_progress` events.
+///
+/// Recovery path after a restore or a corrupted wallet database. The wallet
+/// scan follows script history, which Electrum can't bound by height, so it
+/// always recovers the full history from genesis. `report_from_height` only
+/// scopes which transactions are counted in `transactions_found`.
+#[tauri::command]
+pub async fn rescan_from_height(
+    report_from_height: i64,
+    app: tauri::AppHandle,
+) -> Result<RescanSummary, String> {
+    let _in_flight = crate::InFlightGuard::begin(&app, "rescan_from_height".to_string())?;
+
+    let network = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.network().ok_or("Network not initialized")?
+    };
+    let esplora_url = crate::esplora_url(&app, network);
+    let tip_height = crate::fetch_chain_tip_inner(
+        network.into(),
+        &esplora_url,
+        crate::network_timeouts(&app).esplora(),
+    )
+    .await
+    .map_err(|e| format!("fetch chain tip: {e}"))?
+    .height;
+    let from_height = rescan_start_height(report_from_height, tip_height)?;
+
+    let emit_progress = |phase| {
+        let _ = app.emit(
+            crate::RESCAN_PROGRESS_EVENT,
+            RescanProgress {
+                phase,
+                from_height,
+                tip_height,
+            },
+        );
+    };
+
+    emit_progress("scanning_wallet");
+    let transactions_found = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref().ok_or("Node not initialized")?;
+        node.rescan_wallet().await.map_err(|e| format!("{e}"))?;
+        node.transactions()
+            .map_err(|e| format!("{e}"))?
+            .iter()
+            .filter(|tx| tx.height.is_none_or(|h| h >= from_height))
+            .count()
+    };
+
+    emit_progress("syncing_store");
+    let mut summary = RescanSummary {
+        from_height,
+        tip_height,
+        transactions_found,
+        sync: None,
+        warnings: Vec::new(),
+    };
+    match crate::sync_wallet(app.clone(), None).await {
+        Ok(sync) => summary.sync = Some(sync),
+        Err(e) => summary.warnings.push(format!("sync wallet: {e}")),
+    }
+
+    emit_progress("complete");
+    bump_revision_and_emit(&app).await?;
+    Ok(summary)
+}
+
+#[derive(Serialize)]
+pub struct MarketIssuanceResponse {
+    pub yes_entropy: String,
+    pub no_entropy: String,
+    pub yes_blinding_nonce: String,
+    pub no_blinding_nonce: String,
+}
+
+/// Rebuild a stored market's issuance data from its creation transaction,
+/// for markets whose data the store sync never picked up.
+#[tauri::command]
+pub async fn recover_market_issuance(
+    market_id: String,
+    app: tauri::AppHandle,
+) -> Result<MarketIssuanceResponse, String> {
+    let mid = deadcat_sdk::MarketId(decode_hex_32(&market_id, "market_id")?);
+    let network = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.network().ok_or("Network not initialized")?
+    };
+    let electrum_url = crate::wallet_electrum_url(&app, network).await;
+    let store_arc = get_store(&app)?;
+    let data = tokio::task::spawn_blocking(move || {
+        let chain = crate::chain_adapter::ElectrumChainAdapter::new(&electrum_url);
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        store
+            .recover_issuance_data(&mid, &chain)
+            .map_err(|e| format!("recover issuance data: {e}"))
+    })
+    .await
+    .map_err(|e| format!("task join: {e}"))??;
+
+    Ok(MarketIssuanceResponse {
+        yes_entropy: hex::encode(data.yes_entropy),
+        no_entropy: hex::encode(data.no_entropy),
+        yes_blinding_nonce: hex::encode(data.yes_blinding_nonce),
+        no_blinding_nonce: hex::encode(data.no_blinding_nonce),
+    })
+}
+
+/// Blocks of history kept by [`prune_store`] by default (about two weeks of
+/// one-minute Liquid blocks).
+pub(crate) const DEFAULT_PRUNE_RETAIN_BLOCKS: u32 = 20_160;
+/// Price snapshots kept per pool below the prune cutoff by default.
+pub(crate) const DEFAULT_PRUNE_KEEP_SNAPSHOTS: usize = 500;
+
+#[derive(Serialize)]
+pub struct PruneStoreResponse {
+    pub before_height: u32,
+    pub spent_utxos: usize,
+    pub price_snapshots: usize,
+}
+
+/// Prune spent UTXOs and thin price history older than `retain_blocks`
+/// below the store's last synced height.
+pub(crate) async fn prune_store_inner<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    retain_blocks: u32,
+    keep_snapshots_per_pool: usize,
+) -> Result<PruneStoreResponse, String> {
+    let store_arc = get_store(app)?;
+    tokio::task::spawn_blocking(move || {
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        let synced = store
+            .last_synced_height()
+            .map_err(|e| format!("last synced height: {e}"))?;
+        let before_height = synced.saturating_sub(retain_blocks);
+        let report = store
+            .prune(before_height, keep_snapshots_per_pool)
+            .map_err(|e| format!("prune store: {e}"))?;
+        Ok(PruneStoreResponse {
+            before_height,
+            spent_utxos: report.spent_utxos,
+            price_snapshots: report.price_snapshots,
         })
-        .collect();
+    })
+    .await
+    .map_err(|e| format!("task join: {e}"))?
+}
 
-    Ok(own)
+/// Prune the store now. Also runs periodically in the background with the
+/// default retention.
+#[tauri::command]
+pub async fn prune_store(
+    retain_blocks: Option<u32>,
+    keep_snapshots_per_pool: Option<usize>,
+    app: tauri::AppHandle,
+) -> Result<PruneStoreResponse, String> {
+    prune_store_inner(
+        &app,
+        retain_blocks.unwrap_or(DEFAULT_PRUNE_RETAIN_BLOCKS),
+        keep_snapshots_per_pool.unwrap_or(DEFAULT_PRUNE_KEEP_SNAPSHOTS),
+    )
+    .await
 }
 
 // =========================================================================
@@ -2137,61 +6365,493 @@ pub fn generate_lmsr_table(
 }
 
 #[derive(Deserialize)]
-pub struct CreateLmsrPoolRequest {
-    pub market_params_json: String,
+pub struct CreateLmsrPoolRequest {
+    pub market_params_json: String,
+    pub pool_params_json: String,
+    pub initial_s_index: u64,
+    pub initial_reserves_yes: u64,
+    pub initial_reserves_no: u64,
+    pub initial_reserves_lbtc: u64,
+    pub table_values: Vec<u64>,
+    pub fee_amount: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct CreateLmsrPoolResponse {
+    pub txid: String,
+    pub pool_id: String,
+}
+
+#[tauri::command]
+pub async fn create_lmsr_pool(
+    request: CreateLmsrPoolRequest,
+    app: tauri::AppHandle,
+) -> Result<CreateLmsrPoolResponse, String> {
+    let market_params: deadcat_sdk::PredictionMarketParams =
+        serde_json::from_str(&request.market_params_json)
+            .map_err(|e| format!("invalid market params: {e}"))?;
+    let pool_params: deadcat_sdk::LmsrPoolParams = serde_json::from_str(&request.pool_params_json)
+        .map_err(|e| format!("invalid pool params: {e}"))?;
+
+    let _in_flight = crate::InFlightGuard::begin(
+        &app,
+        format!("create_lmsr_pool:{}", market_params.market_id()),
+    )?;
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let fee_amount = resolve_covenant_fee(
+        node,
+        &market_params,
+        &[deadcat_sdk::CovenantOp::CreateLmsrPool],
+        request.fee_amount,
+        None,
+    )?;
+    let sdk_request = deadcat_sdk::CreateLmsrPoolRequest {
+        market_params,
+        pool_params,
+        initial_s_index: request.initial_s_index,
+        initial_reserves: deadcat_sdk::PoolReserves {
+            r_yes: request.initial_reserves_yes,
+            r_no: request.initial_reserves_no,
+            r_lbtc: request.initial_reserves_lbtc,
+        },
+        table_values: request.table_values,
+        fee_amount,
+    };
+    let result = node
+        .create_lmsr_pool(sdk_request)
+        .await
+        .map_err(describe_funding_error)?;
+    let creator_pubkey = node.pool_admin_pubkey(WALLET_KEY_INDEX).await;
+    drop(guard);
+
+    let pool_id = result.snapshot.locator.pool_id.to_hex();
+
+    // Tag the pool as ours so it shows up in the portfolio view
+    match creator_pubkey {
+        Ok(creator_pubkey) => {
+            if let Ok(store_arc) = get_store(&app) {
+                if let Ok(mut store) = store_arc.lock() {
+                    if let Err(e) = store.record_lmsr_pool_creator(&pool_id, &creator_pubkey) {
+                        log::warn!("failed to record pool creator: {e}");
+                    }
+                }
+            }
+        }
+        Err(e) => log::warn!("failed to derive pool admin pubkey: {e}"),
+    }
+
+    record_action(
+        &app,
+        deadcat_store::ActionKind::CreateLmsrPool,
+        Some(hex::encode(market_params.market_id().as_bytes())),
+        Some(result.txid.to_string()),
+        serde_json::json!({ "pool_id": pool_id, "fee_amount": fee_amount }),
+    );
+    bump_revision_and_emit(&app).await?;
+
+    Ok(CreateLmsrPoolResponse {
+        txid: result.txid.to_string(),
+        pool_id,
+    })
+}
+
+#[derive(Deserialize)]
+pub struct CreateMarketWithPoolRequest {
+    /// Market to create. Omit when resuming an earlier run with `market_id`.
+    pub market: Option<CreateContractRequest>,
+    /// Market an earlier run created, to pick up where that run stopped.
+    pub market_id: Option<String>,
+    /// YES+NO pairs to issue before seeding the pool.
+    pub pairs: u64,
+    /// Pool parameters. The asset IDs and `half_payout_sats` are filled in
+    /// from the market, so they may be left zeroed.
+    pub pool_params_json: String,
+    pub initial_s_index: u64,
+    pub initial_reserves_yes: u64,
+    pub initial_reserves_no: u64,
+    pub initial_reserves_lbtc: u64,
+    pub table_values: Vec<u64>,
+}
+
+/// Txids are `None` for steps an earlier run had already completed.
+#[derive(Serialize)]
+pub struct CreateMarketWithPoolResponse {
+    pub market_id: String,
+    pub creation_txid: Option<String>,
+    pub issuance_txid: Option<String>,
+    pub pool_txid: Option<String>,
+    pub pool_id: String,
+}
+
+/// Create a market, issue its initial supply and seed an LMSR pool from it.
+///
+/// Each step is skipped when it already happened: issuance once the market
+/// has left Dormant, the pool once the store has one this wallet created for
+/// the market. After a failure, call again with the reported `market_id` to
+/// resume.
+#[tauri::command]
+pub async fn create_market_with_pool(
+    request: CreateMarketWithPoolRequest,
+    confirm_mainnet: Option<bool>,
+    app: tauri::AppHandle,
+) -> Result<CreateMarketWithPoolResponse, String> {
+    if request.pairs == 0 {
+        return Err("pairs must be non-zero".to_string());
+    }
+    if request
+        .initial_reserves_yes
+        .max(request.initial_reserves_no)
+        > request.pairs
+    {
+        return Err(format!(
+            "pool token reserves must fit within the {} issued pairs",
+            request.pairs
+        ));
+    }
+    let mut pool_params: deadcat_sdk::LmsrPoolParams =
+        serde_json::from_str(&request.pool_params_json)
+            .map_err(|e| format!("invalid pool params: {e}"))?;
+
+    let (params, anchor, creation_txid) = match (request.market, request.market_id) {
+        (Some(market), None) => {
+            let created = create_contract_onchain(market, confirm_mainnet, app.clone()).await?;
+            let params = deadcat_sdk::discovered_market_to_contract_params(&created.market)?;
+            let txid = created.market.anchor.creation_txid.clone();
+            (params, created.market.anchor, Some(txid))
+        }
+        (None, Some(market_id)) => {
+            crate::require_mainnet_confirmation(&app, confirm_mainnet)?;
+            let mid = deadcat_sdk::MarketId(decode_hex_32(&market_id, "market_id")?);
+            let store_arc = get_store(&app)?;
+            let mut store = store_arc
+                .lock()
+                .map_err(|_| "store lock failed".to_string())?;
+            let info = store
+                .get_market(&mid)
+                .map_err(|e| format!("get market: {e}"))?
+                .ok_or_else(|| format!("market {market_id} not found"))?;
+            (info.params, info.anchor, None)
+        }
+        _ => return Err("pass exactly one of market and market_id".to_string()),
+    };
+    let market_id = hex::encode(params.market_id().as_bytes());
+    let resumable = |step: &str, e: String| {
+        format!("{step} failed for market {market_id}; retry with its market_id to resume: {e}")
+    };
+    let market_params_json =
+        serde_json::to_string(&params).map_err(|e| format!("serialize market params: {e}"))?;
+
+    let (state, pool_admin_pubkey) = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref().ok_or("Node not initialized")?;
+        let state = node
+            .market_state(params, anchor.clone())
+            .await
+            .map_err(|e| resumable("market scan", format!("{e}")))?;
+        let pool_admin_pubkey = node
+            .pool_admin_pubkey(WALLET_KEY_INDEX)
+            .await
+            .map_err(|e| format!("{e}"))?;
+        (state, pool_admin_pubkey)
+    };
+    let issuance_txid = if state == deadcat_sdk::MarketState::Dormant {
+        let issued = issue_tokens(
+            market_params_json.clone(),
+            anchor,
+            request.pairs,
+            None,
+            None,
+            None,
+            app.clone(),
+        )
+        .await
+        .map_err(|e| resumable("token issuance", e))?;
+        Some(issued.txid)
+    } else {
+        None
+    };
+
+    // Only a pool this wallet created counts as an earlier run's; someone
+    // else's pool on the same market doesn't stop us seeding our own.
+    let existing_pool_id = {
+        let store_arc = get_store(&app)?;
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        store
+            .list_my_lmsr_pools(&pool_admin_pubkey)
+            .map_err(|e| format!("list pools: {e}"))?
+            .into_iter()
+            .find(|pool| pool.market_id == market_id)
+            .map(|pool| pool.pool_id)
+    };
+    let (pool_id, pool_txid) = match existing_pool_id {
+        Some(pool_id) => (pool_id, None),
+        None => {
+            pool_params.yes_asset_id = params.yes_token_asset;
+            pool_params.no_asset_id = params.no_token_asset;
+            pool_params.collateral_asset_id = params.collateral_asset_id;
+            pool_params.half_payout_sats = params.collateral_per_token;
+            let pool_params_json = serde_json::to_string(&pool_params)
+                .map_err(|e| format!("serialize pool params: {e}"))?;
+            let created = create_lmsr_pool(
+                CreateLmsrPoolRequest {
+                    market_params_json,
+                    pool_params_json,
+                    initial_s_index: request.initial_s_index,
+                    initial_reserves_yes: request.initial_reserves_yes,
+                    initial_reserves_no: request.initial_reserves_no,
+                    initial_reserves_lbtc: request.initial_reserves_lbtc,
+                    table_values: request.table_values,
+                    fee_amount: None,
+                },
+                app.clone(),
+            )
+            .await
+            .map_err(|e| resumable("pool creation", e))?;
+            (created.pool_id, Some(created.txid))
+        }
+    };
+
+    Ok(CreateMarketWithPoolResponse {
+        market_id,
+        creation_txid,
+        issuance_txid,
+        pool_txid,
+        pool_id,
+    })
+}
+
+/// How far the reserve-implied YES price may sit from the curve's starting
+/// price before the preview warns.
+const POOL_PRICE_DRIFT_WARN_BPS: u16 = 1_000;
+
+/// Ratio between the token reserves' value and the L-BTC reserve beyond
+/// which the preview warns that one side is thin.
+const POOL_VALUE_IMBALANCE_RATIO: u128 = 2;
+
+#[derive(Deserialize)]
+pub struct PreviewLmsrPoolCreationRequest {
     pub pool_params_json: String,
     pub initial_s_index: u64,
     pub initial_reserves_yes: u64,
     pub initial_reserves_no: u64,
     pub initial_reserves_lbtc: u64,
     pub table_values: Vec<u64>,
-    pub fee_amount: Option<u64>,
 }
 
 #[derive(Serialize)]
-pub struct CreateLmsrPoolResponse {
-    pub txid: String,
-    pub pool_id: String,
+pub struct LmsrPoolCreationPreviewResponse {
+    /// Fee-free starting prices from the LMSR curve at `initial_s_index`.
+    pub initial_yes_price_bps: u16,
+    pub initial_no_price_bps: u16,
+    /// YES price implied by the YES/NO reserve ratio alone.
+    pub reserve_yes_price_bps: Option<u16>,
+    /// YES+NO reserves valued at the starting prices.
+    pub token_reserve_value_sats: u64,
+    /// Swap fee charged on every trade against the pool, and the highest fee
+    /// `create_lmsr_pool` accepts.
+    pub fee_bps: u64,
+    pub max_fee_bps: u64,
+    pub warnings: Vec<String>,
 }
 
+/// Starting prices and fee for a pool `create_lmsr_pool` would create, with
+/// warnings when its reserves are out of balance with them. Fails on a fee
+/// creation would reject.
 #[tauri::command]
-pub async fn create_lmsr_pool(
-    request: CreateLmsrPoolRequest,
-    app: tauri::AppHandle,
-) -> Result<CreateLmsrPoolResponse, String> {
-    let market_params: deadcat_sdk::PredictionMarketParams =
-        serde_json::from_str(&request.market_params_json)
-            .map_err(|e| format!("invalid market params: {e}"))?;
+pub fn preview_lmsr_pool_creation(
+    request: PreviewLmsrPoolCreationRequest,
+) -> Result<LmsrPoolCreationPreviewResponse, String> {
     let pool_params: deadcat_sdk::LmsrPoolParams = serde_json::from_str(&request.pool_params_json)
         .map_err(|e| format!("invalid pool params: {e}"))?;
+    if pool_params.fee_bps > deadcat_sdk::MAX_CREATION_FEE_BPS {
+        return Err(deadcat_sdk::Error::PoolFeeOutOfRange {
+            fee_bps: pool_params.fee_bps,
+            max_bps: deadcat_sdk::MAX_CREATION_FEE_BPS,
+        }
+        .to_string());
+    }
+    let manifest =
+        deadcat_sdk::LmsrTableManifest::new(pool_params.table_depth, request.table_values)
+            .map_err(|e| format!("{e}"))?;
+    let yes_bps =
+        deadcat_sdk::fee_free_yes_spot_price_bps(&manifest, &pool_params, request.initial_s_index)
+            .map_err(|e| format!("{e}"))?;
+    let reserves = deadcat_sdk::PoolReserves {
+        r_yes: request.initial_reserves_yes,
+        r_no: request.initial_reserves_no,
+        r_lbtc: request.initial_reserves_lbtc,
+    };
+    let token_reserve_value_sats =
+        token_reserve_value_sats(&reserves, yes_bps, pool_params.half_payout_sats);
+
+    Ok(LmsrPoolCreationPreviewResponse {
+        initial_yes_price_bps: yes_bps,
+        initial_no_price_bps: 10_000u16.saturating_sub(yes_bps),
+        reserve_yes_price_bps: deadcat_sdk::implied_probability_bps(&reserves).map(|(yes, _)| yes),
+        token_reserve_value_sats,
+        fee_bps: pool_params.fee_bps,
+        max_fee_bps: deadcat_sdk::MAX_CREATION_FEE_BPS,
+        warnings: pool_creation_warnings(&reserves, yes_bps, token_reserve_value_sats),
+    })
+}
 
-    let sdk_request = deadcat_sdk::CreateLmsrPoolRequest {
-        market_params,
-        pool_params,
-        initial_s_index: request.initial_s_index,
-        initial_reserves: deadcat_sdk::PoolReserves {
-            r_yes: request.initial_reserves_yes,
-            r_no: request.initial_reserves_no,
-            r_lbtc: request.initial_reserves_lbtc,
-        },
-        table_values: request.table_values,
-        fee_amount: request.fee_amount.unwrap_or(500),
+/// YES and NO reserves valued at `yes_bps`, where a winning token pays
+/// `2 * half_payout_sats`. Saturates rather than overflowing.
+fn token_reserve_value_sats(
+    reserves: &deadcat_sdk::PoolReserves,
+    yes_bps: u16,
+    half_payout_sats: u64,
+) -> u64 {
+    let no_bps = 10_000u16.saturating_sub(yes_bps);
+    let weighted = u128::from(reserves.r_yes) * u128::from(yes_bps)
+        + u128::from(reserves.r_no) * u128::from(no_bps);
+    let value = weighted * 2 * u128::from(half_payout_sats) / 10_000;
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
+
+fn pool_creation_warnings(
+    reserves: &deadcat_sdk::PoolReserves,
+    yes_bps: u16,
+    token_reserve_value_sats: u64,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if let Some((reserve_yes_bps, _)) = deadcat_sdk::implied_probability_bps(reserves) {
+        if reserve_yes_bps.abs_diff(yes_bps) > POOL_PRICE_DRIFT_WARN_BPS {
+            warnings.push(format!(
+                "reserves imply a YES price of {reserve_yes_bps} bps \
+                 but the curve starts at {yes_bps} bps"
+            ));
+        }
+    }
+
+    let tokens = u128::from(token_reserve_value_sats);
+    let lbtc = u128::from(reserves.r_lbtc);
+    if tokens > lbtc * POOL_VALUE_IMBALANCE_RATIO || lbtc > tokens * POOL_VALUE_IMBALANCE_RATIO {
+        warnings.push(format!(
+            "YES+NO reserves are worth {token_reserve_value_sats} sats at the starting price \
+             but the pool holds {} sats of L-BTC",
+            reserves.r_lbtc
+        ));
+    }
+    warnings
+}
+
+#[derive(Serialize)]
+pub struct LmsrPoolDepositQuoteResponse {
+    pub deposit_yes: u64,
+    pub deposit_no: u64,
+    pub deposit_lbtc: u64,
+    /// Reserves to pass to a pool adjustment for this deposit.
+    pub new_reserves_yes: u64,
+    pub new_reserves_no: u64,
+    pub new_reserves_lbtc: u64,
+}
+
+/// Amounts of every reserve to add alongside `amount` of `asset` so a
+/// stored pool's reserves keep their current ratio.
+#[tauri::command]
+pub fn quote_lmsr_pool_deposit(
+    pool_id: String,
+    asset: deadcat_sdk::PoolAsset,
+    amount: u64,
+    app: tauri::AppHandle,
+) -> Result<LmsrPoolDepositQuoteResponse, String> {
+    let (_, current) = stored_lmsr_pool_reserves(&app, &pool_id)?;
+    let new = current
+        .balanced_deposit(asset, amount)
+        .ok_or("pool reserve for that asset is empty, or the deposit overflows")?;
+    Ok(LmsrPoolDepositQuoteResponse {
+        deposit_yes: new.r_yes - current.r_yes,
+        deposit_no: new.r_no - current.r_no,
+        deposit_lbtc: new.r_lbtc - current.r_lbtc,
+        new_reserves_yes: new.r_yes,
+        new_reserves_no: new.r_no,
+        new_reserves_lbtc: new.r_lbtc,
+    })
+}
+
+/// The stored pool `pool_id` and the reserves it was last seen with.
+fn stored_lmsr_pool_reserves(
+    app: &tauri::AppHandle,
+    pool_id: &str,
+) -> Result<(deadcat_store::LmsrPoolInfo, deadcat_sdk::PoolReserves), String> {
+    let store_arc = get_store(app)?;
+    let pool = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?
+        .list_lmsr_pools(&deadcat_store::LmsrPoolFilter {
+            pool_id: Some(pool_id.to_string()),
+            limit: Some(1),
+            ..Default::default()
+        })
+        .map_err(|e| format!("list pools: {e}"))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("pool {pool_id} not found"))?;
+    let reserves = deadcat_sdk::PoolReserves {
+        r_yes: pool.reserve_yes,
+        r_no: pool.reserve_no,
+        r_lbtc: pool.reserve_collateral,
     };
+    Ok((pool, reserves))
+}
 
-    let node_state = app.state::<NodeState>();
-    let guard = node_state.node.lock().await;
-    let node = guard.as_ref().ok_or("Node not initialized")?;
-    let result = node
-        .create_lmsr_pool(sdk_request)
-        .await
-        .map_err(|e| format!("{e}"))?;
-    drop(guard);
+#[derive(Serialize)]
+pub struct LmsrPoolWithdrawalQuoteResponse {
+    pub withdraw_yes: u64,
+    pub withdraw_no: u64,
+    pub withdraw_lbtc: u64,
+    /// Reserves to pass to a pool adjustment for this withdrawal.
+    pub new_reserves_yes: u64,
+    pub new_reserves_no: u64,
+    pub new_reserves_lbtc: u64,
+}
 
-    bump_revision_and_emit(&app).await?;
+/// What withdrawing `amount` of `asset`, with the other reserves in
+/// proportion, takes out of a stored pool. Errors when the withdrawal would
+/// leave any reserve below the pool's minimum.
+#[tauri::command]
+pub fn quote_lmsr_pool_withdrawal(
+    pool_id: String,
+    asset: deadcat_sdk::PoolAsset,
+    amount: u64,
+    app: tauri::AppHandle,
+) -> Result<LmsrPoolWithdrawalQuoteResponse, String> {
+    let (pool, current) = stored_lmsr_pool_reserves(&app, &pool_id)?;
+    let params: deadcat_sdk::LmsrPoolParams =
+        serde_json::from_str(&pool.params_json).map_err(|e| format!("invalid pool params: {e}"))?;
+    let new = current.balanced_withdrawal(asset, amount).ok_or_else(|| {
+        format!(
+            "cannot withdraw {amount}: the pool holds {} of that asset",
+            current.get(asset)
+        )
+    })?;
+    for (name, reserve, minimum) in [
+        ("YES", new.r_yes, params.min_r_yes),
+        ("NO", new.r_no, params.min_r_no),
+        ("collateral", new.r_lbtc, params.min_r_collateral),
+    ] {
+        if reserve < minimum {
+            return Err(format!(
+                "withdrawal leaves the {name} reserve at {reserve}, \
+                 below the pool minimum {minimum}"
+            ));
+        }
+    }
 
-    Ok(CreateLmsrPoolResponse {
-        txid: result.txid.to_string(),
-        pool_id: result.snapshot.locator.pool_id.to_hex(),
+    Ok(LmsrPoolWithdrawalQuoteResponse {
+        withdraw_yes: current.r_yes - new.r_yes,
+        withdraw_no: current.r_no - new.r_no,
+        withdraw_lbtc: current.r_lbtc - new.r_lbtc,
+        new_reserves_yes: new.r_yes,
+        new_reserves_no: new.r_no,
+        new_reserves_lbtc: new.r_lbtc,
     })
 }
 
@@ -2237,6 +6897,69 @@ pub async fn scan_lmsr_pool(
     scan_lmsr_pool_inner(pool_id, app).await
 }
 
+#[derive(Serialize)]
+pub struct VerifyPoolResponse {
+    pub pool_id: String,
+    /// "verified", "stale" or "invalid".
+    pub status: String,
+    pub reason: Option<String>,
+    pub current_s_index: Option<u64>,
+    pub reserve_yes: Option<u64>,
+    pub reserve_no: Option<u64>,
+    pub reserve_collateral: Option<u64>,
+}
+
+impl From<deadcat_sdk::PoolVerification> for VerifyPoolResponse {
+    fn from(v: deadcat_sdk::PoolVerification) -> Self {
+        let (status, reason) = match v.status {
+            deadcat_sdk::PoolVerificationStatus::Verified => ("verified", None),
+            deadcat_sdk::PoolVerificationStatus::Stale => {
+                let reason = match (v.announced, &v.snapshot) {
+                    (Some((s_index, reserves)), Some(snapshot)) => Some(format!(
+                        "announced s_index {s_index} with reserves {}/{}/{}, \
+                         on-chain s_index {} with reserves {}/{}/{}",
+                        reserves.r_yes,
+                        reserves.r_no,
+                        reserves.r_lbtc,
+                        snapshot.current_s_index,
+                        snapshot.reserves.r_yes,
+                        snapshot.reserves.r_no,
+                        snapshot.reserves.r_lbtc,
+                    )),
+                    _ => None,
+                };
+                ("stale", reason)
+            }
+            deadcat_sdk::PoolVerificationStatus::Invalid(reason) => ("invalid", Some(reason)),
+        };
+        Self {
+            pool_id: v.pool_id,
+            status: status.to_string(),
+            reason,
+            current_s_index: v.snapshot.as_ref().map(|s| s.current_s_index),
+            reserve_yes: v.snapshot.as_ref().map(|s| s.reserves.r_yes),
+            reserve_no: v.snapshot.as_ref().map(|s| s.reserves.r_no),
+            reserve_collateral: v.snapshot.as_ref().map(|s| s.reserves.r_lbtc),
+        }
+    }
+}
+
+/// Check an announced LMSR pool against the chain and store the real reserves.
+#[tauri::command]
+pub async fn verify_pool(
+    pool_id: String,
+    app: tauri::AppHandle,
+) -> Result<VerifyPoolResponse, String> {
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let verification = node
+        .verify_pool(&pool_id)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    Ok(verification.into())
+}
+
 #[derive(Deserialize)]
 pub struct AdjustLmsrPoolTauriRequest {
     pub pool_id: String,
@@ -2311,6 +7034,28 @@ pub struct LmsrPoolInfoResponse {
     pub params_json: String,
     pub created_at: String,
     pub updated_at: String,
+    /// Stored verdict of the last `verify_pool`: "verified", "stale" or
+    /// "invalid"; `None` until the pool has been checked.
+    pub verification_status: Option<String>,
+    pub verification_reason: Option<String>,
+}
+
+fn map_lmsr_pool_info(p: deadcat_store::LmsrPoolInfo) -> LmsrPoolInfoResponse {
+    LmsrPoolInfoResponse {
+        pool_id: p.pool_id,
+        market_id: p.market_id,
+        creation_txid: p.creation_txid,
+        current_s_index: p.current_s_index,
+        reserve_yes: p.reserve_yes,
+        reserve_no: p.reserve_no,
+        reserve_collateral: p.reserve_collateral,
+        state_source: p.state_source,
+        params_json: p.params_json,
+        created_at: p.created_at,
+        updated_at: p.updated_at,
+        verification_status: p.verification_status,
+        verification_reason: p.verification_reason,
+    }
 }
 
 #[tauri::command]
@@ -2339,22 +7084,7 @@ pub fn list_lmsr_pools(
         })
         .map_err(|e| format!("list pools: {e}"))?;
 
-    Ok(pools
-        .into_iter()
-        .map(|p| LmsrPoolInfoResponse {
-            pool_id: p.pool_id,
-            market_id: p.market_id,
-            creation_txid: p.creation_txid,
-            current_s_index: p.current_s_index,
-            reserve_yes: p.reserve_yes,
-            reserve_no: p.reserve_no,
-            reserve_collateral: p.reserve_collateral,
-            state_source: p.state_source,
-            params_json: p.params_json,
-            created_at: p.created_at,
-            updated_at: p.updated_at,
-        })
-        .collect())
+    Ok(pools.into_iter().map(map_lmsr_pool_info).collect())
 }
 
 #[derive(Serialize)]
@@ -2391,7 +7121,7 @@ fn map_price_history_entries(
         .collect()
 }
 
-fn get_store<R: tauri::Runtime>(
+pub(crate) fn get_store<R: tauri::Runtime>(
     app: &tauri::AppHandle<R>,
 ) -> Result<std::sync::Arc<std::sync::Mutex<deadcat_store::DeadcatStore>>, String> {
     let state_handle = app
@@ -2497,3 +7227,383 @@ async fn get_pool_price_history_inner<R: tauri::Runtime>(
 
     Ok(map_price_history_entries(entries))
 }
+
+// =========================================================================
+// Market detail aggregate
+// =========================================================================
+
+const DEFAULT_ORDER_BOOK_DEPTH: usize = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct OrderBookLevelResponse {
+    pub price: u64,
+    pub order_count: u32,
+    pub total_offered: u64,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct OrderBookSideResponse {
+    /// Resting buy orders (maker offers quote), best price first.
+    pub bids: Vec<OrderBookLevelResponse>,
+    /// Resting sell orders (maker offers base), best price first.
+    pub asks: Vec<OrderBookLevelResponse>,
+}
+
+#[derive(Serialize)]
+pub struct MarketDetailPoolResponse {
+    pub pool: LmsrPoolInfoResponse,
+    /// Fee-free spot prices; `None` when the pool's LMSR table is not stored.
+    pub yes_price_bps: Option<u16>,
+    pub no_price_bps: Option<u16>,
+}
+
+#[derive(Serialize)]
+pub struct MarketDetailResponse {
+    pub market: DiscoveredMarket,
+    pub state: u8,
+    /// Height the local store was last synced to; state is as of this block.
+    pub synced_height: u32,
+    /// Depth of the transition into `state` at `synced_height`; `None` when
+    /// the store holds no live covenant outputs for the market.
+    pub confirmations: Option<u32>,
+    pub contract_params_json: String,
+    pub pool: Option<MarketDetailPoolResponse>,
+    pub yes_order_book: OrderBookSideResponse,
+    pub no_order_book: OrderBookSideResponse,
+}
+
+/// Fee-free YES/NO spot prices for a stored pool, if its table is available.
+fn lmsr_pool_spot_prices(pool: &deadcat_store::LmsrPoolInfo) -> Option<(u16, u16)> {
+    let params: deadcat_sdk::LmsrPoolParams = serde_json::from_str(&pool.params_json).ok()?;
+    let values = pool.lmsr_table_values.clone()?;
+    let manifest = deadcat_sdk::LmsrTableManifest::new(params.table_depth, values).ok()?;
+    let yes =
+        deadcat_sdk::fee_free_yes_spot_price_bps(&manifest, &params, pool.current_s_index).ok()?;
+    Some((yes, 10_000u16.saturating_sub(yes)))
+}
+
+/// Collapse resting orders into price levels, keeping the best `depth` per side.
+fn aggregate_order_book_levels(
+    orders: &[deadcat_store::MakerOrderInfo],
+    depth: usize,
+) -> OrderBookSideResponse {
+    use std::collections::BTreeMap;
+
+    let mut bids: BTreeMap<u64, OrderBookLevelResponse> = BTreeMap::new();
+    let mut asks: BTreeMap<u64, OrderBookLevelResponse> = BTreeMap::new();
+    for order in orders {
+        if !matches!(
+            order.status,
+            deadcat_store::OrderStatus::Active | deadcat_store::OrderStatus::PartiallyFilled
+        ) {
+            continue;
+        }
+        let levels = match order.params.direction {
+            deadcat_sdk::OrderDirection::SellQuote => &mut bids,
+            deadcat_sdk::OrderDirection::SellBase => &mut asks,
+        };
+        let level = levels
+            .entry(order.params.price)
+            .or_insert(OrderBookLevelResponse {
+                price: order.params.price,
+                order_count: 0,
+                total_offered: 0,
+            });
+        level.order_count += 1;
+        level.total_offered = level
+            .total_offered
+            .saturating_add(order.offered_amount.unwrap_or(0));
+    }
+
+    OrderBookSideResponse {
+        bids: bids.into_values().rev().take(depth).collect(),
+        asks: asks.into_values().take(depth).collect(),
+    }
+}
+
+#[derive(Serialize)]
+pub struct MarketMetricsResponse {
+    pub total_collateral_locked: u64,
+    pub pool_tvl: u64,
+    pub open_order_count: usize,
+    pub snapshot_count: usize,
+    pub last_activity: String,
+}
+
+/// Liquidity and activity summary for a stored market.
+#[tauri::command]
+pub fn get_market_metrics(
+    market_id: String,
+    app: tauri::AppHandle,
+) -> Result<MarketMetricsResponse, String> {
+    let mid = deadcat_sdk::MarketId(decode_hex_32(&market_id, "market_id")?);
+
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let metrics = store
+        .market_metrics(&mid)
+        .map_err(|e| format!("market metrics: {e}"))?
+        .ok_or_else(|| format!("market {market_id} not found"))?;
+
+    Ok(MarketMetricsResponse {
+        total_collateral_locked: metrics.total_collateral_locked,
+        pool_tvl: metrics.pool_tvl,
+        open_order_count: metrics.open_order_count,
+        snapshot_count: metrics.snapshot_count,
+        last_activity: metrics.last_activity,
+    })
+}
+
+/// Everything the market detail view needs, read from the store in one pass.
+#[tauri::command]
+pub fn get_market_detail(
+    market_id: String,
+    depth: Option<usize>,
+    app: tauri::AppHandle,
+) -> Result<MarketDetailResponse, String> {
+    let depth = depth.unwrap_or(DEFAULT_ORDER_BOOK_DEPTH);
+    let mid = deadcat_sdk::MarketId(decode_hex_32(&market_id, "market_id")?);
+
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+
+    let info = store
+        .get_market(&mid)
+        .map_err(|e| format!("get market: {e}"))?
+        .ok_or_else(|| format!("market {market_id} not found"))?;
+    let synced_height = store
+        .last_synced_height()
+        .map_err(|e| format!("get sync height: {e}"))?;
+    let confirmations = store
+        .market_state_confirmations(&mid, synced_height)
+        .map_err(|e| format!("get confirmations: {e}"))?;
+
+    let pool = store
+        .list_lmsr_pools(&deadcat_store::LmsrPoolFilter {
+            market_id: Some(market_id.clone()),
+            limit: Some(1),
+            ..Default::default()
+        })
+        .map_err(|e| format!("list pools: {e}"))?
+        .into_iter()
+        .next();
+
+    let p = &info.params;
+    let mut order_book_for = |base_asset_id: [u8; 32]| -> Result<OrderBookSideResponse, String> {
+        let orders = store
+            .list_maker_orders(&deadcat_store::OrderFilter {
+                base_asset_id: Some(base_asset_id),
+                quote_asset_id: Some(p.collateral_asset_id),
+                ..Default::default()
+            })
+            .map_err(|e| format!("list orders: {e}"))?;
+        Ok(aggregate_order_book_levels(&orders, depth))
+    };
+    let yes_order_book = order_book_for(p.yes_token_asset)?;
+    let no_order_book = order_book_for(p.no_token_asset)?;
+    drop(store);
+
+    let pool = pool.map(|pool| {
+        let prices = lmsr_pool_spot_prices(&pool);
+        MarketDetailPoolResponse {
+            yes_price_bps: prices.map(|(yes, _)| yes),
+            no_price_bps: prices.map(|(_, no)| no),
+            pool: map_lmsr_pool_info(pool),
+        }
+    });
+    let (yes_price_bps, no_price_bps) = match &pool {
+        Some(pool) => (pool.yes_price_bps, pool.no_price_bps),
+        None => (None, None),
+    };
+
+    Ok(MarketDetailResponse {
+        market: market_info_to_discovered(&info, yes_price_bps, no_price_bps),
+        state: market_state_to_u8(info.state),
+        synced_height,
+        confirmations,
+        contract_params_json: serde_json::to_string(&info.params)
+            .map_err(|e| format!("serialize params: {e}"))?,
+        pool,
+        yes_order_book,
+        no_order_book,
+    })
+}
+
+// =========================================================================
+// Wallet activity (portfolio)
+// =========================================================================
+
+/// Key index used for maker orders and pool admin keys created by this app.
+const WALLET_KEY_INDEX: u32 = 0;
+
+#[derive(Serialize)]
+pub struct MyActivityResponse {
+    pub markets: Vec<DiscoveredMarket>,
+    pub orders: Vec<OwnOrderSummary>,
+    pub pools: Vec<LmsrPoolInfoResponse>,
+}
+
+/// Markets, orders and pools created by the unlocked wallet.
+#[tauri::command]
+pub async fn get_my_activity(app: tauri::AppHandle) -> Result<MyActivityResponse, String> {
+    let (creator_pubkey, maker_base_pubkey, pool_admin_pubkey) = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref().ok_or("Node not initialized")?;
+        let creator_pubkey = node.keys().public_key().to_bytes();
+        let maker_base_pubkey = node
+            .maker_base_pubkey(WALLET_KEY_INDEX)
+            .await
+            .map_err(|e| format!("{e}"))?;
+        let pool_admin_pubkey = node
+            .pool_admin_pubkey(WALLET_KEY_INDEX)
+            .await
+            .map_err(|e| format!("{e}"))?;
+        (creator_pubkey, maker_base_pubkey, pool_admin_pubkey)
+    };
+
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+
+    let markets = store
+        .list_my_markets(&creator_pubkey)
+        .map_err(|e| format!("list markets: {e}"))?;
+    let orders = store
+        .list_my_maker_orders(&maker_base_pubkey)
+        .map_err(|e| format!("list orders: {e}"))?;
+    let pools = store
+        .list_my_lmsr_pools(&pool_admin_pubkey)
+        .map_err(|e| format!("list pools: {e}"))?;
+
+    Ok(MyActivityResponse {
+        markets: markets
+            .iter()
+            .map(|info| market_info_to_discovered(info, None, None))
+            .collect(),
+        orders: orders.into_iter().map(map_own_order).collect(),
+        pools: pools.into_iter().map(map_lmsr_pool_info).collect(),
+    })
+}
+
+// =========================================================================
+// Action log
+// =========================================================================
+
+/// Append a completed action to the store's action log. Failures are only
+/// logged: the transaction has already been broadcast.
+fn record_action<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    kind: deadcat_store::ActionKind,
+    market_id: Option<String>,
+    txid: Option<String>,
+    details: serde_json::Value,
+) {
+    let result = get_store(app).and_then(|store_arc| {
+        let mut store = store_arc
+            .lock()
+            .map_err(|_| "store lock failed".to_string())?;
+        store
+            .log_action(
+                kind,
+                market_id.as_deref(),
+                txid.as_deref(),
+                &details.to_string(),
+            )
+            .map(|_| ())
+            .map_err(|e| format!("{e}"))
+    });
+    if let Err(e) = result {
+        log::warn!("failed to log {} action: {e}", kind.as_str());
+    }
+}
+
+fn record_redemption(
+    app: &tauri::AppHandle,
+    kind: deadcat_store::ActionKind,
+    params: &deadcat_sdk::PredictionMarketParams,
+    result: &deadcat_sdk::RedemptionResult,
+    fee_amount: u64,
+) {
+    record_action(
+        app,
+        kind,
+        Some(hex::encode(params.market_id().as_bytes())),
+        Some(result.txid.to_string()),
+        serde_json::json!({
+            "tokens": result.tokens_redeemed,
+            "payout_sats": result.payout_sats,
+            "extra_txids": result.extra_txids.iter().map(|t| t.to_string()).collect::<Vec<_>>(),
+            "fee_amount": fee_amount,
+        }),
+    );
+}
+
+#[derive(Serialize)]
+pub struct ActionLogEntryResponse {
+    pub id: i32,
+    pub kind: String,
+    pub market_id: Option<String>,
+    pub txid: Option<String>,
+    pub details: serde_json::Value,
+    pub created_at: String,
+}
+
+impl From<deadcat_store::ActionLogEntry> for ActionLogEntryResponse {
+    fn from(entry: deadcat_store::ActionLogEntry) -> Self {
+        Self {
+            id: entry.id,
+            kind: entry.kind.as_str().to_string(),
+            market_id: entry.market_id,
+            txid: entry.txid,
+            details: serde_json::from_str(&entry.details_json).unwrap_or_default(),
+            created_at: entry.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct ActionLogFilterRequest {
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub market_id: Option<String>,
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// Actions the user completed from this app, newest first.
+#[tauri::command]
+pub fn get_action_log(
+    filter: Option<ActionLogFilterRequest>,
+    app: tauri::AppHandle,
+) -> Result<Vec<ActionLogEntryResponse>, String> {
+    let filter = filter.unwrap_or_default();
+    let kind = filter
+        .kind
+        .map(|kind| {
+            deadcat_store::ActionKind::parse(&kind)
+                .ok_or_else(|| format!("unknown action kind: {kind}"))
+        })
+        .transpose()?;
+    let store_arc = get_store(&app)?;
+    let mut store = store_arc
+        .lock()
+        .map_err(|_| "store lock failed".to_string())?;
+    let entries = store
+        .get_action_log(&deadcat_store::ActionLogFilter {
+            kind,
+            market_id: filter.market_id,
+            limit: filter.limit,
+        })
+        .map_err(|e| format!("action log: {e}"))?;
+    Ok(entries
+        .into_iter()
+        .map(ActionLogEntryResponse::from)
+        .collect())
+}
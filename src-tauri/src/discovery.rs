@@ -2,11 +2,19 @@ use nostr_sdk::{
     Client, Event, EventBuilder, Filter, Keys, Kind, PublicKey, SecretKey, Tag, TagKind,
 };
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// d-tag for wallet mnemonic backup events (NIP-78).
 pub const WALLET_BACKUP_D_TAG: &str = "deadcat-wallet-backup";
 
+/// d-tag prefix for app state backup events (NIP-78).
+pub const APP_STATE_BACKUP_D_TAG: &str = "deadcat-app-state-backup";
+
+/// Maximum plaintext bytes per app state backup chunk. NIP-44 v2 caps the
+/// plaintext at 65535 bytes, so this leaves comfortable headroom.
+pub const APP_STATE_BACKUP_CHUNK_BYTES: usize = 32 * 1024;
+
 // Re-export SDK-owned types and functions for use by the app layer.
 pub use deadcat_sdk::{
     // Builders
@@ -19,6 +27,7 @@ pub use deadcat_sdk::{
     // Conversions
     discovered_market_to_contract_params,
     fetch_announcements,
+    fetch_events_with_relays,
     parse_announcement_event,
     publish_event,
     sign_attestation,
@@ -32,7 +41,6 @@ pub use deadcat_sdk::{
     CONTRACT_ANNOUNCEMENT_VERSION,
     CONTRACT_TAG,
     DEFAULT_RELAYS,
-    NETWORK_TAG,
 };
 pub use deadcat_sdk::{ContractAnnouncement, ContractMetadata, DiscoveredOrder};
 
@@ -72,12 +80,35 @@ pub struct NostrBackupStatus {
     pub relay_results: Vec<RelayBackupResult>,
 }
 
+/// Outcome of checking that the wallet backup on relays is usable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NostrBackupVerification {
+    pub exists: bool,
+    pub decryptable: bool,
+    pub matches_current_wallet: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RelayBackupResult {
     pub url: String,
     pub has_backup: bool,
 }
 
+/// Result of publishing an app state backup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppStateBackupResponse {
+    pub event_id: String,
+    pub chunk_count: usize,
+    pub event_count: usize,
+}
+
+/// Result of restoring an app state backup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppStateRestoreResponse {
+    pub events_restored: usize,
+    pub labels_restored: usize,
+}
+
 /// User profile metadata from kind 0.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NostrProfile {
@@ -90,8 +121,12 @@ pub struct NostrProfile {
 // Multi-relay client (app-layer, wraps SDK single-relay connect_client)
 // ---------------------------------------------------------------------------
 
-/// Connect a Nostr client to multiple relays (or defaults if empty).
-pub async fn connect_multi_relay_client(relays: &[String]) -> Result<Client, String> {
+/// Connect a Nostr client to multiple relays (or defaults if empty), waiting
+/// up to `timeout` for the connections.
+pub async fn connect_multi_relay_client(
+    relays: &[String],
+    timeout: Duration,
+) -> Result<Client, String> {
     let client = Client::default();
     let urls: Vec<&str> = if relays.is_empty() {
         DEFAULT_RELAYS.to_vec()
@@ -104,7 +139,7 @@ pub async fn connect_multi_relay_client(relays: &[String]) -> Result<Client, Str
             .await
             .map_err(|e| format!("failed to add relay {url}: {e}"))?;
     }
-    client.connect_with_timeout(Duration::from_secs(5)).await;
+    client.connect_with_timeout(timeout).await;
     Ok(client)
 }
 
@@ -112,7 +147,30 @@ pub async fn connect_multi_relay_client(relays: &[String]) -> Result<Client, Str
 // NIP-44 wallet backup (kind 30078)
 // ---------------------------------------------------------------------------
 
-/// Build a kind 30078 event containing a NIP-44 encrypted wallet mnemonic.
+/// Plaintext of a wallet backup: the mnemonic and the descriptor template
+/// the wallet uses. Backups made before the template was recorded hold the
+/// bare mnemonic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletBackup {
+    pub mnemonic: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descriptor_template: Option<String>,
+}
+
+impl WalletBackup {
+    pub fn to_plaintext(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("failed to encode wallet backup: {e}"))
+    }
+
+    pub fn from_plaintext(plaintext: &str) -> Self {
+        serde_json::from_str(plaintext).unwrap_or_else(|_| WalletBackup {
+            mnemonic: plaintext.to_string(),
+            descriptor_template: None,
+        })
+    }
+}
+
+/// Build a kind 30078 event containing a NIP-44 encrypted [`WalletBackup`].
 pub fn build_wallet_backup_event(keys: &Keys, encrypted_content: &str) -> Result<Event, String> {
     let tags = vec![
         Tag::identifier(WALLET_BACKUP_D_TAG),
@@ -183,6 +241,138 @@ pub fn build_backup_deletion_event(keys: &Keys) -> Result<Event, String> {
         .map_err(|e| format!("failed to build deletion event: {e}"))
 }
 
+// ---------------------------------------------------------------------------
+// NIP-44 app state backup (kind 30078, chunked)
+// ---------------------------------------------------------------------------
+
+/// Split `payload` into pieces of at most `max_bytes`, never splitting a
+/// UTF-8 character.
+pub fn split_backup_payload(payload: &str, max_bytes: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = payload;
+    while !rest.is_empty() {
+        let mut end = rest.len().min(max_bytes);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks
+}
+
+/// d-tag for chunk `index` of the app state backup.
+fn app_state_chunk_d_tag(index: usize) -> String {
+    format!("{APP_STATE_BACKUP_D_TAG}/{index}")
+}
+
+/// Build the manifest event announcing an app state backup of `chunk_count`
+/// chunks. Chunks are only accepted if they carry the same `backup_id`.
+pub fn build_app_state_manifest_event(
+    keys: &Keys,
+    backup_id: &str,
+    chunk_count: usize,
+) -> Result<Event, String> {
+    let tags = vec![
+        Tag::identifier(APP_STATE_BACKUP_D_TAG),
+        Tag::custom(TagKind::custom("backup"), vec![backup_id.to_string()]),
+        Tag::custom(TagKind::custom("chunks"), vec![chunk_count.to_string()]),
+    ];
+
+    EventBuilder::new(APP_EVENT_KIND, "")
+        .tags(tags)
+        .sign_with_keys(keys)
+        .map_err(|e| format!("failed to build app state manifest event: {e}"))
+}
+
+/// Build a kind 30078 event holding one NIP-44 encrypted app state chunk.
+pub fn build_app_state_chunk_event(
+    keys: &Keys,
+    backup_id: &str,
+    index: usize,
+    encrypted_content: &str,
+) -> Result<Event, String> {
+    let tags = vec![
+        Tag::identifier(app_state_chunk_d_tag(index)),
+        Tag::custom(TagKind::custom("backup"), vec![backup_id.to_string()]),
+        Tag::custom(TagKind::custom("encrypted"), vec!["true".to_string()]),
+        Tag::custom(TagKind::custom("encryption"), vec!["nip44".to_string()]),
+    ];
+
+    EventBuilder::new(APP_EVENT_KIND, encrypted_content)
+        .tags(tags)
+        .sign_with_keys(keys)
+        .map_err(|e| format!("failed to build app state chunk event: {e}"))
+}
+
+/// Build a filter to query the app state backup manifest for a given pubkey.
+pub fn build_app_state_manifest_filter(pubkey: &PublicKey) -> Filter {
+    Filter::new()
+        .kind(APP_EVENT_KIND)
+        .author(*pubkey)
+        .identifier(APP_STATE_BACKUP_D_TAG)
+}
+
+/// Build a filter to query all chunks listed in an app state manifest.
+pub fn build_app_state_chunks_filter(pubkey: &PublicKey, chunk_count: usize) -> Filter {
+    Filter::new()
+        .kind(APP_EVENT_KIND)
+        .author(*pubkey)
+        .identifiers((0..chunk_count).map(app_state_chunk_d_tag))
+}
+
+/// Read the `(backup_id, chunk_count)` pair from a manifest event.
+pub fn parse_app_state_manifest(event: &Event) -> Result<(String, usize), String> {
+    let backup_id = single_tag_value(event, "backup")
+        .ok_or_else(|| "app state manifest is missing its backup id".to_string())?;
+    let chunk_count = single_tag_value(event, "chunks")
+        .and_then(|v| v.parse::<usize>().ok())
+        .ok_or_else(|| "app state manifest has an invalid chunk count".to_string())?;
+    Ok((backup_id, chunk_count))
+}
+
+/// Pick the encrypted chunk contents belonging to `backup_id`, in order.
+///
+/// Fails if any chunk is missing; stale chunks left over from an earlier,
+/// larger backup are ignored.
+pub fn collect_app_state_chunks<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+    backup_id: &str,
+    chunk_count: usize,
+) -> Result<Vec<String>, String> {
+    let mut chunks: Vec<Option<String>> = vec![None; chunk_count];
+    for event in events {
+        if single_tag_value(event, "backup").as_deref() != Some(backup_id) {
+            continue;
+        }
+        let index = single_tag_value(event, "d")
+            .as_deref()
+            .and_then(|d| d.strip_prefix(APP_STATE_BACKUP_D_TAG)?.strip_prefix('/'))
+            .and_then(|i| i.parse::<usize>().ok());
+        if let Some(slot) = index.and_then(|i| chunks.get_mut(i)) {
+            *slot = Some(event.content.clone());
+        }
+    }
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| c.ok_or_else(|| format!("app state backup chunk {i} not found on relays")))
+        .collect()
+}
+
+fn single_tag_value(event: &Event, name: &str) -> Option<String> {
+    event.tags.iter().find_map(|t| {
+        let tag_vec = t.as_slice();
+        if tag_vec.len() >= 2 && tag_vec[0] == name {
+            Some(tag_vec[1].to_string())
+        } else {
+            None
+        }
+    })
+}
+
 // ---------------------------------------------------------------------------
 // NIP-65 relay list (kind 10002)
 // ---------------------------------------------------------------------------
@@ -219,32 +409,94 @@ pub fn parse_relay_list_event(event: &Event) -> Vec<String> {
         .collect()
 }
 
+/// Most of another user's write relays queried for their events; NIP-65
+/// asks clients to keep to a few per user.
+pub const MAX_AUTHOR_WRITE_RELAYS: usize = 4;
+
+/// The relays a kind 10002 event lists for writing: `r` tags marked `write`
+/// or unmarked, deduplicated and capped at [`MAX_AUTHOR_WRITE_RELAYS`].
+pub fn parse_write_relays(event: &Event) -> Vec<String> {
+    let mut relays: Vec<String> = Vec::new();
+    for tag in event.tags.iter() {
+        let v = tag.as_slice();
+        if v.len() < 2 || v[0] != "r" || v.get(2).is_some_and(|marker| marker != "write") {
+            continue;
+        }
+        let url = normalize_relay_url(&v[1]);
+        if !relays.contains(&url) {
+            relays.push(url);
+        }
+        if relays.len() == MAX_AUTHOR_WRITE_RELAYS {
+            break;
+        }
+    }
+    relays
+}
+
 /// Fetch a user's NIP-65 relay list from connected relays.
 pub async fn fetch_relay_list(
     client: &Client,
     pubkey: &PublicKey,
+    timeout: Duration,
+) -> Result<Option<Vec<String>>, String> {
+    fetch_relay_list_with(client, pubkey, timeout, parse_relay_list_event).await
+}
+
+/// Fetch the relays another user publishes to, per their NIP-65 list; see
+/// [`parse_write_relays`].
+pub async fn fetch_write_relays(
+    client: &Client,
+    pubkey: &PublicKey,
+    timeout: Duration,
+) -> Result<Option<Vec<String>>, String> {
+    fetch_relay_list_with(client, pubkey, timeout, parse_write_relays).await
+}
+
+async fn fetch_relay_list_with(
+    client: &Client,
+    pubkey: &PublicKey,
+    timeout: Duration,
+    parse: fn(&Event) -> Vec<String>,
 ) -> Result<Option<Vec<String>>, String> {
     let filter = Filter::new().kind(RELAY_LIST_KIND).author(*pubkey).limit(1);
 
     let events = client
-        .fetch_events(vec![filter], Duration::from_secs(10))
+        .fetch_events(vec![filter], timeout)
         .await
         .map_err(|e| format!("failed to fetch relay list: {e}"))?;
 
-    let result = {
-        let mut iter = events.iter();
-        if let Some(event) = iter.next() {
-            let relays = parse_relay_list_event(event);
-            if relays.is_empty() {
-                None
-            } else {
-                Some(relays)
-            }
-        } else {
-            None
-        }
-    };
-    Ok(result)
+    let relays = events
+        .iter()
+        .max_by_key(|event| event.created_at)
+        .map(parse)
+        .filter(|relays| !relays.is_empty());
+    Ok(relays)
+}
+
+/// How long another user's NIP-65 relay list is reused before refetching.
+pub const RELAY_LIST_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Other users' NIP-65 relay lists, keyed by pubkey.
+///
+/// A `None` list records that the user has published none, so lookups fall
+/// back to the default relays without asking again until the entry expires.
+#[derive(Default)]
+pub struct RelayListCache {
+    entries: HashMap<PublicKey, (Instant, Option<Vec<String>>)>,
+}
+
+impl RelayListCache {
+    /// The cached list for `pubkey`, or `None` on a miss or expired entry.
+    pub fn get(&self, pubkey: &PublicKey) -> Option<Option<Vec<String>>> {
+        self.entries
+            .get(pubkey)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < RELAY_LIST_CACHE_TTL)
+            .map(|(_, relays)| relays.clone())
+    }
+
+    pub fn insert(&mut self, pubkey: PublicKey, relays: Option<Vec<String>>) {
+        self.entries.insert(pubkey, (Instant::now(), relays));
+    }
 }
 
 /// Normalize a relay URL: lowercase, ensure wss://, strip trailing slash.
@@ -264,17 +516,27 @@ pub fn normalize_relay_url(url: &str) -> String {
 pub async fn fetch_profile(
     client: &Client,
     pubkey: &PublicKey,
+    timeout: Duration,
+) -> Result<Option<NostrProfile>, String> {
+    fetch_profile_with_relays(client, pubkey, &[], timeout).await
+}
+
+/// [`fetch_profile`], also querying `extra_relays` (e.g. the user's NIP-65
+/// list). The newest profile found on any relay wins.
+pub async fn fetch_profile_with_relays(
+    client: &Client,
+    pubkey: &PublicKey,
+    extra_relays: &[String],
+    timeout: Duration,
 ) -> Result<Option<NostrProfile>, String> {
     let filter = Filter::new().kind(Kind::Metadata).author(*pubkey).limit(1);
 
-    let events = client
-        .fetch_events(vec![filter], Duration::from_secs(10))
+    let events = fetch_events_with_relays(client, filter, extra_relays, timeout)
         .await
         .map_err(|e| format!("failed to fetch profile: {e}"))?;
 
     let result = {
-        let mut iter = events.iter();
-        if let Some(event) = iter.next() {
+        if let Some(event) = events.iter().max_by_key(|event| event.created_at) {
             let parsed: serde_json::Value = serde_json::from_str(&event.content)
                 .map_err(|e| format!("failed to parse profile JSON: {e}"))?;
             Some(NostrProfile {
@@ -302,12 +564,29 @@ pub async fn fetch_profile(
 // Identity persistence (app-layer concern)
 // ---------------------------------------------------------------------------
 
+/// Plaintext oracle key written by earlier builds. Oracle keys are now
+/// derived from the wallet seed; this file is only read, so markets already
+/// committed to its key can still be resolved.
+const LEGACY_ORACLE_KEY_FILE: &str = "oracle.key";
+
 /// Load an existing Nostr keypair from disk. Returns `None` if no key file exists.
 pub fn load_keys(app_data_dir: &std::path::Path) -> Result<Option<Keys>, String> {
-    let key_path = app_data_dir.join("nostr_identity.key");
+    read_key_file(&app_data_dir.join("nostr_identity.key"))
+}
 
+/// Generate a new Nostr keypair, persist to disk, and return it.
+pub fn generate_keys(app_data_dir: &std::path::Path) -> Result<Keys, String> {
+    write_new_key_file(&app_data_dir.join("nostr_identity.key"))
+}
+
+/// Load the legacy plaintext oracle key. Returns `None` if there is none.
+pub fn load_legacy_oracle_keys(app_data_dir: &std::path::Path) -> Result<Option<Keys>, String> {
+    read_key_file(&app_data_dir.join(LEGACY_ORACLE_KEY_FILE))
+}
+
+fn read_key_file(key_path: &std::path::Path) -> Result<Option<Keys>, String> {
     if key_path.exists() {
-        let hex_str = std::fs::read_to_string(&key_path)
+        let hex_str = std::fs::read_to_string(key_path)
             .map_err(|e| format!("failed to read key file: {e}"))?;
         let secret_key = SecretKey::from_hex(hex_str.trim())
             .map_err(|e| format!("failed to parse secret key: {e}"))?;
@@ -317,15 +596,13 @@ pub fn load_keys(app_data_dir: &std::path::Path) -> Result<Option<Keys>, String>
     }
 }
 
-/// Generate a new Nostr keypair, persist to disk, and return it.
-pub fn generate_keys(app_data_dir: &std::path::Path) -> Result<Keys, String> {
-    let key_path = app_data_dir.join("nostr_identity.key");
+fn write_new_key_file(key_path: &std::path::Path) -> Result<Keys, String> {
     let keys = Keys::generate();
     let secret_hex = keys.secret_key().to_secret_hex();
     if let Some(parent) = key_path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| format!("failed to create data dir: {e}"))?;
     }
-    std::fs::write(&key_path, secret_hex).map_err(|e| format!("failed to write key file: {e}"))?;
+    std::fs::write(key_path, secret_hex).map_err(|e| format!("failed to write key file: {e}"))?;
     Ok(keys)
 }
 
@@ -423,4 +700,92 @@ mod tests {
         assert_ne!(msg_yes, msg_no);
         assert_ne!(sig_yes, sig_no);
     }
+
+    #[test]
+    fn app_state_chunks_round_trip_and_ignore_stale_backups() {
+        let keys = Keys::generate();
+        let payload = "é".repeat(10) + &"x".repeat(25);
+        let pieces = split_backup_payload(&payload, 8);
+        assert!(pieces.iter().all(|p| p.len() <= 8));
+        assert_eq!(pieces.concat(), payload);
+
+        let manifest = build_app_state_manifest_event(&keys, "new", pieces.len()).unwrap();
+        assert_eq!(
+            parse_app_state_manifest(&manifest).unwrap(),
+            ("new".to_string(), pieces.len())
+        );
+
+        // Publish chunks in reverse order, plus a stale chunk from an older backup.
+        let mut events: Vec<Event> = pieces
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, p)| build_app_state_chunk_event(&keys, "new", i, p).unwrap())
+            .collect();
+        events.push(build_app_state_chunk_event(&keys, "old", pieces.len(), "stale").unwrap());
+
+        let chunks = collect_app_state_chunks(&events, "new", pieces.len()).unwrap();
+        assert_eq!(chunks.concat(), payload);
+
+        events.remove(0);
+        assert!(collect_app_state_chunks(&events, "new", pieces.len()).is_err());
+    }
+
+    #[test]
+    fn wallet_backup_round_trips_and_reads_bare_mnemonics() {
+        let backup = WalletBackup {
+            mnemonic: "abandon ability able".to_string(),
+            descriptor_template: Some("sh_wpkh".to_string()),
+        };
+        let parsed = WalletBackup::from_plaintext(&backup.to_plaintext().unwrap());
+        assert_eq!(parsed.mnemonic, backup.mnemonic);
+        assert_eq!(parsed.descriptor_template.as_deref(), Some("sh_wpkh"));
+
+        let legacy = WalletBackup::from_plaintext("abandon ability able");
+        assert_eq!(legacy.mnemonic, "abandon ability able");
+        assert!(legacy.descriptor_template.is_none());
+    }
+
+    #[test]
+    fn write_relays_skip_read_only_entries_and_are_capped() {
+        let keys = Keys::generate();
+        let r_tag = |values: &[&str]| {
+            Tag::custom(
+                TagKind::custom("r"),
+                values.iter().map(|value| value.to_string()),
+            )
+        };
+        let mut tags = vec![
+            r_tag(&["wss://read.example", "read"]),
+            r_tag(&["wss://both.example"]),
+            r_tag(&["wss://both.example/"]),
+        ];
+        tags.extend((0..10).map(|i| r_tag(&[format!("wss://w{i}.example").as_str(), "write"])));
+        let event = EventBuilder::new(RELAY_LIST_KIND, "")
+            .tags(tags)
+            .sign_with_keys(&keys)
+            .unwrap();
+
+        let relays = parse_write_relays(&event);
+        assert_eq!(relays.len(), MAX_AUTHOR_WRITE_RELAYS);
+        assert_eq!(relays[0], "wss://both.example");
+        assert!(!relays.iter().any(|url| url.contains("read.example")));
+        assert_eq!(relays[1], "wss://w0.example");
+    }
+
+    #[test]
+    fn legacy_oracle_key_is_read_but_not_the_identity() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("deadcat-oracle-key-{nanos}"));
+
+        assert!(load_legacy_oracle_keys(&dir).unwrap().is_none());
+        let keys = write_new_key_file(&dir.join(LEGACY_ORACLE_KEY_FILE)).unwrap();
+        let loaded = load_legacy_oracle_keys(&dir).unwrap().unwrap();
+        assert_eq!(loaded.public_key(), keys.public_key());
+        assert!(load_keys(&dir).unwrap().is_none());
+        let _ = std::fs::remove_dir_all(dir);
+    }
 }
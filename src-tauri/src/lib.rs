@@ -2,20 +2,49 @@ mod chain_adapter;
 pub mod commands;
 pub mod discovery;
 mod payments;
+#[cfg(feature = "regtest")]
+mod regtest;
 pub mod state;
 pub mod wallet;
 mod wallet_store;
 
-use std::sync::Mutex;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 use deadcat_sdk::elements::hashes::Hash as _;
 use deadcat_store::ChainSource;
 use serde::Deserialize;
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
 
-use state::{AppState, AppStateManager, PaymentSwap, AUTO_LOCK_TIMEOUT_SECS};
+use state::{AppState, AppStateManager, PaymentSwap};
 
 const APP_STATE_UPDATED_EVENT: &str = "app_state_updated";
+/// Emitted instead of `app_state_updated` when only wallet balances changed;
+/// carries just the changed assets (see `state::BalanceUpdate`).
+const BALANCE_UPDATED_EVENT: &str = "balance_updated";
+/// Emitted by the background poller whenever the chain tip height changes.
+const CHAIN_TIP_EVENT: &str = "chain_tip";
+/// Emitted after a sync when a market the wallet holds tokens in resolves.
+const MARKET_RESOLVED_EVENT: &str = "market_resolved";
+/// Emitted by the chain-tip poller, once per market or order, when a held
+/// position's market is within the expiry warning window.
+const MARKET_EXPIRING_EVENT: &str = "market_expiring";
+const ORDER_EXPIRING_EVENT: &str = "order_expiring";
+/// Emitted by the chain-tip poller when a pool's YES price crosses a user's
+/// price alert threshold.
+const PRICE_ALERT_EVENT: &str = "price_alert";
+/// Emitted by `rescan_from_height` as it moves through its phases.
+const RESCAN_PROGRESS_EVENT: &str = "rescan_progress";
+/// Emitted after unlock when interrupted covenant broadcasts are still
+/// pending and can be retried or discarded.
+const BROADCAST_INTENTS_PENDING_EVENT: &str = "broadcast_intents_pending";
+const DEFAULT_LOG_LEVEL: log::LevelFilter = log::LevelFilter::Info;
+/// How often the background task prunes old store history.
+const STORE_PRUNE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(6 * 60 * 60);
+/// How often announcements short of relay quorum are published again.
+const PUBLISH_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2 * 60);
 
 /// Holds the DeadcatNode behind a tokio Mutex for async access.
 /// Separate from `AppStateManager` because the node's async methods
@@ -24,19 +53,271 @@ const APP_STATE_UPDATED_EVENT: &str = "app_state_updated";
 ///
 /// NOTE: Commands should drop this guard as soon as possible after the
 /// node call completes, especially before acquiring `AppStateManager`'s
-/// std Mutex, to avoid holding both locks simultaneously.
+/// std Mutex, to avoid holding both locks simultaneously. Background tasks
+/// that run for long clone the `Arc` and release the guard instead.
 pub struct NodeState {
-    pub node: tokio::sync::Mutex<Option<deadcat_sdk::DeadcatNode<deadcat_store::DeadcatStore>>>,
+    pub node:
+        tokio::sync::Mutex<Option<Arc<deadcat_sdk::DeadcatNode<deadcat_store::DeadcatStore>>>>,
+    /// Keys of broadcasting operations currently running; see [`InFlightGuard`].
+    in_flight: Mutex<HashSet<String>>,
+    /// Cancellable operations currently running, by caller-chosen id; see
+    /// [`run_cancellable`].
+    operations: Mutex<HashMap<String, RunningOperation>>,
 }
 
 impl Default for NodeState {
     fn default() -> Self {
         Self {
             node: tokio::sync::Mutex::new(None),
+            in_flight: Mutex::new(HashSet::new()),
+            operations: Mutex::new(HashMap::new()),
         }
     }
 }
 
+/// A command failure the frontend acts on rather than only displaying.
+///
+/// Commands still fail with a `String`; these convert into a tagged JSON
+/// one, `{"code": ..., "message": ...}` plus any fields of the variant,
+/// which `src/api/tauri.ts` turns back into a typed error so the UI matches
+/// on the code instead of the wording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// A real-money mainnet command lacks `confirm_mainnet: true`.
+    MainnetConfirmationRequired,
+    /// The operation was stopped through `cancel_operation`.
+    Cancelled,
+    /// An identical operation is already being built or broadcast.
+    OperationInProgress,
+    /// The wallet's only L-BTC UTXO is needed for collateral, so the fee
+    /// needs a separate one of at least `min_amount` sats. The UI offers a
+    /// `split_utxo` before the operation is retried.
+    SplitUtxoRequired { min_amount: u64 },
+    /// The wallet holds `available` separate UTXOs of `asset_id` where the
+    /// transaction needs `needed`. Splitting or funding and retrying is safe.
+    NeedMoreUtxos {
+        asset_id: String,
+        needed: usize,
+        available: usize,
+    },
+}
+
+impl CommandError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CommandError::MainnetConfirmationRequired => "mainnet_confirmation_required",
+            CommandError::Cancelled => "operation_cancelled",
+            CommandError::OperationInProgress => "operation_in_progress",
+            CommandError::SplitUtxoRequired { .. } => "split_utxo_required",
+            CommandError::NeedMoreUtxos { .. } => "need_more_utxos",
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            CommandError::MainnetConfirmationRequired => "mainnet confirmation required".into(),
+            CommandError::Cancelled => "operation cancelled".into(),
+            CommandError::OperationInProgress => "operation already in progress".into(),
+            CommandError::SplitUtxoRequired { min_amount } => format!(
+                "the fee needs a separate L-BTC UTXO of at least {min_amount} sats; \
+                 split a wallet UTXO first"
+            ),
+            CommandError::NeedMoreUtxos {
+                asset_id,
+                needed,
+                available,
+            } => format!(
+                "need {needed} separate UTXOs of asset {asset_id} but the wallet has \
+                 {available}; split or fund the wallet first"
+            ),
+        }
+    }
+}
+
+impl From<CommandError> for String {
+    fn from(err: CommandError) -> Self {
+        let mut json = serde_json::json!({ "code": err.code(), "message": err.message() });
+        match err {
+            CommandError::SplitUtxoRequired { min_amount } => {
+                json["min_amount"] = min_amount.into();
+            }
+            CommandError::NeedMoreUtxos {
+                asset_id,
+                needed,
+                available,
+            } => {
+                json["asset_id"] = asset_id.into();
+                json["needed"] = needed.into();
+                json["available"] = available.into();
+            }
+            _ => {}
+        }
+        json.to_string()
+    }
+}
+
+/// Marks a broadcasting operation as in flight until dropped.
+///
+/// The UI can fire the same command twice (e.g. a double-click), and each
+/// call would otherwise build and broadcast its own transaction. Commands
+/// claim a key made of the operation name plus its salient inputs; a second
+/// claim of the same key fails with [`CommandError::OperationInProgress`] until
+/// the first call returns, whether it succeeded or not.
+pub(crate) struct InFlightGuard<R: Runtime> {
+    app: AppHandle<R>,
+    key: String,
+}
+
+impl<R: Runtime> InFlightGuard<R> {
+    pub(crate) fn begin(app: &AppHandle<R>, key: String) -> Result<Self, String> {
+        let node_state = app.state::<NodeState>();
+        let mut in_flight = node_state
+            .in_flight
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        if !in_flight.insert(key.clone()) {
+            return Err(CommandError::OperationInProgress.into());
+        }
+        Ok(Self {
+            app: app.clone(),
+            key,
+        })
+    }
+}
+
+impl<R: Runtime> Drop for InFlightGuard<R> {
+    fn drop(&mut self) {
+        let node_state = self.app.state::<NodeState>();
+        if let Ok(mut in_flight) = node_state.in_flight.lock() {
+            in_flight.remove(&self.key);
+        }
+    }
+}
+
+/// Set when an operation is cancelled. Work the abort can't interrupt,
+/// such as a `spawn_blocking` closure, checks it before emitting events.
+#[derive(Clone, Default)]
+pub(crate) struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+struct RunningOperation {
+    token: CancellationToken,
+    abort: tokio::task::AbortHandle,
+}
+
+/// Removes an operation from [`NodeState`] when it ends, however it ends.
+struct RunningOperationGuard<R: Runtime> {
+    app: AppHandle<R>,
+    op_id: String,
+    token: CancellationToken,
+}
+
+impl<R: Runtime> Drop for RunningOperationGuard<R> {
+    fn drop(&mut self) {
+        let node_state = self.app.state::<NodeState>();
+        if let Ok(mut operations) = node_state.operations.lock() {
+            // A cancelled operation's id can be reused before this guard
+            // drops; leave a newer operation's entry alone.
+            let ours = operations
+                .get(&self.op_id)
+                .is_some_and(|op| Arc::ptr_eq(&op.token.0, &self.token.0));
+            if ours {
+                operations.remove(&self.op_id);
+            }
+        }
+    }
+}
+
+/// Run `task` as a spawned operation that `cancel_operation(op_id)` can
+/// abort.
+///
+/// Aborting drops the task at its next await, releasing the node and state
+/// locks it holds, and the call returns [`CommandError::Cancelled`].
+/// Without an `op_id` the task runs inline and cannot be cancelled.
+pub(crate) async fn run_cancellable<R, T, F, Fut>(
+    app: &AppHandle<R>,
+    op_id: Option<String>,
+    task: F,
+) -> Result<T, String>
+where
+    R: Runtime,
+    T: Send + 'static,
+    F: FnOnce(CancellationToken) -> Fut,
+    Fut: Future<Output = Result<T, String>> + Send + 'static,
+{
+    let token = CancellationToken::default();
+    let Some(op_id) = op_id else {
+        return task(token).await;
+    };
+
+    let node_state = app.state::<NodeState>();
+    let handle = {
+        let mut operations = node_state
+            .operations
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        if operations.contains_key(&op_id) {
+            return Err(CommandError::OperationInProgress.into());
+        }
+        let handle = tokio::spawn(task(token.clone()));
+        operations.insert(
+            op_id.clone(),
+            RunningOperation {
+                token: token.clone(),
+                abort: handle.abort_handle(),
+            },
+        );
+        handle
+    };
+    let _guard = RunningOperationGuard {
+        app: app.clone(),
+        op_id,
+        token,
+    };
+
+    match handle.await {
+        Ok(result) => result,
+        Err(e) if e.is_cancelled() => Err(CommandError::Cancelled.into()),
+        Err(e) => Err(format!("operation failed: {e}")),
+    }
+}
+
+/// Cancel the operation started under `op_id`. Returns `false` when no such
+/// operation is running (it may already have finished).
+#[tauri::command]
+fn cancel_operation(app: AppHandle, op_id: String) -> Result<bool, String> {
+    cancel_operation_inner(&app, &op_id)
+}
+
+pub(crate) fn cancel_operation_inner<R: Runtime>(
+    app: &AppHandle<R>,
+    op_id: &str,
+) -> Result<bool, String> {
+    let node_state = app.state::<NodeState>();
+    let operation = node_state
+        .operations
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?
+        .remove(op_id);
+    Ok(match operation {
+        Some(operation) => {
+            operation.token.cancel();
+            operation.abort.abort();
+            true
+        }
+        None => false,
+    })
+}
+
 /// Minimal state for the legacy wallet_store commands.
 #[derive(Default)]
 pub struct WalletStoreState {
@@ -46,6 +327,17 @@ pub struct WalletStoreState {
 /// App-layer Nostr state: relay list (keys come from the node).
 pub struct NostrAppState {
     pub relay_list: std::sync::RwLock<Vec<String>>,
+    pub relay_list_cache: Mutex<discovery::RelayListCache>,
+}
+
+impl NostrAppState {
+    /// Start from `network`'s default relays.
+    pub fn for_network(network: Network) -> Self {
+        Self {
+            relay_list: std::sync::RwLock::new(default_relays(network)),
+            relay_list_cache: Mutex::default(),
+        }
+    }
 }
 
 impl Default for NostrAppState {
@@ -57,10 +349,52 @@ impl Default for NostrAppState {
                     .map(|s| s.to_string())
                     .collect(),
             ),
+            relay_list_cache: Mutex::default(),
         }
     }
 }
 
+pub(crate) fn default_relays(network: Network) -> Vec<String> {
+    state::to_sdk_network(network)
+        .default_relays()
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// The user's per-service network timeouts, or the defaults when app state
+/// is unavailable.
+pub(crate) fn network_timeouts<R: Runtime>(app: &AppHandle<R>) -> state::NetworkTimeouts {
+    app.state::<Mutex<AppStateManager>>()
+        .lock()
+        .map(|mgr| mgr.network_timeouts())
+        .unwrap_or_default()
+}
+
+/// The Esplora base URL configured for `network`; see
+/// [`AppStateManager::esplora_api_url`].
+pub(crate) fn esplora_url<R: Runtime>(app: &AppHandle<R>, network: Network) -> String {
+    app.state::<Mutex<AppStateManager>>()
+        .lock()
+        .map(|mgr| mgr.esplora_api_url(network))
+        .unwrap_or_else(|_| state::to_sdk_network(network).esplora_url().to_string())
+}
+
+/// The Electrum URL the loaded wallet syncs from, or the network default
+/// when no wallet is loaded.
+pub(crate) async fn wallet_electrum_url<R: Runtime>(
+    app: &AppHandle<R>,
+    network: Network,
+) -> String {
+    let node = app.state::<NodeState>().node.lock().await.clone();
+    node.and_then(|node| node.electrum_url())
+        .unwrap_or_else(|| {
+            state::to_sdk_network(network)
+                .default_electrum_url()
+                .to_string()
+        })
+}
+
 // ============================================================================
 // Network type
 // ============================================================================
@@ -100,6 +434,29 @@ impl std::str::FromStr for Network {
     }
 }
 
+/// Mainnet `send_lbtc` amounts (sats) from which confirmation is required.
+pub const MAINNET_CONFIRMATION_SEND_THRESHOLD_SATS: u64 = 1_000_000;
+
+/// Refuse a dangerous command on mainnet unless the caller confirmed it,
+/// failing with [`CommandError::MainnetConfirmationRequired`].
+///
+/// Testnet and regtest always pass, as does mainnet once the user turns the
+/// `require_mainnet_confirmation` setting off.
+pub(crate) fn require_mainnet_confirmation<R: Runtime>(
+    app: &AppHandle<R>,
+    confirm_mainnet: Option<bool>,
+) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    let gated = mgr.network().is_some_and(|n| n.is_mainnet()) && mgr.require_mainnet_confirmation();
+    if gated && !confirm_mainnet.unwrap_or(false) {
+        return Err(CommandError::MainnetConfirmationRequired.into());
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Network Commands
 // ============================================================================
@@ -125,7 +482,18 @@ async fn set_network(network: Network, app: AppHandle) -> Result<AppState, Strin
         let mut mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
+        let changed = mgr.network() != Some(network);
         let state = mgr.set_network(network);
+        drop(mgr);
+        // Each network discovers on its own relays; custom lists don't carry over.
+        if changed {
+            let nostr_state = app_handle.state::<NostrAppState>();
+            let mut list = nostr_state
+                .relay_list
+                .write()
+                .map_err(|_| "failed to write relay_list".to_string())?;
+            *list = default_relays(network);
+        }
         emit_state(&app_handle, &state);
         Ok(state)
     })
@@ -179,24 +547,99 @@ async fn get_wallet_status(app: AppHandle) -> Result<wallet::types::WalletStatus
 }
 
 #[tauri::command]
-async fn create_wallet(password: String, app: AppHandle) -> Result<String, String> {
+async fn create_wallet(
+    password: String,
+    descriptor_template: Option<String>,
+    app: AppHandle,
+) -> Result<String, String> {
+    let template = parse_descriptor_template(descriptor_template.as_deref())?;
     let app_handle = app.clone();
     tokio::task::spawn_blocking(move || {
         let manager = app_handle.state::<Mutex<AppStateManager>>();
         let mut mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        let network = mgr.network().ok_or("Network not initialized")?;
-        let sdk_network = state::to_sdk_network(network);
+        let mnemonic = generate_and_save_mnemonic(&mut mgr, &password, template)?;
 
-        let mnemonic =
-            deadcat_sdk::DeadcatNode::<deadcat_sdk::NoopStore>::generate_mnemonic(sdk_network)
-                .map_err(|e| format!("{e}"))?;
+        mgr.bump_revision();
+        let state = mgr.snapshot();
+        emit_state(&app_handle, &state);
+        Ok(mnemonic)
+    })
+    .await
+    .map_err(|e| format!("create_wallet task failed: {e}"))?
+}
 
-        let persister = mgr.persister_mut().ok_or("Persister not initialized")?;
-        persister
-            .save(&mnemonic, &password)
-            .map_err(|e| e.to_string())?;
+/// Generate a fresh mnemonic for the active profile's network and store it
+/// encrypted under `password`, along with the descriptor `template`.
+fn generate_and_save_mnemonic(
+    mgr: &mut AppStateManager,
+    password: &str,
+    template: deadcat_sdk::DescriptorTemplate,
+) -> Result<String, String> {
+    let network = mgr.network().ok_or("Network not initialized")?;
+    let sdk_network = state::to_sdk_network(network);
+
+    let mnemonic =
+        deadcat_sdk::DeadcatNode::<deadcat_sdk::NoopStore>::generate_mnemonic(sdk_network)
+            .map_err(|e| format!("{e}"))?;
+    template
+        .validate(&mnemonic, sdk_network.is_mainnet())
+        .map_err(|e| format!("{e}"))?;
+
+    let persister = mgr.persister_mut().ok_or("Persister not initialized")?;
+    persister
+        .save(&mnemonic, password, template)
+        .map_err(|e| e.to_string())?;
+    Ok(mnemonic)
+}
+
+/// Parse the optional `descriptor_template` command argument, falling back to
+/// the SDK default when the frontend doesn't pick one.
+fn parse_descriptor_template(
+    template: Option<&str>,
+) -> Result<deadcat_sdk::DescriptorTemplate, String> {
+    template
+        .map(|t| t.parse().map_err(|e| format!("{e}")))
+        .unwrap_or(Ok(deadcat_sdk::DescriptorTemplate::default()))
+}
+
+#[tauri::command]
+async fn list_wallets(app: AppHandle) -> Result<Vec<state::WalletProfileInfo>, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr.list_wallet_profiles())
+}
+
+/// Create a named wallet profile on `network`, switch to it, and generate its
+/// mnemonic. Returns the mnemonic for backup, like `create_wallet`.
+#[tauri::command]
+async fn create_named_wallet(
+    name: String,
+    network: Network,
+    password: String,
+    descriptor_template: Option<String>,
+    app: AppHandle,
+) -> Result<String, String> {
+    let template = parse_descriptor_template(descriptor_template.as_deref())?;
+    {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.create_wallet_profile(&name, network)?;
+    }
+    switch_wallet(name, app.clone()).await?;
+
+    let app_handle = app.clone();
+    tokio::task::spawn_blocking(move || {
+        let manager = app_handle.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let mnemonic = generate_and_save_mnemonic(&mut mgr, &password, template)?;
 
         mgr.bump_revision();
         let state = mgr.snapshot();
@@ -204,15 +647,55 @@ async fn create_wallet(password: String, app: AppHandle) -> Result<String, Strin
         Ok(mnemonic)
     })
     .await
-    .map_err(|e| format!("create_wallet task failed: {e}"))?
+    .map_err(|e| format!("create_named_wallet task failed: {e}"))?
+}
+
+/// Make `name` the active wallet profile. Locks the current wallet, reloads
+/// network, mnemonic and store from the new profile, and rebuilds the node
+/// (with the same Nostr identity) against the new store.
+#[tauri::command]
+async fn switch_wallet(name: String, app: AppHandle) -> Result<AppState, String> {
+    let _operation = OperationGuard::begin(&app)?;
+    let _in_flight = InFlightGuard::begin(&app, "switch_wallet".to_string())?;
+
+    // Lock the outgoing wallet and keep its Nostr keys for the new node.
+    let keys = {
+        let node_state = app.state::<NodeState>();
+        let mut guard = node_state.node.lock().await;
+        let keys = guard.as_ref().map(|node| {
+            node.lock_wallet();
+            node.keys().clone()
+        });
+        *guard = None;
+        keys
+    };
+
+    let (state, has_network) = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let state = mgr.switch_wallet_profile(&name)?;
+        (state, mgr.is_initialized())
+    };
+
+    if let (Some(keys), true) = (keys, has_network) {
+        commands::construct_and_store_node(&app, keys).await?;
+    }
+
+    log::info!("switched to wallet profile '{name}'");
+    emit_state(&app, &state);
+    Ok(state)
 }
 
 #[tauri::command]
 async fn restore_wallet(
     mnemonic: String,
     password: String,
+    descriptor_template: Option<String>,
     app: AppHandle,
 ) -> Result<AppState, String> {
+    let template = parse_descriptor_template(descriptor_template.as_deref())?;
     let app_handle = app.clone();
     tokio::task::spawn_blocking(move || {
         let manager = app_handle.state::<Mutex<AppStateManager>>();
@@ -224,10 +707,14 @@ async fn restore_wallet(
         let _: bip39::Mnemonic = mnemonic
             .parse()
             .map_err(|_| "Invalid mnemonic".to_string())?;
+        let network = mgr.network().ok_or("Network not initialized")?;
+        template
+            .validate(&mnemonic, state::to_sdk_network(network).is_mainnet())
+            .map_err(|e| format!("{e}"))?;
 
         let persister = mgr.persister_mut().ok_or("Persister not initialized")?;
         persister
-            .save(&mnemonic, &password)
+            .save(&mnemonic, &password, template)
             .map_err(|e| e.to_string())?;
 
         mgr.bump_revision();
@@ -244,7 +731,7 @@ async fn unlock_wallet(password: String, app: AppHandle) -> Result<AppState, Str
     let app_handle = app.clone();
 
     // 1. Decrypt mnemonic (blocking — Argon2 KDF)
-    let (mnemonic, network, data_dir) = tokio::task::spawn_blocking({
+    let (mnemonic, template, network, data_dir) = tokio::task::spawn_blocking({
         let app_ref = app_handle.clone();
         move || {
             let manager = app_ref.state::<Mutex<AppStateManager>>();
@@ -259,9 +746,10 @@ async fn unlock_wallet(password: String, app: AppHandle) -> Result<AppState, Str
             } else {
                 persister.load(&password).map_err(|e| e.to_string())?
             };
+            let template = persister.descriptor_template().map_err(|e| e.to_string())?;
 
-            let data_dir = mgr.app_data_dir.clone();
-            Ok::<_, String>((mnemonic, network, data_dir))
+            let data_dir = mgr.profile_dir();
+            Ok::<_, String>((mnemonic, template, network, data_dir))
         }
     })
     .await
@@ -276,8 +764,22 @@ async fn unlock_wallet(password: String, app: AppHandle) -> Result<AppState, Str
 
     let sdk_network = state::to_sdk_network(network);
     let electrum_url = sdk_network.default_electrum_url();
-    node.unlock_wallet(&mnemonic, electrum_url, &data_dir)
+    node.unlock_wallet_with_descriptor(&mnemonic, electrum_url, &data_dir, template)
         .map_err(|e| format!("{e}"))?;
+    let fee_coin_selection = app_handle
+        .state::<Mutex<AppStateManager>>()
+        .lock()
+        .map(|mgr| mgr.fee_coin_selection())
+        .unwrap_or_default();
+    for (operation, strategy) in fee_coin_selection {
+        if let Err(e) = node.set_fee_coin_selection(operation, strategy).await {
+            log::warn!("failed to apply {strategy} fee coin selection to {operation}: {e}");
+        }
+    }
+    let electrum_timeout = network_timeouts(&app_handle).electrum();
+    if let Err(e) = node.set_electrum_timeout(Some(electrum_timeout)).await {
+        log::warn!("failed to apply Electrum timeout: {e}");
+    }
     drop(guard);
 
     // 3. Update app state
@@ -299,11 +801,66 @@ async fn unlock_wallet(password: String, app: AppHandle) -> Result<AppState, Str
     .await
     .map_err(|e| format!("unlock state task failed: {e}"))??;
 
+    // 4. Settle broadcasts interrupted by a crash or quit
+    tauri::async_runtime::spawn(async move {
+        match commands::reconcile_broadcast_intents_inner(&app_handle).await {
+            Ok(intents) if !intents.is_empty() => {
+                log::info!("{} interrupted broadcasts still pending", intents.len());
+                let _ = app_handle.emit(BROADCAST_INTENTS_PENDING_EVENT, &intents);
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("broadcast intent reconciliation failed: {e}"),
+        }
+    });
+
     Ok(state)
 }
 
 #[tauri::command]
 async fn lock_wallet(app: AppHandle) -> Result<AppState, String> {
+    lock_node_and_state(&app).await
+}
+
+/// Called by the frontend when the app is minimized or sent to the
+/// background. Locks the wallet if `lock_on_background` is enabled and no
+/// send/swap is in flight. Returns whether the wallet was locked.
+#[tauri::command]
+async fn notify_app_backgrounded(app: AppHandle) -> Result<bool, String> {
+    let should_lock = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.should_lock_on_background()
+    };
+    if !should_lock {
+        return Ok(false);
+    }
+    lock_node_and_state(&app).await?;
+    log::info!("wallet locked after app moved to background");
+    Ok(true)
+}
+
+#[tauri::command]
+async fn get_lock_on_background(app: AppHandle) -> Result<bool, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr.lock_on_background())
+}
+
+#[tauri::command]
+async fn set_lock_on_background(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mut mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    mgr.set_lock_on_background(enabled);
+    Ok(())
+}
+
+async fn lock_node_and_state(app: &AppHandle) -> Result<AppState, String> {
     // Lock the node's wallet
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
@@ -331,18 +888,36 @@ async fn lock_wallet(app: AppHandle) -> Result<AppState, String> {
     .map_err(|e| format!("lock_wallet task failed: {e}"))?
 }
 
+/// Delete the active wallet. A named profile is removed along with its
+/// directory and the app switches back to the default profile; the default
+/// profile keeps its network config and store.
 #[tauri::command]
 async fn delete_wallet(app: AppHandle) -> Result<AppState, String> {
-    // Lock/drop the wallet in the node
-    let node_state = app.state::<NodeState>();
-    let guard = node_state.node.lock().await;
-    if let Some(node) = guard.as_ref() {
-        node.lock_wallet();
-    }
-    drop(guard);
+    let removes_profile = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.wallet_profile() != state::DEFAULT_WALLET_PROFILE
+    };
+
+    // Lock the wallet in the node; a removed profile's node is dropped so its
+    // store is closed before the directory goes away.
+    let keys = {
+        let node_state = app.state::<NodeState>();
+        let mut guard = node_state.node.lock().await;
+        let keys = guard.as_ref().map(|node| {
+            node.lock_wallet();
+            node.keys().clone()
+        });
+        if removes_profile {
+            *guard = None;
+        }
+        keys
+    };
 
     let app_handle = app.clone();
-    tokio::task::spawn_blocking(move || {
+    let (state, rebuild_node) = tokio::task::spawn_blocking(move || {
         let manager = app_handle.state::<Mutex<AppStateManager>>();
         let mut mgr = manager
             .lock()
@@ -351,43 +926,97 @@ async fn delete_wallet(app: AppHandle) -> Result<AppState, String> {
         if let Some(persister) = mgr.persister_mut() {
             persister.delete().map_err(|e| e.to_string())?;
         }
+        if removes_profile {
+            let state = mgr.remove_active_wallet_profile()?;
+            return Ok::<_, String>((state, mgr.is_initialized()));
+        }
         mgr.bump_revision();
-        let state = mgr.snapshot();
-        emit_state(&app_handle, &state);
-        Ok(state)
+        Ok((mgr.snapshot(), false))
     })
     .await
-    .map_err(|e| format!("delete_wallet task failed: {e}"))?
+    .map_err(|e| format!("delete_wallet task failed: {e}"))??;
+
+    if let (Some(keys), true) = (keys, rebuild_node) {
+        commands::construct_and_store_node(&app, keys).await?;
+    }
+
+    emit_state(&app, &state);
+    Ok(state)
 }
 
+/// Sync the wallet and store; cancellable when started with an `op_id`.
 #[tauri::command]
-async fn sync_wallet(app: AppHandle) -> Result<AppState, String> {
+async fn sync_wallet(
+    app: AppHandle,
+    op_id: Option<String>,
+) -> Result<commands::WalletSyncSummary, String> {
+    let task_app = app.clone();
+    run_cancellable(&app, op_id, move |token| sync_wallet_inner(task_app, token)).await
+}
+
+async fn sync_wallet_inner(
+    app: AppHandle,
+    token: CancellationToken,
+) -> Result<commands::WalletSyncSummary, String> {
     // Sync via the node (async — uses spawn_blocking internally)
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
-    node.sync().await.map_err(|e| format!("{e}"))?;
+    let pool_snapshots = node.sync().await.map_err(|e| format!("{e}"))?;
+    let mut summary = commands::WalletSyncSummary {
+        pool_snapshots,
+        ..Default::default()
+    };
 
     // Grab balance from the snapshot (sync — no lock needed)
-    let wallet_balance = node.balance().ok().map(|m| {
-        m.into_iter()
-            .filter(|(_, v)| *v > 0)
-            .map(|(k, v)| (k.to_string(), v))
+    let held_balance = node.balance().ok();
+    let wallet_balance = held_balance.as_ref().map(|m| {
+        m.iter()
+            .filter(|(_, v)| **v > 0)
+            .map(|(k, v)| (k.to_string(), *v))
             .collect()
     });
+
+    let explicit_indices = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.explicit_address_indices()
+    };
+    if !explicit_indices.is_empty() {
+        match node.explicit_address_utxos(explicit_indices).await {
+            Ok(utxos) => {
+                summary.explicit_utxos = utxos
+                    .into_iter()
+                    .map(|utxo| commands::ExplicitUtxoSummary {
+                        index: utxo.index,
+                        txid: utxo.outpoint.txid.to_string(),
+                        vout: utxo.outpoint.vout,
+                        asset_id: utxo.asset.to_string(),
+                        amount: utxo.value,
+                    })
+                    .collect();
+            }
+            Err(e) => summary
+                .warnings
+                .push(format!("explicit address scan failed: {e}")),
+        }
+    }
     drop(guard);
 
     // Also sync the store against the chain
     let app_handle = app.clone();
     tokio::task::spawn_blocking(move || {
         let manager = app_handle.state::<Mutex<AppStateManager>>();
-        let (store_arc, network) = {
+        let (store_arc, network, min_confirmations) = {
             let mgr = manager
                 .lock()
                 .map_err(|_| "state lock failed".to_string())?;
             (
                 mgr.store().cloned(),
                 mgr.network().unwrap_or(Network::Testnet),
+                mgr.min_confirmations(),
             )
         };
 
@@ -477,21 +1106,45 @@ async fn sync_wallet(app: AppHandle) -> Result<AppState, String> {
                             now_unix
                         );
                     }
-                    if let Err(e) = store.sync(&chain) {
-                        log::warn!("failed to sync store from {}: {e}", electrum_url);
+                    store.set_min_confirmations(min_confirmations);
+                    match store.sync(&chain) {
+                        Ok(report) => {
+                            if let Some(balance) = &held_balance {
+                                for event in
+                                    commands::held_market_resolutions(&mut store, &report, balance)
+                                {
+                                    let _ = app_handle.emit(MARKET_RESOLVED_EVENT, &event);
+                                }
+                            }
+                            summary.record_store_report(report);
+                        }
+                        Err(e) => {
+                            log::warn!("failed to sync store from {}: {e}", electrum_url);
+                            summary
+                                .warnings
+                                .push(format!("store sync from {electrum_url} failed: {e}"));
+                        }
                     }
                 }
-                Err(_) => log::warn!("failed to lock store for candidate promotion and sync"),
+                Err(_) => {
+                    log::warn!("failed to lock store for candidate promotion and sync");
+                    summary
+                        .warnings
+                        .push("store is unavailable; market and order state not synced".into());
+                }
             }
         }
 
+        // The abort can't stop this closure; don't announce state for a
+        // sync the user already walked away from.
+        if token.is_cancelled() {
+            return Err(CommandError::Cancelled.into());
+        }
         let mut mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.bump_revision();
-        let state = mgr.snapshot_with_balance(wallet_balance);
-        let _ = app_handle.emit(APP_STATE_UPDATED_EVENT, &state);
-        Ok(state)
+        emit_balance(&app_handle, &mut mgr, wallet_balance);
+        Ok(summary)
     })
     .await
     .map_err(|e| format!("sync task failed: {e}"))?
@@ -503,14 +1156,14 @@ async fn get_wallet_balance(app: AppHandle) -> Result<wallet::types::WalletBalan
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
     let balance_map = node.balance().map_err(|e| format!("{e}"))?;
+    let policy_asset = node.policy_asset();
 
-    let mut assets = std::collections::HashMap::new();
-    for (asset_id, amount) in balance_map.iter() {
-        if *amount > 0 {
-            assets.insert(asset_id.to_string(), *amount);
-        }
-    }
-    Ok(wallet::types::WalletBalance { assets })
+    Ok(wallet::types::WalletBalance::new(
+        balance_map
+            .iter()
+            .map(|(asset_id, amount)| (asset_id.to_string(), *amount)),
+        &policy_asset.to_string(),
+    ))
 }
 
 #[tauri::command]
@@ -528,28 +1181,98 @@ async fn get_wallet_address(
     })
 }
 
+/// Shown with every explicit address handed out.
+pub const EXPLICIT_ADDRESS_WARNING: &str =
+    "Explicit addresses are not confidential: amounts and assets sent here are public.";
+
+/// Unconfidential receive address for the same derivation as
+/// `get_wallet_address`. Opt-in only: the index is remembered so later syncs
+/// look for explicit funds paid to it.
 #[tauri::command]
-async fn get_wallet_transactions(
+async fn get_explicit_address(
+    index: Option<u32>,
     app: AppHandle,
-) -> Result<Vec<wallet::types::WalletTransaction>, String> {
-    let node_state = app.state::<NodeState>();
-    let guard = node_state.node.lock().await;
-    let node = guard.as_ref().ok_or("Node not initialized")?;
-    let policy_asset = node.policy_asset().await.map_err(|e| format!("{e}"))?;
-    let txs = node.transactions().map_err(|e| format!("{e}"))?;
+) -> Result<wallet::types::ExplicitWalletAddress, String> {
+    let (index, address) = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref().ok_or("Node not initialized")?;
+        node.explicit_address(index)
+            .await
+            .map_err(|e| format!("{e}"))?
+    };
+    {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.track_explicit_address_index(index);
+    }
+    Ok(wallet::types::ExplicitWalletAddress {
+        index,
+        address: address.to_string(),
+        warning: EXPLICIT_ADDRESS_WARNING,
+    })
+}
+
+/// Wallet history, optionally narrowed to transactions that move `asset_id`
+/// and to those that move any token of the stored market `market_id`.
+#[tauri::command]
+async fn get_wallet_transactions(
+    asset_id: Option<String>,
+    market_id: Option<String>,
+    app: AppHandle,
+) -> Result<Vec<wallet::types::WalletTransaction>, String> {
+    let asset_filter = asset_id
+        .map(|hex| {
+            hex.parse::<deadcat_sdk::elements::AssetId>()
+                .map_err(|e| format!("invalid asset id: {e}"))
+        })
+        .transpose()?;
+    let market_filter = match market_id {
+        Some(market_id) => {
+            let mid = deadcat_sdk::MarketId(commands::decode_hex_32(&market_id, "market_id")?);
+            let store_arc = commands::get_store(&app)?;
+            let mut store = store_arc
+                .lock()
+                .map_err(|_| "store lock failed".to_string())?;
+            let params = store
+                .get_market(&mid)
+                .map_err(|e| format!("get market: {e}"))?
+                .ok_or_else(|| format!("market {market_id} not found"))?
+                .params;
+            let assets = [
+                params.yes_token_asset,
+                params.no_token_asset,
+                params.yes_reissuance_token,
+                params.no_reissuance_token,
+            ]
+            .iter()
+            .map(|bytes| {
+                deadcat_sdk::elements::AssetId::from_slice(bytes)
+                    .map_err(|e| format!("invalid market asset: {e}"))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+            Some(assets)
+        }
+        None => None,
+    };
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let policy_asset = node.policy_asset();
+    let txs = node.transactions().map_err(|e| format!("{e}"))?;
     Ok(txs
         .iter()
-        .map(|tx| {
-            let balance_change = tx.balance.get(&policy_asset).copied().unwrap_or(0);
-            wallet::types::WalletTransaction {
-                txid: tx.txid.to_string(),
-                balance_change,
-                fee: tx.fee,
-                height: tx.height,
-                timestamp: tx.timestamp,
-                tx_type: tx.type_.clone(),
-            }
+        .filter(|tx| {
+            asset_filter
+                .is_none_or(|asset| wallet::types::tx_touches_any(tx, std::slice::from_ref(&asset)))
+                && market_filter
+                    .as_deref()
+                    .is_none_or(|assets| wallet::types::tx_touches_any(tx, assets))
         })
+        .map(|tx| wallet::types::WalletTransaction::from_wallet_tx(tx, &policy_asset))
         .collect())
 }
 
@@ -558,8 +1281,14 @@ async fn send_lbtc(
     address: String,
     amount_sat: u64,
     fee_rate: Option<f32>,
+    confirm_mainnet: Option<bool>,
     app: AppHandle,
 ) -> Result<wallet::types::LiquidSendResult, String> {
+    if amount_sat >= MAINNET_CONFIRMATION_SEND_THRESHOLD_SATS {
+        require_mainnet_confirmation(&app, confirm_mainnet)?;
+    }
+    let _operation = OperationGuard::begin(&app)?;
+    let _in_flight = InFlightGuard::begin(&app, format!("send_lbtc:{address}:{amount_sat}"))?;
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
@@ -583,9 +1312,7 @@ async fn send_lbtc(
         let mut mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.bump_revision();
-        let state = mgr.snapshot_with_balance(wallet_balance);
-        emit_state(&app_handle, &state);
+        emit_balance(&app_handle, &mut mgr, wallet_balance);
         Ok::<_, String>(())
     })
     .await
@@ -597,6 +1324,76 @@ async fn send_lbtc(
     })
 }
 
+/// Send `amount_sat` to a fresh wallet address so operations that need a
+/// separate fee UTXO can proceed. Returns once the wallet sees the split.
+#[tauri::command]
+async fn split_utxo(
+    amount_sat: u64,
+    fee_rate: Option<f32>,
+    app: AppHandle,
+) -> Result<wallet::types::LiquidSendResult, String> {
+    let _operation = OperationGuard::begin(&app)?;
+    let _in_flight = InFlightGuard::begin(&app, "split_utxo".to_string())?;
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    let node = guard.as_ref().ok_or("Node not initialized")?;
+    let (txid, fee_sat) = node
+        .split_utxo(amount_sat, fee_rate)
+        .await
+        .map_err(|e| format!("{e}"))?;
+    let wallet_balance = node.balance().ok().map(|m| {
+        m.into_iter()
+            .filter(|(_, v)| *v > 0)
+            .map(|(k, v)| (k.to_string(), v))
+            .collect()
+    });
+    drop(guard);
+
+    let app_handle = app.clone();
+    tokio::task::spawn_blocking(move || {
+        let manager = app_handle.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        emit_balance(&app_handle, &mut mgr, wallet_balance);
+        Ok::<_, String>(())
+    })
+    .await
+    .map_err(|e| format!("split_utxo state task failed: {e}"))??;
+
+    Ok(wallet::types::LiquidSendResult {
+        txid: txid.to_string(),
+        fee_sat,
+    })
+}
+
+/// Precision of `asset_id` on the currently selected network.
+fn asset_precision_for(app: &AppHandle, asset_id: &str) -> Result<u8, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    if !mgr.is_initialized() {
+        return Err("Not initialized - select a network first".to_string());
+    }
+    let policy_asset_id = mgr.network_status().policy_asset_id;
+    Ok(wallet::amount::asset_precision(asset_id, &policy_asset_id))
+}
+
+/// Render a raw amount of `asset_id` for display (8 decimals for L-BTC).
+#[tauri::command]
+fn format_amount(asset_id: String, raw_amount: u64, app: AppHandle) -> Result<String, String> {
+    let precision = asset_precision_for(&app, &asset_id)?;
+    Ok(wallet::amount::format_amount(raw_amount, precision))
+}
+
+/// Parse a user-entered decimal amount of `asset_id` into raw units.
+#[tauri::command]
+fn parse_amount(asset_id: String, human: String, app: AppHandle) -> Result<u64, String> {
+    let precision = asset_precision_for(&app, &asset_id)?;
+    wallet::amount::parse_amount(&human, precision).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn get_wallet_mnemonic(password: String, app: AppHandle) -> Result<String, String> {
     tokio::task::spawn_blocking(move || {
@@ -612,6 +1409,50 @@ async fn get_wallet_mnemonic(password: String, app: AppHandle) -> Result<String,
     .map_err(|e| format!("mnemonic task failed: {e}"))?
 }
 
+/// Check seed-phrase confirmation answers (zero-based index, word) against
+/// the stored mnemonic. Returns the indices that were wrong; empty means all
+/// answers matched.
+#[tauri::command]
+async fn verify_mnemonic_words(
+    password: String,
+    answers: Vec<(usize, String)>,
+    app: AppHandle,
+) -> Result<Vec<usize>, String> {
+    tokio::task::spawn_blocking(move || {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let persister = mgr.persister_mut().ok_or("Persister not initialized")?;
+        persister
+            .verify_words(&password, &answers)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| format!("verify_mnemonic task failed: {e}"))?
+}
+
+/// Re-encrypt the stored mnemonic under a new password.
+#[tauri::command]
+async fn change_wallet_password(
+    old_password: String,
+    new_password: String,
+    app: AppHandle,
+) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let persister = mgr.persister_mut().ok_or("Persister not initialized")?;
+        persister
+            .change_password(&old_password, &new_password)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| format!("change_password task failed: {e}"))?
+}
+
 /// Return the word count of the mnemonic (12 or 24) after verifying password.
 #[tauri::command]
 async fn get_mnemonic_word_count(password: String, app: AppHandle) -> Result<usize, String> {
@@ -659,6 +1500,8 @@ async fn pay_lightning_invoice(
     invoice: String,
     app: AppHandle,
 ) -> Result<payments::boltz::BoltzSubmarineSwapCreated, String> {
+    let _operation = OperationGuard::begin(&app)?;
+    let _in_flight = InFlightGuard::begin(&app, format!("pay_lightning_invoice:{invoice}"))?;
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
@@ -668,22 +1511,23 @@ async fn pay_lightning_invoice(
         .map_err(|e| format!("Wallet must be unlocked to initiate swap: {e}"))?;
     drop(guard);
 
-    let network = {
+    let (network, boltz_config) = {
         let manager = app.state::<Mutex<AppStateManager>>();
         let mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.network()
+        mgr.boltz_service_config()
             .ok_or("Not initialized - select a network first")?
     };
 
-    let boltz = payments::boltz::BoltzService::new(network, None);
+    let boltz = payments::boltz::BoltzService::new(network, boltz_config);
     let created = boltz
         .create_submarine_swap(&invoice, &refund_pubkey_hex)
         .await
         .map_err(|e| e.to_string())?;
 
     let now = chrono::Utc::now().to_rfc3339();
+    let payment_hash = payments::boltz::invoice_payment_hash(&invoice);
     let saved_swap = PaymentSwap {
         id: created.id.clone(),
         flow: created.flow.clone(),
@@ -698,6 +1542,10 @@ async fn pay_lightning_invoice(
         invoice_expiry_seconds: Some(created.invoice_expiry_seconds),
         invoice_expires_at: Some(created.invoice_expires_at.clone()),
         lockup_txid: None,
+        claim_txid: None,
+        preimage_hash: payment_hash,
+        preimage: None,
+        claim_details: None,
         created_at: now.clone(),
         updated_at: now,
     };
@@ -708,7 +1556,7 @@ async fn pay_lightning_invoice(
         let mut mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.upsert_payment_swap(saved_swap);
+        mgr.upsert_payment_swap(saved_swap)?;
         let state = mgr.snapshot();
         emit_state(&app_ref, &state);
         Ok::<_, String>(())
@@ -724,6 +1572,7 @@ async fn create_lightning_receive(
     amount_sat: u64,
     app: AppHandle,
 ) -> Result<payments::boltz::BoltzLightningReceiveCreated, String> {
+    let _operation = OperationGuard::begin(&app)?;
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
@@ -733,16 +1582,16 @@ async fn create_lightning_receive(
         .map_err(|e| format!("Wallet must be unlocked to initiate swap: {e}"))?;
     drop(guard);
 
-    let network = {
+    let (network, boltz_config) = {
         let manager = app.state::<Mutex<AppStateManager>>();
         let mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.network()
+        mgr.boltz_service_config()
             .ok_or("Not initialized - select a network first")?
     };
 
-    let boltz = payments::boltz::BoltzService::new(network, None);
+    let boltz = payments::boltz::BoltzService::new(network, boltz_config);
     let created = boltz
         .create_lightning_receive(amount_sat, &claim_pubkey_hex)
         .await
@@ -763,6 +1612,10 @@ async fn create_lightning_receive(
         invoice_expiry_seconds: Some(created.invoice_expiry_seconds),
         invoice_expires_at: Some(created.invoice_expires_at.clone()),
         lockup_txid: None,
+        claim_txid: None,
+        preimage_hash: Some(created.preimage_hash.clone()),
+        preimage: created.preimage.clone(),
+        claim_details: None,
         created_at: now.clone(),
         updated_at: now,
     };
@@ -773,7 +1626,7 @@ async fn create_lightning_receive(
         let mut mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.upsert_payment_swap(saved_swap);
+        mgr.upsert_payment_swap(saved_swap)?;
         let state = mgr.snapshot();
         emit_state(&app_ref, &state);
         Ok::<_, String>(())
@@ -789,6 +1642,7 @@ async fn create_bitcoin_receive(
     amount_sat: u64,
     app: AppHandle,
 ) -> Result<payments::boltz::BoltzChainSwapCreated, String> {
+    let _operation = OperationGuard::begin(&app)?;
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
@@ -802,16 +1656,16 @@ async fn create_bitcoin_receive(
         .map_err(|e| format!("Wallet must be unlocked to initiate swap: {e}"))?;
     drop(guard);
 
-    let network = {
+    let (network, boltz_config) = {
         let manager = app.state::<Mutex<AppStateManager>>();
         let mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.network()
+        mgr.boltz_service_config()
             .ok_or("Not initialized - select a network first")?
     };
 
-    let boltz = payments::boltz::BoltzService::new(network, None);
+    let boltz = payments::boltz::BoltzService::new(network, boltz_config);
     let created = boltz
         .create_chain_swap_btc_to_lbtc(amount_sat, &claim_pubkey_hex, &refund_pubkey_hex)
         .await
@@ -832,6 +1686,10 @@ async fn create_bitcoin_receive(
         invoice_expiry_seconds: None,
         invoice_expires_at: None,
         lockup_txid: None,
+        claim_txid: None,
+        preimage_hash: Some(created.preimage_hash.clone()),
+        preimage: created.preimage.clone(),
+        claim_details: created.claim_details.clone(),
         created_at: now.clone(),
         updated_at: now,
     };
@@ -842,7 +1700,7 @@ async fn create_bitcoin_receive(
         let mut mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.upsert_payment_swap(saved_swap);
+        mgr.upsert_payment_swap(saved_swap)?;
         let state = mgr.snapshot();
         emit_state(&app_ref, &state);
         Ok::<_, String>(())
@@ -858,6 +1716,8 @@ async fn create_bitcoin_send(
     amount_sat: u64,
     app: AppHandle,
 ) -> Result<payments::boltz::BoltzChainSwapCreated, String> {
+    let _operation = OperationGuard::begin(&app)?;
+    let _in_flight = InFlightGuard::begin(&app, format!("create_bitcoin_send:{amount_sat}"))?;
     let node_state = app.state::<NodeState>();
     let guard = node_state.node.lock().await;
     let node = guard.as_ref().ok_or("Node not initialized")?;
@@ -871,16 +1731,16 @@ async fn create_bitcoin_send(
         .map_err(|e| format!("Wallet must be unlocked to initiate swap: {e}"))?;
     drop(guard);
 
-    let network = {
+    let (network, boltz_config) = {
         let manager = app.state::<Mutex<AppStateManager>>();
         let mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.network()
+        mgr.boltz_service_config()
             .ok_or("Not initialized - select a network first")?
     };
 
-    let boltz = payments::boltz::BoltzService::new(network, None);
+    let boltz = payments::boltz::BoltzService::new(network, boltz_config);
     let created = boltz
         .create_chain_swap_lbtc_to_btc(amount_sat, &claim_pubkey_hex, &refund_pubkey_hex)
         .await
@@ -901,6 +1761,10 @@ async fn create_bitcoin_send(
         invoice_expiry_seconds: None,
         invoice_expires_at: None,
         lockup_txid: None,
+        claim_txid: None,
+        preimage_hash: Some(created.preimage_hash.clone()),
+        preimage: created.preimage.clone(),
+        claim_details: created.claim_details.clone(),
         created_at: now.clone(),
         updated_at: now,
     };
@@ -911,7 +1775,7 @@ async fn create_bitcoin_send(
         let mut mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.upsert_payment_swap(saved_swap);
+        mgr.upsert_payment_swap(saved_swap)?;
         let state = mgr.snapshot();
         emit_state(&app_ref, &state);
         Ok::<_, String>(())
@@ -926,22 +1790,61 @@ async fn create_bitcoin_send(
 async fn get_chain_swap_pairs(
     app: AppHandle,
 ) -> Result<payments::boltz::BoltzChainSwapPairsInfo, String> {
-    let network = {
+    let (network, boltz_config) = {
         let manager = app.state::<Mutex<AppStateManager>>();
         let mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.network()
+        mgr.boltz_service_config()
             .ok_or("Not initialized - select a network first".to_string())?
     };
 
-    let boltz = payments::boltz::BoltzService::new(network, None);
+    let boltz = payments::boltz::BoltzService::new(network, boltz_config);
     boltz
         .get_chain_swap_pairs_info()
         .await
         .map_err(|e| e.to_string())
 }
 
+/// Override the Boltz API base URL for the current network. Pass `null` (or
+/// an empty string) to go back to the network default.
+#[tauri::command]
+async fn set_boltz_url(url: Option<String>, app: AppHandle) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mut mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    mgr.set_boltz_api_url(url)
+}
+
+/// Override the Esplora API base URL used for chain-tip fetches on the
+/// current network, e.g. a local Esplora for regtest. Pass `null` (or an
+/// empty string) to go back to the network default.
+#[tauri::command]
+async fn set_esplora_url(url: Option<String>, app: AppHandle) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mut mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    mgr.set_esplora_api_url(url)
+}
+
+/// Change the log level at runtime (`off`, `error`, `warn`, `info`, `debug`,
+/// `trace`). Not persisted; the app starts at `DEFAULT_LOG_LEVEL`.
+///
+/// SDK operation spans log under `deadcat_sdk::op` at `debug` and need the
+/// `telemetry` feature.
+#[tauri::command]
+fn set_log_level(level: String) -> Result<(), String> {
+    let filter: log::LevelFilter = level
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid log level: {level}"))?;
+    log::set_max_level(filter);
+    log::info!("log level set to {filter}");
+    Ok(())
+}
+
 #[tauri::command]
 async fn list_payment_swaps(app: AppHandle) -> Result<Vec<PaymentSwap>, String> {
     tokio::task::spawn_blocking(move || {
@@ -949,7 +1852,11 @@ async fn list_payment_swaps(app: AppHandle) -> Result<Vec<PaymentSwap>, String>
         let mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        Ok(mgr.payment_swaps().to_vec())
+        Ok(mgr
+            .payment_swaps()
+            .iter()
+            .map(PaymentSwap::redacted)
+            .collect())
     })
     .await
     .map_err(|e| format!("list_swaps task failed: {e}"))?
@@ -961,21 +1868,74 @@ async fn refresh_payment_swap_status(
     app: AppHandle,
 ) -> Result<PaymentSwap, String> {
     let swap_id_clone = swap_id.clone();
-    let network = {
+    let (((network, boltz_config), pending_preimage), claim_inputs) = {
         let manager = app.state::<Mutex<AppStateManager>>();
         let mgr = manager
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
-        mgr.network()
-            .ok_or("Not initialized - select a network first".to_string())?
+        let config = mgr
+            .boltz_service_config()
+            .ok_or("Not initialized - select a network first".to_string())?;
+        // Flow and payment hash of a swap whose preimage is still unknown.
+        let pending_preimage = mgr
+            .payment_swap(&swap_id)
+            .filter(|swap| swap.preimage.is_none())
+            .and_then(|swap| Some((swap.flow, swap.preimage_hash?)));
+        // Never claim twice: only swaps that haven't finished and have no
+        // claim broadcast are candidates.
+        let claim_inputs = mgr
+            .payment_swap(&swap_id)
+            .filter(|swap| !swap.status.is_terminal() && swap.claim_txid.is_none())
+            .map(|swap| (swap.flow, swap.claim_details, swap.preimage));
+        ((config, pending_preimage), claim_inputs)
     };
 
-    let boltz = payments::boltz::BoltzService::new(network, None);
+    let boltz = payments::boltz::BoltzService::new(network, boltz_config);
     let status = boltz
         .get_swap_status(&swap_id_clone)
         .await
         .map_err(|e| e.to_string())?;
 
+    let mut preimage = None;
+    if let Some((flow, payment_hash)) = pending_preimage {
+        if payments::boltz::submarine_preimage_available(&flow, &status.status) {
+            match boltz.get_submarine_preimage(&swap_id).await {
+                Ok(revealed) if payments::boltz::preimage_matches(&revealed, &payment_hash) => {
+                    preimage = Some(revealed);
+                }
+                Ok(_) => log::warn!(
+                    "Boltz returned a preimage for swap {swap_id} that does not match its \
+                     payment hash"
+                ),
+                Err(e) => log::warn!("failed to fetch preimage for swap {swap_id}: {e}"),
+            }
+        }
+    }
+
+    let mut claim_txid = None;
+    if let Some((flow, claim_details, claim_preimage)) = claim_inputs {
+        if payments::boltz::is_chain_swap_claimable(&flow, &status.status) {
+            match (flow.as_str(), claim_details, claim_preimage) {
+                ("bitcoin_to_liquid", Some(details), Some(preimage_hex)) => {
+                    match claim_chain_swap_lockup(&app, &boltz, &swap_id, &details, &preimage_hex)
+                        .await
+                    {
+                        Ok(txid) => {
+                            log::info!("claimed chain swap {swap_id} in {txid}");
+                            claim_txid = Some(txid);
+                        }
+                        Err(e) => log::warn!("failed to claim chain swap {swap_id}: {e}"),
+                    }
+                }
+                _ => log::warn!(
+                    "chain swap {swap_id} is claimable ({}) but its claim details or \
+                     preimage are missing; claim it manually before it times out",
+                    status.status
+                ),
+            }
+        }
+    }
+
     let app_ref = app.clone();
     let updated_swap = tokio::task::spawn_blocking(move || {
         let manager = app_ref.state::<Mutex<AppStateManager>>();
@@ -983,21 +1943,24 @@ async fn refresh_payment_swap_status(
             .lock()
             .map_err(|_| "state lock failed".to_string())?;
         let existing = mgr
-            .payment_swaps()
-            .iter()
-            .find(|swap| swap.id == swap_id_clone)
-            .cloned()
+            .payment_swap(&swap_id_clone)
             .ok_or_else(|| format!("Payment swap not found: {}", swap_id_clone))?;
 
         let mut updated = existing;
         updated.status = status.status;
         updated.lockup_txid = status.lockup_txid;
+        if preimage.is_some() {
+            updated.preimage = preimage;
+        }
+        if claim_txid.is_some() {
+            updated.claim_txid = claim_txid;
+        }
         updated.updated_at = chrono::Utc::now().to_rfc3339();
 
-        mgr.upsert_payment_swap(updated.clone());
+        mgr.upsert_payment_swap(updated.clone())?;
         let state = mgr.snapshot();
         emit_state(&app_ref, &state);
-        Ok::<_, String>(updated)
+        Ok::<_, String>(updated.redacted())
     })
     .await
     .map_err(|e| format!("refresh_swap save task failed: {e}"))??;
@@ -1005,18 +1968,233 @@ async fn refresh_payment_swap_status(
     Ok(updated_swap)
 }
 
+/// Claim a Bitcoin-to-Liquid chain swap's L-BTC lockup into a fresh
+/// wallet address and return the claim txid.
+async fn claim_chain_swap_lockup(
+    app: &AppHandle,
+    boltz: &payments::boltz::BoltzService,
+    swap_id: &str,
+    claim_details: &str,
+    preimage_hex: &str,
+) -> Result<String, String> {
+    let _in_flight = InFlightGuard::begin(app, format!("claim_chain_swap:{swap_id}"))?;
+    // A concurrent refresh may have claimed while this one was fetching
+    // the status.
+    let already_claimed = app
+        .state::<Mutex<AppStateManager>>()
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?
+        .payment_swap(swap_id)
+        .is_some_and(|swap| swap.claim_txid.is_some());
+    if already_claimed {
+        return Err(format!("swap {swap_id} was already claimed"));
+    }
+
+    let (secret_key, claim_address, electrum_url) = {
+        let node_state = app.state::<NodeState>();
+        let guard = node_state.node.lock().await;
+        let node = guard.as_ref().ok_or("Node not initialized")?;
+        let secret_key = node
+            .boltz_reverse_claim_secret_key()
+            .await
+            .map_err(|e| format!("Wallet must be unlocked to claim swap: {e}"))?;
+        let claim_address = node
+            .address(None)
+            .await
+            .map_err(|e| e.to_string())?
+            .address()
+            .to_string();
+        let electrum_url = node
+            .electrum_url()
+            .ok_or("Wallet must be unlocked to claim swap")?;
+        (secret_key, claim_address, electrum_url)
+    };
+
+    boltz
+        .claim_chain_swap(
+            swap_id,
+            claim_details,
+            &secret_key.secret_bytes(),
+            preimage_hex,
+            &claim_address,
+            &electrum_url,
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Receipt for a successfully completed swap; see [`state::SwapReceipt`].
+/// Failed, expired and refunded swaps paid nothing, so they have none.
+#[tauri::command]
+async fn get_swap_receipt(swap_id: String, app: AppHandle) -> Result<state::SwapReceipt, String> {
+    tokio::task::spawn_blocking(move || {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let swap = mgr
+            .payment_swap(&swap_id)
+            .ok_or_else(|| format!("Payment swap not found: {swap_id}"))?;
+        if !swap.status.is_terminal() {
+            return Err(format!(
+                "Payment swap {swap_id} has not finished (status {})",
+                swap.status
+            ));
+        }
+        if !swap.status.is_success() {
+            return Err(format!(
+                "Payment swap {swap_id} did not complete (status {}), so it has no receipt",
+                swap.status
+            ));
+        }
+        Ok(state::SwapReceipt::from_swap(&swap))
+    })
+    .await
+    .map_err(|e| format!("swap_receipt task failed: {e}"))?
+}
+
+/// [`get_swap_receipt`] as pretty-printed JSON, for saving or sharing.
+#[tauri::command]
+async fn export_swap_receipt(swap_id: String, app: AppHandle) -> Result<String, String> {
+    let receipt = get_swap_receipt(swap_id, app).await?;
+    serde_json::to_string_pretty(&receipt).map_err(|e| e.to_string())
+}
+
+/// Blocks and seconds left before a swap's lockup timeout and invoice
+/// expiry, measured against the Liquid tip and the current time.
+#[tauri::command]
+async fn swap_time_remaining(
+    swap_id: String,
+    app: AppHandle,
+) -> Result<state::SwapTimeRemaining, String> {
+    let (swap, network) = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let network = mgr
+            .network()
+            .ok_or("Not initialized - select a network first".to_string())?;
+        let swap = mgr
+            .payment_swap(&swap_id)
+            .ok_or_else(|| format!("Payment swap not found: {swap_id}"))?;
+        (swap, network)
+    };
+
+    let tip_height = if swap.timeout_block_height.is_some()
+        && payments::boltz::swap_lockup_on_liquid(&swap.flow)
+    {
+        let url = esplora_url(&app, network);
+        let tip =
+            fetch_chain_tip_inner(network.into(), &url, network_timeouts(&app).esplora()).await?;
+        Some(tip.height)
+    } else {
+        None
+    };
+    Ok(state::SwapTimeRemaining::for_swap(
+        &swap,
+        tip_height,
+        chrono::Utc::now(),
+    ))
+}
+
+/// Non-terminal swaps whose status has not changed for
+/// [`payments::boltz::STUCK_SWAP_AFTER_SECS`], each with a recommended
+/// recovery action based on the Liquid tip and the lockup's UTXOs.
+#[tauri::command]
+async fn get_stuck_swaps(app: AppHandle) -> Result<Vec<state::StuckSwap>, String> {
+    let (swaps, network) = {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        let network = mgr
+            .network()
+            .ok_or("Not initialized - select a network first".to_string())?;
+        (mgr.payment_swaps().to_vec(), network)
+    };
+
+    let now = chrono::Utc::now();
+    let stale: Vec<PaymentSwap> = swaps
+        .into_iter()
+        .filter(|swap| !swap.status.is_terminal())
+        .filter(|swap| {
+            // An unparseable timestamp is treated as stale.
+            chrono::DateTime::parse_from_rfc3339(&swap.updated_at).map_or(true, |updated| {
+                (now - updated.with_timezone(&chrono::Utc)).num_seconds()
+                    >= payments::boltz::STUCK_SWAP_AFTER_SECS
+            })
+        })
+        .collect();
+    if stale.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let url = esplora_url(&app, network);
+    let tip_height = fetch_chain_tip_inner(network.into(), &url, network_timeouts(&app).esplora())
+        .await?
+        .height;
+    let electrum_url = wallet_electrum_url(&app, network).await;
+
+    tokio::task::spawn_blocking(move || {
+        let chain = chain_adapter::ElectrumChainAdapter::new(&electrum_url);
+
+        let stuck = stale
+            .into_iter()
+            .filter_map(|swap| {
+                let lockup_funded = swap
+                    .lockup_address
+                    .as_deref()
+                    .filter(|_| payments::boltz::swap_lockup_on_liquid(&swap.flow))
+                    .and_then(|address| {
+                        let address = address.parse::<deadcat_sdk::elements::Address>().ok()?;
+                        match chain.list_unspent(address.script_pubkey().as_bytes()) {
+                            Ok(utxos) => Some(!utxos.is_empty()),
+                            Err(e) => {
+                                log::warn!("lockup lookup for swap {} failed: {e}", swap.id);
+                                None
+                            }
+                        }
+                    });
+                // An empty lockup was never funded or has already been
+                // spent, so nothing is stuck.
+                if lockup_funded == Some(false) {
+                    return None;
+                }
+                let action = payments::boltz::swap_recovery_action(
+                    &swap.flow,
+                    &swap.status,
+                    swap.claim_txid.is_some(),
+                    swap.timeout_block_height,
+                    tip_height,
+                    lockup_funded,
+                );
+                Some(state::StuckSwap {
+                    swap: swap.redacted(),
+                    action,
+                    tip_height,
+                    lockup_funded,
+                })
+            })
+            .collect();
+        Ok(stuck)
+    })
+    .await
+    .map_err(|e| format!("get_stuck_swaps task failed: {e}"))?
+}
+
 // ============================================================================
 // Legacy Commands (backward compatibility)
 // ============================================================================
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, Clone)]
 pub struct ChainTipResponse {
     height: u32,
     block_hash: String,
     timestamp: u32,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Copy, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum WalletNetwork {
     Liquid,
@@ -1044,23 +2222,28 @@ impl From<Network> for WalletNetwork {
     }
 }
 
-pub async fn fetch_chain_tip_inner(network: WalletNetwork) -> Result<ChainTipResponse, String> {
-    let url = match network {
-        WalletNetwork::Liquid => "https://blockstream.info/liquid/api",
-        WalletNetwork::LiquidTestnet => "https://blockstream.info/liquidtestnet/api",
-        WalletNetwork::LiquidRegtest => {
-            return Err(
-                "liquid-regtest tip fetch is not configured; use liquid or liquid-testnet"
-                    .to_string(),
-            )
+impl From<WalletNetwork> for Network {
+    fn from(n: WalletNetwork) -> Self {
+        match n {
+            WalletNetwork::Liquid => Network::Mainnet,
+            WalletNetwork::LiquidTestnet => Network::Testnet,
+            WalletNetwork::LiquidRegtest => Network::Regtest,
         }
-    };
+    }
+}
 
-    let mut client = lwk_wollet::asyncr::EsploraClient::new(network.into_lwk(), url);
-    let tip = client
-        .tip()
+/// Fetch the chain tip from the Esplora at `esplora_url` (see
+/// [`esplora_url`]).
+pub async fn fetch_chain_tip_inner(
+    network: WalletNetwork,
+    esplora_url: &str,
+    timeout: std::time::Duration,
+) -> Result<ChainTipResponse, String> {
+    let mut client = lwk_wollet::asyncr::EsploraClient::new(network.into_lwk(), esplora_url);
+    let tip = tokio::time::timeout(timeout, client.tip())
         .await
-        .map_err(|e| format!("failed to fetch chain tip from LWK esplora: {e}"))?;
+        .map_err(|_| format!("chain tip fetch timed out after {}s", timeout.as_secs()))?
+        .map_err(|e| format!("failed to fetch chain tip from esplora at {esplora_url}: {e}"))?;
 
     Ok(ChainTipResponse {
         height: tip.height,
@@ -1070,8 +2253,12 @@ pub async fn fetch_chain_tip_inner(network: WalletNetwork) -> Result<ChainTipRes
 }
 
 #[tauri::command]
-async fn fetch_chain_tip(network: WalletNetwork) -> Result<ChainTipResponse, String> {
-    fetch_chain_tip_inner(network).await
+async fn fetch_chain_tip(
+    app: AppHandle,
+    network: WalletNetwork,
+) -> Result<ChainTipResponse, String> {
+    let url = esplora_url(&app, network.into());
+    fetch_chain_tip_inner(network, &url, network_timeouts(&app).esplora()).await
 }
 
 // ============================================================================
@@ -1089,6 +2276,190 @@ async fn record_activity(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Returns the auto-lock timeout in seconds, or `None` when auto-lock is off.
+#[tauri::command]
+async fn get_auto_lock_timeout(app: AppHandle) -> Result<Option<u64>, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr.auto_lock_timeout_secs())
+}
+
+/// Sets the auto-lock timeout. Pass `null` to never auto-lock.
+#[tauri::command]
+async fn set_auto_lock_timeout(app: AppHandle, seconds: Option<u64>) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mut mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    mgr.set_auto_lock_timeout(seconds)
+}
+
+/// Returns the background chain-tip poll interval in seconds.
+#[tauri::command]
+async fn get_chain_tip_poll_interval(app: AppHandle) -> Result<u64, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr.chain_tip_poll_secs())
+}
+
+/// Sets the background chain-tip poll interval; takes effect after the
+/// current wait.
+#[tauri::command]
+async fn set_chain_tip_poll_interval(app: AppHandle, seconds: u64) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mut mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    mgr.set_chain_tip_poll_secs(seconds)
+}
+
+/// Returns the per-service network timeouts in seconds.
+#[tauri::command]
+async fn get_network_timeouts(app: AppHandle) -> Result<state::NetworkTimeouts, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr.network_timeouts())
+}
+
+/// Sets the per-service network timeouts and applies the Electrum and Nostr
+/// ones to the running node; Esplora and Boltz read them per request.
+#[tauri::command]
+async fn set_network_timeouts(
+    app: AppHandle,
+    timeouts: state::NetworkTimeouts,
+) -> Result<(), String> {
+    {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.set_network_timeouts(timeouts)?;
+    }
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    if let Some(node) = guard.as_ref() {
+        node.set_nostr_timeout(timeouts.nostr());
+        if node.is_wallet_unlocked() {
+            node.set_electrum_timeout(Some(timeouts.electrum()))
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns how many blocks before market expiry held positions are flagged.
+#[tauri::command]
+async fn get_expiry_warning_window(app: AppHandle) -> Result<u32, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr.expiry_warning_blocks())
+}
+
+/// Sets the expiry warning window in blocks; `0` disables the warnings.
+#[tauri::command]
+async fn set_expiry_warning_window(app: AppHandle, blocks: u32) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mut mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    mgr.set_expiry_warning_blocks(blocks);
+    Ok(())
+}
+
+/// Returns each covenant operation's fee UTXO selection strategy, by name.
+#[tauri::command]
+async fn get_fee_coin_selection(app: AppHandle) -> Result<HashMap<String, String>, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr
+        .fee_coin_selection()
+        .into_iter()
+        .map(|(operation, strategy)| (operation.to_string(), strategy.to_string()))
+        .collect())
+}
+
+/// Sets how `operation` picks its fee UTXO: `smallest_first`,
+/// `largest_first` or `exact_match_or_largest`.
+#[tauri::command]
+async fn set_fee_coin_selection(
+    operation: String,
+    strategy: String,
+    app: AppHandle,
+) -> Result<(), String> {
+    let operation: deadcat_sdk::FeeOperation = operation.parse().map_err(|e| format!("{e}"))?;
+    let strategy: deadcat_sdk::CoinSelection = strategy.parse().map_err(|e| format!("{e}"))?;
+    {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.set_fee_coin_selection(operation, strategy);
+    }
+
+    let node_state = app.state::<NodeState>();
+    let guard = node_state.node.lock().await;
+    if let Some(node) = guard.as_ref().filter(|node| node.is_wallet_unlocked()) {
+        node.set_fee_coin_selection(operation, strategy)
+            .await
+            .map_err(|e| format!("{e}"))?;
+    }
+    Ok(())
+}
+
+/// Returns whether dangerous mainnet commands demand `confirm_mainnet: true`.
+#[tauri::command]
+async fn get_require_mainnet_confirmation(app: AppHandle) -> Result<bool, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr.require_mainnet_confirmation())
+}
+
+#[tauri::command]
+async fn set_require_mainnet_confirmation(app: AppHandle, required: bool) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mut mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    mgr.set_require_mainnet_confirmation(required);
+    Ok(())
+}
+
+/// Returns how many confirmations a market transition needs before it is final.
+#[tauri::command]
+async fn get_min_confirmations(app: AppHandle) -> Result<u32, String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    Ok(mgr.min_confirmations())
+}
+
+/// Sets the market transition confirmation threshold; `0` trusts unconfirmed
+/// transitions.
+#[tauri::command]
+async fn set_min_confirmations(app: AppHandle, confirmations: u32) -> Result<(), String> {
+    let manager = app.state::<Mutex<AppStateManager>>();
+    let mut mgr = manager
+        .lock()
+        .map_err(|_| "state lock failed".to_string())?;
+    mgr.set_min_confirmations(confirmations);
+    Ok(())
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -1097,6 +2468,163 @@ fn emit_state(app: &AppHandle, state: &AppState) {
     let _ = app.emit(APP_STATE_UPDATED_EVENT, state);
 }
 
+/// Announce a wallet balance refresh. Sends only the changed assets as
+/// `balance_updated` when the frontend already has an earlier balance, and
+/// the full state otherwise.
+fn emit_balance(
+    app: &AppHandle,
+    mgr: &mut AppStateManager,
+    wallet_balance: Option<HashMap<String, u64>>,
+) {
+    mgr.bump_revision();
+    match wallet_balance.as_ref().and_then(|b| mgr.record_balance(b)) {
+        Some(update) if update.changed.is_empty() => {}
+        Some(update) => {
+            let _ = app.emit(BALANCE_UPDATED_EVENT, &update);
+        }
+        None => emit_state(app, &mgr.snapshot_with_balance(wallet_balance)),
+    }
+}
+
+/// Marks a send/swap as in flight for the lifetime of the guard so that
+/// `notify_app_backgrounded` doesn't lock the wallet mid-flow.
+pub(crate) struct OperationGuard {
+    app: AppHandle,
+}
+
+impl OperationGuard {
+    pub(crate) fn begin(app: &AppHandle) -> Result<Self, String> {
+        let manager = app.state::<Mutex<AppStateManager>>();
+        let mut mgr = manager
+            .lock()
+            .map_err(|_| "state lock failed".to_string())?;
+        mgr.begin_operation();
+        Ok(Self { app: app.clone() })
+    }
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        let manager = self.app.state::<Mutex<AppStateManager>>();
+        if let Ok(mut mgr) = manager.lock() {
+            mgr.end_operation();
+        }
+    }
+}
+
+/// Poll the chain tip and emit `CHAIN_TIP_EVENT` on every new height, then
+/// resync the node so store-backed confirmation counts catch up and warn
+/// about positions nearing expiry. Polling is paused while the wallet is
+/// locked.
+async fn poll_chain_tip(app: AppHandle) {
+    let mut last_height = None;
+    // Expiry warnings already emitted, so each fires once per session.
+    let mut warned: HashSet<String> = HashSet::new();
+    loop {
+        // Re-read the interval each iteration so changes apply without a
+        // restart.
+        let (poll_secs, network) = match app.state::<Mutex<AppStateManager>>().lock() {
+            Ok(mgr) => (mgr.chain_tip_poll_secs(), mgr.network()),
+            Err(_) => (state::DEFAULT_CHAIN_TIP_POLL_SECS, None),
+        };
+        tokio::time::sleep(std::time::Duration::from_secs(poll_secs)).await;
+
+        let Some(network) = network else { continue };
+        let unlocked = {
+            let node_state = app.state::<NodeState>();
+            let guard = node_state.node.lock().await;
+            guard.as_ref().is_some_and(|node| node.is_wallet_unlocked())
+        };
+        if !unlocked {
+            continue;
+        }
+
+        let timeout = network_timeouts(&app).esplora();
+        let url = esplora_url(&app, network);
+        let tip = match fetch_chain_tip_inner(network.into(), &url, timeout).await {
+            Ok(tip) => tip,
+            Err(e) => {
+                log::debug!("chain tip poll failed: {e}");
+                continue;
+            }
+        };
+        if last_height == Some(tip.height) {
+            continue;
+        }
+        last_height = Some(tip.height);
+        let _ = app.emit(CHAIN_TIP_EVENT, &tip);
+
+        // Sync on a handle rather than under the guard, so commands aren't
+        // blocked for the length of the sync.
+        let node = {
+            let node_state = app.state::<NodeState>();
+            let guard = node_state.node.lock().await;
+            guard.clone()
+        };
+        let Some(node) = node else { continue };
+        if let Err(e) = node.sync().await {
+            log::warn!("sync after new block {} failed: {e}", tip.height);
+            continue;
+        }
+        drop(node);
+
+        let (snapshot, expiry_window) = {
+            let manager = app.state::<Mutex<AppStateManager>>();
+            match manager.lock() {
+                Ok(mut mgr) => {
+                    mgr.bump_revision();
+                    (Some(mgr.snapshot()), mgr.expiry_warning_blocks())
+                }
+                Err(_) => (None, 0),
+            }
+        };
+        if let Some(state) = snapshot {
+            emit_state(&app, &state);
+        }
+
+        emit_price_alerts(&app);
+        if expiry_window > 0 {
+            emit_expiry_warnings(&app, tip.height, expiry_window, &mut warned).await;
+        }
+    }
+}
+
+async fn emit_expiry_warnings(
+    app: &AppHandle,
+    tip_height: u32,
+    window: u32,
+    warned: &mut HashSet<String>,
+) {
+    let positions = match commands::expiring_positions(app, tip_height, window).await {
+        Ok(positions) => positions,
+        Err(e) => {
+            log::warn!("expiry check at height {tip_height} failed: {e}");
+            return;
+        }
+    };
+    for event in positions.markets {
+        if warned.insert(format!("market:{}", event.market_id)) {
+            let _ = app.emit(MARKET_EXPIRING_EVENT, &event);
+        }
+    }
+    for event in positions.orders {
+        if warned.insert(format!("order:{}", event.order_id)) {
+            let _ = app.emit(ORDER_EXPIRING_EVENT, &event);
+        }
+    }
+}
+
+fn emit_price_alerts(app: &AppHandle) {
+    match commands::crossed_price_alerts(app) {
+        Ok(events) => {
+            for event in events {
+                let _ = app.emit(PRICE_ALERT_EVENT, &event);
+            }
+        }
+        Err(e) => log::warn!("price alert check failed: {e}"),
+    }
+}
+
 // ============================================================================
 // App Entry Point
 // ============================================================================
@@ -1109,8 +2637,11 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(
+            // The plugin filters at Trace so `set_log_level` can raise
+            // verbosity at runtime; the effective level is the global max
+            // level, set to DEFAULT_LOG_LEVEL in setup.
             tauri_plugin_log::Builder::default()
-                .level(log::LevelFilter::Info)
+                .level(log::LevelFilter::Trace)
                 .level_for("rustls", log::LevelFilter::Warn)
                 .level_for("tungstenite", log::LevelFilter::Warn)
                 .level_for("tokio_tungstenite", log::LevelFilter::Warn)
@@ -1120,6 +2651,8 @@ pub fn run() {
                 .build(),
         )
         .setup(|app| {
+            log::set_max_level(DEFAULT_LOG_LEVEL);
+
             let app_data_dir = app
                 .path()
                 .app_data_dir()
@@ -1134,19 +2667,26 @@ pub fn run() {
                 manager.set_network(Network::Testnet);
             }
 
+            let nostr_state = manager
+                .network()
+                .map(NostrAppState::for_network)
+                .unwrap_or_default();
             app.manage(Mutex::new(manager));
             app.manage(NodeState::default());
-            app.manage(NostrAppState::default());
+            app.manage(nostr_state);
             app.manage(WalletStoreState::default());
 
             // Spawn auto-lock background timer
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
-                let interval_secs = std::cmp::max(AUTO_LOCK_TIMEOUT_SECS / 5, 10);
-                let mut interval =
-                    tokio::time::interval(std::time::Duration::from_secs(interval_secs));
                 loop {
-                    interval.tick().await;
+                    // Re-read the tick interval each iteration so timeout
+                    // changes take effect without restarting the timer.
+                    let tick_secs = match app_handle.state::<Mutex<AppStateManager>>().lock() {
+                        Ok(mgr) => mgr.auto_lock_tick_secs(),
+                        Err(_) => 10,
+                    };
+                    tokio::time::sleep(std::time::Duration::from_secs(tick_secs)).await;
 
                     // Check auto-lock: lock the node's wallet if timeout elapsed
                     let should_lock = {
@@ -1179,6 +2719,55 @@ pub fn run() {
                 }
             });
 
+            // Spawn chain-tip poller
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(poll_chain_tip(app_handle));
+
+            // Spawn store pruner
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    tokio::time::sleep(STORE_PRUNE_INTERVAL).await;
+                    match commands::prune_store_inner(
+                        &app_handle,
+                        commands::DEFAULT_PRUNE_RETAIN_BLOCKS,
+                        commands::DEFAULT_PRUNE_KEEP_SNAPSHOTS,
+                    )
+                    .await
+                    {
+                        Ok(report) => log::debug!(
+                            "store prune below height {}: {} spent utxos, {} price snapshots",
+                            report.before_height,
+                            report.spent_utxos,
+                            report.price_snapshots
+                        ),
+                        Err(e) => log::debug!("store prune skipped: {e}"),
+                    }
+                }
+            });
+
+            // Spawn announcement publish retrier
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    tokio::time::sleep(PUBLISH_RETRY_INTERVAL).await;
+                    match commands::retry_pending_publishes_inner(&app_handle).await {
+                        Ok(records) => {
+                            for record in records {
+                                log::info!(
+                                    "republished {}: {} after {} attempts ({} relays)",
+                                    record.event_id,
+                                    record.status,
+                                    record.attempts,
+                                    record.relays_accepted
+                                );
+                            }
+                        }
+                        Err(e) => log::debug!("publish retry skipped: {e}"),
+                    }
+                }
+            });
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -1190,28 +2779,62 @@ pub fn run() {
             // Wallet
             get_wallet_status,
             create_wallet,
+            list_wallets,
+            create_named_wallet,
+            switch_wallet,
             restore_wallet,
             unlock_wallet,
             lock_wallet,
             delete_wallet,
             sync_wallet,
+            cancel_operation,
             get_wallet_balance,
             get_wallet_address,
+            get_explicit_address,
             get_wallet_transactions,
             get_wallet_mnemonic,
             get_mnemonic_word_count,
             get_mnemonic_word,
+            verify_mnemonic_words,
+            change_wallet_password,
             send_lbtc,
+            split_utxo,
+            format_amount,
+            parse_amount,
             // Activity / auto-lock
             record_activity,
+            get_auto_lock_timeout,
+            set_auto_lock_timeout,
+            get_chain_tip_poll_interval,
+            set_chain_tip_poll_interval,
+            get_network_timeouts,
+            set_network_timeouts,
+            get_expiry_warning_window,
+            set_expiry_warning_window,
+            get_fee_coin_selection,
+            set_fee_coin_selection,
+            get_min_confirmations,
+            set_min_confirmations,
+            get_require_mainnet_confirmation,
+            set_require_mainnet_confirmation,
+            get_lock_on_background,
+            set_lock_on_background,
+            notify_app_backgrounded,
             // Payments (Boltz)
             pay_lightning_invoice,
             create_lightning_receive,
             create_bitcoin_receive,
             create_bitcoin_send,
             get_chain_swap_pairs,
+            set_boltz_url,
+            set_esplora_url,
+            set_log_level,
             list_payment_swaps,
             refresh_payment_swap_status,
+            get_stuck_swaps,
+            get_swap_receipt,
+            export_swap_receipt,
+            swap_time_remaining,
             // Legacy
             fetch_chain_tip,
             // SDK / Nostr
@@ -1222,42 +2845,105 @@ pub fn run() {
             commands::delete_nostr_identity,
             commands::import_nostr_nsec,
             commands::discover_contracts,
+            commands::get_market_by_event,
+            commands::get_market_share_link,
+            commands::get_order_share_link,
+            commands::get_pool_share_link,
             commands::publish_contract,
             commands::oracle_attest,
+            commands::fetch_market_attestation,
             commands::backup_mnemonic_to_nostr,
             commands::restore_mnemonic_from_nostr,
+            commands::backup_app_state_to_nostr,
+            commands::restore_app_state_from_nostr,
+            commands::recover_positions,
+            commands::get_transaction_detail,
             commands::check_nostr_backup,
+            commands::verify_nostr_backup,
             commands::delete_nostr_backup,
             commands::get_relay_list,
             commands::set_relay_list,
+            commands::reset_relays_to_default,
             commands::fetch_nip65_relay_list,
             commands::add_relay,
             commands::remove_relay,
+            commands::republish_event,
+            commands::list_pending_broadcast_intents,
+            commands::retry_broadcast_intent,
+            commands::discard_broadcast_intent,
+            commands::delete_announcement,
             commands::fetch_nostr_profile,
+            commands::fetch_creator_profile,
+            commands::fetch_creator_markets,
+            commands::fetch_creator_orders,
             commands::create_contract_onchain,
             commands::issue_tokens,
+            commands::preview_issuance,
             commands::cancel_tokens,
             commands::resolve_market,
+            commands::get_oracle_key,
+            commands::list_markets_to_resolve,
+            commands::publish_oracle_resolution,
             commands::redeem_tokens,
             commands::redeem_expired,
+            commands::redeem_expired_all,
+            commands::preview_redemption,
+            commands::bump_transaction_fee,
+            commands::sweep_private_key,
+            commands::consolidate_wallet,
             commands::get_market_state,
+            commands::get_market_addresses,
             commands::quote_trade,
+            commands::get_best_quote,
             commands::execute_trade,
             commands::get_wallet_utxos,
             commands::list_contracts,
+            commands::search_markets,
+            commands::list_market_categories,
+            commands::list_markets_by_category,
             commands::fetch_orders,
+            commands::discover_live_orders,
             commands::create_limit_order,
+            commands::validate_order_params,
             commands::cancel_limit_order,
+            commands::top_up_limit_order,
+            commands::reprice_limit_order,
+            commands::cancel_all_limit_orders,
+            commands::simulate_order_fill,
             commands::list_own_orders,
+            commands::create_price_alert,
+            commands::list_price_alerts,
+            commands::delete_price_alert,
+            commands::get_action_log,
+            commands::run_diagnostics,
+            commands::get_node_info,
+            commands::get_policy_asset,
+            #[cfg(feature = "regtest")]
+            commands::regtest_fund,
+            commands::get_store_schema_version,
+            commands::check_store_integrity,
+            commands::compact_store,
+            commands::rebuild_store,
+            commands::rescan_from_height,
+            commands::recover_market_issuance,
+            commands::prune_store,
             // LMSR Pools
             commands::generate_lmsr_table,
             commands::create_lmsr_pool,
+            commands::create_market_with_pool,
+            commands::preview_lmsr_pool_creation,
+            commands::quote_lmsr_pool_deposit,
+            commands::quote_lmsr_pool_withdrawal,
             commands::scan_lmsr_pool,
+            commands::verify_pool,
             commands::adjust_lmsr_pool,
             commands::close_lmsr_pool,
             commands::list_lmsr_pools,
             commands::get_price_history,
             commands::get_pool_price_history,
+            commands::get_market_detail,
+            commands::get_market_metrics,
+            commands::get_my_activity,
             // Wallet store (SDK)
             wallet_store::create_software_signer,
             wallet_store::create_wollet,
@@ -1,15 +1,22 @@
+use std::future::Future;
 use std::str::FromStr;
-use std::time::Duration;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
+use boltz_client::bitcoin::hashes::{sha256, Hash as _};
+use boltz_client::bitcoin::secp256k1::{Keypair, Secp256k1};
+use boltz_client::fees::Fee;
+use boltz_client::network::electrum::ElectrumLiquidClient;
 use boltz_client::network::{BitcoinChain, Chain as BoltzChain, LiquidChain};
 use boltz_client::swaps::boltz::{
-    BoltzApiClientV2, CreateChainRequest, CreateReverseRequest, CreateSubmarineRequest,
-    BOLTZ_MAINNET_URL_V2, BOLTZ_REGTEST, BOLTZ_TESTNET_URL_V2,
+    BoltzApiClientV2, ChainSwapDetails, CreateChainRequest, CreateReverseRequest,
+    CreateSubmarineRequest, Side, BOLTZ_MAINNET_URL_V2, BOLTZ_REGTEST, BOLTZ_TESTNET_URL_V2,
 };
 use boltz_client::util::secrets::Preimage as BoltzPreimage;
 use boltz_client::{bitcoin::PublicKey as BoltzPublicKey, Bolt11Invoice};
+use boltz_client::{LBtcSwapScript, LBtcSwapTx};
 use chrono::TimeZone;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::Network;
@@ -21,12 +28,102 @@ pub enum PaymentError {
 
     #[error("Network error: {0}")]
     Network(String),
+
+    #[error("Boltz service unavailable after {attempts} attempts: {last_error}")]
+    ServiceUnavailable { attempts: u32, last_error: String },
+
+    #[error("Amount out of range: must be between {min} and {max} sats")]
+    AmountOutOfRange { min: u64, max: u64 },
+}
+
+/// Per-request timeout when the config doesn't set one.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// Minimum spacing between Boltz API requests across the whole process.
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Earliest instant the next Boltz request may be sent.
+static NEXT_REQUEST_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// How long fetched pair limits are trusted for pre-flight amount checks.
+const PAIR_LIMITS_TTL: Duration = Duration::from_secs(60);
+
+/// Fee rate (sat/vB) of chain swap claim transactions on Liquid.
+const LIQUID_CLAIM_FEE_RATE: f64 = 0.1;
+
+/// Electrum timeout (seconds) when claiming a chain swap lockup.
+const CLAIM_ELECTRUM_TIMEOUT_SECS: u32 = 30;
+
+/// Recently seen pair limits, keyed by API URL and pair kind.
+static PAIR_LIMITS_CACHE: Mutex<Vec<CachedPairLimits>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PairKind {
+    Submarine,
+    Reverse,
+    ChainBtcToLbtc,
+    ChainLbtcToBtc,
+}
+
+struct CachedPairLimits {
+    api_url: String,
+    kind: PairKind,
+    min: u64,
+    max: u64,
+    fetched_at: Instant,
+}
+
+/// Exponential-backoff policy for read-only Boltz API calls.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(4),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Single attempt, no retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Optional overrides for [`BoltzService::new`].
+#[derive(Debug, Clone, Default)]
+pub struct BoltzServiceConfig {
+    /// Use this API URL instead of the network default.
+    pub api_url: Option<String>,
+    pub retry_policy: RetryPolicy,
+    /// Per-request timeout instead of `DEFAULT_REQUEST_TIMEOUT`.
+    pub timeout: Option<Duration>,
 }
 
 pub struct BoltzService {
     client: BoltzApiClientV2,
     network: Network,
     boltz_api_url: String,
+    retry_policy: RetryPolicy,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -36,7 +133,7 @@ pub struct BoltzSubmarineSwapCreated {
     pub flow: String,
     pub network: String,
     pub boltz_api_url: String,
-    pub status: String,
+    pub status: SwapStatus,
     pub invoice_amount_sat: u64,
     pub expected_amount_sat: u64,
     pub lockup_address: String,
@@ -54,7 +151,7 @@ pub struct BoltzLightningReceiveCreated {
     pub flow: String,
     pub network: String,
     pub boltz_api_url: String,
-    pub status: String,
+    pub status: SwapStatus,
     pub invoice_amount_sat: u64,
     pub expected_onchain_amount_sat: u64,
     pub lockup_address: String,
@@ -64,6 +161,9 @@ pub struct BoltzLightningReceiveCreated {
     pub invoice_expiry_seconds: u64,
     pub invoice_expires_at: String,
     pub preimage_hash: String,
+    /// Hex preimage, kept for the swap receipt; not sent to the frontend.
+    #[serde(skip)]
+    pub preimage: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -73,7 +173,7 @@ pub struct BoltzChainSwapCreated {
     pub flow: String,
     pub network: String,
     pub boltz_api_url: String,
-    pub status: String,
+    pub status: SwapStatus,
     pub amount_sat: u64,
     pub expected_amount_sat: u64,
     pub lockup_address: String,
@@ -83,6 +183,13 @@ pub struct BoltzChainSwapCreated {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bip21: Option<String>,
     pub preimage_hash: String,
+    /// See [`BoltzLightningReceiveCreated::preimage`].
+    #[serde(skip)]
+    pub preimage: Option<String>,
+    /// JSON of the lockup the wallet claims, for
+    /// [`BoltzService::claim_chain_swap`]; not sent to the frontend.
+    #[serde(skip)]
+    pub claim_details: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -109,19 +216,93 @@ pub struct BoltzChainSwapPairsInfo {
 #[serde(rename_all = "camelCase")]
 pub struct BoltzSwapStatusResponse {
     pub id: String,
-    pub status: String,
+    pub status: SwapStatus,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lockup_txid: Option<String>,
 }
 
 impl BoltzService {
-    pub fn new(network: Network, boltz_api_url_override: Option<String>) -> Self {
-        let boltz_api_url = boltz_api_url_override.unwrap_or_else(|| default_api_url(network));
-        let client = BoltzApiClientV2::new(boltz_api_url.clone(), Some(Duration::from_secs(8)));
+    pub fn new(network: Network, config: BoltzServiceConfig) -> Self {
+        let boltz_api_url = config.api_url.unwrap_or_else(|| default_api_url(network));
+        let timeout = config.timeout.unwrap_or(DEFAULT_REQUEST_TIMEOUT);
+        let client = BoltzApiClientV2::new(boltz_api_url.clone(), Some(timeout));
         Self {
             client,
             network,
             boltz_api_url,
+            retry_policy: config.retry_policy,
+        }
+    }
+
+    /// Reject `amount_sat` early using recently cached limits, before any
+    /// network round-trip.
+    fn precheck_amount(&self, kind: PairKind, amount_sat: u64) -> Result<(), PaymentError> {
+        let cache = PAIR_LIMITS_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        match cache.iter().find(|entry| {
+            entry.kind == kind
+                && entry.api_url == self.boltz_api_url
+                && entry.fetched_at.elapsed() < PAIR_LIMITS_TTL
+        }) {
+            Some(entry) => check_amount_in_range(amount_sat, entry.min, entry.max),
+            None => Ok(()),
+        }
+    }
+
+    /// Record freshly fetched limits and validate `amount_sat` against them.
+    fn validate_amount(
+        &self,
+        kind: PairKind,
+        amount_sat: u64,
+        min: u64,
+        max: u64,
+    ) -> Result<(), PaymentError> {
+        self.remember_limits(kind, min, max);
+        check_amount_in_range(amount_sat, min, max)
+    }
+
+    fn remember_limits(&self, kind: PairKind, min: u64, max: u64) {
+        let mut cache = PAIR_LIMITS_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        cache.retain(|entry| !(entry.kind == kind && entry.api_url == self.boltz_api_url));
+        cache.push(CachedPairLimits {
+            api_url: self.boltz_api_url.clone(),
+            kind,
+            min,
+            max,
+            fetched_at: Instant::now(),
+        });
+    }
+
+    /// Run a read-only Boltz request through the shared rate limiter,
+    /// retrying transient failures (429/5xx/timeouts) with backoff.
+    ///
+    /// Swap-creating POSTs are deliberately not routed through here: retrying
+    /// them after an ambiguous failure could open a duplicate swap.
+    async fn with_retry<T, F, Fut>(&self, op: &str, mut request: F) -> Result<T, PaymentError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, boltz_client::error::Error>>,
+    {
+        let max_attempts = self.retry_policy.max_attempts.max(1);
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            throttle().await;
+            let err = match request().await {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+            if !is_transient_error(&err) {
+                return Err(map_boltz_err(err));
+            }
+            let message = err.to_string();
+            if attempt >= max_attempts {
+                return Err(PaymentError::ServiceUnavailable {
+                    attempts: attempt,
+                    last_error: message,
+                });
+            }
+            log::warn!("Boltz {op} failed (attempt {attempt}/{max_attempts}): {message}");
+            tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
         }
     }
 
@@ -132,21 +313,25 @@ impl BoltzService {
     ) -> Result<BoltzSubmarineSwapCreated, PaymentError> {
         let amount_sat = parse_invoice_amount_sat(invoice)?;
         let (invoice_expiry_seconds, invoice_expires_at) = parse_invoice_expiry(invoice)?;
+        self.precheck_amount(PairKind::Submarine, amount_sat)?;
         let refund_public_key = BoltzPublicKey::from_str(refund_pubkey_hex).map_err(|e| {
             PaymentError::InvalidParameters(format!("Invalid refund public key: {}", e))
         })?;
 
         let pairs = self
-            .client
-            .get_submarine_pairs()
-            .await
-            .map_err(map_boltz_err)?;
+            .with_retry("get_submarine_pairs", || self.client.get_submarine_pairs())
+            .await?;
         let pair = pairs.get_lbtc_to_btc_pair().ok_or_else(|| {
             PaymentError::Network(
                 "Boltz did not return an L-BTC -> BTC submarine pair for this network".to_string(),
             )
         })?;
-        pair.limits.within(amount_sat).map_err(map_boltz_err)?;
+        self.validate_amount(
+            PairKind::Submarine,
+            amount_sat,
+            pair.limits.minimal,
+            pair.limits.maximal,
+        )?;
         let pair_hash = pair.hash.clone();
 
         let req = CreateSubmarineRequest {
@@ -159,6 +344,7 @@ impl BoltzService {
             webhook: None,
         };
 
+        throttle().await;
         let response = self
             .client
             .post_swap_req(&req)
@@ -177,7 +363,7 @@ impl BoltzService {
             flow: "liquid_to_lightning".to_string(),
             network: self.network.as_str().to_string(),
             boltz_api_url: self.boltz_api_url.clone(),
-            status: "swap.created".to_string(),
+            status: SwapStatus::Created,
             invoice_amount_sat: amount_sat,
             expected_amount_sat: response.expected_amount,
             lockup_address: response.address,
@@ -203,19 +389,23 @@ impl BoltzService {
         let claim_public_key = BoltzPublicKey::from_str(claim_pubkey_hex).map_err(|e| {
             PaymentError::InvalidParameters(format!("Invalid claim public key: {}", e))
         })?;
+        self.precheck_amount(PairKind::Reverse, amount_sat)?;
         let preimage = BoltzPreimage::new();
 
         let pairs = self
-            .client
-            .get_reverse_pairs()
-            .await
-            .map_err(map_boltz_err)?;
+            .with_retry("get_reverse_pairs", || self.client.get_reverse_pairs())
+            .await?;
         let pair = pairs.get_btc_to_lbtc_pair().ok_or_else(|| {
             PaymentError::Network(
                 "Boltz did not return a BTC -> L-BTC reverse pair for this network".to_string(),
             )
         })?;
-        pair.limits.within(amount_sat).map_err(map_boltz_err)?;
+        self.validate_amount(
+            PairKind::Reverse,
+            amount_sat,
+            pair.limits.minimal,
+            pair.limits.maximal,
+        )?;
         let pair_hash = pair.hash.clone();
 
         let req = CreateReverseRequest {
@@ -232,6 +422,7 @@ impl BoltzService {
             referral_id: None,
             webhook: None,
         };
+        throttle().await;
         let response = self
             .client
             .post_reverse_req(req)
@@ -255,7 +446,7 @@ impl BoltzService {
             flow: "lightning_to_liquid".to_string(),
             network: self.network.as_str().to_string(),
             boltz_api_url: self.boltz_api_url.clone(),
-            status: "swap.created".to_string(),
+            status: SwapStatus::Created,
             invoice_amount_sat: amount_sat,
             expected_onchain_amount_sat: response.onchain_amount,
             lockup_address: response.lockup_address,
@@ -265,6 +456,7 @@ impl BoltzService {
             invoice_expiry_seconds,
             invoice_expires_at,
             preimage_hash: preimage.sha256.to_string(),
+            preimage: preimage.bytes.map(hex::encode),
         })
     }
 
@@ -286,14 +478,23 @@ impl BoltzService {
         let refund_public_key = BoltzPublicKey::from_str(refund_pubkey_hex).map_err(|e| {
             PaymentError::InvalidParameters(format!("Invalid refund public key: {}", e))
         })?;
+        self.precheck_amount(PairKind::ChainBtcToLbtc, amount_sat)?;
         let preimage = BoltzPreimage::new();
 
-        let pairs = self.client.get_chain_pairs().await.map_err(map_boltz_err)?;
+        let pairs = self
+            .with_retry("get_chain_pairs", || self.client.get_chain_pairs())
+            .await?;
         let pair = pairs.get_btc_to_lbtc_pair().ok_or_else(|| {
             PaymentError::Network(
                 "Boltz did not return a BTC -> L-BTC chain pair for this network".to_string(),
             )
         })?;
+        self.validate_amount(
+            PairKind::ChainBtcToLbtc,
+            amount_sat,
+            pair.limits.minimal,
+            pair.limits.maximal,
+        )?;
         let pair_hash = pair.hash.clone();
 
         let req = CreateChainRequest {
@@ -309,6 +510,7 @@ impl BoltzService {
             webhook: None,
         };
 
+        throttle().await;
         let response = self
             .client
             .post_chain_req(req)
@@ -328,7 +530,7 @@ impl BoltzService {
             flow: "bitcoin_to_liquid".to_string(),
             network: self.network.as_str().to_string(),
             boltz_api_url: self.boltz_api_url.clone(),
-            status: "swap.created".to_string(),
+            status: SwapStatus::Created,
             amount_sat,
             expected_amount_sat: response.claim_details.amount,
             lockup_address: response.lockup_details.lockup_address,
@@ -337,6 +539,8 @@ impl BoltzService {
             pair_hash,
             bip21: response.lockup_details.bip21,
             preimage_hash: preimage.sha256.to_string(),
+            preimage: preimage.bytes.map(hex::encode),
+            claim_details: serde_json::to_string(&response.claim_details).ok(),
         })
     }
 
@@ -358,14 +562,23 @@ impl BoltzService {
         let refund_public_key = BoltzPublicKey::from_str(refund_pubkey_hex).map_err(|e| {
             PaymentError::InvalidParameters(format!("Invalid refund public key: {}", e))
         })?;
+        self.precheck_amount(PairKind::ChainLbtcToBtc, amount_sat)?;
         let preimage = BoltzPreimage::new();
 
-        let pairs = self.client.get_chain_pairs().await.map_err(map_boltz_err)?;
+        let pairs = self
+            .with_retry("get_chain_pairs", || self.client.get_chain_pairs())
+            .await?;
         let pair = pairs.get_lbtc_to_btc_pair().ok_or_else(|| {
             PaymentError::Network(
                 "Boltz did not return an L-BTC -> BTC chain pair for this network".to_string(),
             )
         })?;
+        self.validate_amount(
+            PairKind::ChainLbtcToBtc,
+            amount_sat,
+            pair.limits.minimal,
+            pair.limits.maximal,
+        )?;
         let pair_hash = pair.hash.clone();
 
         let req = CreateChainRequest {
@@ -381,6 +594,7 @@ impl BoltzService {
             webhook: None,
         };
 
+        throttle().await;
         let response = self
             .client
             .post_chain_req(req)
@@ -400,7 +614,7 @@ impl BoltzService {
             flow: "liquid_to_bitcoin".to_string(),
             network: self.network.as_str().to_string(),
             boltz_api_url: self.boltz_api_url.clone(),
-            status: "swap.created".to_string(),
+            status: SwapStatus::Created,
             amount_sat,
             expected_amount_sat: response.claim_details.amount,
             lockup_address: response.lockup_details.lockup_address,
@@ -409,20 +623,101 @@ impl BoltzService {
             pair_hash,
             bip21: response.lockup_details.bip21,
             preimage_hash: preimage.sha256.to_string(),
+            preimage: preimage.bytes.map(hex::encode),
+            claim_details: serde_json::to_string(&response.claim_details).ok(),
         })
     }
 
     pub async fn get_swap_status(&self, id: &str) -> Result<BoltzSwapStatusResponse, PaymentError> {
-        let swap = self.client.get_swap(id).await.map_err(map_boltz_err)?;
+        let swap = self
+            .with_retry("get_swap", || self.client.get_swap(id))
+            .await?;
         Ok(BoltzSwapStatusResponse {
             id: id.to_string(),
-            status: swap.status,
+            status: SwapStatus::parse(&swap.status),
             lockup_txid: swap.transaction.map(|tx| tx.id),
         })
     }
 
+    /// Preimage Boltz learned by paying a submarine swap's invoice.
+    pub async fn get_submarine_preimage(&self, id: &str) -> Result<String, PaymentError> {
+        let id = id.to_string();
+        let details = self
+            .with_retry("get_submarine_claim_tx_details", || {
+                self.client.get_submarine_claim_tx_details(&id)
+            })
+            .await?;
+        Ok(details.preimage)
+    }
+
+    /// Claim the L-BTC Boltz locked for a Bitcoin-to-Liquid chain swap into
+    /// `claim_address` and return the claim txid.
+    ///
+    /// Spends the script path with the preimage, so Boltz's cooperation is
+    /// not needed. `claim_details` is the JSON kept from swap creation.
+    pub async fn claim_chain_swap(
+        &self,
+        swap_id: &str,
+        claim_details: &str,
+        claim_secret_key: &[u8; 32],
+        preimage_hex: &str,
+        claim_address: &str,
+        electrum_url: &str,
+    ) -> Result<String, PaymentError> {
+        let details: ChainSwapDetails = serde_json::from_str(claim_details).map_err(|e| {
+            PaymentError::InvalidParameters(format!("Invalid chain swap claim details: {e}"))
+        })?;
+        let keypair = Keypair::from_seckey_slice(&Secp256k1::new(), claim_secret_key)
+            .map_err(|e| PaymentError::InvalidParameters(format!("Invalid claim key: {e}")))?;
+        let preimage = BoltzPreimage::from_str(preimage_hex)
+            .map_err(|e| PaymentError::InvalidParameters(format!("Invalid preimage: {e}")))?;
+        let script = LBtcSwapScript::chain_from_swap_resp(
+            Side::Claim,
+            details,
+            BoltzPublicKey::new(keypair.public_key()),
+        )
+        .map_err(map_boltz_err)?;
+
+        let (host, tls) = electrum_endpoint(electrum_url);
+        let chain_client = ElectrumLiquidClient::new(
+            liquid_chain(self.network),
+            host,
+            tls,
+            tls,
+            CLAIM_ELECTRUM_TIMEOUT_SECS,
+        )
+        .map_err(map_boltz_err)?;
+
+        throttle().await;
+        let claim_tx = LBtcSwapTx::new_claim(
+            script,
+            claim_address.to_string(),
+            &chain_client,
+            &self.client,
+            swap_id.to_string(),
+        )
+        .await
+        .map_err(map_boltz_err)?;
+        let signed = claim_tx
+            .sign_claim(
+                &keypair,
+                &preimage,
+                Fee::Relative(LIQUID_CLAIM_FEE_RATE),
+                None,
+                true,
+            )
+            .await
+            .map_err(map_boltz_err)?;
+        claim_tx
+            .broadcast(&signed, &chain_client, None)
+            .await
+            .map_err(map_boltz_err)
+    }
+
     pub async fn get_chain_swap_pairs_info(&self) -> Result<BoltzChainSwapPairsInfo, PaymentError> {
-        let pairs = self.client.get_chain_pairs().await.map_err(map_boltz_err)?;
+        let pairs = self
+            .with_retry("get_chain_pairs", || self.client.get_chain_pairs())
+            .await?;
 
         let btc_to_lbtc = pairs.get_btc_to_lbtc_pair().ok_or_else(|| {
             PaymentError::Network(
@@ -435,6 +730,17 @@ impl BoltzService {
             )
         })?;
 
+        self.remember_limits(
+            PairKind::ChainBtcToLbtc,
+            btc_to_lbtc.limits.minimal,
+            btc_to_lbtc.limits.maximal,
+        );
+        self.remember_limits(
+            PairKind::ChainLbtcToBtc,
+            lbtc_to_btc.limits.minimal,
+            lbtc_to_btc.limits.maximal,
+        );
+
         Ok(BoltzChainSwapPairsInfo {
             bitcoin_to_liquid: map_chain_pair_info(&btc_to_lbtc),
             liquid_to_bitcoin: map_chain_pair_info(&lbtc_to_btc),
@@ -442,6 +748,204 @@ impl BoltzService {
     }
 }
 
+/// A Boltz swap status. (De)serialized as the Boltz status string, so
+/// stored swaps and the frontend see the same values as before; statuses
+/// this version doesn't know are kept verbatim in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum SwapStatus {
+    Created,
+    InvoiceSet,
+    InvoicePending,
+    InvoicePaid,
+    InvoiceSettled,
+    InvoiceExpired,
+    InvoiceFailedToPay,
+    /// The user's lockup (or Boltz's, for reverse swaps) is in the mempool.
+    LockupMempool,
+    LockupConfirmed,
+    /// Boltz's lockup of a chain swap is in the mempool.
+    ServerLockupMempool,
+    ServerLockupConfirmed,
+    ClaimPending,
+    Claimed,
+    Refunded,
+    Failed,
+    LockupFailed,
+    Expired,
+    Other(String),
+}
+
+impl SwapStatus {
+    const KNOWN: [SwapStatus; 17] = [
+        SwapStatus::Created,
+        SwapStatus::InvoiceSet,
+        SwapStatus::InvoicePending,
+        SwapStatus::InvoicePaid,
+        SwapStatus::InvoiceSettled,
+        SwapStatus::InvoiceExpired,
+        SwapStatus::InvoiceFailedToPay,
+        SwapStatus::LockupMempool,
+        SwapStatus::LockupConfirmed,
+        SwapStatus::ServerLockupMempool,
+        SwapStatus::ServerLockupConfirmed,
+        SwapStatus::ClaimPending,
+        SwapStatus::Claimed,
+        SwapStatus::Refunded,
+        SwapStatus::Failed,
+        SwapStatus::LockupFailed,
+        SwapStatus::Expired,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            SwapStatus::Created => "swap.created",
+            SwapStatus::InvoiceSet => "invoice.set",
+            SwapStatus::InvoicePending => "invoice.pending",
+            SwapStatus::InvoicePaid => "invoice.paid",
+            SwapStatus::InvoiceSettled => "invoice.settled",
+            SwapStatus::InvoiceExpired => "invoice.expired",
+            SwapStatus::InvoiceFailedToPay => "invoice.failedToPay",
+            SwapStatus::LockupMempool => "transaction.mempool",
+            SwapStatus::LockupConfirmed => "transaction.confirmed",
+            SwapStatus::ServerLockupMempool => "transaction.server.mempool",
+            SwapStatus::ServerLockupConfirmed => "transaction.server.confirmed",
+            SwapStatus::ClaimPending => "transaction.claim.pending",
+            SwapStatus::Claimed => "transaction.claimed",
+            SwapStatus::Refunded => "transaction.refunded",
+            SwapStatus::Failed => "transaction.failed",
+            SwapStatus::LockupFailed => "transaction.lockupFailed",
+            SwapStatus::Expired => "swap.expired",
+            SwapStatus::Other(status) => status,
+        }
+    }
+
+    pub fn parse(status: &str) -> Self {
+        Self::KNOWN
+            .into_iter()
+            .find(|known| known.as_str() == status)
+            .unwrap_or_else(|| SwapStatus::Other(status.to_string()))
+    }
+
+    /// Whether the swap needs no further action from the wallet.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            SwapStatus::Claimed
+                | SwapStatus::Refunded
+                | SwapStatus::Failed
+                | SwapStatus::LockupFailed
+                | SwapStatus::InvoiceSettled
+                | SwapStatus::InvoiceExpired
+                | SwapStatus::InvoiceFailedToPay
+                | SwapStatus::Expired
+        )
+    }
+
+    /// Whether the swap finished with the payment delivered.
+    pub fn is_success(&self) -> bool {
+        matches!(self, SwapStatus::Claimed | SwapStatus::InvoiceSettled)
+    }
+}
+
+impl From<String> for SwapStatus {
+    fn from(status: String) -> Self {
+        SwapStatus::parse(&status)
+    }
+}
+
+impl From<SwapStatus> for String {
+    fn from(status: SwapStatus) -> Self {
+        match status {
+            SwapStatus::Other(status) => status,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for SwapStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Whether a chain swap has reached the state where the wallet must claim
+/// the server's lockup (Boltz has locked funds and they are confirmed).
+///
+/// Only Bitcoin-to-Liquid swaps qualify: Liquid-to-Bitcoin swaps pay out on
+/// Bitcoin, where the wallet has no address to claim into.
+pub fn is_chain_swap_claimable(flow: &str, status: &SwapStatus) -> bool {
+    flow == "bitcoin_to_liquid" && *status == SwapStatus::ServerLockupConfirmed
+}
+
+/// Whether a submarine swap's invoice has been paid, so Boltz can reveal
+/// the preimage.
+pub fn submarine_preimage_available(flow: &str, status: &SwapStatus) -> bool {
+    flow == "liquid_to_lightning"
+        && matches!(
+            status,
+            SwapStatus::InvoicePaid | SwapStatus::ClaimPending | SwapStatus::Claimed
+        )
+}
+
+/// Whether `preimage_hex` is the SHA-256 preimage of `hash_hex`.
+pub fn preimage_matches(preimage_hex: &str, hash_hex: &str) -> bool {
+    let Ok(preimage) = hex::decode(preimage_hex) else {
+        return false;
+    };
+    sha256::Hash::hash(&preimage).to_string() == hash_hex.to_ascii_lowercase()
+}
+
+/// Swaps whose status has not changed for this long are reported by
+/// `get_stuck_swaps`.
+pub const STUCK_SWAP_AFTER_SECS: i64 = 30 * 60;
+
+/// What the user should do about a swap that stopped making progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwapRecoveryAction {
+    /// Boltz's lockup is confirmed and the wallet can claim it.
+    Claim,
+    /// The user's Liquid lockup timed out; it can be refunded.
+    Refund,
+    /// Nothing to do yet (or the decision needs data we don't have).
+    Wait,
+}
+
+/// Whether `lockup_address` of a swap in `flow` is a Liquid address.
+/// Bitcoin-to-Liquid swaps lock the user's funds on Bitcoin.
+pub fn swap_lockup_on_liquid(flow: &str) -> bool {
+    flow != "bitcoin_to_liquid"
+}
+
+/// Recommend a recovery action for a non-terminal swap.
+///
+/// `lockup_funded` is whether the Liquid lockup still holds a UTXO, or
+/// `None` when it could not be checked. An empty lockup means there is
+/// nothing left to claim or refund. A timed-out lockup of the user's own
+/// funds is refunded whatever the server has done since; claims are only
+/// recommended for flows the wallet can claim.
+pub fn swap_recovery_action(
+    flow: &str,
+    status: &SwapStatus,
+    claimed: bool,
+    timeout_block_height: Option<u64>,
+    tip_height: u32,
+    lockup_funded: Option<bool>,
+) -> SwapRecoveryAction {
+    if claimed || lockup_funded == Some(false) {
+        return SwapRecoveryAction::Wait;
+    }
+    let timed_out = timeout_block_height.is_some_and(|t| u64::from(tip_height) >= t);
+    if timed_out && matches!(flow, "liquid_to_lightning" | "liquid_to_bitcoin") {
+        return SwapRecoveryAction::Refund;
+    }
+    if is_chain_swap_claimable(flow, status) {
+        return SwapRecoveryAction::Claim;
+    }
+    SwapRecoveryAction::Wait
+}
+
 fn default_api_url(network: Network) -> String {
     match network {
         Network::Mainnet => BOLTZ_MAINNET_URL_V2.to_string(),
@@ -450,13 +954,26 @@ fn default_api_url(network: Network) -> String {
     }
 }
 
-fn boltz_liquid_chain(network: Network) -> BoltzChain {
-    let chain = match network {
+fn liquid_chain(network: Network) -> LiquidChain {
+    match network {
         Network::Mainnet => LiquidChain::Liquid,
         Network::Testnet => LiquidChain::LiquidTestnet,
         Network::Regtest => LiquidChain::LiquidRegtest,
-    };
-    chain.into()
+    }
+}
+
+fn boltz_liquid_chain(network: Network) -> BoltzChain {
+    liquid_chain(network).into()
+}
+
+/// Split an Electrum URL (`ssl://host:port`, `tcp://host:port` or bare
+/// `host:port`) into the address and whether it uses TLS.
+fn electrum_endpoint(url: &str) -> (&str, bool) {
+    if let Some(address) = url.strip_prefix("ssl://") {
+        (address, true)
+    } else {
+        (url.strip_prefix("tcp://").unwrap_or(url), false)
+    }
 }
 
 fn boltz_bitcoin_chain(network: Network) -> BoltzChain {
@@ -484,6 +1001,51 @@ fn map_chain_pair_info(pair: &boltz_client::swaps::boltz::ChainPair) -> BoltzCha
     }
 }
 
+fn check_amount_in_range(amount_sat: u64, min: u64, max: u64) -> Result<(), PaymentError> {
+    if amount_sat < min || amount_sat > max {
+        return Err(PaymentError::AmountOutOfRange { min, max });
+    }
+    Ok(())
+}
+
+/// Wait until the shared rate limiter allows another request.
+async fn throttle() {
+    let wait = {
+        let mut next = NEXT_REQUEST_AT.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let slot = next.map_or(now, |at| at.max(now));
+        *next = Some(slot + MIN_REQUEST_INTERVAL);
+        slot - now
+    };
+    if !wait.is_zero() {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Whether a Boltz client error is a transient server or transport failure
+/// worth retrying: a rate limit or gateway status, a timeout, or a failed
+/// connection.
+fn is_transient_error(err: &boltz_client::error::Error) -> bool {
+    match err {
+        boltz_client::error::Error::HTTPStatusNotSuccess(status, _) => {
+            is_transient_status(status.as_u16())
+        }
+        boltz_client::error::Error::Reqwest(err) => {
+            err.is_timeout()
+                || err.is_connect()
+                || err
+                    .status()
+                    .is_some_and(|status| is_transient_status(status.as_u16()))
+        }
+        _ => false,
+    }
+}
+
+/// 429 and the 5xx statuses a busy or restarting server answers with.
+fn is_transient_status(status: u16) -> bool {
+    matches!(status, 429 | 500 | 502 | 503 | 504)
+}
+
 fn map_boltz_err(err: boltz_client::error::Error) -> PaymentError {
     PaymentError::Network(format!("Boltz API error: {}", err))
 }
@@ -499,6 +1061,13 @@ fn parse_invoice_amount_sat(invoice: &str) -> Result<u64, PaymentError> {
     Ok(amount_msat.div_ceil(1_000))
 }
 
+/// Payment hash of a BOLT11 invoice, hex encoded.
+pub fn invoice_payment_hash(invoice: &str) -> Option<String> {
+    Bolt11Invoice::from_str(invoice)
+        .ok()
+        .map(|invoice| invoice.payment_hash().to_string())
+}
+
 fn parse_invoice_expiry(invoice: &str) -> Result<(u64, String), PaymentError> {
     let invoice = Bolt11Invoice::from_str(invoice)
         .map_err(|e| PaymentError::InvalidParameters(format!("Invalid BOLT11 invoice: {}", e)))?;
@@ -517,3 +1086,243 @@ fn parse_invoice_expiry(invoice: &str) -> Result<(u64, String), PaymentError> {
         .to_rfc3339();
     Ok((expiry_seconds, expires_at_rfc3339))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(2), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(10), Duration::from_secs(4));
+    }
+
+    #[test]
+    fn chain_swaps_are_claimable_once_server_lockup_confirms() {
+        assert!(is_chain_swap_claimable(
+            "bitcoin_to_liquid",
+            &SwapStatus::ServerLockupConfirmed
+        ));
+        assert!(!is_chain_swap_claimable(
+            "bitcoin_to_liquid",
+            &SwapStatus::ServerLockupMempool
+        ));
+        assert!(!is_chain_swap_claimable(
+            "lightning_to_liquid",
+            &SwapStatus::ServerLockupConfirmed
+        ));
+        assert!(!is_chain_swap_claimable(
+            "liquid_to_bitcoin",
+            &SwapStatus::ServerLockupConfirmed
+        ));
+        assert!(SwapStatus::Claimed.is_terminal());
+        assert!(!SwapStatus::ServerLockupConfirmed.is_terminal());
+        assert!(SwapStatus::InvoiceSettled.is_success());
+        assert!(SwapStatus::Refunded.is_terminal() && !SwapStatus::Refunded.is_success());
+    }
+
+    #[test]
+    fn electrum_urls_split_into_address_and_tls() {
+        assert_eq!(
+            electrum_endpoint("ssl://blockstream.info:995"),
+            ("blockstream.info:995", true)
+        );
+        assert_eq!(
+            electrum_endpoint("tcp://localhost:50001"),
+            ("localhost:50001", false)
+        );
+        assert_eq!(
+            electrum_endpoint("localhost:50001"),
+            ("localhost:50001", false)
+        );
+    }
+
+    #[test]
+    fn swap_status_round_trips_boltz_strings() {
+        for status in SwapStatus::KNOWN {
+            assert_eq!(SwapStatus::parse(status.as_str()), status);
+        }
+        assert_eq!(
+            SwapStatus::parse("invoice.failedToPay"),
+            SwapStatus::InvoiceFailedToPay
+        );
+        let unknown = SwapStatus::parse("transaction.direct");
+        assert_eq!(unknown, SwapStatus::Other("transaction.direct".to_string()));
+        assert!(!unknown.is_terminal());
+
+        let json = serde_json::to_string(&SwapStatus::ServerLockupConfirmed).unwrap();
+        assert_eq!(json, "\"transaction.server.confirmed\"");
+        let parsed: SwapStatus = serde_json::from_str("\"transaction.direct\"").unwrap();
+        assert_eq!(parsed, unknown);
+    }
+
+    #[test]
+    fn submarine_preimage_is_checked_against_payment_hash() {
+        let zeros = "00".repeat(32);
+        let hash = "66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925";
+        assert!(preimage_matches(&zeros, hash));
+        assert!(preimage_matches(&zeros, &hash.to_uppercase()));
+        assert!(!preimage_matches(&"01".repeat(32), hash));
+        assert!(!preimage_matches("not hex", hash));
+        assert!(submarine_preimage_available(
+            "liquid_to_lightning",
+            &SwapStatus::Claimed
+        ));
+        assert!(!submarine_preimage_available(
+            "liquid_to_lightning",
+            &SwapStatus::LockupMempool
+        ));
+        assert!(!submarine_preimage_available(
+            "lightning_to_liquid",
+            &SwapStatus::InvoicePaid
+        ));
+    }
+
+    #[test]
+    fn stuck_swap_recovery_actions() {
+        use SwapRecoveryAction::*;
+        let action = |flow: &str, status: &SwapStatus, timeout, funded| {
+            swap_recovery_action(flow, status, false, timeout, 1_000, funded)
+        };
+        // No reverse-swap claim path exists, so there is nothing to offer.
+        assert_eq!(
+            action(
+                "lightning_to_liquid",
+                &SwapStatus::LockupConfirmed,
+                None,
+                None
+            ),
+            Wait
+        );
+        assert_eq!(
+            action(
+                "liquid_to_bitcoin",
+                &SwapStatus::ServerLockupConfirmed,
+                Some(1_001),
+                Some(true)
+            ),
+            Wait
+        );
+        // A timed-out user lockup is refunded even after Boltz locked up.
+        assert_eq!(
+            action(
+                "liquid_to_bitcoin",
+                &SwapStatus::ServerLockupConfirmed,
+                Some(1_000),
+                Some(true)
+            ),
+            Refund
+        );
+        assert_eq!(
+            action(
+                "bitcoin_to_liquid",
+                &SwapStatus::ServerLockupConfirmed,
+                None,
+                None
+            ),
+            Claim
+        );
+        assert_eq!(
+            action(
+                "liquid_to_lightning",
+                &SwapStatus::LockupMempool,
+                Some(1_000),
+                Some(true)
+            ),
+            Refund
+        );
+        assert_eq!(
+            action(
+                "liquid_to_lightning",
+                &SwapStatus::LockupMempool,
+                Some(1_001),
+                Some(true)
+            ),
+            Wait
+        );
+        // Empty lockup: already spent or never funded.
+        assert_eq!(
+            action(
+                "liquid_to_bitcoin",
+                &SwapStatus::LockupMempool,
+                Some(900),
+                Some(false)
+            ),
+            Wait
+        );
+        // Bitcoin lockups time out on Bitcoin heights, which we don't track.
+        assert_eq!(
+            action(
+                "bitcoin_to_liquid",
+                &SwapStatus::LockupMempool,
+                Some(900),
+                None
+            ),
+            Wait
+        );
+        assert_eq!(
+            swap_recovery_action(
+                "lightning_to_liquid",
+                &SwapStatus::LockupConfirmed,
+                true,
+                None,
+                1_000,
+                None
+            ),
+            Wait
+        );
+    }
+
+    #[test]
+    fn amounts_outside_pair_limits_are_rejected() {
+        assert!(check_amount_in_range(25_000, 25_000, 1_000_000).is_ok());
+        assert!(check_amount_in_range(1_000_000, 25_000, 1_000_000).is_ok());
+        assert!(matches!(
+            check_amount_in_range(24_999, 25_000, 1_000_000),
+            Err(PaymentError::AmountOutOfRange {
+                min: 25_000,
+                max: 1_000_000
+            })
+        ));
+        assert!(check_amount_in_range(1_000_001, 25_000, 1_000_000).is_err());
+    }
+
+    #[test]
+    fn cached_limits_reject_before_fetching() {
+        let service = BoltzService::new(
+            Network::Regtest,
+            BoltzServiceConfig {
+                api_url: Some("http://limits-cache.test".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(service.precheck_amount(PairKind::Reverse, 1).is_ok());
+        service.remember_limits(PairKind::Reverse, 1_000, 50_000);
+        assert!(matches!(
+            service.precheck_amount(PairKind::Reverse, 1),
+            Err(PaymentError::AmountOutOfRange { .. })
+        ));
+        assert!(service.precheck_amount(PairKind::Submarine, 1).is_ok());
+    }
+
+    #[test]
+    fn transient_errors_are_detected() {
+        use boltz_client::error::Error;
+
+        let status =
+            |code: u16| Error::HTTPStatusNotSuccess(code.try_into().unwrap(), String::new());
+        assert!(is_transient_error(&status(429)));
+        assert!(is_transient_error(&status(503)));
+        assert!(!is_transient_error(&status(400)));
+        assert!(!is_transient_error(&status(404)));
+        assert!(!is_transient_error(&Error::Protocol(
+            "amount 500 is below the minimum".to_string()
+        )));
+        assert!(!is_transient_error(&Error::Generic(
+            "connection refused by policy".to_string()
+        )));
+    }
+}
@@ -0,0 +1,84 @@
+use bitcoincore_rpc::{Auth, Client, RpcApi};
+use serde_json::json;
+
+const RPC_URL_ENV: &str = "DEADCAT_REGTEST_RPC_URL";
+const RPC_USER_ENV: &str = "DEADCAT_REGTEST_RPC_USER";
+const RPC_PASSWORD_ENV: &str = "DEADCAT_REGTEST_RPC_PASSWORD";
+
+/// Blocks mined after the send so the funds confirm.
+const CONFIRMATION_BLOCKS: u64 = 1;
+
+pub struct RegtestFunding {
+    pub txid: String,
+    pub blocks_mined: u64,
+}
+
+/// RPC client for the regtest node named by [`RPC_URL_ENV`]. Without both
+/// user and password variables the client connects unauthenticated.
+fn client_from_env() -> Result<Client, String> {
+    let url = std::env::var(RPC_URL_ENV)
+        .map_err(|_| format!("{RPC_URL_ENV} is not set; point it at the regtest node's RPC"))?;
+    let auth = match (std::env::var(RPC_USER_ENV), std::env::var(RPC_PASSWORD_ENV)) {
+        (Ok(user), Ok(password)) => Auth::UserPass(user, password),
+        _ => Auth::None,
+    };
+    Client::new(&url, auth).map_err(|e| format!("regtest rpc client: {e}"))
+}
+
+/// Format sats as the decimal coin amount `sendtoaddress` expects.
+fn sats_to_coin_amount(amount_sat: u64) -> String {
+    format!(
+        "{}.{:08}",
+        amount_sat / 100_000_000,
+        amount_sat % 100_000_000
+    )
+}
+
+/// Send `amount_sat` of the policy asset from the regtest node's wallet to
+/// `address`, then mine a block to confirm it. Blocking.
+///
+/// The node's wallet must already hold spendable coins, e.g. from
+/// `-initialfreecoins`.
+pub fn fund(address: &str, amount_sat: u64) -> Result<RegtestFunding, String> {
+    if amount_sat == 0 {
+        return Err("amount must be non-zero".to_string());
+    }
+    let client = client_from_env()?;
+
+    let txid: String = client
+        .call(
+            "sendtoaddress",
+            &[json!(address), json!(sats_to_coin_amount(amount_sat))],
+        )
+        .map_err(|e| format!("sendtoaddress: {e}"))?;
+
+    let mine_to: String = client
+        .call("getnewaddress", &[])
+        .map_err(|e| format!("getnewaddress: {e}"))?;
+    let _: Vec<String> = client
+        .call(
+            "generatetoaddress",
+            &[json!(CONFIRMATION_BLOCKS), json!(mine_to)],
+        )
+        .map_err(|e| format!("generatetoaddress: {e}"))?;
+
+    Ok(RegtestFunding {
+        txid,
+        blocks_mined: CONFIRMATION_BLOCKS,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_sats_as_coin_amount() {
+        assert_eq!(sats_to_coin_amount(1), "0.00000001");
+        assert_eq!(sats_to_coin_amount(150_000_000), "1.50000000");
+        assert_eq!(
+            sats_to_coin_amount(2_100_000_000_000_000),
+            "21000000.00000000"
+        );
+    }
+}
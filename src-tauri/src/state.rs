@@ -2,30 +2,238 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 
+use crate::payments::boltz::{BoltzServiceConfig, SwapRecoveryAction, SwapStatus};
 use crate::wallet::persister::MnemonicPersister;
-use crate::wallet::types::WalletStatus;
+use crate::wallet::types::{sorted_balance, WalletStatus};
 use crate::Network;
 
-/// Duration of inactivity (in seconds) before the wallet auto-locks.
-pub const AUTO_LOCK_TIMEOUT_SECS: u64 = 300; // 5 minutes
+/// Default duration of inactivity (in seconds) before the wallet auto-locks.
+pub const DEFAULT_AUTO_LOCK_TIMEOUT_SECS: u64 = 300; // 5 minutes
+
+/// Shortest auto-lock timeout accepted from the user.
+pub const MIN_AUTO_LOCK_TIMEOUT_SECS: u64 = 30;
+
+/// Tick interval used by the auto-lock timer when auto-lock is disabled.
+const AUTO_LOCK_IDLE_TICK_SECS: u64 = 60;
+
+/// Default interval (in seconds) between chain-tip polls.
+pub const DEFAULT_CHAIN_TIP_POLL_SECS: u64 = 30;
+
+/// Shortest chain-tip poll interval accepted from the user.
+pub const MIN_CHAIN_TIP_POLL_SECS: u64 = 5;
+
+/// Default expiry warning window in blocks (about a day of Liquid blocks).
+pub const DEFAULT_EXPIRY_WARNING_BLOCKS: u32 = 1_440;
+
+/// Default depth a market transition needs before the store records it;
+/// 0 keeps the store's behaviour of accepting unconfirmed transitions.
+pub const DEFAULT_MIN_CONFIRMATIONS: u32 = 0;
+
+/// Shortest per-operation network timeout accepted from the user.
+pub const MIN_NETWORK_TIMEOUT_SECS: u64 = 1;
+
+/// Longest per-operation network timeout; Electrum takes seconds as a `u8`.
+pub const MAX_NETWORK_TIMEOUT_SECS: u64 = 255;
+
+/// Profile backed by the top-level app data dir, as before profiles existed.
+pub const DEFAULT_WALLET_PROFILE: &str = "default";
+
+/// Longest wallet profile name accepted from the user.
+const MAX_WALLET_PROFILE_NAME_LEN: usize = 32;
+
+/// Named profiles live under `<app_data_dir>/profiles/<name>`.
+const PROFILES_DIR: &str = "profiles";
 
 const LOCAL_STATE_FILE: &str = "deadcat_state.json";
 const CONFIG_FILE: &str = "network_config.json";
-const STORE_CUTOVER_MARKER_FILE: &str = "deadcat_store_cutover_v3.marker";
+const SERVICE_ENDPOINTS_FILE: &str = "service_endpoints.json";
+const STORE_CUTOVER_MARKER_FILE: &str = "deadcat_store_cutover_v4.marker";
+
+/// Store tables [`AppStateManager::reset_store`] copies into the rebuilt
+/// database because they can't be re-derived from the chain or Nostr: swap
+/// secrets, journaled signed transactions, the Nostr publish retry queue,
+/// the user's alerts and action history, and cached oracle attestations.
+const REBUILD_PRESERVED_TABLES: &[&str] = &[
+    "payment_swaps",
+    "broadcast_intents",
+    "event_publishes",
+    "price_alerts",
+    "action_log",
+    "market_attestations",
+];
 
 // ============================================================================
 // Persisted local state (payment swaps)
 // ============================================================================
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Settings and unstored swaps of one wallet profile, kept in that profile's
+/// directory. The default profile's directory is the app data directory, so
+/// its file also records which profile to open on launch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct LocalState {
-    #[serde(default)]
+    /// Swaps not yet moved into a network's store. Also holds new swaps
+    /// while the store is an in-memory fallback.
+    #[serde(default, serialize_with = "serialize_swaps_with_preimages")]
     payment_swaps: Vec<PaymentSwap>,
+    /// Auto-lock timeout in seconds; `None` disables auto-lock.
+    #[serde(default = "default_auto_lock_timeout_secs")]
+    auto_lock_timeout_secs: Option<u64>,
+    /// Lock the wallet as soon as the app is sent to the background.
+    #[serde(default)]
+    lock_on_background: bool,
+    /// Seconds between chain-tip polls while the wallet is unlocked.
+    #[serde(default = "default_chain_tip_poll_secs")]
+    chain_tip_poll_secs: u64,
+    /// Warn about held positions this many blocks before market expiry;
+    /// `0` disables the warnings.
+    #[serde(default = "default_expiry_warning_blocks")]
+    expiry_warning_blocks: u32,
+    /// Confirmations a market state transition needs before store sync
+    /// treats it as final.
+    #[serde(default = "default_min_confirmations")]
+    min_confirmations: u32,
+    /// Make dangerous mainnet commands demand `confirm_mainnet: true`.
+    #[serde(default = "default_require_mainnet_confirmation")]
+    require_mainnet_confirmation: bool,
+    /// Wallet profile to open on launch; `None` means the default profile.
+    /// Only read from the default profile's file.
+    #[serde(default)]
+    active_wallet_profile: Option<String>,
+    /// Explicit receive-address indices handed out, keyed by
+    /// `profile/network`, so sync can look for funds paid to them.
+    #[serde(default)]
+    explicit_address_indices: HashMap<String, Vec<u32>>,
+    /// Fee UTXO selection strategy per covenant operation, both by name.
+    /// Operations not listed use the SDK default.
+    #[serde(default)]
+    fee_coin_selection: HashMap<String, String>,
+    #[serde(default)]
+    network_timeouts: NetworkTimeouts,
+}
+
+impl Default for LocalState {
+    fn default() -> Self {
+        Self {
+            payment_swaps: Vec::new(),
+            auto_lock_timeout_secs: default_auto_lock_timeout_secs(),
+            lock_on_background: false,
+            chain_tip_poll_secs: default_chain_tip_poll_secs(),
+            expiry_warning_blocks: default_expiry_warning_blocks(),
+            min_confirmations: default_min_confirmations(),
+            require_mainnet_confirmation: default_require_mainnet_confirmation(),
+            active_wallet_profile: None,
+            explicit_address_indices: HashMap::new(),
+            fee_coin_selection: HashMap::new(),
+            network_timeouts: NetworkTimeouts::default(),
+        }
+    }
+}
+
+fn default_auto_lock_timeout_secs() -> Option<u64> {
+    Some(DEFAULT_AUTO_LOCK_TIMEOUT_SECS)
+}
+
+fn default_chain_tip_poll_secs() -> u64 {
+    DEFAULT_CHAIN_TIP_POLL_SECS
+}
+
+fn default_expiry_warning_blocks() -> u32 {
+    DEFAULT_EXPIRY_WARNING_BLOCKS
+}
+
+fn default_min_confirmations() -> u32 {
+    DEFAULT_MIN_CONFIRMATIONS
+}
+
+fn default_require_mainnet_confirmation() -> bool {
+    true
+}
+
+/// Per-service network timeouts in seconds, raised by users on slow or Tor
+/// connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkTimeouts {
+    /// Socket timeout for each Electrum call.
+    pub electrum: u64,
+    /// Esplora chain-tip requests.
+    pub esplora: u64,
+    /// Boltz API requests.
+    pub boltz: u64,
+    /// One-shot Nostr relay fetches.
+    pub nostr: u64,
+    /// Waiting for Nostr relays to connect before publishing or fetching.
+    pub nostr_connect: u64,
+}
+
+impl Default for NetworkTimeouts {
+    fn default() -> Self {
+        Self {
+            electrum: 30,
+            esplora: 15,
+            boltz: 8,
+            nostr: 15,
+            nostr_connect: 5,
+        }
+    }
+}
+
+impl NetworkTimeouts {
+    pub fn electrum(&self) -> Duration {
+        Duration::from_secs(self.electrum)
+    }
+
+    pub fn esplora(&self) -> Duration {
+        Duration::from_secs(self.esplora)
+    }
+
+    pub fn boltz(&self) -> Duration {
+        Duration::from_secs(self.boltz)
+    }
+
+    pub fn nostr(&self) -> Duration {
+        Duration::from_secs(self.nostr)
+    }
+
+    pub fn nostr_connect(&self) -> Duration {
+        Duration::from_secs(self.nostr_connect)
+    }
+
+    /// Pull every timeout into the accepted range, for values read from a
+    /// hand-edited or older state file.
+    fn clamped(self) -> Self {
+        let clamp = |secs: u64| secs.clamp(MIN_NETWORK_TIMEOUT_SECS, MAX_NETWORK_TIMEOUT_SECS);
+        Self {
+            electrum: clamp(self.electrum),
+            esplora: clamp(self.esplora),
+            boltz: clamp(self.boltz),
+            nostr: clamp(self.nostr),
+            nostr_connect: clamp(self.nostr_connect),
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for (name, secs) in [
+            ("electrum", self.electrum),
+            ("esplora", self.esplora),
+            ("boltz", self.boltz),
+            ("nostr", self.nostr),
+            ("nostr connect", self.nostr_connect),
+        ] {
+            if !(MIN_NETWORK_TIMEOUT_SECS..=MAX_NETWORK_TIMEOUT_SECS).contains(&secs) {
+                return Err(format!(
+                    "{name} timeout must be between {MIN_NETWORK_TIMEOUT_SECS} and \
+                     {MAX_NETWORK_TIMEOUT_SECS} seconds"
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,7 +242,7 @@ pub struct PaymentSwap {
     pub id: String,
     pub flow: String,
     pub network: String,
-    pub status: String,
+    pub status: SwapStatus,
     pub invoice_amount_sat: u64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expected_amount_sat: Option<u64>,
@@ -52,10 +260,216 @@ pub struct PaymentSwap {
     pub invoice_expires_at: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lockup_txid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claim_txid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preimage_hash: Option<String>,
+    /// Generated by the wallet for reverse and chain swaps; fetched from
+    /// Boltz once a submarine swap's invoice is paid. Never serialized, so
+    /// it can't leak through snapshots or exports; read it with
+    /// [`PaymentSwap::preimage`].
+    #[serde(default, skip_serializing)]
+    pub(crate) preimage: Option<String>,
+    /// Boltz's description of the lockup a chain swap claims, as JSON; see
+    /// [`crate::payments::boltz::BoltzService::claim_chain_swap`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claim_details: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+impl PaymentSwap {
+    /// Copy without the preimage, for snapshots and swap listings. Only
+    /// the swap receipt exposes it.
+    pub fn redacted(&self) -> Self {
+        Self {
+            preimage: None,
+            ..self.clone()
+        }
+    }
+
+    /// The hex preimage, for the swap receipt.
+    pub fn preimage(&self) -> Option<&str> {
+        self.preimage.as_deref()
+    }
+}
+
+/// Writes swaps to the local state file with their preimages, which
+/// [`PaymentSwap`] itself never serializes.
+fn serialize_swaps_with_preimages<S: serde::Serializer>(
+    swaps: &[PaymentSwap],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct WithPreimage<'a> {
+        #[serde(flatten)]
+        swap: &'a PaymentSwap,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        preimage: Option<&'a str>,
+    }
+    serializer.collect_seq(swaps.iter().map(|swap| WithPreimage {
+        swap,
+        preimage: swap.preimage(),
+    }))
+}
+
+impl From<PaymentSwap> for deadcat_store::PaymentSwapRecord {
+    fn from(swap: PaymentSwap) -> Self {
+        Self {
+            id: swap.id,
+            flow: swap.flow,
+            network: swap.network,
+            status: swap.status.into(),
+            invoice_amount_sat: swap.invoice_amount_sat,
+            expected_amount_sat: swap.expected_amount_sat,
+            lockup_address: swap.lockup_address,
+            timeout_block_height: swap.timeout_block_height,
+            pair_hash: swap.pair_hash,
+            invoice: swap.invoice,
+            invoice_expiry_seconds: swap.invoice_expiry_seconds,
+            invoice_expires_at: swap.invoice_expires_at,
+            lockup_txid: swap.lockup_txid,
+            claim_txid: swap.claim_txid,
+            preimage_hash: swap.preimage_hash,
+            preimage: swap.preimage,
+            claim_details: swap.claim_details,
+            created_at: swap.created_at,
+            updated_at: swap.updated_at,
+        }
+    }
+}
+
+impl From<deadcat_store::PaymentSwapRecord> for PaymentSwap {
+    fn from(record: deadcat_store::PaymentSwapRecord) -> Self {
+        Self {
+            id: record.id,
+            flow: record.flow,
+            network: record.network,
+            status: SwapStatus::parse(&record.status),
+            invoice_amount_sat: record.invoice_amount_sat,
+            expected_amount_sat: record.expected_amount_sat,
+            lockup_address: record.lockup_address,
+            timeout_block_height: record.timeout_block_height,
+            pair_hash: record.pair_hash,
+            invoice: record.invoice,
+            invoice_expiry_seconds: record.invoice_expiry_seconds,
+            invoice_expires_at: record.invoice_expires_at,
+            lockup_txid: record.lockup_txid,
+            claim_txid: record.claim_txid,
+            preimage_hash: record.preimage_hash,
+            preimage: record.preimage,
+            claim_details: record.claim_details,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+}
+
+/// Everything known about a finished swap, as proof of payment.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapReceipt {
+    pub swap_id: String,
+    pub flow: String,
+    pub network: String,
+    pub status: SwapStatus,
+    pub invoice: Option<String>,
+    pub payment_hash: Option<String>,
+    pub preimage: Option<String>,
+    /// Amount the wallet paid into the swap.
+    pub sent_amount_sat: Option<u64>,
+    /// Amount the swap paid out, before the wallet's own claim fee.
+    pub received_amount_sat: Option<u64>,
+    /// Boltz service and miner fees: `sent - received`.
+    pub fee_sat: Option<u64>,
+    pub lockup_address: Option<String>,
+    pub lockup_txid: Option<String>,
+    pub claim_txid: Option<String>,
+    pub created_at: String,
+    pub completed_at: String,
+}
+
+impl SwapReceipt {
+    pub fn from_swap(swap: &PaymentSwap) -> Self {
+        // Submarine swaps pay the invoice from an on-chain lockup; every
+        // other flow receives `expected_amount_sat` for `invoice_amount_sat`.
+        let (sent, received) = if swap.flow == "liquid_to_lightning" {
+            (swap.expected_amount_sat, Some(swap.invoice_amount_sat))
+        } else {
+            (Some(swap.invoice_amount_sat), swap.expected_amount_sat)
+        };
+        Self {
+            swap_id: swap.id.clone(),
+            flow: swap.flow.clone(),
+            network: swap.network.clone(),
+            status: swap.status.clone(),
+            invoice: swap.invoice.clone(),
+            payment_hash: swap.preimage_hash.clone(),
+            preimage: swap.preimage().map(str::to_string),
+            sent_amount_sat: sent,
+            received_amount_sat: received,
+            fee_sat: sent.zip(received).and_then(|(s, r)| s.checked_sub(r)),
+            lockup_address: swap.lockup_address.clone(),
+            lockup_txid: swap.lockup_txid.clone(),
+            claim_txid: swap.claim_txid.clone(),
+            created_at: swap.created_at.clone(),
+            completed_at: swap.updated_at.clone(),
+        }
+    }
+}
+
+/// Countdown to a swap's deadlines. Zero or negative once a deadline has
+/// passed; `None` when the swap has no such deadline or it can't be
+/// computed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapTimeRemaining {
+    /// Liquid blocks until the lockup can be refunded. Not computed for
+    /// Bitcoin lockups, whose timeout is a Bitcoin height.
+    pub blocks_until_timeout: Option<i64>,
+    pub seconds_until_invoice_expiry: Option<i64>,
+    pub tip_height: Option<u32>,
+}
+
+impl SwapTimeRemaining {
+    pub fn for_swap(
+        swap: &PaymentSwap,
+        tip_height: Option<u32>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        let blocks_until_timeout = swap
+            .timeout_block_height
+            .zip(tip_height)
+            .filter(|_| crate::payments::boltz::swap_lockup_on_liquid(&swap.flow))
+            .map(|(timeout, tip)| i64::try_from(timeout).unwrap_or(i64::MAX) - i64::from(tip));
+        let seconds_until_invoice_expiry = swap
+            .invoice_expires_at
+            .as_deref()
+            .and_then(|expires_at| chrono::DateTime::parse_from_rfc3339(expires_at).ok())
+            .map(|expires_at| (expires_at.with_timezone(&chrono::Utc) - now).num_seconds());
+        Self {
+            blocks_until_timeout,
+            seconds_until_invoice_expiry,
+            tip_height,
+        }
+    }
+}
+
+/// A non-terminal swap that has not progressed recently, with the action
+/// the user should take to recover it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StuckSwap {
+    #[serde(flatten)]
+    pub swap: PaymentSwap,
+    pub action: SwapRecoveryAction,
+    /// Liquid chain tip the action was computed against.
+    pub tip_height: u32,
+    /// Whether the Liquid lockup still holds funds; `None` for Bitcoin
+    /// lockups or when the lookup failed.
+    pub lockup_funded: Option<bool>,
+}
+
 // ============================================================================
 // Network status & app state (sent to frontend)
 // ============================================================================
@@ -69,16 +483,143 @@ pub struct NetworkStatus {
     pub policy_asset_id: String,
 }
 
+/// A wallet profile as listed to the frontend.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletProfileInfo {
+    pub name: String,
+    /// Network the profile was last used on, if it has been set up.
+    pub network: Option<String>,
+    pub is_active: bool,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AppState {
     pub revision: u64,
+    pub wallet_profile: String,
     pub network_status: NetworkStatus,
     pub wallet_status: WalletStatus,
-    pub wallet_balance: Option<HashMap<String, u64>>,
+    /// Non-zero balances in `sorted_balance` order.
+    pub wallet_balance: Option<Vec<(String, u64)>>,
     pub payment_swaps: Vec<PaymentSwap>,
 }
 
+/// Payload of the `balance_updated` event: only the assets whose balance
+/// changed since the last one sent, with `0` for assets no longer held.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceUpdate {
+    pub revision: u64,
+    pub changed: HashMap<String, u64>,
+}
+
+fn profile_dir_for(app_data_dir: &Path, profile: &str) -> PathBuf {
+    if profile == DEFAULT_WALLET_PROFILE {
+        app_data_dir.to_path_buf()
+    } else {
+        app_data_dir.join(PROFILES_DIR).join(profile)
+    }
+}
+
+fn write_local_state(dir: &Path, state: &LocalState) {
+    if let Ok(json) = serde_json::to_string_pretty(state) {
+        let _ = fs::write(dir.join(LOCAL_STATE_FILE), json);
+    }
+}
+
+/// Custom service API URLs, keyed by network name. They describe the
+/// network rather than a wallet, so every profile shares the one file in the
+/// app data directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServiceEndpoints {
+    #[serde(default)]
+    boltz_api_urls: HashMap<String, String>,
+    /// Regtest has no public Esplora, so this points the chain-tip fetch at
+    /// a local one.
+    #[serde(default)]
+    esplora_api_urls: HashMap<String, String>,
+}
+
+fn load_service_endpoints(app_data_dir: &Path) -> ServiceEndpoints {
+    fs::read_to_string(app_data_dir.join(SERVICE_ENDPOINTS_FILE))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Delete a store database along with its SQLite WAL sidecars.
+fn remove_store_files(db_path: &Path) {
+    if let Err(e) = fs::remove_file(db_path) {
+        if db_path.exists() {
+            log::warn!("failed to remove store db {}: {e}", db_path.display());
+        }
+    }
+    for suffix in ["-wal", "-shm"] {
+        let sidecar = PathBuf::from(format!("{}{suffix}", db_path.display()));
+        if let Err(e) = fs::remove_file(&sidecar) {
+            if sidecar.exists() {
+                log::warn!("failed to remove sqlite sidecar {}: {e}", sidecar.display());
+            }
+        }
+    }
+}
+
+fn upsert_swap(swaps: &mut Vec<PaymentSwap>, swap: PaymentSwap) {
+    match swaps.iter_mut().find(|s| s.id == swap.id) {
+        Some(existing) => *existing = swap,
+        None => swaps.push(swap),
+    }
+}
+
+fn load_network_config_at(dir: &Path) -> Option<Network> {
+    let contents = fs::read_to_string(dir.join(CONFIG_FILE)).ok()?;
+    let config: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    let network_str = config.get("network")?.as_str()?;
+    network_str.parse().ok()
+}
+
+/// Profile names become directory names, so keep them to a safe charset.
+fn validate_wallet_profile_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > MAX_WALLET_PROFILE_NAME_LEN {
+        return Err(format!(
+            "wallet name must be 1-{MAX_WALLET_PROFILE_NAME_LEN} characters"
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("wallet name may only contain letters, digits, '-' and '_'".to_string());
+    }
+    if name == DEFAULT_WALLET_PROFILE {
+        return Err(format!("'{DEFAULT_WALLET_PROFILE}' is reserved"));
+    }
+    Ok(())
+}
+
+fn auto_lock_tick_secs(timeout_secs: Option<u64>) -> u64 {
+    match timeout_secs {
+        Some(secs) => std::cmp::max(secs / 5, 10),
+        None => AUTO_LOCK_IDLE_TICK_SECS,
+    }
+}
+
+/// Check a user-supplied `service` base URL: only absolute http(s) URLs with
+/// a host are accepted, and a trailing slash is stripped.
+fn validate_api_url(service: &str, url: &str) -> Result<String, String> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(|| format!("{service} URL must start with http:// or https://: {url}"))?;
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if host.is_empty() || url.chars().any(char::is_whitespace) {
+        return Err(format!("Invalid {service} URL: {url}"));
+    }
+    Ok(url.trim_end_matches('/').to_string())
+}
+
 // ============================================================================
 // SDK network conversion
 // ============================================================================
@@ -97,30 +638,63 @@ pub fn to_sdk_network(network: Network) -> deadcat_sdk::Network {
 
 pub struct AppStateManager {
     pub app_data_dir: PathBuf,
+    /// Active wallet profile; selects where the network config, mnemonic and
+    /// store live (see `profile_dir`).
+    profile: String,
     network: Option<Network>,
     persister: Option<MnemonicPersister>,
     store: Option<Arc<std::sync::Mutex<deadcat_store::DeadcatStore>>>,
+    /// Why the on-disk store could not be opened (a failed migration or a
+    /// directory belonging to another network), in which case `store` is an
+    /// in-memory fallback and the file is left untouched.
+    store_migration_error: Option<String>,
     /// Whether the node's wallet is currently unlocked.
     /// Updated by the caller after node operations.
     wallet_unlocked: bool,
     local_state: LocalState,
+    service_endpoints: ServiceEndpoints,
+    /// Swaps of the open store, loaded when it opens and written through
+    /// on every upsert, so snapshots never wait on the store lock.
+    payment_swaps: Vec<PaymentSwap>,
     revision: u64,
     /// Timestamp of last user activity (for auto-lock).
     last_activity: Instant,
+    /// Number of sends/swaps currently in flight. Background locking is
+    /// suppressed while this is non-zero.
+    operations_in_progress: usize,
+    /// Balance last sent to the frontend, diffed by `record_balance`.
+    /// Cleared whenever the wallet locks, unlocks or changes.
+    emitted_balance: Option<HashMap<String, u64>>,
 }
 
 impl AppStateManager {
     pub fn new(app_data_dir: PathBuf) -> Self {
-        let local_state = Self::load_local_state(&app_data_dir).unwrap_or_default();
+        let root_state = Self::load_local_state(&app_data_dir).unwrap_or_default();
+        let profile = root_state
+            .active_wallet_profile
+            .clone()
+            .filter(|name| profile_dir_for(&app_data_dir, name).is_dir())
+            .unwrap_or_else(|| DEFAULT_WALLET_PROFILE.to_string());
+        let local_state = if profile == DEFAULT_WALLET_PROFILE {
+            root_state
+        } else {
+            Self::load_local_state(&profile_dir_for(&app_data_dir, &profile)).unwrap_or_default()
+        };
         Self {
+            service_endpoints: load_service_endpoints(&app_data_dir),
             app_data_dir,
+            profile,
             network: None,
             persister: None,
             store: None,
+            store_migration_error: None,
             wallet_unlocked: false,
+            payment_swaps: local_state.payment_swaps.clone(),
             local_state,
             revision: 0,
             last_activity: Instant::now(),
+            operations_in_progress: 0,
+            emitted_balance: None,
         }
     }
 
@@ -152,44 +726,222 @@ impl AppStateManager {
 
     fn init_with_network(&mut self, network: Network) {
         self.network = Some(network);
-        self.persister = Some(MnemonicPersister::new(&self.app_data_dir, network.as_str()));
+        self.emitted_balance = None;
+        let profile_dir = self.profile_dir();
+        self.persister = Some(MnemonicPersister::new(&profile_dir, network.as_str()));
 
-        // Open the store at <app_data_dir>/<network>/deadcat.db
-        let store_dir = self.app_data_dir.join(network.as_str());
+        // Open the store at <profile_dir>/<network>/deadcat.db
+        let store_dir = profile_dir.join(network.as_str());
         std::fs::create_dir_all(&store_dir).ok();
         let db_path = store_dir.join("deadcat.db");
+        self.store_migration_error = None;
+        // Never open another network's chain state, e.g. a copied directory.
+        if let Err(e) = deadcat_sdk::ensure_network_marker(&store_dir, to_sdk_network(network)) {
+            log::error!(
+                "refusing to open store at {}, using an in-memory store: {e}",
+                db_path.display()
+            );
+            self.store_migration_error = Some(e.to_string());
+            self.store = Some(Arc::new(std::sync::Mutex::new(
+                deadcat_store::DeadcatStore::open_in_memory()
+                    .expect("failed to open in-memory fallback store"),
+            )));
+            self.payment_swaps = self.load_payment_swaps();
+            return;
+        }
         self.apply_unreleased_store_cutover(&store_dir, &db_path);
-        let store = deadcat_store::DeadcatStore::open(db_path.to_str().unwrap_or(":memory:"))
-            .expect("failed to open deadcat store");
+        let store = match deadcat_store::DeadcatStore::open(db_path.to_str().unwrap_or(":memory:"))
+        {
+            Ok(store) => store,
+            Err(e @ deadcat_store::StoreError::MigrationRolledBack { .. }) => {
+                log::error!(
+                    "store at {} could not be migrated, using an in-memory store: {e}",
+                    db_path.display()
+                );
+                self.store_migration_error = Some(e.to_string());
+                deadcat_store::DeadcatStore::open_in_memory()
+                    .expect("failed to open in-memory fallback store")
+            }
+            Err(e) => panic!("failed to open deadcat store: {e}"),
+        };
         self.write_store_cutover_marker(&store_dir);
         self.store = Some(Arc::new(std::sync::Mutex::new(store)));
+        self.import_local_payment_swaps(network);
+        self.payment_swaps = self.load_payment_swaps();
+    }
+
+    /// Move this network's swaps from the local state file into the store.
+    fn import_local_payment_swaps(&mut self, network: Network) {
+        let Some(store) = self.durable_store().cloned() else {
+            return;
+        };
+        let Ok(mut store) = store.lock() else {
+            return;
+        };
+        let before = self.local_state.payment_swaps.len();
+        let mut failed = false;
+        self.local_state.payment_swaps.retain(|swap| {
+            if failed || swap.network != network.as_str() {
+                return true;
+            }
+            match store.upsert_payment_swap(&swap.clone().into()) {
+                Ok(()) => false,
+                Err(e) => {
+                    log::warn!(
+                        "failed to move payment swap {} into the store: {e}",
+                        swap.id
+                    );
+                    failed = true;
+                    true
+                }
+            }
+        });
+        drop(store);
+        if self.local_state.payment_swaps.len() != before {
+            self.save_local_state();
+        }
     }
 
     fn apply_unreleased_store_cutover(&self, store_dir: &Path, db_path: &Path) {
-        // Pre-release breaking cutover: wipe any pre-cutover DB once per network.
-        // v3 drops support for the legacy LMSR `params_json` encoding and other
-        // in-flight LMSR history rows that only existed in local/dev DBs.
+        // Pre-release breaking cutover: rebuild any pre-cutover DB once per
+        // network. v4 follows the prediction-market covenant gaining its
+        // collateral merge path, which moved every market's CMR and covenant
+        // addresses; markets stored against the old script can't be found or
+        // spent, so they are dropped and rediscovered from current
+        // announcements. Rows that can't be re-derived are carried over.
         let marker = store_dir.join(STORE_CUTOVER_MARKER_FILE);
         if marker.exists() || !db_path.exists() {
             return;
         }
 
         log::warn!(
-            "applying pre-release store cutover: resetting database at {}",
+            "applying pre-release store cutover: rebuilding database at {}",
             db_path.display()
         );
-
-        if let Err(e) = fs::remove_file(db_path) {
-            log::warn!("failed to remove pre-cutover db {}: {e}", db_path.display());
+        let rebuild_path = store_dir.join("deadcat.db.rebuild");
+        remove_store_files(&rebuild_path);
+        let carried =
+            deadcat_store::DeadcatStore::open(rebuild_path.to_str().unwrap_or(":memory:"))
+                .and_then(|mut rebuilt| {
+                    rebuilt.copy_tables_from(
+                        db_path.to_str().unwrap_or_default(),
+                        REBUILD_PRESERVED_TABLES,
+                    )
+                });
+        let complete = match carried {
+            Ok(copied) => copied.iter().all(|(table, result)| match result {
+                Ok(_) => true,
+                Err(e) => {
+                    log::warn!("store cutover could not carry over {table}: {e}");
+                    false
+                }
+            }),
+            Err(e) => {
+                log::warn!("store cutover could not read the old store: {e}");
+                false
+            }
+        };
+        if complete {
+            remove_store_files(db_path);
+        } else {
+            // Keep what couldn't be carried over for manual recovery.
+            let backup = store_dir.join("deadcat.db.pre-cutover");
+            remove_store_files(&backup);
+            if let Err(e) = fs::rename(db_path, &backup) {
+                log::error!("store cutover could not keep the old store: {e}");
+                remove_store_files(&rebuild_path);
+                return;
+            }
+            remove_store_files(db_path);
+        }
+        if let Err(e) = fs::rename(&rebuild_path, db_path) {
+            log::error!("store cutover could not replace the store: {e}");
         }
-        for suffix in ["-wal", "-shm"] {
-            let sidecar = store_dir.join(format!("deadcat.db{suffix}"));
-            if let Err(e) = fs::remove_file(&sidecar) {
-                if sidecar.exists() {
-                    log::warn!("failed to remove sqlite sidecar {}: {e}", sidecar.display());
+    }
+
+    /// Replace the network's store with an empty database, keeping the
+    /// tables in [`REBUILD_PRESERVED_TABLES`].
+    ///
+    /// This is the recovery path for a store that fails its integrity check
+    /// or its migrations. Preserved rows are copied from the database file
+    /// on disk, not from the open store, which may be the in-memory
+    /// fallback. Tables that can't be read are returned as warnings, and the
+    /// old file is then kept as `deadcat.db.pre-rebuild` instead of deleted.
+    /// The reset is refused if `broadcast_intents` can't be read, since
+    /// pending intents must survive until they are reconciled.
+    /// The database is swapped inside the existing mutex, so the node's
+    /// handle to the store stays valid.
+    pub fn reset_store(&mut self) -> Result<Vec<String>, String> {
+        let network = self
+            .network
+            .ok_or("Not initialized - select a network first")?;
+        let store_dir = self.profile_dir().join(network.as_str());
+        deadcat_sdk::ensure_network_marker(&store_dir, to_sdk_network(network))
+            .map_err(|e| e.to_string())?;
+        let db_path = store_dir.join("deadcat.db");
+        let rebuild_path = store_dir.join("deadcat.db.rebuild");
+        let store = self.store.clone().ok_or("Store not initialized")?;
+        // Held throughout so nothing writes to the old file after its rows
+        // were copied.
+        let mut guard = store.lock().map_err(|_| "store lock failed".to_string())?;
+
+        remove_store_files(&rebuild_path);
+        let mut rebuilt =
+            deadcat_store::DeadcatStore::open(rebuild_path.to_str().unwrap_or(":memory:"))
+                .map_err(|e| format!("failed to create rebuilt store: {e}"))?;
+        let mut warnings = Vec::new();
+        if db_path.exists() {
+            match rebuilt.copy_tables_from(
+                db_path.to_str().unwrap_or_default(),
+                REBUILD_PRESERVED_TABLES,
+            ) {
+                Ok(copied) => {
+                    for (table, result) in copied {
+                        match result {
+                            // Dropping journaled transactions before they are
+                            // reconciled defeats the journal.
+                            Err(e) if table == "broadcast_intents" => {
+                                drop(rebuilt);
+                                remove_store_files(&rebuild_path);
+                                return Err(format!(
+                                    "could not carry over pending broadcast intents ({e}); \
+                                     the store was not reset"
+                                ));
+                            }
+                            Err(e) => warnings.push(format!("could not carry over {table}: {e}")),
+                            Ok(_) => {}
+                        }
+                    }
                 }
+                Err(e) => warnings.push(format!("could not read the old store: {e}")),
             }
         }
+        drop(rebuilt);
+
+        // Close the file before replacing it.
+        *guard = deadcat_store::DeadcatStore::open_in_memory()
+            .map_err(|e| format!("failed to reset store: {e}"))?;
+        log::warn!("resetting store database at {}", db_path.display());
+        if warnings.is_empty() {
+            remove_store_files(&db_path);
+        } else if db_path.exists() {
+            let backup = store_dir.join("deadcat.db.pre-rebuild");
+            remove_store_files(&backup);
+            fs::rename(&db_path, &backup)
+                .map_err(|e| format!("failed to keep the old store: {e}"))?;
+            remove_store_files(&db_path);
+            warnings.push(format!("the old store was kept at {}", backup.display()));
+        }
+        fs::rename(&rebuild_path, &db_path).map_err(|e| format!("failed to replace store: {e}"))?;
+        *guard = deadcat_store::DeadcatStore::open(db_path.to_str().unwrap_or(":memory:"))
+            .map_err(|e| format!("failed to reopen store: {e}"))?;
+        drop(guard);
+
+        self.store_migration_error = None;
+        // Swaps saved to the state file while the fallback was in use.
+        self.import_local_payment_swaps(network);
+        self.payment_swaps = self.load_payment_swaps();
+        Ok(warnings)
     }
 
     fn write_store_cutover_marker(&self, store_dir: &Path) {
@@ -198,7 +950,7 @@ impl AppStateManager {
             return;
         }
 
-        if let Err(e) = fs::write(&marker, b"deadcat-store-cutover-v3\n") {
+        if let Err(e) = fs::write(&marker, b"deadcat-store-cutover-v4\n") {
             log::warn!(
                 "failed to write store cutover marker {}: {e}",
                 marker.display()
@@ -218,9 +970,23 @@ impl AppStateManager {
         self.store.as_ref()
     }
 
+    /// The store, unless it is the in-memory fallback.
+    fn durable_store(&self) -> Option<&Arc<std::sync::Mutex<deadcat_store::DeadcatStore>>> {
+        self.store
+            .as_ref()
+            .filter(|_| self.store_migration_error.is_none())
+    }
+
+    /// Migration failure or network mismatch that forced the in-memory
+    /// fallback store, if any.
+    pub fn store_migration_error(&self) -> Option<&str> {
+        self.store_migration_error.as_deref()
+    }
+
     /// Mark the wallet as unlocked/locked (synced from the NodeState).
     pub fn set_wallet_unlocked(&mut self, unlocked: bool) {
         self.wallet_unlocked = unlocked;
+        self.emitted_balance = None;
     }
 
     pub fn network_status(&self) -> NetworkStatus {
@@ -265,12 +1031,20 @@ impl AppStateManager {
 
     /// Build an `AppState` snapshot, optionally including wallet balance.
     pub fn snapshot_with_balance(&self, wallet_balance: Option<HashMap<String, u64>>) -> AppState {
+        let network_status = self.network_status();
+        let wallet_balance =
+            wallet_balance.map(|balance| sorted_balance(balance, &network_status.policy_asset_id));
         AppState {
             revision: self.revision,
-            network_status: self.network_status(),
+            wallet_profile: self.profile.clone(),
+            network_status,
             wallet_status: self.wallet_status(),
             wallet_balance,
-            payment_swaps: self.local_state.payment_swaps.clone(),
+            payment_swaps: self
+                .payment_swaps
+                .iter()
+                .map(PaymentSwap::redacted)
+                .collect(),
         }
     }
 
@@ -278,6 +1052,30 @@ impl AppStateManager {
         self.revision += 1;
     }
 
+    /// Remember `wallet_balance` as sent to the frontend and return what
+    /// changed since the previous one. `None` when there is no previous
+    /// balance for this wallet session, so the caller sends a full snapshot.
+    pub fn record_balance(
+        &mut self,
+        wallet_balance: &HashMap<String, u64>,
+    ) -> Option<BalanceUpdate> {
+        let previous = self.emitted_balance.replace(wallet_balance.clone())?;
+        let mut changed: HashMap<String, u64> = wallet_balance
+            .iter()
+            .filter(|(asset, amount)| previous.get(*asset) != Some(*amount))
+            .map(|(asset, amount)| (asset.clone(), *amount))
+            .collect();
+        for asset in previous.keys() {
+            if !wallet_balance.contains_key(asset) {
+                changed.insert(asset.clone(), 0);
+            }
+        }
+        Some(BalanceUpdate {
+            revision: self.revision,
+            changed,
+        })
+    }
+
     /// Record user activity (resets the auto-lock timer).
     pub fn touch_activity(&mut self) {
         self.last_activity = Instant::now();
@@ -286,9 +1084,12 @@ impl AppStateManager {
     /// Check if the auto-lock timeout has elapsed. If so, mark wallet locked
     /// and return `true` so the caller can lock the node and emit state.
     pub fn check_auto_lock(&mut self) -> bool {
-        if self.last_activity.elapsed().as_secs() >= AUTO_LOCK_TIMEOUT_SECS && self.wallet_unlocked
-        {
+        let Some(timeout_secs) = self.local_state.auto_lock_timeout_secs else {
+            return false;
+        };
+        if self.last_activity.elapsed().as_secs() >= timeout_secs && self.wallet_unlocked {
             self.wallet_unlocked = false;
+            self.emitted_balance = None;
             if let Some(persister) = self.persister.as_mut() {
                 persister.clear_cache();
             }
@@ -298,36 +1099,408 @@ impl AppStateManager {
         false
     }
 
-    pub fn payment_swaps(&self) -> &[PaymentSwap] {
-        &self.local_state.payment_swaps
+    /// Current auto-lock timeout in seconds, or `None` if auto-lock is disabled.
+    pub fn auto_lock_timeout_secs(&self) -> Option<u64> {
+        self.local_state.auto_lock_timeout_secs
     }
 
-    pub fn upsert_payment_swap(&mut self, swap: PaymentSwap) {
-        match self
-            .local_state
-            .payment_swaps
-            .iter_mut()
-            .find(|s| s.id == swap.id)
-        {
-            Some(existing) => *existing = swap,
-            None => self.local_state.payment_swaps.push(swap),
+    /// Set (and persist) the auto-lock timeout. `None` disables auto-lock.
+    pub fn set_auto_lock_timeout(&mut self, timeout_secs: Option<u64>) -> Result<(), String> {
+        if let Some(secs) = timeout_secs {
+            if secs < MIN_AUTO_LOCK_TIMEOUT_SECS {
+                return Err(format!(
+                    "auto-lock timeout must be at least {MIN_AUTO_LOCK_TIMEOUT_SECS} seconds"
+                ));
+            }
+        }
+        self.local_state.auto_lock_timeout_secs = timeout_secs;
+        self.save_local_state();
+        // Restart the inactivity window so a shorter timeout doesn't lock
+        // the wallet immediately after the user changes it.
+        self.touch_activity();
+        Ok(())
+    }
+
+    pub fn lock_on_background(&self) -> bool {
+        self.local_state.lock_on_background
+    }
+
+    pub fn set_lock_on_background(&mut self, enabled: bool) {
+        self.local_state.lock_on_background = enabled;
+        self.save_local_state();
+    }
+
+    /// Network and Boltz client settings for the current network, honoring
+    /// any user-configured API URL.
+    pub fn boltz_service_config(&self) -> Option<(Network, BoltzServiceConfig)> {
+        let network = self.network?;
+        let config = BoltzServiceConfig {
+            api_url: self
+                .service_endpoints
+                .boltz_api_urls
+                .get(network.as_str())
+                .cloned(),
+            timeout: Some(self.local_state.network_timeouts.boltz()),
+            ..Default::default()
+        };
+        Some((network, config))
+    }
+
+    /// Set (or clear, with `None`/empty) the Boltz API URL for the current
+    /// network, for every wallet profile.
+    pub fn set_boltz_api_url(&mut self, url: Option<String>) -> Result<(), String> {
+        let network = self
+            .network
+            .ok_or("Not initialized - select a network first")?;
+        match url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty()) {
+            Some(url) => {
+                let url = validate_api_url("Boltz", &url)?;
+                self.service_endpoints
+                    .boltz_api_urls
+                    .insert(network.as_str().to_string(), url);
+            }
+            None => {
+                self.service_endpoints
+                    .boltz_api_urls
+                    .remove(network.as_str());
+            }
+        }
+        self.save_service_endpoints()
+    }
+
+    /// Esplora API base URL for `network`: the user's override, or the
+    /// SDK default (a local Esplora for regtest).
+    pub fn esplora_api_url(&self, network: Network) -> String {
+        self.service_endpoints
+            .esplora_api_urls
+            .get(network.as_str())
+            .cloned()
+            .unwrap_or_else(|| to_sdk_network(network).esplora_url().to_string())
+    }
+
+    /// Set (or clear, with `None`/empty) the Esplora API URL for the
+    /// current network, for every wallet profile.
+    pub fn set_esplora_api_url(&mut self, url: Option<String>) -> Result<(), String> {
+        let network = self
+            .network
+            .ok_or("Not initialized - select a network first")?;
+        match url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty()) {
+            Some(url) => {
+                let url = validate_api_url("Esplora", &url)?;
+                self.service_endpoints
+                    .esplora_api_urls
+                    .insert(network.as_str().to_string(), url);
+            }
+            None => {
+                self.service_endpoints
+                    .esplora_api_urls
+                    .remove(network.as_str());
+            }
         }
+        self.save_service_endpoints()
+    }
+
+    /// Mark the start of a send or swap that must not be interrupted by a
+    /// background lock. Pair with `end_operation`.
+    pub fn begin_operation(&mut self) {
+        self.operations_in_progress += 1;
+    }
+
+    pub fn end_operation(&mut self) {
+        self.operations_in_progress = self.operations_in_progress.saturating_sub(1);
+    }
+
+    /// Whether the wallet should be locked now that the app went to the
+    /// background.
+    pub fn should_lock_on_background(&self) -> bool {
+        self.local_state.lock_on_background
+            && self.wallet_unlocked
+            && self.operations_in_progress == 0
+    }
+
+    /// How often the background timer should poll `check_auto_lock`.
+    pub fn auto_lock_tick_secs(&self) -> u64 {
+        auto_lock_tick_secs(self.local_state.auto_lock_timeout_secs)
+    }
+
+    pub fn network_timeouts(&self) -> NetworkTimeouts {
+        self.local_state.network_timeouts
+    }
+
+    /// Set (and persist) the per-service network timeouts.
+    pub fn set_network_timeouts(&mut self, timeouts: NetworkTimeouts) -> Result<(), String> {
+        timeouts.validate()?;
+        self.local_state.network_timeouts = timeouts;
+        self.save_local_state();
+        Ok(())
+    }
+
+    pub fn chain_tip_poll_secs(&self) -> u64 {
+        self.local_state.chain_tip_poll_secs
+    }
+
+    /// Set (and persist) the chain-tip poll interval.
+    pub fn set_chain_tip_poll_secs(&mut self, secs: u64) -> Result<(), String> {
+        if secs < MIN_CHAIN_TIP_POLL_SECS {
+            return Err(format!(
+                "chain tip poll interval must be at least {MIN_CHAIN_TIP_POLL_SECS} seconds"
+            ));
+        }
+        self.local_state.chain_tip_poll_secs = secs;
+        self.save_local_state();
+        Ok(())
+    }
+
+    pub fn expiry_warning_blocks(&self) -> u32 {
+        self.local_state.expiry_warning_blocks
+    }
+
+    /// Set (and persist) the expiry warning window; `0` disables warnings.
+    pub fn set_expiry_warning_blocks(&mut self, blocks: u32) {
+        self.local_state.expiry_warning_blocks = blocks;
+        self.save_local_state();
+    }
+
+    pub fn min_confirmations(&self) -> u32 {
+        self.local_state.min_confirmations
+    }
+
+    /// Set (and persist) the market transition confirmation threshold.
+    pub fn set_min_confirmations(&mut self, confirmations: u32) {
+        self.local_state.min_confirmations = confirmations;
+        self.save_local_state();
+    }
+
+    pub fn require_mainnet_confirmation(&self) -> bool {
+        self.local_state.require_mainnet_confirmation
+    }
+
+    /// Set (and persist) whether dangerous mainnet commands need confirmation.
+    pub fn set_require_mainnet_confirmation(&mut self, required: bool) {
+        self.local_state.require_mainnet_confirmation = required;
         self.save_local_state();
+    }
+
+    /// Effective fee UTXO selection strategy of every covenant operation.
+    pub fn fee_coin_selection(
+        &self,
+    ) -> Vec<(deadcat_sdk::FeeOperation, deadcat_sdk::CoinSelection)> {
+        deadcat_sdk::FeeOperation::ALL
+            .into_iter()
+            .map(|operation| {
+                let strategy = self
+                    .local_state
+                    .fee_coin_selection
+                    .get(operation.as_str())
+                    .and_then(|name| name.parse().ok())
+                    .unwrap_or_default();
+                (operation, strategy)
+            })
+            .collect()
+    }
+
+    /// Set (and persist) the fee UTXO selection strategy of `operation`.
+    pub fn set_fee_coin_selection(
+        &mut self,
+        operation: deadcat_sdk::FeeOperation,
+        strategy: deadcat_sdk::CoinSelection,
+    ) {
+        self.local_state.fee_coin_selection.insert(
+            operation.as_str().to_string(),
+            strategy.as_str().to_string(),
+        );
+        self.save_local_state();
+    }
+
+    /// Explicit receive-address indices handed out for the active wallet.
+    pub fn explicit_address_indices(&self) -> Vec<u32> {
+        self.explicit_address_key()
+            .and_then(|key| self.local_state.explicit_address_indices.get(&key))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Remember (and persist) that the explicit address at `index` was
+    /// handed out for the active wallet.
+    pub fn track_explicit_address_index(&mut self, index: u32) {
+        let Some(key) = self.explicit_address_key() else {
+            return;
+        };
+        let indices = self
+            .local_state
+            .explicit_address_indices
+            .entry(key)
+            .or_default();
+        if let Err(pos) = indices.binary_search(&index) {
+            indices.insert(pos, index);
+            self.save_local_state();
+        }
+    }
+
+    fn explicit_address_key(&self) -> Option<String> {
+        let network = self.network?;
+        Some(format!("{}/{}", self.profile, network.as_str()))
+    }
+
+    // --- Wallet profiles ---
+
+    pub fn wallet_profile(&self) -> &str {
+        &self.profile
+    }
+
+    /// Directory holding the active profile's network config, encrypted
+    /// mnemonic and store. The default profile uses the app data dir itself
+    /// so wallets created before profiles existed keep working.
+    pub fn profile_dir(&self) -> PathBuf {
+        profile_dir_for(&self.app_data_dir, &self.profile)
+    }
+
+    /// All profiles, default first, then named profiles alphabetically.
+    pub fn list_wallet_profiles(&self) -> Vec<WalletProfileInfo> {
+        let mut names: Vec<String> = fs::read_dir(self.app_data_dir.join(PROFILES_DIR))
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().is_dir())
+                    .filter_map(|entry| entry.file_name().into_string().ok())
+                    .filter(|name| validate_wallet_profile_name(name).is_ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        names.insert(0, DEFAULT_WALLET_PROFILE.to_string());
+
+        names
+            .into_iter()
+            .map(|name| WalletProfileInfo {
+                network: load_network_config_at(&profile_dir_for(&self.app_data_dir, &name))
+                    .map(|n| n.as_str().to_string()),
+                is_active: name == self.profile,
+                name,
+            })
+            .collect()
+    }
+
+    /// Create an empty named profile bound to `network`. Does not switch to it.
+    pub fn create_wallet_profile(&mut self, name: &str, network: Network) -> Result<(), String> {
+        validate_wallet_profile_name(name)?;
+        let dir = profile_dir_for(&self.app_data_dir, name);
+        if dir.exists() {
+            return Err(format!("wallet profile '{name}' already exists"));
+        }
+        fs::create_dir_all(&dir).map_err(|e| format!("failed to create profile: {e}"))?;
+        let config = serde_json::json!({ "network": network.as_str() });
+        let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+        fs::write(dir.join(CONFIG_FILE), json)
+            .map_err(|e| format!("failed to write profile config: {e}"))?;
+        Ok(())
+    }
+
+    /// Make `name` the active profile: drops the current persister and store,
+    /// marks the wallet locked, and reloads settings, network, mnemonic and
+    /// store from the new profile's directory. The caller is responsible for
+    /// locking and replacing the node.
+    pub fn switch_wallet_profile(&mut self, name: &str) -> Result<AppState, String> {
+        if name != DEFAULT_WALLET_PROFILE {
+            validate_wallet_profile_name(name)?;
+            if !profile_dir_for(&self.app_data_dir, name).is_dir() {
+                return Err(format!("wallet profile '{name}' does not exist"));
+            }
+        }
+
+        self.wallet_unlocked = false;
+        self.emitted_balance = None;
+        if let Some(persister) = self.persister.as_mut() {
+            persister.clear_cache();
+        }
+        self.persister = None;
+        self.store = None;
+        self.network = None;
+
+        self.profile = name.to_string();
+        self.save_active_wallet_profile();
+        self.local_state = Self::load_local_state(&self.profile_dir()).unwrap_or_default();
+        self.payment_swaps = self.local_state.payment_swaps.clone();
+        self.initialize();
+
+        self.bump_revision();
+        Ok(self.snapshot())
+    }
+
+    /// Delete the active named profile's directory (config, mnemonic, store
+    /// and settings) and switch back to the default profile. The default
+    /// profile lives in the app data dir and cannot be removed this way. The
+    /// caller is responsible for dropping the node first.
+    pub fn remove_active_wallet_profile(&mut self) -> Result<AppState, String> {
+        if self.profile == DEFAULT_WALLET_PROFILE {
+            return Err(format!(
+                "'{DEFAULT_WALLET_PROFILE}' profile cannot be removed"
+            ));
+        }
+        let dir = self.profile_dir();
+        let state = self.switch_wallet_profile(DEFAULT_WALLET_PROFILE)?;
+        fs::remove_dir_all(&dir).map_err(|e| format!("failed to remove profile: {e}"))?;
+        Ok(state)
+    }
+
+    /// This network's swaps, oldest first.
+    pub fn payment_swaps(&self) -> &[PaymentSwap] {
+        &self.payment_swaps
+    }
+
+    pub fn payment_swap(&self, id: &str) -> Option<PaymentSwap> {
+        self.payment_swaps
+            .iter()
+            .find(|swap| swap.id == id)
+            .cloned()
+    }
+
+    /// Read swaps from the store, or from the local state file while the
+    /// store is an in-memory fallback.
+    fn load_payment_swaps(&self) -> Vec<PaymentSwap> {
+        let Some(store) = self.durable_store() else {
+            return self.local_state.payment_swaps.clone();
+        };
+        let swaps = match store.lock() {
+            Ok(mut store) => store.list_payment_swaps(),
+            Err(_) => {
+                log::warn!("failed to lock store for payment swaps");
+                return Vec::new();
+            }
+        };
+        match swaps {
+            Ok(swaps) => swaps.into_iter().map(PaymentSwap::from).collect(),
+            Err(e) => {
+                log::warn!("failed to load payment swaps: {e}");
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn upsert_payment_swap(&mut self, swap: PaymentSwap) -> Result<(), String> {
+        if let Some(store) = self.durable_store() {
+            store
+                .lock()
+                .map_err(|_| "store lock failed".to_string())?
+                .upsert_payment_swap(&swap.clone().into())
+                .map_err(|e| format!("failed to save payment swap: {e}"))?;
+        } else {
+            upsert_swap(&mut self.local_state.payment_swaps, swap.clone());
+            self.save_local_state();
+        }
+        upsert_swap(&mut self.payment_swaps, swap);
         self.bump_revision();
+        Ok(())
     }
 
     // --- Persistence helpers ---
 
     fn load_network_config(&self) -> Option<Network> {
-        let path = self.app_data_dir.join(CONFIG_FILE);
-        let contents = fs::read_to_string(path).ok()?;
-        let config: serde_json::Value = serde_json::from_str(&contents).ok()?;
-        let network_str = config.get("network")?.as_str()?;
-        network_str.parse().ok()
+        load_network_config_at(&self.profile_dir())
     }
 
     fn save_network_config(&self, network: Network) {
-        let path = self.app_data_dir.join(CONFIG_FILE);
+        let path = self.profile_dir().join(CONFIG_FILE);
         if let Some(parent) = path.parent() {
             let _ = fs::create_dir_all(parent);
         }
@@ -340,13 +1513,640 @@ impl AppStateManager {
     fn load_local_state(dir: &Path) -> Option<LocalState> {
         let path = dir.join(LOCAL_STATE_FILE);
         let contents = fs::read_to_string(path).ok()?;
-        serde_json::from_str(&contents).ok()
+        let mut state: LocalState = serde_json::from_str(&contents).ok()?;
+        state.network_timeouts = state.network_timeouts.clamped();
+        Some(state)
     }
 
     fn save_local_state(&self) {
-        let path = self.app_data_dir.join(LOCAL_STATE_FILE);
-        if let Ok(json) = serde_json::to_string_pretty(&self.local_state) {
-            let _ = fs::write(path, json);
+        write_local_state(&self.profile_dir(), &self.local_state);
+    }
+
+    fn save_service_endpoints(&self) -> Result<(), String> {
+        let json =
+            serde_json::to_string_pretty(&self.service_endpoints).map_err(|e| e.to_string())?;
+        fs::write(self.app_data_dir.join(SERVICE_ENDPOINTS_FILE), json)
+            .map_err(|e| format!("failed to save service endpoints: {e}"))
+    }
+
+    /// Record the active profile in the default profile's state file, the
+    /// one read on launch.
+    fn save_active_wallet_profile(&self) {
+        let mut root_state = Self::load_local_state(&self.app_data_dir).unwrap_or_default();
+        root_state.active_wallet_profile =
+            (self.profile != DEFAULT_WALLET_PROFILE).then(|| self.profile.clone());
+        write_local_state(&self.app_data_dir, &root_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn unique_test_dir(name: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("deadcat-state-{name}-{nanos}"));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn sample_swap(id: &str) -> PaymentSwap {
+        PaymentSwap {
+            id: id.to_string(),
+            flow: "lightning_to_liquid".to_string(),
+            network: "testnet".to_string(),
+            status: SwapStatus::InvoiceSettled,
+            invoice_amount_sat: 100_000,
+            expected_amount_sat: Some(99_500),
+            lockup_address: None,
+            timeout_block_height: None,
+            pair_hash: None,
+            invoice: Some("lntb1".to_string()),
+            invoice_expiry_seconds: None,
+            invoice_expires_at: None,
+            lockup_txid: Some("aa".to_string()),
+            claim_txid: Some("bb".to_string()),
+            preimage_hash: Some("cc".to_string()),
+            preimage: Some("dd".to_string()),
+            claim_details: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: "2026-01-01T00:10:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn swap_receipt_fee_follows_flow_direction() {
+        let mut swap = sample_swap("swap");
+        let receipt = SwapReceipt::from_swap(&swap);
+        assert_eq!(receipt.sent_amount_sat, Some(100_000));
+        assert_eq!(receipt.received_amount_sat, Some(99_500));
+        assert_eq!(receipt.fee_sat, Some(500));
+        assert_eq!(receipt.preimage.as_deref(), Some("dd"));
+        assert!(!serde_json::to_string(&swap).unwrap().contains("\"dd\""));
+
+        swap.flow = "liquid_to_lightning".to_string();
+        swap.expected_amount_sat = Some(100_400);
+        let receipt = SwapReceipt::from_swap(&swap);
+        assert_eq!(receipt.sent_amount_sat, Some(100_400));
+        assert_eq!(receipt.received_amount_sat, Some(100_000));
+        assert_eq!(receipt.fee_sat, Some(400));
+    }
+
+    #[test]
+    fn swap_time_remaining_goes_negative_after_expiry() {
+        let mut swap = sample_swap("swap");
+        swap.timeout_block_height = Some(1_000);
+        swap.invoice_expires_at = Some("2026-01-01T01:00:00Z".to_string());
+        let at = |rfc3339: &str| {
+            chrono::DateTime::parse_from_rfc3339(rfc3339)
+                .unwrap()
+                .with_timezone(&chrono::Utc)
+        };
+
+        let remaining = SwapTimeRemaining::for_swap(&swap, Some(990), at("2026-01-01T00:00:00Z"));
+        assert_eq!(remaining.blocks_until_timeout, Some(10));
+        assert_eq!(remaining.seconds_until_invoice_expiry, Some(3_600));
+
+        let remaining = SwapTimeRemaining::for_swap(&swap, Some(1_005), at("2026-01-01T01:00:30Z"));
+        assert_eq!(remaining.blocks_until_timeout, Some(-5));
+        assert_eq!(remaining.seconds_until_invoice_expiry, Some(-30));
+
+        // Bitcoin lockup timeouts aren't comparable with the Liquid tip.
+        swap.flow = "bitcoin_to_liquid".to_string();
+        let remaining = SwapTimeRemaining::for_swap(&swap, Some(990), at("2026-01-01T00:00:00Z"));
+        assert_eq!(remaining.blocks_until_timeout, None);
+    }
+
+    #[test]
+    fn auto_lock_timeout_defaults_and_persists() {
+        let dir = unique_test_dir("auto-lock");
+        let mut mgr = AppStateManager::new(dir.clone());
+        assert_eq!(
+            mgr.auto_lock_timeout_secs(),
+            Some(DEFAULT_AUTO_LOCK_TIMEOUT_SECS)
+        );
+
+        mgr.set_auto_lock_timeout(Some(600)).unwrap();
+        assert_eq!(
+            AppStateManager::new(dir.clone()).auto_lock_timeout_secs(),
+            Some(600)
+        );
+
+        mgr.set_auto_lock_timeout(None).unwrap();
+        assert_eq!(
+            AppStateManager::new(dir.clone()).auto_lock_timeout_secs(),
+            None
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn auto_lock_timeout_rejects_values_below_minimum() {
+        let dir = unique_test_dir("auto-lock-min");
+        let mut mgr = AppStateManager::new(dir.clone());
+        assert!(mgr
+            .set_auto_lock_timeout(Some(MIN_AUTO_LOCK_TIMEOUT_SECS - 1))
+            .is_err());
+        assert_eq!(
+            mgr.auto_lock_timeout_secs(),
+            Some(DEFAULT_AUTO_LOCK_TIMEOUT_SECS)
+        );
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn chain_tip_poll_interval_defaults_validates_and_persists() {
+        let dir = unique_test_dir("chain-tip-poll");
+        let mut mgr = AppStateManager::new(dir.clone());
+        assert_eq!(mgr.chain_tip_poll_secs(), DEFAULT_CHAIN_TIP_POLL_SECS);
+
+        assert!(mgr
+            .set_chain_tip_poll_secs(MIN_CHAIN_TIP_POLL_SECS - 1)
+            .is_err());
+        mgr.set_chain_tip_poll_secs(120).unwrap();
+        assert_eq!(AppStateManager::new(dir.clone()).chain_tip_poll_secs(), 120);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn network_timeouts_default_validate_and_persist() {
+        let dir = unique_test_dir("network-timeouts");
+        let mut mgr = AppStateManager::new(dir.clone());
+        assert_eq!(mgr.network_timeouts(), NetworkTimeouts::default());
+
+        let slow = NetworkTimeouts {
+            electrum: 120,
+            esplora: 60,
+            boltz: 45,
+            nostr: 90,
+            nostr_connect: 20,
+        };
+        mgr.set_network_timeouts(slow).unwrap();
+        assert_eq!(AppStateManager::new(dir.clone()).network_timeouts(), slow);
+
+        for invalid in [
+            NetworkTimeouts { nostr: 0, ..slow },
+            NetworkTimeouts {
+                electrum: MAX_NETWORK_TIMEOUT_SECS + 1,
+                ..slow
+            },
+        ] {
+            assert!(mgr.set_network_timeouts(invalid).is_err());
         }
+        assert_eq!(mgr.network_timeouts(), slow);
+
+        // Out-of-range values written to the file by hand are clamped on load.
+        let path = dir.join(LOCAL_STATE_FILE);
+        let mut json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        json["networkTimeouts"]["nostr"] = 0.into();
+        json["networkTimeouts"]["electrum"] = 100_000.into();
+        fs::write(&path, json.to_string()).unwrap();
+        let loaded = AppStateManager::new(dir.clone()).network_timeouts();
+        assert_eq!(loaded.nostr, MIN_NETWORK_TIMEOUT_SECS);
+        assert_eq!(loaded.electrum, MAX_NETWORK_TIMEOUT_SECS);
+        assert_eq!(loaded.boltz, slow.boltz);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn expiry_warning_window_defaults_and_persists() {
+        let dir = unique_test_dir("expiry-warning");
+        let mut mgr = AppStateManager::new(dir.clone());
+        assert_eq!(mgr.expiry_warning_blocks(), DEFAULT_EXPIRY_WARNING_BLOCKS);
+
+        mgr.set_expiry_warning_blocks(0);
+        assert_eq!(AppStateManager::new(dir.clone()).expiry_warning_blocks(), 0);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn fee_coin_selection_defaults_and_persists() {
+        use deadcat_sdk::{CoinSelection, FeeOperation};
+
+        let dir = unique_test_dir("fee-coin-selection");
+        let mut mgr = AppStateManager::new(dir.clone());
+        assert!(mgr
+            .fee_coin_selection()
+            .iter()
+            .all(|(_, strategy)| *strategy == CoinSelection::SmallestFirst));
+
+        mgr.set_fee_coin_selection(FeeOperation::FillLimitOrder, CoinSelection::LargestFirst);
+        let reloaded = AppStateManager::new(dir.clone()).fee_coin_selection();
+        assert!(reloaded.contains(&(FeeOperation::FillLimitOrder, CoinSelection::LargestFirst)));
+        assert!(reloaded.contains(&(FeeOperation::IssueTokens, CoinSelection::SmallestFirst)));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn wallet_profiles_are_isolated_and_switchable() {
+        let dir = unique_test_dir("wallet-profiles");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_network(Network::Testnet);
+        mgr.persister_mut()
+            .unwrap()
+            .save(MNEMONIC, "pw", deadcat_sdk::DescriptorTemplate::default())
+            .unwrap();
+
+        assert!(mgr
+            .create_wallet_profile("default", Network::Testnet)
+            .is_err());
+        assert!(mgr
+            .create_wallet_profile("../escape", Network::Testnet)
+            .is_err());
+        mgr.create_wallet_profile("savings", Network::Mainnet)
+            .unwrap();
+        assert!(mgr
+            .create_wallet_profile("savings", Network::Mainnet)
+            .is_err());
+
+        let state = mgr.switch_wallet_profile("savings").unwrap();
+        assert_eq!(state.wallet_profile, "savings");
+        assert_eq!(mgr.network(), Some(Network::Mainnet));
+        assert!(matches!(mgr.wallet_status(), WalletStatus::NotCreated));
+
+        let profiles = mgr.list_wallet_profiles();
+        let names: Vec<_> = profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["default", "savings"]);
+        assert!(profiles[1].is_active);
+
+        // The active profile survives a restart; switching back finds the
+        // original wallet untouched.
+        let mut reopened = AppStateManager::new(dir.clone());
+        assert_eq!(reopened.wallet_profile(), "savings");
+        reopened
+            .switch_wallet_profile(DEFAULT_WALLET_PROFILE)
+            .unwrap();
+        assert_eq!(reopened.network(), Some(Network::Testnet));
+        assert!(matches!(reopened.wallet_status(), WalletStatus::Locked));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn removing_a_profile_deletes_its_directory() {
+        let dir = unique_test_dir("remove-profile");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_network(Network::Testnet);
+        assert!(mgr.remove_active_wallet_profile().is_err());
+
+        mgr.create_wallet_profile("savings", Network::Testnet)
+            .unwrap();
+        mgr.switch_wallet_profile("savings").unwrap();
+        let profile_dir = mgr.profile_dir();
+        mgr.persister_mut()
+            .unwrap()
+            .save(MNEMONIC, "pw", deadcat_sdk::DescriptorTemplate::default())
+            .unwrap();
+
+        let state = mgr.remove_active_wallet_profile().unwrap();
+        assert_eq!(state.wallet_profile, DEFAULT_WALLET_PROFILE);
+        assert!(!profile_dir.exists());
+        let names: Vec<_> = mgr
+            .list_wallet_profiles()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(names, [DEFAULT_WALLET_PROFILE]);
+        assert_eq!(
+            AppStateManager::new(dir.clone()).wallet_profile(),
+            DEFAULT_WALLET_PROFILE
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn settings_are_scoped_to_wallet_profile() {
+        let dir = unique_test_dir("profile-settings");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_auto_lock_timeout(Some(600)).unwrap();
+        mgr.create_wallet_profile("savings", Network::Testnet)
+            .unwrap();
+
+        mgr.switch_wallet_profile("savings").unwrap();
+        assert_eq!(
+            mgr.auto_lock_timeout_secs(),
+            Some(DEFAULT_AUTO_LOCK_TIMEOUT_SECS)
+        );
+        mgr.set_lock_on_background(true);
+
+        let mut reopened = AppStateManager::new(dir.clone());
+        assert_eq!(reopened.wallet_profile(), "savings");
+        assert!(reopened.lock_on_background());
+
+        reopened
+            .switch_wallet_profile(DEFAULT_WALLET_PROFILE)
+            .unwrap();
+        assert_eq!(reopened.auto_lock_timeout_secs(), Some(600));
+        assert!(!reopened.lock_on_background());
+        assert_eq!(
+            AppStateManager::new(dir.clone()).wallet_profile(),
+            DEFAULT_WALLET_PROFILE
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn explicit_address_indices_are_scoped_to_wallet() {
+        let dir = unique_test_dir("explicit-addresses");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.track_explicit_address_index(3);
+        assert!(mgr.explicit_address_indices().is_empty());
+
+        mgr.set_network(Network::Testnet);
+        mgr.track_explicit_address_index(5);
+        mgr.track_explicit_address_index(2);
+        mgr.track_explicit_address_index(5);
+        assert_eq!(
+            AppStateManager::new(dir.clone()).explicit_address_indices(),
+            vec![2, 5]
+        );
+
+        mgr.create_wallet_profile("savings", Network::Testnet)
+            .unwrap();
+        mgr.switch_wallet_profile("savings").unwrap();
+        assert!(mgr.explicit_address_indices().is_empty());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn auto_lock_tick_scales_with_timeout() {
+        assert_eq!(auto_lock_tick_secs(Some(300)), 60);
+        assert_eq!(auto_lock_tick_secs(Some(30)), 10);
+        assert_eq!(auto_lock_tick_secs(None), AUTO_LOCK_IDLE_TICK_SECS);
+    }
+
+    #[test]
+    fn background_lock_waits_for_in_flight_operations() {
+        let dir = unique_test_dir("background-lock");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_wallet_unlocked(true);
+        assert!(!mgr.should_lock_on_background());
+
+        mgr.set_lock_on_background(true);
+        assert!(mgr.should_lock_on_background());
+
+        mgr.begin_operation();
+        assert!(!mgr.should_lock_on_background());
+        mgr.end_operation();
+        assert!(mgr.should_lock_on_background());
+        assert!(AppStateManager::new(dir.clone()).lock_on_background());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn boltz_url_is_validated_and_shared_across_profiles() {
+        assert_eq!(
+            validate_api_url("Boltz", "http://localhost:9001/v2/").unwrap(),
+            "http://localhost:9001/v2"
+        );
+        assert!(validate_api_url("Boltz", "localhost:9001").is_err());
+        assert!(validate_api_url("Boltz", "https:///v2").is_err());
+
+        let dir = unique_test_dir("boltz-url");
+        let mut mgr = AppStateManager::new(dir.clone());
+        assert!(mgr.set_boltz_api_url(Some("http://x".into())).is_err());
+        mgr.network = Some(Network::Regtest);
+        mgr.set_boltz_api_url(Some("http://localhost:9001/v2".into()))
+            .unwrap();
+        let (_, config) = mgr.boltz_service_config().unwrap();
+        assert_eq!(config.api_url.as_deref(), Some("http://localhost:9001/v2"));
+
+        // Endpoints belong to the app, not to a wallet profile.
+        mgr.create_wallet_profile("savings", Network::Regtest)
+            .unwrap();
+        mgr.switch_wallet_profile("savings").unwrap();
+        let (_, config) = mgr.boltz_service_config().unwrap();
+        assert_eq!(config.api_url.as_deref(), Some("http://localhost:9001/v2"));
+
+        mgr.network = Some(Network::Testnet);
+        assert!(mgr.boltz_service_config().unwrap().1.api_url.is_none());
+
+        mgr.network = Some(Network::Regtest);
+        mgr.set_boltz_api_url(None).unwrap();
+        assert!(mgr.boltz_service_config().unwrap().1.api_url.is_none());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn esplora_url_defaults_per_network_and_can_be_overridden() {
+        let dir = unique_test_dir("esplora-url");
+        let mut mgr = AppStateManager::new(dir.clone());
+        assert_eq!(
+            mgr.esplora_api_url(Network::Regtest),
+            to_sdk_network(Network::Regtest).esplora_url()
+        );
+        assert!(mgr.set_esplora_api_url(Some("http://x".into())).is_err());
+
+        mgr.network = Some(Network::Regtest);
+        assert!(mgr
+            .set_esplora_api_url(Some("localhost:3002".into()))
+            .is_err());
+        mgr.set_esplora_api_url(Some("http://127.0.0.1:3002/".into()))
+            .unwrap();
+        assert_eq!(
+            AppStateManager::new(dir.clone()).esplora_api_url(Network::Regtest),
+            "http://127.0.0.1:3002"
+        );
+        assert_eq!(
+            mgr.esplora_api_url(Network::Testnet),
+            to_sdk_network(Network::Testnet).esplora_url()
+        );
+
+        mgr.set_esplora_api_url(None).unwrap();
+        assert_eq!(
+            mgr.esplora_api_url(Network::Regtest),
+            to_sdk_network(Network::Regtest).esplora_url()
+        );
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn reset_store_empties_database_behind_shared_handle() {
+        let dir = unique_test_dir("reset-store");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_network(Network::Regtest);
+        let store = mgr.store().cloned().unwrap();
+        store
+            .lock()
+            .unwrap()
+            .cache_transaction(&[7; 32], b"raw")
+            .unwrap();
+        store
+            .lock()
+            .unwrap()
+            .log_action(deadcat_store::ActionKind::Swap, None, Some("11"), "{}")
+            .unwrap();
+
+        assert!(mgr.reset_store().unwrap().is_empty());
+
+        let mut store = store.lock().unwrap();
+        assert!(store.get_cached_transaction(&[7; 32]).unwrap().is_none());
+        let actions = store
+            .get_action_log(&deadcat_store::ActionLogFilter::default())
+            .unwrap();
+        assert_eq!(actions.len(), 1);
+        assert!(store.integrity_check().unwrap());
+        drop(store);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn store_cutover_carries_over_payment_swaps() {
+        let dir = unique_test_dir("store-cutover");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_network(Network::Regtest);
+        let mut swap = sample_swap("kept");
+        swap.network = Network::Regtest.as_str().to_string();
+        mgr.upsert_payment_swap(swap).unwrap();
+        let store_dir = mgr.profile_dir().join(Network::Regtest.as_str());
+        drop(mgr);
+        fs::remove_file(store_dir.join(STORE_CUTOVER_MARKER_FILE)).unwrap();
+
+        let mut reopened = AppStateManager::new(dir.clone());
+        reopened.initialize();
+        assert_eq!(reopened.payment_swap("kept").unwrap().id, "kept");
+        assert!(store_dir.join(STORE_CUTOVER_MARKER_FILE).exists());
+        assert!(!store_dir.join("deadcat.db.pre-cutover").exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn payment_swaps_move_into_store_and_survive_reset() {
+        let dir = unique_test_dir("payment-swaps");
+        let mut legacy = LocalState::default();
+        let mut regtest_swap = sample_swap("legacy");
+        regtest_swap.network = Network::Regtest.as_str().to_string();
+        legacy.payment_swaps = vec![regtest_swap.clone(), sample_swap("other-network")];
+        fs::write(
+            dir.join(LOCAL_STATE_FILE),
+            serde_json::to_string(&legacy).unwrap(),
+        )
+        .unwrap();
+
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_network(Network::Regtest);
+        let mut fresh = sample_swap("fresh");
+        fresh.network = Network::Regtest.as_str().to_string();
+        fresh.created_at = "2026-01-02T00:00:00Z".to_string();
+        mgr.upsert_payment_swap(fresh.clone()).unwrap();
+        let ids = |mgr: &AppStateManager| -> Vec<String> {
+            mgr.payment_swaps()
+                .iter()
+                .map(|swap| swap.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&mgr), ["legacy", "fresh"]);
+        assert_eq!(mgr.payment_swap("legacy").unwrap().preimage(), Some("dd"));
+        // Only the imported swap left the state file, preimage included.
+        assert_eq!(mgr.local_state.payment_swaps.len(), 1);
+        let saved = fs::read_to_string(dir.join(LOCAL_STATE_FILE)).unwrap();
+        assert!(saved.contains("\"preimage\": \"dd\""));
+
+        assert!(mgr.reset_store().unwrap().is_empty());
+        assert_eq!(ids(&mgr), ["legacy", "fresh"]);
+
+        let mut reopened = AppStateManager::new(dir.clone());
+        reopened.initialize();
+        assert_eq!(reopened.payment_swap("legacy").unwrap().id, "legacy");
+        assert_eq!(ids(&reopened), ["legacy", "fresh"]);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn reset_store_keeps_on_disk_swaps_while_using_the_fallback() {
+        let dir = unique_test_dir("reset-fallback");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_network(Network::Regtest);
+        let mut on_disk = sample_swap("on-disk");
+        on_disk.network = Network::Regtest.as_str().to_string();
+        mgr.upsert_payment_swap(on_disk).unwrap();
+
+        // As if the on-disk store had failed to migrate: the open store is
+        // the in-memory fallback and new swaps go to the state file.
+        mgr.store = Some(Arc::new(std::sync::Mutex::new(
+            deadcat_store::DeadcatStore::open_in_memory().unwrap(),
+        )));
+        mgr.store_migration_error = Some("migration failed".to_string());
+        let mut pending = sample_swap("fallback");
+        pending.network = Network::Regtest.as_str().to_string();
+        pending.created_at = "2026-01-02T00:00:00Z".to_string();
+        mgr.upsert_payment_swap(pending).unwrap();
+
+        assert!(mgr.reset_store().unwrap().is_empty());
+        assert_eq!(mgr.store_migration_error(), None);
+        let ids: Vec<String> = mgr.payment_swaps().iter().map(|s| s.id.clone()).collect();
+        assert_eq!(ids, ["on-disk", "fallback"]);
+        assert!(mgr.local_state.payment_swaps.is_empty());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn disabled_auto_lock_never_fires() {
+        let dir = unique_test_dir("auto-lock-never");
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_auto_lock_timeout(None).unwrap();
+        mgr.set_wallet_unlocked(true);
+        mgr.last_activity = Instant::now() - std::time::Duration::from_secs(3600);
+        assert!(!mgr.check_auto_lock());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn record_balance_reports_only_changed_assets() {
+        let dir = unique_test_dir("record-balance");
+        let mut mgr = AppStateManager::new(dir.clone());
+        let balance = |entries: &[(&str, u64)]| -> HashMap<String, u64> {
+            entries.iter().map(|(a, v)| (a.to_string(), *v)).collect()
+        };
+
+        assert!(mgr
+            .record_balance(&balance(&[("lbtc", 100), ("yes", 5)]))
+            .is_none());
+
+        let update = mgr
+            .record_balance(&balance(&[("lbtc", 90), ("no", 3)]))
+            .unwrap();
+        assert_eq!(
+            update.changed,
+            balance(&[("lbtc", 90), ("no", 3), ("yes", 0)])
+        );
+
+        mgr.set_wallet_unlocked(false);
+        assert!(mgr.record_balance(&balance(&[("lbtc", 90)])).is_none());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn store_of_another_network_is_not_opened() {
+        let dir = unique_test_dir("network-marker");
+        let regtest_dir = dir.join(Network::Regtest.as_str());
+        fs::create_dir_all(&regtest_dir).unwrap();
+        fs::write(
+            regtest_dir.join(deadcat_sdk::NETWORK_MARKER_FILE),
+            "testnet",
+        )
+        .unwrap();
+
+        let mut mgr = AppStateManager::new(dir.clone());
+        mgr.set_network(Network::Regtest);
+        assert!(mgr.store_migration_error().is_some());
+        assert!(!regtest_dir.join("deadcat.db").exists());
+        let _ = fs::remove_dir_all(dir);
     }
 }
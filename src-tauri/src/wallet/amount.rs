@@ -0,0 +1,129 @@
+//! Conversion between raw on-chain amounts and their human-readable form.
+//!
+//! Every asset is stored as an integer number of base units. The policy asset
+//! (L-BTC) has 8 decimal places; market tokens and any asset we have no
+//! metadata for are indivisible.
+
+use thiserror::Error;
+
+/// Decimal places of the policy asset (1 L-BTC = 100,000,000 sats).
+pub const LBTC_PRECISION: u8 = 8;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum AmountError {
+    #[error("invalid amount: {0:?}")]
+    InvalidDecimal(String),
+
+    #[error("amount has more than {precision} decimal places")]
+    TooManyDecimals { precision: u8 },
+
+    #[error("amount is too large")]
+    Overflow,
+}
+
+/// Decimal places used to display `asset_id`.
+pub fn asset_precision(asset_id: &str, policy_asset_id: &str) -> u8 {
+    if asset_id.eq_ignore_ascii_case(policy_asset_id) {
+        LBTC_PRECISION
+    } else {
+        0
+    }
+}
+
+/// Render `raw_amount` base units with exactly `precision` decimal places.
+pub fn format_amount(raw_amount: u64, precision: u8) -> String {
+    if precision == 0 {
+        return raw_amount.to_string();
+    }
+    let digits = format!("{raw_amount:0>width$}", width = precision as usize + 1);
+    let (whole, frac) = digits.split_at(digits.len() - precision as usize);
+    format!("{whole}.{frac}")
+}
+
+/// Parse a human-entered decimal string into base units.
+///
+/// Accepts plain decimals such as `"1"`, `"0.5"` or `".25"`; signs, exponents,
+/// separators and more than `precision` decimal places are rejected.
+pub fn parse_amount(human: &str, precision: u8) -> Result<u64, AmountError> {
+    let trimmed = human.trim();
+    let invalid = || AmountError::InvalidDecimal(human.to_string());
+
+    let (whole, frac) = match trimmed.split_once('.') {
+        Some((whole, frac)) => (whole, frac),
+        None => (trimmed, ""),
+    };
+    if whole.is_empty() && frac.is_empty() {
+        return Err(invalid());
+    }
+    if !whole
+        .bytes()
+        .chain(frac.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    if frac.len() > precision as usize {
+        return Err(AmountError::TooManyDecimals { precision });
+    }
+
+    let scale = 10u64
+        .checked_pow(u32::from(precision))
+        .ok_or(AmountError::Overflow)?;
+    let whole_units = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<u64>().map_err(|_| AmountError::Overflow)?
+    };
+    let frac_units = if frac.is_empty() {
+        0
+    } else {
+        let padded = format!("{frac:0<width$}", width = precision as usize);
+        padded.parse::<u64>().map_err(|_| AmountError::Overflow)?
+    };
+
+    whole_units
+        .checked_mul(scale)
+        .and_then(|units| units.checked_add(frac_units))
+        .ok_or(AmountError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_pads_and_places_decimal_point() {
+        assert_eq!(format_amount(150_000_000, 8), "1.50000000");
+        assert_eq!(format_amount(42, 8), "0.00000042");
+        assert_eq!(format_amount(0, 8), "0.00000000");
+        assert_eq!(format_amount(7, 0), "7");
+    }
+
+    #[test]
+    fn parse_round_trips_formatted_amounts() {
+        for raw in [0, 1, 42, 150_000_000, u64::MAX] {
+            assert_eq!(parse_amount(&format_amount(raw, 8), 8), Ok(raw));
+        }
+        assert_eq!(parse_amount(" .5 ", 8), Ok(50_000_000));
+        assert_eq!(parse_amount("3.", 8), Ok(300_000_000));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_and_oversized_input() {
+        for bad in ["", ".", "-1", "1e8", "1,000", "1.2.3", "abc"] {
+            assert!(matches!(
+                parse_amount(bad, 8),
+                Err(AmountError::InvalidDecimal(_))
+            ));
+        }
+        assert_eq!(
+            parse_amount("0.000000001", 8),
+            Err(AmountError::TooManyDecimals { precision: 8 })
+        );
+        assert_eq!(
+            parse_amount("1.5", 0),
+            Err(AmountError::TooManyDecimals { precision: 0 })
+        );
+        assert_eq!(parse_amount("184467440738", 8), Err(AmountError::Overflow));
+    }
+}
@@ -1,2 +1,3 @@
+pub mod amount;
 pub mod persister;
 pub mod types;
@@ -5,6 +5,7 @@ use aes_gcm::aead::Aead;
 use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
 use base64::engine::general_purpose::STANDARD as BASE64;
 use base64::Engine;
+use deadcat_sdk::DescriptorTemplate;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use zeroize::Zeroizing;
@@ -24,6 +25,9 @@ pub enum WalletPersistError {
 
     #[error("Wrong password")]
     WrongPassword,
+
+    #[error("Invalid descriptor template: {0}")]
+    DescriptorTemplate(String),
 }
 
 #[derive(Serialize, Deserialize)]
@@ -31,6 +35,11 @@ struct EncryptedWalletFile {
     salt: String,
     nonce: String,
     ciphertext: String,
+    /// Descriptor shape the wallet was created with. Stored in the clear so
+    /// unlock can rebuild the same descriptor; absent in files written before
+    /// templates existed, which all use the default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    descriptor_template: Option<String>,
 }
 
 pub struct MnemonicPersister {
@@ -85,7 +94,24 @@ impl MnemonicPersister {
         Ok(())
     }
 
-    pub fn save(&self, mnemonic: &str, password: &str) -> Result<(), WalletPersistError> {
+    /// Read the descriptor template stored with the wallet file.
+    pub fn descriptor_template(&self) -> Result<DescriptorTemplate, WalletPersistError> {
+        let contents = fs::read_to_string(&self.file_path)?;
+        let file: EncryptedWalletFile = serde_json::from_str(&contents)?;
+        match file.descriptor_template {
+            Some(template) => template.parse().map_err(|e: deadcat_sdk::Error| {
+                WalletPersistError::DescriptorTemplate(e.to_string())
+            }),
+            None => Ok(DescriptorTemplate::default()),
+        }
+    }
+
+    pub fn save(
+        &self,
+        mnemonic: &str,
+        password: &str,
+        descriptor_template: DescriptorTemplate,
+    ) -> Result<(), WalletPersistError> {
         let salt: [u8; 16] = rand::random();
 
         let mut key_bytes = [0u8; 32];
@@ -105,16 +131,36 @@ impl MnemonicPersister {
             salt: BASE64.encode(salt),
             nonce: BASE64.encode(nonce_bytes),
             ciphertext: BASE64.encode(ciphertext),
+            descriptor_template: Some(descriptor_template.as_str().to_string()),
         };
 
         if let Some(parent) = self.file_path.parent() {
             fs::create_dir_all(parent)?;
         }
         let json = serde_json::to_string_pretty(&file)?;
-        fs::write(&self.file_path, json)?;
+        // Write to a sibling temp file and rename over the original so a
+        // crash mid-write never leaves a truncated wallet file behind.
+        let tmp_path = self.file_path.with_extension("json.tmp");
+        fs::write(&tmp_path, json)?;
+        fs::rename(&tmp_path, &self.file_path)?;
         Ok(())
     }
 
+    /// Re-encrypt the stored mnemonic under `new_password` with a fresh salt.
+    ///
+    /// The old password is verified first; on mismatch the wallet file is left
+    /// untouched. The mnemonic itself doesn't change, so a cached copy stays
+    /// valid and an unlocked wallet stays unlocked.
+    pub fn change_password(
+        &mut self,
+        old_password: &str,
+        new_password: &str,
+    ) -> Result<(), WalletPersistError> {
+        let mnemonic = Zeroizing::new(self.decrypt(old_password)?);
+        let template = self.descriptor_template()?;
+        self.save(&mnemonic, new_password, template)
+    }
+
     /// Decrypt and return a single word by index without caching the full mnemonic.
     pub fn load_word(
         &mut self,
@@ -132,6 +178,31 @@ impl MnemonicPersister {
             .ok_or_else(|| WalletPersistError::Crypto("word index out of range".to_string()))
     }
 
+    /// Check user-supplied `(index, word)` pairs against the stored mnemonic
+    /// and return the indices that did not match. Out-of-range indices count
+    /// as mismatches.
+    ///
+    /// The file is decrypted even when the mnemonic is cached, so a wrong
+    /// password fails the check.
+    pub fn verify_words(
+        &self,
+        password: &str,
+        answers: &[(usize, String)],
+    ) -> Result<Vec<usize>, WalletPersistError> {
+        let mnemonic = self.decrypt_uncached(password)?;
+        let words: Vec<&str> = mnemonic.split_whitespace().collect();
+        let mut wrong = Vec::new();
+        for (index, answer) in answers {
+            let matches = words
+                .get(*index)
+                .is_some_and(|word| *word == answer.trim().to_lowercase());
+            if !matches {
+                wrong.push(*index);
+            }
+        }
+        Ok(wrong)
+    }
+
     /// Decrypt and return the word count without exposing the full mnemonic.
     pub fn load_word_count(&mut self, password: &str) -> Result<usize, WalletPersistError> {
         if let Some(count) = self.cached_word_count() {
@@ -143,6 +214,22 @@ impl MnemonicPersister {
     }
 
     pub fn load(&mut self, password: &str) -> Result<String, WalletPersistError> {
+        let mnemonic_str = self.decrypt(password)?;
+        let ret = mnemonic_str.clone();
+        self.cached_mnemonic = Some(Zeroizing::new(mnemonic_str));
+        Ok(ret)
+    }
+
+    /// Decrypt the mnemonic without caching it, for one-off checks that
+    /// must not leave the seed in memory while the wallet is locked.
+    pub fn decrypt_uncached(
+        &self,
+        password: &str,
+    ) -> Result<Zeroizing<String>, WalletPersistError> {
+        Ok(Zeroizing::new(self.decrypt(password)?))
+    }
+
+    fn decrypt(&self, password: &str) -> Result<String, WalletPersistError> {
         let contents = fs::read_to_string(&self.file_path)?;
         let file: EncryptedWalletFile = serde_json::from_str(&contents)?;
 
@@ -169,10 +256,151 @@ impl MnemonicPersister {
             .decrypt(nonce, ciphertext.as_ref())
             .map_err(|_| WalletPersistError::WrongPassword)?;
 
-        let mnemonic_str =
-            String::from_utf8(plaintext).map_err(|e| WalletPersistError::Crypto(e.to_string()))?;
-        let ret = mnemonic_str.clone();
-        self.cached_mnemonic = Some(Zeroizing::new(mnemonic_str));
-        Ok(ret)
+        String::from_utf8(plaintext).map_err(|e| WalletPersistError::Crypto(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn test_persister(name: &str) -> (PathBuf, MnemonicPersister) {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("deadcat-persister-{name}-{nanos}"));
+        let persister = MnemonicPersister::new(&dir, "testnet");
+        (dir, persister)
+    }
+
+    #[test]
+    fn change_password_reencrypts_under_new_password() {
+        let (dir, mut persister) = test_persister("change-password");
+        persister
+            .save(MNEMONIC, "old", DescriptorTemplate::default())
+            .unwrap();
+        persister.load("old").unwrap();
+
+        persister.change_password("old", "new").unwrap();
+        assert_eq!(persister.cached(), Some(MNEMONIC));
+        assert!(matches!(
+            persister.load("old"),
+            Err(WalletPersistError::WrongPassword)
+        ));
+        assert_eq!(persister.load("new").unwrap(), MNEMONIC);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn descriptor_template_survives_password_change() {
+        let (dir, mut persister) = test_persister("descriptor-template");
+        persister
+            .save(MNEMONIC, "old", DescriptorTemplate::ShWpkh)
+            .unwrap();
+        assert_eq!(
+            persister.descriptor_template().unwrap(),
+            DescriptorTemplate::ShWpkh
+        );
+
+        persister.change_password("old", "new").unwrap();
+        assert_eq!(
+            persister.descriptor_template().unwrap(),
+            DescriptorTemplate::ShWpkh
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn wallet_file_without_template_uses_default() {
+        let (dir, persister) = test_persister("legacy-template");
+        persister
+            .save(MNEMONIC, "pw", DescriptorTemplate::ShWpkh)
+            .unwrap();
+        let mut file: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&persister.file_path).unwrap()).unwrap();
+        file.as_object_mut().unwrap().remove("descriptor_template");
+        fs::write(&persister.file_path, file.to_string()).unwrap();
+
+        assert_eq!(
+            persister.descriptor_template().unwrap(),
+            DescriptorTemplate::default()
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn verify_words_reports_mismatched_indices() {
+        let (dir, mut persister) = test_persister("verify-words");
+        persister
+            .save(MNEMONIC, "pw", DescriptorTemplate::default())
+            .unwrap();
+
+        let answers = vec![
+            (0, "abandon".to_string()),
+            (11, " About ".to_string()),
+            (3, "zoo".to_string()),
+            (12, "abandon".to_string()),
+        ];
+        assert_eq!(persister.verify_words("pw", &answers).unwrap(), vec![3, 12]);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn verify_words_checks_password_while_unlocked() {
+        let (dir, mut persister) = test_persister("verify-words-unlocked");
+        persister
+            .save(MNEMONIC, "pw", DescriptorTemplate::default())
+            .unwrap();
+        persister.load("pw").unwrap();
+
+        let answers = vec![(0, "abandon".to_string())];
+        assert!(matches!(
+            persister.verify_words("wrong", &answers),
+            Err(WalletPersistError::WrongPassword)
+        ));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn decrypt_uncached_leaves_a_locked_wallet_uncached() {
+        let (dir, mut persister) = test_persister("decrypt-uncached");
+        persister
+            .save(MNEMONIC, "pw", DescriptorTemplate::default())
+            .unwrap();
+        persister.clear_cache();
+
+        assert_eq!(persister.decrypt_uncached("pw").unwrap().as_str(), MNEMONIC);
+        assert!(persister.cached().is_none());
+        assert!(matches!(
+            persister.decrypt_uncached("wrong"),
+            Err(WalletPersistError::WrongPassword)
+        ));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn change_password_with_wrong_old_password_leaves_file_untouched() {
+        let (dir, mut persister) = test_persister("change-password-wrong");
+        persister
+            .save(MNEMONIC, "old", DescriptorTemplate::default())
+            .unwrap();
+        let before = fs::read_to_string(&persister.file_path).unwrap();
+
+        assert!(matches!(
+            persister.change_password("wrong", "new"),
+            Err(WalletPersistError::WrongPassword)
+        ));
+        assert_eq!(fs::read_to_string(&persister.file_path).unwrap(), before);
+
+        let _ = fs::remove_dir_all(dir);
     }
 }
@@ -1,5 +1,4 @@
 use serde::Serialize;
-use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -12,8 +11,34 @@ pub enum WalletStatus {
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WalletBalance {
-    /// Map of asset_id hex -> satoshi amount
-    pub assets: HashMap<String, u64>,
+    /// (asset_id hex, satoshi amount) pairs in `sorted_balance` order.
+    pub assets: Vec<(String, u64)>,
+}
+
+impl WalletBalance {
+    pub fn new(assets: impl IntoIterator<Item = (String, u64)>, policy_asset_id: &str) -> Self {
+        Self {
+            assets: sorted_balance(assets, policy_asset_id),
+        }
+    }
+}
+
+/// Drop zero amounts and order the rest for display: the policy asset first,
+/// then the remaining assets by asset id.
+pub fn sorted_balance<T: Default + PartialEq>(
+    assets: impl IntoIterator<Item = (String, T)>,
+    policy_asset_id: &str,
+) -> Vec<(String, T)> {
+    let mut sorted: Vec<(String, T)> = assets
+        .into_iter()
+        .filter(|(_, amount)| *amount != T::default())
+        .collect();
+    sorted.sort_by(|(a, _), (b, _)| {
+        (a != policy_asset_id)
+            .cmp(&(b != policy_asset_id))
+            .then_with(|| a.cmp(b))
+    });
+    sorted
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -23,6 +48,15 @@ pub struct WalletAddress {
     pub address: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplicitWalletAddress {
+    pub index: u32,
+    pub address: String,
+    /// Privacy warning to display alongside the address.
+    pub warning: &'static str,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WalletUtxo {
@@ -39,6 +73,9 @@ pub struct WalletTransaction {
     pub txid: String,
     /// Net L-BTC balance change in satoshis (positive = received, negative = sent)
     pub balance_change: i64,
+    /// Net change of every asset the transaction moved, in `sorted_balance`
+    /// order, so token issuances, fills and redemptions show their tokens.
+    pub asset_changes: Vec<(String, i64)>,
     pub fee: u64,
     pub height: Option<u32>,
     pub timestamp: Option<u32>,
@@ -46,6 +83,35 @@ pub struct WalletTransaction {
     pub tx_type: String,
 }
 
+impl WalletTransaction {
+    pub fn from_wallet_tx(
+        tx: &lwk_wollet::WalletTx,
+        policy_asset: &lwk_wollet::elements::AssetId,
+    ) -> Self {
+        Self {
+            txid: tx.txid.to_string(),
+            balance_change: tx.balance.get(policy_asset).copied().unwrap_or(0),
+            asset_changes: sorted_balance(
+                tx.balance
+                    .iter()
+                    .map(|(asset_id, change)| (asset_id.to_string(), *change)),
+                &policy_asset.to_string(),
+            ),
+            fee: tx.fee,
+            height: tx.height,
+            timestamp: tx.timestamp,
+            tx_type: tx.type_.clone(),
+        }
+    }
+}
+
+/// Whether `tx` changed the wallet's balance of any of `assets`.
+pub fn tx_touches_any(tx: &lwk_wollet::WalletTx, assets: &[lwk_wollet::elements::AssetId]) -> bool {
+    tx.balance
+        .iter()
+        .any(|(asset_id, change)| *change != 0 && assets.contains(asset_id))
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LiquidSendResult {
@@ -69,27 +135,18 @@ impl WalletSnapshotEvent {
         snapshot: &deadcat_sdk::WalletSnapshot,
         policy_asset: &lwk_wollet::elements::AssetId,
     ) -> Self {
-        let mut assets = HashMap::new();
-        for (asset_id, amount) in &snapshot.balance {
-            if *amount > 0 {
-                assets.insert(asset_id.to_string(), *amount);
-            }
-        }
+        let balance = WalletBalance::new(
+            snapshot
+                .balance
+                .iter()
+                .map(|(asset_id, amount)| (asset_id.to_string(), *amount)),
+            &policy_asset.to_string(),
+        );
 
         let transactions = snapshot
             .transactions
             .iter()
-            .map(|tx| {
-                let balance_change = tx.balance.get(policy_asset).copied().unwrap_or(0);
-                WalletTransaction {
-                    txid: tx.txid.to_string(),
-                    balance_change,
-                    fee: tx.fee,
-                    height: tx.height,
-                    timestamp: tx.timestamp,
-                    tx_type: tx.type_.clone(),
-                }
-            })
+            .map(|tx| WalletTransaction::from_wallet_tx(tx, policy_asset))
             .collect();
 
         let utxos = snapshot
@@ -105,9 +162,50 @@ impl WalletSnapshotEvent {
             .collect();
 
         Self {
-            balance: WalletBalance { assets },
+            balance,
             transactions,
             utxos,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_balance_puts_policy_asset_first() {
+        let assets = [
+            ("cc".to_string(), 3),
+            ("bb".to_string(), 2),
+            ("ff".to_string(), 100),
+            ("aa".to_string(), 0),
+        ];
+        assert_eq!(
+            sorted_balance(assets, "ff"),
+            vec![
+                ("ff".to_string(), 100),
+                ("bb".to_string(), 2),
+                ("cc".to_string(), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn sorted_balance_keeps_signed_changes() {
+        let changes = [
+            ("bb".to_string(), -5_i64),
+            ("ff".to_string(), -1_200),
+            ("aa".to_string(), 0),
+            ("cc".to_string(), 10),
+        ];
+        assert_eq!(
+            sorted_balance(changes, "ff"),
+            vec![
+                ("ff".to_string(), -1_200),
+                ("bb".to_string(), -5),
+                ("cc".to_string(), 10),
+            ]
+        );
+    }
+}
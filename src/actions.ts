@@ -138,6 +138,7 @@ export const MARKET_ACTION_LIST = [
   "cancel-limit-order",
   "submit-trade",
   "submit-issue",
+  "prepare-wallet-utxo",
   "submit-redeem",
   "submit-cancel",
   "submit-create-market",
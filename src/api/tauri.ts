@@ -1,19 +1,123 @@
-import { invoke } from "@tauri-apps/api/core";
+import { type InvokeArgs, invoke as coreInvoke } from "@tauri-apps/api/core";
 import type {
+  ActionLogEntry,
+  ActionLogFilter,
   AppNetwork,
+  AppStateBackupResponse,
+  AppStateRestoreResponse,
+  BroadcastIntent,
   ChainTipResponse,
+  CoinSelection,
+  ConsolidationResponse,
+  DeleteAnnouncementResponse,
+  DescriptorTemplate,
+  DiscoveredMarket,
+  DiscoveredOrder,
+  ExplicitWalletAddress,
+  DiagnosticsReport,
+  NetworkTimeouts,
+  NodeInfo,
   NostrBackupStatus,
+  NostrBackupVerification,
+  NostrProfile,
   PaymentSwap,
+  PolicyAsset,
+  PruneStoreResponse,
+  PublishStatusResponse,
+  RecoverPositionsResponse,
+  RegtestFundResponse,
+  RescanSummary,
+  ShareLink,
+  StoreIntegrityResponse,
+  StoreRebuildSummary,
+  StoreSchemaVersionResponse,
+  StuckSwap,
+  SwapReceipt,
+  SwapTimeRemaining,
+  TransactionDetailResponse,
+  WalletBalanceEntries,
   WalletNetwork,
+  WalletSyncSummary,
   WalletTransaction,
 } from "../types.ts";
 
 type AppStateResponse = {
+  walletProfile: string;
   walletStatus: "not_created" | "locked" | "unlocked";
   networkStatus: { network: AppNetwork; policyAssetId: string };
 };
 
-type WalletBalanceResponse = { assets: Record<string, number> };
+type WalletBalanceResponse = { assets: WalletBalanceEntries };
+
+type WalletProfileInfo = {
+  name: string;
+  network: AppNetwork | null;
+  isActive: boolean;
+};
+
+// Codes of the structured errors commands fail with (`CommandError` in
+// src-tauri/src/lib.rs).
+export type CommandErrorCode =
+  | "mainnet_confirmation_required"
+  // A command stopped through `cancelOperation`.
+  | "operation_cancelled"
+  // The same operation is already being built or broadcast.
+  | "operation_in_progress"
+  // The fee needs a separate L-BTC UTXO; carries `min_amount` in sats.
+  | "split_utxo_required"
+  // Too few separate UTXOs of an asset; carries `asset_id`, `needed` and
+  // `available`.
+  | "need_more_utxos";
+
+// A structured command error. It stringifies to its message, so callers
+// that only display errors are unaffected. `fields` holds the variant's
+// typed fields, such as `min_amount`.
+export class CommandError extends Error {
+  constructor(
+    readonly code: CommandErrorCode,
+    message: string,
+    readonly fields: Record<string, unknown> = {},
+  ) {
+    super(message);
+    this.name = "CommandError";
+  }
+
+  override toString(): string {
+    return this.message;
+  }
+}
+
+export function hasErrorCode(error: unknown, code: CommandErrorCode): boolean {
+  return error instanceof CommandError && error.code === code;
+}
+
+function parseCommandError(error: unknown): unknown {
+  if (typeof error !== "string" || !error.startsWith("{")) return error;
+  try {
+    const { code, message, ...fields } = JSON.parse(error) as Record<
+      string,
+      unknown
+    >;
+    if (typeof code === "string" && typeof message === "string") {
+      return new CommandError(code as CommandErrorCode, message, fields);
+    }
+  } catch {
+    // Plain error text that happens to start with a brace.
+  }
+  return error;
+}
+
+// `invoke` from @tauri-apps/api/core, with structured errors decoded.
+export async function invoke<T>(
+  command: string,
+  payload?: InvokeArgs,
+): Promise<T> {
+  try {
+    return await coreInvoke<T>(command, payload);
+  } catch (e) {
+    throw parseCommandError(e);
+  }
+}
 
 export function tauriInvoke<T>(
   command: string,
@@ -24,23 +128,169 @@ export function tauriInvoke<T>(
 
 export const tauriApi = {
   getAppState: () => tauriInvoke<AppStateResponse>("get_app_state"),
+  listWallets: () => tauriInvoke<WalletProfileInfo[]>("list_wallets"),
+  createNamedWallet: (
+    name: string,
+    network: AppNetwork,
+    password: string,
+    descriptorTemplate?: DescriptorTemplate,
+  ) =>
+    tauriInvoke<string>("create_named_wallet", {
+      name,
+      network,
+      password,
+      descriptorTemplate,
+    }),
+  switchWallet: (name: string) =>
+    tauriInvoke<AppStateResponse>("switch_wallet", { name }),
   recordActivity: () => tauriInvoke<void>("record_activity"),
+  getAutoLockTimeout: () => tauriInvoke<number | null>("get_auto_lock_timeout"),
+  setAutoLockTimeout: (seconds: number | null) =>
+    tauriInvoke<void>("set_auto_lock_timeout", { seconds }),
+  getLockOnBackground: () => tauriInvoke<boolean>("get_lock_on_background"),
+  setLockOnBackground: (enabled: boolean) =>
+    tauriInvoke<void>("set_lock_on_background", { enabled }),
+  notifyAppBackgrounded: () => tauriInvoke<boolean>("notify_app_backgrounded"),
 
   fetchChainTip: (network: WalletNetwork) =>
     tauriInvoke<ChainTipResponse>("fetch_chain_tip", { network }),
+  getChainTipPollInterval: () =>
+    tauriInvoke<number>("get_chain_tip_poll_interval"),
+  setChainTipPollInterval: (seconds: number) =>
+    tauriInvoke<void>("set_chain_tip_poll_interval", { seconds }),
+  getNetworkTimeouts: () =>
+    tauriInvoke<NetworkTimeouts>("get_network_timeouts"),
+  setNetworkTimeouts: (timeouts: NetworkTimeouts) =>
+    tauriInvoke<void>("set_network_timeouts", { timeouts }),
+  getExpiryWarningWindow: () =>
+    tauriInvoke<number>("get_expiry_warning_window"),
+  setExpiryWarningWindow: (blocks: number) =>
+    tauriInvoke<void>("set_expiry_warning_window", { blocks }),
+  getFeeCoinSelection: () =>
+    tauriInvoke<Record<string, CoinSelection>>("get_fee_coin_selection"),
+  setFeeCoinSelection: (operation: string, strategy: CoinSelection) =>
+    tauriInvoke<void>("set_fee_coin_selection", { operation, strategy }),
+  getMinConfirmations: () => tauriInvoke<number>("get_min_confirmations"),
+  setMinConfirmations: (confirmations: number) =>
+    tauriInvoke<void>("set_min_confirmations", { confirmations }),
+  getRequireMainnetConfirmation: () =>
+    tauriInvoke<boolean>("get_require_mainnet_confirmation"),
+  setRequireMainnetConfirmation: (required: boolean) =>
+    tauriInvoke<void>("set_require_mainnet_confirmation", { required }),
 
   fetchNip65RelayList: () => tauriInvoke<string[]>("fetch_nip65_relay_list"),
+  fetchCreatorProfile: (pubkey: string) =>
+    tauriInvoke<NostrProfile | null>("fetch_creator_profile", { pubkey }),
+  fetchCreatorMarkets: (pubkey: string) =>
+    tauriInvoke<DiscoveredMarket[]>("fetch_creator_markets", { pubkey }),
+  getMarketByEvent: (event: string) =>
+    tauriInvoke<DiscoveredMarket>("get_market_by_event", { event }),
+  getMarketShareLink: (marketId: string) =>
+    tauriInvoke<ShareLink>("get_market_share_link", { marketId }),
+  getOrderShareLink: (orderId: number) =>
+    tauriInvoke<ShareLink>("get_order_share_link", { orderId }),
+  getPoolShareLink: (poolId: string) =>
+    tauriInvoke<ShareLink>("get_pool_share_link", { poolId }),
+  fetchCreatorOrders: (pubkey: string) =>
+    tauriInvoke<DiscoveredOrder[]>("fetch_creator_orders", { pubkey }),
+  republishEvent: (eventId: string) =>
+    tauriInvoke<PublishStatusResponse>("republish_event", { eventId }),
+  listPendingBroadcastIntents: () =>
+    tauriInvoke<BroadcastIntent[]>("list_pending_broadcast_intents"),
+  retryBroadcastIntent: (txid: string) =>
+    tauriInvoke<string>("retry_broadcast_intent", { txid }),
+  discardBroadcastIntent: (txid: string) =>
+    tauriInvoke<void>("discard_broadcast_intent", { txid }),
+  deleteAnnouncement: (eventId: string) =>
+    tauriInvoke<DeleteAnnouncementResponse>("delete_announcement", { eventId }),
   checkNostrBackup: () => tauriInvoke<NostrBackupStatus>("check_nostr_backup"),
+  verifyNostrBackup: (password: string) =>
+    tauriInvoke<NostrBackupVerification>("verify_nostr_backup", { password }),
+  backupAppStateToNostr: () =>
+    tauriInvoke<AppStateBackupResponse>("backup_app_state_to_nostr"),
+  restoreAppStateFromNostr: () =>
+    tauriInvoke<AppStateRestoreResponse>("restore_app_state_from_nostr"),
+  recoverPositions: () =>
+    tauriInvoke<RecoverPositionsResponse>("recover_positions"),
 
   getWalletBalance: () =>
     tauriInvoke<WalletBalanceResponse>("get_wallet_balance"),
-  getWalletTransactions: () =>
-    tauriInvoke<WalletTransaction[]>("get_wallet_transactions"),
+  getWalletTransactions: (filter?: { assetId?: string; marketId?: string }) =>
+    tauriInvoke<WalletTransaction[]>("get_wallet_transactions", {
+      assetId: filter?.assetId ?? null,
+      marketId: filter?.marketId ?? null,
+    }),
+  getExplicitAddress: (index?: number) =>
+    tauriInvoke<ExplicitWalletAddress>("get_explicit_address", { index }),
+  getTransactionDetail: (txid: string) =>
+    tauriInvoke<TransactionDetailResponse>("get_transaction_detail", { txid }),
+  consolidateWallet: (
+    maxInputs: number,
+    feeRate: number,
+    assetId: string | null = null,
+    confirmMainnet?: boolean,
+  ) =>
+    tauriInvoke<ConsolidationResponse>("consolidate_wallet", {
+      assetId,
+      maxInputs,
+      feeRate,
+      confirmMainnet,
+    }),
+  getActionLog: (filter: ActionLogFilter = {}) =>
+    tauriInvoke<ActionLogEntry[]>("get_action_log", { filter }),
   listPaymentSwaps: () => tauriInvoke<PaymentSwap[]>("list_payment_swaps"),
+  getStuckSwaps: () => tauriInvoke<StuckSwap[]>("get_stuck_swaps"),
+  getSwapReceipt: (swapId: string) =>
+    tauriInvoke<SwapReceipt>("get_swap_receipt", { swapId }),
+  exportSwapReceipt: (swapId: string) =>
+    tauriInvoke<string>("export_swap_receipt", { swapId }),
+  swapTimeRemaining: (swapId: string) =>
+    tauriInvoke<SwapTimeRemaining>("swap_time_remaining", { swapId }),
+  setBoltzUrl: (url: string | null) =>
+    tauriInvoke<void>("set_boltz_url", { url }),
+  setEsploraUrl: (url: string | null) =>
+    tauriInvoke<void>("set_esplora_url", { url }),
+  setLogLevel: (level: string) =>
+    tauriInvoke<void>("set_log_level", { level }),
+  formatAmount: (assetId: string, rawAmount: number) =>
+    tauriInvoke<string>("format_amount", { assetId, rawAmount }),
+  parseAmount: (assetId: string, human: string) =>
+    tauriInvoke<number>("parse_amount", { assetId, human }),
 
-  restoreWallet: (mnemonic: string, password: string) =>
-    tauriInvoke<void>("restore_wallet", { mnemonic, password }),
+  restoreWallet: (
+    mnemonic: string,
+    password: string,
+    descriptorTemplate?: DescriptorTemplate,
+  ) =>
+    tauriInvoke<void>("restore_wallet", {
+      mnemonic,
+      password,
+      descriptorTemplate,
+    }),
   unlockWallet: (password: string) =>
     tauriInvoke<void>("unlock_wallet", { password }),
-  syncWallet: () => tauriInvoke<void>("sync_wallet"),
+  syncWallet: (opId?: string) =>
+    tauriInvoke<WalletSyncSummary>("sync_wallet", { opId: opId ?? null }),
+  cancelOperation: (opId: string) =>
+    tauriInvoke<boolean>("cancel_operation", { opId }),
+  runDiagnostics: () => tauriInvoke<DiagnosticsReport>("run_diagnostics"),
+  getNodeInfo: () => tauriInvoke<NodeInfo>("get_node_info"),
+  getPolicyAsset: () => tauriInvoke<PolicyAsset>("get_policy_asset"),
+  // Only registered in builds with the backend's `regtest` feature.
+  regtestFund: (address: string, amountSat: number) =>
+    tauriInvoke<RegtestFundResponse>("regtest_fund", { address, amountSat }),
+  getStoreSchemaVersion: () =>
+    tauriInvoke<StoreSchemaVersionResponse>("get_store_schema_version"),
+  checkStoreIntegrity: () =>
+    tauriInvoke<StoreIntegrityResponse>("check_store_integrity"),
+  compactStore: () => tauriInvoke<void>("compact_store"),
+  rebuildStore: () => tauriInvoke<StoreRebuildSummary>("rebuild_store"),
+  // Rescans the full history; the height only scopes `transactions_found`.
+  rescanFromHeight: (reportFromHeight: number) =>
+    tauriInvoke<RescanSummary>("rescan_from_height", { reportFromHeight }),
+  pruneStore: (retainBlocks?: number, keepSnapshotsPerPool?: number) =>
+    tauriInvoke<PruneStoreResponse>("prune_store", {
+      retainBlocks,
+      keepSnapshotsPerPool,
+    }),
 };
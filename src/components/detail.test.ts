@@ -68,10 +68,10 @@ function setWalletLotsForMarket(
   noLots = 0,
 ): void {
   stateModule.state.walletData = {
-    balance: {
-      [reverseHex(market.yesAssetId)]: yesLots,
-      [reverseHex(market.noAssetId)]: noLots,
-    },
+    balance: [
+      [reverseHex(market.yesAssetId), yesLots],
+      [reverseHex(market.noAssetId), noLots],
+    ],
     transactions: [],
     utxos: [],
     swaps: [],
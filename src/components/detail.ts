@@ -844,6 +844,14 @@ export function renderActionTicket(market: Market): string {
           <div class="flex items-center justify-between"><span>Required collateral</span><span>${formatSats(issueCollateral)}</span></div>
           <div class="mt-1 text-xs text-slate-400">Formula: pairs * 2 * CPT (${state.pairsInput} * 2 * ${market.cptSats})</div>
         </div>
+        ${
+          state.issueSplitSuggestionSats !== null
+            ? `<div class="mt-3 rounded-xl border border-amber-500/40 bg-amber-500/10 p-3 text-xs text-amber-200">
+          <p>Issuance needs a separate L-BTC UTXO for the fee. Prepare the wallet by sending ${formatSats(state.issueSplitSuggestionSats)} to yourself, then submit again.</p>
+          <button data-action="prepare-wallet-utxo" class="mt-2 w-full rounded-lg border border-amber-400/60 px-3 py-1.5 font-semibold text-amber-100">Prepare wallet</button>
+        </div>`
+            : ""
+        }
         <button data-action="submit-issue" ${paths.issue || paths.initialIssue ? "" : "disabled"} class="mt-4 w-full rounded-lg ${paths.issue || paths.initialIssue ? "bg-emerald-300 text-slate-950" : "bg-slate-700 text-slate-400"} px-4 py-2 font-semibold">Submit Issuance Transaction</button>
       </div>
       `
@@ -976,6 +984,7 @@ export function renderDetail(): string {
                 <div class="kv-row"><span class="shrink-0">Outcome bytes</span><span class="mono">YES=0x01, NO=0x00</span></div>
                 <div class="kv-row"><span class="shrink-0">Resolve status</span><span class="${market.resolveTx?.sigVerified ? "text-emerald-300" : "text-slate-400"}">${market.resolveTx ? `Attested ${market.resolveTx.outcome.toUpperCase()} @ ${market.resolveTx.height}` : "Unresolved"}</span></div>
                 ${market.resolveTx ? `<div class="kv-row"><span class="shrink-0">Sig hash</span><button data-action="copy-to-clipboard" data-copy-value="${market.resolveTx.signatureHash}" class="mono truncate text-right hover:text-slate-100 transition cursor-pointer" title="${market.resolveTx.signatureHash}">${market.resolveTx.signatureHash.slice(0, 8)}...${market.resolveTx.signatureHash.slice(-8)}</button></div><div class="kv-row"><span class="shrink-0">Resolve tx</span><button data-action="copy-to-clipboard" data-copy-value="${market.resolveTx.txid}" class="mono truncate text-right hover:text-slate-100 transition cursor-pointer" title="${market.resolveTx.txid}">${market.resolveTx.txid.slice(0, 8)}...${market.resolveTx.txid.slice(-8)}</button></div>` : ""}
+                ${market.attestation ? `<div class="kv-row"><span class="shrink-0">Oracle attestation</span><span class="text-emerald-300">${market.attestation.outcome_yes ? "YES" : "NO"} · signed ${new Date(market.attestation.signed_at * 1000).toLocaleString()}</span></div>` : ""}
               </div>
               ${
                 state.marketMakerMode &&
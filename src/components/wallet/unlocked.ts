@@ -1,6 +1,7 @@
 import { btcLabel, formatLbtc, satsLabel } from "../../services/wallet.ts";
 import { markets, state } from "../../state.ts";
 import type { DiscoveredOrder, Market } from "../../types.ts";
+import { balanceOf } from "../../utils/balance.ts";
 import { reverseHex } from "../../utils/crypto.ts";
 import { satsToFiatStr } from "../../utils/format.ts";
 import { escapeAttr, escapeHtml } from "../../utils/html.ts";
@@ -24,7 +25,7 @@ export function renderWalletUnlocked(params: {
   const wd = state.walletData;
   const policyBalance =
     wd && state.walletPolicyAssetId
-      ? (wd.balance[state.walletPolicyAssetId] ?? 0)
+      ? balanceOf(wd.balance, state.walletPolicyAssetId)
       : 0;
 
   const creationTxToMarket = new Map(
@@ -66,7 +67,7 @@ export function renderWalletUnlocked(params: {
   }
 
   // Token positions: non-policy assets with positive balance
-  const tokenPositions = Object.entries(wd?.balance ?? {})
+  const tokenPositions = (wd?.balance ?? [])
     .filter(([id, amt]) => id !== state.walletPolicyAssetId && amt > 0)
     .map(([id, amt]) => {
       const info = assetLabel.get(id);
@@ -1,5 +1,5 @@
-import { invoke } from "@tauri-apps/api/core";
 import { openUrl } from "@tauri-apps/plugin-opener";
+import { hasErrorCode, invoke } from "../api/tauri.ts";
 import { MOCK_MARKETS } from "../mock-markets.ts";
 import {
   cancelLimitOrder,
@@ -12,6 +12,8 @@ import {
   marketToContractParamsJson,
   mergeOrdersIntoMarket,
   quoteTrade,
+  splitUtxo,
+  splitUtxoRequiredSats,
 } from "../services/markets.ts";
 import {
   fetchWalletStatus,
@@ -37,7 +39,7 @@ import type {
   BoltzLightningReceiveCreated,
   BoltzSubmarineSwapCreated,
   CovenantState,
-  DiscoveredMarket,
+  CreateContractResponse,
   IdentityResponse,
   Market,
   MarketCategory,
@@ -45,11 +47,14 @@ import type {
   NostrBackupStatus,
   OrderType,
   PaymentSwap,
+  RecoverPositionsResponse,
   RelayBackupResult,
   Side,
   SizeMode,
   TradeDirection,
   TradeIntent,
+  WalletBackup,
+  WalletBalanceEntries,
   WalletTransaction,
 } from "../types.ts";
 import {
@@ -59,6 +64,7 @@ import {
   updateOverlayMessage,
 } from "../ui/loader.ts";
 import { showToast } from "../ui/toast.ts";
+import { balanceOf } from "../utils/balance.ts";
 import { reverseHex } from "../utils/crypto.ts";
 import { formatSats, formatSatsInput } from "../utils/format.ts";
 import {
@@ -129,6 +135,44 @@ function requireMarketAnchor(
   return market.anchor;
 }
 
+async function recoverRestoredPositions(): Promise<void> {
+  updateOverlayMessage("Recovering positions...");
+  let recovered: RecoverPositionsResponse;
+  try {
+    recovered = await invoke<RecoverPositionsResponse>("recover_positions");
+  } catch (e) {
+    // The wallet itself is restored; only the position scan failed.
+    showToast(
+      `Wallet restored, but positions were not recovered: ${e}`,
+      "warning",
+    );
+    return;
+  }
+  if (recovered.unidentified.length > 0) {
+    showToast(
+      `${recovered.unidentified.length} wallet asset(s) could not be matched to a market`,
+      "warning",
+    );
+  }
+}
+
+async function invokeConfirmingMainnet<T>(
+  command: string,
+  args: Record<string, unknown>,
+  description: string,
+): Promise<T> {
+  try {
+    return await invoke<T>(command, args);
+  } catch (e) {
+    if (!hasErrorCode(e, "mainnet_confirmation_required")) throw e;
+    const confirmed = window.confirm(
+      `${description} spends real funds on Liquid mainnet. Continue?`,
+    );
+    if (!confirmed) throw new Error("Cancelled.");
+    return invoke<T>(command, { ...args, confirmMainnet: true });
+  }
+}
+
 export async function handleClick(
   e: MouseEvent,
   deps: ClickDeps,
@@ -450,6 +494,7 @@ export async function handleClick(
         });
         updateOverlayMessage("Scanning blockchain...");
         await invoke("sync_wallet");
+        await recoverRestoredPositions();
         updateOverlayMessage("Loading markets...");
         showToast("Wallet restored!", "success");
         await finishOnboarding();
@@ -476,11 +521,14 @@ export async function handleClick(
     render();
     (async () => {
       try {
-        const mnemonic = await invoke<string>("restore_mnemonic_from_nostr");
+        const backup = await invoke<WalletBackup>(
+          "restore_mnemonic_from_nostr",
+        );
         updateOverlayMessage("Restoring wallet...");
         await invoke("restore_wallet", {
-          mnemonic: mnemonic.trim(),
+          mnemonic: backup.mnemonic.trim(),
           password: state.onboardingWalletPassword,
+          descriptorTemplate: backup.descriptor_template ?? undefined,
         });
         updateOverlayMessage("Unlocking wallet...");
         await invoke("unlock_wallet", {
@@ -488,6 +536,7 @@ export async function handleClick(
         });
         updateOverlayMessage("Scanning blockchain...");
         await invoke("sync_wallet");
+        await recoverRestoredPositions();
         updateOverlayMessage("Loading markets...");
         showToast("Wallet restored from Nostr backup!", "success");
         await finishOnboarding();
@@ -863,13 +912,8 @@ export async function handleClick(
     render();
     (async () => {
       try {
-        await invoke("set_relay_list", {
-          relays: ["wss://relay.damus.io", "wss://relay.primal.net"],
-        });
-        state.relays = [
-          { url: "wss://relay.damus.io", has_backup: false },
-          { url: "wss://relay.primal.net", has_backup: false },
-        ];
+        const list = await invoke<string[]>("reset_relays_to_default");
+        state.relays = list.map((u) => ({ url: u, has_backup: false }));
         showToast("Relays reset to defaults", "success");
       } catch (e) {
         showToast(`Failed to reset relays: ${String(e)}`, "error");
@@ -1000,11 +1044,14 @@ export async function handleClick(
     showOverlayLoader("Fetching backup from relays...");
     (async () => {
       try {
-        const mnemonic = await invoke<string>("restore_mnemonic_from_nostr");
+        const backup = await invoke<WalletBackup>(
+          "restore_mnemonic_from_nostr",
+        );
         hideOverlayLoader();
         // Pre-fill the mnemonic in the restore form
         state.walletShowRestore = true;
-        state.walletRestoreMnemonic = mnemonic;
+        state.walletRestoreMnemonic = backup.mnemonic;
+        state.walletRestoreDescriptorTemplate = backup.descriptor_template;
         render();
         showToast("Recovery phrase retrieved from Nostr", "success");
       } catch (e) {
@@ -1067,7 +1114,7 @@ export async function handleClick(
       void invoke("sync_wallet")
         .then(async () => {
           const [balance, txs, swaps] = await Promise.all([
-            invoke<{ assets: Record<string, number> }>("get_wallet_balance"),
+            invoke<{ assets: WalletBalanceEntries }>("get_wallet_balance"),
             invoke<WalletTransaction[]>("get_wallet_transactions"),
             invoke<PaymentSwap[]>("list_payment_swaps"),
           ]);
@@ -1150,14 +1197,18 @@ export async function handleClick(
         await invoke("restore_wallet", {
           mnemonic: state.walletRestoreMnemonic.trim(),
           password: state.walletPassword,
+          descriptorTemplate:
+            state.walletRestoreDescriptorTemplate ?? undefined,
         });
         state.walletRestoreMnemonic = "";
+        state.walletRestoreDescriptorTemplate = null;
         state.walletPassword = "";
         updateOverlayMessage("Scanning blockchain...");
         await invoke("sync_wallet");
+        await recoverRestoredPositions();
         await fetchWalletStatus();
         if (state.walletStatus === "unlocked") {
-          const balance = await invoke<{ assets: Record<string, number> }>(
+          const balance = await invoke<{ assets: WalletBalanceEntries }>(
             "get_wallet_balance",
           );
           const txs = await invoke<WalletTransaction[]>(
@@ -1200,7 +1251,7 @@ export async function handleClick(
         await fetchWalletStatus();
         // Load cached wallet data instantly (no Electrum sync)
         const [balance, txs, swaps] = await Promise.all([
-          invoke<{ assets: Record<string, number> }>("get_wallet_balance"),
+          invoke<{ assets: WalletBalanceEntries }>("get_wallet_balance"),
           invoke<WalletTransaction[]>("get_wallet_transactions"),
           invoke<PaymentSwap[]>("list_payment_swaps"),
         ]);
@@ -1224,7 +1275,7 @@ export async function handleClick(
         invoke("sync_wallet")
           .then(async () => {
             const [freshBalance, freshTxs] = await Promise.all([
-              invoke<{ assets: Record<string, number> }>("get_wallet_balance"),
+              invoke<{ assets: WalletBalanceEntries }>("get_wallet_balance"),
               invoke<WalletTransaction[]>("get_wallet_transactions"),
             ]);
             if (state.walletData) {
@@ -1614,13 +1665,17 @@ export async function handleClick(
     render();
     (async () => {
       try {
-        const result = await invoke<{ txid: string; feeSat: number }>(
+        const result = await invokeConfirmingMainnet<{
+          txid: string;
+          feeSat: number;
+        }>(
           "send_lbtc",
           {
             address,
             amountSat,
             feeRate: null,
           },
+          "This send",
         );
         state.sentLiquidResult = { txid: result.txid, feeSat: result.feeSat };
       } catch (e) {
@@ -1930,17 +1985,21 @@ export async function handleClick(
     render();
     (async () => {
       try {
-        const result = await invoke<{
+        const result = await invokeConfirmingMainnet<{
           txid: string;
           previous_state: number;
           new_state: number;
           outcome_yes: boolean;
-        }>("resolve_market", {
-          contractParamsJson: marketToContractParamsJson(market),
-          anchor,
-          outcomeYes,
-          oracleSignatureHex: state.lastAttestationSig,
-        });
+        }>(
+          "resolve_market",
+          {
+            contractParamsJson: marketToContractParamsJson(market),
+            anchor,
+            outcomeYes,
+            oracleSignatureHex: state.lastAttestationSig,
+          },
+          "Resolving this market",
+        );
         market.state = result.outcome_yes ? 2 : 3;
         state.lastAttestationSig = null;
         state.lastAttestationOutcome = null;
@@ -2272,6 +2331,9 @@ export async function handleClick(
           `Order cancelled! Refunded ${result.refunded_amount} sats. txid: ${result.txid.slice(0, 16)}...`,
           "success",
         );
+        if (result.announcement_warning) {
+          showToast(result.announcement_warning, "warning");
+        }
         await refreshWallet(render);
         const orders = await fetchOrders(orderMarketId);
         mergeOrdersIntoMarket(orderMarketId, orders);
@@ -2290,6 +2352,27 @@ export async function handleClick(
     return;
   }
 
+  if (action === "prepare-wallet-utxo") {
+    const amountSat = state.issueSplitSuggestionSats;
+    if (amountSat === null) return;
+    showToast("Preparing wallet...", "info");
+    (async () => {
+      try {
+        const result = await splitUtxo(amountSat);
+        state.issueSplitSuggestionSats = null;
+        showToast(
+          `Wallet prepared (txid: ${result.txid.slice(0, 16)}...). Submit issuance again.`,
+          "success",
+        );
+        await refreshWallet(render);
+      } catch (error) {
+        showToast(`Preparing wallet failed: ${error}`, "error");
+      }
+      render();
+    })();
+    return;
+  }
+
   if (
     action === "submit-trade" ||
     action === "submit-issue" ||
@@ -2320,7 +2403,7 @@ export async function handleClick(
       render();
       (async () => {
         try {
-          const result = await invoke<DiscoveredMarket>(
+          const result = await invokeConfirmingMainnet<CreateContractResponse>(
             "create_contract_onchain",
             {
               request: {
@@ -2332,6 +2415,7 @@ export async function handleClick(
                 collateral_per_token: 5000,
               },
             },
+            "Creating this market",
           );
           markets.push(discoveredToMarket(result));
           state.view = "home";
@@ -2343,6 +2427,12 @@ export async function handleClick(
             `Market created! txid: ${result.anchor?.creation_txid ?? "unknown"}`,
             "success",
           );
+          if (result.publish_warning) {
+            showToast(
+              `Market announcement ${result.publish_warning}`,
+              "warning",
+            );
+          }
         } catch (error) {
           showToast(`Failed to create market: ${error}`, "error");
         } finally {
@@ -2376,6 +2466,12 @@ export async function handleClick(
               `Limit order placed! txid: ${result.txid.slice(0, 16)}...`,
               "success",
             );
+            if (result.publish_warning) {
+              showToast(
+                `Order announcement ${result.publish_warning}`,
+                "warning",
+              );
+            }
             await refreshWallet(render);
             const orders = await fetchOrders(market.marketId);
             mergeOrdersIntoMarket(market.marketId, orders);
@@ -2487,13 +2583,24 @@ export async function handleClick(
       (async () => {
         try {
           const result = await issueTokens(market, pairs);
+          state.issueSplitSuggestionSats = null;
           showToast(
             `Tokens issued! txid: ${result.txid.slice(0, 16)}...`,
             "success",
           );
         } catch (error) {
-          showToast(`Issuance failed: ${error}`, "error");
+          const splitSats = splitUtxoRequiredSats(error);
+          if (splitSats !== null) {
+            state.issueSplitSuggestionSats = splitSats;
+            showToast(
+              "Issuance needs a separate fee UTXO. Prepare the wallet first.",
+              "warning",
+            );
+          } else {
+            showToast(`Issuance failed: ${error}`, "error");
+          }
         }
+        render();
       })();
       return;
     }
@@ -2563,7 +2670,7 @@ export async function handleClick(
       } else if (paths.expiryRedeem) {
         // For expiry redemption, determine which token side the user holds
         const yesBalance =
-          state.walletData?.balance?.[reverseHex(market.yesAssetId)] ?? 0;
+          balanceOf(state.walletData?.balance, reverseHex(market.yesAssetId));
         // Use whichever side the user holds (prefer YES if both)
         const tokenAssetHex =
           yesBalance > 0 ? market.yesAssetId : market.noAssetId;
@@ -1,11 +1,28 @@
 import { listen } from "@tauri-apps/api/event";
 import {
-  fetchOrders,
+  fetchOrderBook,
   mergeOrdersIntoMarket,
   refreshMarketsFromStore,
 } from "../services/markets.ts";
-import { createWalletData, markets, state } from "../state.ts";
-import type { WalletTransaction, WalletUtxo } from "../types.ts";
+import {
+  acceptStateRevision,
+  createWalletData,
+  markets,
+  state,
+} from "../state.ts";
+import type {
+  BalanceUpdatedEvent,
+  BroadcastIntent,
+  MarketExpiringEvent,
+  MarketResolvedEvent,
+  OrderExpiringEvent,
+  PriceAlertEvent,
+  WalletBalanceEntries,
+  WalletTransaction,
+  WalletUtxo,
+} from "../types.ts";
+import { showToast } from "../ui/toast.ts";
+import { applyBalanceChanges } from "../utils/balance.ts";
 
 export function setupTauriSubscriptions(render: () => void): () => void {
   let marketRefreshInFlight = false;
@@ -48,10 +65,12 @@ export function setupTauriSubscriptions(render: () => void): () => void {
 
   registerListener(
     listen<{
+      revision: number;
       walletStatus: "not_created" | "locked" | "unlocked";
     }>("app_state_updated", (event) => {
       if (disposed) return;
       const payload = event.payload;
+      if (!acceptStateRevision(payload.revision)) return;
       if (
         payload.walletStatus === "locked" &&
         state.walletStatus === "unlocked"
@@ -67,7 +86,7 @@ export function setupTauriSubscriptions(render: () => void): () => void {
 
   registerListener(
     listen<{
-      balance: { assets: Record<string, number> };
+      balance: { assets: WalletBalanceEntries };
       transactions: WalletTransaction[];
       utxos: WalletUtxo[];
     } | null>("wallet_snapshot", (event) => {
@@ -87,6 +106,19 @@ export function setupTauriSubscriptions(render: () => void): () => void {
     }),
   );
 
+  registerListener(
+    listen<BalanceUpdatedEvent>("balance_updated", (event) => {
+      if (disposed || !state.walletData) return;
+      if (!acceptStateRevision(event.payload.revision)) return;
+      state.walletData.balance = applyBalanceChanges(
+        state.walletData.balance,
+        event.payload.changed,
+        state.walletPolicyAssetId,
+      );
+      render();
+    }),
+  );
+
   let orderRefreshInFlight = false;
   let orderRefreshQueued = false;
 
@@ -102,7 +134,7 @@ export function setupTauriSubscriptions(render: () => void): () => void {
 
     const marketId = selectedMarket.marketId;
     orderRefreshInFlight = true;
-    void fetchOrders(marketId)
+    void fetchOrderBook(marketId)
       .then((orders) => {
         if (disposed) return;
         mergeOrdersIntoMarket(marketId, orders);
@@ -133,6 +165,74 @@ export function setupTauriSubscriptions(render: () => void): () => void {
     }),
   );
 
+  const marketLabel = (marketId: string, fallback: string): string => {
+    const market = markets.find((m) => m.marketId === marketId);
+    return market ? `"${market.question}"` : fallback;
+  };
+
+  registerListener(
+    listen<MarketResolvedEvent>("market_resolved", (event) => {
+      if (disposed) return;
+      const { market_id, outcome, winning_tokens } = event.payload;
+      const label = marketLabel(market_id, "A market you hold");
+      const result = outcome.toUpperCase();
+      showToast(
+        winning_tokens > 0
+          ? `${label} resolved ${result}. You can redeem ${winning_tokens} winning tokens.`
+          : `${label} resolved ${result}.`,
+        winning_tokens > 0 ? "success" : "info",
+      );
+      scheduleMarketRefresh();
+    }),
+  );
+
+  registerListener(
+    listen<MarketExpiringEvent>("market_expiring", (event) => {
+      if (disposed) return;
+      const { market_id, blocks_remaining } = event.payload;
+      showToast(
+        `${marketLabel(market_id, "A market you hold")} expires in ${blocks_remaining} blocks.`,
+        "warning",
+      );
+    }),
+  );
+
+  registerListener(
+    listen<OrderExpiringEvent>("order_expiring", (event) => {
+      if (disposed) return;
+      const { market_id, blocks_remaining } = event.payload;
+      showToast(
+        `Your open order on ${marketLabel(market_id, "a market")} has ${blocks_remaining} blocks until the market expires. Consider cancelling it.`,
+        "warning",
+      );
+    }),
+  );
+
+  registerListener(
+    listen<PriceAlertEvent>("price_alert", (event) => {
+      if (disposed) return;
+      const { market_id, direction, threshold_bps, yes_price_bps } =
+        event.payload;
+      const price = (yes_price_bps / 100).toFixed(1);
+      const threshold = (threshold_bps / 100).toFixed(1);
+      showToast(
+        `${marketLabel(market_id, "A market")} YES is at ${price}%, ${direction} your ${threshold}% alert.`,
+        "info",
+      );
+    }),
+  );
+
+  registerListener(
+    listen<BroadcastIntent[]>("broadcast_intents_pending", (event) => {
+      if (disposed) return;
+      const count = event.payload.length;
+      showToast(
+        `${count} transaction${count === 1 ? " was" : "s were"} interrupted before reaching the network.`,
+        "warning",
+      );
+    }),
+  );
+
   return () => {
     disposed = true;
     while (unlisteners.length > 0) {
@@ -1,6 +1,6 @@
 import "./style.css";
-import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
+import { invoke } from "./api/tauri.ts";
 import { renderCreateMarket } from "./components/create.ts";
 import { renderDetail } from "./components/detail.ts";
 import { renderHome } from "./components/home.ts";
@@ -16,6 +16,8 @@ import { handleInput } from "./handlers/input.ts";
 import { handleKeydown } from "./handlers/keydown.ts";
 // Services
 import {
+  fetchMarketAttestation,
+  fetchOrderBook,
   fetchOrders,
   fetchOwnOrders,
   loadMarkets,
@@ -28,17 +30,26 @@ import {
   refreshWallet,
   syncCurrentHeightFromLwk,
 } from "./services/wallet.ts";
-import { app, createWalletData, markets, state } from "./state.ts";
+import {
+  acceptStateRevision,
+  app,
+  createWalletData,
+  markets,
+  state,
+} from "./state.ts";
 import type {
+  BalanceUpdatedEvent,
   IdentityResponse,
   NostrBackupStatus,
   NostrProfile,
   RelayBackupResult,
   Side,
   TradeIntent,
+  WalletBalanceEntries,
   WalletTransaction,
   WalletUtxo,
 } from "./types.ts";
+import { applyBalanceChanges } from "./utils/balance.ts";
 import { formatSatsInput } from "./utils/format.ts";
 // Utils
 import {
@@ -169,7 +180,7 @@ function openMarket(
   render();
 
   // Fetch limit orders for the selected market in the background
-  fetchOrders(market.marketId).then((orders) => {
+  fetchOrderBook(market.marketId).then((orders) => {
     mergeOrdersIntoMarket(market.marketId, orders);
     render();
   });
@@ -181,6 +192,16 @@ function openMarket(
       render();
     })
     .catch(() => {});
+
+  // Show the oracle's verified attestation for resolved markets
+  if (market.state === 2 || market.state === 3) {
+    fetchMarketAttestation(market.marketId)
+      .then((attestation) => {
+        market.attestation = attestation ?? undefined;
+        render();
+      })
+      .catch(() => {});
+  }
 }
 
 async function finishOnboarding(): Promise<void> {
@@ -391,9 +412,11 @@ async function initApp(): Promise<void> {
 // ── Backend state listener (auto-lock, etc.) ────────────────────────
 
 void listen<{
+  revision: number;
   walletStatus: "not_created" | "locked" | "unlocked";
 }>("app_state_updated", (event) => {
   const payload = event.payload;
+  if (!acceptStateRevision(payload.revision)) return;
   if (payload.walletStatus === "locked" && state.walletStatus === "unlocked") {
     state.walletStatus = "locked";
     state.walletData = null;
@@ -405,7 +428,7 @@ void listen<{
 
 // Push wallet balance + transactions from backend whenever the snapshot changes
 void listen<{
-  balance: { assets: Record<string, number> };
+  balance: { assets: WalletBalanceEntries };
   transactions: WalletTransaction[];
   utxos: WalletUtxo[];
 } | null>("wallet_snapshot", (event) => {
@@ -423,6 +446,18 @@ void listen<{
   render();
 });
 
+// Merge balance-only refreshes into the wallet balance
+void listen<BalanceUpdatedEvent>("balance_updated", (event) => {
+  if (!state.walletData) return;
+  if (!acceptStateRevision(event.payload.revision)) return;
+  state.walletData.balance = applyBalanceChanges(
+    state.walletData.balance,
+    event.payload.changed,
+    state.walletPolicyAssetId,
+  );
+  render();
+});
+
 // ── Discovery event listeners ────────────────────────────────────────
 
 void listen("discovery:market", () => {
@@ -453,6 +488,10 @@ for (const evt of ["click", "keydown", "mousemove", "scroll"] as const) {
   window.addEventListener(evt, reportActivity, { passive: true });
 }
 
+document.addEventListener("visibilitychange", () => {
+  if (document.hidden) void invoke("notify_app_backgrounded");
+});
+
 // ── Event listeners ──────────────────────────────────────────────────
 
 const clickDeps = { render, openMarket, finishOnboarding };
@@ -1,19 +1,31 @@
-import { invoke } from "@tauri-apps/api/core";
+import { CommandError, hasErrorCode, invoke } from "../api/tauri.ts";
 import { markets, setMarkets } from "../state.ts";
 import type {
+  BestQuoteResponse,
   CancelLimitOrderResponse,
   CreateLimitOrderResponse,
   DiscoveredMarket,
   DiscoveredOrder,
   ExecuteTradeExpectedQuote,
   ExecuteTradeResponse,
+  IssuancePreview,
   IssuanceResult,
+  ListSortBy,
   Market,
   MarketCategory,
+  MarketCategoryCount,
+  MarketIssuance,
+  MarketMetrics,
+  OracleKey,
+  OracleResolutionResponse,
   OwnOrderSummary,
+  RedemptionPreview,
+  RepriceLimitOrderResponse,
   Side,
   TradeDirection,
+  TopUpLimitOrderResponse,
   TradeQuoteResponse,
+  VerifiedAttestation,
 } from "../types.ts";
 import { hexToBytes } from "../utils/crypto.ts";
 
@@ -58,11 +70,14 @@ export function discoveredToMarket(d: DiscoveredMarket): Market {
   };
 }
 
-export async function loadMarkets(): Promise<void> {
+export async function loadMarkets(opId?: string): Promise<void> {
   try {
-    const stored = await invoke<DiscoveredMarket[]>("discover_contracts");
+    const stored = await invoke<DiscoveredMarket[]>("discover_contracts", {
+      opId: opId ?? null,
+    });
     setMarkets(stored.map(discoveredToMarket));
   } catch (error) {
+    if (hasErrorCode(error, "operation_cancelled")) return;
     console.warn("Failed to load markets:", error);
     setMarkets([]);
   }
@@ -88,6 +103,82 @@ export async function refreshMarketsFromStore(): Promise<void> {
   }
 }
 
+export async function listMarketsSorted(
+  sortBy: ListSortBy,
+  descending = false,
+): Promise<Market[]> {
+  const stored = await invoke<DiscoveredMarket[]>("list_contracts", {
+    sortBy,
+    descending,
+  });
+  return stored.map(discoveredToMarket);
+}
+
+export async function searchMarkets(query: string): Promise<Market[]> {
+  const found = await invoke<DiscoveredMarket[]>("search_markets", { query });
+  return found.map(discoveredToMarket);
+}
+
+export async function listMarketCategories(): Promise<MarketCategoryCount[]> {
+  return invoke<MarketCategoryCount[]>("list_market_categories");
+}
+
+export async function listMarketsByCategory(
+  category: string,
+): Promise<Market[]> {
+  const found = await invoke<DiscoveredMarket[]>("list_markets_by_category", {
+    category,
+  });
+  return found.map(discoveredToMarket);
+}
+
+export async function fetchMarketAttestation(
+  marketId: string,
+): Promise<VerifiedAttestation | null> {
+  return invoke<VerifiedAttestation | null>("fetch_market_attestation", {
+    marketId,
+  });
+}
+
+export async function getOracleKey(): Promise<OracleKey | null> {
+  return invoke<OracleKey | null>("get_oracle_key");
+}
+
+export async function listMarketsToResolve(
+  oraclePubkey: string,
+): Promise<Market[]> {
+  const found = await invoke<DiscoveredMarket[]>("list_markets_to_resolve", {
+    oraclePubkey,
+  });
+  return found.map(discoveredToMarket);
+}
+
+export async function publishOracleResolution(
+  marketId: string,
+  outcomeYes: boolean,
+  resolveOnchain = false,
+  confirmMainnet?: boolean,
+): Promise<OracleResolutionResponse> {
+  return invoke<OracleResolutionResponse>("publish_oracle_resolution", {
+    marketId,
+    outcomeYes,
+    resolveOnchain,
+    confirmMainnet,
+  });
+}
+
+export async function getMarketMetrics(
+  marketId: string,
+): Promise<MarketMetrics> {
+  return invoke<MarketMetrics>("get_market_metrics", { marketId });
+}
+
+export async function recoverMarketIssuance(
+  marketId: string,
+): Promise<MarketIssuance> {
+  return invoke<MarketIssuance>("recover_market_issuance", { marketId });
+}
+
 export function marketToContractParamsJson(market: Market): string {
   return JSON.stringify({
     oracle_public_key: hexToBytes(market.oraclePubkey),
@@ -104,6 +195,7 @@ export function marketToContractParamsJson(market: Market): string {
 export async function issueTokens(
   market: Market,
   pairs: number,
+  feeAmount?: number,
 ): Promise<IssuanceResult> {
   if (!market.anchor) {
     throw new Error("Market has no canonical anchor — cannot issue tokens");
@@ -112,6 +204,82 @@ export async function issueTokens(
     contractParamsJson: marketToContractParamsJson(market),
     anchor: market.anchor,
     pairs,
+    feeAmount: feeAmount ?? null,
+  });
+}
+
+/** Collateral locked and assets minted by issuing `pairs`; builds no tx. */
+export async function previewIssuance(
+  market: Market,
+  pairs: number,
+): Promise<IssuancePreview> {
+  if (!market.anchor) {
+    throw new Error(
+      "Market has no canonical anchor — cannot preview issuance",
+    );
+  }
+  return invoke<IssuancePreview>("preview_issuance", {
+    contractParamsJson: marketToContractParamsJson(market),
+    anchor: market.anchor,
+    pairs,
+  });
+}
+
+/**
+ * Payout for redeeming `tokens`, with any shortfall in held tokens or covenant
+ * collateral. `tokenAssetHex` is the token to burn on the expiry path.
+ */
+export async function previewRedemption(
+  market: Market,
+  tokens: number,
+  tokenAssetHex?: string,
+): Promise<RedemptionPreview> {
+  if (!market.anchor) {
+    throw new Error(
+      "Market has no canonical anchor — cannot preview redemption",
+    );
+  }
+  return invoke<RedemptionPreview>("preview_redemption", {
+    contractParamsJson: marketToContractParamsJson(market),
+    anchor: market.anchor,
+    tokens,
+    tokenAssetHex: tokenAssetHex ?? null,
+  });
+}
+
+/**
+ * Minimum fee UTXO, in sats, when an issuance failed because the wallet has
+ * no L-BTC UTXO besides the collateral one; `null` for any other error.
+ */
+export function splitUtxoRequiredSats(error: unknown): number | null {
+  if (!(error instanceof CommandError)) return null;
+  if (error.code !== "split_utxo_required") return null;
+  return Number(error.fields.min_amount);
+}
+
+/**
+ * Asset and UTXO counts when an operation failed because the wallet has too
+ * few separate UTXOs of that asset; `null` for any other error.
+ */
+export function needMoreUtxos(
+  error: unknown,
+): { assetId: string; needed: number; available: number } | null {
+  if (!(error instanceof CommandError)) return null;
+  if (error.code !== "need_more_utxos") return null;
+  return {
+    assetId: String(error.fields.asset_id),
+    needed: Number(error.fields.needed),
+    available: Number(error.fields.available),
+  };
+}
+
+/** Split off a second L-BTC UTXO; resolves once the wallet sees it. */
+export async function splitUtxo(
+  amountSat: number,
+): Promise<{ txid: string; feeSat: number }> {
+  return invoke<{ txid: string; feeSat: number }>("split_utxo", {
+    amountSat,
+    feeRate: null,
   });
 }
 
@@ -132,6 +300,26 @@ export async function quoteTrade(
   });
 }
 
+/** The same trade quoted via the pool, the order book, and both. */
+export async function getBestQuote(
+  market: Market,
+  side: Side,
+  direction: TradeDirection,
+  exactInput: number,
+  feeAmount?: number,
+): Promise<BestQuoteResponse> {
+  return invoke<BestQuoteResponse>("get_best_quote", {
+    request: {
+      contract_params_json: marketToContractParamsJson(market),
+      market_id: market.marketId,
+      side,
+      direction,
+      exact_input: Math.max(1, Math.floor(exactInput)),
+      fee_amount: feeAmount,
+    },
+  });
+}
+
 export async function executeTrade(
   market: Market,
   side: Side,
@@ -161,6 +349,21 @@ export async function fetchOrders(
   });
 }
 
+export async function discoverLiveOrders(
+  marketId?: string,
+): Promise<DiscoveredOrder[]> {
+  return invoke<DiscoveredOrder[]>("discover_live_orders", {
+    marketId: marketId ?? null,
+  });
+}
+
+export async function fetchOrderBook(
+  marketId: string,
+): Promise<DiscoveredOrder[]> {
+  // Covenant scans need an unlocked wallet; show announcements until then.
+  return discoverLiveOrders(marketId).catch(() => fetchOrders(marketId));
+}
+
 export async function createLimitOrder(
   market: Market,
   side: Side,
@@ -186,6 +389,8 @@ export async function cancelLimitOrder(
   order: DiscoveredOrder,
   orderIndex?: number,
   feeAmount = 500,
+  outbidPendingFill = false,
+  deleteAnnouncement = true,
 ): Promise<CancelLimitOrderResponse> {
   return invoke<CancelLimitOrderResponse>("cancel_limit_order", {
     request: {
@@ -202,12 +407,72 @@ export async function cancelLimitOrder(
       maker_receive_spk_hash: order.maker_receive_spk_hash,
       fee_amount: feeAmount,
       order_index: orderIndex ?? null,
+      outbid_pending_fill: outbidPendingFill,
+      delete_announcement_id: deleteAnnouncement ? order.id : null,
+    },
+  });
+}
+
+export async function topUpLimitOrder(
+  order: DiscoveredOrder,
+  additionalAmount: number,
+  orderIndex?: number,
+  feeAmount = 500,
+): Promise<TopUpLimitOrderResponse> {
+  return invoke<TopUpLimitOrderResponse>("top_up_limit_order", {
+    request: {
+      market_id: order.market_id,
+      base_asset_id: order.base_asset_id,
+      quote_asset_id: order.quote_asset_id,
+      price: order.price,
+      min_fill_lots: order.min_fill_lots,
+      min_remainder_lots: order.min_remainder_lots,
+      direction: order.direction,
+      direction_label: order.direction_label,
+      maker_base_pubkey: order.maker_base_pubkey,
+      order_nonce: order.order_nonce,
+      cosigner_pubkey: order.cosigner_pubkey,
+      maker_receive_spk_hash: order.maker_receive_spk_hash,
+      additional_amount: Math.floor(additionalAmount),
+      fee_amount: feeAmount,
+      order_index: orderIndex ?? null,
+    },
+  });
+}
+
+export async function repriceLimitOrder(
+  order: DiscoveredOrder,
+  newPrice: number,
+  orderIndex?: number,
+  feeAmount = 500,
+): Promise<RepriceLimitOrderResponse> {
+  return invoke<RepriceLimitOrderResponse>("reprice_limit_order", {
+    request: {
+      market_id: order.market_id,
+      base_asset_id: order.base_asset_id,
+      quote_asset_id: order.quote_asset_id,
+      price: order.price,
+      min_fill_lots: order.min_fill_lots,
+      min_remainder_lots: order.min_remainder_lots,
+      direction: order.direction,
+      direction_label: order.direction_label,
+      maker_base_pubkey: order.maker_base_pubkey,
+      order_nonce: order.order_nonce,
+      cosigner_pubkey: order.cosigner_pubkey,
+      maker_receive_spk_hash: order.maker_receive_spk_hash,
+      new_price: Math.floor(newPrice),
+      fee_amount: feeAmount,
+      order_index: orderIndex ?? null,
+      delete_announcement_id: order.id,
     },
   });
 }
 
-export async function fetchOwnOrders(): Promise<OwnOrderSummary[]> {
-  return invoke<OwnOrderSummary[]>("list_own_orders");
+export async function fetchOwnOrders(
+  sortBy?: ListSortBy,
+  descending?: boolean,
+): Promise<OwnOrderSummary[]> {
+  return invoke<OwnOrderSummary[]>("list_own_orders", { sortBy, descending });
 }
 
 export function mergeOrdersIntoMarket(
@@ -1,9 +1,18 @@
-import { invoke } from "@tauri-apps/api/core";
+import { invoke } from "../api/tauri.ts";
 import type {
   CreateLmsrPoolResponse,
+  CreateMarketWithPoolRequest,
+  CreateMarketWithPoolResponse,
+  LmsrPoolCreationPreview,
+  LmsrPoolDepositQuote,
   LmsrPoolInfo,
+  LmsrPoolWithdrawalQuote,
+  PoolAsset,
+  PriceAlert,
+  PriceAlertDirection,
   PriceHistoryEntry,
   ScanLmsrPoolResponse,
+  VerifyPoolResponse,
 } from "../types.ts";
 
 export async function generateLmsrTable(
@@ -46,12 +55,77 @@ export async function createLmsrPool(
   });
 }
 
+/**
+ * Create a market, issue `pairs` and seed a pool from them. After a failure,
+ * pass the reported `market_id` instead of `market` to resume.
+ */
+export async function createMarketWithPool(
+  request: CreateMarketWithPoolRequest,
+  confirmMainnet?: boolean,
+): Promise<CreateMarketWithPoolResponse> {
+  return invoke<CreateMarketWithPoolResponse>("create_market_with_pool", {
+    request,
+    confirmMainnet,
+  });
+}
+
+/** Starting prices for `createLmsrPool` and warnings on unbalanced reserves. */
+export async function previewLmsrPoolCreation(
+  poolParamsJson: string,
+  initialSIndex: number,
+  initialReservesYes: number,
+  initialReservesNo: number,
+  initialReservesLbtc: number,
+  tableValues: number[],
+): Promise<LmsrPoolCreationPreview> {
+  return invoke<LmsrPoolCreationPreview>("preview_lmsr_pool_creation", {
+    request: {
+      pool_params_json: poolParamsJson,
+      initial_s_index: initialSIndex,
+      initial_reserves_yes: initialReservesYes,
+      initial_reserves_no: initialReservesNo,
+      initial_reserves_lbtc: initialReservesLbtc,
+      table_values: tableValues,
+    },
+  });
+}
+
+/** Deposit into a stored pool, keeping its reserve ratio. */
+export async function quoteLmsrPoolDeposit(
+  poolId: string,
+  asset: PoolAsset,
+  amount: number,
+): Promise<LmsrPoolDepositQuote> {
+  return invoke<LmsrPoolDepositQuote>("quote_lmsr_pool_deposit", {
+    poolId,
+    asset,
+    amount,
+  });
+}
+
+/** Proportional withdrawal from a stored pool, checked against its minimums. */
+export async function quoteLmsrPoolWithdrawal(
+  poolId: string,
+  asset: PoolAsset,
+  amount: number,
+): Promise<LmsrPoolWithdrawalQuote> {
+  return invoke<LmsrPoolWithdrawalQuote>("quote_lmsr_pool_withdrawal", {
+    poolId,
+    asset,
+    amount,
+  });
+}
+
 export async function scanLmsrPool(
   poolId: string,
 ): Promise<ScanLmsrPoolResponse> {
   return invoke<ScanLmsrPoolResponse>("scan_lmsr_pool", { poolId });
 }
 
+export async function verifyPool(poolId: string): Promise<VerifyPoolResponse> {
+  return invoke<VerifyPoolResponse>("verify_pool", { poolId });
+}
+
 export async function listLmsrPools(
   marketId?: string,
 ): Promise<LmsrPoolInfo[]> {
@@ -79,3 +153,23 @@ export async function getPoolPriceHistory(
     sinceBlockHeight,
   });
 }
+
+export async function createPriceAlert(
+  poolId: string,
+  direction: PriceAlertDirection,
+  thresholdBps: number,
+): Promise<PriceAlert> {
+  return invoke<PriceAlert>("create_price_alert", {
+    poolId,
+    direction,
+    thresholdBps,
+  });
+}
+
+export async function listPriceAlerts(poolId?: string): Promise<PriceAlert[]> {
+  return invoke<PriceAlert[]>("list_price_alerts", { poolId });
+}
+
+export async function deletePriceAlert(id: number): Promise<void> {
+  return invoke<void>("delete_price_alert", { id });
+}
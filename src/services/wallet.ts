@@ -4,6 +4,7 @@ import { markets, state } from "../state";
 import type {
   AppNetwork,
   PaymentSwap,
+  WalletBalanceEntries,
   WalletNetwork,
   WalletTransaction,
 } from "../types";
@@ -11,7 +12,7 @@ import { hideOverlayLoader, showOverlayLoader } from "../ui/loader";
 import { getPriceHistory } from "./pools.ts";
 
 export type WalletSnapshot = {
-  balance: Record<string, number>;
+  balance: WalletBalanceEntries;
   transactions: WalletTransaction[];
   swaps: PaymentSwap[];
 };
@@ -205,6 +206,7 @@ export function resetWalletStoredState(): void {
   resetWalletSessionState();
   state.walletMnemonic = "";
   state.walletRestoreMnemonic = "";
+  state.walletRestoreDescriptorTemplate = null;
   state.walletShowRestore = false;
 }
 
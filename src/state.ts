@@ -7,6 +7,7 @@ import type {
   BoltzLightningReceiveCreated,
   BoltzSubmarineSwapCreated,
   ChartTimescale,
+  DescriptorTemplate,
   LimitSellWarning,
   LmsrPoolInfo,
   Market,
@@ -29,7 +30,7 @@ import type {
 
 export function createWalletData(): WalletData {
   return {
-    balance: {},
+    balance: [],
     transactions: [],
     utxos: [],
     swaps: [],
@@ -40,6 +41,16 @@ export function createWalletData(): WalletData {
   };
 }
 
+let appliedStateRevision = 0;
+
+// Backend state events carry a revision that only goes up, but they can be
+// delivered out of order. Returns false for one older than the last applied.
+export function acceptStateRevision(revision: number): boolean {
+  if (revision < appliedStateRevision) return false;
+  appliedStateRevision = revision;
+  return true;
+}
+
 export const app = document.querySelector<HTMLDivElement>(
   "#app",
 ) as HTMLDivElement;
@@ -119,6 +130,7 @@ export const state: {
   cancellingOrderId: string | null;
   tradeQuoteSnapshot: TradeQuoteSnapshot | null;
   tradeError: string | null;
+  issueSplitSuggestionSats: number | null;
   tradeSizeSats: number;
   tradeSizeSatsDraft: string;
   tradeContracts: number;
@@ -159,6 +171,8 @@ export const state: {
   walletPassword: string;
   walletPasswordConfirm: string;
   walletRestoreMnemonic: string;
+  /** Template from a Nostr backup that pre-filled the restore form. */
+  walletRestoreDescriptorTemplate: DescriptorTemplate | null;
   walletShowRestore: boolean;
   walletModal: "none" | "receive" | "send";
   walletModalTab: "lightning" | "liquid" | "bitcoin";
@@ -266,6 +280,7 @@ export const state: {
   cancellingOrderId: null,
   tradeQuoteSnapshot: null,
   tradeError: null,
+  issueSplitSuggestionSats: null,
   tradeSizeSats: 10000,
   tradeSizeSatsDraft: "10,000",
   tradeContracts: 10,
@@ -310,6 +325,7 @@ export const state: {
   walletPassword: "",
   walletPasswordConfirm: "",
   walletRestoreMnemonic: "",
+  walletRestoreDescriptorTemplate: null,
   walletShowRestore: false,
   walletModal: "none",
   walletModalTab: "lightning",
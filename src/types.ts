@@ -32,6 +32,21 @@ export type CollateralUtxo = {
 
 export type WalletNetwork = "liquid" | "liquid-testnet" | "liquid-regtest";
 export type AppNetwork = "mainnet" | "testnet" | "regtest";
+export type DescriptorTemplate = "wpkh" | "sh_wpkh";
+
+export type CoinSelection =
+  | "smallest_first"
+  | "largest_first"
+  | "exact_match_or_largest";
+
+// Per-service network timeouts in seconds.
+export type NetworkTimeouts = {
+  electrum: number;
+  esplora: number;
+  boltz: number;
+  nostr: number;
+  nostr_connect: number;
+};
 
 export type ChainTipResponse = {
   height: number;
@@ -95,13 +110,48 @@ export type PaymentSwap = {
   invoiceAmountSat: number;
   expectedAmountSat: number | null;
   lockupAddress: string | null;
+  timeoutBlockHeight?: number;
   invoice: string | null;
   invoiceExpiresAt: string | null;
   lockupTxid: string | null;
+  claimTxid?: string | null;
+  preimageHash?: string | null;
   createdAt: string;
   updatedAt: string;
 };
 
+export type SwapReceipt = {
+  swapId: string;
+  flow: string;
+  network: string;
+  status: string;
+  invoice: string | null;
+  paymentHash: string | null;
+  preimage: string | null;
+  sentAmountSat: number | null;
+  receivedAmountSat: number | null;
+  feeSat: number | null;
+  lockupAddress: string | null;
+  lockupTxid: string | null;
+  claimTxid: string | null;
+  createdAt: string;
+  completedAt: string;
+};
+
+export type SwapTimeRemaining = {
+  blocksUntilTimeout: number | null;
+  secondsUntilInvoiceExpiry: number | null;
+  tipHeight: number | null;
+};
+
+export type SwapRecoveryAction = "claim" | "refund" | "wait";
+
+export type StuckSwap = PaymentSwap & {
+  action: SwapRecoveryAction;
+  tipHeight: number;
+  lockupFunded: boolean | null;
+};
+
 export type DormantOutputOpening = {
   asset_blinding_factor: string;
   value_blinding_factor: string;
@@ -113,6 +163,29 @@ export type PredictionMarketAnchor = {
   no_dormant_opening: DormantOutputOpening;
 };
 
+// Sort keys accepted by `list_contracts` and `list_own_orders`.
+export type ListSortBy = "created_at" | "expiry" | "volume" | "price";
+
+export type MarketCategoryCount = {
+  category: string;
+  market_count: number;
+};
+
+export type MarketMetrics = {
+  total_collateral_locked: number;
+  pool_tvl: number;
+  open_order_count: number;
+  snapshot_count: number;
+  last_activity: string;
+};
+
+export type MarketIssuance = {
+  yes_entropy: string;
+  no_entropy: string;
+  yes_blinding_nonce: string;
+  no_blinding_nonce: string;
+};
+
 export type DiscoveredMarket = {
   id: string;
   nevent: string;
@@ -138,6 +211,12 @@ export type DiscoveredMarket = {
   no_price_bps?: number | null;
 };
 
+export type CreateContractResponse = DiscoveredMarket & {
+  relays_accepted: number;
+  relays_total: number;
+  publish_warning: string | null;
+};
+
 export type DiscoveredOrder = {
   id: string;
   market_id: string;
@@ -165,6 +244,15 @@ export type IssuanceResult = {
   previous_state: number;
   new_state: number;
   pairs_issued: number;
+  unsigned_pset: string | null;
+};
+
+export type IssuancePreview = {
+  required_collateral: number;
+  yes_asset: string;
+  no_asset: string;
+  current_state: number;
+  initial_issuance: boolean;
 };
 
 export type IdentityResponse = { pubkey_hex: string; npub: string };
@@ -175,6 +263,58 @@ export type NostrBackupStatus = {
   has_backup: boolean;
   relay_results: RelayBackupResult[];
 };
+/** Decrypted wallet backup; older backups carry no descriptor template. */
+export type WalletBackup = {
+  mnemonic: string;
+  descriptor_template: DescriptorTemplate | null;
+};
+export type NostrBackupVerification = {
+  exists: boolean;
+  decryptable: boolean;
+  matches_current_wallet: boolean;
+};
+export type AppStateBackupResponse = {
+  event_id: string;
+  chunk_count: number;
+  event_count: number;
+};
+export type AppStateRestoreResponse = {
+  events_restored: number;
+  labels_restored: number;
+};
+export type RecoveredPosition = {
+  asset_id: string;
+  amount: number;
+  market_id: string;
+  role: "yes" | "no" | "yes_reissuance" | "no_reissuance";
+};
+export type RecoverPositionsResponse = {
+  positions: RecoveredPosition[];
+  unidentified: { asset_id: string; amount: number }[];
+};
+export type CovenantLabel =
+  | { kind: "market"; market_id: string; slot: string }
+  | { kind: "maker_order"; order_id: number }
+  | { kind: "lmsr_pool"; pool_id: string; market_id: string };
+export type TransactionDetailEntry = {
+  txid: string;
+  vout: number;
+  asset_id: string | null;
+  amount: number | null;
+  address: string | null;
+  is_mine: boolean;
+  is_change: boolean;
+  is_fee: boolean;
+  covenant: CovenantLabel | null;
+};
+export type TransactionDetailResponse = {
+  txid: string;
+  height: number | null;
+  confirmations: number;
+  fee: number;
+  inputs: TransactionDetailEntry[];
+  outputs: TransactionDetailEntry[];
+};
 export type NostrProfile = {
   picture?: string;
   name?: string;
@@ -188,6 +328,30 @@ export type AttestationResult = {
   nostr_event_id: string;
 };
 
+// An oracle attestation checked against the market's oracle key.
+export type VerifiedAttestation = {
+  market_id: string;
+  outcome_yes: boolean;
+  signature_hex: string;
+  nostr_event_id: string;
+  signed_at: number;
+};
+
+export type OracleKey = {
+  pubkey_hex: string;
+};
+
+export type OracleResolutionResponse = {
+  attestation: AttestationResult;
+  // Set when the on-chain resolution was requested and broadcast.
+  resolution: {
+    txid: string;
+    previous_state: number;
+    new_state: number;
+    outcome_yes: boolean;
+  } | null;
+};
+
 export type Market = {
   id: string;
   nevent: string;
@@ -212,6 +376,7 @@ export type Market = {
   creationTxid: string | null;
   collateralUtxos: CollateralUtxo[];
   resolveTx?: ResolveTx;
+  attestation?: VerifiedAttestation;
   nostrEventJson: string | null;
   yesPrice: number | null;
   change24h: number;
@@ -231,12 +396,20 @@ export type PathAvailability = {
 export type WalletTransaction = {
   txid: string;
   balanceChange: number;
+  // [asset id, net change] for every asset moved, policy asset first.
+  assetChanges: [string, number][];
   fee: number;
   height: number | null;
   timestamp: number | null;
   txType: string;
 };
 
+export type ExplicitWalletAddress = {
+  index: number;
+  address: string;
+  warning: string;
+};
+
 export type WalletUtxo = {
   txid: string;
   vout: number;
@@ -246,7 +419,7 @@ export type WalletUtxo = {
 };
 
 export type WalletData = {
-  balance: Record<string, number>;
+  balance: WalletBalanceEntries;
   transactions: WalletTransaction[];
   utxos: WalletUtxo[];
   swaps: PaymentSwap[];
@@ -342,6 +515,21 @@ export type TradeQuoteResponse = {
   legs: RouteLeg[];
 };
 
+export type RouteOutcome = {
+  total_input: number;
+  total_output: number;
+  unfilled_input: number;
+  net_price: number;
+  legs: RouteLeg[];
+};
+
+export type BestQuoteResponse = {
+  via_pool: RouteOutcome | null;
+  via_orders: RouteOutcome | null;
+  via_split: RouteOutcome | null;
+  recommended: "pool" | "orders" | "split" | null;
+};
+
 export type QuoteMarketTradeResult = TradeQuoteResponse & {
   direction: TradeDirection;
   quote_id?: string;
@@ -373,11 +561,282 @@ export type CreateLimitOrderResponse = {
   covenant_address: string;
   order_amount: number;
   order_index: number;
+  relays_accepted: number;
+  relays_total: number;
+  publish_warning: string | null;
+};
+
+export type PublishStatusResponse = {
+  event_id: string;
+  status: "pending" | "published" | "failed" | "deleted";
+  relays_accepted: number;
+  attempts: number;
+  last_error: string | null;
+};
+
+export type CancelAllOrderOutcome = {
+  order_id: number;
+  market_id: string | null;
+  txid: string | null;
+  refunded_amount: number | null;
+  error: string | null;
+};
+
+export type ValidateOrderParamsResponse = {
+  order_lots: number;
 };
 
 export type CancelLimitOrderResponse = {
   txid: string;
   refunded_amount: number;
+  replaced_fill_txid: string | null;
+  announcement_deleted: boolean;
+  announcement_warning: string | null;
+};
+
+export type TopUpLimitOrderResponse = {
+  txid: string;
+  previous_amount: number;
+  offered_amount: number;
+  nostr_event_id: string;
+  relays_accepted: number;
+  relays_total: number;
+  publish_warning: string | null;
+};
+
+export type RepriceLimitOrderResponse = {
+  cancel_txid: string;
+  create_txid: string | null;
+  refunded_amount: number;
+  order_amount: number | null;
+  nostr_event_id: string | null;
+  covenant_address: string | null;
+  relays_accepted: number;
+  relays_total: number;
+  publish_warning: string | null;
+  announcement_deleted: boolean;
+  announcement_warning: string | null;
+  /** Set when the order was cancelled but could not be recreated. */
+  recreate_error: string | null;
+};
+
+export type DeleteAnnouncementResponse = {
+  deletion_event_id: string;
+  relays_accepted: number;
+  relays_total: number;
+  publish_warning: string | null;
+};
+
+export type SimulateOrderFillResponse = {
+  can_afford: boolean;
+  is_partial: boolean;
+  input_asset_id: string;
+  input_needed: number;
+  output_asset_id: string;
+  output_received: number;
+  fee_est: number;
+  input_shortfall: number;
+  fee_shortfall: number;
+};
+
+export type WalletSyncSummary = {
+  block_height: number | null;
+  new_utxos: number;
+  spent_utxos: number;
+  market_state_changes: {
+    market_id: string;
+    old_state: number;
+    new_state: number;
+  }[];
+  tentative_market_states: {
+    market_id: string;
+    old_state: number;
+    new_state: number;
+  }[];
+  order_status_changes: {
+    order_id: number;
+    old_status: string;
+    new_status: string;
+  }[];
+  pool_snapshots: number;
+  explicit_utxos: {
+    index: number;
+    txid: string;
+    vout: number;
+    asset_id: string;
+    amount: number;
+  }[];
+  warnings: string[];
+};
+
+// Non-zero wallet balances: the policy asset first, then by asset id.
+export type WalletBalanceEntries = [assetId: string, amount: number][];
+
+// Assets whose wallet balance changed; 0 means the asset is no longer held.
+export type BalanceUpdatedEvent = {
+  revision: number;
+  changed: Record<string, number>;
+};
+
+export type MarketResolvedEvent = {
+  market_id: string;
+  outcome: "yes" | "no";
+  winning_tokens: number;
+  losing_tokens: number;
+};
+
+export type MarketExpiringEvent = {
+  market_id: string;
+  expiry_height: number;
+  blocks_remaining: number;
+  yes_tokens: number;
+  no_tokens: number;
+};
+
+export type OrderExpiringEvent = {
+  order_id: number;
+  market_id: string;
+  expiry_height: number;
+  blocks_remaining: number;
+};
+
+export type DiagnosticCheck = {
+  ok: boolean;
+  detail: string;
+  elapsed_ms: number;
+};
+
+export type DiagnosticsReport = {
+  wallet_unlocked: boolean;
+  electrum_url: string;
+  electrum: DiagnosticCheck;
+  esplora: DiagnosticCheck;
+  boltz: DiagnosticCheck;
+  relays: { url: string; check: DiagnosticCheck }[];
+  store: DiagnosticCheck;
+};
+
+export type NodeInfo = {
+  network: AppNetwork;
+  wallet_unlocked: boolean;
+  // Null while the wallet is locked.
+  fingerprint: string | null;
+  xpub: string | null;
+  wallet_synced_height: number | null;
+  store_synced_height: number | null;
+  // Null when the chain backend is unreachable.
+  tip_height: number | null;
+  market_count: number;
+  order_count: number;
+  pool_count: number;
+  sdk_version: string;
+};
+
+export type PolicyAsset = {
+  asset_id: string;
+  // "L-BTC" on mainnet, "tL-BTC" on testnet and regtest.
+  ticker: string;
+};
+
+export type RegtestFundResponse = {
+  txid: string;
+  blocks_mined: number;
+};
+
+export type PruneStoreResponse = {
+  before_height: number;
+  spent_utxos: number;
+  price_snapshots: number;
+};
+
+export type StoreSchemaVersionResponse = {
+  version: string | null;
+  migration_error: string | null;
+};
+
+export type StoreIntegrityResponse = {
+  ok: boolean;
+  problems: string[];
+};
+
+export type StoreRebuildSummary = {
+  markets: number;
+  orders: number;
+  pools: number;
+  sync: WalletSyncSummary | null;
+  warnings: string[];
+};
+
+export type RescanProgress = {
+  phase: "scanning_wallet" | "syncing_store" | "complete";
+  from_height: number;
+  tip_height: number;
+};
+
+export type RescanSummary = {
+  from_height: number;
+  tip_height: number;
+  transactions_found: number;
+  sync: WalletSyncSummary | null;
+  warnings: string[];
+};
+
+export type ExpiredRedemptionSweepResponse = {
+  redemptions: {
+    txid: string;
+    extra_txids: string[];
+    previous_state: number;
+    tokens_redeemed: number;
+    payout_sats: number;
+    unsigned_pset: string | null;
+  }[];
+  total_tokens: number;
+  total_payout_sats: number;
+};
+
+export type RedemptionPreview = {
+  payout_sats: number;
+  is_expiry: boolean;
+  winning_asset: string;
+  current_state: number;
+  token_shortfall: number;
+  collateral_shortfall_sats: number;
+};
+
+export type ConsolidationResponse = {
+  txid: string;
+  asset_id: string;
+  inputs_consolidated: number;
+  output_value: number;
+  fee_sats: number;
+};
+
+export type ActionKind =
+  | "create_market"
+  | "issue_tokens"
+  | "cancel_tokens"
+  | "resolve_market"
+  | "redeem_tokens"
+  | "redeem_expired"
+  | "swap"
+  | "create_limit_order"
+  | "cancel_limit_order"
+  | "top_up_limit_order"
+  | "create_lmsr_pool";
+
+export type ActionLogEntry = {
+  id: number;
+  kind: ActionKind;
+  market_id: string | null;
+  txid: string | null;
+  details: Record<string, unknown>;
+  created_at: string;
+};
+
+export type ActionLogFilter = {
+  kind?: ActionKind;
+  market_id?: string;
+  limit?: number;
 };
 
 export type OwnOrderSummary = {
@@ -410,6 +869,8 @@ export type LmsrPoolInfo = {
   params_json: string;
   created_at: string;
   updated_at: string;
+  verification_status: "verified" | "stale" | "invalid" | null;
+  verification_reason: string | null;
 };
 
 export type PriceHistoryEntry = {
@@ -432,6 +893,64 @@ export type CreateLmsrPoolResponse = {
   pool_id: string;
 };
 
+export type CreateMarketWithPoolRequest = {
+  /** Omit when resuming an earlier run with `market_id`. */
+  market?: {
+    question: string;
+    description: string;
+    category: string;
+    resolution_source: string;
+    settlement_deadline_unix: number;
+    collateral_per_token: number;
+  };
+  market_id?: string;
+  pairs: number;
+  pool_params_json: string;
+  initial_s_index: number;
+  initial_reserves_yes: number;
+  initial_reserves_no: number;
+  initial_reserves_lbtc: number;
+  table_values: number[];
+};
+
+export type CreateMarketWithPoolResponse = {
+  market_id: string;
+  creation_txid: string | null;
+  issuance_txid: string | null;
+  pool_txid: string | null;
+  pool_id: string;
+};
+
+export type PoolAsset = "yes" | "no" | "lbtc";
+
+export type LmsrPoolDepositQuote = {
+  deposit_yes: number;
+  deposit_no: number;
+  deposit_lbtc: number;
+  new_reserves_yes: number;
+  new_reserves_no: number;
+  new_reserves_lbtc: number;
+};
+
+export type LmsrPoolWithdrawalQuote = {
+  withdraw_yes: number;
+  withdraw_no: number;
+  withdraw_lbtc: number;
+  new_reserves_yes: number;
+  new_reserves_no: number;
+  new_reserves_lbtc: number;
+};
+
+export type LmsrPoolCreationPreview = {
+  initial_yes_price_bps: number;
+  initial_no_price_bps: number;
+  reserve_yes_price_bps: number | null;
+  token_reserve_value_sats: number;
+  fee_bps: number;
+  max_fee_bps: number;
+  warnings: string[];
+};
+
 export type ScanLmsrPoolResponse = {
   pool_id: string;
   current_s_index: number;
@@ -440,6 +959,49 @@ export type ScanLmsrPoolResponse = {
   reserve_collateral: number;
 };
 
+export type VerifyPoolResponse = {
+  pool_id: string;
+  status: "verified" | "stale" | "invalid";
+  reason: string | null;
+  current_s_index: number | null;
+  reserve_yes: number | null;
+  reserve_no: number | null;
+  reserve_collateral: number | null;
+};
+
+export type PriceAlertDirection = "above" | "below";
+
+export type PriceAlert = {
+  id: number;
+  pool_id: string;
+  direction: PriceAlertDirection;
+  threshold_bps: number;
+  triggered: boolean;
+  created_at: string;
+};
+
+export type ShareLink = {
+  uri: string;
+  nevent: string;
+  relays: string[];
+};
+
+export type BroadcastIntent = {
+  txid: string;
+  kind: "issue_tokens" | "resolve_market" | "pool_swap";
+  market_id: string | null;
+  status: "pending" | "completed" | "abandoned";
+};
+
+export type PriceAlertEvent = {
+  alert_id: number;
+  pool_id: string;
+  market_id: string;
+  direction: PriceAlertDirection;
+  threshold_bps: number;
+  yes_price_bps: number;
+};
+
 export type CloseLmsrPoolResponse = {
   txid: string;
   reclaimed_yes: number;
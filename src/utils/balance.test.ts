@@ -0,0 +1,39 @@
+import { describe, expect, it } from "vitest";
+import type { WalletBalanceEntries } from "../types.ts";
+import { applyBalanceChanges, balanceOf } from "./balance.ts";
+
+const POLICY = "f".repeat(64);
+const TOKEN_A = "a".repeat(64);
+const TOKEN_B = "b".repeat(64);
+const TOKEN_C = "c".repeat(64);
+
+describe("wallet balance entries", () => {
+  it("reads amounts and treats missing assets as zero", () => {
+    const balance: WalletBalanceEntries = [
+      [POLICY, 5_000],
+      [TOKEN_A, 10],
+    ];
+    expect(balanceOf(balance, TOKEN_A)).toBe(10);
+    expect(balanceOf(balance, TOKEN_B)).toBe(0);
+    expect(balanceOf(undefined, POLICY)).toBe(0);
+  });
+
+  it("keeps the policy asset first and tokens by asset id after a delta", () => {
+    const balance: WalletBalanceEntries = [
+      [POLICY, 5_000],
+      [TOKEN_B, 20],
+      [TOKEN_C, 30],
+    ];
+    const next = applyBalanceChanges(
+      balance,
+      { [TOKEN_A]: 10, [TOKEN_C]: 0, [POLICY]: 4_000 },
+      POLICY,
+    );
+    expect(next).toEqual([
+      [POLICY, 4_000],
+      [TOKEN_A, 10],
+      [TOKEN_B, 20],
+    ]);
+    expect(balance).toHaveLength(3);
+  });
+});
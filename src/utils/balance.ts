@@ -0,0 +1,26 @@
+import type { WalletBalanceEntries } from "../types.ts";
+
+// Amount of `assetId` in `balance`, 0 when it is not held.
+export function balanceOf(
+  balance: WalletBalanceEntries | undefined,
+  assetId: string,
+): number {
+  return balance?.find(([id]) => id === assetId)?.[1] ?? 0;
+}
+
+// Apply a balance_updated delta, keeping the backend's order: the policy
+// asset first, then the other assets by asset id.
+export function applyBalanceChanges(
+  balance: WalletBalanceEntries,
+  changed: Record<string, number>,
+  policyAssetId: string,
+): WalletBalanceEntries {
+  const next = balance.filter(([id]) => !(id in changed));
+  for (const [id, amount] of Object.entries(changed)) {
+    if (amount > 0) next.push([id, amount]);
+  }
+  const rank = (id: string) => (id === policyAssetId ? 0 : 1);
+  return next.sort(
+    ([a], [b]) => rank(a) - rank(b) || (a < b ? -1 : a > b ? 1 : 0),
+  );
+}
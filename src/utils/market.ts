@@ -20,6 +20,7 @@ import type {
   TradeIntent,
   TradePreview,
 } from "../types.ts";
+import { balanceOf } from "./balance.ts";
 import { reverseHex } from "./crypto.ts";
 import { formatSatsInput } from "./format.ts";
 
@@ -99,12 +100,9 @@ export function getPositionContracts(market: Market): {
   no: number;
 } {
   const balance = state.walletData?.balance;
-  if (!balance) return { yes: 0, no: 0 };
-  const yesKey = reverseHex(market.yesAssetId);
-  const noKey = reverseHex(market.noAssetId);
   return {
-    yes: balance[yesKey] ?? 0,
-    no: balance[noKey] ?? 0,
+    yes: balanceOf(balance, reverseHex(market.yesAssetId)),
+    no: balanceOf(balance, reverseHex(market.noAssetId)),
   };
 }
 